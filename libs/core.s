@@ -83,6 +83,12 @@ _print:
     je .handle_string
     cmp al, 'd'
     je .handle_int
+    cmp al, 'u'
+    je .handle_uint
+    cmp al, 'D'
+    je .handle_wide
+    cmp al, 'U'
+    je .handle_uwide
     cmp al, 'f'
     je .handle_float
     cmp al, 'c'
@@ -104,6 +110,30 @@ _print:
     inc rsi
     jmp .loop
 
+.handle_uint:
+    mov rax, [rbx]
+    add rbx, 8
+    call _putuint_buf
+    inc rsi
+    jmp .loop
+
+/* %D / %U: 128 bitlik tamsayı iki argüman yuvasındadır (önce alt, sonra üst yarı) */
+.handle_wide:
+    mov rax, [rbx]
+    mov rdx, [rbx + 8]
+    add rbx, 16
+    call _putwide_buf
+    inc rsi
+    jmp .loop
+
+.handle_uwide:
+    mov rax, [rbx]
+    mov rdx, [rbx + 8]
+    add rbx, 16
+    call _putuwide_buf
+    inc rsi
+    jmp .loop
+
 .handle_char:
     mov rax, [rbx]
     add rbx, 8
@@ -188,13 +218,23 @@ _putint_buf:
     sub rsp, 40 
     
     test rax, rax
-    jns .pi_pos
+    jns .pi_digits
     push rax
     mov al, '-'
     call _putchar_buf
     pop rax
     neg rax
-.pi_pos:
+    jmp .pi_digits
+
+/* RAX'taki işaretsiz tamsayıyı yazar; _putint_buf ile aynı yığın düzenini kullanır */
+_putuint_buf:
+    push rdi
+    push rsi
+    push rbx
+    push rdx
+    push rcx
+    sub rsp, 40
+.pi_digits:
     lea rdi, [rsp + 32]     /* Local buffer area */
     mov byte ptr [rdi], 0
     mov rcx, 10
@@ -222,6 +262,65 @@ _putint_buf:
     pop rdi
     ret
 
+/* RDX:RAX'taki işaretli 128 bitlik tamsayıyı yazar */
+_putwide_buf:
+    /* entry: ...8 */
+    test rdx, rdx
+    jns _putuwide_buf
+    push rax        /* ...0 */
+    push rdx        /* ...8 */
+    sub rsp, 8      /* ...0 (Aligned) */
+    mov al, '-'
+    call _putchar_buf
+    add rsp, 8
+    pop rdx
+    pop rax
+    neg rax
+    adc rdx, 0
+    neg rdx
+    jmp _putuwide_buf
+
+/* RDX:RAX'taki işaretsiz 128 bitlik tamsayıyı yazar (39 basamağa kadar) */
+_putuwide_buf:
+    /* entry: ...8 */
+    push rdi        /* ...0 */
+    push rbx        /* ...8 */
+    push rcx        /* ...0 */
+    push r8         /* ...8 */
+    sub rsp, 56     /* ...0 (Aligned) */
+    lea rdi, [rsp + 48]
+    mov byte ptr [rdi], 0
+    mov rcx, 10
+.pw_loop:
+    /* Önce üst yarı, sonra kalanla birlikte alt yarı 10'a bölünür. */
+    mov r8, rax
+    mov rax, rdx
+    xor rdx, rdx
+    div rcx
+    xchg rax, r8
+    div rcx
+    add dl, '0'
+    dec rdi
+    mov [rdi], dl
+    mov rdx, r8
+    mov rbx, rax
+    or rbx, rdx
+    jnz .pw_loop
+.pw_print:
+    movzx rax, byte ptr [rdi]
+    test al, al
+    jz .pw_done
+    call _putchar_buf
+    inc rdi
+    jmp .pw_print
+.pw_done:
+    add rsp, 56
+    pop r8
+    pop rcx
+    pop rbx
+    pop rdi
+    ret
+
 _putfloat_buf:
     push r12
     push rax
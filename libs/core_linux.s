@@ -0,0 +1,862 @@
+/*
+   Linux x86-64 çalışma zamanı (runtime) kütüphanesi.
+   libc kullanmaz; tüm işler doğrudan syscall ile yapılır.
+   Çağrı kuralı: System V AMD64 (RDI, RSI, RDX, RCX, R8, R9 / XMM0-7).
+
+   Derleyici --target linux seçildiğinde bu dosyayı otomatik olarak derler ve bağlar:
+   gcc -x assembler -c core_linux.s -o core_linux.o
+*/
+
+.intel_syntax noprefix
+
+.section .data
+    _rt_heap_ptr:   .quad 0
+    _rt_heap_end:   .quad 0
+    _rt_half:       .double 0.5
+
+.section .bss
+    .lcomm _rt_outbuf, 4096
+
+.section .text
+.global _print
+.global _sprint
+.global _strlen
+.global _input
+.global _fmod
+.global _alloc_str
+.global _exit_process
+.global _io_open
+.global _io_close
+.global _io_read
+.global _io_write
+.global _io_seek
+.global _io_size
+.global _io_exists
+.global _io_remove
+.global _io_copy
+.global _io_flush
+.global _io_get_std
+.global _io_read_console
+
+/* Syscall numaraları */
+.set SYS_READ,   0
+.set SYS_WRITE,  1
+.set SYS_OPEN,   2
+.set SYS_CLOSE,  3
+.set SYS_LSEEK,  8
+.set SYS_MMAP,   9
+.set SYS_FSYNC,  74
+.set SYS_UNLINK, 87
+.set SYS_EXIT_GROUP, 231
+
+/* -------------------------------------------------------------------------- */
+/* Variadic argüman alanı (R14 ile gösterilir)                                */
+/*   [r14 + 0..47]   : RDI, RSI, RDX, RCX, R8, R9                             */
+/*   [r14 + 48..111] : XMM0 - XMM7                                            */
+/*   [r14 + 112]     : sıradaki tamsayı argümanın ofseti                      */
+/*   [r14 + 120]     : sıradaki float argümanın ofseti                        */
+/*   [r14 + 128]     : stack'teki (overflow) argümanların adresi              */
+/* -------------------------------------------------------------------------- */
+
+/* _print(format: RDI, ...) - formatlı çıktıyı stdout'a yazar */
+_print:
+    push rbp
+    mov rbp, rsp
+    sub rsp, 144
+    push rbx
+    push r12
+    push r13
+    push r14
+    push r15
+    sub rsp, 8
+
+    lea r14, [rbp - 144]
+    call _rt_save_args
+    mov qword ptr [r14 + 112], 8    # Format string ilk tamsayı argümanı
+    mov r12, rdi                    # R12 = Format
+    xor r13d, r13d                  # R13 = 0: stdout modu
+    lea r15, [rip + _rt_outbuf]     # R15 = Çıktı imleci
+    call _rt_format
+    call _rt_flush
+
+    add rsp, 8
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    leave
+    ret
+
+/* _sprint(buffer: RDI, format: RSI, ...) -> RAX (yazılan uzunluk) */
+_sprint:
+    push rbp
+    mov rbp, rsp
+    sub rsp, 144
+    push rbx
+    push r12
+    push r13
+    push r14
+    push r15
+    sub rsp, 8
+
+    lea r14, [rbp - 144]
+    call _rt_save_args
+    mov qword ptr [r14 + 112], 16   # Buffer ve format ilk iki argüman
+    mov r12, rsi                    # R12 = Format
+    mov r13d, 1                     # R13 = 1: buffer modu
+    mov r15, rdi                    # R15 = Hedef buffer
+    mov rbx, rdi
+    call _rt_format
+    mov byte ptr [r15], 0
+    mov rax, r15
+    sub rax, rbx
+
+    add rsp, 8
+    pop r15
+    pop r14
+    pop r13
+    pop r12
+    pop rbx
+    leave
+    ret
+
+/* Argüman register'larını R14'teki alana kaydeder (çağıranın RBP'si ile) */
+_rt_save_args:
+    mov [r14], rdi
+    mov [r14 + 8], rsi
+    mov [r14 + 16], rdx
+    mov [r14 + 24], rcx
+    mov [r14 + 32], r8
+    mov [r14 + 40], r9
+    movsd [r14 + 48], xmm0
+    movsd [r14 + 56], xmm1
+    movsd [r14 + 64], xmm2
+    movsd [r14 + 72], xmm3
+    movsd [r14 + 80], xmm4
+    movsd [r14 + 88], xmm5
+    movsd [r14 + 96], xmm6
+    movsd [r14 + 104], xmm7
+    mov qword ptr [r14 + 120], 0
+    lea rax, [rbp + 16]             # İlk stack argümanı
+    mov [r14 + 128], rax
+    ret
+
+/* Sıradaki tamsayı/pointer argümanı -> RAX */
+_rt_va_gp:
+    mov rax, [r14 + 112]
+    cmp rax, 48
+    jae .Lva_gp_stack
+    mov rax, [r14 + rax]
+    add qword ptr [r14 + 112], 8
+    ret
+.Lva_gp_stack:
+    mov rax, [r14 + 128]
+    add qword ptr [r14 + 128], 8
+    mov rax, [rax]
+    ret
+
+/* Sıradaki float argümanı -> XMM0 */
+_rt_va_fp:
+    mov rax, [r14 + 120]
+    cmp rax, 64
+    jae .Lva_fp_stack
+    movsd xmm0, [r14 + 48 + rax]
+    add qword ptr [r14 + 120], 8
+    ret
+.Lva_fp_stack:
+    mov rax, [r14 + 128]
+    add qword ptr [r14 + 128], 8
+    movsd xmm0, [rax]
+    ret
+
+/* -------------------------------------------------------------------------- */
+/* _rt_format: R12 = format, R15 = çıktı imleci, R13 = mod, R14 = argümanlar  */
+/* Desteklenen belirteçler: %d %u %s %c %f %.Nf %D %U %%                      */
+/* %D / %U: 128 bitlik tamsayı, iki argüman olarak (önce alt, sonra üst yarı)  */
+/* -------------------------------------------------------------------------- */
+_rt_format:
+    push rbx
+.Lf_loop:
+    movzx eax, byte ptr [r12]
+    test al, al
+    jz .Lf_done
+    cmp al, '%'
+    jne .Lf_literal
+
+    inc r12
+    movzx eax, byte ptr [r12]
+    mov ebx, 6                      # Varsayılan hassasiyet
+    cmp al, '.'
+    jne .Lf_spec
+    inc r12
+    xor ebx, ebx
+.Lf_prec:
+    movzx eax, byte ptr [r12]
+    cmp al, '0'
+    jb .Lf_prec_done
+    cmp al, '9'
+    ja .Lf_prec_done
+    imul rbx, rbx, 10
+    sub eax, '0'
+    add rbx, rax
+    inc r12
+    jmp .Lf_prec
+.Lf_prec_done:
+    cmp rbx, 17
+    jbe .Lf_spec
+    mov ebx, 17
+
+.Lf_spec:
+    test al, al
+    jz .Lf_done
+    cmp al, 'd'
+    je .Lf_int
+    cmp al, 'u'
+    je .Lf_uint
+    cmp al, 'D'
+    je .Lf_wide
+    cmp al, 'U'
+    je .Lf_uwide
+    cmp al, 's'
+    je .Lf_str
+    cmp al, 'c'
+    je .Lf_char
+    cmp al, 'f'
+    je .Lf_float
+    cmp al, '%'
+    je .Lf_literal
+    # Bilinmeyen belirteç: olduğu gibi yaz
+    mov al, '%'
+    call _rt_putc
+    movzx eax, byte ptr [r12]
+    jmp .Lf_literal
+
+.Lf_int:
+    call _rt_va_gp
+    call _rt_putint
+    inc r12
+    jmp .Lf_loop
+
+.Lf_uint:
+    call _rt_va_gp
+    mov ecx, 1
+    call _rt_putuint_w
+    inc r12
+    jmp .Lf_loop
+
+.Lf_wide:
+    call _rt_va_gp
+    mov r8, rax
+    call _rt_va_gp
+    mov rdx, rax
+    mov rax, r8
+    call _rt_putwide
+    inc r12
+    jmp .Lf_loop
+
+.Lf_uwide:
+    call _rt_va_gp
+    mov r8, rax
+    call _rt_va_gp
+    mov rdx, rax
+    mov rax, r8
+    call _rt_putuwide
+    inc r12
+    jmp .Lf_loop
+
+.Lf_str:
+    call _rt_va_gp
+    call _rt_putstr
+    inc r12
+    jmp .Lf_loop
+
+.Lf_char:
+    call _rt_va_gp
+    call _rt_putc
+    inc r12
+    jmp .Lf_loop
+
+.Lf_float:
+    call _rt_va_fp
+    call _rt_putfloat
+    inc r12
+    jmp .Lf_loop
+
+.Lf_literal:
+    call _rt_putc
+    inc r12
+    jmp .Lf_loop
+
+.Lf_done:
+    pop rbx
+    ret
+
+/* AL'deki karakteri çıktıya ekler; stdout modunda buffer dolunca boşaltır */
+_rt_putc:
+    mov [r15], al
+    inc r15
+    test r13, r13
+    jnz .Lpc_ret
+    push rax
+    lea rax, [rip + _rt_outbuf + 4096]
+    cmp r15, rax
+    pop rax
+    jb .Lpc_ret
+    call _rt_flush
+.Lpc_ret:
+    ret
+
+/* stdout buffer'ını write(1, ...) ile boşaltır. Register'ları korur. */
+_rt_flush:
+    test r13, r13
+    jnz .Lfl_ret
+    push rax
+    push rdi
+    push rsi
+    push rdx
+    push rcx
+    push r11
+    lea rsi, [rip + _rt_outbuf]
+    mov rdx, r15
+    sub rdx, rsi
+    jz .Lfl_restore
+    mov edi, 1
+    mov eax, SYS_WRITE
+    syscall
+    lea r15, [rip + _rt_outbuf]
+.Lfl_restore:
+    pop r11
+    pop rcx
+    pop rdx
+    pop rsi
+    pop rdi
+    pop rax
+.Lfl_ret:
+    ret
+
+/* RAX'taki null-terminated string'i yazar */
+_rt_putstr:
+    push r8
+    mov r8, rax
+    test r8, r8
+    jnz .Lps_loop
+    lea r8, [rip + .Lnull_str]
+.Lps_loop:
+    movzx eax, byte ptr [r8]
+    test al, al
+    jz .Lps_done
+    call _rt_putc
+    inc r8
+    jmp .Lps_loop
+.Lps_done:
+    pop r8
+    ret
+
+/* RAX'taki işaretli tamsayıyı yazar */
+_rt_putint:
+    test rax, rax
+    jns .Lpi_pos
+    push rax
+    mov al, '-'
+    call _rt_putc
+    pop rax
+    neg rax
+.Lpi_pos:
+    mov ecx, 1
+    jmp _rt_putuint_w
+
+/* RAX'taki işaretsiz tamsayıyı en az RCX basamakla (sıfır dolgulu) yazar */
+_rt_putuint_w:
+    push r9
+    push r10
+    sub rsp, 40
+    lea r9, [rsp + 32]
+    mov r10, 10
+.Lpu_div:
+    xor edx, edx
+    div r10
+    add dl, '0'
+    dec r9
+    mov [r9], dl
+    dec rcx
+    test rax, rax
+    jnz .Lpu_div
+.Lpu_pad:
+    cmp rcx, 0
+    jle .Lpu_out
+    dec r9
+    mov byte ptr [r9], '0'
+    dec rcx
+    jmp .Lpu_pad
+.Lpu_out:
+    lea r10, [rsp + 32]
+.Lpu_loop:
+    cmp r9, r10
+    jae .Lpu_done
+    mov al, [r9]
+    call _rt_putc
+    inc r9
+    jmp .Lpu_loop
+.Lpu_done:
+    add rsp, 40
+    pop r10
+    pop r9
+    ret
+
+/* RDX:RAX'taki işaretli 128 bitlik tamsayıyı yazar */
+_rt_putwide:
+    test rdx, rdx
+    jns _rt_putuwide
+    push rax
+    mov al, '-'
+    call _rt_putc
+    pop rax
+    neg rax
+    adc rdx, 0
+    neg rdx
+    jmp _rt_putuwide
+
+/* RDX:RAX'taki işaretsiz 128 bitlik tamsayıyı yazar (39 basamağa kadar) */
+_rt_putuwide:
+    push r9
+    push r10
+    push r11
+    sub rsp, 48
+    lea r9, [rsp + 48]
+    mov r10, 10
+.Lpw_div:
+    # Önce üst yarı, sonra kalanla birlikte alt yarı 10'a bölünür.
+    mov r11, rax
+    mov rax, rdx
+    xor edx, edx
+    div r10
+    xchg rax, r11
+    div r10
+    add dl, '0'
+    dec r9
+    mov [r9], dl
+    mov rdx, r11
+    mov rcx, rax
+    or rcx, rdx
+    jnz .Lpw_div
+    lea r10, [rsp + 48]
+.Lpw_loop:
+    cmp r9, r10
+    jae .Lpw_done
+    mov al, [r9]
+    call _rt_putc
+    inc r9
+    jmp .Lpw_loop
+.Lpw_done:
+    add rsp, 48
+    pop r11
+    pop r10
+    pop r9
+    ret
+
+/* XMM0'daki double'ı RBX basamak hassasiyetle yazar */
+_rt_putfloat:
+    sub rsp, 24
+    pxor xmm1, xmm1
+    ucomisd xmm0, xmm1
+    jae .Lpf_pos
+    movsd [rsp], xmm0
+    mov al, '-'
+    call _rt_putc
+    movsd xmm0, [rsp]
+    pxor xmm1, xmm1
+    subsd xmm1, xmm0
+    movapd xmm0, xmm1
+.Lpf_pos:
+    # Ölçek = 10^hassasiyet
+    mov rcx, rbx
+    mov eax, 1
+.Lpf_scale:
+    test rcx, rcx
+    jz .Lpf_scaled
+    imul rax, rax, 10
+    dec rcx
+    jmp .Lpf_scale
+.Lpf_scaled:
+    cvtsi2sd xmm2, rax
+    # Yuvarlama: x + 0.5 / ölçek
+    movsd xmm3, [rip + _rt_half]
+    divsd xmm3, xmm2
+    addsd xmm0, xmm3
+    cvttsd2si rax, xmm0             # Tam kısım
+    cvtsi2sd xmm1, rax
+    subsd xmm0, xmm1                # Ondalık kısım
+    mulsd xmm0, xmm2
+    cvttsd2si rcx, xmm0
+    mov [rsp + 8], rcx
+    mov ecx, 1
+    call _rt_putuint_w
+    test rbx, rbx
+    jz .Lpf_done
+    mov al, '.'
+    call _rt_putc
+    mov rax, [rsp + 8]
+    mov rcx, rbx
+    call _rt_putuint_w
+.Lpf_done:
+    add rsp, 24
+    ret
+
+/* _fmod(a: XMM0, b: XMM1) -> XMM0 */
+_fmod:
+    movapd xmm2, xmm0
+    divsd xmm0, xmm1
+    cvttsd2si rax, xmm0
+    cvtsi2sd xmm0, rax
+    mulsd xmm0, xmm1
+    subsd xmm2, xmm0
+    movapd xmm0, xmm2
+    ret
+
+/* _strlen(str: RDI) -> RAX */
+_strlen:
+    xor eax, eax
+.Lsl_loop:
+    cmp byte ptr [rdi + rax], 0
+    je .Lsl_done
+    inc rax
+    jmp .Lsl_loop
+.Lsl_done:
+    ret
+
+/* _input(prompt: RDI) -> RAX (satır sonu temizlenmiş yeni string) */
+_input:
+    push rbx
+    push r12
+    sub rsp, 8
+    test rdi, rdi
+    jz .Lin_no_prompt
+    xor eax, eax
+    call _print
+.Lin_no_prompt:
+    mov edi, 1024
+    call _alloc_str
+    mov rbx, rax
+    test rbx, rbx
+    jz .Lin_ret
+    xor r12d, r12d
+.Lin_loop:
+    # Satırı karakter karakter oku; sonraki input() çağrıları kendi satırlarını alır.
+    cmp r12, 1023
+    jae .Lin_done
+    xor edi, edi                    # stdin
+    lea rsi, [rbx + r12]
+    mov edx, 1
+    mov eax, SYS_READ
+    syscall
+    cmp rax, 1
+    jne .Lin_done
+    cmp byte ptr [rbx + r12], 10
+    je .Lin_done
+    inc r12
+    jmp .Lin_loop
+.Lin_done:
+    test r12, r12
+    jz .Lin_term
+    cmp byte ptr [rbx + r12 - 1], 13
+    jne .Lin_term
+    dec r12
+.Lin_term:
+    mov byte ptr [rbx + r12], 0
+    mov rax, rbx
+.Lin_ret:
+    add rsp, 8
+    pop r12
+    pop rbx
+    ret
+
+/* _alloc_str(size: RDI) -> RAX: mmap ile alınan bloklardan sıfırlanmış bellek */
+_alloc_str:
+    add rdi, 15
+    and rdi, -16
+    mov rax, [rip + _rt_heap_ptr]
+    mov rdx, [rip + _rt_heap_end]
+    lea rcx, [rax + rdi]
+    test rax, rax
+    jz .Lal_new
+    cmp rcx, rdx
+    jbe .Lal_ok
+.Lal_new:
+    # Yeni blok: max(boyut, 1 MB)
+    push rdi
+    mov rsi, 1048576
+    cmp rdi, rsi
+    cmova rsi, rdi
+    push rsi
+    xor edi, edi                    # addr = NULL
+    mov edx, 3                      # PROT_READ | PROT_WRITE
+    mov r10d, 0x22                  # MAP_PRIVATE | MAP_ANONYMOUS
+    mov r8, -1                      # fd
+    xor r9d, r9d                    # offset
+    mov eax, SYS_MMAP
+    syscall
+    pop rsi
+    pop rdi
+    cmp rax, -4096
+    ja .Lal_fail
+    lea rdx, [rax + rsi]
+    mov [rip + _rt_heap_end], rdx
+    lea rcx, [rax + rdi]
+.Lal_ok:
+    mov [rip + _rt_heap_ptr], rcx
+    ret
+.Lal_fail:
+    xor eax, eax
+    ret
+
+/* _exit_process(code: RDI) - süreci verilen çıkış koduyla sonlandırır */
+_exit_process:
+    mov eax, SYS_EXIT_GROUP
+    syscall
+    hlt
+
+/* -------------------------------------------------------------------------- */
+/* Dosya G/Ç: libs/file.n'deki Windows imzalarıyla uyumlu sarmalayıcılar.     */
+/* Handle olarak dosya tanımlayıcısı (fd) kullanılır; hata -1'dir.            */
+/* -------------------------------------------------------------------------- */
+
+/* _io_open(path: RDI, access: RSI, share: RDX, create: RCX) -> RAX (fd) */
+_io_open:
+    # Erişim: GENERIC_READ (0x80000000) / GENERIC_WRITE (0x40000000)
+    xor eax, eax                    # O_RDONLY
+    mov r8d, 0x40000000
+    test esi, r8d
+    jz .Lio_flags_create
+    mov eax, 1                      # O_WRONLY
+    mov r8d, 0x80000000
+    test esi, r8d
+    jz .Lio_flags_create
+    mov eax, 2                      # O_RDWR
+.Lio_flags_create:
+    # Oluşturma: 1 CREATE_NEW, 2 CREATE_ALWAYS, 3 OPEN_EXISTING, 4 OPEN_ALWAYS, 5 TRUNCATE_EXISTING
+    cmp ecx, 1
+    jne .Lio_c2
+    or eax, 0xC0                    # O_CREAT | O_EXCL
+    jmp .Lio_do_open
+.Lio_c2:
+    cmp ecx, 2
+    jne .Lio_c4
+    or eax, 0x240                   # O_CREAT | O_TRUNC
+    jmp .Lio_do_open
+.Lio_c4:
+    cmp ecx, 4
+    jne .Lio_c5
+    or eax, 0x40                    # O_CREAT
+    jmp .Lio_do_open
+.Lio_c5:
+    cmp ecx, 5
+    jne .Lio_do_open
+    or eax, 0x200                   # O_TRUNC
+.Lio_do_open:
+    mov esi, eax
+    mov edx, 0644
+    mov eax, SYS_OPEN
+    syscall
+    test rax, rax
+    jns .Lio_open_ret
+    mov rax, -1
+.Lio_open_ret:
+    ret
+
+/* _io_close(fd: RDI) -> RAX (başarılıysa 1) */
+_io_close:
+    mov eax, SYS_CLOSE
+    syscall
+    jmp _rt_bool_result
+
+/* _io_read(fd: RDI, buf: RSI, len: RDX, bytesReadPtr: RCX) -> RAX (başarılıysa 1) */
+_io_read:
+_io_read_console:
+    mov r8, rcx
+    mov eax, SYS_READ
+    syscall
+    jmp _rt_store_count
+
+/* _io_write(fd: RDI, buf: RSI, len: RDX, bytesWrittenPtr: RCX) -> RAX (başarılıysa 1) */
+_io_write:
+    mov r8, rcx
+    mov eax, SYS_WRITE
+    syscall
+    jmp _rt_store_count
+
+/* Okunan/yazılan byte sayısını R8'deki adrese yazar (NULL değilse) */
+_rt_store_count:
+    test rax, rax
+    js .Lsc_fail
+    test r8, r8
+    jz .Lsc_ok
+    mov [r8], rax
+.Lsc_ok:
+    mov eax, 1
+    ret
+.Lsc_fail:
+    test r8, r8
+    jz .Lsc_ret
+    mov qword ptr [r8], 0
+.Lsc_ret:
+    xor eax, eax
+    ret
+
+/* Syscall sonucunu (0 = başarı) bool'a çevirir */
+_rt_bool_result:
+    test rax, rax
+    sete al
+    movzx eax, al
+    ret
+
+/* _io_seek(fd: RDI, offset: RSI, origin: RDX) -> RAX (yeni konum, hata: -1) */
+_io_seek:
+    mov eax, SYS_LSEEK
+    syscall
+    test rax, rax
+    jns .Lsk_ret
+    mov rax, -1
+.Lsk_ret:
+    ret
+
+/* _io_size(fd: RDI, sizePtr: RSI) -> RAX (başarılıysa 1) */
+_io_size:
+    push rbx
+    push r12
+    push r13
+    mov rbx, rdi
+    mov r12, rsi
+    # Mevcut konumu sakla, sona git, eski konuma dön
+    xor esi, esi
+    mov edx, 1                      # SEEK_CUR
+    mov eax, SYS_LSEEK
+    syscall
+    test rax, rax
+    js .Lsz_fail
+    mov r13, rax
+    mov rdi, rbx
+    xor esi, esi
+    mov edx, 2                      # SEEK_END
+    mov eax, SYS_LSEEK
+    syscall
+    test rax, rax
+    js .Lsz_fail
+    test r12, r12
+    jz .Lsz_restore
+    mov [r12], rax
+.Lsz_restore:
+    mov rdi, rbx
+    mov rsi, r13
+    xor edx, edx                    # SEEK_SET
+    mov eax, SYS_LSEEK
+    syscall
+    mov eax, 1
+    jmp .Lsz_ret
+.Lsz_fail:
+    xor eax, eax
+.Lsz_ret:
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+/* _io_exists(path: RDI) -> RAX (bool) */
+_io_exists:
+    xor esi, esi                    # O_RDONLY
+    xor edx, edx
+    mov eax, SYS_OPEN
+    syscall
+    test rax, rax
+    js .Lex_no
+    mov rdi, rax
+    mov eax, SYS_CLOSE
+    syscall
+    mov eax, 1
+    ret
+.Lex_no:
+    xor eax, eax
+    ret
+
+/* _io_remove(path: RDI) -> RAX (bool) */
+_io_remove:
+    mov eax, SYS_UNLINK
+    syscall
+    jmp _rt_bool_result
+
+/* _io_copy(src: RDI, dest: RSI) -> RAX (bool) */
+_io_copy:
+    push rbx
+    push r12
+    push r13
+    sub rsp, 4096
+    mov r13, rsi
+    xor esi, esi                    # O_RDONLY
+    xor edx, edx
+    mov eax, SYS_OPEN
+    syscall
+    test rax, rax
+    js .Lcp_fail
+    mov rbx, rax                    # RBX = kaynak fd
+    mov rdi, r13
+    mov esi, 0x241                  # O_WRONLY | O_CREAT | O_TRUNC
+    mov edx, 0644
+    mov eax, SYS_OPEN
+    syscall
+    test rax, rax
+    js .Lcp_close_src
+    mov r12, rax                    # R12 = hedef fd
+.Lcp_loop:
+    mov rdi, rbx
+    mov rsi, rsp
+    mov edx, 4096
+    mov eax, SYS_READ
+    syscall
+    test rax, rax
+    jle .Lcp_done
+    mov rdi, r12
+    mov rsi, rsp
+    mov rdx, rax
+    mov eax, SYS_WRITE
+    syscall
+    test rax, rax
+    jns .Lcp_loop
+.Lcp_done:
+    mov rdi, r12
+    mov eax, SYS_CLOSE
+    syscall
+    mov rdi, rbx
+    mov eax, SYS_CLOSE
+    syscall
+    mov eax, 1
+    jmp .Lcp_ret
+.Lcp_close_src:
+    mov rdi, rbx
+    mov eax, SYS_CLOSE
+    syscall
+.Lcp_fail:
+    xor eax, eax
+.Lcp_ret:
+    add rsp, 4096
+    pop r13
+    pop r12
+    pop rbx
+    ret
+
+/* _io_flush(fd: RDI) -> RAX (bool) */
+_io_flush:
+    mov eax, SYS_FSYNC
+    syscall
+    jmp _rt_bool_result
+
+/* _io_get_std(type: RDI) -> RAX: -10 stdin, -11 stdout, -12 stderr (Windows uyumlu) */
+_io_get_std:
+    mov rax, rdi
+    neg rax
+    sub rax, 10
+    ret
+
+.section .rodata
+.Lnull_str: .asciz "(null)"
+
+.section .note.GNU-stack,"",@progbits
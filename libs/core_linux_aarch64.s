@@ -0,0 +1,805 @@
+/*
+   Linux AArch64 çalışma zamanı (runtime) kütüphanesi.
+   core_linux.s'in AArch64 karşılığıdır; libc kullanmaz, tüm işler svc ile yapılır.
+   Çağrı kuralı: AAPCS64 (X0-X7 / D0-D7). Variadic argümanlar da aynı register'larda gelir.
+
+   Derleyici --target linux --arch aarch64 seçildiğinde bu dosyayı otomatik olarak derler ve bağlar:
+   aarch64-linux-gnu-gcc -x assembler -c core_linux_aarch64.s -o core_linux_aarch64.o
+*/
+
+.section .data
+.balign 8
+    _rt_heap_ptr:   .quad 0
+    _rt_heap_end:   .quad 0
+
+.section .bss
+    .lcomm _rt_outbuf, 4096
+
+.section .text
+.global _print
+.global _sprint
+.global _strlen
+.global _input
+.global _fmod
+.global _alloc_str
+.global _exit_process
+.global _io_open
+.global _io_close
+.global _io_read
+.global _io_write
+.global _io_seek
+.global _io_size
+.global _io_exists
+.global _io_remove
+.global _io_copy
+.global _io_flush
+.global _io_get_std
+.global _io_read_console
+
+/* Syscall numaraları (asm-generic) */
+.set SYS_UNLINKAT,   35
+.set SYS_OPENAT,     56
+.set SYS_CLOSE,      57
+.set SYS_LSEEK,      62
+.set SYS_READ,       63
+.set SYS_WRITE,      64
+.set SYS_FSYNC,      82
+.set SYS_EXIT_GROUP, 94
+.set SYS_MMAP,       222
+.set AT_FDCWD,       -100
+
+/* -------------------------------------------------------------------------- */
+/* Variadic argüman alanı (X21 ile gösterilir)                                */
+/*   [x21 + 0..63]   : X0 - X7                                                */
+/*   [x21 + 64..127] : D0 - D7                                                */
+/*   [x21 + 128]     : sıradaki tamsayı argümanın ofseti                      */
+/*   [x21 + 136]     : sıradaki float argümanın ofseti                        */
+/*   [x21 + 144]     : stack'teki (overflow) argümanların adresi              */
+/*                                                                            */
+/* Formatlayıcı register'ları: X19 = format, X20 = mod, X22 = çıktı imleci,   */
+/* X23 = hassasiyet. Yardımcılar yalnızca X0-X1, X9-X15 ve D0-D3'ü bozar.     */
+/* -------------------------------------------------------------------------- */
+
+/* _print(format: X0, ...) - formatlı çıktıyı stdout'a yazar */
+_print:
+    stp x29, x30, [sp, #-240]!
+    mov x29, sp
+    stp x19, x20, [sp, #16]
+    stp x21, x22, [sp, #32]
+    stp x23, x24, [sp, #48]
+
+    add x21, sp, #64
+    bl _rt_save_args
+    mov x9, #8                      // Format string ilk tamsayı argümanı
+    str x9, [x21, #128]
+    mov x19, x0                     // X19 = Format
+    mov x20, #0                     // X20 = 0: stdout modu
+    adrp x22, _rt_outbuf            // X22 = Çıktı imleci
+    add x22, x22, :lo12:_rt_outbuf
+    bl _rt_format
+    bl _rt_flush
+
+    ldp x23, x24, [sp, #48]
+    ldp x21, x22, [sp, #32]
+    ldp x19, x20, [sp, #16]
+    ldp x29, x30, [sp], #240
+    ret
+
+/* _sprint(buffer: X0, format: X1, ...) -> X0 (yazılan uzunluk) */
+_sprint:
+    stp x29, x30, [sp, #-240]!
+    mov x29, sp
+    stp x19, x20, [sp, #16]
+    stp x21, x22, [sp, #32]
+    stp x23, x24, [sp, #48]
+
+    add x21, sp, #64
+    bl _rt_save_args
+    mov x9, #16                     // Buffer ve format ilk iki argüman
+    str x9, [x21, #128]
+    mov x19, x1                     // X19 = Format
+    mov x20, #1                     // X20 = 1: buffer modu
+    mov x22, x0                     // X22 = Hedef buffer
+    mov x24, x0
+    bl _rt_format
+    strb wzr, [x22]
+    sub x0, x22, x24
+
+    ldp x23, x24, [sp, #48]
+    ldp x21, x22, [sp, #32]
+    ldp x19, x20, [sp, #16]
+    ldp x29, x30, [sp], #240
+    ret
+
+/* Argüman register'larını X21'deki alana kaydeder (çağıranın X29'u ile) */
+_rt_save_args:
+    stp x0, x1, [x21]
+    stp x2, x3, [x21, #16]
+    stp x4, x5, [x21, #32]
+    stp x6, x7, [x21, #48]
+    stp d0, d1, [x21, #64]
+    stp d2, d3, [x21, #80]
+    stp d4, d5, [x21, #96]
+    stp d6, d7, [x21, #112]
+    str xzr, [x21, #136]
+    add x9, x29, #240               // İlk stack argümanı (çağıranın giriş SP'si)
+    str x9, [x21, #144]
+    ret
+
+/* Sıradaki tamsayı/pointer argümanı -> X0 */
+_rt_va_gp:
+    ldr x9, [x21, #128]
+    cmp x9, #64
+    b.hs .Lva_gp_stack
+    ldr x0, [x21, x9]
+    add x9, x9, #8
+    str x9, [x21, #128]
+    ret
+.Lva_gp_stack:
+    ldr x9, [x21, #144]
+    ldr x0, [x9], #8
+    str x9, [x21, #144]
+    ret
+
+/* Sıradaki float argümanı -> D0 */
+_rt_va_fp:
+    ldr x9, [x21, #136]
+    cmp x9, #64
+    b.hs .Lva_fp_stack
+    add x10, x21, #64
+    ldr d0, [x10, x9]
+    add x9, x9, #8
+    str x9, [x21, #136]
+    ret
+.Lva_fp_stack:
+    ldr x9, [x21, #144]
+    ldr d0, [x9], #8
+    str x9, [x21, #144]
+    ret
+
+/* -------------------------------------------------------------------------- */
+/* _rt_format: X19 = format, X22 = çıktı imleci, X20 = mod, X21 = argümanlar  */
+/* Desteklenen belirteçler: %d %u %s %c %f %.Nf %D %U %%                      */
+/* %D / %U: 128 bitlik tamsayı, iki argüman olarak (önce alt, sonra üst yarı)  */
+/* -------------------------------------------------------------------------- */
+_rt_format:
+    stp x29, x30, [sp, #-16]!
+    mov x29, sp
+.Lf_loop:
+    ldrb w0, [x19]
+    cbz w0, .Lf_done
+    cmp w0, #'%'
+    b.ne .Lf_literal
+
+    add x19, x19, #1
+    ldrb w0, [x19]
+    mov x23, #6                     // Varsayılan hassasiyet
+    cmp w0, #'.'
+    b.ne .Lf_spec
+    add x19, x19, #1
+    mov x23, #0
+.Lf_prec:
+    ldrb w0, [x19]
+    sub w9, w0, #'0'
+    cmp w9, #9
+    b.hi .Lf_prec_done
+    mov x10, #10
+    madd x23, x23, x10, x9
+    add x19, x19, #1
+    b .Lf_prec
+.Lf_prec_done:
+    cmp x23, #17
+    b.ls .Lf_spec
+    mov x23, #17
+
+.Lf_spec:
+    cbz w0, .Lf_done
+    cmp w0, #'d'
+    b.eq .Lf_int
+    cmp w0, #'u'
+    b.eq .Lf_uint
+    cmp w0, #'D'
+    b.eq .Lf_wide
+    cmp w0, #'U'
+    b.eq .Lf_uwide
+    cmp w0, #'s'
+    b.eq .Lf_str
+    cmp w0, #'c'
+    b.eq .Lf_char
+    cmp w0, #'f'
+    b.eq .Lf_float
+    cmp w0, #'%'
+    b.eq .Lf_literal
+    // Bilinmeyen belirteç: olduğu gibi yaz
+    mov w0, #'%'
+    bl _rt_putc
+    ldrb w0, [x19]
+    b .Lf_literal
+
+.Lf_int:
+    bl _rt_va_gp
+    bl _rt_putint
+    add x19, x19, #1
+    b .Lf_loop
+
+.Lf_uint:
+    bl _rt_va_gp
+    mov x1, #1
+    bl _rt_putuint_w
+    add x19, x19, #1
+    b .Lf_loop
+
+.Lf_wide:
+    bl _rt_va_gp
+    mov x15, x0
+    bl _rt_va_gp
+    mov x1, x0
+    mov x0, x15
+    bl _rt_putwide
+    add x19, x19, #1
+    b .Lf_loop
+
+.Lf_uwide:
+    bl _rt_va_gp
+    mov x15, x0
+    bl _rt_va_gp
+    mov x1, x0
+    mov x0, x15
+    bl _rt_putuwide
+    add x19, x19, #1
+    b .Lf_loop
+
+.Lf_str:
+    bl _rt_va_gp
+    bl _rt_putstr
+    add x19, x19, #1
+    b .Lf_loop
+
+.Lf_char:
+    bl _rt_va_gp
+    bl _rt_putc
+    add x19, x19, #1
+    b .Lf_loop
+
+.Lf_float:
+    bl _rt_va_fp
+    bl _rt_putfloat
+    add x19, x19, #1
+    b .Lf_loop
+
+.Lf_literal:
+    bl _rt_putc
+    add x19, x19, #1
+    b .Lf_loop
+
+.Lf_done:
+    ldp x29, x30, [sp], #16
+    ret
+
+/* W0'daki karakteri çıktıya ekler; stdout modunda buffer dolunca boşaltır (X9'u bozar) */
+_rt_putc:
+    strb w0, [x22], #1
+    cbnz x20, .Lpc_ret
+    adrp x9, _rt_outbuf
+    add x9, x9, :lo12:_rt_outbuf
+    add x9, x9, #4096
+    cmp x22, x9
+    b.hs _rt_flush
+.Lpc_ret:
+    ret
+
+/* stdout buffer'ını write(1, ...) ile boşaltır. Register'ları korur. */
+_rt_flush:
+    cbnz x20, .Lfl_ret
+    stp x0, x1, [sp, #-32]!
+    stp x2, x8, [sp, #16]
+    adrp x1, _rt_outbuf
+    add x1, x1, :lo12:_rt_outbuf
+    subs x2, x22, x1
+    b.eq .Lfl_restore
+    mov x0, #1
+    mov x8, #SYS_WRITE
+    svc #0
+    adrp x22, _rt_outbuf
+    add x22, x22, :lo12:_rt_outbuf
+.Lfl_restore:
+    ldp x2, x8, [sp, #16]
+    ldp x0, x1, [sp], #32
+.Lfl_ret:
+    ret
+
+/* X0'daki null-terminated string'i yazar */
+_rt_putstr:
+    stp x29, x30, [sp, #-16]!
+    mov x29, sp
+    mov x11, x0
+    cbnz x11, .Lps_loop
+    adrp x11, .Lnull_str
+    add x11, x11, :lo12:.Lnull_str
+.Lps_loop:
+    ldrb w0, [x11], #1
+    cbz w0, .Lps_done
+    bl _rt_putc
+    b .Lps_loop
+.Lps_done:
+    ldp x29, x30, [sp], #16
+    ret
+
+/* X0'daki işaretli tamsayıyı yazar */
+_rt_putint:
+    mov x1, #1
+    tbz x0, #63, _rt_putuint_w
+    stp x29, x30, [sp, #-16]!
+    mov x29, sp
+    mov x12, x0
+    mov w0, #'-'
+    bl _rt_putc
+    neg x0, x12
+    mov x1, #1
+    ldp x29, x30, [sp], #16
+    b _rt_putuint_w
+
+/* X0'daki işaretsiz tamsayıyı en az X1 basamakla (sıfır dolgulu) yazar */
+_rt_putuint_w:
+    stp x29, x30, [sp, #-48]!
+    mov x29, sp
+    add x12, sp, #48                // Basamak alanının sonu
+    mov x13, x12
+    mov x14, #10
+.Lpu_div:
+    udiv x15, x0, x14
+    msub x9, x15, x14, x0
+    add w9, w9, #'0'
+    strb w9, [x13, #-1]!
+    sub x1, x1, #1
+    mov x0, x15
+    cbnz x0, .Lpu_div
+.Lpu_pad:
+    cmp x1, #0
+    b.le .Lpu_out
+    mov w9, #'0'
+    strb w9, [x13, #-1]!
+    sub x1, x1, #1
+    b .Lpu_pad
+.Lpu_out:
+    cmp x13, x12
+    b.hs .Lpu_done
+    ldrb w0, [x13], #1
+    bl _rt_putc
+    b .Lpu_out
+.Lpu_done:
+    ldp x29, x30, [sp], #48
+    ret
+
+/* X1:X0'daki işaretli 128 bitlik tamsayıyı yazar */
+_rt_putwide:
+    cmp x1, #0
+    b.ge _rt_putuwide
+    stp x29, x30, [sp, #-32]!
+    mov x29, sp
+    stp x0, x1, [sp, #16]
+    mov w0, #'-'
+    bl _rt_putc
+    ldp x0, x1, [sp, #16]
+    negs x0, x0
+    ngc x1, x1
+    ldp x29, x30, [sp], #32
+    b _rt_putuwide
+
+/* X1:X0'daki işaretsiz 128 bitlik tamsayıyı yazar (39 basamağa kadar) */
+_rt_putuwide:
+    stp x29, x30, [sp, #-64]!
+    mov x29, sp
+    add x12, sp, #64                // Basamak alanının sonu
+    mov x13, x12
+    mov x14, #10
+.Lpw_div:
+    // Önce üst yarı, sonra kalanla birlikte alt yarının 32'şer bitlik parçaları 10'a bölünür.
+    udiv x15, x1, x14
+    msub x9, x15, x14, x1
+    mov x1, x15
+    lsr x10, x0, #32
+    orr x10, x10, x9, lsl #32
+    udiv x15, x10, x14
+    msub x9, x15, x14, x10
+    lsl x11, x15, #32
+    mov w10, w0
+    orr x10, x10, x9, lsl #32
+    udiv x15, x10, x14
+    msub x9, x15, x14, x10
+    orr x0, x11, x15
+    add w9, w9, #'0'
+    strb w9, [x13, #-1]!
+    orr x9, x0, x1
+    cbnz x9, .Lpw_div
+.Lpw_out:
+    cmp x13, x12
+    b.hs .Lpw_done
+    ldrb w0, [x13], #1
+    bl _rt_putc
+    b .Lpw_out
+.Lpw_done:
+    ldp x29, x30, [sp], #64
+    ret
+
+/* D0'daki double'ı X23 basamak hassasiyetle yazar */
+_rt_putfloat:
+    stp x29, x30, [sp, #-32]!
+    mov x29, sp
+    fcmp d0, #0.0
+    b.ge .Lpf_pos
+    str d0, [sp, #16]
+    mov w0, #'-'
+    bl _rt_putc
+    ldr d0, [sp, #16]
+    fneg d0, d0
+.Lpf_pos:
+    // Ölçek = 10^hassasiyet
+    mov x9, x23
+    mov x10, #1
+    mov x11, #10
+.Lpf_scale:
+    cbz x9, .Lpf_scaled
+    mul x10, x10, x11
+    sub x9, x9, #1
+    b .Lpf_scale
+.Lpf_scaled:
+    scvtf d2, x10
+    // Yuvarlama: x + 0.5 / ölçek
+    fmov d3, #0.5
+    fdiv d3, d3, d2
+    fadd d0, d0, d3
+    fcvtzs x0, d0                   // Tam kısım
+    scvtf d1, x0
+    fsub d0, d0, d1                 // Ondalık kısım
+    fmul d0, d0, d2
+    fcvtzs x9, d0
+    str x9, [sp, #24]
+    mov x1, #1
+    bl _rt_putuint_w
+    cbz x23, .Lpf_done
+    mov w0, #'.'
+    bl _rt_putc
+    ldr x0, [sp, #24]
+    mov x1, x23
+    bl _rt_putuint_w
+.Lpf_done:
+    ldp x29, x30, [sp], #32
+    ret
+
+/* _fmod(a: D0, b: D1) -> D0 */
+_fmod:
+    fdiv d2, d0, d1
+    fcvtzs x9, d2
+    scvtf d2, x9
+    fmul d2, d2, d1
+    fsub d0, d0, d2
+    ret
+
+/* _strlen(str: X0) -> X0 */
+_strlen:
+    mov x9, x0
+.Lsl_loop:
+    ldrb w10, [x9], #1
+    cbnz w10, .Lsl_loop
+    sub x0, x9, x0
+    sub x0, x0, #1
+    ret
+
+/* _input(prompt: X0) -> X0 (satır sonu temizlenmiş yeni string) */
+_input:
+    stp x29, x30, [sp, #-32]!
+    mov x29, sp
+    stp x19, x20, [sp, #16]
+    cbz x0, .Lin_no_prompt
+    bl _print
+.Lin_no_prompt:
+    mov x0, #1024
+    bl _alloc_str
+    mov x19, x0
+    cbz x19, .Lin_ret
+    mov x20, #0
+.Lin_loop:
+    // Satırı karakter karakter oku; sonraki input() çağrıları kendi satırlarını alır.
+    cmp x20, #1023
+    b.hs .Lin_done
+    mov x0, #0                      // stdin
+    add x1, x19, x20
+    mov x2, #1
+    mov x8, #SYS_READ
+    svc #0
+    cmp x0, #1
+    b.ne .Lin_done
+    ldrb w9, [x19, x20]
+    cmp w9, #10
+    b.eq .Lin_done
+    add x20, x20, #1
+    b .Lin_loop
+.Lin_done:
+    cbz x20, .Lin_term
+    sub x9, x20, #1
+    ldrb w10, [x19, x9]
+    cmp w10, #13
+    b.ne .Lin_term
+    mov x20, x9
+.Lin_term:
+    strb wzr, [x19, x20]
+    mov x0, x19
+.Lin_ret:
+    ldp x19, x20, [sp, #16]
+    ldp x29, x30, [sp], #32
+    ret
+
+/* _alloc_str(size: X0) -> X0: mmap ile alınan bloklardan sıfırlanmış bellek */
+_alloc_str:
+    add x0, x0, #15
+    and x0, x0, #-16
+    adrp x9, _rt_heap_ptr
+    add x9, x9, :lo12:_rt_heap_ptr  // [x9] = heap_ptr, [x9 + 8] = heap_end
+    ldp x10, x11, [x9]
+    add x12, x10, x0
+    cbz x10, .Lal_new
+    cmp x12, x11
+    b.ls .Lal_ok
+.Lal_new:
+    // Yeni blok: max(boyut, 1 MB). svc X0 dışındaki register'ları korur.
+    mov x13, x0
+    mov x14, #0x100000
+    cmp x13, x14
+    csel x14, x13, x14, hi
+    mov x0, #0                      // addr = NULL
+    mov x1, x14
+    mov x2, #3                      // PROT_READ | PROT_WRITE
+    mov x3, #0x22                   // MAP_PRIVATE | MAP_ANONYMOUS
+    mov x4, #-1                     // fd
+    mov x5, #0                      // offset
+    mov x8, #SYS_MMAP
+    svc #0
+    cmn x0, #4096
+    b.hi .Lal_fail
+    add x11, x0, x14
+    str x11, [x9, #8]
+    mov x10, x0
+    add x12, x0, x13
+.Lal_ok:
+    str x12, [x9]
+    mov x0, x10
+    ret
+.Lal_fail:
+    mov x0, #0
+    ret
+
+/* _exit_process(code: X0) - süreci verilen çıkış koduyla sonlandırır */
+_exit_process:
+    mov x8, #SYS_EXIT_GROUP
+    svc #0
+    brk #0
+
+/* -------------------------------------------------------------------------- */
+/* Dosya G/Ç: libs/file.n'deki Windows imzalarıyla uyumlu sarmalayıcılar.     */
+/* Handle olarak dosya tanımlayıcısı (fd) kullanılır; hata -1'dir.            */
+/* AArch64'te open/unlink yoktur; openat/unlinkat AT_FDCWD ile kullanılır.    */
+/* -------------------------------------------------------------------------- */
+
+/* _io_open(path: X0, access: X1, share: X2, create: X3) -> X0 (fd) */
+_io_open:
+    // Erişim: GENERIC_READ (0x80000000) / GENERIC_WRITE (0x40000000)
+    mov x9, #0                      // O_RDONLY
+    tst w1, #0x40000000
+    b.eq .Lio_flags_create
+    mov x9, #1                      // O_WRONLY
+    tst w1, #0x80000000
+    b.eq .Lio_flags_create
+    mov x9, #2                      // O_RDWR
+.Lio_flags_create:
+    // Oluşturma: 1 CREATE_NEW, 2 CREATE_ALWAYS, 3 OPEN_EXISTING, 4 OPEN_ALWAYS, 5 TRUNCATE_EXISTING
+    cmp w3, #1
+    b.ne .Lio_c2
+    orr x9, x9, #0xC0               // O_CREAT | O_EXCL
+    b .Lio_do_open
+.Lio_c2:
+    cmp w3, #2
+    b.ne .Lio_c4
+    orr x9, x9, #0x40               // O_CREAT | O_TRUNC
+    orr x9, x9, #0x200
+    b .Lio_do_open
+.Lio_c4:
+    cmp w3, #4
+    b.ne .Lio_c5
+    orr x9, x9, #0x40               // O_CREAT
+    b .Lio_do_open
+.Lio_c5:
+    cmp w3, #5
+    b.ne .Lio_do_open
+    orr x9, x9, #0x200              // O_TRUNC
+.Lio_do_open:
+    mov x1, x0
+    mov x0, #AT_FDCWD
+    mov x2, x9
+    mov x3, #420                    // 0644
+    mov x8, #SYS_OPENAT
+    svc #0
+    tbz x0, #63, .Lio_open_ret
+    mov x0, #-1
+.Lio_open_ret:
+    ret
+
+/* _io_close(fd: X0) -> X0 (başarılıysa 1) */
+_io_close:
+    mov x8, #SYS_CLOSE
+    svc #0
+    b _rt_bool_result
+
+/* _io_read(fd: X0, buf: X1, len: X2, bytesReadPtr: X3) -> X0 (başarılıysa 1) */
+_io_read:
+_io_read_console:
+    mov x8, #SYS_READ
+    svc #0
+    b _rt_store_count
+
+/* _io_write(fd: X0, buf: X1, len: X2, bytesWrittenPtr: X3) -> X0 (başarılıysa 1) */
+_io_write:
+    mov x8, #SYS_WRITE
+    svc #0
+    b _rt_store_count
+
+/* Okunan/yazılan byte sayısını X3'teki adrese yazar (NULL değilse) */
+_rt_store_count:
+    tbnz x0, #63, .Lsc_fail
+    cbz x3, .Lsc_ok
+    str x0, [x3]
+.Lsc_ok:
+    mov x0, #1
+    ret
+.Lsc_fail:
+    cbz x3, .Lsc_ret
+    str xzr, [x3]
+.Lsc_ret:
+    mov x0, #0
+    ret
+
+/* Syscall sonucunu (0 = başarı) bool'a çevirir */
+_rt_bool_result:
+    cmp x0, #0
+    cset x0, eq
+    ret
+
+/* _io_seek(fd: X0, offset: X1, origin: X2) -> X0 (yeni konum, hata: -1) */
+_io_seek:
+    mov x8, #SYS_LSEEK
+    svc #0
+    tbz x0, #63, .Lsk_ret
+    mov x0, #-1
+.Lsk_ret:
+    ret
+
+/* _io_size(fd: X0, sizePtr: X1) -> X0 (başarılıysa 1) */
+_io_size:
+    mov x9, x0
+    mov x10, x1
+    // Mevcut konumu sakla, sona git, eski konuma dön
+    mov x1, #0
+    mov x2, #1                      // SEEK_CUR
+    mov x8, #SYS_LSEEK
+    svc #0
+    tbnz x0, #63, .Lsz_fail
+    mov x11, x0
+    mov x0, x9
+    mov x1, #0
+    mov x2, #2                      // SEEK_END
+    mov x8, #SYS_LSEEK
+    svc #0
+    tbnz x0, #63, .Lsz_fail
+    cbz x10, .Lsz_restore
+    str x0, [x10]
+.Lsz_restore:
+    mov x0, x9
+    mov x1, x11
+    mov x2, #0                      // SEEK_SET
+    mov x8, #SYS_LSEEK
+    svc #0
+    mov x0, #1
+    ret
+.Lsz_fail:
+    mov x0, #0
+    ret
+
+/* _io_exists(path: X0) -> X0 (bool) */
+_io_exists:
+    mov x1, x0
+    mov x0, #AT_FDCWD
+    mov x2, #0                      // O_RDONLY
+    mov x3, #0
+    mov x8, #SYS_OPENAT
+    svc #0
+    tbnz x0, #63, .Lex_no
+    mov x8, #SYS_CLOSE
+    svc #0
+    mov x0, #1
+    ret
+.Lex_no:
+    mov x0, #0
+    ret
+
+/* _io_remove(path: X0) -> X0 (bool) */
+_io_remove:
+    mov x1, x0
+    mov x0, #AT_FDCWD
+    mov x2, #0
+    mov x8, #SYS_UNLINKAT
+    svc #0
+    b _rt_bool_result
+
+/* _io_copy(src: X0, dest: X1) -> X0 (bool) */
+_io_copy:
+    stp x19, x20, [sp, #-32]!
+    str x21, [sp, #16]
+    sub sp, sp, #4096
+    mov x21, x1
+    mov x1, x0
+    mov x0, #AT_FDCWD
+    mov x2, #0                      // O_RDONLY
+    mov x3, #0
+    mov x8, #SYS_OPENAT
+    svc #0
+    tbnz x0, #63, .Lcp_fail
+    mov x19, x0                     // X19 = kaynak fd
+    mov x0, #AT_FDCWD
+    mov x1, x21
+    mov x2, #0x241                  // O_WRONLY | O_CREAT | O_TRUNC
+    mov x3, #420                    // 0644
+    mov x8, #SYS_OPENAT
+    svc #0
+    tbnz x0, #63, .Lcp_close_src
+    mov x20, x0                     // X20 = hedef fd
+.Lcp_loop:
+    mov x0, x19
+    mov x1, sp
+    mov x2, #4096
+    mov x8, #SYS_READ
+    svc #0
+    cmp x0, #0
+    b.le .Lcp_done
+    mov x2, x0
+    mov x0, x20
+    mov x1, sp
+    mov x8, #SYS_WRITE
+    svc #0
+    tbz x0, #63, .Lcp_loop
+.Lcp_done:
+    mov x0, x20
+    mov x8, #SYS_CLOSE
+    svc #0
+    mov x0, x19
+    mov x8, #SYS_CLOSE
+    svc #0
+    mov x0, #1
+    b .Lcp_ret
+.Lcp_close_src:
+    mov x0, x19
+    mov x8, #SYS_CLOSE
+    svc #0
+.Lcp_fail:
+    mov x0, #0
+.Lcp_ret:
+    add sp, sp, #4096
+    ldr x21, [sp, #16]
+    ldp x19, x20, [sp], #32
+    ret
+
+/* _io_flush(fd: X0) -> X0 (bool) */
+_io_flush:
+    mov x8, #SYS_FSYNC
+    svc #0
+    b _rt_bool_result
+
+/* _io_get_std(type: X0) -> X0: -10 stdin, -11 stdout, -12 stderr (Windows uyumlu) */
+_io_get_std:
+    neg x0, x0
+    sub x0, x0, #10
+    ret
+
+.section .rodata
+.Lnull_str: .asciz "(null)"
+
+.section .note.GNU-stack,"",@progbits
@@ -0,0 +1,1308 @@
+;; WebAssembly (WASI preview1) çalışma zamanı (runtime) kütüphanesi.
+;; core_linux_aarch64.s'in WASI karşılığıdır; tüm G/Ç wasi_snapshot_preview1 içe aktarımlarıyla yapılır.
+;;
+;; Bu dosya tek başına bir modül değildir: --target wasm32-wasi seçildiğinde kod üretici bu alanları
+;; programın `(module ...)` metnine (dış içe aktarımlardan sonra, program fonksiyonlarından önce) ekler.
+;; Belleği (`memory`), `$__heap_base` global'ini, string literallerini ve `_start`'ı kod üretici tanımlar.
+;;
+;; Çağrı kuralı: derlenen koddan çağrılan rutinlerin tüm tamsayı ve pointer argümanları i64, float'lar
+;; f64'tür (IR değer tipleri). `$_rt_` önekli yardımcılar yalnızca runtime içinden çağrılır ve i32 kullanır.
+;;
+;; Bellek haritası (src/codegen/wasm.rs ile aynı olmalıdır):
+;;   0x000400 - 0x0fffff : gölge stack (`$__stack_pointer` 0x100000'dan aşağı büyür)
+;;   0x100000 - 0x100fff : stdout buffer'ı
+;;   0x101000 - 0x101fff : _io_copy buffer'ı
+;;   0x102000 - 0x1023ff : _itoa/_ftoa dönüşüm buffer'ı
+;;   0x102400 / 0x102408 : _nim_argc / _nim_argv
+;;   0x102410            : iovec (buf: u32, len: u32)
+;;   0x102418            : WASI çağrılarının sonuç alanı (byte sayısı, fd, yeni konum)
+;;   0x102440 - 0x10247f : filestat
+;;   0x102480            : "(null)"
+;;   0x102490 - 0x1024af : sayı basamakları
+;;   0x1024b0 - 0x1024df : 128 bitlik sayı basamakları
+;;   0x103000 -          : programın string literalleri, ardından heap (`$__heap_base`)
+
+(import "wasi_snapshot_preview1" "fd_write" (func $fd_write (param i32 i32 i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "fd_read" (func $fd_read (param i32 i32 i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "fd_close" (func $fd_close (param i32) (result i32)))
+(import "wasi_snapshot_preview1" "fd_seek" (func $fd_seek (param i32 i64 i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "fd_sync" (func $fd_sync (param i32) (result i32)))
+(import "wasi_snapshot_preview1" "fd_filestat_get" (func $fd_filestat_get (param i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "path_open" (func $path_open (param i32 i32 i32 i32 i32 i64 i64 i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "path_filestat_get" (func $path_filestat_get (param i32 i32 i32 i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "path_unlink_file" (func $path_unlink_file (param i32 i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "args_sizes_get" (func $args_sizes_get (param i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "args_get" (func $args_get (param i32 i32) (result i32)))
+(import "wasi_snapshot_preview1" "proc_exit" (func $proc_exit (param i32)))
+
+(global $__stack_pointer (mut i32) (i32.const 0x100000))
+(global $_rt_heap_ptr (mut i32) (i32.const 0))
+;; Formatlayıcı durumu: çıktı imleci, mod (0 = stdout, 1 = buffer) ve sıradaki variadic argüman
+(global $_rt_out (mut i32) (i32.const 0x100000))
+(global $_rt_mode (mut i32) (i32.const 0))
+(global $_rt_va (mut i32) (i32.const 0))
+;; Dosya yollarının çözüldüğü dizin: ilk önceden açılmış dizin (örn: wasmtime --dir=.)
+(global $_rt_dirfd i32 (i32.const 3))
+
+(data (i32.const 0x102480) "(null)\00")
+
+;; ---------------------------------------------------------------------------
+;; Biçimlendirme: _print, _sprint ve yardımcıları
+;; Desteklenen belirteçler: %d %u %s %c %f %.Nf %D %U %%
+;; %D / %U: 128 bitlik tamsayı, iki yuva olarak (önce alt, sonra üst yarı)
+;; Variadic argümanlar çağıranın gölge stack'inde 8'er byte'lık yuvalardadır.
+;; ---------------------------------------------------------------------------
+
+;; _print(format, args) - formatlı çıktıyı stdout'a yazar
+(func $_print (param $fmt i64) (param $args i64)
+    local.get $args
+    i32.wrap_i64
+    global.set $_rt_va
+    i32.const 0
+    global.set $_rt_mode
+    i32.const 0x100000
+    global.set $_rt_out
+    local.get $fmt
+    i32.wrap_i64
+    call $_rt_format
+    call $_rt_flush
+)
+
+;; _sprint(buffer, format, args) -> yazılan uzunluk
+(func $_sprint (param $buf i64) (param $fmt i64) (param $args i64) (result i64)
+    local.get $args
+    i32.wrap_i64
+    global.set $_rt_va
+    i32.const 1
+    global.set $_rt_mode
+    local.get $buf
+    i32.wrap_i64
+    global.set $_rt_out
+    local.get $fmt
+    i32.wrap_i64
+    call $_rt_format
+    global.get $_rt_out
+    i32.const 0
+    i32.store8
+    global.get $_rt_out
+    i64.extend_i32_u
+    local.get $buf
+    i64.sub
+)
+
+;; Sıradaki variadic argümanın adresi
+(func $_rt_va_next (result i32)
+    global.get $_rt_va
+    global.get $_rt_va
+    i32.const 8
+    i32.add
+    global.set $_rt_va
+)
+
+(func $_rt_format (param $f i32) (local $c i32) (local $prec i32)
+    block $done
+      loop $next
+        local.get $f
+        i32.load8_u
+        local.tee $c
+        i32.eqz
+        br_if $done
+        block $literal
+          local.get $c
+          i32.const 37 ;; '%'
+          i32.ne
+          br_if $literal
+          local.get $f
+          i32.const 1
+          i32.add
+          local.tee $f
+          i32.load8_u
+          local.set $c
+          i32.const 6 ;; Varsayılan hassasiyet
+          local.set $prec
+          local.get $c
+          i32.const 46 ;; '.'
+          i32.eq
+          if
+            i32.const 0
+            local.set $prec
+            block $prec_done
+              loop $prec_digit
+                local.get $f
+                i32.const 1
+                i32.add
+                local.tee $f
+                i32.load8_u
+                local.tee $c
+                i32.const 48
+                i32.sub
+                i32.const 9
+                i32.gt_u
+                br_if $prec_done
+                local.get $prec
+                i32.const 10
+                i32.mul
+                local.get $c
+                i32.const 48
+                i32.sub
+                i32.add
+                local.set $prec
+                br $prec_digit
+              end
+            end
+            local.get $prec
+            i32.const 17
+            i32.gt_u
+            if
+              i32.const 17
+              local.set $prec
+            end
+          end
+          local.get $c
+          i32.eqz
+          br_if $done
+          block $spec_done
+            local.get $c
+            i32.const 100 ;; 'd'
+            i32.eq
+            if
+              call $_rt_va_next
+              i64.load
+              call $_rt_putint
+              br $spec_done
+            end
+            local.get $c
+            i32.const 117 ;; 'u'
+            i32.eq
+            if
+              call $_rt_va_next
+              i64.load
+              i32.const 1
+              call $_rt_putuint_w
+              br $spec_done
+            end
+            local.get $c
+            i32.const 68 ;; 'D'
+            i32.eq
+            if
+              call $_rt_va_next
+              i64.load
+              call $_rt_va_next
+              i64.load
+              call $_rt_putwide
+              br $spec_done
+            end
+            local.get $c
+            i32.const 85 ;; 'U'
+            i32.eq
+            if
+              call $_rt_va_next
+              i64.load
+              call $_rt_va_next
+              i64.load
+              call $_rt_putuwide
+              br $spec_done
+            end
+            local.get $c
+            i32.const 115 ;; 's'
+            i32.eq
+            if
+              call $_rt_va_next
+              i64.load
+              i32.wrap_i64
+              call $_rt_putstr
+              br $spec_done
+            end
+            local.get $c
+            i32.const 99 ;; 'c'
+            i32.eq
+            if
+              call $_rt_va_next
+              i32.load8_u
+              call $_rt_putc
+              br $spec_done
+            end
+            local.get $c
+            i32.const 102 ;; 'f'
+            i32.eq
+            if
+              call $_rt_va_next
+              f64.load
+              local.get $prec
+              call $_rt_putfloat
+              br $spec_done
+            end
+            ;; %% veya bilinmeyen belirteç: bilinmeyenler olduğu gibi yazılır
+            local.get $c
+            i32.const 37
+            i32.ne
+            if
+              i32.const 37
+              call $_rt_putc
+            end
+            local.get $c
+            call $_rt_putc
+          end
+          local.get $f
+          i32.const 1
+          i32.add
+          local.set $f
+          br $next
+        end
+        local.get $c
+        call $_rt_putc
+        local.get $f
+        i32.const 1
+        i32.add
+        local.set $f
+        br $next
+      end
+    end
+)
+
+;; Bir byte'ı çıktıya ekler; stdout modunda buffer dolunca boşaltır
+(func $_rt_putc (param $c i32)
+    global.get $_rt_out
+    local.get $c
+    i32.store8
+    global.get $_rt_out
+    i32.const 1
+    i32.add
+    global.set $_rt_out
+    global.get $_rt_mode
+    i32.eqz
+    if
+      global.get $_rt_out
+      i32.const 0x101000
+      i32.ge_u
+      if
+        call $_rt_flush
+      end
+    end
+)
+
+;; stdout buffer'ını fd 1'e yazar (buffer modunda etkisizdir)
+(func $_rt_flush
+    global.get $_rt_mode
+    if
+      return
+    end
+    i32.const 1
+    i32.const 0x100000
+    global.get $_rt_out
+    i32.const 0x100000
+    i32.sub
+    call $_rt_write_all
+    drop
+    i32.const 0x100000
+    global.set $_rt_out
+)
+
+;; `len` byte'ın tamamını yazar; başarılıysa 1
+(func $_rt_write_all (param $fd i32) (param $buf i32) (param $len i32) (result i32) (local $n i32)
+    block $done
+      loop $again
+        local.get $len
+        i32.eqz
+        br_if $done
+        i32.const 0x102410
+        local.get $buf
+        i32.store
+        i32.const 0x102410
+        local.get $len
+        i32.store offset=4
+        local.get $fd
+        i32.const 0x102410
+        i32.const 1
+        i32.const 0x102418
+        call $fd_write
+        if
+          i32.const 0
+          return
+        end
+        i32.const 0x102418
+        i32.load
+        local.tee $n
+        i32.eqz
+        if
+          i32.const 0
+          return
+        end
+        local.get $buf
+        local.get $n
+        i32.add
+        local.set $buf
+        local.get $len
+        local.get $n
+        i32.sub
+        local.set $len
+        br $again
+      end
+    end
+    i32.const 1
+)
+
+;; Null-terminated string'i yazar (NULL ise "(null)")
+(func $_rt_putstr (param $p i32) (local $c i32)
+    local.get $p
+    i32.eqz
+    if
+      i32.const 0x102480
+      local.set $p
+    end
+    block $done
+      loop $next
+        local.get $p
+        i32.load8_u
+        local.tee $c
+        i32.eqz
+        br_if $done
+        local.get $c
+        call $_rt_putc
+        local.get $p
+        i32.const 1
+        i32.add
+        local.set $p
+        br $next
+      end
+    end
+)
+
+;; İşaretli tamsayıyı yazar
+(func $_rt_putint (param $v i64)
+    local.get $v
+    i64.const 0
+    i64.lt_s
+    if
+      i32.const 45 ;; '-'
+      call $_rt_putc
+      i64.const 0
+      local.get $v
+      i64.sub
+      local.set $v
+    end
+    local.get $v
+    i32.const 1
+    call $_rt_putuint_w
+)
+
+;; İşaretli 128 bitlik tamsayıyı (alt, üst) yazar
+(func $_rt_putwide (param $lo i64) (param $hi i64)
+    local.get $hi
+    i64.const 0
+    i64.lt_s
+    if
+      i32.const 45 ;; '-'
+      call $_rt_putc
+      ;; -(hi:lo) = (-hi - (lo != 0)) : -lo
+      i64.const 0
+      local.get $hi
+      i64.sub
+      local.get $lo
+      i64.const 0
+      i64.ne
+      i64.extend_i32_u
+      i64.sub
+      local.set $hi
+      i64.const 0
+      local.get $lo
+      i64.sub
+      local.set $lo
+    end
+    local.get $lo
+    local.get $hi
+    call $_rt_putuwide
+)
+
+;; İşaretsiz 128 bitlik tamsayıyı (alt, üst) yazar. Her basamakta sayı 10'a bölünür:
+;; önce üst yarı, sonra kalanla birlikte alt yarının 32'şer bitlik parçaları.
+(func $_rt_putuwide (param $lo i64) (param $hi i64) (local $p i32) (local $r i64) (local $a i64) (local $b i64)
+    i32.const 0x1024e0
+    local.set $p
+    loop $digit
+      local.get $hi
+      i64.const 10
+      i64.rem_u
+      local.set $r
+      local.get $hi
+      i64.const 10
+      i64.div_u
+      local.set $hi
+      local.get $r
+      i64.const 32
+      i64.shl
+      local.get $lo
+      i64.const 32
+      i64.shr_u
+      i64.or
+      local.tee $a
+      i64.const 10
+      i64.rem_u
+      local.set $r
+      local.get $a
+      i64.const 10
+      i64.div_u
+      i64.const 32
+      i64.shl
+      local.set $a
+      local.get $r
+      i64.const 32
+      i64.shl
+      local.get $lo
+      i64.const 0xffffffff
+      i64.and
+      i64.or
+      local.tee $b
+      i64.const 10
+      i64.div_u
+      local.get $a
+      i64.or
+      local.set $lo
+      local.get $p
+      i32.const 1
+      i32.sub
+      local.tee $p
+      local.get $b
+      i64.const 10
+      i64.rem_u
+      i32.wrap_i64
+      i32.const 48
+      i32.add
+      i32.store8
+      local.get $lo
+      local.get $hi
+      i64.or
+      i64.eqz
+      i32.eqz
+      br_if $digit
+    end
+    block $done
+      loop $out
+        local.get $p
+        i32.const 0x1024e0
+        i32.ge_u
+        br_if $done
+        local.get $p
+        i32.load8_u
+        call $_rt_putc
+        local.get $p
+        i32.const 1
+        i32.add
+        local.set $p
+        br $out
+      end
+    end
+)
+
+;; İşaretsiz tamsayıyı en az `width` basamakla (sıfır dolgulu) yazar
+(func $_rt_putuint_w (param $v i64) (param $width i32) (local $p i32)
+    i32.const 0x1024b0
+    local.set $p
+    loop $digit
+      local.get $p
+      i32.const 1
+      i32.sub
+      local.tee $p
+      local.get $v
+      i64.const 10
+      i64.rem_u
+      i32.wrap_i64
+      i32.const 48
+      i32.add
+      i32.store8
+      local.get $v
+      i64.const 10
+      i64.div_u
+      local.set $v
+      local.get $width
+      i32.const 1
+      i32.sub
+      local.set $width
+      local.get $v
+      i64.eqz
+      i32.eqz
+      br_if $digit
+    end
+    block $padded
+      loop $pad
+        local.get $width
+        i32.const 0
+        i32.le_s
+        br_if $padded
+        local.get $p
+        i32.const 1
+        i32.sub
+        local.tee $p
+        i32.const 48
+        i32.store8
+        local.get $width
+        i32.const 1
+        i32.sub
+        local.set $width
+        br $pad
+      end
+    end
+    block $done
+      loop $out
+        local.get $p
+        i32.const 0x1024b0
+        i32.ge_u
+        br_if $done
+        local.get $p
+        i32.load8_u
+        call $_rt_putc
+        local.get $p
+        i32.const 1
+        i32.add
+        local.set $p
+        br $out
+      end
+    end
+)
+
+;; Double'ı `prec` basamak hassasiyetle yazar
+(func $_rt_putfloat (param $v f64) (param $prec i32) (local $scale i64) (local $i i32) (local $int i64)
+    local.get $v
+    f64.const 0
+    f64.lt
+    if
+      i32.const 45 ;; '-'
+      call $_rt_putc
+      local.get $v
+      f64.neg
+      local.set $v
+    end
+    ;; Ölçek = 10^hassasiyet
+    i64.const 1
+    local.set $scale
+    local.get $prec
+    local.set $i
+    block $scaled
+      loop $scale_loop
+        local.get $i
+        i32.eqz
+        br_if $scaled
+        local.get $scale
+        i64.const 10
+        i64.mul
+        local.set $scale
+        local.get $i
+        i32.const 1
+        i32.sub
+        local.set $i
+        br $scale_loop
+      end
+    end
+    ;; Yuvarlama: x + 0.5 / ölçek
+    local.get $v
+    f64.const 0.5
+    local.get $scale
+    f64.convert_i64_s
+    f64.div
+    f64.add
+    local.tee $v
+    i64.trunc_sat_f64_s
+    local.tee $int
+    i32.const 1
+    call $_rt_putuint_w
+    local.get $prec
+    i32.eqz
+    if
+      return
+    end
+    i32.const 46 ;; '.'
+    call $_rt_putc
+    local.get $v
+    local.get $int
+    f64.convert_i64_s
+    f64.sub
+    local.get $scale
+    f64.convert_i64_s
+    f64.mul
+    i64.trunc_sat_f64_s
+    local.get $prec
+    call $_rt_putuint_w
+)
+
+;; ---------------------------------------------------------------------------
+;; Dönüşümler ve string yardımcıları
+;; ---------------------------------------------------------------------------
+
+;; _itoa(değer) -> string (dönüşüm buffer'ı)
+(func $_itoa (param $v i64) (result i64)
+    i32.const 1
+    global.set $_rt_mode
+    i32.const 0x102000
+    global.set $_rt_out
+    local.get $v
+    call $_rt_putint
+    global.get $_rt_out
+    i32.const 0
+    i32.store8
+    i64.const 0x102000
+)
+
+;; _ftoa(değer) -> string (dönüşüm buffer'ı, "%f" biçiminde)
+(func $_ftoa (param $v f64) (result i64)
+    i32.const 1
+    global.set $_rt_mode
+    i32.const 0x102000
+    global.set $_rt_out
+    local.get $v
+    i32.const 6
+    call $_rt_putfloat
+    global.get $_rt_out
+    i32.const 0
+    i32.store8
+    i64.const 0x102000
+)
+
+;; _atoi(string) -> tamsayı
+(func $_atoi (param $s i64) (result i64) (local $p i32) (local $c i32) (local $v i64) (local $sign i64)
+    local.get $s
+    i32.wrap_i64
+    local.set $p
+    i64.const 1
+    local.set $sign
+    local.get $p
+    i32.load8_u
+    i32.const 45 ;; '-'
+    i32.eq
+    if
+      i64.const -1
+      local.set $sign
+      local.get $p
+      i32.const 1
+      i32.add
+      local.set $p
+    end
+    block $done
+      loop $digit
+        local.get $p
+        i32.load8_u
+        i32.const 48
+        i32.sub
+        local.tee $c
+        i32.const 9
+        i32.gt_u
+        br_if $done
+        local.get $v
+        i64.const 10
+        i64.mul
+        local.get $c
+        i64.extend_i32_u
+        i64.add
+        local.set $v
+        local.get $p
+        i32.const 1
+        i32.add
+        local.set $p
+        br $digit
+      end
+    end
+    local.get $v
+    local.get $sign
+    i64.mul
+)
+
+;; _strlen(string) -> uzunluk
+(func $_strlen (param $s i64) (result i64)
+    local.get $s
+    i32.wrap_i64
+    call $_rt_strlen
+    i64.extend_i32_u
+)
+
+(func $_rt_strlen (param $s i32) (result i32) (local $p i32)
+    local.get $s
+    local.set $p
+    block $done
+      loop $next
+        local.get $p
+        i32.load8_u
+        i32.eqz
+        br_if $done
+        local.get $p
+        i32.const 1
+        i32.add
+        local.set $p
+        br $next
+      end
+    end
+    local.get $p
+    local.get $s
+    i32.sub
+)
+
+;; _fmod(a, b) -> a - trunc(a / b) * b
+(func $_fmod (param $a f64) (param $b f64) (result f64)
+    local.get $a
+    local.get $a
+    local.get $b
+    f64.div
+    f64.trunc
+    local.get $b
+    f64.mul
+    f64.sub
+)
+
+;; ---------------------------------------------------------------------------
+;; Bellek, giriş ve süreç
+;; ---------------------------------------------------------------------------
+
+;; _alloc_str(boyut) -> sıfırlanmış bellek (heap `$__heap_base`'den başlar, memory.grow ile büyür)
+(func $_alloc_str (param $size i64) (result i64) (local $ptr i32) (local $end i32) (local $pages i32)
+    local.get $size
+    i64.const 0x40000000
+    i64.gt_u
+    if
+      i64.const 0
+      return
+    end
+    global.get $_rt_heap_ptr
+    i32.eqz
+    if
+      global.get $__heap_base
+      i32.const 15
+      i32.add
+      i32.const -16
+      i32.and
+      global.set $_rt_heap_ptr
+    end
+    global.get $_rt_heap_ptr
+    local.tee $ptr
+    local.get $size
+    i32.wrap_i64
+    i32.const 15
+    i32.add
+    i32.const -16
+    i32.and
+    i32.add
+    local.tee $end
+    memory.size
+    i32.const 16
+    i32.shl
+    i32.gt_u
+    if
+      local.get $end
+      memory.size
+      i32.const 16
+      i32.shl
+      i32.sub
+      i32.const 65535
+      i32.add
+      i32.const 16
+      i32.shr_u
+      memory.grow
+      i32.const -1
+      i32.eq
+      if
+        i64.const 0
+        return
+      end
+    end
+    local.get $end
+    global.set $_rt_heap_ptr
+    local.get $ptr
+    i64.extend_i32_u
+)
+
+;; _input(prompt) -> satır sonu temizlenmiş yeni string (prompt 0 ise yazılmaz)
+(func $_input (param $prompt i64) (result i64) (local $buf i32) (local $n i32)
+    local.get $prompt
+    i64.eqz
+    i32.eqz
+    if
+      local.get $prompt
+      i64.const 0
+      call $_print
+    end
+    i64.const 1024
+    call $_alloc_str
+    i32.wrap_i64
+    local.tee $buf
+    i32.eqz
+    if
+      i64.const 0
+      return
+    end
+    ;; Satırı byte byte oku; sonraki input() çağrıları kendi satırlarını alır.
+    block $done
+      loop $next
+        local.get $n
+        i32.const 1023
+        i32.ge_u
+        br_if $done
+        i32.const 0x102410
+        local.get $buf
+        local.get $n
+        i32.add
+        i32.store
+        i32.const 0x102410
+        i32.const 1
+        i32.store offset=4
+        i32.const 0
+        i32.const 0x102410
+        i32.const 1
+        i32.const 0x102418
+        call $fd_read
+        br_if $done
+        i32.const 0x102418
+        i32.load
+        i32.const 1
+        i32.ne
+        br_if $done
+        local.get $buf
+        local.get $n
+        i32.add
+        i32.load8_u
+        i32.const 10
+        i32.eq
+        br_if $done
+        local.get $n
+        i32.const 1
+        i32.add
+        local.set $n
+        br $next
+      end
+    end
+    local.get $n
+    if
+      local.get $buf
+      local.get $n
+      i32.add
+      i32.const 1
+      i32.sub
+      i32.load8_u
+      i32.const 13
+      i32.eq
+      if
+        local.get $n
+        i32.const 1
+        i32.sub
+        local.set $n
+      end
+    end
+    local.get $buf
+    local.get $n
+    i32.add
+    i32.const 0
+    i32.store8
+    local.get $buf
+    i64.extend_i32_u
+)
+
+;; _exit_process(kod) - süreci verilen çıkış koduyla sonlandırır
+(func $_exit_process (param $code i64)
+    local.get $code
+    i32.wrap_i64
+    call $proc_exit
+    unreachable
+)
+
+;; Komut satırı argümanlarını okur: _nim_argc'ye sayı, _nim_argv'ye 8 byte'lık pointer dizisi yazılır.
+(func $_rt_init_args (local $argc i32) (local $ptrs i32) (local $table i32) (local $i i32)
+    i32.const 0x102418
+    i32.const 0x10241c
+    call $args_sizes_get
+    if
+      return
+    end
+    i32.const 0x102418
+    i32.load
+    local.set $argc
+    local.get $argc
+    i64.extend_i32_u
+    i64.const 4
+    i64.mul
+    call $_alloc_str
+    i32.wrap_i64
+    local.set $ptrs
+    local.get $argc
+    i64.extend_i32_u
+    i64.const 8
+    i64.mul
+    call $_alloc_str
+    i32.wrap_i64
+    local.set $table
+    local.get $ptrs
+    i32.const 0x10241c
+    i32.load
+    i64.extend_i32_u
+    call $_alloc_str
+    i32.wrap_i64
+    call $args_get
+    if
+      return
+    end
+    block $done
+      loop $next
+        local.get $i
+        local.get $argc
+        i32.ge_u
+        br_if $done
+        local.get $table
+        local.get $i
+        i32.const 3
+        i32.shl
+        i32.add
+        local.get $ptrs
+        local.get $i
+        i32.const 2
+        i32.shl
+        i32.add
+        i64.load32_u
+        i64.store
+        local.get $i
+        i32.const 1
+        i32.add
+        local.set $i
+        br $next
+      end
+    end
+    i32.const 0x102400
+    local.get $argc
+    i64.extend_i32_u
+    i64.store
+    i32.const 0x102408
+    local.get $table
+    i64.extend_i32_u
+    i64.store
+)
+
+;; ---------------------------------------------------------------------------
+;; Dosya G/Ç: libs/file.n'deki Windows imzalarıyla uyumlu sarmalayıcılar.
+;; Handle olarak WASI dosya tanımlayıcısı (fd) kullanılır; hata -1'dir. Göreli yollar ilk önceden
+;; açılmış dizine göre çözülür; baştaki "./" atılır.
+;; ---------------------------------------------------------------------------
+
+(func $_rt_path (param $path i64) (result i32) (local $p i32)
+    local.get $path
+    i32.wrap_i64
+    local.tee $p
+    i32.load8_u
+    i32.const 46 ;; '.'
+    i32.eq
+    if
+      local.get $p
+      i32.load8_u offset=1
+      i32.const 47 ;; '/'
+      i32.eq
+      if
+        local.get $p
+        i32.const 2
+        i32.add
+        local.set $p
+      end
+    end
+    local.get $p
+)
+
+;; iovec'i (buf, len) ile doldurur
+(func $_rt_iovec (param $buf i64) (param $len i64)
+    i32.const 0x102410
+    local.get $buf
+    i64.store32
+    i32.const 0x102410
+    local.get $len
+    i64.store32 offset=4
+)
+
+;; _io_open(path, access, share, create) -> fd (hata: -1)
+;; Erişim: GENERIC_READ (0x80000000) / GENERIC_WRITE (0x40000000)
+;; Oluşturma: 1 CREATE_NEW, 2 CREATE_ALWAYS, 3 OPEN_EXISTING, 4 OPEN_ALWAYS, 5 TRUNCATE_EXISTING
+(func $_io_open (param $path i64) (param $access i64) (param $share i64) (param $create i64) (result i64) (local $p i32) (local $oflags i32) (local $rights i64)
+    ;; WASI oflags: CREAT = 1, EXCL = 4, TRUNC = 8
+    local.get $create
+    i64.const 1
+    i64.eq
+    if
+      i32.const 5
+      local.set $oflags
+    end
+    local.get $create
+    i64.const 2
+    i64.eq
+    if
+      i32.const 9
+      local.set $oflags
+    end
+    local.get $create
+    i64.const 4
+    i64.eq
+    if
+      i32.const 1
+      local.set $oflags
+    end
+    local.get $create
+    i64.const 5
+    i64.eq
+    if
+      i32.const 8
+      local.set $oflags
+    end
+    ;; Haklar: okuma FD_READ|FD_SEEK|FD_TELL|FD_FILESTAT_GET, yazma FD_DATASYNC|FD_SEEK|FD_SYNC|FD_TELL|
+    ;; FD_WRITE|FD_FILESTAT_GET|FD_FILESTAT_SET_SIZE
+    i64.const 2097190
+    local.set $rights
+    local.get $access
+    i64.const 0x40000000
+    i64.and
+    i64.eqz
+    i32.eqz
+    if
+      i64.const 6291573
+      local.set $rights
+      local.get $access
+      i64.const 0x80000000
+      i64.and
+      i64.eqz
+      i32.eqz
+      if
+        i64.const 6291575
+        local.set $rights
+      end
+    end
+    global.get $_rt_dirfd
+    i32.const 1 ;; LOOKUP_SYMLINK_FOLLOW
+    local.get $path
+    call $_rt_path
+    local.tee $p
+    local.get $p
+    call $_rt_strlen
+    local.get $oflags
+    local.get $rights
+    local.get $rights
+    i32.const 0
+    i32.const 0x102418
+    call $path_open
+    if
+      i64.const -1
+      return
+    end
+    i32.const 0x102418
+    i64.load32_u
+)
+
+;; _io_close(fd) -> başarılıysa 1
+(func $_io_close (param $fd i64) (result i64)
+    local.get $fd
+    i32.wrap_i64
+    call $fd_close
+    i32.eqz
+    i64.extend_i32_u
+)
+
+;; _io_read(fd, buf, len, bytesReadPtr) -> başarılıysa 1
+(func $_io_read (param $fd i64) (param $buf i64) (param $len i64) (param $count i64) (result i64)
+    local.get $buf
+    local.get $len
+    call $_rt_iovec
+    local.get $fd
+    i32.wrap_i64
+    i32.const 0x102410
+    i32.const 1
+    i32.const 0x102418
+    call $fd_read
+    local.get $count
+    call $_rt_store_count
+)
+
+;; _io_read_console(fd, buf, len, bytesReadPtr): WASI'de _io_read ile aynıdır
+(func $_io_read_console (param $fd i64) (param $buf i64) (param $len i64) (param $count i64) (result i64)
+    local.get $fd
+    local.get $buf
+    local.get $len
+    local.get $count
+    call $_io_read
+)
+
+;; _io_write(fd, buf, len, bytesWrittenPtr) -> başarılıysa 1
+(func $_io_write (param $fd i64) (param $buf i64) (param $len i64) (param $count i64) (result i64)
+    local.get $buf
+    local.get $len
+    call $_rt_iovec
+    local.get $fd
+    i32.wrap_i64
+    i32.const 0x102410
+    i32.const 1
+    i32.const 0x102418
+    call $fd_write
+    local.get $count
+    call $_rt_store_count
+)
+
+;; WASI hata kodu 0 ise okunan/yazılan byte sayısını `count` adresine yazar (0 değilse); bool döner
+(func $_rt_store_count (param $errno i32) (param $count i64) (result i64) (local $n i64)
+    local.get $errno
+    i32.eqz
+    if
+      i32.const 0x102418
+      i64.load32_u
+      local.set $n
+    end
+    local.get $count
+    i64.eqz
+    i32.eqz
+    if
+      local.get $count
+      i32.wrap_i64
+      local.get $n
+      i64.store
+    end
+    local.get $errno
+    i32.eqz
+    i64.extend_i32_u
+)
+
+;; _io_seek(fd, offset, origin) -> yeni konum (hata: -1)
+(func $_io_seek (param $fd i64) (param $offset i64) (param $origin i64) (result i64)
+    local.get $fd
+    i32.wrap_i64
+    local.get $offset
+    local.get $origin
+    i32.wrap_i64
+    i32.const 0x102418
+    call $fd_seek
+    if
+      i64.const -1
+      return
+    end
+    i32.const 0x102418
+    i64.load
+)
+
+;; _io_size(fd, sizePtr) -> başarılıysa 1
+(func $_io_size (param $fd i64) (param $size i64) (result i64)
+    local.get $fd
+    i32.wrap_i64
+    i32.const 0x102440
+    call $fd_filestat_get
+    if
+      i64.const 0
+      return
+    end
+    local.get $size
+    i64.eqz
+    i32.eqz
+    if
+      local.get $size
+      i32.wrap_i64
+      i32.const 0x102440
+      i64.load offset=32
+      i64.store
+    end
+    i64.const 1
+)
+
+;; _io_exists(path) -> bool
+(func $_io_exists (param $path i64) (result i64) (local $p i32)
+    global.get $_rt_dirfd
+    i32.const 1
+    local.get $path
+    call $_rt_path
+    local.tee $p
+    local.get $p
+    call $_rt_strlen
+    i32.const 0x102440
+    call $path_filestat_get
+    i32.eqz
+    i64.extend_i32_u
+)
+
+;; _io_remove(path) -> bool
+(func $_io_remove (param $path i64) (result i64) (local $p i32)
+    global.get $_rt_dirfd
+    local.get $path
+    call $_rt_path
+    local.tee $p
+    local.get $p
+    call $_rt_strlen
+    call $path_unlink_file
+    i32.eqz
+    i64.extend_i32_u
+)
+
+;; _io_copy(src, dest) -> bool
+(func $_io_copy (param $src i64) (param $dest i64) (result i64) (local $in i64) (local $out i64) (local $n i32)
+    local.get $src
+    i64.const 0x80000000
+    i64.const 0
+    i64.const 3 ;; OPEN_EXISTING
+    call $_io_open
+    local.tee $in
+    i64.const 0
+    i64.lt_s
+    if
+      i64.const 0
+      return
+    end
+    local.get $dest
+    i64.const 0x40000000
+    i64.const 0
+    i64.const 2 ;; CREATE_ALWAYS
+    call $_io_open
+    local.tee $out
+    i64.const 0
+    i64.lt_s
+    if
+      local.get $in
+      call $_io_close
+      drop
+      i64.const 0
+      return
+    end
+    block $done
+      loop $next
+        i32.const 0x102410
+        i32.const 0x101000
+        i32.store
+        i32.const 0x102410
+        i32.const 4096
+        i32.store offset=4
+        local.get $in
+        i32.wrap_i64
+        i32.const 0x102410
+        i32.const 1
+        i32.const 0x102418
+        call $fd_read
+        br_if $done
+        i32.const 0x102418
+        i32.load
+        local.tee $n
+        i32.eqz
+        br_if $done
+        local.get $out
+        i32.wrap_i64
+        i32.const 0x101000
+        local.get $n
+        call $_rt_write_all
+        i32.eqz
+        br_if $done
+        br $next
+      end
+    end
+    local.get $out
+    call $_io_close
+    drop
+    local.get $in
+    call $_io_close
+    drop
+    i64.const 1
+)
+
+;; _io_flush(fd) -> bool
+(func $_io_flush (param $fd i64) (result i64)
+    local.get $fd
+    i32.wrap_i64
+    call $fd_sync
+    i32.eqz
+    i64.extend_i32_u
+)
+
+;; _io_get_std(type) -> Windows STD_*_HANDLE değerlerini (-10, -11, -12) fd 0, 1, 2'ye çevirir
+(func $_io_get_std (param $type i64) (result i64)
+    i64.const -10
+    local.get $type
+    i64.sub
+)
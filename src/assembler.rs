@@ -0,0 +1,688 @@
+// src/assembler.rs
+
+// Yerleşik x86-64 assembler'ı: codegen'in ürettiği GAS Intel sözdizimli metni (ve Linux runtime'ını)
+// harici bir araç çağırmadan ELF64 relocatable nesne dosyasına (.o) çevirir.
+//
+// Tek geçişte çalışır: komut boyları etiket adreslerinden bağımsız olduğu için (dallanmalar daima
+// rel32) her satır hemen kodlanır, sembol içeren alanlar ise `Fixup` olarak bekletilir. Tüm etiketler
+// bilindiğinde aynı bölümdeki başvurular doğrudan yazılır, geri kalanlar relocation kaydı olur.
+// `.loc` ve `.cfi_*` direktiflerinden .debug_line ve .eh_frame bölümleri üretilir (bkz. dwarf.rs).
+
+use std::collections::{HashMap, HashSet};
+
+mod dwarf;
+mod elf;
+mod encode;
+mod parse;
+// WAT metninden ikili WebAssembly modülü (--target wasm32-wasi)
+pub mod wasm;
+
+use encode::{Fixup, FixupKind};
+use parse::{Expr, Mem, Operand, Stmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionKind {
+    Code,
+    Data,
+    ReadOnly,
+    Bss,
+    Debug,
+    Unwind,
+    Note,
+}
+
+#[derive(Debug, Clone)]
+pub struct Section {
+    pub name: String,
+    pub kind: SectionKind,
+    pub data: Vec<u8>,
+    // Yalnızca .bss için: dosyada yer kaplamayan boyut
+    pub bss_size: u64,
+    pub align: u64,
+    pub relocs: Vec<Reloc>,
+}
+
+impl Section {
+    fn new(name: &str, kind: SectionKind) -> Self {
+        Section { name: name.to_string(), kind, data: Vec::new(), bss_size: 0, align: 1, relocs: Vec::new() }
+    }
+
+    pub fn size(&self) -> u64 {
+        if self.kind == SectionKind::Bss { self.bss_size } else { self.data.len() as u64 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    pub name: String,
+    // Tanımlı olduğu bölüm; `None` ise dışarıdan gelir (undefined)
+    pub section: Option<usize>,
+    pub value: u64,
+    pub global: bool,
+    pub function: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelocTarget {
+    Symbol(String),
+    Section(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelocKind {
+    Abs64,
+    Abs32,
+    Abs32S,
+    Pc32,
+    Plt32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Reloc {
+    pub offset: u64,
+    pub target: RelocTarget,
+    pub kind: RelocKind,
+    pub addend: i64,
+}
+
+// Assemble edilmiş nesne: bölümler ve symtab'a girecek semboller.
+#[derive(Debug, Clone, Default)]
+pub struct Object {
+    pub sections: Vec<Section>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl Object {
+    pub fn section_index(&self, name: &str) -> Option<usize> {
+        self.sections.iter().position(|s| s.name == name)
+    }
+
+    // ELF64 relocatable dosya içeriği (bkz. elf.rs)
+    pub fn to_elf(&self) -> Vec<u8> {
+        elf::write(self)
+    }
+
+    // ELF64 relocatable dosyasını okur (yerleşik linker için).
+    pub fn from_elf(data: &[u8]) -> Result<Object, String> {
+        elf::read(data)
+    }
+}
+
+// Assembly metnini nesneye çevirir. Hata mesajı "satır N: ..." biçimindedir.
+pub fn assemble(source: &str) -> Result<Object, String> {
+    let mut asm = Assembler::default();
+    for (index, line) in parse::strip_block_comments(source).lines().enumerate() {
+        asm.line = index + 1;
+        let stmts = parse::parse_line(line).map_err(|e| asm.error(e))?;
+        for stmt in stmts {
+            asm.statement(stmt).map_err(|e| asm.error(e))?;
+        }
+    }
+    asm.finish()
+}
+
+// Çözümlenmeyi bekleyen sembollü alan
+struct PendingFixup {
+    section: usize,
+    offset: usize,
+    fixup: Fixup,
+    line: usize,
+}
+
+// Satır tablosu satırı (.loc)
+struct LineRow {
+    section: usize,
+    offset: u64,
+    file: u64,
+    line: u64,
+    column: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CfiOp {
+    DefCfa(u8, u64),
+    DefCfaOffset(u64),
+    DefCfaRegister(u8),
+    Offset(u8, i64),
+    Undefined(u8),
+}
+
+// Bir fonksiyonun (.cfi_startproc ... .cfi_endproc) çerçeve bilgisi
+struct Frame {
+    section: usize,
+    start: u64,
+    end: u64,
+    ops: Vec<(u64, CfiOp)>,
+}
+
+#[derive(Default)]
+struct Assembler {
+    line: usize,
+    sections: Vec<Section>,
+    current: Option<usize>,
+    labels: HashMap<String, (usize, u64)>,
+    label_order: Vec<String>,
+    constants: HashMap<String, i64>,
+    globals: Vec<String>,
+    functions: HashSet<String>,
+    fixups: Vec<PendingFixup>,
+    files: Vec<(u64, String)>,
+    rows: Vec<LineRow>,
+    frames: Vec<Frame>,
+    open_frame: Option<Frame>,
+}
+
+impl Assembler {
+    fn error(&self, message: impl Into<String>) -> String {
+        format!("satır {}: {}", self.line, message.into())
+    }
+
+    fn section(&mut self, name: &str, kind: SectionKind) -> usize {
+        match self.sections.iter().position(|s| s.name == name) {
+            Some(index) => index,
+            None => {
+                self.sections.push(Section::new(name, kind));
+                self.sections.len() - 1
+            }
+        }
+    }
+
+    // Bölüm seçilmeden gelen içerik .text'e yazılır (GAS ile aynı).
+    fn current(&mut self) -> usize {
+        match self.current {
+            Some(index) => index,
+            None => {
+                let index = self.section(".text", SectionKind::Code);
+                self.current = Some(index);
+                index
+            }
+        }
+    }
+
+    fn offset(&mut self) -> u64 {
+        let index = self.current();
+        self.sections[index].size()
+    }
+
+    fn emit(&mut self, bytes: &[u8]) -> Result<(), String> {
+        let index = self.current();
+        let section = &mut self.sections[index];
+        if section.kind == SectionKind::Bss {
+            if bytes.iter().any(|b| *b != 0) {
+                return Err(format!("'{}' bölümüne sıfırdan farklı veri yazılamaz", section.name));
+            }
+            section.bss_size += bytes.len() as u64;
+        } else {
+            section.data.extend_from_slice(bytes);
+        }
+        Ok(())
+    }
+
+    // İfadedeki `.set` sabitlerini yerine koyar.
+    fn substitute(&self, expr: &mut Expr) {
+        if let Some(value) = expr.symbol.as_ref().and_then(|s| self.constants.get(s)) {
+            expr.constant = expr.constant.wrapping_add(*value);
+            expr.symbol = None;
+        }
+        if let Some(value) = expr.minus.as_ref().and_then(|s| self.constants.get(s)) {
+            expr.constant = expr.constant.wrapping_sub(*value);
+            expr.minus = None;
+        }
+    }
+
+    fn constant_expr(&self, text: &str) -> Result<i64, String> {
+        let mut expr = parse::parse_expr(text)?;
+        self.substitute(&mut expr);
+        expr.as_constant().ok_or_else(|| format!("Sabit bir ifade bekleniyordu: '{}'", text))
+    }
+
+    fn define_label(&mut self, name: String) -> Result<(), String> {
+        if self.labels.contains_key(&name) || self.constants.contains_key(&name) {
+            return Err(format!("'{}' sembolü birden fazla kez tanımlanmış", name));
+        }
+        let section = self.current();
+        let offset = self.offset();
+        self.labels.insert(name.clone(), (section, offset));
+        self.label_order.push(name);
+        Ok(())
+    }
+
+    fn statement(&mut self, stmt: Stmt) -> Result<(), String> {
+        match stmt {
+            Stmt::Label(name) => self.define_label(name),
+            Stmt::Directive(name, args) => self.directive(&name, &args),
+            Stmt::Inst { mnemonic, prefix, operands } => self.instruction(&mnemonic, prefix.as_deref(), operands),
+        }
+    }
+
+    fn instruction(&mut self, mnemonic: &str, prefix: Option<&str>, mut operands: Vec<Operand>) -> Result<(), String> {
+        let branch = mnemonic == "call" || mnemonic.starts_with('j');
+        for operand in &mut operands {
+            match operand {
+                Operand::Imm(expr) => self.substitute(expr),
+                Operand::Mem(mem) => self.substitute(&mut mem.disp),
+                Operand::Symbol(expr) => {
+                    self.substitute(expr);
+                    let expr = std::mem::take(expr);
+                    *operand = if branch || expr.as_constant().is_some() {
+                        Operand::Imm(expr)
+                    } else {
+                        Operand::Mem(Mem { size: None, base: None, index: None, rip: false, disp: expr })
+                    };
+                }
+                Operand::Reg(_) => {}
+            }
+        }
+        if !branch && operands.iter().any(|op| matches!(op, Operand::Imm(e) if e.as_constant().is_none() && e.minus.is_some())) {
+            return Err("Anlık değerde iki sembolün farkı desteklenmiyor".to_string());
+        }
+
+        let section = self.current();
+        if self.sections[section].kind == SectionKind::Bss {
+            return Err(format!("'{}' bölümüne komut yazılamaz", self.sections[section].name));
+        }
+        let encoded = encode::encode(mnemonic, prefix, &operands)?;
+        let base = self.sections[section].data.len();
+        for fixup in encoded.fixups {
+            self.fixups.push(PendingFixup { section, offset: base + fixup.offset, fixup, line: self.line });
+        }
+        self.sections[section].data.extend_from_slice(&encoded.bytes);
+        Ok(())
+    }
+
+    // .byte/.long/.quad gibi veri direktifleri
+    fn data(&mut self, args: &str, size: u8) -> Result<(), String> {
+        for arg in parse::split_args(args) {
+            let mut expr = parse::parse_expr(&arg)?;
+            self.substitute(&mut expr);
+            match expr.as_constant() {
+                Some(value) => {
+                    if !encode::fits(value, size) {
+                        return Err(format!("{} değeri {} byte'lık alana sığmıyor", value, size));
+                    }
+                    self.emit(&value.to_le_bytes()[..size as usize])?;
+                }
+                None => {
+                    let section = self.current();
+                    if self.sections[section].kind == SectionKind::Bss {
+                        return Err("'.bss' bölümüne sembol adresi yazılamaz".to_string());
+                    }
+                    let offset = self.sections[section].data.len();
+                    let kind = FixupKind::Absolute { signed: false };
+                    self.fixups.push(PendingFixup { section, offset, fixup: Fixup { offset: 0, size, expr, kind }, line: self.line });
+                    self.emit(&vec![0; size as usize])?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn align(&mut self, alignment: u64) -> Result<(), String> {
+        if !alignment.is_power_of_two() {
+            return Err(format!("Hizalama 2'nin kuvveti olmalı: {}", alignment));
+        }
+        let index = self.current();
+        let fill = if self.sections[index].kind == SectionKind::Code { 0x90 } else { 0 };
+        let padding = self.offset().next_multiple_of(alignment) - self.offset();
+        self.emit(&vec![fill; padding as usize])?;
+        let section = &mut self.sections[index];
+        section.align = section.align.max(alignment);
+        Ok(())
+    }
+
+    fn directive(&mut self, name: &str, args: &str) -> Result<(), String> {
+        match name {
+            ".intel_syntax" | ".size" | ".ident" | ".extern" => {}
+            ".text" => self.current = Some(self.section(".text", SectionKind::Code)),
+            ".data" => self.current = Some(self.section(".data", SectionKind::Data)),
+            ".bss" => self.current = Some(self.section(".bss", SectionKind::Bss)),
+            ".rodata" => self.current = Some(self.section(".rodata", SectionKind::ReadOnly)),
+            ".section" => {
+                let parts = parse::split_args(args);
+                let section_name = parts.first().map(String::as_str).unwrap_or("");
+                if section_name.is_empty() {
+                    return Err("'.section' için bölüm adı bekleniyordu".to_string());
+                }
+                let flags = parts.get(1).map(|f| f.trim_matches('"').to_string());
+                let kind = section_kind(section_name, flags.as_deref());
+                self.current = Some(self.section(section_name, kind));
+            }
+            ".global" | ".globl" => {
+                for symbol in parse::split_args(args) {
+                    if !self.globals.contains(&symbol) {
+                        self.globals.push(symbol);
+                    }
+                }
+            }
+            ".type" => {
+                let parts = parse::split_args(args);
+                if let [symbol, kind] = parts.as_slice() {
+                    if kind.trim_start_matches(['@', '%']) == "function" {
+                        self.functions.insert(symbol.clone());
+                    }
+                }
+            }
+            ".set" | ".equ" => {
+                let parts = parse::split_args(args);
+                let [symbol, value] = parts.as_slice() else {
+                    return Err(format!("'{}' için 'ad, değer' bekleniyordu", name));
+                };
+                if self.labels.contains_key(symbol) {
+                    return Err(format!("'{}' sembolü birden fazla kez tanımlanmış", symbol));
+                }
+                let value = self.constant_expr(value)?;
+                self.constants.insert(symbol.clone(), value);
+            }
+            ".lcomm" | ".comm" => {
+                let parts = parse::split_args(args);
+                let (symbol, size) = match parts.as_slice() {
+                    [symbol, size] | [symbol, size, _] => (symbol.clone(), self.constant_expr(size)? as u64),
+                    _ => return Err(format!("'{}' için 'ad, boyut' bekleniyordu", name)),
+                };
+                let alignment = match parts.get(2) {
+                    Some(align) => self.constant_expr(align)? as u64,
+                    None => size.next_power_of_two().clamp(1, 16),
+                };
+                let previous = self.current;
+                self.current = Some(self.section(".bss", SectionKind::Bss));
+                self.align(alignment)?;
+                self.define_label(symbol.clone())?;
+                self.emit(&vec![0; size as usize])?;
+                self.current = previous;
+                if name == ".comm" && !self.globals.contains(&symbol) {
+                    self.globals.push(symbol);
+                }
+            }
+            ".byte" => self.data(args, 1)?,
+            ".value" | ".short" | ".word" | ".2byte" => self.data(args, 2)?,
+            ".long" | ".int" | ".4byte" => self.data(args, 4)?,
+            ".quad" | ".8byte" => self.data(args, 8)?,
+            ".ascii" | ".asciz" | ".string" => {
+                let terminate = name != ".ascii";
+                for arg in parse::split_args(args) {
+                    let (mut bytes, rest) = parse::parse_string(&arg)?;
+                    if !rest.trim().is_empty() {
+                        return Err(format!("String sonrasında beklenmeyen metin: '{}'", rest.trim()));
+                    }
+                    if terminate {
+                        bytes.push(0);
+                    }
+                    self.emit(&bytes)?;
+                }
+            }
+            ".double" | ".float" | ".single" => {
+                for arg in parse::split_args(args) {
+                    let value: f64 = arg.parse().map_err(|_| format!("Geçersiz ondalık sayı: '{}'", arg))?;
+                    if name == ".double" {
+                        self.emit(&value.to_le_bytes())?;
+                    } else {
+                        self.emit(&(value as f32).to_le_bytes())?;
+                    }
+                }
+            }
+            ".space" | ".skip" | ".zero" => {
+                let parts = parse::split_args(args);
+                let size = self.constant_expr(parts.first().map(String::as_str).unwrap_or(""))?;
+                let fill = match parts.get(1) {
+                    Some(fill) => self.constant_expr(fill)? as u8,
+                    None => 0,
+                };
+                if size < 0 {
+                    return Err(format!("Geçersiz boyut: {}", size));
+                }
+                self.emit(&vec![fill; size as usize])?;
+            }
+            ".align" | ".balign" => {
+                let alignment = self.constant_expr(parse::split_args(args).first().map(String::as_str).unwrap_or(""))?;
+                self.align(alignment as u64)?;
+            }
+            ".p2align" => {
+                let power = self.constant_expr(parse::split_args(args).first().map(String::as_str).unwrap_or(""))?;
+                if !(0..32).contains(&power) {
+                    return Err(format!("Geçersiz hizalama: {}", power));
+                }
+                self.align(1 << power)?;
+            }
+            ".uleb128" | ".sleb128" => {
+                for arg in parse::split_args(args) {
+                    let value = self.constant_expr(&arg)?;
+                    let mut bytes = Vec::new();
+                    if name == ".uleb128" {
+                        dwarf::uleb128(&mut bytes, value as u64);
+                    } else {
+                        dwarf::sleb128(&mut bytes, value);
+                    }
+                    self.emit(&bytes)?;
+                }
+            }
+            ".file" => {
+                // `.file N "ad"` satır tablosu dosyasıdır; `.file "ad"` yalnızca bilgi amaçlıdır.
+                let (number, rest) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+                if let Some(number) = parse::parse_number(number) {
+                    let (file, _) = parse::parse_string(rest)?;
+                    self.files.retain(|(n, _)| *n != number as u64);
+                    self.files.push((number as u64, String::from_utf8_lossy(&file).into_owned()));
+                }
+            }
+            ".loc" => {
+                let fields: Vec<&str> = args.split_whitespace().collect();
+                let number = |i: usize| fields.get(i).and_then(|f| parse::parse_number(f)).map(|v| v as u64);
+                let (Some(file), Some(line)) = (number(0), number(1)) else {
+                    return Err("'.loc' için dosya ve satır numarası bekleniyordu".to_string());
+                };
+                let section = self.current();
+                let offset = self.offset();
+                self.rows.push(LineRow { section, offset, file, line, column: number(2).unwrap_or(0) });
+            }
+            _ if name.starts_with(".cfi_") => self.cfi(name, args)?,
+            _ => return Err(format!("Bilinmeyen direktif: '{}'", name)),
+        }
+        Ok(())
+    }
+
+    fn cfi(&mut self, name: &str, args: &str) -> Result<(), String> {
+        let section = self.current();
+        let offset = self.offset();
+        if name == ".cfi_startproc" {
+            if self.open_frame.is_some() {
+                return Err("'.cfi_startproc' önceki fonksiyon kapanmadan kullanıldı".to_string());
+            }
+            self.open_frame = Some(Frame { section, start: offset, end: offset, ops: Vec::new() });
+            return Ok(());
+        }
+        let Some(frame) = &mut self.open_frame else {
+            return Err(format!("'{}' bir '.cfi_startproc' bloğu dışında kullanıldı", name));
+        };
+        let parts = parse::split_args(args);
+        let register = |i: usize| -> Result<u8, String> {
+            let text = parts.get(i).map(String::as_str).unwrap_or("");
+            dwarf::register_number(text).ok_or_else(|| format!("Geçersiz CFI register'ı: '{}'", text))
+        };
+        let number = |i: usize| -> Result<i64, String> {
+            let text = parts.get(i).map(String::as_str).unwrap_or("");
+            parse::parse_expr(text)?.as_constant().ok_or_else(|| format!("Sabit bir değer bekleniyordu: '{}'", text))
+        };
+        let op = match name {
+            ".cfi_endproc" => {
+                let mut frame = self.open_frame.take().expect("açık çerçeve");
+                frame.end = offset;
+                self.frames.push(frame);
+                return Ok(());
+            }
+            ".cfi_def_cfa" => CfiOp::DefCfa(register(0)?, number(1)? as u64),
+            ".cfi_def_cfa_offset" => CfiOp::DefCfaOffset(number(0)? as u64),
+            ".cfi_def_cfa_register" => CfiOp::DefCfaRegister(register(0)?),
+            ".cfi_offset" => CfiOp::Offset(register(0)?, number(1)?),
+            ".cfi_undefined" => CfiOp::Undefined(register(0)?),
+            _ => return Err(format!("Desteklenmeyen CFI direktifi: '{}'", name)),
+        };
+        if frame.section != section {
+            return Err("CFI direktifleri fonksiyonla aynı bölümde olmalı".to_string());
+        }
+        frame.ops.push((offset, op));
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Object, String> {
+        if self.open_frame.is_some() {
+            return Err("'.cfi_startproc' bloğu kapatılmamış ('.cfi_endproc' eksik)".to_string());
+        }
+        for pending in std::mem::take(&mut self.fixups) {
+            self.line = pending.line;
+            self.resolve(pending).map_err(|e| self.error(e))?;
+        }
+        self.debug_sections()?;
+
+        let mut symbols = Vec::new();
+        let mut defined = HashSet::new();
+        for name in &self.label_order {
+            let global = self.globals.contains(name);
+            // .L ile başlayan yerel etiketler symtab'a girmez
+            if name.starts_with(".L") && !global {
+                continue;
+            }
+            let (section, value) = self.labels[name];
+            defined.insert(name.clone());
+            symbols.push(Symbol { name: name.clone(), section: Some(section), value, global, function: self.functions.contains(name) });
+        }
+        // Başvurulan veya .global ile bildirilen ama tanımlanmayan semboller dışarıdan gelir
+        let mut undefined: Vec<String> = self.globals.iter().filter(|g| !self.labels.contains_key(*g)).cloned().collect();
+        for section in &self.sections {
+            for reloc in &section.relocs {
+                if let RelocTarget::Symbol(name) = &reloc.target {
+                    if !defined.contains(name) && !undefined.contains(name) {
+                        undefined.push(name.clone());
+                    }
+                }
+            }
+        }
+        for name in undefined {
+            symbols.push(Symbol { name, section: None, value: 0, global: true, function: false });
+        }
+        Ok(Object { sections: self.sections, symbols })
+    }
+
+    // Bekleyen alanı aynı bölüm içinde çözer veya relocation kaydına çevirir.
+    fn resolve(&mut self, pending: PendingFixup) -> Result<(), String> {
+        let PendingFixup { section, offset, fixup, .. } = pending;
+        let Fixup { size, expr, kind, .. } = fixup;
+        let lookup = |name: &str| self.labels.get(name).copied();
+
+        let mut value = expr.constant;
+        if let Some(minus) = &expr.minus {
+            // `a - b`: iki etiket de aynı bölümde tanımlı olmalı (veya b, alanın bölümünde olmalı)
+            let (minus_section, minus_value) = lookup(minus).ok_or_else(|| format!("Tanımsız sembol: '{}'", minus))?;
+            let Some(symbol) = &expr.symbol else {
+                return Err(format!("Yalnızca çıkarılan sembol içeren ifade desteklenmiyor: '-{}'", minus));
+            };
+            let (symbol_section, symbol_value) = lookup(symbol).ok_or_else(|| format!("Tanımsız sembol: '{}'", symbol))?;
+            if symbol_section != minus_section {
+                return Err(format!("'{} - {}': semboller farklı bölümlerde", symbol, minus));
+            }
+            value += symbol_value as i64 - minus_value as i64;
+            if !matches!(kind, FixupKind::Absolute { .. }) {
+                return Err("Sembol farkı göreli bir alanda kullanılamaz".to_string());
+            }
+            return self.patch(section, offset, size, value);
+        }
+
+        let Some(symbol) = &expr.symbol else {
+            if kind == FixupKind::Branch {
+                return Err("Dallanma hedefi bir etiket olmalı".to_string());
+            }
+            return self.patch(section, offset, size, value);
+        };
+        let target = lookup(symbol);
+        let global = self.globals.contains(symbol);
+        let relative = matches!(kind, FixupKind::PcRelative | FixupKind::Branch);
+
+        // Aynı bölümdeki yerel sembollere göreli başvurular (dallanmalar, rip göreli adresler) doğrudan
+        // çözülür; global semboller bağlayıcıda değiştirilebileceği için relocation olarak kalır.
+        if let Some((target_section, target_value)) = target {
+            if relative && target_section == section && !global {
+                value += target_value as i64 - offset as i64;
+                return self.patch(section, offset, size, value);
+            }
+        }
+
+        let reloc_kind = match (kind, size) {
+            (FixupKind::Branch, _) if target.is_none() || global => RelocKind::Plt32,
+            (FixupKind::Branch | FixupKind::PcRelative, 4) => RelocKind::Pc32,
+            (FixupKind::Absolute { .. }, 8) => RelocKind::Abs64,
+            (FixupKind::Absolute { signed: true }, 4) => RelocKind::Abs32S,
+            (FixupKind::Absolute { signed: false }, 4) => RelocKind::Abs32,
+            _ => return Err(format!("'{}' sembolü {} byte'lık bir alanda kullanılamaz", symbol, size)),
+        };
+        let (target, addend) = match target {
+            // Yerel semboller bölüm sembolüne göre yazılır (GAS ile aynı)
+            Some((target_section, target_value)) if !global => (RelocTarget::Section(target_section), value + target_value as i64),
+            _ => (RelocTarget::Symbol(symbol.clone()), value),
+        };
+        self.sections[section].relocs.push(Reloc { offset: offset as u64, target, kind: reloc_kind, addend });
+        Ok(())
+    }
+
+    fn patch(&mut self, section: usize, offset: usize, size: u8, value: i64) -> Result<(), String> {
+        if !encode::fits(value, size) {
+            return Err(format!("{} değeri {} byte'lık alana sığmıyor", value, size));
+        }
+        let size = size as usize;
+        self.sections[section].data[offset..offset + size].copy_from_slice(&value.to_le_bytes()[..size]);
+        Ok(())
+    }
+
+    // .loc satırlarından .debug_line programını, CFI bloklarından .eh_frame'i üretir.
+    fn debug_sections(&mut self) -> Result<(), String> {
+        if !self.rows.is_empty() {
+            let index = self.section(".debug_line", SectionKind::Debug);
+            let mut files = self.files.clone();
+            files.sort();
+            let base = self.sections[index].data.len() as u64;
+            let section_sizes: Vec<u64> = self.sections.iter().map(Section::size).collect();
+            let (bytes, relocs) = dwarf::line_program(&files, &self.rows, &section_sizes);
+            let section = &mut self.sections[index];
+            section.data.extend(bytes);
+            section.relocs.extend(relocs.into_iter().map(|r| Reloc { offset: r.offset + base, ..r }));
+        }
+        if !self.frames.is_empty() {
+            let index = self.section(".eh_frame", SectionKind::Unwind);
+            let (bytes, relocs) = dwarf::eh_frame(&self.frames);
+            let section = &mut self.sections[index];
+            if !section.data.is_empty() {
+                return Err("'.eh_frame' bölümü elle yazılamaz".to_string());
+            }
+            section.data = bytes;
+            section.relocs = relocs;
+            section.align = 8;
+        }
+        Ok(())
+    }
+}
+
+// Bölüm adı ve GAS bayraklarından ("awx") bölüm türü
+fn section_kind(name: &str, flags: Option<&str>) -> SectionKind {
+    match name {
+        ".text" => return SectionKind::Code,
+        ".data" => return SectionKind::Data,
+        ".bss" => return SectionKind::Bss,
+        ".rodata" => return SectionKind::ReadOnly,
+        ".eh_frame" => return SectionKind::Unwind,
+        _ if name.starts_with(".text.") => return SectionKind::Code,
+        _ if name.starts_with(".data.") => return SectionKind::Data,
+        _ if name.starts_with(".bss.") => return SectionKind::Bss,
+        _ if name.starts_with(".rodata.") => return SectionKind::ReadOnly,
+        _ if name.starts_with(".debug_") => return SectionKind::Debug,
+        _ if name.starts_with(".note") => return SectionKind::Note,
+        _ => {}
+    }
+    let flags = flags.unwrap_or("");
+    if flags.contains('x') {
+        SectionKind::Code
+    } else if flags.contains('w') {
+        SectionKind::Data
+    } else if flags.contains('a') {
+        SectionKind::ReadOnly
+    } else {
+        SectionKind::Note
+    }
+}
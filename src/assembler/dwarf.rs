@@ -0,0 +1,238 @@
+// src/assembler/dwarf.rs
+
+// Assembler'ın ürettiği DWARF bölümleri: `.loc` satırlarından .debug_line satır programı ve
+// `.cfi_*` direktiflerinden .eh_frame (CIE + fonksiyon başına FDE). Adresler ilgili kod bölümüne
+// göre relocation ile yazılır; bağlayıcı son adresleri doldurur.
+
+use super::{CfiOp, Frame, LineRow, Reloc, RelocKind, RelocTarget};
+
+pub(super) fn uleb128(out: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+pub(super) fn sleb128(out: &mut Vec<u8>, mut value: i64) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if done {
+            out.push(byte);
+            return;
+        }
+        out.push(byte | 0x80);
+    }
+}
+
+// x86-64 DWARF register numaraları (System V ABI)
+pub(super) fn register_number(name: &str) -> Option<u8> {
+    const NAMES: [&str; 17] = [
+        "rax", "rdx", "rcx", "rbx", "rsi", "rdi", "rbp", "rsp", "r8", "r9", "r10", "r11", "r12", "r13", "r14", "r15", "rip",
+    ];
+    let name = name.trim().to_ascii_lowercase();
+    NAMES.iter().position(|r| *r == name).map(|n| n as u8).or_else(|| name.parse().ok())
+}
+
+const DW_LNS_COPY: u8 = 1;
+const DW_LNS_ADVANCE_PC: u8 = 2;
+const DW_LNS_ADVANCE_LINE: u8 = 3;
+const DW_LNS_SET_FILE: u8 = 4;
+const DW_LNS_SET_COLUMN: u8 = 5;
+const DW_LNE_END_SEQUENCE: u8 = 1;
+const DW_LNE_SET_ADDRESS: u8 = 2;
+
+// Satır programı (DWARF 4). Her bölüm için bir dizi (sequence) üretilir; satırlar özel opcode'lar
+// yerine standart opcode'larla yazılır, bu tablo boyutunu büyütür ama üretimi basit tutar.
+pub(super) fn line_program(files: &[(u64, String)], rows: &[LineRow], section_sizes: &[u64]) -> (Vec<u8>, Vec<Reloc>) {
+    let mut header = vec![
+        1,          // minimum_instruction_length
+        1,          // maximum_operations_per_instruction
+        1,          // default_is_stmt
+        -5i8 as u8, // line_base
+        14,         // line_range
+        13,         // opcode_base
+    ];
+    header.extend_from_slice(&[0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1]); // standart opcode argüman sayıları
+    header.push(0); // include_directories (boş)
+    // Dosya numaraları 1'den başlar; boşluk bırakılan numaralar için yer tutucu yazılır.
+    let last = files.iter().map(|(n, _)| *n).max().unwrap_or(0);
+    for number in 1..=last {
+        let name = files.iter().find(|(n, _)| *n == number).map(|(_, name)| name.as_str()).unwrap_or("<bilinmiyor>");
+        header.extend_from_slice(name.as_bytes());
+        header.extend_from_slice(&[0, 0, 0, 0]); // NUL, dizin, değişiklik zamanı, boyut
+    }
+    header.push(0);
+
+    let mut program = Vec::new();
+    let mut relocs = Vec::new();
+    let mut sorted: Vec<&LineRow> = rows.iter().collect();
+    sorted.sort_by_key(|row| (row.section, row.offset));
+    // Alanlar (unit_length + version + header_length + başlık) sonrası ofset
+    let program_start = 4 + 2 + 4 + header.len() as u64;
+    let mut index = 0;
+    while index < sorted.len() {
+        let section = sorted[index].section;
+        let (mut address, mut file, mut line, mut column) = (sorted[index].offset, 1, 1, 0);
+        program.extend_from_slice(&[0, 9, DW_LNE_SET_ADDRESS]);
+        relocs.push(Reloc {
+            offset: program_start + program.len() as u64,
+            target: RelocTarget::Section(section),
+            kind: RelocKind::Abs64,
+            addend: address as i64,
+        });
+        program.extend_from_slice(&[0; 8]);
+        while index < sorted.len() && sorted[index].section == section {
+            let row = sorted[index];
+            if row.file != file {
+                program.push(DW_LNS_SET_FILE);
+                uleb128(&mut program, row.file);
+                file = row.file;
+            }
+            if row.column != column {
+                program.push(DW_LNS_SET_COLUMN);
+                uleb128(&mut program, row.column);
+                column = row.column;
+            }
+            if row.line != line {
+                program.push(DW_LNS_ADVANCE_LINE);
+                sleb128(&mut program, row.line as i64 - line as i64);
+                line = row.line;
+            }
+            if row.offset != address {
+                program.push(DW_LNS_ADVANCE_PC);
+                uleb128(&mut program, row.offset - address);
+                address = row.offset;
+            }
+            program.push(DW_LNS_COPY);
+            index += 1;
+        }
+        let end = section_sizes[section];
+        if end > address {
+            program.push(DW_LNS_ADVANCE_PC);
+            uleb128(&mut program, end - address);
+        }
+        program.extend_from_slice(&[0, 1, DW_LNE_END_SEQUENCE]);
+    }
+
+    let mut out = Vec::new();
+    let unit_length = 2 + 4 + header.len() + program.len();
+    out.extend_from_slice(&(unit_length as u32).to_le_bytes());
+    out.extend_from_slice(&4u16.to_le_bytes());
+    out.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    out.extend(header);
+    out.extend(program);
+    (out, relocs)
+}
+
+const DW_CFA_ADVANCE_LOC: u8 = 0x40;
+const DW_CFA_OFFSET: u8 = 0x80;
+const DW_CFA_ADVANCE_LOC1: u8 = 0x02;
+const DW_CFA_ADVANCE_LOC2: u8 = 0x03;
+const DW_CFA_ADVANCE_LOC4: u8 = 0x04;
+const DW_CFA_OFFSET_EXTENDED: u8 = 0x05;
+const DW_CFA_UNDEFINED: u8 = 0x07;
+const DW_CFA_DEF_CFA: u8 = 0x0c;
+const DW_CFA_DEF_CFA_REGISTER: u8 = 0x0d;
+const DW_CFA_DEF_CFA_OFFSET: u8 = 0x0e;
+
+const DATA_ALIGN: i64 = -8;
+const RETURN_REGISTER: u8 = 16;
+
+// Kaydı 8 byte'a hizalar (DW_CFA_nop ile) ve uzunluk alanını doldurur.
+fn close_record(out: &mut Vec<u8>, start: usize) {
+    while !(out.len() - start).is_multiple_of(8) {
+        out.push(0);
+    }
+    let length = (out.len() - start - 4) as u32;
+    out[start..start + 4].copy_from_slice(&length.to_le_bytes());
+}
+
+pub(super) fn eh_frame(frames: &[Frame]) -> (Vec<u8>, Vec<Reloc>) {
+    let mut out = Vec::new();
+    let mut relocs = Vec::new();
+
+    // CIE: "zR" artırımı, pc-göreli sdata4 adresler; başlangıçta CFA = rsp + 8, dönüş adresi CFA - 8'de
+    out.extend_from_slice(&[0; 4]);
+    out.extend_from_slice(&0u32.to_le_bytes()); // CIE kimliği
+    out.push(1); // sürüm
+    out.extend_from_slice(b"zR\0");
+    uleb128(&mut out, 1); // kod hizalama çarpanı
+    sleb128(&mut out, DATA_ALIGN);
+    out.push(RETURN_REGISTER);
+    uleb128(&mut out, 1); // artırım verisi uzunluğu
+    out.push(0x1b); // DW_EH_PE_pcrel | DW_EH_PE_sdata4
+    out.extend_from_slice(&[DW_CFA_DEF_CFA, 7, 8, DW_CFA_OFFSET | RETURN_REGISTER, 1]);
+    close_record(&mut out, 0);
+
+    for frame in frames {
+        let start = out.len();
+        out.extend_from_slice(&[0; 4]);
+        out.extend_from_slice(&((out.len()) as u32).to_le_bytes()); // CIE'ye geri ofset
+        relocs.push(Reloc {
+            offset: out.len() as u64,
+            target: RelocTarget::Section(frame.section),
+            kind: RelocKind::Pc32,
+            addend: frame.start as i64,
+        });
+        out.extend_from_slice(&[0; 4]);
+        out.extend_from_slice(&((frame.end - frame.start) as u32).to_le_bytes());
+        uleb128(&mut out, 0); // artırım verisi yok
+
+        let mut location = frame.start;
+        for (offset, op) in &frame.ops {
+            let delta = offset - location;
+            if delta > 0 {
+                if delta < 0x40 {
+                    out.push(DW_CFA_ADVANCE_LOC | delta as u8);
+                } else if delta <= u8::MAX as u64 {
+                    out.extend_from_slice(&[DW_CFA_ADVANCE_LOC1, delta as u8]);
+                } else if delta <= u16::MAX as u64 {
+                    out.push(DW_CFA_ADVANCE_LOC2);
+                    out.extend_from_slice(&(delta as u16).to_le_bytes());
+                } else {
+                    out.push(DW_CFA_ADVANCE_LOC4);
+                    out.extend_from_slice(&(delta as u32).to_le_bytes());
+                }
+                location = *offset;
+            }
+            match *op {
+                CfiOp::DefCfa(reg, offset) => {
+                    out.push(DW_CFA_DEF_CFA);
+                    uleb128(&mut out, reg as u64);
+                    uleb128(&mut out, offset);
+                }
+                CfiOp::DefCfaOffset(offset) => {
+                    out.push(DW_CFA_DEF_CFA_OFFSET);
+                    uleb128(&mut out, offset);
+                }
+                CfiOp::DefCfaRegister(reg) => {
+                    out.push(DW_CFA_DEF_CFA_REGISTER);
+                    uleb128(&mut out, reg as u64);
+                }
+                CfiOp::Offset(reg, offset) => {
+                    let factored = (offset / DATA_ALIGN) as u64;
+                    if reg < 0x40 {
+                        out.push(DW_CFA_OFFSET | reg);
+                    } else {
+                        out.push(DW_CFA_OFFSET_EXTENDED);
+                        uleb128(&mut out, reg as u64);
+                    }
+                    uleb128(&mut out, factored);
+                }
+                CfiOp::Undefined(reg) => {
+                    out.push(DW_CFA_UNDEFINED);
+                    uleb128(&mut out, reg as u64);
+                }
+            }
+        }
+        close_record(&mut out, start);
+    }
+    (out, relocs)
+}
@@ -0,0 +1,380 @@
+// src/assembler/elf.rs
+
+// ELF64 relocatable (ET_REL) nesne dosyası yazıcısı ve okuyucusu. Yazılan bölüm sırası:
+// null, içerik bölümleri, her relocation'lı bölüm için .rela<ad>, .symtab, .strtab, .shstrtab.
+// Symtab'da önce yerel semboller (bölüm sembolleri, .L olmayan yerel etiketler), sonra global'ler gelir.
+// Okuyucu, yerleşik linker için nesne dosyalarını (bizim veya GAS'ın ürettiği) tekrar `Object`'e çevirir.
+
+use super::{Object, Reloc, RelocKind, RelocTarget, Section, SectionKind, Symbol};
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_RELA: u32 = 4;
+const SHT_NOBITS: u32 = 8;
+const SHT_X86_64_UNWIND: u32 = 0x7000_0001;
+
+const SHF_WRITE: u64 = 0x1;
+const SHF_ALLOC: u64 = 0x2;
+const SHF_EXECINSTR: u64 = 0x4;
+const SHF_INFO_LINK: u64 = 0x40;
+
+const SHN_UNDEF: u16 = 0;
+const SHN_ABS: u16 = 0xfff1;
+const SHN_COMMON: u16 = 0xfff2;
+
+const STB_LOCAL: u8 = 0;
+const STB_GLOBAL: u8 = 1;
+const STT_NOTYPE: u8 = 0;
+const STT_FUNC: u8 = 2;
+const STT_SECTION: u8 = 3;
+const STT_FILE: u8 = 4;
+
+fn reloc_type(kind: RelocKind) -> u32 {
+    match kind {
+        RelocKind::Abs64 => 1,  // R_X86_64_64
+        RelocKind::Pc32 => 2,   // R_X86_64_PC32
+        RelocKind::Plt32 => 4,  // R_X86_64_PLT32
+        RelocKind::Abs32 => 10, // R_X86_64_32
+        RelocKind::Abs32S => 11, // R_X86_64_32S
+    }
+}
+
+fn reloc_kind(kind: u32) -> Option<RelocKind> {
+    Some(match kind {
+        1 => RelocKind::Abs64,
+        2 => RelocKind::Pc32,
+        4 => RelocKind::Plt32,
+        10 => RelocKind::Abs32,
+        11 => RelocKind::Abs32S,
+        _ => return None,
+    })
+}
+
+// İsimleri NUL ile ayrılmış tabloya ekler; ilk byte boş isimdir.
+struct StringTable(Vec<u8>);
+
+impl StringTable {
+    fn new() -> Self {
+        StringTable(vec![0])
+    }
+
+    fn add(&mut self, name: &str) -> u32 {
+        let offset = self.0.len() as u32;
+        self.0.extend_from_slice(name.as_bytes());
+        self.0.push(0);
+        offset
+    }
+}
+
+struct SectionHeader {
+    name: u32,
+    kind: u32,
+    flags: u64,
+    offset: u64,
+    size: u64,
+    link: u32,
+    info: u32,
+    align: u64,
+    entsize: u64,
+}
+
+pub(super) fn write(object: &Object) -> Vec<u8> {
+    let mut shstrtab = StringTable::new();
+    let mut strtab = StringTable::new();
+    let mut out = vec![0u8; 64];
+    let mut headers = vec![SectionHeader { name: 0, kind: 0, flags: 0, offset: 0, size: 0, link: 0, info: 0, align: 0, entsize: 0 }];
+
+    // İçerik bölümleri: ELF bölüm numarası = dizin + 1
+    for section in &object.sections {
+        let (kind, flags) = match section.kind {
+            SectionKind::Code => (SHT_PROGBITS, SHF_ALLOC | SHF_EXECINSTR),
+            SectionKind::Data => (SHT_PROGBITS, SHF_ALLOC | SHF_WRITE),
+            SectionKind::ReadOnly => (SHT_PROGBITS, SHF_ALLOC),
+            SectionKind::Bss => (SHT_NOBITS, SHF_ALLOC | SHF_WRITE),
+            SectionKind::Debug | SectionKind::Note => (SHT_PROGBITS, 0),
+            SectionKind::Unwind => (SHT_X86_64_UNWIND, SHF_ALLOC),
+        };
+        align(&mut out, section.align.max(1));
+        let offset = out.len() as u64;
+        out.extend_from_slice(&section.data);
+        headers.push(SectionHeader {
+            name: shstrtab.add(&section.name),
+            kind,
+            flags,
+            offset,
+            size: section.size(),
+            link: 0,
+            info: 0,
+            align: section.align.max(1),
+            entsize: 0,
+        });
+    }
+
+    // Symtab: null, bölüm sembolleri, yerel etiketler, global'ler
+    let mut symtab = vec![0u8; 24];
+    let mut symbol_index = std::collections::HashMap::new();
+    for index in 0..object.sections.len() {
+        push_symbol(&mut symtab, 0, STB_LOCAL << 4 | STT_SECTION, index as u16 + 1, 0);
+    }
+    let mut count = 1 + object.sections.len();
+    let ordered = object.symbols.iter().filter(|s| !s.global).chain(object.symbols.iter().filter(|s| s.global));
+    let mut first_global = None;
+    for symbol in ordered {
+        if symbol.global && first_global.is_none() {
+            first_global = Some(count);
+        }
+        let binding = if symbol.global { STB_GLOBAL } else { STB_LOCAL };
+        let kind = if symbol.function { STT_FUNC } else { STT_NOTYPE };
+        let shndx = symbol.section.map_or(0, |s| s as u16 + 1);
+        push_symbol(&mut symtab, strtab.add(&symbol.name), binding << 4 | kind, shndx, symbol.value);
+        symbol_index.insert(symbol.name.as_str(), count);
+        count += 1;
+    }
+    let first_global = first_global.unwrap_or(count);
+
+    let symtab_header = headers.len() + object.sections.iter().filter(|s| !s.relocs.is_empty()).count();
+    for (index, section) in object.sections.iter().enumerate() {
+        if section.relocs.is_empty() {
+            continue;
+        }
+        align(&mut out, 8);
+        let offset = out.len() as u64;
+        for reloc in &section.relocs {
+            let symbol = match &reloc.target {
+                RelocTarget::Section(s) => s + 1,
+                RelocTarget::Symbol(name) => symbol_index[name.as_str()],
+            };
+            out.extend_from_slice(&reloc.offset.to_le_bytes());
+            out.extend_from_slice(&((symbol as u64) << 32 | reloc_type(reloc.kind) as u64).to_le_bytes());
+            out.extend_from_slice(&reloc.addend.to_le_bytes());
+        }
+        headers.push(SectionHeader {
+            name: shstrtab.add(&format!(".rela{}", section.name)),
+            kind: SHT_RELA,
+            flags: SHF_INFO_LINK,
+            offset,
+            size: section.relocs.len() as u64 * 24,
+            link: symtab_header as u32,
+            info: index as u32 + 1,
+            align: 8,
+            entsize: 24,
+        });
+    }
+
+    align(&mut out, 8);
+    headers.push(SectionHeader {
+        name: shstrtab.add(".symtab"),
+        kind: SHT_SYMTAB,
+        flags: 0,
+        offset: out.len() as u64,
+        size: symtab.len() as u64,
+        link: symtab_header as u32 + 1,
+        info: first_global as u32,
+        align: 8,
+        entsize: 24,
+    });
+    out.extend_from_slice(&symtab);
+    headers.push(SectionHeader {
+        name: shstrtab.add(".strtab"),
+        kind: SHT_STRTAB,
+        flags: 0,
+        offset: out.len() as u64,
+        size: strtab.0.len() as u64,
+        link: 0,
+        info: 0,
+        align: 1,
+        entsize: 0,
+    });
+    out.extend_from_slice(&strtab.0);
+    let shstrtab_name = shstrtab.add(".shstrtab");
+    headers.push(SectionHeader {
+        name: shstrtab_name,
+        kind: SHT_STRTAB,
+        flags: 0,
+        offset: out.len() as u64,
+        size: shstrtab.0.len() as u64,
+        link: 0,
+        info: 0,
+        align: 1,
+        entsize: 0,
+    });
+    out.extend_from_slice(&shstrtab.0);
+
+    align(&mut out, 8);
+    let section_headers = out.len() as u64;
+    for header in &headers {
+        out.extend_from_slice(&header.name.to_le_bytes());
+        out.extend_from_slice(&header.kind.to_le_bytes());
+        out.extend_from_slice(&header.flags.to_le_bytes());
+        out.extend_from_slice(&0u64.to_le_bytes()); // adres
+        out.extend_from_slice(&header.offset.to_le_bytes());
+        out.extend_from_slice(&header.size.to_le_bytes());
+        out.extend_from_slice(&header.link.to_le_bytes());
+        out.extend_from_slice(&header.info.to_le_bytes());
+        out.extend_from_slice(&header.align.to_le_bytes());
+        out.extend_from_slice(&header.entsize.to_le_bytes());
+    }
+
+    // ELF başlığı
+    let mut header = Vec::with_capacity(64);
+    header.extend_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1, 1, 0]); // 64 bit, little endian, System V
+    header.extend_from_slice(&[0; 8]);
+    header.extend_from_slice(&1u16.to_le_bytes()); // ET_REL
+    header.extend_from_slice(&62u16.to_le_bytes()); // EM_X86_64
+    header.extend_from_slice(&1u32.to_le_bytes());
+    header.extend_from_slice(&0u64.to_le_bytes()); // giriş noktası
+    header.extend_from_slice(&0u64.to_le_bytes()); // program başlıkları
+    header.extend_from_slice(&section_headers.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes()); // bayraklar
+    header.extend_from_slice(&64u16.to_le_bytes());
+    header.extend_from_slice(&0u16.to_le_bytes());
+    header.extend_from_slice(&0u16.to_le_bytes());
+    header.extend_from_slice(&64u16.to_le_bytes());
+    header.extend_from_slice(&(headers.len() as u16).to_le_bytes());
+    header.extend_from_slice(&(headers.len() as u16 - 1).to_le_bytes()); // .shstrtab en sonda
+    out[..64].copy_from_slice(&header);
+    out
+}
+
+fn push_symbol(symtab: &mut Vec<u8>, name: u32, info: u8, shndx: u16, value: u64) {
+    symtab.extend_from_slice(&name.to_le_bytes());
+    symtab.push(info);
+    symtab.push(0); // görünürlük: default
+    symtab.extend_from_slice(&shndx.to_le_bytes());
+    symtab.extend_from_slice(&value.to_le_bytes());
+    symtab.extend_from_slice(&0u64.to_le_bytes()); // boyut
+}
+
+fn align(out: &mut Vec<u8>, alignment: u64) {
+    let len = (out.len() as u64).next_multiple_of(alignment);
+    out.resize(len as usize, 0);
+}
+
+// Okuma yardımcıları: sınır dışı erişim hata döner.
+fn bytes_at(data: &[u8], offset: u64, len: u64) -> Result<&[u8], String> {
+    let end = offset.checked_add(len).filter(|end| *end <= data.len() as u64).ok_or("Nesne dosyası bozuk (sınır dışı okuma)")?;
+    Ok(&data[offset as usize..end as usize])
+}
+
+fn u16_at(data: &[u8], offset: u64) -> Result<u16, String> {
+    Ok(u16::from_le_bytes(bytes_at(data, offset, 2)?.try_into().expect("2 byte")))
+}
+
+fn u32_at(data: &[u8], offset: u64) -> Result<u32, String> {
+    Ok(u32::from_le_bytes(bytes_at(data, offset, 4)?.try_into().expect("4 byte")))
+}
+
+fn u64_at(data: &[u8], offset: u64) -> Result<u64, String> {
+    Ok(u64::from_le_bytes(bytes_at(data, offset, 8)?.try_into().expect("8 byte")))
+}
+
+fn name_at(table: &[u8], offset: u32) -> String {
+    let start = (offset as usize).min(table.len());
+    let end = table[start..].iter().position(|b| *b == 0).map_or(table.len(), |p| start + p);
+    String::from_utf8_lossy(&table[start..end]).into_owned()
+}
+
+// x86-64 ELF64 relocatable nesne dosyasını okur.
+pub(super) fn read(data: &[u8]) -> Result<Object, String> {
+    if data.len() < 64 || data[..4] != [0x7f, b'E', b'L', b'F'] {
+        return Err("ELF dosyası değil".to_string());
+    }
+    if data[4] != 2 || data[5] != 1 || u16_at(data, 16)? != 1 || u16_at(data, 18)? != 62 {
+        return Err("Yalnızca x86-64 ELF64 relocatable (.o) dosyaları desteklenir".to_string());
+    }
+    let section_headers = u64_at(data, 40)?;
+    let count = u16_at(data, 60)? as u64;
+    let names_index = u16_at(data, 62)? as u64;
+    let header = |index: u64| -> Result<SectionHeader, String> {
+        let base = section_headers + index * 64;
+        Ok(SectionHeader {
+            name: u32_at(data, base)?,
+            kind: u32_at(data, base + 4)?,
+            flags: u64_at(data, base + 8)?,
+            offset: u64_at(data, base + 24)?,
+            size: u64_at(data, base + 32)?,
+            link: u32_at(data, base + 40)?,
+            info: u32_at(data, base + 44)?,
+            align: u64_at(data, base + 48)?,
+            entsize: u64_at(data, base + 56)?,
+        })
+    };
+    let headers: Vec<SectionHeader> = (0..count).map(header).collect::<Result<_, _>>()?;
+    let names = headers.get(names_index as usize).ok_or("Bölüm isim tablosu eksik")?;
+    let names = bytes_at(data, names.offset, names.size)?;
+
+    // İçerik bölümleri: ELF bölüm numarası -> Object bölüm dizini
+    let mut object = Object::default();
+    let mut mapping = vec![None; headers.len()];
+    for (index, header) in headers.iter().enumerate() {
+        let name = name_at(names, header.name);
+        let kind = match header.kind {
+            SHT_NOBITS => SectionKind::Bss,
+            SHT_X86_64_UNWIND => SectionKind::Unwind,
+            SHT_PROGBITS if header.flags & SHF_EXECINSTR != 0 => SectionKind::Code,
+            SHT_PROGBITS if header.flags & SHF_WRITE != 0 => SectionKind::Data,
+            SHT_PROGBITS if header.flags & SHF_ALLOC != 0 => SectionKind::ReadOnly,
+            SHT_PROGBITS if name == ".eh_frame" => SectionKind::Unwind,
+            SHT_PROGBITS if name.starts_with(".debug_") => SectionKind::Debug,
+            SHT_PROGBITS => SectionKind::Note,
+            _ => continue,
+        };
+        let mut section = Section::new(&name, kind);
+        section.align = header.align.max(1);
+        if kind == SectionKind::Bss {
+            section.bss_size = header.size;
+        } else {
+            section.data = bytes_at(data, header.offset, header.size)?.to_vec();
+        }
+        mapping[index] = Some(object.sections.len());
+        object.sections.push(section);
+    }
+
+    // Sembol tablosu: bölüm sembolleri relocation hedefi olarak bölüme çevrilir.
+    let mut targets: Vec<Option<RelocTarget>> = Vec::new();
+    if let Some(symtab) = headers.iter().find(|h| h.kind == SHT_SYMTAB) {
+        let strings = headers.get(symtab.link as usize).ok_or("Sembol isim tablosu eksik")?;
+        let strings = bytes_at(data, strings.offset, strings.size)?;
+        for index in 0..symtab.size / 24 {
+            let base = symtab.offset + index * 24;
+            let name = name_at(strings, u32_at(data, base)?);
+            let info = *bytes_at(data, base + 4, 1)?.first().expect("1 byte");
+            let shndx = u16_at(data, base + 6)?;
+            let value = u64_at(data, base + 8)?;
+            let (binding, kind) = (info >> 4, info & 0xf);
+            if index == 0 || kind == STT_FILE || shndx == SHN_ABS {
+                targets.push(None);
+                continue;
+            }
+            if shndx == SHN_COMMON {
+                return Err(format!("COMMON sembolü desteklenmiyor: '{}'", name));
+            }
+            let section = if shndx == SHN_UNDEF { None } else { mapping.get(shndx as usize).copied().flatten() };
+            if kind == STT_SECTION {
+                targets.push(section.map(RelocTarget::Section));
+                continue;
+            }
+            if shndx != SHN_UNDEF && section.is_none() {
+                targets.push(None);
+                continue;
+            }
+            let global = binding != STB_LOCAL;
+            object.symbols.push(Symbol { name: name.clone(), section, value, global, function: kind == STT_FUNC });
+            targets.push(Some(RelocTarget::Symbol(name)));
+        }
+    }
+
+    for header in headers.iter().filter(|h| h.kind == SHT_RELA) {
+        let Some(section) = mapping.get(header.info as usize).copied().flatten() else { continue };
+        for index in 0..header.size / 24 {
+            let base = header.offset + index * 24;
+            let info = u64_at(data, base + 8)?;
+            let kind = reloc_kind(info as u32).ok_or_else(|| format!("Desteklenmeyen relocation türü: {}", info as u32))?;
+            let target = targets.get((info >> 32) as usize).cloned().flatten().ok_or("Relocation geçersiz bir sembole başvuruyor")?;
+            let addend = u64_at(data, base + 16)? as i64;
+            object.sections[section].relocs.push(Reloc { offset: u64_at(data, base)?, target, kind, addend });
+        }
+    }
+    Ok(object)
+}
@@ -0,0 +1,675 @@
+// src/assembler/encode.rs
+
+// x86-64 komut kodlayıcı. Her komut tek başına kodlanır: sembollere başvuran alanlar (adres
+// yer değiştirmesi, anlık değer, dallanma hedefi) sıfır ile doldurulur ve bir `Fixup` olarak
+// döndürülür; değerleri tüm etiketler bilindiğinde veya bağlayıcı (relocation) tarafından yazılır.
+// Dallanmalar her zaman rel32 biçimindedir, böylece komut boyları etiket adreslerinden bağımsızdır.
+
+use super::parse::{Expr, Mem, Operand, Reg};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum FixupKind {
+    // Mutlak değer; `signed` ise 64 bite işaret genişletilerek kullanılır (R_X86_64_32S)
+    Absolute { signed: bool },
+    // Komutun sonuna göre göreli (rip göreli adresler)
+    PcRelative,
+    // call/jmp/jcc hedefi (tanımsız semboller için PLT32)
+    Branch,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Fixup {
+    // Komut içindeki alan ofseti ve boyutu
+    pub offset: usize,
+    pub size: u8,
+    pub expr: Expr,
+    pub kind: FixupKind,
+}
+
+#[derive(Debug, Default)]
+pub(super) struct Encoded {
+    pub bytes: Vec<u8>,
+    pub fixups: Vec<Fixup>,
+}
+
+// Koşul kodu son ekleri (jcc/setcc/cmovcc)
+fn condition(suffix: &str) -> Option<u8> {
+    Some(match suffix {
+        "o" => 0,
+        "no" => 1,
+        "b" | "c" | "nae" => 2,
+        "ae" | "nb" | "nc" => 3,
+        "e" | "z" => 4,
+        "ne" | "nz" => 5,
+        "be" | "na" => 6,
+        "a" | "nbe" => 7,
+        "s" => 8,
+        "ns" => 9,
+        "p" | "pe" => 10,
+        "np" | "po" => 11,
+        "l" | "nge" => 12,
+        "ge" | "nl" => 13,
+        "le" | "ng" => 14,
+        "g" | "nle" => 15,
+        _ => return None,
+    })
+}
+
+// Kodlanacak komutun parçaları: önekler, REX, opcode, ModRM (+SIB/disp), anlık değer.
+struct Builder {
+    prefixes: Vec<u8>,
+    rex_w: bool,
+    // REX gerektiren (spl..dil) / REX ile kullanılamayan (ah..bh) byte register'ları
+    force_rex: bool,
+    forbid_rex: bool,
+    opcode: Vec<u8>,
+    reg: u8,
+    rm: Option<Rm>,
+    // Opcode'a eklenen register (push r, mov r, imm, ...)
+    opcode_reg: Option<u8>,
+    imm: Option<(Expr, u8, FixupKind)>,
+}
+
+enum Rm {
+    Reg(u8),
+    Mem(Mem),
+}
+
+impl Builder {
+    fn new(opcode: &[u8]) -> Self {
+        Builder {
+            prefixes: Vec::new(),
+            rex_w: false,
+            force_rex: false,
+            forbid_rex: false,
+            opcode: opcode.to_vec(),
+            reg: 0,
+            rm: None,
+            opcode_reg: None,
+            imm: None,
+        }
+    }
+
+    fn prefix(mut self, prefix: u8) -> Self {
+        self.prefixes.push(prefix);
+        self
+    }
+
+    // İşlenen boyutuna göre 0x66 öneki ve REX.W
+    fn size(mut self, size: u8) -> Self {
+        match size {
+            2 => self.prefixes.push(0x66),
+            8 => self.rex_w = true,
+            _ => {}
+        }
+        self
+    }
+
+    fn w(mut self, w: bool) -> Self {
+        self.rex_w = w;
+        self
+    }
+
+    fn note_reg(&mut self, reg: &Reg) {
+        self.force_rex |= reg.rex8;
+        self.forbid_rex |= reg.high8;
+    }
+
+    fn reg(mut self, reg: &Reg) -> Self {
+        self.note_reg(reg);
+        self.reg = reg.num;
+        self
+    }
+
+    fn ext(mut self, digit: u8) -> Self {
+        self.reg = digit;
+        self
+    }
+
+    fn rm(mut self, op: &Operand) -> Self {
+        match op {
+            Operand::Reg(reg) => {
+                self.note_reg(reg);
+                self.rm = Some(Rm::Reg(reg.num));
+            }
+            Operand::Mem(mem) => self.rm = Some(Rm::Mem(mem.clone())),
+            Operand::Imm(_) | Operand::Symbol(_) => unreachable!("rm işleneni register veya bellek olmalı"),
+        }
+        self
+    }
+
+    fn opcode_reg(mut self, reg: &Reg) -> Self {
+        self.note_reg(reg);
+        self.opcode_reg = Some(reg.num);
+        self
+    }
+
+    fn imm(mut self, expr: &Expr, size: u8, signed: bool) -> Self {
+        self.imm = Some((expr.clone(), size, FixupKind::Absolute { signed }));
+        self
+    }
+
+    fn build(self) -> Result<Encoded, String> {
+        let mut out = Encoded::default();
+        out.bytes.extend(&self.prefixes);
+
+        let (mut rex_r, mut rex_x, mut rex_b) = (false, false, false);
+        rex_r |= self.reg >= 8;
+        if let Some(reg) = self.opcode_reg {
+            rex_b |= reg >= 8;
+        }
+        match &self.rm {
+            Some(Rm::Reg(num)) => rex_b |= *num >= 8,
+            Some(Rm::Mem(mem)) => {
+                rex_b |= mem.base.is_some_and(|b| b.num >= 8);
+                rex_x |= mem.index.is_some_and(|(i, _)| i.num >= 8);
+            }
+            None => {}
+        }
+        let rex = 0x40 | (self.rex_w as u8) << 3 | (rex_r as u8) << 2 | (rex_x as u8) << 1 | rex_b as u8;
+        if rex != 0x40 || self.force_rex {
+            if self.forbid_rex {
+                return Err("ah/bh/ch/dh, REX öneki gerektiren bir komutta kullanılamaz".to_string());
+            }
+            out.bytes.push(rex);
+        }
+
+        out.bytes.extend(&self.opcode);
+        if let Some(reg) = self.opcode_reg {
+            *out.bytes.last_mut().expect("opcode") += reg & 7;
+        }
+
+        match self.rm {
+            Some(Rm::Reg(num)) => out.bytes.push(0xC0 | (self.reg & 7) << 3 | (num & 7)),
+            Some(Rm::Mem(mem)) => encode_memory(&mut out, self.reg & 7, &mem)?,
+            None => {}
+        }
+
+        if let Some((expr, size, kind)) = self.imm {
+            push_field(&mut out, &expr, size, kind)?;
+        }
+
+        // rip göreli alanlar komutun sonuna göredir (S + A - P); alanın kendisi ve ardından gelen
+        // anlık değer de addend'e katılır.
+        let len = out.bytes.len();
+        for fixup in &mut out.fixups {
+            if fixup.kind == FixupKind::PcRelative {
+                fixup.expr.constant -= (len - fixup.offset) as i64;
+            }
+        }
+        Ok(out)
+    }
+}
+
+// Sabitse değeri yazar; değilse yerini sıfırla doldurup fixup ekler.
+fn push_field(out: &mut Encoded, expr: &Expr, size: u8, kind: FixupKind) -> Result<(), String> {
+    match expr.as_constant() {
+        Some(value) if kind != FixupKind::PcRelative => {
+            if !fits(value, size) {
+                return Err(format!("{} değeri {} byte'lık alana sığmıyor", value, size));
+            }
+            out.bytes.extend_from_slice(&value.to_le_bytes()[..size as usize]);
+        }
+        _ => {
+            out.fixups.push(Fixup { offset: out.bytes.len(), size, expr: expr.clone(), kind });
+            out.bytes.extend(std::iter::repeat_n(0, size as usize));
+        }
+    }
+    Ok(())
+}
+
+// Değer, `size` byte'a işaretli veya işaretsiz olarak sığar mı?
+pub(super) fn fits(value: i64, size: u8) -> bool {
+    match size {
+        1 => (-128..=255).contains(&value),
+        2 => (-32768..=65535).contains(&value),
+        4 => (i32::MIN as i64..=u32::MAX as i64).contains(&value),
+        _ => true,
+    }
+}
+
+fn fits_i8(value: i64) -> bool {
+    (-128..=127).contains(&value)
+}
+
+fn fits_i32(value: i64) -> bool {
+    (i32::MIN as i64..=i32::MAX as i64).contains(&value)
+}
+
+fn encode_memory(out: &mut Encoded, reg: u8, mem: &Mem) -> Result<(), String> {
+    let reg = reg << 3;
+    if mem.rip {
+        out.bytes.push(reg | 0b101);
+        // rip + sabit: göreli değer doğrudan yazılır
+        let kind = if mem.disp.as_constant().is_some() { FixupKind::Absolute { signed: true } } else { FixupKind::PcRelative };
+        return push_field(out, &mem.disp, 4, kind);
+    }
+    let symbolic = mem.disp.as_constant().is_none();
+    let disp = mem.disp.constant;
+    if !symbolic && !fits_i32(disp) {
+        return Err(format!("Adres ofseti 32 bite sığmıyor: {}", disp));
+    }
+    let absolute = FixupKind::Absolute { signed: true };
+
+    let Some(base) = mem.base else {
+        // Tabansız adres: SIB ile mutlak disp32 (indeks varsa indeks*ölçek + disp32)
+        let (index, scale) = match mem.index {
+            Some((index, scale)) => (index.num & 7, scale),
+            None => (0b100, 1),
+        };
+        if mem.index.is_some_and(|(i, _)| i.num == 4) {
+            return Err("rsp indeks register'ı olamaz".to_string());
+        }
+        out.bytes.push(reg | 0b100);
+        out.bytes.push(scale_bits(scale) << 6 | index << 3 | 0b101);
+        return push_field(out, &mem.disp, 4, absolute);
+    };
+
+    let (mode, disp_size) = if symbolic || !fits_i8(disp) {
+        (0b10, 4)
+    } else if disp == 0 && base.num & 7 != 5 {
+        (0b00, 0)
+    } else {
+        (0b01, 1)
+    };
+    match mem.index {
+        Some((index, scale)) => {
+            if index.num == 4 {
+                return Err("rsp indeks register'ı olamaz".to_string());
+            }
+            out.bytes.push(mode << 6 | reg | 0b100);
+            out.bytes.push(scale_bits(scale) << 6 | (index.num & 7) << 3 | (base.num & 7));
+        }
+        None if base.num & 7 == 4 => {
+            out.bytes.push(mode << 6 | reg | 0b100);
+            out.bytes.push(0x24);
+        }
+        None => out.bytes.push(mode << 6 | reg | (base.num & 7)),
+    }
+    match disp_size {
+        0 => Ok(()),
+        1 => {
+            out.bytes.push(disp as u8);
+            Ok(())
+        }
+        _ => push_field(out, &mem.disp, 4, absolute),
+    }
+}
+
+fn scale_bits(scale: u8) -> u8 {
+    match scale {
+        1 => 0,
+        2 => 1,
+        4 => 2,
+        _ => 3,
+    }
+}
+
+// İşlenenin boyutu: register'ın kendisi veya belleğin `ptr` boyutu
+fn operand_size(op: &Operand) -> Option<u8> {
+    match op {
+        Operand::Reg(reg) => Some(reg.size),
+        Operand::Mem(mem) => mem.size,
+        Operand::Imm(_) | Operand::Symbol(_) => None,
+    }
+}
+
+fn is_rm(op: &Operand) -> bool {
+    matches!(op, Operand::Reg(r) if !r.xmm) || matches!(op, Operand::Mem(_))
+}
+
+fn is_xmm_or_mem(op: &Operand) -> bool {
+    matches!(op, Operand::Reg(r) if r.xmm) || matches!(op, Operand::Mem(_))
+}
+
+fn gpr(op: &Operand) -> Option<&Reg> {
+    match op {
+        Operand::Reg(reg) if !reg.xmm => Some(reg),
+        _ => None,
+    }
+}
+
+fn xmm(op: &Operand) -> Option<&Reg> {
+    match op {
+        Operand::Reg(reg) if reg.xmm => Some(reg),
+        _ => None,
+    }
+}
+
+// İki işlenenli komutta ortak boyut (register boyutu veya `ptr` boyutu)
+fn common_size(dst: &Operand, src: &Operand) -> Result<u8, String> {
+    match (operand_size(dst), operand_size(src)) {
+        (Some(a), Some(b)) if a != b => Err("İşlenen boyutları uyuşmuyor".to_string()),
+        (Some(a), _) | (None, Some(a)) => Ok(a),
+        (None, None) => Err("İşlenen boyutu belirsiz; 'qword ptr' gibi bir boyut belirtin".to_string()),
+    }
+}
+
+fn unsupported(mnemonic: &str) -> String {
+    format!("'{}' komutu bu işlenenlerle desteklenmiyor", mnemonic)
+}
+
+pub(super) fn encode(mnemonic: &str, prefix: Option<&str>, ops: &[Operand]) -> Result<Encoded, String> {
+    if let Some(prefix) = prefix {
+        let byte = match prefix {
+            "rep" | "repe" | "repz" => 0xF3,
+            "repne" | "repnz" => 0xF2,
+            _ => 0xF0, // lock
+        };
+        let mut inner = encode(mnemonic, None, ops)?;
+        inner.bytes.insert(0, byte);
+        for fixup in &mut inner.fixups {
+            fixup.offset += 1;
+        }
+        return Ok(inner);
+    }
+
+    let alu = ["add", "or", "adc", "sbb", "and", "sub", "xor", "cmp"];
+    if let Some(n) = alu.iter().position(|m| *m == mnemonic) {
+        return encode_alu(mnemonic, n as u8, ops);
+    }
+    let shifts = [("rol", 0), ("ror", 1), ("rcl", 2), ("rcr", 3), ("shl", 4), ("sal", 4), ("shr", 5), ("sar", 7)];
+    if let Some((_, n)) = shifts.iter().find(|(m, _)| *m == mnemonic) {
+        return encode_shift(mnemonic, *n, ops);
+    }
+    let sse_arith = [
+        ("addsd", 0xF2, 0x58), ("mulsd", 0xF2, 0x59), ("subsd", 0xF2, 0x5C), ("divsd", 0xF2, 0x5E),
+        ("sqrtsd", 0xF2, 0x51), ("minsd", 0xF2, 0x5D), ("maxsd", 0xF2, 0x5F),
+        ("addss", 0xF3, 0x58), ("mulss", 0xF3, 0x59), ("subss", 0xF3, 0x5C), ("divss", 0xF3, 0x5E),
+        ("sqrtss", 0xF3, 0x51), ("cvtss2sd", 0xF3, 0x5A), ("cvtsd2ss", 0xF2, 0x5A),
+        ("ucomisd", 0x66, 0x2E), ("comisd", 0x66, 0x2F), ("xorpd", 0x66, 0x57), ("andpd", 0x66, 0x54),
+        ("andnpd", 0x66, 0x55), ("orpd", 0x66, 0x56), ("pxor", 0x66, 0xEF), ("addpd", 0x66, 0x58),
+        ("ucomiss", 0, 0x2E), ("comiss", 0, 0x2F), ("xorps", 0, 0x57), ("andps", 0, 0x54),
+    ];
+    if let Some((_, prefix, op)) = sse_arith.iter().find(|(m, _, _)| *m == mnemonic) {
+        let [dst, src] = ops else { return Err(unsupported(mnemonic)) };
+        let (Some(dst), true) = (xmm(dst), is_xmm_or_mem(src)) else { return Err(unsupported(mnemonic)) };
+        let b = Builder::new(&[0x0F, *op]).reg(dst).rm(src);
+        return if *prefix == 0 { b.build() } else { b.prefix(*prefix).build() };
+    }
+    let sse_moves = [
+        ("movsd", 0xF2, 0x10, 0x11), ("movss", 0xF3, 0x10, 0x11), ("movapd", 0x66, 0x28, 0x29), ("movaps", 0, 0x28, 0x29),
+        ("movupd", 0x66, 0x10, 0x11), ("movups", 0, 0x10, 0x11), ("movdqu", 0xF3, 0x6F, 0x7F), ("movdqa", 0x66, 0x6F, 0x7F),
+    ];
+    if let Some((_, prefix, load, store)) = sse_moves.iter().find(|(m, _, _, _)| *m == mnemonic) {
+        // İşlenensiz movsd, string komutudur (aşağıda)
+        if !(mnemonic == "movsd" && ops.is_empty()) {
+            let b = match ops {
+                [dst, src] if xmm(dst).is_some() && is_xmm_or_mem(src) => Builder::new(&[0x0F, *load]).reg(xmm(dst).unwrap()).rm(src),
+                [dst @ Operand::Mem(_), src] if xmm(src).is_some() => Builder::new(&[0x0F, *store]).reg(xmm(src).unwrap()).rm(dst),
+                _ => return Err(unsupported(mnemonic)),
+            };
+            return if *prefix == 0 { b.build() } else { b.prefix(*prefix).build() };
+        }
+    }
+
+    if let Some(cc) = mnemonic.strip_prefix('j').and_then(condition) {
+        let [Operand::Imm(target)] = ops else { return Err(unsupported(mnemonic)) };
+        return branch(&[0x0F, 0x80 + cc], target);
+    }
+    if let Some(cc) = mnemonic.strip_prefix("set").and_then(condition) {
+        let [dst] = ops else { return Err(unsupported(mnemonic)) };
+        if !is_rm(dst) || operand_size(dst).is_some_and(|s| s != 1) {
+            return Err(unsupported(mnemonic));
+        }
+        return Builder::new(&[0x0F, 0x90 + cc]).ext(0).rm(dst).build();
+    }
+    if let Some(cc) = mnemonic.strip_prefix("cmov").and_then(condition) {
+        let [dst, src] = ops else { return Err(unsupported(mnemonic)) };
+        let (Some(dst), true) = (gpr(dst), is_rm(src)) else { return Err(unsupported(mnemonic)) };
+        return Builder::new(&[0x0F, 0x40 + cc]).size(dst.size).reg(dst).rm(src).build();
+    }
+
+    match (mnemonic, ops) {
+        ("mov", [dst, src]) => encode_mov(dst, src),
+        ("movabs", [Operand::Reg(dst), Operand::Imm(imm)]) if !dst.xmm && dst.size == 8 => {
+            Builder::new(&[0xB8]).w(true).opcode_reg(dst).imm(imm, 8, false).build()
+        }
+        ("lea", [Operand::Reg(dst), src @ Operand::Mem(_)]) if !dst.xmm && dst.size >= 2 => {
+            Builder::new(&[0x8D]).size(dst.size).reg(dst).rm(src).build()
+        }
+        ("movzx" | "movsx", [Operand::Reg(dst), src]) if !dst.xmm && is_rm(src) => {
+            let src_size = operand_size(src).ok_or("movzx/movsx kaynak boyutu belirsiz")?;
+            let base = if mnemonic == "movzx" { 0xB6 } else { 0xBE };
+            let opcode = match src_size {
+                1 => base,
+                2 => base + 1,
+                _ => return Err(unsupported(mnemonic)),
+            };
+            Builder::new(&[0x0F, opcode]).size(dst.size).reg(dst).rm(src).build()
+        }
+        ("movsxd", [Operand::Reg(dst), src]) if !dst.xmm && dst.size == 8 && is_rm(src) => {
+            Builder::new(&[0x63]).w(true).reg(dst).rm(src).build()
+        }
+        ("test", [dst, src]) if is_rm(dst) => {
+            let size = common_size(dst, src)?;
+            match src {
+                Operand::Reg(reg) if !reg.xmm => Builder::new(&[if size == 1 { 0x84 } else { 0x85 }]).size(size).reg(reg).rm(dst).build(),
+                Operand::Imm(imm) if matches!(dst, Operand::Reg(r) if r.num == 0 && !r.high8) => {
+                    Builder::new(&[if size == 1 { 0xA8 } else { 0xA9 }]).size(size).imm(imm, size.min(4), size == 8).build()
+                }
+                Operand::Imm(imm) => {
+                    let b = Builder::new(&[if size == 1 { 0xF6 } else { 0xF7 }]).size(size).ext(0).rm(dst);
+                    b.imm(imm, size.min(4), size == 8).build()
+                }
+                _ => Err(unsupported(mnemonic)),
+            }
+        }
+        ("xchg", [dst, src]) => {
+            let (reg, other) = match (gpr(dst), gpr(src)) {
+                (_, Some(reg)) => (reg, dst),
+                (Some(reg), _) => (reg, src),
+                _ => return Err(unsupported(mnemonic)),
+            };
+            let size = common_size(dst, src)?;
+            Builder::new(&[if size == 1 { 0x86 } else { 0x87 }]).size(size).reg(reg).rm(other).build()
+        }
+        ("inc" | "dec" | "not" | "neg" | "mul" | "div" | "idiv", [dst]) | ("imul", [dst]) if is_rm(dst) => {
+            let size = operand_size(dst).ok_or("İşlenen boyutu belirsiz; 'qword ptr' gibi bir boyut belirtin")?;
+            let (opcode, digit) = match mnemonic {
+                "inc" => (0xFE, 0),
+                "dec" => (0xFE, 1),
+                "not" => (0xF6, 2),
+                "neg" => (0xF6, 3),
+                "mul" => (0xF6, 4),
+                "imul" => (0xF6, 5),
+                "div" => (0xF6, 6),
+                _ => (0xF6, 7),
+            };
+            let opcode = if size == 1 { opcode } else { opcode + 1 };
+            Builder::new(&[opcode]).size(size).ext(digit).rm(dst).build()
+        }
+        ("imul", [Operand::Reg(dst), src]) if !dst.xmm && is_rm(src) => {
+            common_size(&Operand::Reg(*dst), src)?;
+            Builder::new(&[0x0F, 0xAF]).size(dst.size).reg(dst).rm(src).build()
+        }
+        // `imul r, imm` = `imul r, r, imm`
+        ("imul", [dst @ Operand::Reg(_), imm @ Operand::Imm(_)]) => encode("imul", None, &[dst.clone(), dst.clone(), imm.clone()]),
+        ("imul", [Operand::Reg(dst), src, Operand::Imm(imm)]) if !dst.xmm && is_rm(src) => {
+            common_size(&Operand::Reg(*dst), src)?;
+            match imm.as_constant() {
+                Some(v) if fits_i8(v) => Builder::new(&[0x6B]).size(dst.size).reg(dst).rm(src).imm(imm, 1, true).build(),
+                _ => Builder::new(&[0x69]).size(dst.size).reg(dst).rm(src).imm(imm, dst.size.min(4), true).build(),
+            }
+        }
+        ("push", [Operand::Reg(reg)]) if !reg.xmm && reg.size == 8 => Builder::new(&[0x50]).opcode_reg(reg).build(),
+        ("pop", [Operand::Reg(reg)]) if !reg.xmm && reg.size == 8 => Builder::new(&[0x58]).opcode_reg(reg).build(),
+        ("push", [mem @ Operand::Mem(_)]) => Builder::new(&[0xFF]).ext(6).rm(mem).build(),
+        ("pop", [mem @ Operand::Mem(_)]) => Builder::new(&[0x8F]).ext(0).rm(mem).build(),
+        ("push", [Operand::Imm(imm)]) => match imm.as_constant() {
+            Some(v) if fits_i8(v) => Builder::new(&[0x6A]).imm(imm, 1, true).build(),
+            _ => Builder::new(&[0x68]).imm(imm, 4, true).build(),
+        },
+        ("call", [Operand::Imm(target)]) => branch(&[0xE8], target),
+        ("jmp", [Operand::Imm(target)]) => branch(&[0xE9], target),
+        ("call", [target]) if is_rm(target) => Builder::new(&[0xFF]).ext(2).rm(target).build(),
+        ("jmp", [target]) if is_rm(target) => Builder::new(&[0xFF]).ext(4).rm(target).build(),
+        ("ret", []) => Ok(bytes(&[0xC3])),
+        ("ret", [Operand::Imm(imm)]) => Builder::new(&[0xC2]).imm(imm, 2, false).build(),
+        ("leave", []) => Ok(bytes(&[0xC9])),
+        ("syscall", []) => Ok(bytes(&[0x0F, 0x05])),
+        ("cqo", []) => Ok(bytes(&[0x48, 0x99])),
+        ("cdq", []) => Ok(bytes(&[0x99])),
+        ("cdqe", []) => Ok(bytes(&[0x48, 0x98])),
+        ("nop", []) => Ok(bytes(&[0x90])),
+        ("hlt", []) => Ok(bytes(&[0xF4])),
+        ("int3", []) => Ok(bytes(&[0xCC])),
+        ("ud2", []) => Ok(bytes(&[0x0F, 0x0B])),
+        ("pause", []) => Ok(bytes(&[0xF3, 0x90])),
+        ("rdtsc", []) => Ok(bytes(&[0x0F, 0x31])),
+        ("rdtscp", []) => Ok(bytes(&[0x0F, 0x01, 0xF9])),
+        ("cpuid", []) => Ok(bytes(&[0x0F, 0xA2])),
+        ("lfence", []) => Ok(bytes(&[0x0F, 0xAE, 0xE8])),
+        ("mfence", []) => Ok(bytes(&[0x0F, 0xAE, 0xF0])),
+        ("sfence", []) => Ok(bytes(&[0x0F, 0xAE, 0xF8])),
+        ("bswap", [Operand::Reg(reg)]) if !reg.xmm && reg.size >= 4 => {
+            Builder::new(&[0x0F, 0xC8]).size(reg.size).opcode_reg(reg).build()
+        }
+        // popcnt/lzcnt/tzcnt zorunlu F3 önekiyle, bsf/bsr öneksiz kodlanır
+        ("popcnt" | "lzcnt" | "tzcnt" | "bsf" | "bsr", [Operand::Reg(dst), src]) if !dst.xmm && dst.size >= 2 && is_rm(src) => {
+            common_size(&Operand::Reg(*dst), src)?;
+            let opcode = match mnemonic {
+                "popcnt" => 0xB8,
+                "tzcnt" | "bsf" => 0xBC,
+                _ => 0xBD,
+            };
+            let b = Builder::new(&[0x0F, opcode]).size(dst.size).reg(dst).rm(src);
+            if mnemonic.starts_with('b') { b.build() } else { b.prefix(0xF3).build() }
+        }
+        ("xadd" | "cmpxchg", [dst, Operand::Reg(src)]) if !src.xmm && is_rm(dst) => {
+            let size = common_size(dst, &Operand::Reg(*src))?;
+            let base = if mnemonic == "xadd" { 0xC0 } else { 0xB0 };
+            Builder::new(&[0x0F, if size == 1 { base } else { base + 1 }]).size(size).reg(src).rm(dst).build()
+        }
+        ("stosb", []) => Ok(bytes(&[0xAA])),
+        ("stosd", []) => Ok(bytes(&[0xAB])),
+        ("stosq", []) => Ok(bytes(&[0x48, 0xAB])),
+        ("movsb", []) => Ok(bytes(&[0xA4])),
+        ("movsd", []) => Ok(bytes(&[0xA5])),
+        ("movsq", []) => Ok(bytes(&[0x48, 0xA5])),
+        ("lodsb", []) => Ok(bytes(&[0xAC])),
+        ("scasb", []) => Ok(bytes(&[0xAE])),
+        ("cmpsb", []) => Ok(bytes(&[0xA6])),
+        ("movq", [dst, src]) => match (dst, src) {
+            (Operand::Reg(d), s) if d.xmm && is_rm(s) => Builder::new(&[0x0F, 0x6E]).prefix(0x66).w(true).reg(d).rm(s).build(),
+            (Operand::Reg(d), s) if d.xmm && is_xmm_or_mem(s) => Builder::new(&[0x0F, 0x7E]).prefix(0xF3).reg(d).rm(s).build(),
+            (d @ Operand::Reg(_), Operand::Reg(s)) if s.xmm => Builder::new(&[0x0F, 0x7E]).prefix(0x66).w(true).reg(s).rm(d).build(),
+            (d @ Operand::Mem(_), Operand::Reg(s)) if s.xmm => Builder::new(&[0x0F, 0xD6]).prefix(0x66).reg(s).rm(d).build(),
+            _ => Err(unsupported(mnemonic)),
+        },
+        ("movd", [dst, src]) => match (dst, src) {
+            (Operand::Reg(d), s) if d.xmm && is_rm(s) => Builder::new(&[0x0F, 0x6E]).prefix(0x66).reg(d).rm(s).build(),
+            (d, Operand::Reg(s)) if s.xmm && is_rm(d) => Builder::new(&[0x0F, 0x7E]).prefix(0x66).reg(s).rm(d).build(),
+            _ => Err(unsupported(mnemonic)),
+        },
+        ("cvtsi2sd" | "cvtsi2ss", [Operand::Reg(dst), src]) if dst.xmm && is_rm(src) => {
+            let size = operand_size(src).ok_or("cvtsi2sd kaynak boyutu belirsiz")?;
+            let prefix = if mnemonic == "cvtsi2sd" { 0xF2 } else { 0xF3 };
+            Builder::new(&[0x0F, 0x2A]).prefix(prefix).w(size == 8).reg(dst).rm(src).build()
+        }
+        ("cvttsd2si" | "cvtsd2si" | "cvttss2si" | "cvtss2si", [Operand::Reg(dst), src]) if !dst.xmm && is_xmm_or_mem(src) => {
+            let prefix = if mnemonic.contains("sd") { 0xF2 } else { 0xF3 };
+            let opcode = if mnemonic.starts_with("cvtt") { 0x2C } else { 0x2D };
+            Builder::new(&[0x0F, opcode]).prefix(prefix).w(dst.size == 8).reg(dst).rm(src).build()
+        }
+        _ => Err(unsupported(mnemonic)),
+    }
+}
+
+fn bytes(b: &[u8]) -> Encoded {
+    Encoded { bytes: b.to_vec(), fixups: Vec::new() }
+}
+
+// rel32 dallanma: hedef, komutun sonuna göre göreli
+fn branch(opcode: &[u8], target: &Expr) -> Result<Encoded, String> {
+    if target.minus.is_some() {
+        return Err("Dallanma hedefi bir etiket olmalı".to_string());
+    }
+    let mut out = bytes(opcode);
+    let expr = Expr { constant: target.constant - 4, ..target.clone() };
+    out.fixups.push(Fixup { offset: out.bytes.len(), size: 4, expr, kind: FixupKind::Branch });
+    out.bytes.extend([0; 4]);
+    Ok(out)
+}
+
+fn encode_alu(mnemonic: &str, n: u8, ops: &[Operand]) -> Result<Encoded, String> {
+    let [dst, src] = ops else { return Err(unsupported(mnemonic)) };
+    if !is_rm(dst) {
+        return Err(unsupported(mnemonic));
+    }
+    let size = common_size(dst, src)?;
+    let byte = size == 1;
+    match (dst, src) {
+        (_, Operand::Reg(reg)) if !reg.xmm => Builder::new(&[n * 8 + if byte { 0 } else { 1 }]).size(size).reg(reg).rm(dst).build(),
+        (Operand::Reg(reg), Operand::Mem(_)) => Builder::new(&[n * 8 + if byte { 2 } else { 3 }]).size(size).reg(reg).rm(src).build(),
+        // al/ax/eax/rax için kısa akümülatör biçimi (GAS ile aynı seçim)
+        (Operand::Reg(reg), Operand::Imm(imm)) if reg.num == 0 && !reg.high8 && (byte || !imm.as_constant().is_some_and(fits_i8)) => {
+            if byte {
+                return Builder::new(&[n * 8 + 4]).imm(imm, 1, true).build();
+            }
+            if size == 8 && imm.as_constant().is_some_and(|v| !fits_i32(v)) {
+                return Err(format!("{} değeri 32 bitlik işaretli anlık değere sığmıyor", imm.constant));
+            }
+            Builder::new(&[n * 8 + 5]).size(size).imm(imm, size.min(4), size == 8).build()
+        }
+        (_, Operand::Imm(imm)) => {
+            if byte {
+                return Builder::new(&[0x80]).ext(n).rm(dst).imm(imm, 1, true).build();
+            }
+            match imm.as_constant() {
+                Some(v) if fits_i8(v) => Builder::new(&[0x83]).size(size).ext(n).rm(dst).imm(imm, 1, true).build(),
+                Some(v) if size == 8 && !fits_i32(v) => Err(format!("{} değeri 32 bitlik işaretli anlık değere sığmıyor", v)),
+                _ => Builder::new(&[0x81]).size(size).ext(n).rm(dst).imm(imm, size.min(4), size == 8).build(),
+            }
+        }
+        _ => Err(unsupported(mnemonic)),
+    }
+}
+
+fn encode_shift(mnemonic: &str, n: u8, ops: &[Operand]) -> Result<Encoded, String> {
+    let [dst, count] = ops else { return Err(unsupported(mnemonic)) };
+    if !is_rm(dst) {
+        return Err(unsupported(mnemonic));
+    }
+    let size = operand_size(dst).ok_or("İşlenen boyutu belirsiz; 'qword ptr' gibi bir boyut belirtin")?;
+    let wide = if size == 1 { 0 } else { 1 };
+    match count {
+        Operand::Reg(reg) if reg.size == 1 && reg.num == 1 && !reg.high8 => Builder::new(&[0xD2 + wide]).size(size).ext(n).rm(dst).build(),
+        Operand::Imm(imm) if imm.as_constant() == Some(1) => Builder::new(&[0xD0 + wide]).size(size).ext(n).rm(dst).build(),
+        Operand::Imm(imm) => Builder::new(&[0xC0 + wide]).size(size).ext(n).rm(dst).imm(imm, 1, false).build(),
+        _ => Err(unsupported(mnemonic)),
+    }
+}
+
+fn encode_mov(dst: &Operand, src: &Operand) -> Result<Encoded, String> {
+    match (dst, src) {
+        (Operand::Reg(d), Operand::Imm(imm)) if !d.xmm => {
+            if d.size == 8 {
+                return match imm.as_constant() {
+                    Some(v) if fits_i32(v) => Builder::new(&[0xC7]).w(true).ext(0).rm(dst).imm(imm, 4, true).build(),
+                    Some(_) => Builder::new(&[0xB8]).w(true).opcode_reg(d).imm(imm, 8, false).build(),
+                    None => Builder::new(&[0xC7]).w(true).ext(0).rm(dst).imm(imm, 4, true).build(),
+                };
+            }
+            let opcode = if d.size == 1 { 0xB0 } else { 0xB8 };
+            Builder::new(&[opcode]).size(d.size).opcode_reg(d).imm(imm, d.size, false).build()
+        }
+        (Operand::Mem(_), Operand::Imm(imm)) => {
+            let size = operand_size(dst).ok_or("İşlenen boyutu belirsiz; 'qword ptr' gibi bir boyut belirtin")?;
+            if size == 8 && imm.as_constant().is_some_and(|v| !fits_i32(v)) {
+                return Err("64 bitlik sabit belleğe doğrudan yazılamaz; önce bir register'a yükleyin".to_string());
+            }
+            let opcode = if size == 1 { 0xC6 } else { 0xC7 };
+            Builder::new(&[opcode]).size(size).ext(0).rm(dst).imm(imm, size.min(4), size == 8).build()
+        }
+        (_, Operand::Reg(s)) if !s.xmm && is_rm(dst) => {
+            let size = common_size(dst, src)?;
+            Builder::new(&[if size == 1 { 0x88 } else { 0x89 }]).size(size).reg(s).rm(dst).build()
+        }
+        (Operand::Reg(d), Operand::Mem(_)) if !d.xmm => {
+            let size = common_size(dst, src)?;
+            Builder::new(&[if size == 1 { 0x8A } else { 0x8B }]).size(size).reg(d).rm(src).build()
+        }
+        _ => Err(unsupported("mov")),
+    }
+}
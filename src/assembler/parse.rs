@@ -0,0 +1,518 @@
+// src/assembler/parse.rs
+
+// GAS Intel sözdiziminin (`.intel_syntax noprefix`) ayrıştırıcısı. Derleyicinin ürettiği kodun,
+// Linux runtime'ının (libs/core_linux.s) ve asm bloklarında yaygın kullanılan yazımların alt kümesini
+// tanır: etiketler, direktifler ve register / bellek / sabit işlenenli komutlar.
+
+// Register: `num` kodlamadaki numaradır (0-15); `size` byte cinsindendir (xmm için 16).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Reg {
+    pub num: u8,
+    pub size: u8,
+    pub xmm: bool,
+    // ah, ch, dh, bh: REX öneki ile birlikte kullanılamaz
+    pub high8: bool,
+    // spl, bpl, sil, dil: yalnızca REX öneki ile kodlanabilir
+    pub rex8: bool,
+}
+
+impl Reg {
+    fn gpr(num: u8, size: u8) -> Self {
+        Reg { num, size, xmm: false, high8: false, rex8: size == 1 && (4..8).contains(&num) }
+    }
+}
+
+const GPR64: [&str; 16] = ["rax", "rcx", "rdx", "rbx", "rsp", "rbp", "rsi", "rdi", "r8", "r9", "r10", "r11", "r12", "r13", "r14", "r15"];
+const GPR32: [&str; 8] = ["eax", "ecx", "edx", "ebx", "esp", "ebp", "esi", "edi"];
+const GPR16: [&str; 8] = ["ax", "cx", "dx", "bx", "sp", "bp", "si", "di"];
+const GPR8: [&str; 8] = ["al", "cl", "dl", "bl", "spl", "bpl", "sil", "dil"];
+const HIGH8: [&str; 4] = ["ah", "ch", "dh", "bh"];
+
+pub(super) fn register(name: &str) -> Option<Reg> {
+    let name = name.to_ascii_lowercase();
+    if let Some(i) = GPR64.iter().position(|r| *r == name) {
+        return Some(Reg::gpr(i as u8, 8));
+    }
+    if let Some(i) = GPR32.iter().position(|r| *r == name) {
+        return Some(Reg::gpr(i as u8, 4));
+    }
+    if let Some(i) = GPR16.iter().position(|r| *r == name) {
+        return Some(Reg::gpr(i as u8, 2));
+    }
+    if let Some(i) = GPR8.iter().position(|r| *r == name) {
+        return Some(Reg::gpr(i as u8, 1));
+    }
+    if let Some(i) = HIGH8.iter().position(|r| *r == name) {
+        return Some(Reg { num: i as u8 + 4, size: 1, xmm: false, high8: true, rex8: false });
+    }
+    if let Some(n) = name.strip_prefix("xmm") {
+        let num: u8 = n.parse().ok().filter(|n| *n < 16)?;
+        return Some(Reg { num, size: 16, xmm: true, high8: false, rex8: false });
+    }
+    // r8-r15 ve r8d/r8w/r8b (r8l) biçimleri
+    let rest = name.strip_prefix('r')?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let num: u8 = digits.parse().ok().filter(|n| (8..16).contains(n))?;
+    let size = match &rest[digits.len()..] {
+        "" => 8,
+        "d" => 4,
+        "w" => 2,
+        "b" | "l" => 1,
+        _ => return None,
+    };
+    Some(Reg::gpr(num, size))
+}
+
+// `sembol - sembol2 + sabit` biçiminde bir ifade. Semboller etiketler veya `.set` sabitleridir.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(super) struct Expr {
+    pub symbol: Option<String>,
+    pub minus: Option<String>,
+    pub constant: i64,
+}
+
+impl Expr {
+    pub fn as_constant(&self) -> Option<i64> {
+        match (&self.symbol, &self.minus) {
+            (None, None) => Some(self.constant),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Mem {
+    // `byte/word/dword/qword/xmmword ptr` ile verilen boyut
+    pub size: Option<u8>,
+    pub base: Option<Reg>,
+    pub index: Option<(Reg, u8)>,
+    pub rip: bool,
+    pub disp: Expr,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Operand {
+    Reg(Reg),
+    Mem(Mem),
+    Imm(Expr),
+    // Sembol içeren çıplak ifade: GAS'ta dallanmalarda hedef, diğer komutlarda bellek işlenenidir
+    // (`mov rax, x` = `mov rax, [x]`). Anlık adres için `OFFSET x` yazılır.
+    Symbol(Expr),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Stmt {
+    Label(String),
+    // Direktif adı (`.` dahil) ve ham argüman metni
+    Directive(String, String),
+    Inst { mnemonic: String, prefix: Option<String>, operands: Vec<Operand> },
+}
+
+// Kaynak metinden `/* ... */` blok yorumlarını kaldırır (satır sayısı korunur).
+pub(super) fn strip_block_comments(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if c == '\\' {
+                if let Some(next) = chars.next() {
+                    out.push(next);
+                }
+            } else if c == '"' || c == '\n' {
+                in_string = false;
+            }
+            continue;
+        }
+        if c == '/' && chars.peek() == Some(&'*') {
+            chars.next();
+            let mut prev = ' ';
+            for c in chars.by_ref() {
+                if c == '\n' {
+                    out.push('\n');
+                }
+                if prev == '*' && c == '/' {
+                    break;
+                }
+                prev = c;
+            }
+            continue;
+        }
+        if c == '"' {
+            in_string = true;
+        }
+        out.push(c);
+    }
+    out
+}
+
+// Satır sonu yorumunu (`#`) string'lerin dışında keser.
+fn strip_line_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == '#' {
+            return &line[..i];
+        }
+    }
+    line
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == '.' || c == '$'
+}
+
+pub(super) fn parse_line(line: &str) -> Result<Vec<Stmt>, String> {
+    let mut rest = strip_line_comment(line).trim();
+    let mut stmts = Vec::new();
+    // Satır başındaki etiketler (`etiket:`); aynı satırda bir komut veya direktif izleyebilir.
+    loop {
+        let end = rest.find(|c: char| !is_ident_char(c)).unwrap_or(rest.len());
+        if end > 0 && rest[end..].starts_with(':') {
+            stmts.push(Stmt::Label(rest[..end].to_string()));
+            rest = rest[end + 1..].trim_start();
+        } else {
+            break;
+        }
+    }
+    if rest.is_empty() {
+        return Ok(stmts);
+    }
+    if rest.starts_with('.') {
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        stmts.push(Stmt::Directive(rest[..end].to_ascii_lowercase(), rest[end..].trim().to_string()));
+        return Ok(stmts);
+    }
+
+    let (mut mnemonic, mut args) = split_first_word(rest);
+    let mut prefix = None;
+    if matches!(mnemonic.as_str(), "rep" | "repe" | "repz" | "repne" | "repnz" | "lock") {
+        prefix = Some(mnemonic);
+        (mnemonic, args) = split_first_word(args);
+    }
+    let operands = if args.is_empty() {
+        Vec::new()
+    } else {
+        split_operands(args).iter().map(|op| parse_operand(op)).collect::<Result<_, _>>()?
+    };
+    stmts.push(Stmt::Inst { mnemonic, prefix, operands });
+    Ok(stmts)
+}
+
+fn split_first_word(text: &str) -> (String, &str) {
+    let end = text.find(char::is_whitespace).unwrap_or(text.len());
+    (text[..end].to_ascii_lowercase(), text[end..].trim())
+}
+
+// Virgülle ayrılmış işlenenler (köşeli parantez içindeki virgüller bölmez).
+fn split_operands(text: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(text[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(text[start..].trim());
+    parts
+}
+
+fn parse_operand(text: &str) -> Result<Operand, String> {
+    let lower = text.to_ascii_lowercase();
+    let sizes = [("byte", 1), ("word", 2), ("dword", 4), ("qword", 8), ("xmmword", 16), ("oword", 16)];
+    let mut size = None;
+    let mut body = text;
+    for (name, bytes) in sizes {
+        if let Some(after) = lower.strip_prefix(name) {
+            if let Some(after_ptr) = after.trim_start().strip_prefix("ptr") {
+                size = Some(bytes);
+                body = text[text.len() - after_ptr.len()..].trim();
+                break;
+            }
+        }
+    }
+
+    if let Some(inner) = body.strip_prefix('[') {
+        let inner = inner.strip_suffix(']').ok_or_else(|| format!("']' eksik: '{}'", text))?;
+        let mut mem = parse_address(inner)?;
+        mem.size = size;
+        return Ok(Operand::Mem(mem));
+    }
+    if size.is_some() {
+        return Err(format!("'ptr' sonrasında bellek işleneni bekleniyordu: '{}'", text));
+    }
+    if let Some(reg) = register(body) {
+        return Ok(Operand::Reg(reg));
+    }
+    if body.len() > 6 && body[..6].eq_ignore_ascii_case("offset") && body[6..].starts_with(char::is_whitespace) {
+        let expr = body[6..].trim();
+        let expr = expr.strip_prefix("FLAT:").or_else(|| expr.strip_prefix("flat:")).unwrap_or(expr);
+        return Ok(Operand::Imm(parse_expr(expr)?));
+    }
+    let expr = parse_expr(body)?;
+    Ok(if expr.as_constant().is_some() { Operand::Imm(expr) } else { Operand::Symbol(expr) })
+}
+
+// İşaretli terimlere ayırır: "rbp - 8 + rcx*8" -> [(+, rbp), (-, 8), (+, rcx*8)]
+fn split_terms(text: &str) -> Result<Vec<(bool, String)>, String> {
+    let mut terms = Vec::new();
+    let mut negative = false;
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    let mut in_char = false;
+    while let Some(c) = chars.next() {
+        if in_char {
+            current.push(c);
+            if c == '\\' {
+                current.extend(chars.next());
+            } else if c == '\'' {
+                in_char = false;
+            }
+            continue;
+        }
+        match c {
+            '\'' => {
+                in_char = true;
+                current.push(c);
+            }
+            '+' | '-' => {
+                if !current.trim().is_empty() {
+                    terms.push((negative, current.trim().to_string()));
+                    current.clear();
+                    negative = false;
+                }
+                if c == '-' {
+                    negative = !negative;
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        terms.push((negative, current.trim().to_string()));
+    } else if terms.is_empty() || negative {
+        return Err(format!("Geçersiz ifade: '{}'", text));
+    }
+    Ok(terms)
+}
+
+fn parse_address(text: &str) -> Result<Mem, String> {
+    let mut mem = Mem { size: None, base: None, index: None, rip: false, disp: Expr::default() };
+    for (negative, term) in split_terms(text)? {
+        if term.eq_ignore_ascii_case("rip") && !negative {
+            mem.rip = true;
+            continue;
+        }
+        if let Some((a, b)) = term.split_once('*') {
+            let (a, b) = (a.trim(), b.trim());
+            let (reg, scale) = match (register(a), register(b)) {
+                (Some(reg), None) => (reg, b),
+                (None, Some(reg)) => (reg, a),
+                _ => {
+                    let product = parse_number(a).zip(parse_number(b)).ok_or_else(|| format!("Geçersiz adres terimi: '{}'", term))?;
+                    mem.disp.constant += if negative { -product.0 * product.1 } else { product.0 * product.1 };
+                    continue;
+                }
+            };
+            let scale: u8 = scale.parse().ok().filter(|s| matches!(s, 1 | 2 | 4 | 8)).ok_or_else(|| format!("Geçersiz ölçek: '{}'", term))?;
+            if negative || mem.index.is_some() {
+                return Err(format!("Geçersiz adres: '{}'", text));
+            }
+            mem.index = Some((reg, scale));
+            continue;
+        }
+        if let Some(reg) = register(&term) {
+            if negative {
+                return Err(format!("Register çıkarılamaz: '{}'", text));
+            }
+            if mem.base.is_none() {
+                mem.base = Some(reg);
+            } else if mem.index.is_none() {
+                mem.index = Some((reg, 1));
+            } else {
+                return Err(format!("Geçersiz adres: '{}'", text));
+            }
+            continue;
+        }
+        add_term(&mut mem.disp, negative, &term)?;
+    }
+    if mem.rip && (mem.base.is_some() || mem.index.is_some()) {
+        return Err(format!("rip göreli adres başka register içeremez: '{}'", text));
+    }
+    Ok(mem)
+}
+
+pub(super) fn parse_expr(text: &str) -> Result<Expr, String> {
+    let mut expr = Expr::default();
+    for (negative, term) in split_terms(text)? {
+        add_term(&mut expr, negative, &term)?;
+    }
+    Ok(expr)
+}
+
+fn add_term(expr: &mut Expr, negative: bool, term: &str) -> Result<(), String> {
+    if let Some(value) = parse_number(term) {
+        expr.constant = expr.constant.wrapping_add(if negative { value.wrapping_neg() } else { value });
+        return Ok(());
+    }
+    if !term.chars().all(is_ident_char) || term.chars().next().is_some_and(|c| c.is_ascii_digit()) {
+        return Err(format!("Geçersiz ifade terimi: '{}'", term));
+    }
+    let slot = if negative { &mut expr.minus } else { &mut expr.symbol };
+    if slot.is_some() {
+        return Err(format!("İfade en fazla bir eklenen ve bir çıkarılan sembol içerebilir: '{}'", term));
+    }
+    *slot = Some(term.to_string());
+    Ok(())
+}
+
+pub(super) fn parse_number(text: &str) -> Option<i64> {
+    let text = text.trim();
+    if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        return u64::from_str_radix(hex, 16).ok().map(|v| v as i64);
+    }
+    if let Some(bin) = text.strip_prefix("0b").or_else(|| text.strip_prefix("0B")) {
+        return u64::from_str_radix(bin, 2).ok().map(|v| v as i64);
+    }
+    if let Some(c) = text.strip_prefix('\'') {
+        let c = c.strip_suffix('\'').unwrap_or(c);
+        let value = match c {
+            "\\n" => '\n',
+            "\\t" => '\t',
+            "\\r" => '\r',
+            "\\0" => '\0',
+            "\\\\" => '\\',
+            "\\'" => '\'',
+            _ => {
+                let mut chars = c.chars();
+                let ch = chars.next()?;
+                if chars.next().is_some() {
+                    return None;
+                }
+                ch
+            }
+        };
+        return Some(value as i64);
+    }
+    if text.chars().all(|c| c.is_ascii_digit()) && !text.is_empty() {
+        // GAS: başında 0 olan sayılar sekizliktir
+        if text.len() > 1 && text.starts_with('0') {
+            return u64::from_str_radix(&text[1..], 8).ok().map(|v| v as i64);
+        }
+        return text.parse::<u64>().ok().map(|v| v as i64);
+    }
+    None
+}
+
+// `.asciz`/`.ascii` string literali (GAS kaçış dizileriyle) -> byte'lar
+pub(super) fn parse_string(text: &str) -> Result<(Vec<u8>, &str), String> {
+    let text = text.trim_start();
+    let mut chars = text.char_indices();
+    if chars.next().map(|(_, c)| c) != Some('"') {
+        return Err(format!("String literali bekleniyordu: '{}'", text));
+    }
+    let mut bytes = Vec::new();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Ok((bytes, &text[i + 1..])),
+            '\\' => {
+                let (_, e) = chars.next().ok_or("Yarım kalan kaçış dizisi")?;
+                match e {
+                    'n' => bytes.push(b'\n'),
+                    'r' => bytes.push(b'\r'),
+                    't' => bytes.push(b'\t'),
+                    'b' => bytes.push(8),
+                    'f' => bytes.push(12),
+                    '\\' => bytes.push(b'\\'),
+                    '"' => bytes.push(b'"'),
+                    'x' | 'X' => {
+                        let mut value = 0u32;
+                        let mut digits = 0;
+                        while let Some(d) = chars.clone().next().and_then(|(_, d)| d.to_digit(16)) {
+                            chars.next();
+                            value = value * 16 + d;
+                            digits += 1;
+                        }
+                        if digits == 0 {
+                            return Err("\\x sonrasında onaltılık rakam bekleniyordu".to_string());
+                        }
+                        bytes.push(value as u8);
+                    }
+                    '0'..='7' => {
+                        let mut value = e.to_digit(8).unwrap_or(0);
+                        for _ in 0..2 {
+                            match chars.clone().next().and_then(|(_, d)| d.to_digit(8)) {
+                                Some(d) => {
+                                    chars.next();
+                                    value = value * 8 + d;
+                                }
+                                None => break,
+                            }
+                        }
+                        bytes.push(value as u8);
+                    }
+                    other => {
+                        let mut buf = [0; 4];
+                        bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+                    }
+                }
+            }
+            other => {
+                let mut buf = [0; 4];
+                bytes.extend_from_slice(other.encode_utf8(&mut buf).as_bytes());
+            }
+        }
+    }
+    Err(format!("Kapanmamış string: '{}'", text))
+}
+
+// Direktif argümanlarını virgüllerden böler (string içindekiler hariç).
+pub(super) fn split_args(text: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    for c in text.chars() {
+        if in_string {
+            current.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+        match c {
+            '"' => {
+                in_string = true;
+                current.push(c);
+            }
+            ',' => parts.push(std::mem::take(&mut current).trim().to_string()),
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() || !parts.is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
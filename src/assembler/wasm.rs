@@ -0,0 +1,957 @@
+// src/assembler/wasm.rs
+
+// Yerleşik WebAssembly assembler'ı: codegen'in ürettiği WAT metnini (WASI runtime'ı libs/core_wasi.wat
+// dahil) harici bir araç çağırmadan ikili .wasm modülüne çevirir.
+//
+// WAT'ın derleyicinin kullandığı alt kümesini tanır: `import` (yalnızca fonksiyon), `memory`, `global`,
+// `data`, `func` ve `export` alanları; komutlar düz (katlanmamış) biçimde yazılır. Blok tipleri boş
+// veya tek sonuçludur. Etiket ve fonksiyon isimleri (`$ad`) kodlama sırasında indekslere çözülür;
+// fonksiyon indeks uzayında önce içe aktarılanlar, sonra tanımlananlar gelir.
+
+use std::collections::HashMap;
+
+use super::dwarf::{sleb128, uleb128};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ValType {
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+impl ValType {
+    fn parse(name: &str) -> Option<ValType> {
+        Some(match name {
+            "i32" => ValType::I32,
+            "i64" => ValType::I64,
+            "f32" => ValType::F32,
+            "f64" => ValType::F64,
+            _ => return None,
+        })
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            ValType::I32 => 0x7f,
+            ValType::I64 => 0x7e,
+            ValType::F32 => 0x7d,
+            ValType::F64 => 0x7c,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct FuncType {
+    params: Vec<ValType>,
+    results: Vec<ValType>,
+}
+
+// Bir komutun işlenen (immediate) biçimi
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Imm {
+    None,
+    Block,
+    Label,
+    LabelTable,
+    Func,
+    Local,
+    Global,
+    // Hizalama doğal boyutun log2'sidir
+    Mem(u32),
+    I32,
+    I64,
+    F32,
+    F64,
+    // memory.size/grow/fill: sıfır bellek indeksi
+    MemIndex,
+    // memory.copy: iki sıfır bellek indeksi
+    MemIndex2,
+}
+
+// Komut adı, işlem kodu byte'ları ve işlenen biçimi
+const INSTRUCTIONS: &[(&str, &[u8], Imm)] = &[
+    ("unreachable", &[0x00], Imm::None),
+    ("nop", &[0x01], Imm::None),
+    ("block", &[0x02], Imm::Block),
+    ("loop", &[0x03], Imm::Block),
+    ("if", &[0x04], Imm::Block),
+    ("else", &[0x05], Imm::None),
+    ("end", &[0x0b], Imm::None),
+    ("br", &[0x0c], Imm::Label),
+    ("br_if", &[0x0d], Imm::Label),
+    ("br_table", &[0x0e], Imm::LabelTable),
+    ("return", &[0x0f], Imm::None),
+    ("call", &[0x10], Imm::Func),
+    ("drop", &[0x1a], Imm::None),
+    ("select", &[0x1b], Imm::None),
+    ("local.get", &[0x20], Imm::Local),
+    ("local.set", &[0x21], Imm::Local),
+    ("local.tee", &[0x22], Imm::Local),
+    ("global.get", &[0x23], Imm::Global),
+    ("global.set", &[0x24], Imm::Global),
+    ("i32.load", &[0x28], Imm::Mem(2)),
+    ("i64.load", &[0x29], Imm::Mem(3)),
+    ("f32.load", &[0x2a], Imm::Mem(2)),
+    ("f64.load", &[0x2b], Imm::Mem(3)),
+    ("i32.load8_s", &[0x2c], Imm::Mem(0)),
+    ("i32.load8_u", &[0x2d], Imm::Mem(0)),
+    ("i32.load16_s", &[0x2e], Imm::Mem(1)),
+    ("i32.load16_u", &[0x2f], Imm::Mem(1)),
+    ("i64.load8_s", &[0x30], Imm::Mem(0)),
+    ("i64.load8_u", &[0x31], Imm::Mem(0)),
+    ("i64.load16_s", &[0x32], Imm::Mem(1)),
+    ("i64.load16_u", &[0x33], Imm::Mem(1)),
+    ("i64.load32_s", &[0x34], Imm::Mem(2)),
+    ("i64.load32_u", &[0x35], Imm::Mem(2)),
+    ("i32.store", &[0x36], Imm::Mem(2)),
+    ("i64.store", &[0x37], Imm::Mem(3)),
+    ("f32.store", &[0x38], Imm::Mem(2)),
+    ("f64.store", &[0x39], Imm::Mem(3)),
+    ("i32.store8", &[0x3a], Imm::Mem(0)),
+    ("i32.store16", &[0x3b], Imm::Mem(1)),
+    ("i64.store8", &[0x3c], Imm::Mem(0)),
+    ("i64.store16", &[0x3d], Imm::Mem(1)),
+    ("i64.store32", &[0x3e], Imm::Mem(2)),
+    ("memory.size", &[0x3f], Imm::MemIndex),
+    ("memory.grow", &[0x40], Imm::MemIndex),
+    ("i32.const", &[0x41], Imm::I32),
+    ("i64.const", &[0x42], Imm::I64),
+    ("f32.const", &[0x43], Imm::F32),
+    ("f64.const", &[0x44], Imm::F64),
+    ("i32.eqz", &[0x45], Imm::None),
+    ("i32.eq", &[0x46], Imm::None),
+    ("i32.ne", &[0x47], Imm::None),
+    ("i32.lt_s", &[0x48], Imm::None),
+    ("i32.lt_u", &[0x49], Imm::None),
+    ("i32.gt_s", &[0x4a], Imm::None),
+    ("i32.gt_u", &[0x4b], Imm::None),
+    ("i32.le_s", &[0x4c], Imm::None),
+    ("i32.le_u", &[0x4d], Imm::None),
+    ("i32.ge_s", &[0x4e], Imm::None),
+    ("i32.ge_u", &[0x4f], Imm::None),
+    ("i64.eqz", &[0x50], Imm::None),
+    ("i64.eq", &[0x51], Imm::None),
+    ("i64.ne", &[0x52], Imm::None),
+    ("i64.lt_s", &[0x53], Imm::None),
+    ("i64.lt_u", &[0x54], Imm::None),
+    ("i64.gt_s", &[0x55], Imm::None),
+    ("i64.gt_u", &[0x56], Imm::None),
+    ("i64.le_s", &[0x57], Imm::None),
+    ("i64.le_u", &[0x58], Imm::None),
+    ("i64.ge_s", &[0x59], Imm::None),
+    ("i64.ge_u", &[0x5a], Imm::None),
+    ("f64.eq", &[0x61], Imm::None),
+    ("f64.ne", &[0x62], Imm::None),
+    ("f64.lt", &[0x63], Imm::None),
+    ("f64.gt", &[0x64], Imm::None),
+    ("f64.le", &[0x65], Imm::None),
+    ("f64.ge", &[0x66], Imm::None),
+    ("i32.add", &[0x6a], Imm::None),
+    ("i32.sub", &[0x6b], Imm::None),
+    ("i32.mul", &[0x6c], Imm::None),
+    ("i32.div_s", &[0x6d], Imm::None),
+    ("i32.div_u", &[0x6e], Imm::None),
+    ("i32.rem_s", &[0x6f], Imm::None),
+    ("i32.rem_u", &[0x70], Imm::None),
+    ("i32.and", &[0x71], Imm::None),
+    ("i32.or", &[0x72], Imm::None),
+    ("i32.xor", &[0x73], Imm::None),
+    ("i32.shl", &[0x74], Imm::None),
+    ("i32.shr_s", &[0x75], Imm::None),
+    ("i32.shr_u", &[0x76], Imm::None),
+    ("i64.add", &[0x7c], Imm::None),
+    ("i64.sub", &[0x7d], Imm::None),
+    ("i64.mul", &[0x7e], Imm::None),
+    ("i64.div_s", &[0x7f], Imm::None),
+    ("i64.div_u", &[0x80], Imm::None),
+    ("i64.rem_s", &[0x81], Imm::None),
+    ("i64.rem_u", &[0x82], Imm::None),
+    ("i64.and", &[0x83], Imm::None),
+    ("i64.or", &[0x84], Imm::None),
+    ("i64.xor", &[0x85], Imm::None),
+    ("i64.shl", &[0x86], Imm::None),
+    ("i64.shr_s", &[0x87], Imm::None),
+    ("i64.shr_u", &[0x88], Imm::None),
+    ("f64.abs", &[0x99], Imm::None),
+    ("f64.neg", &[0x9a], Imm::None),
+    ("f64.ceil", &[0x9b], Imm::None),
+    ("f64.floor", &[0x9c], Imm::None),
+    ("f64.trunc", &[0x9d], Imm::None),
+    ("f64.nearest", &[0x9e], Imm::None),
+    ("f64.sqrt", &[0x9f], Imm::None),
+    ("f64.add", &[0xa0], Imm::None),
+    ("f64.sub", &[0xa1], Imm::None),
+    ("f64.mul", &[0xa2], Imm::None),
+    ("f64.div", &[0xa3], Imm::None),
+    ("f64.min", &[0xa4], Imm::None),
+    ("f64.max", &[0xa5], Imm::None),
+    ("i32.wrap_i64", &[0xa7], Imm::None),
+    ("i64.extend_i32_s", &[0xac], Imm::None),
+    ("i64.extend_i32_u", &[0xad], Imm::None),
+    ("i64.trunc_f64_s", &[0xb0], Imm::None),
+    ("f64.convert_i32_s", &[0xb7], Imm::None),
+    ("f64.convert_i64_s", &[0xb9], Imm::None),
+    ("i64.reinterpret_f64", &[0xbd], Imm::None),
+    ("f64.reinterpret_i64", &[0xbf], Imm::None),
+    ("i64.trunc_sat_f64_s", &[0xfc, 0x06], Imm::None),
+    ("memory.copy", &[0xfc, 0x0a], Imm::MemIndex2),
+    ("memory.fill", &[0xfc, 0x0b], Imm::MemIndex),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Open,
+    Close,
+    Atom(String),
+    Str(Vec<u8>),
+}
+
+// Kaynağı satır numaralı token'lara ayırır; `;;` satır ve `(; ;)` blok yorumları atlanır.
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, String> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        match c {
+            b'\n' => {
+                line += 1;
+                i += 1;
+            }
+            b' ' | b'\t' | b'\r' => i += 1,
+            b';' if bytes.get(i + 1) == Some(&b';') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'(' if bytes.get(i + 1) == Some(&b';') => {
+                let start = line;
+                i += 2;
+                loop {
+                    match bytes.get(i) {
+                        None => return Err(format!("satır {}: kapanmamış blok yorumu", start)),
+                        Some(b';') if bytes.get(i + 1) == Some(&b')') => break,
+                        Some(b'\n') => line += 1,
+                        _ => {}
+                    }
+                    i += 1;
+                }
+                i += 2;
+            }
+            b'(' => {
+                tokens.push((Token::Open, line));
+                i += 1;
+            }
+            b')' => {
+                tokens.push((Token::Close, line));
+                i += 1;
+            }
+            b'"' => {
+                let (value, next) = string_literal(bytes, i + 1).map_err(|e| format!("satır {}: {}", line, e))?;
+                tokens.push((Token::Str(value), line));
+                i = next;
+            }
+            _ => {
+                let start = i;
+                while i < bytes.len() && !matches!(bytes[i], b' ' | b'\t' | b'\r' | b'\n' | b'(' | b')' | b'"' | b';') {
+                    i += 1;
+                }
+                tokens.push((Token::Atom(source[start..i].to_string()), line));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+// `"` sonrasından başlayan string literalinin byte'ları ve kapanıştan sonraki konum
+fn string_literal(bytes: &[u8], mut i: usize) -> Result<(Vec<u8>, usize), String> {
+    let mut value = Vec::new();
+    loop {
+        match bytes.get(i) {
+            None | Some(b'\n') => return Err("kapanmamış string literali".to_string()),
+            Some(b'"') => return Ok((value, i + 1)),
+            Some(b'\\') => {
+                let escape = *bytes.get(i + 1).ok_or("kapanmamış string literali")?;
+                i += 2;
+                match escape {
+                    b'n' => value.push(b'\n'),
+                    b't' => value.push(b'\t'),
+                    b'r' => value.push(b'\r'),
+                    b'\\' => value.push(b'\\'),
+                    b'"' => value.push(b'"'),
+                    b'\'' => value.push(b'\''),
+                    b'u' if bytes.get(i) == Some(&b'{') => {
+                        let end = bytes[i..].iter().position(|b| *b == b'}').ok_or("geçersiz \\u{...} kaçışı")? + i;
+                        let hex = std::str::from_utf8(&bytes[i + 1..end]).map_err(|_| "geçersiz \\u{...} kaçışı")?;
+                        let c = u32::from_str_radix(hex, 16).ok().and_then(char::from_u32).ok_or("geçersiz \\u{...} kaçışı")?;
+                        let mut buf = [0; 4];
+                        value.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                        i = end + 1;
+                    }
+                    _ => {
+                        let high = (escape as char).to_digit(16);
+                        let low = bytes.get(i).and_then(|b| (*b as char).to_digit(16));
+                        match (high, low) {
+                            (Some(high), Some(low)) => value.push((high * 16 + low) as u8),
+                            _ => return Err(format!("geçersiz kaçış dizisi: \\{}", escape as char)),
+                        }
+                        i += 1;
+                    }
+                }
+            }
+            Some(b) => {
+                value.push(*b);
+                i += 1;
+            }
+        }
+    }
+}
+
+// Kodlanmayı bekleyen komut: isimli işlenenler fonksiyon tablosu hazır olunca çözülür.
+#[derive(Debug, Clone)]
+struct Instr {
+    name: String,
+    args: Vec<String>,
+    // `block`/`loop`/`if` için `(result t)`
+    result: Option<ValType>,
+    line: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Func {
+    name: Option<String>,
+    ty: FuncType,
+    // Parametreler dahil tüm yerellerin isimleri (isimsizler None)
+    local_names: Vec<Option<String>>,
+    locals: Vec<ValType>,
+    body: Vec<Instr>,
+    line: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Import {
+    module: Vec<u8>,
+    field: Vec<u8>,
+    name: Option<String>,
+    ty: FuncType,
+}
+
+#[derive(Debug, Clone)]
+struct Global {
+    name: Option<String>,
+    ty: ValType,
+    mutable: bool,
+    init: Instr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExportKind {
+    Func,
+    Memory,
+    Global,
+}
+
+#[derive(Debug, Clone)]
+struct Export {
+    name: Vec<u8>,
+    kind: ExportKind,
+    target: String,
+    line: usize,
+}
+
+#[derive(Debug, Default)]
+struct Module {
+    imports: Vec<Import>,
+    funcs: Vec<Func>,
+    memory: Option<(u32, Option<u32>)>,
+    globals: Vec<Global>,
+    data: Vec<(u32, Vec<u8>)>,
+    exports: Vec<Export>,
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn line(&self) -> usize {
+        self.tokens.get(self.pos).or(self.tokens.last()).map_or(0, |(_, line)| *line)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> Result<T, String> {
+        Err(format!("satır {}: {}", self.line(), message.into()))
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        match self.tokens.get(self.pos) {
+            Some((token, _)) => {
+                self.pos += 1;
+                Ok(token.clone())
+            }
+            None => self.error("beklenmeyen dosya sonu"),
+        }
+    }
+
+    fn expect(&mut self, expected: Token) -> Result<(), String> {
+        let token = self.next()?;
+        if token == expected {
+            Ok(())
+        } else {
+            self.pos -= 1;
+            self.error(format!("{:?} bekleniyordu, {:?} bulundu", expected, token))
+        }
+    }
+
+    fn atom(&mut self) -> Result<String, String> {
+        match self.next()? {
+            Token::Atom(atom) => Ok(atom),
+            other => {
+                self.pos -= 1;
+                self.error(format!("isim veya sayı bekleniyordu, {:?} bulundu", other))
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<Vec<u8>, String> {
+        match self.next()? {
+            Token::Str(value) => Ok(value),
+            other => {
+                self.pos -= 1;
+                self.error(format!("string bekleniyordu, {:?} bulundu", other))
+            }
+        }
+    }
+
+    fn val_type(&mut self) -> Result<ValType, String> {
+        let name = self.atom()?;
+        match ValType::parse(&name) {
+            Some(ty) => Ok(ty),
+            None => self.error(format!("bilinmeyen değer tipi: '{}'", name)),
+        }
+    }
+
+    // Sıradaki token `(anahtar` ise tüketir.
+    fn open_keyword(&mut self, keyword: &str) -> bool {
+        if self.peek() == Some(&Token::Open) && self.peek_at(1) == Some(&Token::Atom(keyword.to_string())) {
+            self.pos += 2;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn optional_id(&mut self) -> Option<String> {
+        match self.peek() {
+            Some(Token::Atom(atom)) if atom.starts_with('$') => {
+                let id = atom.clone();
+                self.pos += 1;
+                Some(id)
+            }
+            _ => None,
+        }
+    }
+
+    fn module(&mut self) -> Result<Module, String> {
+        let mut module = Module::default();
+        self.expect(Token::Open)?;
+        if self.atom()? != "module" {
+            return self.error("'(module' bekleniyor");
+        }
+        while self.peek() == Some(&Token::Open) {
+            let line = self.line();
+            self.pos += 1;
+            let keyword = self.atom()?;
+            match keyword.as_str() {
+                "import" => self.import(&mut module)?,
+                "func" => self.func(&mut module, line)?,
+                "memory" => self.memory(&mut module)?,
+                "global" => self.global(&mut module)?,
+                "data" => self.data(&mut module)?,
+                "export" => {
+                    let name = self.string()?;
+                    self.expect(Token::Open)?;
+                    let kind = self.export_kind()?;
+                    let target = self.atom()?;
+                    self.expect(Token::Close)?;
+                    self.expect(Token::Close)?;
+                    module.exports.push(Export { name, kind, target, line });
+                }
+                other => return self.error(format!("desteklenmeyen modül alanı: '{}'", other)),
+            }
+        }
+        self.expect(Token::Close)?;
+        if self.peek().is_some() {
+            return self.error("modülden sonra beklenmeyen içerik");
+        }
+        Ok(module)
+    }
+
+    fn export_kind(&mut self) -> Result<ExportKind, String> {
+        match self.atom()?.as_str() {
+            "func" => Ok(ExportKind::Func),
+            "memory" => Ok(ExportKind::Memory),
+            "global" => Ok(ExportKind::Global),
+            other => self.error(format!("desteklenmeyen dışa aktarım türü: '{}'", other)),
+        }
+    }
+
+    // `(param ...)*` ve `(result ...)?`; isimli parametrelerin adları `names`'e eklenir.
+    fn signature(&mut self, names: &mut Vec<Option<String>>) -> Result<FuncType, String> {
+        let mut ty = FuncType { params: Vec::new(), results: Vec::new() };
+        while self.open_keyword("param") {
+            if let Some(id) = self.optional_id() {
+                ty.params.push(self.val_type()?);
+                names.push(Some(id));
+            } else {
+                while self.peek() != Some(&Token::Close) {
+                    ty.params.push(self.val_type()?);
+                    names.push(None);
+                }
+            }
+            self.expect(Token::Close)?;
+        }
+        while self.open_keyword("result") {
+            while self.peek() != Some(&Token::Close) {
+                ty.results.push(self.val_type()?);
+            }
+            self.expect(Token::Close)?;
+        }
+        Ok(ty)
+    }
+
+    fn import(&mut self, module: &mut Module) -> Result<(), String> {
+        let from = self.string()?;
+        let field = self.string()?;
+        if !self.open_keyword("func") {
+            return self.error("yalnızca fonksiyon içe aktarımları destekleniyor");
+        }
+        let name = self.optional_id();
+        let ty = self.signature(&mut Vec::new())?;
+        self.expect(Token::Close)?;
+        self.expect(Token::Close)?;
+        module.imports.push(Import { module: from, field, name, ty });
+        Ok(())
+    }
+
+    fn func(&mut self, module: &mut Module, line: usize) -> Result<(), String> {
+        let name = self.optional_id();
+        while self.open_keyword("export") {
+            let export = self.string()?;
+            self.expect(Token::Close)?;
+            let target = match &name {
+                Some(name) => name.clone(),
+                None => (module.imports.len() + module.funcs.len()).to_string(),
+            };
+            module.exports.push(Export { name: export, kind: ExportKind::Func, target, line });
+        }
+        let mut local_names = Vec::new();
+        let ty = self.signature(&mut local_names)?;
+        let mut locals = Vec::new();
+        while self.open_keyword("local") {
+            if let Some(id) = self.optional_id() {
+                locals.push(self.val_type()?);
+                local_names.push(Some(id));
+            } else {
+                while self.peek() != Some(&Token::Close) {
+                    locals.push(self.val_type()?);
+                    local_names.push(None);
+                }
+            }
+            self.expect(Token::Close)?;
+        }
+        let mut body = Vec::new();
+        while self.peek() != Some(&Token::Close) {
+            body.push(self.instr()?);
+        }
+        self.expect(Token::Close)?;
+        module.funcs.push(Func { name, ty, local_names, locals, body, line });
+        Ok(())
+    }
+
+    // Düz biçimli tek komut ve işlenenleri
+    fn instr(&mut self) -> Result<Instr, String> {
+        let line = self.line();
+        let name = self.atom()?;
+        let Some((_, _, imm)) = INSTRUCTIONS.iter().find(|(n, _, _)| *n == name) else {
+            return self.error(format!("bilinmeyen komut: '{}'", name));
+        };
+        let mut args = Vec::new();
+        let mut result = None;
+        match imm {
+            Imm::None | Imm::MemIndex | Imm::MemIndex2 => {}
+            Imm::Block => {
+                args.extend(self.optional_id());
+                if self.open_keyword("result") {
+                    result = Some(self.val_type()?);
+                    self.expect(Token::Close)?;
+                }
+            }
+            Imm::LabelTable => {
+                while let Some(Token::Atom(atom)) = self.peek() {
+                    if !(atom.starts_with('$') || atom.chars().all(|c| c.is_ascii_digit())) {
+                        break;
+                    }
+                    args.push(self.atom()?);
+                }
+                if args.is_empty() {
+                    return self.error("br_table en az bir etiket bekler");
+                }
+            }
+            Imm::Mem(_) => {
+                while let Some(Token::Atom(atom)) = self.peek() {
+                    if !(atom.starts_with("offset=") || atom.starts_with("align=")) {
+                        break;
+                    }
+                    args.push(self.atom()?);
+                }
+            }
+            _ => args.push(self.atom()?),
+        }
+        Ok(Instr { name, args, result, line })
+    }
+
+    fn memory(&mut self, module: &mut Module) -> Result<(), String> {
+        let name = self.optional_id();
+        while self.open_keyword("export") {
+            let export = self.string()?;
+            self.expect(Token::Close)?;
+            let line = self.line();
+            module.exports.push(Export { name: export, kind: ExportKind::Memory, target: name.clone().unwrap_or_else(|| "0".to_string()), line });
+        }
+        let min = parse_u32(&self.atom()?).map_err(|e| format!("satır {}: {}", self.line(), e))?;
+        let max = match self.peek() {
+            Some(Token::Atom(_)) => Some(parse_u32(&self.atom()?).map_err(|e| format!("satır {}: {}", self.line(), e))?),
+            _ => None,
+        };
+        self.expect(Token::Close)?;
+        if module.memory.replace((min, max)).is_some() {
+            return self.error("modülde yalnızca bir bellek tanımlanabilir");
+        }
+        Ok(())
+    }
+
+    fn global(&mut self, module: &mut Module) -> Result<(), String> {
+        let name = self.optional_id();
+        let (ty, mutable) = if self.open_keyword("mut") {
+            let ty = self.val_type()?;
+            self.expect(Token::Close)?;
+            (ty, true)
+        } else {
+            (self.val_type()?, false)
+        };
+        self.expect(Token::Open)?;
+        let init = self.instr()?;
+        self.expect(Token::Close)?;
+        self.expect(Token::Close)?;
+        module.globals.push(Global { name, ty, mutable, init });
+        Ok(())
+    }
+
+    fn data(&mut self, module: &mut Module) -> Result<(), String> {
+        self.expect(Token::Open)?;
+        let offset = self.instr()?;
+        self.expect(Token::Close)?;
+        if offset.name != "i32.const" {
+            return self.error("data ofseti (i32.const N) olmalı");
+        }
+        let offset = parse_int(&offset.args[0]).map_err(|e| format!("satır {}: {}", offset.line, e))? as u32;
+        let mut bytes = Vec::new();
+        while let Some(Token::Str(_)) = self.peek() {
+            bytes.extend(self.string()?);
+        }
+        self.expect(Token::Close)?;
+        module.data.push((offset, bytes));
+        Ok(())
+    }
+}
+
+// Ondalık, onaltılık (0x) ve `_` ayraçlı tamsayılar; negatifler ikinin tümleyeni olarak döner.
+fn parse_int(text: &str) -> Result<i64, String> {
+    let clean: String = text.chars().filter(|c| *c != '_').collect();
+    let (negative, digits) = match clean.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, clean.strip_prefix('+').unwrap_or(&clean)),
+    };
+    let value = match digits.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => digits.parse::<u64>(),
+    }
+    .map_err(|_| format!("geçersiz sayı: '{}'", text))?;
+    Ok(if negative { (value as i64).wrapping_neg() } else { value as i64 })
+}
+
+fn parse_u32(text: &str) -> Result<u32, String> {
+    let value = parse_int(text)?;
+    u32::try_from(value).map_err(|_| format!("sayı 32 bit aralığı dışında: '{}'", text))
+}
+
+fn parse_float(text: &str) -> Result<f64, String> {
+    let clean: String = text.chars().filter(|c| *c != '_').collect();
+    match clean.as_str() {
+        "inf" | "+inf" => Ok(f64::INFINITY),
+        "-inf" => Ok(f64::NEG_INFINITY),
+        "nan" | "+nan" => Ok(f64::NAN),
+        "-nan" => Ok(-f64::NAN),
+        _ => clean.parse::<f64>().or_else(|_| parse_int(&clean).map(|v| v as f64)).map_err(|_| format!("geçersiz ondalık sayı: '{}'", text)),
+    }
+}
+
+fn name_bytes(out: &mut Vec<u8>, name: &[u8]) {
+    uleb128(out, name.len() as u64);
+    out.extend_from_slice(name);
+}
+
+fn section(out: &mut Vec<u8>, id: u8, contents: Vec<u8>) {
+    out.push(id);
+    uleb128(out, contents.len() as u64);
+    out.extend(contents);
+}
+
+fn encode_type(out: &mut Vec<u8>, ty: &FuncType) {
+    out.push(0x60);
+    uleb128(out, ty.params.len() as u64);
+    out.extend(ty.params.iter().map(|t| t.code()));
+    uleb128(out, ty.results.len() as u64);
+    out.extend(ty.results.iter().map(|t| t.code()));
+}
+
+// Komut gövdelerini kodlarken gereken isim tabloları
+struct Encoder<'m> {
+    funcs: HashMap<&'m str, u32>,
+    globals: HashMap<&'m str, u32>,
+}
+
+impl Encoder<'_> {
+    fn index(table: &HashMap<&str, u32>, what: &str, arg: &str) -> Result<u32, String> {
+        if arg.starts_with('$') {
+            table.get(arg).copied().ok_or_else(|| format!("tanımsız {}: '{}'", what, arg))
+        } else {
+            parse_u32(arg)
+        }
+    }
+
+    // `labels`: açık blokların etiketleri (en içteki sonda)
+    fn instr(&self, out: &mut Vec<u8>, instr: &Instr, locals: &[Option<String>], labels: &mut Vec<Option<String>>) -> Result<(), String> {
+        let (_, opcode, imm) = INSTRUCTIONS.iter().find(|(n, _, _)| *n == instr.name).expect("ayrıştırıcı komutu doğruladı");
+        out.extend_from_slice(opcode);
+        let label_depth = |arg: &str, labels: &[Option<String>]| -> Result<u64, String> {
+            if arg.starts_with('$') {
+                labels
+                    .iter()
+                    .rev()
+                    .position(|l| l.as_deref() == Some(arg))
+                    .map(|depth| depth as u64)
+                    .ok_or_else(|| format!("tanımsız etiket: '{}'", arg))
+            } else {
+                parse_u32(arg).map(u64::from)
+            }
+        };
+        match imm {
+            Imm::None => match instr.name.as_str() {
+                "end" => {
+                    labels.pop().ok_or("eşleşmeyen 'end'")?;
+                }
+                "else" if labels.is_empty() => return Err("eşleşmeyen 'else'".to_string()),
+                _ => {}
+            },
+            Imm::Block => {
+                out.push(instr.result.map_or(0x40, ValType::code));
+                labels.push(instr.args.first().cloned());
+            }
+            Imm::Label => uleb128(out, label_depth(&instr.args[0], labels)?),
+            Imm::LabelTable => {
+                let (default, targets) = instr.args.split_last().expect("en az bir etiket");
+                uleb128(out, targets.len() as u64);
+                for target in targets {
+                    uleb128(out, label_depth(target, labels)?);
+                }
+                uleb128(out, label_depth(default, labels)?);
+            }
+            Imm::Func => uleb128(out, u64::from(Self::index(&self.funcs, "fonksiyon", &instr.args[0])?)),
+            Imm::Global => uleb128(out, u64::from(Self::index(&self.globals, "global", &instr.args[0])?)),
+            Imm::Local => {
+                let arg = &instr.args[0];
+                let index = if arg.starts_with('$') {
+                    locals.iter().position(|l| l.as_deref() == Some(arg.as_str())).ok_or_else(|| format!("tanımsız yerel: '{}'", arg))? as u64
+                } else {
+                    u64::from(parse_u32(arg)?)
+                };
+                uleb128(out, index);
+            }
+            Imm::Mem(natural) => {
+                let (mut align, mut offset) = (*natural, 0);
+                for arg in &instr.args {
+                    if let Some(value) = arg.strip_prefix("offset=") {
+                        offset = parse_u32(value)?;
+                    } else if let Some(value) = arg.strip_prefix("align=") {
+                        let bytes = parse_u32(value)?;
+                        if !bytes.is_power_of_two() {
+                            return Err(format!("hizalama ikinin kuvveti olmalı: {}", bytes));
+                        }
+                        align = bytes.trailing_zeros();
+                    }
+                }
+                uleb128(out, u64::from(align));
+                uleb128(out, u64::from(offset));
+            }
+            Imm::I32 => sleb128(out, parse_int(&instr.args[0])? as i32 as i64),
+            Imm::I64 => sleb128(out, parse_int(&instr.args[0])?),
+            Imm::F32 => out.extend((parse_float(&instr.args[0])? as f32).to_le_bytes()),
+            Imm::F64 => out.extend(parse_float(&instr.args[0])?.to_le_bytes()),
+            Imm::MemIndex => out.push(0),
+            Imm::MemIndex2 => out.extend([0, 0]),
+        }
+        Ok(())
+    }
+}
+
+// WAT metnini ikili modüle çevirir. Hata mesajı "satır N: ..." biçimindedir.
+pub fn assemble(source: &str) -> Result<Vec<u8>, String> {
+    let mut parser = Parser { tokens: tokenize(source)?, pos: 0 };
+    let module = parser.module()?;
+
+    let mut types: Vec<FuncType> = Vec::new();
+    let mut type_index = |ty: &FuncType| match types.iter().position(|t| t == ty) {
+        Some(index) => index as u64,
+        None => {
+            types.push(ty.clone());
+            (types.len() - 1) as u64
+        }
+    };
+
+    let mut encoder = Encoder { funcs: HashMap::new(), globals: HashMap::new() };
+    let names = module.imports.iter().map(|i| &i.name).chain(module.funcs.iter().map(|f| &f.name));
+    for (index, name) in names.enumerate() {
+        if let Some(name) = name {
+            if encoder.funcs.insert(name.as_str(), index as u32).is_some() {
+                return Err(format!("fonksiyon birden fazla tanımlanmış: '{}'", name));
+            }
+        }
+    }
+    for (index, global) in module.globals.iter().enumerate() {
+        if let Some(name) = &global.name {
+            encoder.globals.insert(name.as_str(), index as u32);
+        }
+    }
+
+    let mut imports = Vec::new();
+    uleb128(&mut imports, module.imports.len() as u64);
+    for import in &module.imports {
+        name_bytes(&mut imports, &import.module);
+        name_bytes(&mut imports, &import.field);
+        imports.push(0x00);
+        uleb128(&mut imports, type_index(&import.ty));
+    }
+
+    let mut functions = Vec::new();
+    uleb128(&mut functions, module.funcs.len() as u64);
+    for func in &module.funcs {
+        uleb128(&mut functions, type_index(&func.ty));
+    }
+
+    let mut code = Vec::new();
+    uleb128(&mut code, module.funcs.len() as u64);
+    for func in &module.funcs {
+        let mut body = Vec::new();
+        // Ardışık aynı tipteki yereller tek grup olarak yazılır.
+        let mut groups: Vec<(u32, ValType)> = Vec::new();
+        for ty in &func.locals {
+            match groups.last_mut() {
+                Some((count, last)) if last == ty => *count += 1,
+                _ => groups.push((1, *ty)),
+            }
+        }
+        uleb128(&mut body, groups.len() as u64);
+        for (count, ty) in groups {
+            uleb128(&mut body, u64::from(count));
+            body.push(ty.code());
+        }
+        // Fonksiyon gövdesinin kendisi de `end` ile kapanan bir bloktur.
+        let mut labels = vec![None];
+        for instr in &func.body {
+            encoder.instr(&mut body, instr, &func.local_names, &mut labels).map_err(|e| format!("satır {}: {}", instr.line, e))?;
+        }
+        if labels.len() != 1 {
+            return Err(format!("satır {}: fonksiyonda kapanmamış blok var", func.line));
+        }
+        body.push(0x0b);
+        uleb128(&mut code, body.len() as u64);
+        code.extend(body);
+    }
+
+    let mut globals = Vec::new();
+    uleb128(&mut globals, module.globals.len() as u64);
+    for global in &module.globals {
+        globals.push(global.ty.code());
+        globals.push(u8::from(global.mutable));
+        encoder.instr(&mut globals, &global.init, &[], &mut Vec::new()).map_err(|e| format!("satır {}: {}", global.init.line, e))?;
+        globals.push(0x0b);
+    }
+
+    let mut exports = Vec::new();
+    uleb128(&mut exports, module.exports.len() as u64);
+    for export in &module.exports {
+        name_bytes(&mut exports, &export.name);
+        let (kind, index) = match export.kind {
+            ExportKind::Func => (0x00, Encoder::index(&encoder.funcs, "fonksiyon", &export.target)),
+            ExportKind::Memory => (0x02, if export.target.starts_with('$') { Ok(0) } else { parse_u32(&export.target) }),
+            ExportKind::Global => (0x03, Encoder::index(&encoder.globals, "global", &export.target)),
+        };
+        exports.push(kind);
+        uleb128(&mut exports, u64::from(index.map_err(|e| format!("satır {}: {}", export.line, e))?));
+    }
+
+    let mut data = Vec::new();
+    uleb128(&mut data, module.data.len() as u64);
+    for (offset, bytes) in &module.data {
+        data.push(0x00);
+        data.push(0x41);
+        sleb128(&mut data, *offset as i32 as i64);
+        data.push(0x0b);
+        uleb128(&mut data, bytes.len() as u64);
+        data.extend_from_slice(bytes);
+    }
+
+    let mut type_section = Vec::new();
+    uleb128(&mut type_section, types.len() as u64);
+    for ty in &types {
+        encode_type(&mut type_section, ty);
+    }
+
+    // Başlık ("\0asm", sürüm 1) ve bölümler kimlik sırasıyla
+    let mut out = b"\0asm\x01\0\0\0".to_vec();
+    section(&mut out, 1, type_section);
+    section(&mut out, 2, imports);
+    section(&mut out, 3, functions);
+    if let Some((min, max)) = module.memory {
+        let mut memory = vec![1];
+        match max {
+            Some(max) => {
+                memory.push(0x01);
+                uleb128(&mut memory, u64::from(min));
+                uleb128(&mut memory, u64::from(max));
+            }
+            None => {
+                memory.push(0x00);
+                uleb128(&mut memory, u64::from(min));
+            }
+        }
+        section(&mut out, 5, memory);
+    }
+    section(&mut out, 6, globals);
+    section(&mut out, 7, exports);
+    section(&mut out, 10, code);
+    section(&mut out, 11, data);
+    Ok(out)
+}
@@ -1,5 +1,7 @@
 //use crate::token::TokenType;
-//use std::collections::HashMap;
+use std::collections::HashMap;
+use std::fmt;
+use crate::diagnostic::Span;
 
 // YENİ: Derleme hedefini belirten enum.
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -7,9 +9,18 @@ pub enum TargetPlatform {
     Windows,
     Linux,
     Macos,
+    Wasi, // WebAssembly (--target wasm32-wasi)
     Unknown, // Varsayılan veya belirtilmemiş
 }
 
+// Derleme hedefinin işlemci mimarisi (--arch).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TargetArch {
+    X86_64,
+    Aarch64,
+    Wasm32,
+}
+
 // Tipi temsil eden enum
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Type {
@@ -67,9 +78,39 @@ pub enum Type {
     Future(Box<Type>),
     Channel(Box<Type>),
     Result(Box<Type>, Box<Type>), // Result<T, E>
+    Option(Box<Type>), // YENİ: Option<T>
+    // YENİ: Jenerik bildirimin tip parametresi (`fn kutu_yap<T>(deger: T)` içindeki T).
+    Param(String),
+    // YENİ: Jenerik struct veya typedef örneği (`Kutu<i32>`). Monomorphization sonrası kalmaz.
+    Generic(String, Vec<Type>),
     Unknown, // Tip çıkarılamadığında
 }
 
+// YENİ: Jenerik tip parametresi ve sınırları (`T`, `T: Num + Ord`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeParam {
+    pub name: String,
+    pub bounds: Vec<String>,
+}
+
+impl fmt::Display for TypeParam {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.bounds.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{}: {}", self.name, self.bounds.join(" + "))
+        }
+    }
+}
+
+// `<T, U: Num>` gösterimi; parametre yoksa boş metin.
+pub fn type_params_str(params: &[TypeParam]) -> String {
+    if params.is_empty() {
+        return String::new();
+    }
+    format!("<{}>", params.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(", "))
+}
+
 impl Type {
     // YENİ: is_array() yardımcı fonksiyonu
     pub fn is_array(&self) -> bool {
@@ -89,6 +130,68 @@ impl Type {
         matches!(self, Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128)
     }
 
+    // YENİ: Tamsayı tiplerinin değer aralığı (en küçük, en büyük). `byte` ve `hex` 8 bitlik işaretsiz
+    // tiplerdir; u128'in üst sınırı i128'e sığmadığından i128::MAX ile sınırlanır.
+    pub fn int_range(&self) -> Option<(i128, i128)> {
+        Some(match self {
+            Type::I8 => (i8::MIN.into(), i8::MAX.into()),
+            Type::I16 => (i16::MIN.into(), i16::MAX.into()),
+            Type::I32 => (i32::MIN.into(), i32::MAX.into()),
+            Type::I64 => (i64::MIN.into(), i64::MAX.into()),
+            Type::I128 => (i128::MIN, i128::MAX),
+            Type::U8 | Type::Byte | Type::Hex => (0, u8::MAX.into()),
+            Type::U16 => (0, u16::MAX.into()),
+            Type::U32 => (0, u32::MAX.into()),
+            Type::U64 => (0, u64::MAX.into()),
+            Type::U128 => (0, i128::MAX),
+            _ => return None,
+        })
+    }
+
+    // YENİ: Tip parametrelerini `bindings`'teki tiplerle değiştirir (bağlı olmayanlar olduğu gibi kalır).
+    pub fn substitute(&self, bindings: &HashMap<String, Type>) -> Type {
+        let all = |types: &[Type]| types.iter().map(|t| t.substitute(bindings)).collect();
+        match self {
+            Type::Param(name) => bindings.get(name).cloned().unwrap_or_else(|| self.clone()),
+            Type::Generic(name, args) => Type::Generic(name.clone(), all(args)),
+            Type::Tuple(types) => Type::Tuple(all(types)),
+            Type::ArrayLiteral(types) => Type::ArrayLiteral(all(types)),
+            Type::Array(inner, size) => Type::Array(Box::new(inner.substitute(bindings)), *size),
+            Type::Ptr(inner) => Type::Ptr(Box::new(inner.substitute(bindings))),
+            Type::Ref(inner) => Type::Ref(Box::new(inner.substitute(bindings))),
+            Type::Future(inner) => Type::Future(Box::new(inner.substitute(bindings))),
+            Type::Channel(inner) => Type::Channel(Box::new(inner.substitute(bindings))),
+            Type::Option(inner) => Type::Option(Box::new(inner.substitute(bindings))),
+            Type::Result(ok, err) => Type::Result(Box::new(ok.substitute(bindings)), Box::new(err.substitute(bindings))),
+            Type::Fn(params, ret) => Type::Fn(all(params), Box::new(ret.substitute(bindings))),
+            _ => self.clone(),
+        }
+    }
+
+    // YENİ: Tip bir jenerik örnek (Kutu<i32>) içeriyor mu?
+    pub fn has_generics(&self) -> bool {
+        match self {
+            Type::Generic(..) => true,
+            Type::Tuple(types) | Type::ArrayLiteral(types) => types.iter().any(Type::has_generics),
+            Type::Array(inner, _) | Type::Ptr(inner) | Type::Ref(inner) | Type::Future(inner) | Type::Channel(inner) | Type::Option(inner) => inner.has_generics(),
+            Type::Result(ok, err) => ok.has_generics() || err.has_generics(),
+            Type::Fn(params, ret) => params.iter().any(Type::has_generics) || ret.has_generics(),
+            _ => false,
+        }
+    }
+
+    // YENİ: Tip bir tip parametresi içeriyor mu?
+    pub fn has_params(&self) -> bool {
+        match self {
+            Type::Param(_) => true,
+            Type::Generic(_, types) | Type::Tuple(types) | Type::ArrayLiteral(types) => types.iter().any(Type::has_params),
+            Type::Array(inner, _) | Type::Ptr(inner) | Type::Ref(inner) | Type::Future(inner) | Type::Channel(inner) | Type::Option(inner) => inner.has_params(),
+            Type::Result(ok, err) => ok.has_params() || err.has_params(),
+            Type::Fn(params, ret) => params.iter().any(Type::has_params) || ret.has_params(),
+            _ => false,
+        }
+    }
+
     pub fn can_be_assigned_from(&self, other: &Type) -> bool {
         if self == other {
             return true;
@@ -115,13 +218,64 @@ impl Type {
 
             // 'any' tipine her şey atanabilir
             (Type::Any, _) => true,
+
+            // YENİ: Ok(x), Err(e), None gibi kurucuların tipi bilinmeyen (Any) tarafı her tiple uyumludur.
+            (Type::Result(ok, err), Type::Result(other_ok, other_err)) => {
+                (**other_ok == Type::Any || ok.can_be_assigned_from(other_ok))
+                    && (**other_err == Type::Any || err.can_be_assigned_from(other_err))
+            }
+            (Type::Option(inner), Type::Option(other)) => **other == Type::Any || inner.can_be_assigned_from(other),
             _ => false,
         }
     }
 }
 
+// YENİ: Tiplerin kaynak koddaki yazımına yakın, kararlı metin gösterimi (--emit ast/typed-ast).
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |types: &[Type]| types.iter().map(|t| t.to_string()).collect::<Vec<_>>().join(", ");
+        match self {
+            Type::I8 => write!(f, "i8"), Type::I16 => write!(f, "i16"), Type::I32 => write!(f, "i32"),
+            Type::I64 => write!(f, "i64"), Type::I128 => write!(f, "i128"),
+            Type::U8 => write!(f, "u8"), Type::U16 => write!(f, "u16"), Type::U32 => write!(f, "u32"),
+            Type::U64 => write!(f, "u64"), Type::U128 => write!(f, "u128"),
+            Type::F32 => write!(f, "f32"), Type::F64 => write!(f, "f64"), Type::F80 => write!(f, "f80"),
+            Type::F128 => write!(f, "f128"),
+            Type::D32 => write!(f, "d32"), Type::D64 => write!(f, "d64"), Type::D128 => write!(f, "d128"),
+            Type::Hex => write!(f, "hex"),
+            Type::Bool => write!(f, "bool"),
+            Type::Char => write!(f, "char"),
+            Type::Bit => write!(f, "bit"),
+            Type::Byte => write!(f, "byte"),
+            Type::Null => write!(f, "null"),
+            Type::Void => write!(f, "void"),
+            Type::Never => write!(f, "never"),
+            Type::Tuple(types) => write!(f, "({})", list(types)),
+            Type::Array(inner, Some(size)) => write!(f, "{}[{}]", inner, size),
+            Type::Array(inner, None) => write!(f, "{}[]", inner),
+            Type::Arr => write!(f, "arr"),
+            Type::ArrayLiteral(types) => write!(f, "[{}]", list(types)),
+            Type::Ptr(inner) => write!(f, "*{}", inner),
+            Type::Ref(inner) => write!(f, "&{}", inner),
+            Type::Str(Some(len)) => write!(f, "str({})", len),
+            Type::Str(None) => write!(f, "str"),
+            Type::Any => write!(f, "any"),
+            Type::Custom(name) | Type::Enum(name, _) => write!(f, "{}", name),
+            Type::Fn(params, ret) => write!(f, "fn({}): {}", list(params), ret),
+            Type::Future(inner) => write!(f, "future<{}>", inner),
+            Type::Channel(inner) => write!(f, "chan<{}>", inner),
+            Type::Result(ok, err) => write!(f, "result<{}, {}>", ok, err),
+            Type::Option(inner) => write!(f, "option<{}>", inner),
+            Type::Param(name) => write!(f, "{}", name),
+            Type::Generic(name, args) => write!(f, "{}<{}>", name, list(args)),
+            Type::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
 // YENİ: Desteklenen mimariler için CPU register'larını temsil eder.
 #[allow(dead_code)] // Codegen aşamasında kullanılacağı için şimdilik uyarıyı bastır.
+#[allow(clippy::upper_case_acronyms)] // Register isimleri assembly'deki gibi yazılır.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Register {
     // x86_64 Genel Amaçlı Register'lar
@@ -130,9 +284,11 @@ pub enum Register {
     R8, R9, R10, R11,
     R12, R13, R14, R15,
 
-    // Diğer mimariler için register'lar buraya eklenebilir.
-    // ARM
-    // R0, R1, ...
+    // AArch64 Genel Amaçlı Register'lar (X29 çerçeve, X30 dönüş adresi register'ıdır)
+    X0, X1, X2, X3, X4, X5, X6, X7,
+    X8, X9, X10, X11, X12, X13, X14, X15,
+    X16, X17, X18, X19, X20, X21, X22, X23,
+    X24, X25, X26, X27, X28, X29, X30, SP,
 }
 
 impl Type {
@@ -170,6 +326,26 @@ pub enum BinOp {
     LShift, RShift,
 }
 
+impl BinOp {
+    // Operatörün kaynak koddaki yazımı.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            BinOp::Add => "+", BinOp::Sub => "-", BinOp::Mul => "*", BinOp::Div => "/", BinOp::Mod => "%",
+            BinOp::Equal | BinOp::Eq => "==",
+            BinOp::NotEqual | BinOp::Ne => "!=",
+            BinOp::Greater | BinOp::Gt => ">",
+            BinOp::Less | BinOp::Lt => "<",
+            BinOp::GreaterEqual | BinOp::Ge => ">=",
+            BinOp::LessEqual | BinOp::Le => "<=",
+            BinOp::Identical => "===",
+            BinOp::NotIdentical => "!==",
+            BinOp::And => "&&", BinOp::Or => "||",
+            BinOp::BitwiseAnd => "&", BinOp::BitwiseOr => "|", BinOp::BitwiseXor => "^",
+            BinOp::LShift => "<<", BinOp::RShift => ">>",
+        }
+    }
+}
+
 // YENİ: UnOp enum'una derive özellikleri ekleniyor
 #[derive(Debug, Clone)] 
 pub enum UnOp {
@@ -186,10 +362,49 @@ pub enum UnOp {
     Deref,      // *
 }
 
+impl UnOp {
+    // Operatörün kaynak koddaki yazımı (PostInc/PostDec ifadeden sonra yazılır).
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            UnOp::Neg => "-", UnOp::Not => "!",
+            UnOp::PreInc | UnOp::PostInc => "++",
+            UnOp::PreDec | UnOp::PostDec => "--",
+            UnOp::BitwiseNot => "~", UnOp::AddressOf => "&", UnOp::Deref => "*",
+        }
+    }
+}
+
 // İfadeler (Değer dönen yapılar)
+// YENİ: Her düğüm kaynak konumunu (span) taşır; düğümün türü `kind` alanındadır.
+#[derive(Debug, Clone)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+impl Expr {
+    pub fn new(kind: ExprKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    // Kaynakta karşılığı olmayan (derleyicinin ürettiği) ifadeler için.
+    pub fn synthetic(kind: ExprKind) -> Self {
+        Self { kind, span: Span::dummy() }
+    }
+
+    // YENİ: Sayısal üye erişimi (`t.0`, `x.2`): nesne ve basamak. Tuple'da eleman indeksidir; bir
+    // yer tutucunun (`"{x.2}"`) en dış ifadesi olup nesne float ise ondalık basamak sayısıdır.
+    pub fn numeric_member(&self) -> Option<(&Expr, usize)> {
+        match &self.kind {
+            ExprKind::MemberAccess { object, member } => member.parse().ok().map(|digits| (&**object, digits)),
+            _ => None,
+        }
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
-pub enum Expr {
+pub enum ExprKind {
     Literal(LiteralValue),
     Variable(String),
     
@@ -243,6 +458,7 @@ pub enum Expr {
     // YENİ: Struct oluşturma ifadesi (örn: Point { x: 10, y: 20 })
     StructLiteral {
         name: String,
+        type_args: Vec<Type>, // Jenerik struct'a açıkça verilen tip argümanları (Kutu<i32> { ... }); boşsa çıkarılır
         fields: Vec<(String, Expr)>,
     },
     SizeOf(Type), // sizeof(type)
@@ -251,12 +467,50 @@ pub enum Expr {
     Recv(Box<Expr>), // <-ch
 
 }
+
+impl ExprKind {
+    // Hata mesajları için ifade türünün adı (Debug çıktısı yerine).
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExprKind::Literal(_) => "literal",
+            ExprKind::Variable(_) => "değişken",
+            ExprKind::Tuple(_) => "tuple",
+            ExprKind::ArrayLiteral(_) => "dizi literali",
+            ExprKind::Match { .. } => "match",
+            ExprKind::Input(_) => "input",
+            ExprKind::Block { .. } => "blok ifadesi",
+            ExprKind::DefaultCase => "varsayılan durum (_)",
+            ExprKind::ArrayAccess { .. } => "dizi erişimi",
+            ExprKind::MemberAccess { .. } => "üye erişimi",
+            ExprKind::Range { .. } => "aralık",
+            ExprKind::Binary { .. } => "ikili işlem",
+            ExprKind::Unary { .. } => "tekli işlem",
+            ExprKind::Conditional { .. } => "koşul ifadesi (?:)",
+            ExprKind::Await(_) => "await",
+            ExprKind::Assign { .. } => "atama",
+            ExprKind::Call { .. } => "çağrı",
+            ExprKind::Lambda { .. } => "lambda",
+            ExprKind::InterpolatedString(_) => "string interpolasyonu",
+            ExprKind::Try(_) => "'?' operatörü",
+            ExprKind::EnumAccess { .. } => "enum erişimi",
+            ExprKind::StructLiteral { .. } => "struct literali",
+            ExprKind::SizeOf(_) => "sizeof",
+            ExprKind::Send { .. } | ExprKind::Recv(_) => "kanal işlemi",
+        }
+    }
+}
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub enum LiteralValue {
-    Int(i64),
+    // YENİ: Soneksiz tamsayı sabiti (u64 aralığında). Önündeki eksi işareti ayrı bir Unary düğümüdür;
+    // değerin bildirilen tipin aralığında olduğunu tip denetleyicisi denetler.
+    Int(u64),
     Float(f64),
     Hex(u64),
+    // YENİ: Tip sonekli sabitler (`10u8`, `100i128`, `3.0f32`). Tamsayı değeri 128 bit saklanır;
+    // lexer değerin sonekin aralığında olduğunu denetlemiştir.
+    TypedInt(u128, Type),
+    TypedFloat(f64, Type),
     Char(char),
     Str(String),
     Bool(bool),
@@ -264,9 +518,22 @@ pub enum LiteralValue {
 }
 
 // İfadeler (İşlem yapan yapılar)
+#[derive(Debug, Clone)]
+pub struct Stmt {
+    pub kind: StmtKind,
+    pub span: Span,
+}
+
+impl Stmt {
+    pub fn new(kind: StmtKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+}
+
 #[allow(dead_code)]
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
-pub enum Stmt {
+pub enum StmtKind {
     // GÜNCELLENDİ: 'is_const' ve 'is_mutable' alanları eklendi
     VarDecl { 
         name: String, 
@@ -326,29 +593,104 @@ pub enum Stmt {
         label: String,
         stmt: Box<Stmt>,
         is_public: bool, // Metotların pub olması için eklendi
+        doc: Option<String>, // Üyenin önündeki `///` / `/** */` yorumu (nim doc)
     },
     Routine(Box<Expr>),
     Unsafe(Box<Stmt>),
     // YENİ: fastexec ve asm blokları
     FastExec(Box<Stmt>),
+    // YENİ: defer { ... } - içinde bulunduğu blok bittiğinde (return dahil) çalıştırılır.
+    Defer(Box<Stmt>),
     Asm {
         tag: String,
         body: String,
     },
 }
+
+// asm gövdesini satır satır açar: `//` yorumları ve boş satırlar atılır, her `%isim` operandı
+// `operand` kapanışının döndürdüğü metinle değiştirilir.
+pub fn expand_asm_body(body: &str, mut operand: impl FnMut(&str) -> String) -> Vec<String> {
+    let mut lines = Vec::new();
+    for line in body.lines() {
+        let code = line.split("//").next().unwrap_or("").trim();
+        if code.is_empty() {
+            continue;
+        }
+        let mut out = String::new();
+        let mut chars = code.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '%' && chars.peek().is_some_and(|n| n.is_alphabetic() || *n == '_') {
+                let mut name = String::new();
+                while let Some(&n) = chars.peek().filter(|n| n.is_alphanumeric() || **n == '_') {
+                    name.push(n);
+                    chars.next();
+                }
+                out.push_str(&operand(&name));
+            } else {
+                out.push(c);
+            }
+        }
+        lines.push(out);
+    }
+    lines
+}
+
+// asm gövdesinin `%isim` ile başvurduğu değişkenler (ilk görülme sırasıyla, tekrarsız).
+pub fn asm_operands(body: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    expand_asm_body(body, |name| {
+        if !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+        String::new()
+    });
+    names
+}
+
+// Struct'ın bellekteki 8 byte'lık kelimelerinin tipleri (struct değilse None). İç içe struct
+// alanları yerinde açılır; alan ofsetleri ve ABI sınıflandırması bu düzene dayanır.
+pub fn struct_layout(program: &[Decl], struct_name: &str) -> Option<Vec<Type>> {
+    let fields = program.iter().find_map(|decl| match decl {
+        Decl::Struct { name, fields, .. } if name == struct_name => Some(fields),
+        _ => None,
+    })?;
+    let mut words = Vec::new();
+    for (_, f_ty) in fields {
+        match f_ty {
+            Type::Custom(inner) => match struct_layout(program, inner) {
+                Some(inner_words) => words.extend(inner_words),
+                None => words.push(f_ty.clone()),
+            },
+            _ => words.push(f_ty.clone()),
+        }
+    }
+    Some(words)
+}
+
+// Bir değerin struct düzeninde kapladığı kelime sayısı
+pub fn layout_words(program: &[Decl], ty: &Type) -> usize {
+    match ty {
+        Type::Custom(name) => struct_layout(program, name).map_or(1, |words| words.len()),
+        _ => 1,
+    }
+}
 // Üst Düzey Tanımlamalar (Global scope)
 #[allow(dead_code)]
+#[allow(clippy::large_enum_variant, clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub enum Decl {
     Module(String), // :network;
     Function {
         name: String,
+        type_params: Vec<TypeParam>, // YENİ: Jenerik tip parametreleri (fn ad<T: Num>)
         params: Vec<(String, Type, Option<Expr>)>,
         return_type: Type,
         body: Stmt, // Block
         is_inline: bool,
         is_async: bool,
         is_public: bool, // Fonksiyonların pub olması için eklendi
+        doc: Option<String>, // YENİ: `///` veya `/** */` doküman yorumu (`nim doc`)
+        span: Span,
     },
     // YENİ: Dış (C) fonksiyon bildirimi
     ExternFn {
@@ -356,31 +698,44 @@ pub enum Decl {
         params: Vec<(String, Type, Option<Expr>)>,
         return_type: Type,
         is_public: bool,
+        doc: Option<String>,
+        span: Span,
     },
     Group {
         name: String,
+        type_params: Vec<TypeParam>, // YENİ: Üye fonksiyonların paylaştığı tip parametreleri (group Yigin<T>)
         is_export: bool,
         params: Vec<(String, Type, Option<Expr>)>, // group HTTP(param: type = default)
         return_type: Type,
         body: Vec<Decl>, // Group içindeki bildirimler (fonksiyon, const, vs.)
+        doc: Option<String>,
+        span: Span,
     },
 	// Program dışındaki diğer Decl'leri kullanmıyorsanız silebilirsiniz
     Struct { 
         name: String, 
+        type_params: Vec<TypeParam>,
         fields: Vec<(String, Type)>,
         is_public: bool,
+        doc: Option<String>,
+        span: Span,
     },
     // YENİ: Enum Tanımı
     Enum {
         name: String,
         variants: Vec<(String, Option<Expr>)>, // Variant adı ve opsiyonel değeri
         is_public: bool,
+        doc: Option<String>,
+        span: Span,
     },
     // YENİ: Tip Takma Adı Tanımı (typedef)
     Typedef {
         name: String,
+        type_params: Vec<TypeParam>,
         target: Type,
         is_public: bool,
+        doc: Option<String>,
+        span: Span,
     },
     // YENİ: `use` bildiriminin neyi içeri aktardığını belirtir (Ayrı bir enum olarak).
     // Bu enum, Decl'in dışında tanımlanmalıdır.
@@ -393,17 +748,37 @@ pub enum Decl {
         path: Vec<String>, 
         spec: UseSpec, // Artık ayrı bir enum tipi
         is_export: bool, // `export use ...` için eklendi
+        span: Span,
     },
     // YENİ: Stil Tanımı (style Name = "ANSI_CODE")
     Style {
         name: String,
         code: String,
+        span: Span,
     },
 	// YENİ: Programın tamamını temsil eden varyant
     Program(Vec<Decl>),
 	StmtDecl(Box<Stmt>),
 }
 
+impl Decl {
+    // Bildirimin kaynak konumu (hata mesajlarında işaretlenecek aralık).
+    pub fn span(&self) -> Span {
+        match self {
+            Decl::Function { span, .. }
+            | Decl::ExternFn { span, .. }
+            | Decl::Group { span, .. }
+            | Decl::Struct { span, .. }
+            | Decl::Enum { span, .. }
+            | Decl::Typedef { span, .. }
+            | Decl::Use { span, .. }
+            | Decl::Style { span, .. } => *span,
+            Decl::StmtDecl(stmt) => stmt.span,
+            Decl::Module(_) | Decl::Program(_) => Span::dummy(),
+        }
+    }
+}
+
 // UseSpec enum'unu Decl enum'unun dışına taşıyoruz
 #[derive(Debug, Clone, PartialEq)]
 pub enum UseSpecItem {
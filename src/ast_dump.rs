@@ -0,0 +1,369 @@
+// src/ast_dump.rs
+//
+// YENİ: AST'nin okunabilir ağaç gösterimi (`--emit ast` ve `--emit typed-ast`).
+// Her düğüm kendi satırındadır, çocuklar iki boşluk içeri girintilenir. Çıktı Rust `Debug`
+// biçiminden bağımsızdır ve golden testlerde karşılaştırılabilecek kadar kararlıdır.
+// Tipli modda her ifade satırının sonuna tip kontrolcüsünün bulduğu tip eklenir: `Binary + : i32`.
+
+use crate::ast::{type_params_str, Decl, Expr, ExprKind, LiteralValue, Stmt, StmtKind, Type, UnOp, UseSpec, UseSpecItem};
+
+// Bir ifadenin (tip kontrolünde bulunmuş) tipini verir; bulunamadıysa None.
+pub type TypeLookup<'t> = &'t dyn Fn(&Expr) -> Option<Type>;
+
+pub fn dump_program(decls: &[Decl], types: Option<TypeLookup>) -> String {
+    let mut dumper = AstDumper { out: String::new(), types };
+    dumper.line(0, "Program".to_string());
+    for decl in decls {
+        dumper.decl(decl, 1);
+    }
+    dumper.out
+}
+
+struct AstDumper<'t> {
+    out: String,
+    types: Option<TypeLookup<'t>>,
+}
+
+impl AstDumper<'_> {
+    fn line(&mut self, depth: usize, text: String) {
+        for _ in 0..depth {
+            self.out.push_str("  ");
+        }
+        self.out.push_str(&text);
+        self.out.push('\n');
+    }
+
+    fn params(&mut self, params: &[(String, Type, Option<Expr>)], depth: usize) {
+        for (name, ty, default) in params {
+            self.line(depth, format!("Param {}: {}", name, ty));
+            if let Some(default) = default {
+                self.expr(default, depth + 1);
+            }
+        }
+    }
+
+    // Bildirimin doküman yorumu (varsa) tek satırda gösterilir.
+    fn doc(&mut self, doc: &Option<String>, depth: usize) {
+        if let Some(doc) = doc {
+            self.line(depth, format!("Doc {:?}", doc));
+        }
+    }
+
+    fn decl(&mut self, decl: &Decl, depth: usize) {
+        match decl {
+            Decl::Module(name) => self.line(depth, format!("Module {}", name)),
+            Decl::Function { name, type_params, params, return_type, body, is_inline, is_async, is_public, doc, .. } => {
+                self.line(depth, format!("Function {}{}: {}{}", name, type_params_str(type_params), return_type, flags(&[("pub", *is_public), ("inline", *is_inline), ("async", *is_async)])));
+                self.doc(doc, depth + 1);
+                self.params(params, depth + 1);
+                self.stmt(body, depth + 1);
+            }
+            Decl::ExternFn { name, params, return_type, is_public, doc, .. } => {
+                self.line(depth, format!("ExternFn {}: {}{}", name, return_type, flags(&[("pub", *is_public)])));
+                self.doc(doc, depth + 1);
+                self.params(params, depth + 1);
+            }
+            Decl::Group { name, type_params, is_export, params, return_type, body, doc, .. } => {
+                self.line(depth, format!("Group {}{}: {}{}", name, type_params_str(type_params), return_type, flags(&[("export", *is_export)])));
+                self.doc(doc, depth + 1);
+                self.params(params, depth + 1);
+                for decl in body {
+                    self.decl(decl, depth + 1);
+                }
+            }
+            Decl::Struct { name, type_params, fields, is_public, doc, .. } => {
+                self.line(depth, format!("Struct {}{}{}", name, type_params_str(type_params), flags(&[("pub", *is_public)])));
+                self.doc(doc, depth + 1);
+                for (field, ty) in fields {
+                    self.line(depth + 1, format!("Field {}: {}", field, ty));
+                }
+            }
+            Decl::Enum { name, variants, is_public, doc, .. } => {
+                self.line(depth, format!("Enum {}{}", name, flags(&[("pub", *is_public)])));
+                self.doc(doc, depth + 1);
+                for (variant, value) in variants {
+                    self.line(depth + 1, format!("Variant {}", variant));
+                    if let Some(value) = value {
+                        self.expr(value, depth + 2);
+                    }
+                }
+            }
+            Decl::Typedef { name, type_params, target, is_public, doc, .. } => {
+                self.line(depth, format!("Typedef {}{} = {}{}", name, type_params_str(type_params), target, flags(&[("pub", *is_public)])));
+                self.doc(doc, depth + 1);
+            }
+            Decl::Use { path, spec, is_export, .. } => {
+                let spec = match spec {
+                    UseSpec::All(None) => String::new(),
+                    UseSpec::All(Some(alias)) => format!(" as {}", alias),
+                    UseSpec::Wildcard => "::*".to_string(),
+                    UseSpec::Specific(items) => {
+                        let items: Vec<String> = items
+                            .iter()
+                            .map(|item| match item {
+                                UseSpecItem::Item(name) => name.clone(),
+                                UseSpecItem::RenamedItem(name, alias) => format!("{} as {}", name, alias),
+                            })
+                            .collect();
+                        format!("::{{{}}}", items.join(", "))
+                    }
+                };
+                self.line(depth, format!("Use {}{}{}", path.join("::"), spec, flags(&[("export", *is_export)])));
+            }
+            Decl::Style { name, code, .. } => self.line(depth, format!("Style {} {:?}", name, code)),
+            Decl::Program(decls) => {
+                self.line(depth, "Program".to_string());
+                for decl in decls {
+                    self.decl(decl, depth + 1);
+                }
+            }
+            Decl::StmtDecl(stmt) => self.stmt(stmt, depth),
+        }
+    }
+
+    fn stmt(&mut self, stmt: &Stmt, depth: usize) {
+        match &stmt.kind {
+            StmtKind::VarDecl { name, ty, init, is_const, is_let, is_mutable, is_public } => {
+                let flags = flags(&[("const", *is_const), ("let", *is_let), ("mut", *is_mutable), ("pub", *is_public)]);
+                self.line(depth, format!("VarDecl {}: {}{}", name, ty, flags));
+                if let Some(init) = init {
+                    self.expr(init, depth + 1);
+                }
+            }
+            StmtKind::Assign { left, value } => {
+                self.line(depth, "Assign".to_string());
+                self.expr(left, depth + 1);
+                self.expr(value, depth + 1);
+            }
+            StmtKind::Block(stmts) => {
+                self.line(depth, "Block".to_string());
+                for stmt in stmts {
+                    self.stmt(stmt, depth + 1);
+                }
+            }
+            StmtKind::If { cond, then_branch, else_branch } => {
+                self.line(depth, "If".to_string());
+                self.expr(cond, depth + 1);
+                self.line(depth + 1, "Then".to_string());
+                self.stmt(then_branch, depth + 2);
+                if let Some(else_branch) = else_branch {
+                    self.line(depth + 1, "Else".to_string());
+                    self.stmt(else_branch, depth + 2);
+                }
+            }
+            StmtKind::Return(value) => {
+                self.line(depth, "Return".to_string());
+                if let Some(value) = value {
+                    self.expr(value, depth + 1);
+                }
+            }
+            StmtKind::Break => self.line(depth, "Break".to_string()),
+            StmtKind::Continue => self.line(depth, "Continue".to_string()),
+            StmtKind::ExprStmt(expr) => {
+                self.line(depth, "ExprStmt".to_string());
+                self.expr(expr, depth + 1);
+            }
+            StmtKind::While { condition, body } => {
+                self.line(depth, "While".to_string());
+                self.expr(condition, depth + 1);
+                self.stmt(body, depth + 1);
+            }
+            StmtKind::Loop { body } => {
+                self.line(depth, "Loop".to_string());
+                self.stmt(body, depth + 1);
+            }
+            StmtKind::For { initializer, condition, increment, variable, iterable, body } => {
+                match variable {
+                    Some(variable) => self.line(depth, format!("ForIn {}", variable)),
+                    None => self.line(depth, "For".to_string()),
+                }
+                if let Some(initializer) = initializer {
+                    self.line(depth + 1, "Init".to_string());
+                    self.stmt(initializer, depth + 2);
+                }
+                if let Some(condition) = condition {
+                    self.line(depth + 1, "Cond".to_string());
+                    self.expr(condition, depth + 2);
+                }
+                if let Some(increment) = increment {
+                    self.line(depth + 1, "Step".to_string());
+                    self.expr(increment, depth + 2);
+                }
+                if let Some(iterable) = iterable {
+                    self.line(depth + 1, "Iter".to_string());
+                    self.expr(iterable, depth + 2);
+                }
+                self.stmt(body, depth + 1);
+            }
+            StmtKind::Echo(expr) => {
+                self.line(depth, "Echo".to_string());
+                self.expr(expr, depth + 1);
+            }
+            StmtKind::Empty => self.line(depth, "Empty".to_string()),
+            StmtKind::Tag { name, body } => {
+                self.line(depth, format!("Tag {}", name));
+                self.stmt(body, depth + 1);
+            }
+            StmtKind::Rolling(tag) => self.line(depth, format!("Rolling {}", tag)),
+            StmtKind::LabeledExpr { label, expr } => {
+                self.line(depth, format!("LabeledExpr {}", label));
+                self.expr(expr, depth + 1);
+            }
+            StmtKind::LabeledStmt { label, stmt, is_public, .. } => {
+                self.line(depth, format!("LabeledStmt {}{}", label, flags(&[("pub", *is_public)])));
+                self.stmt(stmt, depth + 1);
+            }
+            StmtKind::Routine(expr) => {
+                self.line(depth, "Routine".to_string());
+                self.expr(expr, depth + 1);
+            }
+            StmtKind::Unsafe(body) => {
+                self.line(depth, "Unsafe".to_string());
+                self.stmt(body, depth + 1);
+            }
+            StmtKind::FastExec(body) => {
+                self.line(depth, "FastExec".to_string());
+                self.stmt(body, depth + 1);
+            }
+            StmtKind::Defer(body) => {
+                self.line(depth, "Defer".to_string());
+                self.stmt(body, depth + 1);
+            }
+            StmtKind::Asm { tag, body } => self.line(depth, format!("Asm {} {:?}", tag, body)),
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr, depth: usize) {
+        let label = match &expr.kind {
+            ExprKind::Literal(value) => format!("Literal {}", literal(value)),
+            ExprKind::Variable(name) => format!("Variable {}", name),
+            ExprKind::Tuple(_) => "Tuple".to_string(),
+            ExprKind::ArrayLiteral(_) => "ArrayLiteral".to_string(),
+            ExprKind::Match { .. } => "Match".to_string(),
+            ExprKind::Input(_) => "Input".to_string(),
+            ExprKind::Block { .. } => "BlockExpr".to_string(),
+            ExprKind::DefaultCase => "DefaultCase".to_string(),
+            ExprKind::ArrayAccess { name, .. } => format!("ArrayAccess {}", name),
+            ExprKind::MemberAccess { member, .. } => format!("MemberAccess .{}", member),
+            ExprKind::Range { .. } => "Range".to_string(),
+            ExprKind::Binary { op, .. } => format!("Binary {}", op.symbol()),
+            ExprKind::Unary { op: op @ (UnOp::PostInc | UnOp::PostDec), .. } => format!("Postfix {}", op.symbol()),
+            ExprKind::Unary { op, .. } => format!("Unary {}", op.symbol()),
+            ExprKind::Conditional { .. } => "Conditional".to_string(),
+            ExprKind::Await(_) => "Await".to_string(),
+            ExprKind::Assign { .. } => "AssignExpr".to_string(),
+            ExprKind::Call { .. } => "Call".to_string(),
+            ExprKind::Lambda { return_type, .. } => format!("Lambda: {}", return_type),
+            ExprKind::InterpolatedString(_) => "InterpolatedString".to_string(),
+            ExprKind::Try(_) => "Try".to_string(),
+            ExprKind::EnumAccess { enum_name, variant_name } => format!("EnumAccess {}::{}", enum_name, variant_name),
+            ExprKind::StructLiteral { name, .. } => format!("StructLiteral {}", name),
+            ExprKind::SizeOf(ty) => format!("SizeOf {}", ty),
+            ExprKind::Send { .. } => "Send".to_string(),
+            ExprKind::Recv(_) => "Recv".to_string(),
+        };
+        let ty = self.types.and_then(|lookup| lookup(expr));
+        match ty {
+            Some(ty) => self.line(depth, format!("{} : {}", label, ty)),
+            None => self.line(depth, label),
+        }
+
+        let child = depth + 1;
+        match &expr.kind {
+            ExprKind::Literal(_) | ExprKind::Variable(_) | ExprKind::DefaultCase
+            | ExprKind::EnumAccess { .. } | ExprKind::SizeOf(_) => {}
+            ExprKind::Tuple(items) | ExprKind::ArrayLiteral(items) | ExprKind::InterpolatedString(items) => {
+                for item in items {
+                    self.expr(item, child);
+                }
+            }
+            ExprKind::Match { discriminant, cases } => {
+                self.expr(discriminant, child);
+                for (pattern, result) in cases {
+                    self.line(child, "Case".to_string());
+                    self.expr(pattern, child + 1);
+                    self.expr(result, child + 1);
+                }
+            }
+            ExprKind::Input(prompt) => {
+                if let Some(prompt) = prompt {
+                    self.expr(prompt, child);
+                }
+            }
+            ExprKind::Block { statements } => {
+                for stmt in statements {
+                    self.stmt(stmt, child);
+                }
+            }
+            ExprKind::ArrayAccess { index, .. } => self.expr(index, child),
+            ExprKind::MemberAccess { object, .. } => self.expr(object, child),
+            ExprKind::Range { start, end } => {
+                self.expr(start, child);
+                self.expr(end, child);
+            }
+            ExprKind::Binary { left, right, .. } => {
+                self.expr(left, child);
+                self.expr(right, child);
+            }
+            ExprKind::Unary { right, .. } => self.expr(right, child),
+            ExprKind::Conditional { cond, then_branch, else_branch } => {
+                self.expr(cond, child);
+                self.expr(then_branch, child);
+                self.expr(else_branch, child);
+            }
+            ExprKind::Await(inner) | ExprKind::Try(inner) | ExprKind::Recv(inner) => self.expr(inner, child),
+            ExprKind::Assign { left, value } => {
+                self.expr(left, child);
+                self.expr(value, child);
+            }
+            ExprKind::Call { callee, args } => {
+                self.expr(callee, child);
+                for (name, arg) in args {
+                    match name {
+                        Some(name) => self.line(child, format!("Arg {}", name)),
+                        None => self.line(child, "Arg".to_string()),
+                    }
+                    self.expr(arg, child + 1);
+                }
+            }
+            ExprKind::Lambda { params, body, .. } => {
+                self.params(params, child);
+                self.expr(body, child);
+            }
+            ExprKind::StructLiteral { fields, .. } => {
+                for (name, value) in fields {
+                    self.line(child, format!("Field {}", name));
+                    self.expr(value, child + 1);
+                }
+            }
+            ExprKind::Send { channel, value } => {
+                self.expr(channel, child);
+                self.expr(value, child);
+            }
+        }
+    }
+}
+
+// Doğru olan bayrakları " [pub, inline]" biçiminde yazar; hiçbiri yoksa boş döner.
+fn flags(flags: &[(&str, bool)]) -> String {
+    let set: Vec<&str> = flags.iter().filter(|(_, on)| *on).map(|(name, _)| *name).collect();
+    if set.is_empty() {
+        String::new()
+    } else {
+        format!(" [{}]", set.join(", "))
+    }
+}
+
+fn literal(value: &LiteralValue) -> String {
+    match value {
+        LiteralValue::Int(n) => n.to_string(),
+        LiteralValue::Float(f) => format!("{:?}", f),
+        LiteralValue::Hex(h) => format!("0x{:X}", h),
+        LiteralValue::TypedInt(n, ty) => format!("{}{}", n, ty),
+        LiteralValue::TypedFloat(f, ty) => format!("{:?}{}", f, ty),
+        LiteralValue::Char(c) => format!("{:?}", c),
+        LiteralValue::Str(s) => format!("{:?}", s),
+        LiteralValue::Bool(b) => b.to_string(),
+        LiteralValue::Null => "null".to_string(),
+    }
+}
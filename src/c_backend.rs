@@ -0,0 +1,2317 @@
+// src/c_backend.rs
+
+// `--backend c`: tip kontrolünden geçmiş AST'den tek başına derlenebilen bir C11 kaynak dosyası üretir.
+//
+// Anlam yorumlayıcıyla (interpreter.rs) ve native runtime ile aynıdır: tüm tamsayı tipleri 64 bit
+// (`nim_int`), float'lar `double`, metinler `const char *` (`nim_str`) olarak taşınır; yazdırma
+// biçimleri (bool 1/0, float "%f") printf ile aynı kurallara uyar. Struct, sabit boyutlu dizi, tuple,
+// Result ve Option değerleri C struct'larıyla kopyalanarak taşınır. `defer` blokları her çıkış
+// noktasında (blok sonu, return, break, continue ve `?`) ters sırayla satır içine açılır.
+//
+// Üretilen isimler çakışmasın diye öneklidir: fn_ (fonksiyon), st_ (struct), en_ (enum üyesi),
+// nim_ (runtime, birleşik tipler ve geçiciler). Dış (extern) fonksiyonlar kendi adlarıyla ve gerçek
+// C prototipleriyle (int32_t, const char * ...) bildirilir. Lambdalar, gruplar, modüller, kanallar ve
+// asm blokları henüz desteklenmez.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::ast::{BinOp, Decl, Expr, ExprKind, LiteralValue, Stmt, StmtKind, Type, UnOp};
+use crate::codegen::print_style;
+use crate::diagnostic::{codegen_error_code, Diagnostic, Span, Stage};
+use crate::type_checker::TypeChecker;
+
+type Param = (String, Type, Option<Expr>);
+type Gen<T> = Result<T, (String, Span)>;
+/// Bir dalın hoist edilmiş ön deyimleri ve sonuç ifadesi.
+type Branch = (Vec<String>, String);
+
+fn error<T>(message: impl Into<String>, span: Span) -> Gen<T> {
+    Err((message.into(), span))
+}
+
+// C anahtar kelimeleri ve üretilen kodun kullandığı libc isimleri; bu isimlerdeki değişkenler `v_` önekini alır.
+const RESERVED: &[&str] = &[
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else", "enum", "extern", "float",
+    "for", "goto", "if", "inline", "int", "long", "register", "restrict", "return", "short", "signed", "sizeof",
+    "static", "struct", "switch", "typedef", "union", "unsigned", "void", "volatile", "while", "bool", "true", "false",
+    "main", "argc", "argv", "stdin", "stdout", "stderr", "printf", "fprintf", "fputs", "fflush", "getchar", "strcmp",
+    "strlen", "strtoll", "strtod", "malloc", "realloc", "exit", "fmod", "vsnprintf", "va_list", "NULL", "EOF",
+    "size_t", "int8_t", "int16_t", "int32_t", "int64_t", "uint8_t", "uint16_t", "uint32_t", "uint64_t",
+];
+const RESERVED_PREFIXES: &[&str] = &["fn_", "st_", "en_", "nim_", "v_"];
+
+// <stdio.h>, <stdlib.h>, <string.h> ve <math.h> içinde zaten bildirilmiş fonksiyonlar. Bu isimlerdeki
+// `extern fn` bildirimleri için prototip yazılmaz; başlıktaki bildirimle çakışmasın diye o kullanılır.
+const LIBC_FUNCTIONS: &[&str] = &[
+    "printf", "fprintf", "sprintf", "snprintf", "vsnprintf", "puts", "putchar", "getchar", "fputs", "fputc", "fgets",
+    "fgetc", "fopen", "fclose", "fread", "fwrite", "fflush", "fseek", "ftell", "remove", "rename", "perror", "scanf",
+    "malloc", "calloc", "realloc", "free", "exit", "abort", "atoi", "atol", "atof", "strtol", "strtoll", "strtod",
+    "abs", "labs", "rand", "srand", "system", "getenv", "qsort", "strlen", "strcmp", "strncmp", "strcpy", "strncpy",
+    "strcat", "strncat", "strchr", "strrchr", "strstr", "memcpy", "memmove", "memset", "memcmp", "sqrt", "pow", "sin",
+    "cos", "tan", "atan", "atan2", "exp", "log", "log10", "floor", "ceil", "round", "fabs", "fmod",
+];
+
+// printf biçim dizgisinin parçaları: düz metin veya bir yer tutucu ve onun argümanı.
+enum Piece {
+    Text(String),
+    Arg(&'static str, String),
+    // `{x.2}`: `%.<basamak>f` ile yazılan float değer.
+    Precision(usize, String),
+}
+
+#[derive(Default)]
+struct Scope<'a> {
+    // Kaynak adı -> (C adı, tip)
+    vars: HashMap<String, (String, Type)>,
+    defers: Vec<&'a Stmt>,
+    // Döngü gövdesinin sınırı: break/continue bu kapsama kadarki defer bloklarını çalıştırır.
+    loop_body: bool,
+}
+
+#[derive(Clone)]
+struct FnInfo<'a> {
+    c_name: String,
+    params: &'a [Param],
+    return_type: &'a Type,
+}
+
+struct CGen<'a, 'c, 't> {
+    checker: &'c TypeChecker<'t>,
+    structs: HashMap<&'a str, &'a [(String, Type)]>,
+    enums: HashMap<&'a str, Vec<(&'a str, i64)>>,
+    functions: HashMap<&'a str, FnInfo<'a>>,
+    externs: HashMap<&'a str, FnInfo<'a>>,
+    // Çıktı bölümleri; tipler ve yardımcı fonksiyonlar ilk kullanıldıkları anda bağımlılık sırasıyla eklenir.
+    types: Vec<String>,
+    helpers: Vec<String>,
+    defined: HashSet<String>,
+    globals: Vec<String>,
+    global_vars: HashMap<String, (String, Type)>,
+    uses_args: bool,
+    uses_format: bool,
+    uses_input: bool,
+    uses_panic: bool,
+    // Üretilmekte olan fonksiyon gövdesi ve mevcut deyimden önce yazılacak geçici değişkenler.
+    out: Vec<String>,
+    pre: Vec<String>,
+    indent: usize,
+    scopes: Vec<Scope<'a>>,
+    in_globals: bool,
+    return_type: Type,
+    temps: usize,
+    decl_span: Span,
+}
+
+// Programın C11 karşılığını üretir. Hatalar kod üretimi aşamasının (E0200) tanılarıdır.
+pub fn generate(program: &[Decl], checker: &TypeChecker<'_>) -> Result<String, Diagnostic> {
+    let mut gen = CGen {
+        checker,
+        structs: HashMap::new(),
+        enums: HashMap::new(),
+        functions: HashMap::new(),
+        externs: HashMap::new(),
+        types: Vec::new(),
+        helpers: Vec::new(),
+        defined: HashSet::new(),
+        globals: Vec::new(),
+        global_vars: HashMap::new(),
+        uses_args: false,
+        uses_format: false,
+        uses_input: false,
+        uses_panic: false,
+        out: Vec::new(),
+        pre: Vec::new(),
+        indent: 1,
+        scopes: Vec::new(),
+        in_globals: false,
+        return_type: Type::Void,
+        temps: 0,
+        decl_span: Span::dummy(),
+    };
+    gen.program(program).map_err(|(message, span)| {
+        let span = if span.is_dummy() { gen.decl_span } else { span };
+        let code = codegen_error_code(&message);
+        Diagnostic::error(message).with_stage(Stage::Codegen).with_code(code).with_fallback_span(span)
+    })
+}
+
+fn flatten<'a>(program: &'a [Decl], out: &mut Vec<&'a Decl>) {
+    for decl in program {
+        match decl {
+            Decl::Program(decls) => flatten(decls, out),
+            _ => out.push(decl),
+        }
+    }
+}
+
+impl<'a, 'c, 't> CGen<'a, 'c, 't> {
+    fn program(&mut self, program: &'a [Decl]) -> Gen<String> {
+        let mut decls = Vec::new();
+        flatten(program, &mut decls);
+
+        // 1. Adım: Tanımları kaydet
+        let mut struct_order = Vec::new();
+        let mut enum_decls = Vec::new();
+        for decl in &decls {
+            match decl {
+                Decl::Struct { name, fields, .. } => {
+                    self.structs.insert(name, fields);
+                    struct_order.push(name.as_str());
+                }
+                Decl::Enum { name, variants, span, .. } => {
+                    // Değeri verilmeyen enum üyesi bir öncekinin değerinin bir fazlasını alır (yorumlayıcıyla aynı).
+                    let mut values = Vec::new();
+                    let mut next = 0;
+                    for (variant, value) in variants {
+                        if let Some(value) = value {
+                            next = match const_int(value) {
+                                Some(v) => v,
+                                None => return error(format!("C arka ucu '{}::{}' enum değerini sabit olarak hesaplayamadı.", name, variant), value.span),
+                            };
+                        }
+                        if i32::try_from(next).is_err() {
+                            return error(format!("C arka ucu int aralığı dışındaki enum değerlerini ('{}::{}' = {}) desteklemiyor.", name, variant, next), *span);
+                        }
+                        values.push((variant.as_str(), next));
+                        next += 1;
+                    }
+                    self.enums.insert(name, values);
+                    enum_decls.push(name.as_str());
+                }
+                Decl::Function { name, params, return_type, .. } => {
+                    self.functions.insert(name, FnInfo { c_name: format!("fn_{}", name), params, return_type });
+                }
+                Decl::ExternFn { name, params, return_type, .. } => {
+                    self.externs.insert(name, FnInfo { c_name: name.clone(), params, return_type });
+                }
+                Decl::Group { span, .. } => return error("C arka ucu 'group' bildirimlerini henüz desteklemiyor.", *span),
+                Decl::Use { span, .. } => return error("C arka ucu 'use' ile yüklenen modülleri henüz desteklemiyor.", *span),
+                _ => {}
+            }
+        }
+        let Some(main) = self.functions.get("main").cloned() else {
+            return error("Hata: Programda 'main' fonksiyonu bulunamadı.", Span::dummy());
+        };
+
+        // 2. Adım: Struct'lar (kullanılmasalar da) tanım sırasıyla yazılır.
+        for name in &struct_order {
+            self.c_type(&Type::Custom(name.to_string()), Span::dummy())?;
+        }
+
+        // 3. Adım: Dış fonksiyon prototipleri
+        let mut externs = Vec::new();
+        for decl in &decls {
+            if let Decl::ExternFn { name, params, return_type, span, .. } = decl {
+                self.decl_span = *span;
+                if LIBC_FUNCTIONS.contains(&name.as_str()) {
+                    externs.push(format!("// {}: standart C başlığındaki bildirim kullanılır.", name));
+                    continue;
+                }
+                let mut c_params = Vec::new();
+                for (param_name, param_type, _) in params {
+                    let ty = self.extern_c_type(param_type, *span)?;
+                    c_params.push(if param_name.is_empty() { ty } else { format!("{} {}", ty, self.c_ident(param_name)) });
+                }
+                let params = if c_params.is_empty() { "void".to_string() } else { c_params.join(", ") };
+                externs.push(format!("{} {}({});", self.extern_c_type(return_type, *span)?, name, params));
+            }
+        }
+
+        // 4. Adım: Global deyimler (değişkenler ve üst düzey kod) `nim_init_globals` içinde sırayla çalışır.
+        let mut init_globals = None;
+        if decls.iter().any(|d| matches!(d, Decl::StmtDecl(_))) {
+            self.in_globals = true;
+            self.scopes = vec![Scope::default()];
+            self.return_type = Type::Void;
+            self.out.clear();
+            self.indent = 1;
+            for decl in &decls {
+                if let Decl::StmtDecl(stmt) = decl {
+                    self.decl_span = stmt.span;
+                    self.stmt(stmt)?;
+                }
+            }
+            self.in_globals = false;
+            let mut lines = vec!["static void nim_init_globals(void) {".to_string()];
+            lines.append(&mut self.out);
+            lines.push("}".to_string());
+            init_globals = Some(lines.join("\n"));
+        }
+
+        // 5. Adım: Fonksiyonlar
+        let mut prototypes = Vec::new();
+        let mut definitions = Vec::new();
+        for decl in &decls {
+            if let Decl::Function { name, params, return_type, body, span, .. } = decl {
+                self.decl_span = *span;
+                let (prototype, definition) = self.function(name, params, return_type, body)?;
+                prototypes.push(prototype);
+                definitions.push(definition);
+            }
+        }
+
+        // 6. Adım: C giriş noktası
+        let main_returns_int = is_int_like(&self.resolve(main.return_type));
+        let mut entry = vec![if self.uses_args {
+            "int main(int argc, char **argv) {".to_string()
+        } else {
+            "int main(void) {".to_string()
+        }];
+        if self.uses_args {
+            entry.push("    nim_argc = argc;".to_string());
+            entry.push("    nim_argv = argv;".to_string());
+        }
+        if init_globals.is_some() {
+            entry.push("    nim_init_globals();".to_string());
+        }
+        if main_returns_int {
+            entry.push(format!("    return (int){}();", main.c_name));
+        } else {
+            entry.push(format!("    {}();", main.c_name));
+            entry.push("    return 0;".to_string());
+        }
+        entry.push("}".to_string());
+
+        // Bölümleri birleştir
+        let mut sections: Vec<String> = Vec::new();
+        let mut header = vec![
+            "// NIMBLE (nim) C arka ucu tarafından üretilmiştir: cc -std=c11 <dosya>.c -lm".to_string(),
+            "#include <stdbool.h>".to_string(),
+            "#include <stdint.h>".to_string(),
+            "#include <stdio.h>".to_string(),
+            "#include <stdlib.h>".to_string(),
+            "#include <string.h>".to_string(),
+            "#include <math.h>".to_string(),
+        ];
+        if self.uses_format {
+            header.push("#include <stdarg.h>".to_string());
+        }
+        header.push(String::new());
+        header.push("typedef int64_t nim_int;".to_string());
+        header.push("typedef const char *nim_str;".to_string());
+        if self.uses_args {
+            header.push("static int nim_argc;".to_string());
+            header.push("static char **nim_argv;".to_string());
+        }
+        sections.push(header.join("\n"));
+        if !struct_order.is_empty() {
+            sections.push(struct_order.iter().map(|n| format!("typedef struct st_{0} st_{0};", n)).collect::<Vec<_>>().join("\n"));
+        }
+        for name in enum_decls {
+            let variants = self.enums[name].iter().map(|(v, value)| format!("en_{}_{} = {}", name, v, value)).collect::<Vec<_>>();
+            sections.push(format!("// enum {}\nenum {{ {} }};", name, variants.join(", ")));
+        }
+        sections.append(&mut self.types);
+        if !externs.is_empty() {
+            sections.push(externs.join("\n"));
+        }
+        if !self.globals.is_empty() {
+            sections.push(self.globals.join("\n"));
+        }
+        sections.extend(self.runtime());
+        sections.append(&mut self.helpers);
+        if !prototypes.is_empty() {
+            sections.push(prototypes.join("\n"));
+        }
+        sections.extend(init_globals);
+        sections.extend(definitions);
+        sections.push(entry.join("\n"));
+        Ok(sections.join("\n\n") + "\n")
+    }
+
+    // Kullanılan runtime yardımcıları (printf dışındaki her şey).
+    fn runtime(&self) -> Vec<String> {
+        let mut parts = Vec::new();
+        if self.uses_format {
+            parts.push(
+                "// printf biçiminde yeni bir metin üretir (interpolasyonlu metinler ve _str için).
+static nim_str nim_format(const char *format, ...) {
+    va_list args;
+    va_start(args, format);
+    int length = vsnprintf(NULL, 0, format, args);
+    va_end(args);
+    char *text = malloc((size_t)length + 1);
+    va_start(args, format);
+    vsnprintf(text, (size_t)length + 1, format, args);
+    va_end(args);
+    return text;
+}"
+                .to_string(),
+            );
+        }
+        if self.uses_panic {
+            parts.push(
+                "// panic: mesaj stdout'a yazılır ve süreç 1 koduyla biter (native runtime ile aynı).
+static void nim_panic(nim_str message) {
+    printf(\"%s\", message);
+    exit(1);
+}"
+                .to_string(),
+            );
+        }
+        if self.uses_input {
+            parts.push(
+                "// input: istemi yazar ve stdin'den satır sonu olmadan bir satır okur.
+static nim_str nim_input(nim_str prompt) {
+    if (prompt) {
+        fputs(prompt, stdout);
+    }
+    fflush(stdout);
+    size_t capacity = 64, length = 0;
+    char *line = malloc(capacity);
+    int c;
+    while ((c = getchar()) != EOF && c != '\\n') {
+        if (length + 1 >= capacity) {
+            capacity *= 2;
+            line = realloc(line, capacity);
+        }
+        line[length++] = (char)c;
+    }
+    if (length > 0 && line[length - 1] == '\\r') {
+        length--;
+    }
+    line[length] = '\\0';
+    return line;
+}"
+                .to_string(),
+            );
+        }
+        parts
+    }
+
+    fn function(&mut self, name: &str, params: &'a [Param], return_type: &'a Type, body: &'a Stmt) -> Gen<(String, String)> {
+        let span = self.decl_span;
+        self.return_type = self.resolve(return_type);
+        let ret = self.c_type(&self.return_type.clone(), span)?;
+        self.scopes = vec![Scope::default()];
+        let mut c_params = Vec::new();
+        for (param_name, param_type, _) in params {
+            let ty = self.resolve(param_type);
+            let c_type = self.c_type(&ty, span)?;
+            let c_name = self.declare(param_name, ty);
+            c_params.push(format!("{} {}", c_type, c_name));
+        }
+        let params = if c_params.is_empty() { "void".to_string() } else { c_params.join(", ") };
+        let signature = format!("{} fn_{}({})", ret, name, params);
+
+        self.out.clear();
+        self.indent = 1;
+        match &body.kind {
+            StmtKind::Block(stmts) => self.block(stmts)?,
+            _ => self.block(std::slice::from_ref(body))?,
+        }
+        let mut lines = vec![format!("{} {{", signature)];
+        lines.append(&mut self.out);
+        lines.push("}".to_string());
+        Ok((format!("{};", signature), lines.join("\n")))
+    }
+
+    // --- Tipler ---
+
+    // Takma adları çözer; enum isimleri Enum tipine, iç tipler de özyinelemeli olarak çözülür.
+    fn resolve(&self, ty: &Type) -> Type {
+        match ty {
+            Type::Custom(name) => {
+                if let Some(target) = self.checker.type_aliases.get(name) {
+                    self.resolve(target)
+                } else if self.enums.contains_key(name.as_str()) {
+                    Type::Enum(name.clone(), Box::new(Type::I32))
+                } else {
+                    ty.clone()
+                }
+            }
+            Type::Array(inner, size) => Type::Array(Box::new(self.resolve(inner)), *size),
+            Type::Tuple(types) => Type::Tuple(types.iter().map(|t| self.resolve(t)).collect()),
+            Type::Result(ok, err) => Type::Result(Box::new(self.resolve(ok)), Box::new(self.resolve(err))),
+            Type::Option(inner) => Type::Option(Box::new(self.resolve(inner))),
+            Type::Ptr(inner) => Type::Ptr(Box::new(self.resolve(inner))),
+            Type::Future(inner) => self.resolve(inner),
+            Type::ArrayLiteral(types) if !types.is_empty() => Type::Array(Box::new(self.resolve(&types[0])), Some(types.len())),
+            _ => ty.clone(),
+        }
+    }
+
+    fn c_type(&mut self, ty: &Type, span: Span) -> Gen<String> {
+        let ty = self.resolve(ty);
+        match &ty {
+            Type::I128 => Ok("__int128".to_string()),
+            Type::U128 => Ok("unsigned __int128".to_string()),
+            t if is_int_like(t) => Ok("nim_int".to_string()),
+            t if is_float_like(t) => Ok("double".to_string()),
+            Type::Bool => Ok("bool".to_string()),
+            Type::Char => Ok("char".to_string()),
+            Type::Str(_) => Ok("nim_str".to_string()),
+            Type::Void | Type::Never => Ok("void".to_string()),
+            Type::Custom(name) if self.structs.contains_key(name.as_str()) => {
+                self.require_struct(name, span)?;
+                Ok(format!("st_{}", name))
+            }
+            // Struct'lar baştan ileri bildirildiği için işaretçi tam tanım gerektirmez.
+            Type::Ptr(inner) => match inner.as_ref() {
+                Type::Custom(name) if self.structs.contains_key(name.as_str()) => Ok(format!("st_{} *", name)),
+                Type::Void | Type::Any => Ok("void *".to_string()),
+                inner => Ok(format!("{} *", self.c_type(inner, span)?)),
+            },
+            Type::Array(..) | Type::Tuple(_) | Type::Result(..) | Type::Option(_) => self.require_compound(&ty, span),
+            Type::Any | Type::Unknown | Type::Null | Type::ArrayLiteral(_) => {
+                error("C arka ucu tipi belirlenemeyen bir değeri ('any') çeviremez; değişkene veya dönüş tipine açık bir tip verin.", span)
+            }
+            other => error(format!("C arka ucu '{}' tipini henüz desteklemiyor.", other), span),
+        }
+    }
+
+    // Dış fonksiyon prototiplerindeki tipler gerçek C genişlikleriyle yazılır.
+    fn extern_c_type(&mut self, ty: &Type, span: Span) -> Gen<String> {
+        let ty = self.resolve(ty);
+        Ok(match &ty {
+            Type::I8 => "int8_t".to_string(),
+            Type::I16 => "int16_t".to_string(),
+            Type::I32 => "int32_t".to_string(),
+            Type::I64 => "int64_t".to_string(),
+            Type::U8 | Type::Byte => "uint8_t".to_string(),
+            Type::U16 => "uint16_t".to_string(),
+            Type::U32 => "uint32_t".to_string(),
+            Type::U64 => "uint64_t".to_string(),
+            Type::F32 => "float".to_string(),
+            Type::F64 => "double".to_string(),
+            Type::Str(_) => "const char *".to_string(),
+            Type::Ptr(inner) if matches!(inner.as_ref(), Type::Void | Type::Any) => "void *".to_string(),
+            Type::Ptr(inner) => format!("{} *", self.extern_c_type(inner, span)?),
+            Type::I128 | Type::U128 => return error(format!("C arka ucu dış fonksiyonlarda '{}' tipini desteklemiyor.", ty), span),
+            _ => self.c_type(&ty, span)?,
+        })
+    }
+
+    fn require_struct(&mut self, name: &str, span: Span) -> Gen<()> {
+        let key = format!("st_{}", name);
+        if !self.defined.insert(key.clone()) {
+            return Ok(());
+        }
+        let fields = self.structs[name];
+        let mut lines = vec![format!("struct {} {{", key)];
+        for (field, field_type) in fields {
+            let c_type = self.c_type(field_type, span)?;
+            lines.push(format!("    {} {};", c_type, self.c_ident(field)));
+        }
+        if fields.is_empty() {
+            lines.push("    char nim_unused;".to_string());
+        }
+        lines.push("};".to_string());
+        self.types.push(lines.join("\n"));
+        Ok(())
+    }
+
+    // Dizi, tuple, Result ve Option tipleri için (ilk kullanımda) bir C struct'ı tanımlar.
+    fn require_compound(&mut self, ty: &Type, span: Span) -> Gen<String> {
+        let name = format!("nim_{}", self.mangle(ty, span)?);
+        if !self.defined.insert(name.clone()) {
+            return Ok(name);
+        }
+        let mut fields = Vec::new();
+        match ty {
+            Type::Array(inner, Some(size)) => fields.push(format!("{} items[{}];", self.c_type(inner, span)?, (*size).max(1))),
+            Type::Array(inner, None) => {
+                fields.push(format!("{} *items;", self.c_type(inner, span)?));
+                fields.push("nim_int len;".to_string());
+            }
+            Type::Tuple(types) => {
+                for (i, t) in types.iter().enumerate() {
+                    fields.push(format!("{} f{};", self.c_type(t, span)?, i));
+                }
+                if types.is_empty() {
+                    fields.push("char nim_unused;".to_string());
+                }
+            }
+            Type::Result(ok, err) => {
+                fields.push("bool ok;".to_string());
+                for (t, field) in [(ok, "value"), (err, "error")] {
+                    let c_type = self.c_type(t, span)?;
+                    if c_type != "void" {
+                        fields.push(format!("{} {};", c_type, field));
+                    }
+                }
+            }
+            Type::Option(inner) => {
+                fields.push("bool some;".to_string());
+                let c_type = self.c_type(inner, span)?;
+                if c_type != "void" {
+                    fields.push(format!("{} value;", c_type));
+                }
+            }
+            _ => unreachable!("require_compound yalnızca birleşik tiplerle çağrılır"),
+        }
+        let fields: Vec<String> = fields.into_iter().map(|f| format!("    {}", f)).collect();
+        self.types.push(format!("// {}\ntypedef struct {{\n{}\n}} {};", ty, fields.join("\n"), name));
+        Ok(name)
+    }
+
+    // Birleşik tiplerin C isimlerinde kullanılan kararlı kısaltma (örn: result<i32, str> -> res_int_str).
+    fn mangle(&self, ty: &Type, span: Span) -> Gen<String> {
+        let ty = self.resolve(ty);
+        Ok(match &ty {
+            Type::I128 => "i128".to_string(),
+            Type::U128 => "u128".to_string(),
+            t if is_int_like(t) => "int".to_string(),
+            t if is_float_like(t) => "float".to_string(),
+            Type::Bool => "bool".to_string(),
+            Type::Char => "char".to_string(),
+            Type::Str(_) => "str".to_string(),
+            Type::Void | Type::Never => "void".to_string(),
+            Type::Custom(name) if self.structs.contains_key(name.as_str()) => format!("st_{}", name),
+            Type::Ptr(inner) => format!("ptr_{}", self.mangle(inner, span)?),
+            Type::Array(inner, Some(size)) => format!("arr{}_{}", size, self.mangle(inner, span)?),
+            Type::Array(inner, None) => format!("vec_{}", self.mangle(inner, span)?),
+            Type::Tuple(types) => {
+                let parts = types.iter().map(|t| self.mangle(t, span)).collect::<Gen<Vec<_>>>()?;
+                format!("tup{}_{}", types.len(), parts.join("_"))
+            }
+            Type::Result(ok, err) => format!("res_{}_{}", self.mangle(ok, span)?, self.mangle(err, span)?),
+            Type::Option(inner) => format!("opt_{}", self.mangle(inner, span)?),
+            Type::Any | Type::Unknown | Type::Null => {
+                return error("C arka ucu tipi belirlenemeyen bir değeri ('any') çeviremez; değişkene veya dönüş tipine açık bir tip verin.", span)
+            }
+            other => return error(format!("C arka ucu '{}' tipini henüz desteklemiyor.", other), span),
+        })
+    }
+
+    // Başlangıç değeri verilmeyen değişkenlerin sıfır değeri (metinler boş metin olur, NULL değil).
+    fn default_value(&mut self, ty: &Type, span: Span) -> Gen<String> {
+        let ty = self.resolve(ty);
+        Ok(match &ty {
+            t if is_int_like(t) => "0".to_string(),
+            t if is_float_like(t) => "0.0".to_string(),
+            Type::Bool => "false".to_string(),
+            Type::Char => "'\\0'".to_string(),
+            Type::Str(_) => "\"\"".to_string(),
+            Type::Ptr(_) => "NULL".to_string(),
+            t if !self.contains_str(t) => format!("({}){{0}}", self.c_type(t, span)?),
+            Type::Custom(name) => {
+                let fields = self.structs[name.as_str()];
+                let mut values = Vec::new();
+                for (field, field_type) in fields {
+                    values.push(format!(".{} = {}", self.c_ident(field), self.default_value(field_type, span)?));
+                }
+                format!("({}){{ {} }}", self.c_type(&ty, span)?, values.join(", "))
+            }
+            Type::Tuple(types) => {
+                let values = types.iter().map(|t| self.default_value(t, span)).collect::<Gen<Vec<_>>>()?;
+                format!("({}){{ {} }}", self.c_type(&ty, span)?, values.join(", "))
+            }
+            Type::Array(inner, Some(size)) => {
+                let value = self.default_value(inner, span)?;
+                format!("({}){{ {{ {} }} }}", self.c_type(&ty, span)?, vec![value; *size].join(", "))
+            }
+            other => format!("({}){{0}}", self.c_type(other, span)?),
+        })
+    }
+
+    fn contains_str(&self, ty: &Type) -> bool {
+        match self.resolve(ty) {
+            Type::Str(_) => true,
+            Type::Custom(name) => self.structs.get(name.as_str()).is_some_and(|fields| fields.iter().any(|(_, t)| self.contains_str(t))),
+            Type::Tuple(types) => types.iter().any(|t| self.contains_str(t)),
+            Type::Array(inner, Some(_)) => self.contains_str(&inner),
+            _ => false,
+        }
+    }
+
+    // `sizeof(T)`: yorumlayıcıdaki bildirilen tip boyutlarıyla aynı.
+    fn size_of(&self, ty: &Type) -> usize {
+        match self.resolve(ty) {
+            Type::I8 | Type::U8 | Type::Bool | Type::Char | Type::Bit | Type::Byte | Type::Hex => 1,
+            Type::I16 | Type::U16 => 2,
+            Type::I32 | Type::U32 | Type::F32 | Type::D32 => 4,
+            Type::I128 | Type::U128 | Type::F128 | Type::D128 | Type::F80 => 16,
+            Type::Array(inner, Some(size)) => self.size_of(&inner) * size,
+            Type::Tuple(types) => types.iter().map(|t| self.size_of(t)).sum(),
+            Type::Custom(name) => match self.structs.get(name.as_str()) {
+                Some(fields) => fields.iter().map(|(_, t)| self.size_of(t)).sum(),
+                None => 8,
+            },
+            Type::Void | Type::Never => 0,
+            _ => 8,
+        }
+    }
+
+    fn type_of(&self, expr: &Expr) -> Gen<Type> {
+        match self.checker.checked_type(expr) {
+            Some(ty) => Ok(self.resolve(&ty)),
+            None => error("C arka ucu bu ifadenin tipini bulamadı (tip kontrolünden geçmemiş kod).", expr.span),
+        }
+    }
+
+    // İfadenin değer tipi. Ok(x), None, dizi literalleri gibi tipi tam belli olmayan ifadelerde
+    // bağlamdan gelen beklenen tip kullanılır.
+    fn value_type(&self, expr: &Expr, expected: Option<&Type>) -> Gen<Type> {
+        let checked = self.type_of(expr)?;
+        let expected = expected.map(|t| self.resolve(t)).filter(|t| !matches!(t, Type::Any | Type::Unknown));
+        match expected {
+            Some(expected) if contains_any(&checked) || matches!(expr.kind, ExprKind::ArrayLiteral(_)) => Ok(expected),
+            _ => Ok(checked),
+        }
+    }
+
+    // --- İsimler ---
+
+    fn c_ident(&self, name: &str) -> String {
+        let reserved = RESERVED.contains(&name)
+            || RESERVED_PREFIXES.iter().any(|p| name.starts_with(p))
+            || self.externs.contains_key(name);
+        if reserved { format!("v_{}", name) } else { name.to_string() }
+    }
+
+    // Yeni bir değişken tanımlar. Görünür bir değişkenle aynı C adını almaması için gerekirse numaralandırılır.
+    fn declare(&mut self, name: &str, ty: Type) -> String {
+        let base = self.c_ident(name);
+        let mut c_name = base.clone();
+        let mut n = 1;
+        while self.scopes.iter().flat_map(|s| s.vars.values()).chain(self.global_vars.values()).any(|(c, _)| *c == c_name) {
+            c_name = format!("{}_{}", base, n);
+            n += 1;
+        }
+        self.scopes.last_mut().expect("Kapsam yığını boş olamaz.").vars.insert(name.to_string(), (c_name.clone(), ty));
+        c_name
+    }
+
+    fn lookup(&self, name: &str) -> Option<(String, Type)> {
+        self.scopes.iter().rev().find_map(|s| s.vars.get(name)).or_else(|| self.global_vars.get(name)).cloned()
+    }
+
+    fn temp(&mut self) -> String {
+        self.temps += 1;
+        format!("nim_t{}", self.temps)
+    }
+
+    // Değeri mevcut deyimden önce bir geçici değişkene yazar (bir kez değerlendirilsin diye).
+    fn hoist(&mut self, ty: &Type, value: String, span: Span) -> Gen<String> {
+        let name = self.temp();
+        let c_type = self.c_type(ty, span)?;
+        self.pre.push(format!("{} {} = {};", c_type, name, value));
+        Ok(name)
+    }
+
+    // İfadeyi birden çok kez okunabilecek bir C ifadesine çevirir: değişken yolları olduğu gibi kalır,
+    // diğerleri geçici değişkene alınır.
+    fn stable(&mut self, expr: &'a Expr, ty: &Type) -> Gen<String> {
+        let value = self.expr(expr, Some(ty))?;
+        if self.is_path(expr) { Ok(value) } else { self.hoist(ty, value, expr.span) }
+    }
+
+    fn is_path(&self, expr: &Expr) -> bool {
+        match &expr.kind {
+            ExprKind::Variable(name) => self.lookup(name).is_some(),
+            ExprKind::MemberAccess { object, .. } => self.is_path(object),
+            ExprKind::ArrayAccess { index, .. } => matches!(index.kind, ExprKind::Literal(_) | ExprKind::Variable(_)),
+            _ => false,
+        }
+    }
+
+    // --- Deyimler ---
+
+    fn line(&mut self, text: impl AsRef<str>) {
+        self.out.push(format!("{}{}", "    ".repeat(self.indent), text.as_ref()));
+    }
+
+    fn flush_pre(&mut self) {
+        for line in std::mem::take(&mut self.pre) {
+            self.line(line);
+        }
+    }
+
+    // Kod üretimini geçici bir tampona yönlendirir (girinti bir seviye içeriden başlar).
+    fn capture(&mut self, f: impl FnOnce(&mut Self) -> Gen<()>) -> Gen<Vec<String>> {
+        let saved_out = std::mem::take(&mut self.out);
+        let saved_indent = std::mem::replace(&mut self.indent, 1);
+        let result = f(self);
+        self.indent = saved_indent;
+        let lines = std::mem::replace(&mut self.out, saved_out);
+        result.map(|_| lines)
+    }
+
+    fn block(&mut self, stmts: &'a [Stmt]) -> Gen<()> {
+        self.scopes.push(Scope::default());
+        let result = self.block_body(stmts);
+        self.scopes.pop();
+        result
+    }
+
+    fn block_body(&mut self, stmts: &'a [Stmt]) -> Gen<()> {
+        for stmt in stmts {
+            self.stmt(stmt)?;
+        }
+        // Blok sonunda (return/break/continue ile bitmiyorsa) bu kapsamın defer blokları ters sırayla çalışır.
+        let terminated = stmts.last().is_some_and(|s| matches!(s.kind, StmtKind::Return(_) | StmtKind::Break | StmtKind::Continue));
+        if !terminated {
+            let defers: Vec<&'a Stmt> = self.scopes.last().map(|s| s.defers.iter().rev().copied().collect()).unwrap_or_default();
+            self.emit_defers(defers)?;
+        }
+        Ok(())
+    }
+
+    // if/döngü gövdesi: süslü parantezleri çağıran yazar.
+    fn body(&mut self, stmt: &'a Stmt) -> Gen<()> {
+        self.indent += 1;
+        let result = match &stmt.kind {
+            StmtKind::Block(stmts) => self.block(stmts),
+            _ => self.block(std::slice::from_ref(stmt)),
+        };
+        self.indent -= 1;
+        result
+    }
+
+    fn loop_body(&mut self, stmt: &'a Stmt, variable: Option<(&str, Type, String)>) -> Gen<()> {
+        self.scopes.push(Scope { loop_body: true, ..Scope::default() });
+        let mut result = Ok(());
+        if let Some((name, ty, value)) = variable {
+            result = self.c_type(&ty, stmt.span).map(|c_type| {
+                let c_name = self.declare(name, ty);
+                self.indent += 1;
+                self.line(format!("{} {} = {};", c_type, c_name, value));
+                self.indent -= 1;
+            });
+        }
+        let result = result.and_then(|_| self.body(stmt));
+        self.scopes.pop();
+        result?;
+        self.line("}");
+        Ok(())
+    }
+
+    // Çıkış noktasında çalışacak defer blokları (içten dışa, her kapsamda ters sırayla).
+    fn exit_defers(&self, to_loop: bool) -> Vec<&'a Stmt> {
+        let mut defers = Vec::new();
+        for scope in self.scopes.iter().rev() {
+            defers.extend(scope.defers.iter().rev().copied());
+            if to_loop && scope.loop_body {
+                break;
+            }
+        }
+        defers
+    }
+
+    fn emit_defers(&mut self, defers: Vec<&'a Stmt>) -> Gen<()> {
+        if defers.is_empty() {
+            return Ok(());
+        }
+        // Defer gövdesi üretilirken kayıtlı defer'lar geçici olarak kaldırılır (gövdedeki return onları tekrar açmasın).
+        let saved: Vec<Vec<&'a Stmt>> = self.scopes.iter_mut().map(|s| std::mem::take(&mut s.defers)).collect();
+        let mut result = Ok(());
+        for stmt in defers {
+            result = self.stmt(stmt);
+            if result.is_err() {
+                break;
+            }
+        }
+        for (scope, defers) in self.scopes.iter_mut().zip(saved) {
+            scope.defers = defers;
+        }
+        result
+    }
+
+    fn stmt(&mut self, stmt: &'a Stmt) -> Gen<()> {
+        let outer = std::mem::take(&mut self.pre);
+        let result = self.stmt_inner(stmt);
+        self.pre = outer;
+        result
+    }
+
+
+    fn stmt_inner(&mut self, stmt: &'a Stmt) -> Gen<()> {
+        match &stmt.kind {
+            StmtKind::VarDecl { name, ty, init, is_const, .. } => self.var_decl(name, ty, init.as_ref(), *is_const, stmt.span),
+            StmtKind::Assign { left, value } => self.assign_stmt(left, value),
+            StmtKind::Block(_) => {
+                self.line("{");
+                self.body(stmt)?;
+                self.line("}");
+                Ok(())
+            }
+            StmtKind::If { cond, then_branch, else_branch } => {
+                let cond = self.expr(cond, None)?;
+                self.flush_pre();
+                self.line(format!("if {} {{", paren(&cond)));
+                self.body(then_branch)?;
+                self.else_chain(else_branch.as_deref())
+            }
+            StmtKind::Return(value) => self.return_stmt(value.as_ref(), stmt.span),
+            StmtKind::Break | StmtKind::Continue => {
+                if !self.scopes.iter().any(|s| s.loop_body) {
+                    return error("Hata: 'break'/'continue' bir döngü dışında kullanılamaz.", stmt.span);
+                }
+                let defers = self.exit_defers(true);
+                self.emit_defers(defers)?;
+                self.line(if matches!(stmt.kind, StmtKind::Break) { "break;" } else { "continue;" });
+                Ok(())
+            }
+            StmtKind::ExprStmt(expr) => self.expr_stmt(expr),
+            // Rutinler C çıktısında sırayla (eşzamanlı) çalıştırılır.
+            StmtKind::Routine(expr) => self.expr_stmt(expr),
+            StmtKind::While { condition, body } => {
+                let condition = self.expr(condition, None)?;
+                if self.pre.is_empty() {
+                    self.line(format!("while {} {{", paren(&condition)));
+                } else {
+                    // Koşul geçici değişken gerektiriyorsa her turda yeniden hesaplanır.
+                    let pre = std::mem::take(&mut self.pre);
+                    self.line("for (;;) {");
+                    self.indent += 1;
+                    for line in pre {
+                        self.line(line);
+                    }
+                    self.line(format!("if (!{}) break;", paren(&condition)));
+                    self.indent -= 1;
+                }
+                self.loop_body(body, None)
+            }
+            StmtKind::Loop { body } => {
+                self.line("for (;;) {");
+                self.loop_body(body, None)
+            }
+            StmtKind::For { initializer, condition, increment, variable, iterable, body } => match (variable, iterable) {
+                (Some(variable), Some(iterable)) => self.for_in(variable, iterable, body),
+                _ => {
+                    self.line("{");
+                    self.indent += 1;
+                    self.scopes.push(Scope::default());
+                    let result = self.for_c(initializer.as_deref(), condition.as_ref(), increment.as_ref(), body);
+                    self.scopes.pop();
+                    self.indent -= 1;
+                    result?;
+                    self.line("}");
+                    Ok(())
+                }
+            },
+            StmtKind::Echo(expr) => {
+                let call = self.print_call("echo", Some(expr), None, stmt.span)?;
+                self.flush_pre();
+                self.line(format!("{};", call));
+                Ok(())
+            }
+            StmtKind::Empty => Ok(()),
+            StmtKind::Defer(body) => {
+                self.scopes.last_mut().expect("Kapsam yığını boş olamaz.").defers.push(body);
+                Ok(())
+            }
+            StmtKind::Unsafe(body) | StmtKind::FastExec(body) => self.stmt(body),
+            StmtKind::Asm { .. } => error("C arka ucu 'asm' bloklarını çeviremez; bu bloklar yalnızca native arka uçla derlenebilir.", stmt.span),
+            StmtKind::Tag { .. } | StmtKind::Rolling(_) | StmtKind::LabeledExpr { .. } | StmtKind::LabeledStmt { .. } => {
+                error("C arka ucu etiketli grup deyimlerini henüz desteklemiyor.", stmt.span)
+            }
+        }
+    }
+
+    fn else_chain(&mut self, else_branch: Option<&'a Stmt>) -> Gen<()> {
+        let Some(stmt) = else_branch else {
+            self.line("}");
+            return Ok(());
+        };
+        match &stmt.kind {
+            StmtKind::If { cond, then_branch, else_branch } => {
+                let cond = self.expr(cond, None)?;
+                if self.pre.is_empty() {
+                    self.line(format!("}} else if {} {{", paren(&cond)));
+                    self.body(then_branch)?;
+                    return self.else_chain(else_branch.as_deref());
+                }
+                // Koşulun geçici değişkenleri yalnızca bu dala gelindiğinde hesaplanmalı.
+                self.line("} else {");
+                self.indent += 1;
+                self.flush_pre();
+                self.line(format!("if {} {{", paren(&cond)));
+                self.body(then_branch)?;
+                self.else_chain(else_branch.as_deref())?;
+                self.indent -= 1;
+                self.line("}");
+                Ok(())
+            }
+            _ => {
+                self.line("} else {");
+                self.body(stmt)?;
+                self.line("}");
+                Ok(())
+            }
+        }
+    }
+
+    fn var_decl(&mut self, name: &str, ty: &Type, init: Option<&'a Expr>, is_const: bool, span: Span) -> Gen<()> {
+        // Tipi yazılmamış değişkenler (`var x = ...`) başlangıç değerinin tipini alır.
+        let declared = self.resolve(ty);
+        let ty = match (&declared, init) {
+            (Type::Any | Type::Unknown, Some(init)) => self.value_type(init, None)?,
+            _ => declared,
+        };
+        let c_type = self.c_type(&ty, span)?;
+        let value = match init {
+            Some(init) => self.expr_as(init, &ty)?,
+            None => self.default_value(&ty, span)?,
+        };
+        self.flush_pre();
+        if self.in_globals && self.scopes.len() == 1 {
+            let c_name = self.declare_global(name, ty);
+            self.globals.push(format!("static {} {};", c_type, c_name));
+            self.line(format!("{} = {};", c_name, unparen(&value)));
+        } else {
+            let c_name = self.declare(name, ty);
+            let qualifier = if is_const { "const " } else { "" };
+            self.line(format!("{}{} {} = {};", qualifier, c_type, c_name, unparen(&value)));
+        }
+        Ok(())
+    }
+
+    fn declare_global(&mut self, name: &str, ty: Type) -> String {
+        let base = self.c_ident(name);
+        let mut c_name = base.clone();
+        let mut n = 1;
+        while self.global_vars.values().any(|(c, _)| *c == c_name) {
+            c_name = format!("{}_{}", base, n);
+            n += 1;
+        }
+        self.global_vars.insert(name.to_string(), (c_name.clone(), ty));
+        c_name
+    }
+
+    fn assign_stmt(&mut self, left: &'a Expr, value: &'a Expr) -> Gen<()> {
+        // Tanımlanmamış bir değişkene atama onu mevcut kapsamda tanımlar (yorumlayıcıyla aynı).
+        if let ExprKind::Variable(name) = &left.kind {
+            if self.lookup(name).is_none() {
+                return self.var_decl(name, &Type::Any, Some(value), false, left.span);
+            }
+        }
+        let assignment = self.assign_expr(left, value)?;
+        self.flush_pre();
+        self.line(format!("{};", unparen(&assignment)));
+        Ok(())
+    }
+
+    fn assign_expr(&mut self, left: &'a Expr, value: &'a Expr) -> Gen<String> {
+        let (target, ty) = self.lvalue(left)?;
+        let value = self.expr_as(value, &ty)?;
+        Ok(format!("({} = {})", target, unparen(&value)))
+    }
+
+    // Atama hedefinin C karşılığı ve tipi.
+    fn lvalue(&mut self, target: &'a Expr) -> Gen<(String, Type)> {
+        match &target.kind {
+            ExprKind::Variable(name) => match self.lookup(name) {
+                Some((c_name, ty)) => Ok((c_name, self.resolve(&ty))),
+                None => error(format!("Hata: Tanımlanmamış değişken: '{}'.", name), target.span),
+            },
+            ExprKind::MemberAccess { object, member } => {
+                let (object, ty) = self.lvalue(object)?;
+                self.member(&object, &ty, member, target.span)
+            }
+            ExprKind::ArrayAccess { name, index } => {
+                let Some((array, ty)) = self.lookup(name) else {
+                    return error(format!("Hata: Tanımlanmamış değişken: '{}'.", name), target.span);
+                };
+                let index = self.expr(index, None)?;
+                match self.resolve(&ty) {
+                    Type::Array(inner, _) => Ok((format!("{}.items[{}]", array, unparen(&index)), *inner)),
+                    Type::Ptr(inner) => Ok((format!("{}[{}]", array, unparen(&index)), *inner)),
+                    Type::Str(_) => error("C arka ucu metinlerin karakterlerine atama yapamaz (metinler değiştirilemez).", target.span),
+                    other => error(format!("Hata: '{}' bir dizi değil ({}).", name, other), target.span),
+                }
+            }
+            ExprKind::Unary { op: UnOp::Deref, right } => {
+                let pointer = self.expr(right, None)?;
+                match self.type_of(right)? {
+                    Type::Ptr(inner) => Ok((format!("(*{})", pointer), *inner)),
+                    other => error(format!("Hata: İşaretçi olmayan bir değer ({}) dereferans edilemez.", other), target.span),
+                }
+            }
+            _ => error("Hata: Atama ifadesinin sol tarafı bir değişken, struct alanı veya dizi elemanı olmalıdır.", target.span),
+        }
+    }
+
+    fn member(&mut self, object: &str, ty: &Type, member: &str, span: Span) -> Gen<(String, Type)> {
+        match self.resolve(ty) {
+            Type::Custom(name) if self.structs.contains_key(name.as_str()) => {
+                match self.structs[name.as_str()].iter().find(|(field, _)| field == member) {
+                    Some((_, field_type)) => Ok((format!("{}.{}", object, self.c_ident(member)), self.resolve(field_type))),
+                    None => error(format!("Hata: '{}' struct'ının '{}' isminde bir alanı yok.", name, member), span),
+                }
+            }
+            Type::Ptr(inner) if matches!(inner.as_ref(), Type::Custom(_)) => {
+                let (access, field_type) = self.member(&format!("(*{})", object), &inner, member, span)?;
+                Ok((access, field_type))
+            }
+            Type::Tuple(types) => match member.parse::<usize>().ok().and_then(|i| types.get(i).map(|t| (i, t.clone()))) {
+                Some((index, item_type)) => Ok((format!("{}.f{}", object, index), item_type)),
+                None => error(format!("Hata: Tuple'ın '{}' isminde bir elemanı yok.", member), span),
+            },
+            other => error(format!("Hata: '{}' üyesine bu tip üzerinden erişilemez: {}.", member, other), span),
+        }
+    }
+
+    fn return_stmt(&mut self, value: Option<&'a Expr>, span: Span) -> Gen<()> {
+        if self.in_globals {
+            return error("C arka ucu fonksiyon dışındaki 'return' deyimlerini desteklemiyor.", span);
+        }
+        let defers = self.exit_defers(false);
+        let return_type = self.return_type.clone();
+        match value {
+            Some(value) if !matches!(return_type, Type::Void | Type::Never) => {
+                let value = self.expr_as(value, &return_type)?;
+                if defers.is_empty() {
+                    self.flush_pre();
+                    self.line(format!("return {};", unparen(&value)));
+                } else {
+                    // Dönüş değeri defer blokları çalışmadan önce hesaplanır.
+                    let result = self.hoist(&return_type, value, span)?;
+                    self.flush_pre();
+                    self.emit_defers(defers)?;
+                    self.line(format!("return {};", result));
+                }
+            }
+            Some(value) => {
+                self.expr_stmt(value)?;
+                self.emit_defers(defers)?;
+                self.line("return;");
+            }
+            None => {
+                self.emit_defers(defers)?;
+                self.line("return;");
+            }
+        }
+        Ok(())
+    }
+
+    fn expr_stmt(&mut self, expr: &'a Expr) -> Gen<()> {
+        match &expr.kind {
+            ExprKind::Match { discriminant, cases } => self.match_stmt(discriminant, cases),
+            ExprKind::Assign { left, value } => self.assign_stmt(left, value),
+            _ => {
+                let value = self.expr(expr, None)?;
+                self.flush_pre();
+                self.line(format!("{};", unparen(&value)));
+                Ok(())
+            }
+        }
+    }
+
+    fn for_in(&mut self, variable: &'a str, iterable: &'a Expr, body: &'a Stmt) -> Gen<()> {
+        let span = iterable.span;
+        let index = self.temp();
+        if let ExprKind::Range { start, end } = &iterable.kind {
+            // Aralığın sınırları döngüden önce bir kez hesaplanır.
+            let start = self.expr(start, None)?;
+            let end = self.expr(end, None)?;
+            self.flush_pre();
+            let limit = self.temp();
+            self.line(format!("for (nim_int {0} = {1}, {2} = {3}; {0} < {2}; {0}++) {{", index, unparen(&start), limit, unparen(&end)));
+            return self.loop_body(body, Some((variable, Type::I64, index)));
+        }
+        let ty = self.value_type(iterable, None)?;
+        // Yorumlayıcıda olduğu gibi döngü, dizinin döngü başındaki kopyası üzerinde çalışır.
+        let value = self.expr(iterable, Some(&ty))?;
+        let items = self.hoist(&ty, value, span)?;
+        self.flush_pre();
+        match ty {
+            Type::Array(inner, size) => {
+                let bound = match size {
+                    Some(size) => size.to_string(),
+                    None => format!("{}.len", items),
+                };
+                self.line(format!("for (nim_int {0} = 0; {0} < {1}; {0}++) {{", index, bound));
+                let item = format!("{}.items[{}]", items, index);
+                self.loop_body(body, Some((variable, *inner, item)))
+            }
+            Type::Str(_) => {
+                self.line(format!("for (nim_int {0} = 0; {1}[{0}] != '\\0'; {0}++) {{", index, items));
+                let item = format!("{}[{}]", items, index);
+                self.loop_body(body, Some((variable, Type::Char, item)))
+            }
+            other => error(format!("Hata: 'for-in' döngüsü bu tip üzerinde çalışamaz: {}.", other), span),
+        }
+    }
+
+    fn for_c(&mut self, initializer: Option<&'a Stmt>, condition: Option<&'a Expr>, increment: Option<&'a Expr>, body: &'a Stmt) -> Gen<()> {
+        if let Some(initializer) = initializer {
+            self.stmt(initializer)?;
+        }
+        let condition = match condition {
+            Some(condition) => Some(self.expr(condition, None)?),
+            None => None,
+        };
+        let condition_pre = std::mem::take(&mut self.pre);
+        let increment = match increment {
+            Some(increment) => {
+                let value = self.expr(increment, None)?;
+                if !self.pre.is_empty() {
+                    return error("C arka ucu geçici değer gerektiren for artış ifadelerini desteklemiyor.", increment.span);
+                }
+                unparen(&value).to_string()
+            }
+            None => String::new(),
+        };
+        match condition {
+            Some(condition) if !condition_pre.is_empty() => {
+                self.line(format!("for (;; {}) {{", increment));
+                self.indent += 1;
+                for line in condition_pre {
+                    self.line(line);
+                }
+                self.line(format!("if (!{}) break;", paren(&condition)));
+                self.indent -= 1;
+            }
+            Some(condition) => self.line(format!("for (; {}; {}) {{", unparen(&condition), increment)),
+            None => self.line(format!("for (;; {}) {{", increment)),
+        }
+        self.loop_body(body, None)
+    }
+
+    // match deyimi: kollar sırayla karşılaştırılan bir if/else zinciri olur; `def` kolu son else dalıdır.
+    fn match_stmt(&mut self, discriminant: &'a Expr, cases: &'a [(Expr, Box<Expr>)]) -> Gen<()> {
+        let ty = self.value_type(discriminant, None)?;
+        let value = self.stable(discriminant, &ty)?;
+        let mut arms = Vec::new();
+        for (pattern, result) in cases {
+            if matches!(pattern.kind, ExprKind::DefaultCase) {
+                arms.push((None, result.as_ref()));
+                break;
+            }
+            arms.push((Some(self.pattern(&value, &ty, pattern)?), result.as_ref()));
+        }
+        self.flush_pre();
+        for (i, (condition, result)) in arms.iter().enumerate() {
+            match (i, condition) {
+                (0, Some(condition)) => self.line(format!("if {} {{", paren(condition))),
+                (_, Some(condition)) => self.line(format!("}} else if {} {{", paren(condition))),
+                (0, None) => self.line("{"),
+                (_, None) => self.line("} else {"),
+            }
+            self.indent += 1;
+            let result = match &result.kind {
+                ExprKind::Block { statements } => {
+                    if statements.iter().any(contains_return) {
+                        return error("C arka ucu match kolu bloklarındaki 'return' deyimlerini desteklemiyor.", result.span);
+                    }
+                    self.block(statements)
+                }
+                _ => self.expr_stmt(result),
+            };
+            self.indent -= 1;
+            result?;
+        }
+        if !arms.is_empty() {
+            self.line("}");
+        }
+        Ok(())
+    }
+
+    // Match kolunun koşulu (yorumlayıcıdaki values_equal ile aynı karşılaştırma).
+    fn pattern(&mut self, value: &str, ty: &Type, pattern: &'a Expr) -> Gen<String> {
+        let pattern_value = self.expr_as(pattern, ty)?;
+        let pattern_type = self.value_type(pattern, Some(ty))?;
+        self.equality(value, ty, &pattern_value, &pattern_type, pattern.span)
+    }
+}
+
+// --- İfadeler ---
+impl<'a, 'c, 't> CGen<'a, 'c, 't> {
+    fn expr(&mut self, expr: &'a Expr, expected: Option<&Type>) -> Gen<String> {
+        let span = expr.span;
+        match &expr.kind {
+            ExprKind::Literal(literal) => literal_value(literal, span),
+            ExprKind::Variable(name) => {
+                if let Some((c_name, _)) = self.lookup(name) {
+                    return Ok(c_name);
+                }
+                if name == "None" {
+                    return self.wrapper(expr, expected, "None", None);
+                }
+                error(format!("C arka ucu '{}' ismini bir değer olarak kullanamaz (fonksiyon değerleri henüz desteklenmiyor).", name), span)
+            }
+            ExprKind::Tuple(elements) => {
+                let ty = self.value_type(expr, expected)?;
+                let Type::Tuple(types) = &ty else {
+                    return error(format!("Hata: Tuple ifadesinin tipi bir tuple değil: {}.", ty), span);
+                };
+                let values = self.ordered(elements.iter().zip(types.iter().cloned()).collect())?;
+                Ok(format!("(({}){{ {} }})", self.c_type(&ty, span)?, values.join(", ")))
+            }
+            ExprKind::ArrayLiteral(elements) => self.array_literal(expr, elements, expected),
+            ExprKind::Match { discriminant, cases } => self.match_expr(expr, discriminant, cases, expected),
+            ExprKind::Input(prompt) => self.input(prompt.as_deref()),
+            ExprKind::Block { .. } => error("C arka ucu blok ifadelerini yalnızca match deyimlerinin kollarında destekler.", span),
+            ExprKind::DefaultCase => error("Hata: 'def' sadece match kollarında kullanılabilir.", span),
+            ExprKind::ArrayAccess { name, index } => {
+                let Some((array, ty)) = self.lookup(name) else {
+                    return error(format!("Hata: Tanımlanmamış değişken: '{}'.", name), span);
+                };
+                let index = self.expr(index, None)?;
+                match self.resolve(&ty) {
+                    Type::Array(..) => Ok(format!("{}.items[{}]", array, unparen(&index))),
+                    Type::Str(_) | Type::Ptr(_) => Ok(format!("{}[{}]", array, unparen(&index))),
+                    other => error(format!("Hata: '{}' bir dizi değil ({}).", name, other), span),
+                }
+            }
+            ExprKind::MemberAccess { object, member } => {
+                let ty = self.value_type(object, None)?;
+                let object = self.expr(object, Some(&ty))?;
+                Ok(self.member(&object, &ty, member, span)?.0)
+            }
+            ExprKind::Range { .. } => error("C arka ucu aralık ifadelerini yalnızca for-in döngülerinde destekler.", span),
+            ExprKind::Binary { left, op, right } => self.binary(expr, left, op, right),
+            ExprKind::Unary { op, right } => match op {
+                UnOp::Neg => Ok(format!("(-{})", self.expr(right, None)?)),
+                UnOp::Not => Ok(format!("(!{})", self.expr(right, None)?)),
+                UnOp::BitwiseNot => Ok(format!("(~{})", self.expr(right, None)?)),
+                UnOp::Deref => Ok(format!("(*{})", self.expr(right, None)?)),
+                UnOp::AddressOf => Ok(format!("(&{})", self.lvalue(right)?.0)),
+                UnOp::PreInc => Ok(format!("(++{})", self.lvalue(right)?.0)),
+                UnOp::PreDec => Ok(format!("(--{})", self.lvalue(right)?.0)),
+                UnOp::PostInc => Ok(format!("({}++)", self.lvalue(right)?.0)),
+                UnOp::PostDec => Ok(format!("({}--)", self.lvalue(right)?.0)),
+            },
+            ExprKind::Conditional { cond, then_branch, else_branch } => {
+                let ty = self.value_type(expr, expected)?;
+                let cond = self.expr(cond, None)?;
+                let then_branch = self.branch(then_branch, &ty)?;
+                let else_branch = self.branch(else_branch, &ty)?;
+                self.choose(vec![(Some(cond), then_branch), (None, else_branch)], &ty, span)
+            }
+            // Fonksiyonlar eşzamanlı çalıştırıldığından 'await' değeri olduğu gibi döndürür.
+            ExprKind::Await(inner) => self.expr(inner, expected),
+            ExprKind::Assign { left, value } => self.assign_expr(left, value),
+            ExprKind::Call { callee, args } => self.call(expr, callee, args, expected),
+            ExprKind::Lambda { .. } => error("C arka ucu lambda ifadelerini henüz desteklemiyor.", span),
+            ExprKind::InterpolatedString(_) => {
+                let mut pieces = Vec::new();
+                self.pieces(expr, &mut pieces)?;
+                Ok(self.format_call(pieces))
+            }
+            ExprKind::Try(inner) => self.try_expr(inner, span),
+            ExprKind::EnumAccess { enum_name, variant_name } => {
+                match self.enums.get(enum_name.as_str()).is_some_and(|v| v.iter().any(|(name, _)| name == variant_name)) {
+                    true => Ok(format!("en_{}_{}", enum_name, variant_name)),
+                    false => error(format!("C arka ucu '{}::{}' erişimini çeviremez (modül erişimleri henüz desteklenmiyor).", enum_name, variant_name), span),
+                }
+            }
+            ExprKind::StructLiteral { name, fields, .. } => {
+                let Some(definition) = self.structs.get(name.as_str()).copied() else {
+                    return error(format!("Hata: Tanımlanmamış struct tipi: '{}'.", name), span);
+                };
+                // Alanlar tanım sırasıyla yazılır; verilmeyen alanlar varsayılan değerini alır.
+                let given: Vec<(&'a Expr, Type)> = fields.iter().map(|(field, value)| {
+                    let ty = definition.iter().find(|(n, _)| n == field).map_or(Type::Any, |(_, t)| t.clone());
+                    (value, ty)
+                }).collect();
+                let values = self.ordered(given)?;
+                let mut inits = Vec::new();
+                for (field, field_type) in definition {
+                    let value = match fields.iter().position(|(n, _)| n == field) {
+                        Some(index) => values[index].clone(),
+                        None => self.default_value(field_type, span)?,
+                    };
+                    inits.push(format!(".{} = {}", self.c_ident(field), unparen(&value)));
+                }
+                if inits.is_empty() {
+                    inits.push("0".to_string());
+                }
+                Ok(format!("((st_{}){{ {} }})", name, inits.join(", ")))
+            }
+            ExprKind::SizeOf(ty) => Ok(self.size_of(ty).to_string()),
+            ExprKind::Send { .. } | ExprKind::Recv(_) => error("C arka ucu kanal işlemlerini henüz desteklemiyor.", span),
+        }
+    }
+
+    // İfadeyi hedef tipe uygun biçimde üretir (sabit boyutlu dizi, dinamik dizi parametresine dönüştürülür).
+    fn expr_as(&mut self, expr: &'a Expr, ty: &Type) -> Gen<String> {
+        let target = self.resolve(ty);
+        let expected = if matches!(target, Type::Any | Type::Unknown) { None } else { Some(&target) };
+        let value = self.expr(expr, expected)?;
+        if let Type::Array(_, None) = &target {
+            if let Type::Array(_, Some(size)) = self.type_of(expr)? {
+                let source = self.type_of(expr)?;
+                let items = if self.is_path(expr) { value } else { self.hoist(&source, value, expr.span)? };
+                return Ok(format!("(({}){{ {}.items, {} }})", self.c_type(&target, expr.span)?, items, size));
+            }
+        }
+        // İşaretsiz 64 bitlik değerler (nim_int olarak taşınır) 128 bite işaret uzatılmadan genişletilir.
+        if matches!(target, Type::I128 | Type::U128) {
+            let source = self.type_of(expr)?;
+            if source.is_unsigned_integer() && !matches!(source, Type::U128) {
+                return Ok(format!("((uint64_t){})", paren(&value)));
+            }
+        }
+        Ok(value)
+    }
+
+    // C'de argümanların ve alt ifadelerin değerlendirilme sırası belirsizdir. Yan etkili (veya geçici
+    // değişken gerektiren) birden çok ifade varsa, soldan sağa sırayı korumak için öncekiler geçici
+    // değişkenlere alınır.
+    fn ordered(&mut self, items: Vec<(&'a Expr, Type)>) -> Gen<Vec<String>> {
+        let mut values: Vec<String> = Vec::new();
+        let mut pending: Option<(usize, Type)> = None;
+        for (expr, ty) in items {
+            let position = self.pre.len();
+            let value = self.expr_as(expr, &ty)?;
+            let effectful = !self.is_pure(expr);
+            if effectful || self.pre.len() > position {
+                if let Some((index, pending_type)) = pending.take() {
+                    let name = self.temp();
+                    let pending_type = if matches!(self.resolve(&pending_type), Type::Any | Type::Unknown) { self.type_of(expr)? } else { pending_type };
+                    let c_type = self.c_type(&pending_type, expr.span)?;
+                    self.pre.insert(position, format!("{} {} = {};", c_type, name, unparen(&values[index])));
+                    values[index] = name;
+                }
+            }
+            if effectful {
+                let ty = if matches!(self.resolve(&ty), Type::Any | Type::Unknown) { self.value_type(expr, None)? } else { ty };
+                pending = Some((values.len(), ty));
+            }
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    fn is_pure(&self, expr: &Expr) -> bool {
+        match &expr.kind {
+            ExprKind::Literal(_) | ExprKind::Variable(_) | ExprKind::EnumAccess { .. } | ExprKind::SizeOf(_) => true,
+            ExprKind::MemberAccess { object, .. } => self.is_pure(object),
+            ExprKind::ArrayAccess { index, .. } => self.is_pure(index),
+            ExprKind::Unary { op, right } => matches!(op, UnOp::Neg | UnOp::Not | UnOp::BitwiseNot | UnOp::Deref | UnOp::AddressOf) && self.is_pure(right),
+            ExprKind::Binary { left, right, .. } => self.is_pure(left) && self.is_pure(right),
+            ExprKind::Conditional { cond, then_branch, else_branch } => {
+                self.is_pure(cond) && self.is_pure(then_branch) && self.is_pure(else_branch)
+            }
+            ExprKind::Tuple(items) | ExprKind::ArrayLiteral(items) | ExprKind::InterpolatedString(items) => items.iter().all(|e| self.is_pure(e)),
+            ExprKind::StructLiteral { fields, .. } => fields.iter().all(|(_, e)| self.is_pure(e)),
+            _ => false,
+        }
+    }
+
+    fn array_literal(&mut self, expr: &'a Expr, elements: &'a [Expr], expected: Option<&Type>) -> Gen<String> {
+        let span = expr.span;
+        let ty = self.value_type(expr, expected)?;
+        let Type::Array(inner, size) = &ty else {
+            return error(format!("Hata: Dizi literalinin tipi bir dizi değil: {}.", ty), span);
+        };
+        let mut values = self.ordered(elements.iter().map(|e| (e, (**inner).clone())).collect())?;
+        let values: Vec<String> = values.drain(..).map(|v| unparen(&v).to_string()).collect();
+        let c_type = self.c_type(&ty, span)?;
+        match size {
+            Some(size) => {
+                if values.len() > *size {
+                    return error(format!("Hata: Dizi literalinde {} eleman var, ancak dizinin boyutu {}.", values.len(), size), span);
+                }
+                let mut values = values;
+                while values.len() < *size {
+                    values.push(self.default_value(inner, span)?);
+                }
+                Ok(format!("(({}){{ {{ {} }} }})", c_type, values.join(", ")))
+            }
+            None if values.is_empty() => Ok(format!("(({}){{ NULL, 0 }})", c_type)),
+            None => {
+                // Dinamik dizi elemanları bloğun ömrüne bağlı kalmasın diye heap'e kopyalanır.
+                let item = self.c_type(inner, span)?;
+                let bytes = format!("sizeof({}) * {}", item, values.len());
+                Ok(format!("(({}){{ memcpy(malloc({}), ({}[]){{ {} }}, {}), {} }})", c_type, bytes, item, values.join(", "), bytes, values.len()))
+            }
+        }
+    }
+
+    fn input(&mut self, prompt: Option<&'a Expr>) -> Gen<String> {
+        self.uses_input = true;
+        let prompt = match prompt {
+            Some(prompt) => {
+                let mut pieces = Vec::new();
+                self.pieces(prompt, &mut pieces)?;
+                self.format_call(pieces)
+            }
+            None => "NULL".to_string(),
+        };
+        Ok(format!("nim_input({})", unparen(&prompt)))
+    }
+
+    // Yalnızca koşula bağlı olarak değerlendirilmesi gereken bir dal: geçici değişken satırları ve değer.
+    fn branch(&mut self, expr: &'a Expr, ty: &Type) -> Gen<Branch> {
+        let saved = std::mem::take(&mut self.pre);
+        let value = self.expr_as(expr, ty);
+        let lines = std::mem::replace(&mut self.pre, saved);
+        Ok((lines, value?))
+    }
+
+    // Koşullu dallardan birini seçen ifade (?:, match). Dallardan biri geçici değişken gerektiriyorsa
+    // dalların yalnızca seçileni çalışsın diye if/else zinciri ile bir geçici değişkene yazılır.
+    fn choose(&mut self, arms: Vec<(Option<String>, Branch)>, ty: &Type, span: Span) -> Gen<String> {
+        let is_void = matches!(ty, Type::Void | Type::Never);
+        if arms.iter().all(|(_, (lines, _))| lines.is_empty()) {
+            let mut result = match arms.last() {
+                Some((None, (_, value))) => value.clone(),
+                _ if is_void => "((void)0)".to_string(),
+                _ => self.default_value(ty, span)?,
+            };
+            for (condition, (_, value)) in arms.iter().rev() {
+                result = match condition {
+                    Some(condition) => format!("({} ? {} : {})", unparen(condition), value, result),
+                    None => value.clone(),
+                };
+            }
+            return Ok(result);
+        }
+        let result = if is_void { None } else { Some(self.temp()) };
+        if let Some(result) = &result {
+            let c_type = self.c_type(ty, span)?;
+            self.pre.push(format!("{} {};", c_type, result));
+        }
+        let has_default = arms.last().is_some_and(|(c, _)| c.is_none());
+        for (i, (condition, (lines, value))) in arms.into_iter().enumerate() {
+            match (i, &condition) {
+                (0, Some(condition)) => self.pre.push(format!("if {} {{", paren(condition))),
+                (_, Some(condition)) => self.pre.push(format!("}} else if {} {{", paren(condition))),
+                (0, None) => self.pre.push("{".to_string()),
+                (_, None) => self.pre.push("} else {".to_string()),
+            }
+            for line in lines {
+                self.pre.push(format!("    {}", line));
+            }
+            match &result {
+                Some(result) => self.pre.push(format!("    {} = {};", result, unparen(&value))),
+                None => self.pre.push(format!("    {};", unparen(&value))),
+            }
+        }
+        if let (Some(result), false) = (&result, has_default) {
+            let default = self.default_value(ty, span)?;
+            self.pre.push("} else {".to_string());
+            self.pre.push(format!("    {} = {};", result, default));
+        }
+        self.pre.push("}".to_string());
+        Ok(result.unwrap_or_else(|| "((void)0)".to_string()))
+    }
+
+    fn match_expr(&mut self, expr: &'a Expr, discriminant: &'a Expr, cases: &'a [(Expr, Box<Expr>)], expected: Option<&Type>) -> Gen<String> {
+        let ty = self.value_type(expr, expected)?;
+        let discriminant_type = self.value_type(discriminant, None)?;
+        let value = self.stable(discriminant, &discriminant_type)?;
+        let mut arms = Vec::new();
+        for (pattern, result) in cases {
+            if let ExprKind::Block { .. } = result.kind {
+                return error("C arka ucu match ifadelerinde blok kollarını desteklemiyor; match'i deyim olarak kullanın.", result.span);
+            }
+            if matches!(pattern.kind, ExprKind::DefaultCase) {
+                arms.push((None, self.branch(result, &ty)?));
+                break;
+            }
+            let condition = self.pattern(&value, &discriminant_type, pattern)?;
+            arms.push((Some(condition), self.branch(result, &ty)?));
+        }
+        self.choose(arms, &ty, expr.span)
+    }
+
+    fn binary(&mut self, expr: &'a Expr, left: &'a Expr, op: &BinOp, right: &'a Expr) -> Gen<String> {
+        let span = expr.span;
+        match op {
+            BinOp::And | BinOp::Or => {
+                // Kısa devre: sağ taraf geçici değişken gerektiriyorsa yalnızca gerektiğinde hesaplanır.
+                let left = self.expr(left, None)?;
+                let (lines, right) = self.branch(right, &Type::Bool)?;
+                if lines.is_empty() {
+                    return Ok(format!("({} {} {})", left, op.symbol(), right));
+                }
+                let result = self.hoist(&Type::Bool, left, span)?;
+                let negate = if matches!(op, BinOp::And) { "" } else { "!" };
+                self.pre.push(format!("if ({}{}) {{", negate, result));
+                for line in lines {
+                    self.pre.push(format!("    {}", line));
+                }
+                self.pre.push(format!("    {} = {};", result, unparen(&right)));
+                self.pre.push("}".to_string());
+                Ok(result)
+            }
+            BinOp::Equal | BinOp::Eq | BinOp::Identical | BinOp::NotEqual | BinOp::Ne | BinOp::NotIdentical => {
+                let left_type = self.value_type(left, None)?;
+                let right_type = self.value_type(right, None)?;
+                // Ok(..)/None gibi tipi eksik taraf, diğer tarafın tipini alır.
+                let ty = if contains_any(&left_type) { right_type.clone() } else { left_type.clone() };
+                let values = self.ordered(vec![(left, ty.clone()), (right, ty.clone())])?;
+                let left_type = if contains_any(&left_type) { ty.clone() } else { left_type };
+                let right_type = if contains_any(&right_type) { ty } else { right_type };
+                let equal = self.equality(&values[0], &left_type, &values[1], &right_type, span)?;
+                match op {
+                    BinOp::NotEqual | BinOp::Ne | BinOp::NotIdentical => Ok(format!("(!{})", paren(&equal))),
+                    _ => Ok(equal),
+                }
+            }
+            _ => {
+                let left_type = self.value_type(left, None)?;
+                let right_type = self.value_type(right, None)?;
+                let values = self.ordered(vec![(left, left_type.clone()), (right, right_type.clone())])?;
+                let (l, r) = (&values[0], &values[1]);
+                match op {
+                    BinOp::Greater | BinOp::Gt | BinOp::Less | BinOp::Lt | BinOp::GreaterEqual | BinOp::Ge | BinOp::LessEqual | BinOp::Le
+                        if matches!(left_type, Type::Str(_)) =>
+                    {
+                        Ok(format!("(strcmp({}, {}) {} 0)", unparen(l), unparen(r), op.symbol()))
+                    }
+                    BinOp::Mod if is_float_like(&left_type) || is_float_like(&right_type) => Ok(format!("fmod({}, {})", unparen(l), unparen(r))),
+                    // Kaydırma miktarı yorumlayıcıdaki wrapping_shl/shr gibi 64'e göre modüler alınır.
+                    BinOp::LShift | BinOp::RShift => Ok(format!("({} {} ({} & 63))", l, op.symbol(), r)),
+                    _ => Ok(format!("({} {} {})", l, op.symbol(), r)),
+                }
+            }
+        }
+    }
+
+    // İki değerin eşitliği (yorumlayıcıdaki values_equal): metinler içerikle, birleşik tipler alan alan karşılaştırılır.
+    fn equality(&mut self, left: &str, left_type: &Type, right: &str, right_type: &Type, span: Span) -> Gen<String> {
+        let (left_type, right_type) = (self.resolve(left_type), self.resolve(right_type));
+        if matches!(left_type, Type::Null) || matches!(right_type, Type::Null) {
+            return Ok(format!("({} == {})", left, right));
+        }
+        self.equal_values(&left_type, left, right, span)
+    }
+
+    fn equal_values(&mut self, ty: &Type, left: &str, right: &str, span: Span) -> Gen<String> {
+        Ok(match ty {
+            Type::Str(_) => format!("(strcmp({}, {}) == 0)", unparen(left), unparen(right)),
+            Type::Custom(_) | Type::Tuple(_) | Type::Array(..) | Type::Result(..) | Type::Option(_) => {
+                let helper = self.require_eq(ty, span)?;
+                format!("{}({}, {})", helper, unparen(left), unparen(right))
+            }
+            _ => format!("({} == {})", left, right),
+        })
+    }
+
+    // Birleşik tipler için eşitlik yardımcı fonksiyonu (nim_eq_<tip>).
+    fn require_eq(&mut self, ty: &Type, span: Span) -> Gen<String> {
+        let name = format!("nim_eq_{}", self.mangle(ty, span)?);
+        if !self.defined.insert(name.clone()) {
+            return Ok(name);
+        }
+        let c_type = self.c_type(ty, span)?;
+        let mut body = Vec::new();
+        match ty {
+            Type::Custom(struct_name) => {
+                let mut parts = Vec::new();
+                for (field, field_type) in self.structs[struct_name.as_str()] {
+                    let field = self.c_ident(field);
+                    let field_type = self.resolve(field_type);
+                    parts.push(self.equal_values(&field_type, &format!("a.{}", field), &format!("b.{}", field), span)?);
+                }
+                body.push(format!("return {};", if parts.is_empty() { "true".to_string() } else { parts.join(" && ") }));
+            }
+            Type::Tuple(types) => {
+                let mut parts = Vec::new();
+                for (i, item_type) in types.iter().enumerate() {
+                    parts.push(self.equal_values(item_type, &format!("a.f{}", i), &format!("b.f{}", i), span)?);
+                }
+                body.push(format!("return {};", if parts.is_empty() { "true".to_string() } else { parts.join(" && ") }));
+            }
+            Type::Array(inner, size) => {
+                let bound = match size {
+                    Some(size) => size.to_string(),
+                    None => {
+                        body.push("if (a.len != b.len) return false;".to_string());
+                        "a.len".to_string()
+                    }
+                };
+                let item = self.equal_values(inner, "a.items[i]", "b.items[i]", span)?;
+                body.push(format!("for (nim_int i = 0; i < {}; i++) {{", bound));
+                body.push(format!("    if (!{}) return false;", paren(&item)));
+                body.push("}".to_string());
+                body.push("return true;".to_string());
+            }
+            Type::Result(ok, err) => {
+                let ok_equal = self.optional_equal(ok, "a.value", "b.value", span)?;
+                let err_equal = self.optional_equal(err, "a.error", "b.error", span)?;
+                body.push(format!("return a.ok == b.ok && (a.ok ? {} : {});", ok_equal, err_equal));
+            }
+            Type::Option(inner) => {
+                let equal = self.optional_equal(inner, "a.value", "b.value", span)?;
+                body.push(format!("return a.some == b.some && (!a.some || {});", equal));
+            }
+            _ => unreachable!("require_eq yalnızca birleşik tiplerle çağrılır"),
+        }
+        let body: Vec<String> = body.into_iter().map(|line| format!("    {}", line)).collect();
+        self.helpers.push(format!("static bool {}({1} a, {1} b) {{\n{2}\n}}", name, c_type, body.join("\n")));
+        Ok(name)
+    }
+
+    // void alanı olmayan Result/Option değerlerinde karşılaştırılacak bir şey yoktur.
+    fn optional_equal(&mut self, ty: &Type, left: &str, right: &str, span: Span) -> Gen<String> {
+        match self.resolve(ty) {
+            Type::Void | Type::Never => Ok("true".to_string()),
+            ty => self.equal_values(&ty, left, right, span),
+        }
+    }
+
+    fn wrapper(&mut self, expr: &'a Expr, expected: Option<&Type>, kind: &str, value: Option<&'a Expr>) -> Gen<String> {
+        let span = expr.span;
+        let ty = self.value_type(expr, expected)?;
+        if contains_any(&ty) {
+            return error(format!("C arka ucu '{}' değerinin tipini belirleyemedi; değişkene veya dönüş tipine açık bir Result/Option tipi verin.", kind), span);
+        }
+        let c_type = self.c_type(&ty, span)?;
+        let (flag, field, inner) = match (kind, &ty) {
+            ("Ok", Type::Result(ok, _)) => (".ok = true", ".value", ok.as_ref().clone()),
+            ("Err", Type::Result(_, err)) => (".ok = false", ".error", err.as_ref().clone()),
+            ("Some", Type::Option(inner)) => (".some = true", ".value", inner.as_ref().clone()),
+            ("None", Type::Option(_)) => return Ok(format!("(({}){{ .some = false }})", c_type)),
+            _ => return error(format!("Hata: '{}' değeri bu tiple uyumlu değil: {}.", kind, ty), span),
+        };
+        match value {
+            Some(value) if !matches!(inner, Type::Void | Type::Never) => {
+                let value = self.expr_as(value, &inner)?;
+                Ok(format!("(({}){{ {}, {} = {} }})", c_type, flag, field, unparen(&value)))
+            }
+            _ => Ok(format!("(({}){{ {} }})", c_type, flag)),
+        }
+    }
+
+    // `expr?`: başarısız değer, mevcut fonksiyondan (defer blokları çalıştırılarak) hemen döndürülür.
+    fn try_expr(&mut self, inner: &'a Expr, span: Span) -> Gen<String> {
+        let ty = self.value_type(inner, None)?;
+        let value = self.expr(inner, Some(&ty))?;
+        let return_type = self.return_type.clone();
+        let return_c_type = self.c_type(&return_type, span).ok();
+        let result = self.hoist(&ty, value, span)?;
+        let (failed, failure) = match (&ty, &return_type) {
+            (Type::Result(_, err), Type::Result(..)) => {
+                let failure = match self.resolve(err) {
+                    Type::Void | Type::Never => format!("(({}){{ .ok = false }})", return_c_type.unwrap_or_default()),
+                    _ => format!("(({}){{ .ok = false, .error = {}.error }})", return_c_type.unwrap_or_default(), result),
+                };
+                (format!("!{}.ok", result), failure)
+            }
+            (Type::Option(_), Type::Option(_)) => (format!("!{}.some", result), format!("(({}){{ .some = false }})", return_c_type.unwrap_or_default())),
+            _ => return error(format!("Hata: '?' operatörü yalnızca aynı türden (Result/Option) değer döndüren fonksiyonlarda kullanılabilir; bulundu: {}.", ty), span),
+        };
+        let defers = self.exit_defers(false);
+        let lines = self.capture(|gen| {
+            gen.emit_defers(defers)?;
+            gen.line(format!("return {};", failure));
+            Ok(())
+        })?;
+        self.pre.push(format!("if ({}) {{", failed));
+        self.pre.extend(lines);
+        self.pre.push("}".to_string());
+        Ok(format!("{}.value", result))
+    }
+}
+
+// --- Çağrılar ve yazdırma ---
+impl<'a, 'c, 't> CGen<'a, 'c, 't> {
+    fn call(&mut self, expr: &'a Expr, callee: &'a Expr, args: &'a [(Option<String>, Expr)], expected: Option<&Type>) -> Gen<String> {
+        let span = expr.span;
+        let name = match &callee.kind {
+            ExprKind::MemberAccess { object, member } => {
+                let ty = self.value_type(object, None)?;
+                if matches!(ty, Type::Result(..) | Type::Option(_)) {
+                    return self.wrapper_method(object, &ty, member, args, span);
+                }
+                return error(format!("C arka ucu '{}' metot çağrısını henüz desteklemiyor.", member), span);
+            }
+            ExprKind::Variable(name) if self.lookup(name).is_none() => name.as_str(),
+            _ => return error("C arka ucu fonksiyon değerleri üzerinden çağrıları henüz desteklemiyor.", span),
+        };
+        if let Some(info) = self.functions.get(name).cloned() {
+            return self.user_call(&info, args, span);
+        }
+        if let Some(info) = self.externs.get(name).cloned() {
+            if args.iter().any(|(name, _)| name.is_some()) {
+                return error(format!("Hata: Dış fonksiyon '{}' isimli argümanlarla çağrılamaz.", name), span);
+            }
+            if args.len() != info.params.len() {
+                return error(format!("Hata: '{}' fonksiyonu {} argüman bekler, {} verildi.", name, info.params.len(), args.len()), span);
+            }
+            let items = args.iter().zip(info.params).map(|((_, arg), (_, ty, _))| (arg, ty.clone())).collect();
+            let values = self.ordered(items)?;
+            let values: Vec<&str> = values.iter().map(|v| unparen(v)).collect();
+            return Ok(format!("{}({})", info.c_name, values.join(", ")));
+        }
+        self.builtin(expr, name, args, expected)
+    }
+
+    // Argümanları parametrelerle eşleştirir: isimliler adıyla, pozisyoneller sırayla; eksikler varsayılan değeri alır.
+    fn user_call(&mut self, info: &FnInfo<'a>, args: &'a [(Option<String>, Expr)], span: Span) -> Gen<String> {
+        let params = info.params;
+        let mut bound: Vec<Option<&'a Expr>> = vec![None; params.len()];
+        let mut next_positional = 0;
+        for (name, value) in args {
+            let index = match name {
+                Some(name) => match params.iter().position(|(p, _, _)| p == name) {
+                    Some(index) => index,
+                    None => return error(format!("Hata: Fonksiyonun '{}' isminde bir parametresi yok.", name), span),
+                },
+                None => {
+                    while next_positional < bound.len() && bound[next_positional].is_some() {
+                        next_positional += 1;
+                    }
+                    next_positional
+                }
+            };
+            if index >= bound.len() {
+                return error(format!("Hata: Fonksiyona çok fazla argüman verildi. Beklenen: {}.", params.len()), span);
+            }
+            bound[index] = Some(value);
+        }
+        let mut items = Vec::new();
+        for ((name, ty, default), value) in params.iter().zip(bound) {
+            match (value, default) {
+                (Some(value), _) | (None, Some(value)) => items.push((value, ty.clone())),
+                (None, None) => return error(format!("Hata: Gerekli olan '{}' parametresi sağlanmadı.", name), span),
+            }
+        }
+        let values = self.ordered(items)?;
+        let values: Vec<&str> = values.iter().map(|v| unparen(v)).collect();
+        Ok(format!("{}({})", info.c_name, values.join(", ")))
+    }
+
+    // Result/Option metotları (is_ok, unwrap, unwrap_or, expect ...).
+    fn wrapper_method(&mut self, object: &'a Expr, ty: &Type, method: &str, args: &'a [(Option<String>, Expr)], span: Span) -> Gen<String> {
+        if contains_any(ty) {
+            return error("C arka ucu bu Result/Option değerinin tipini belirleyemedi; değeri önce tipli bir değişkene atayın.", span);
+        }
+        let value = self.stable(object, ty)?;
+        let flag = if matches!(ty, Type::Result(..)) { "ok" } else { "some" };
+        let inner = match ty {
+            Type::Result(ok, _) => ok.as_ref().clone(),
+            Type::Option(inner) => inner.as_ref().clone(),
+            _ => unreachable!("wrapper_method yalnızca Result/Option ile çağrılır"),
+        };
+        let argument = |index: usize| args.get(index).map(|(_, e)| e);
+        match method {
+            "is_ok" | "is_some" => Ok(format!("{}.{}", value, flag)),
+            "is_err" | "is_none" => Ok(format!("(!{}.{})", value, flag)),
+            "unwrap" => {
+                let helper = self.require_unwrap(ty, span)?;
+                Ok(format!("{}({})", helper, value))
+            }
+            "unwrap_or" => {
+                let Some(default) = argument(0) else {
+                    return error("Hata: unwrap_or() bir varsayılan değer bekler.", span);
+                };
+                let default = self.expr_as(default, &inner)?;
+                Ok(format!("({}.{} ? {}.value : {})", value, flag, value, default))
+            }
+            "expect" => {
+                let message = match argument(0) {
+                    Some(message) => self.expr_as(message, &Type::Str(None))?,
+                    None => c_string("expect() başarısız oldu."),
+                };
+                let helper = self.require_expect(ty, span)?;
+                Ok(format!("{}({}, {})", helper, value, unparen(&message)))
+            }
+            _ => error(format!("Hata: Result/Option değerinin '{}' isminde bir metodu yok.", method), span),
+        }
+    }
+
+    fn require_unwrap(&mut self, ty: &Type, span: Span) -> Gen<String> {
+        let name = format!("nim_{}_unwrap", self.mangle(ty, span)?);
+        if !self.defined.insert(name.clone()) {
+            return Ok(name);
+        }
+        let c_type = self.c_type(ty, span)?;
+        let (value_type, failure) = match ty {
+            Type::Result(ok, err) => {
+                let mut pieces = vec![Piece::Text("Hata: unwrap() bir Err değerine uygulandı".to_string())];
+                if !matches!(self.resolve(err), Type::Void | Type::Never) {
+                    pieces.push(Piece::Text(": ".to_string()));
+                    self.format_value(err, "r.error".to_string(), &mut pieces, span)?;
+                }
+                pieces.push(Piece::Text("\n".to_string()));
+                (ok.as_ref().clone(), self.printf_call("fprintf(stderr, ", pieces))
+            }
+            Type::Option(inner) => (inner.as_ref().clone(), "fputs(\"Hata: unwrap() bir None değerine uygulandı.\\n\", stderr)".to_string()),
+            _ => unreachable!("require_unwrap yalnızca Result/Option ile çağrılır"),
+        };
+        let flag = if matches!(ty, Type::Result(..)) { "ok" } else { "some" };
+        let value_c_type = self.c_type(&value_type, span)?;
+        let give = if value_c_type == "void" { "return;".to_string() } else { "return r.value;".to_string() };
+        self.helpers.push(format!(
+            "static {0} {1}({2} r) {{\n    if (!r.{3}) {{\n        fflush(stdout);\n        {4};\n        exit(1);\n    }}\n    {5}\n}}",
+            value_c_type, name, c_type, flag, failure, give
+        ));
+        Ok(name)
+    }
+
+    fn require_expect(&mut self, ty: &Type, span: Span) -> Gen<String> {
+        let name = format!("nim_{}_expect", self.mangle(ty, span)?);
+        if !self.defined.insert(name.clone()) {
+            return Ok(name);
+        }
+        let c_type = self.c_type(ty, span)?;
+        let (value_type, flag) = match ty {
+            Type::Result(ok, _) => (ok.as_ref().clone(), "ok"),
+            Type::Option(inner) => (inner.as_ref().clone(), "some"),
+            _ => unreachable!("require_expect yalnızca Result/Option ile çağrılır"),
+        };
+        let value_c_type = self.c_type(&value_type, span)?;
+        let give = if value_c_type == "void" { "return;".to_string() } else { "return r.value;".to_string() };
+        self.helpers.push(format!(
+            "static {0} {1}({2} r, nim_str message) {{\n    if (!r.{3}) {{\n        fflush(stdout);\n        fprintf(stderr, \"%s\\n\", message);\n        exit(1);\n    }}\n    {4}\n}}",
+            value_c_type, name, c_type, flag, give
+        ));
+        Ok(name)
+    }
+
+    fn builtin(&mut self, expr: &'a Expr, name: &str, args: &'a [(Option<String>, Expr)], expected: Option<&Type>) -> Gen<String> {
+        let span = expr.span;
+        // Argüman adıyla veya sırasıyla verilebilir (örn: println("x", style: "info")).
+        let argument = |index: usize, names: &[&str]| {
+            args.iter()
+                .find(|(n, _)| n.as_deref().is_some_and(|n| names.contains(&n)))
+                .or_else(|| args.iter().filter(|(n, _)| n.is_none()).nth(index))
+                .map(|(_, e)| e)
+        };
+        let first = argument(0, &["message", "value", "val", "s", "arr", "code", "prompt"]);
+        let required = |value: Option<&'a Expr>| match value {
+            Some(value) => Ok(value),
+            None => error(format!("Hata: '{}' fonksiyonu bir argüman bekler.", name), span),
+        };
+        match name {
+            "print" | "println" | "echo" | "eprint" => self.print_call(name, first, argument(1, &["style"]), span),
+            "input" => self.input(first),
+            "strlen" => {
+                let value = required(first)?;
+                let text = self.as_text(value)?;
+                Ok(format!("((nim_int)strlen({}))", unparen(&text)))
+            }
+            "arrlen" => {
+                let value = required(first)?;
+                match self.value_type(value, None)? {
+                    Type::Array(_, Some(size)) => Ok(size.to_string()),
+                    ty @ Type::Array(_, None) => Ok(format!("{}.len", self.stable(value, &ty)?)),
+                    other => error(format!("Hata: 'arrlen' bir dizi bekler, bulundu: {}.", other), span),
+                }
+            }
+            "exit" => {
+                let code = match first {
+                    Some(code) => self.expr(code, None)?,
+                    None => "0".to_string(),
+                };
+                Ok(format!("exit((int){})", paren(&code)))
+            }
+            "panic" => {
+                self.uses_panic = true;
+                let message = self.as_text(required(first)?)?;
+                Ok(format!("nim_panic({})", unparen(&message)))
+            }
+            "args" => {
+                self.uses_args = true;
+                self.require_args(span)
+            }
+            "arg_count" => {
+                self.uses_args = true;
+                Ok("((nim_int)nim_argc)".to_string())
+            }
+            "_int" => {
+                let value = required(first)?;
+                let ty = self.value_type(value, None)?;
+                let value = self.expr(value, Some(&ty))?;
+                match ty {
+                    Type::Str(_) => Ok(format!("((nim_int)strtoll({}, NULL, 10))", unparen(&value))),
+                    _ => Ok(format!("((nim_int){})", paren(&value))),
+                }
+            }
+            "_float" => {
+                let value = required(first)?;
+                let ty = self.value_type(value, None)?;
+                let value = self.expr(value, Some(&ty))?;
+                match ty {
+                    Type::Str(_) => Ok(format!("strtod({}, NULL)", unparen(&value))),
+                    _ => Ok(format!("((double){})", paren(&value))),
+                }
+            }
+            "_str" => self.as_text(required(first)?),
+            "clone" => {
+                let value = required(first)?;
+                let ty = match expected {
+                    Some(ty) => ty.clone(),
+                    None => self.value_type(value, None)?,
+                };
+                self.expr_as(value, &ty)
+            }
+            "Ok" | "Err" | "Some" => self.wrapper(expr, expected, name, first),
+            _ => error(format!("C arka ucu '{}' fonksiyonunu henüz desteklemiyor.", name), span),
+        }
+    }
+
+    // args(): program adı dahil komut satırı argümanları (yorumlayıcıyla aynı) str[] olarak.
+    fn require_args(&mut self, span: Span) -> Gen<String> {
+        let ty = Type::Array(Box::new(Type::Str(None)), None);
+        let c_type = self.c_type(&ty, span)?;
+        if self.defined.insert("nim_args".to_string()) {
+            self.helpers.push(format!(
+                "static {0} nim_args(void) {{\n    {0} result = {{ (nim_str *)nim_argv, nim_argc }};\n    return result;\n}}",
+                c_type
+            ));
+        }
+        Ok("nim_args()".to_string())
+    }
+
+    // Değerin metin gösterimi (string ise kendisi, değilse yazdırma biçimiyle biçimlendirilir).
+    fn as_text(&mut self, expr: &'a Expr) -> Gen<String> {
+        if let Type::Str(_) = self.value_type(expr, None)? {
+            if !matches!(expr.kind, ExprKind::InterpolatedString(_)) {
+                return self.expr(expr, None);
+            }
+        }
+        let mut pieces = Vec::new();
+        self.pieces(expr, &mut pieces)?;
+        Ok(self.format_call(pieces))
+    }
+
+    fn print_call(&mut self, name: &str, value: Option<&'a Expr>, style: Option<&'a Expr>, span: Span) -> Gen<String> {
+        let style = match (name, style.map(|s| &s.kind)) {
+            ("eprint", _) => "error".to_string(),
+            (_, Some(ExprKind::Literal(LiteralValue::Str(style)))) => style.clone(),
+            (_, Some(_)) => return error("C arka ucu print stilini yalnızca sabit bir metin olarak destekler.", span),
+            _ => String::new(),
+        };
+        let (prefix, suffix) = print_style(&self.checker.styles, &style);
+        let mut pieces = vec![Piece::Text(prefix)];
+        if let Some(value) = value {
+            self.pieces(value, &mut pieces)?;
+        }
+        pieces.push(Piece::Text(suffix));
+        if matches!(name, "println" | "eprint") {
+            pieces.push(Piece::Text("\n".to_string()));
+        }
+        Ok(self.printf_call("printf(", pieces))
+    }
+
+    // Yazdırılacak ifadeyi printf parçalarına ayırır; interpolasyonlu metnin düz kısımları biçim dizgisine girer.
+    fn pieces(&mut self, expr: &'a Expr, out: &mut Vec<Piece>) -> Gen<()> {
+        match &expr.kind {
+            ExprKind::Literal(LiteralValue::Str(text)) => out.push(Piece::Text(text.clone())),
+            ExprKind::InterpolatedString(parts) => {
+                for part in parts {
+                    if let Some((object, digits)) = part.numeric_member() {
+                        let ty = self.value_type(object, None)?;
+                        if is_float_like(&self.resolve(&ty)) {
+                            let value = self.expr(object, Some(&ty))?;
+                            out.push(Piece::Precision(digits, value));
+                            continue;
+                        }
+                    }
+                    self.pieces(part, out)?;
+                }
+            }
+            _ => {
+                let ty = self.value_type(expr, None)?;
+                let value = self.expr(expr, Some(&ty))?;
+                // Parçalar soldan sağa değerlendirilsin (ve birleşik değerler bir kez) diye geçici değişkene alınır.
+                let simple = self.is_path(expr) || (self.is_pure(expr) && !is_compound(&ty));
+                let value = if simple || matches!(ty, Type::Void | Type::Never) { value } else { self.hoist(&ty, value, expr.span)? };
+                self.format_value(&ty, value, out, expr.span)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn format_value(&mut self, ty: &Type, value: String, out: &mut Vec<Piece>, span: Span) -> Gen<()> {
+        let ty = self.resolve(ty);
+        match &ty {
+            Type::I128 | Type::U128 => {
+                let helper = self.require_show(&ty, span)?;
+                out.push(Piece::Arg("%s", format!("{}({})", helper, unparen(&value))));
+            }
+            t if t.is_unsigned_integer() => out.push(Piece::Arg("%llu", format!("(unsigned long long){}", paren(&value)))),
+            t if is_int_like(t) => out.push(Piece::Arg("%lld", format!("(long long){}", paren(&value)))),
+            t if is_float_like(t) => out.push(Piece::Arg("%f", value)),
+            Type::Bool => out.push(Piece::Arg("%d", format!("(int){}", paren(&value)))),
+            Type::Char => out.push(Piece::Arg("%c", value)),
+            Type::Str(_) => out.push(Piece::Arg("%s", unparen(&value).to_string())),
+            Type::Null => out.push(Piece::Text("null".to_string())),
+            Type::Ptr(_) => out.push(Piece::Arg("%p", format!("(void *){}", paren(&value)))),
+            Type::Void | Type::Never => return error("Hata: Değer döndürmeyen bir ifade yazdırılamaz.", span),
+            Type::Custom(name) if self.structs.contains_key(name.as_str()) => {
+                out.push(Piece::Text(format!("{} {{ ", name)));
+                for (i, (field, field_type)) in self.structs[name.as_str()].iter().enumerate() {
+                    if i > 0 {
+                        out.push(Piece::Text(", ".to_string()));
+                    }
+                    out.push(Piece::Text(format!("{}: ", field)));
+                    self.format_value(field_type, format!("{}.{}", value, self.c_ident(field)), out, span)?;
+                }
+                out.push(Piece::Text(" }".to_string()));
+            }
+            Type::Tuple(types) => {
+                out.push(Piece::Text("(".to_string()));
+                for (i, item_type) in types.iter().enumerate() {
+                    if i > 0 {
+                        out.push(Piece::Text(", ".to_string()));
+                    }
+                    self.format_value(item_type, format!("{}.f{}", value, i), out, span)?;
+                }
+                out.push(Piece::Text(")".to_string()));
+            }
+            Type::Array(inner, Some(size)) => {
+                out.push(Piece::Text("[".to_string()));
+                for i in 0..*size {
+                    if i > 0 {
+                        out.push(Piece::Text(", ".to_string()));
+                    }
+                    self.format_value(inner, format!("{}.items[{}]", value, i), out, span)?;
+                }
+                out.push(Piece::Text("]".to_string()));
+            }
+            Type::Array(..) | Type::Result(..) | Type::Option(_) => {
+                let helper = self.require_show(&ty, span)?;
+                out.push(Piece::Arg("%s", format!("{}({})", helper, unparen(&value))));
+            }
+            other => return error(format!("C arka ucu '{}' tipindeki değerleri yazdıramaz.", other), span),
+        }
+        Ok(())
+    }
+
+    // Çalışma anında biçimlendirilmesi gereken değerler (dinamik dizi, Result, Option, i128/u128) için metin üreten yardımcı.
+    fn require_show(&mut self, ty: &Type, span: Span) -> Gen<String> {
+        let name = format!("nim_show_{}", self.mangle(ty, span)?);
+        if !self.defined.insert(name.clone()) {
+            return Ok(name);
+        }
+        self.uses_format = true;
+        let c_type = self.c_type(ty, span)?;
+        let mut body = Vec::new();
+        match ty {
+            Type::Array(inner, None) => {
+                let mut item = Vec::new();
+                self.format_value(inner, "v.items[i]".to_string(), &mut item, span)?;
+                let item = self.format_call(item);
+                body.push("nim_str text = \"[\";".to_string());
+                body.push("for (nim_int i = 0; i < v.len; i++) {".to_string());
+                body.push(format!("    text = nim_format(i == 0 ? \"%s%s\" : \"%s, %s\", text, {});", unparen(&item)));
+                body.push("}".to_string());
+                body.push("return nim_format(\"%s]\", text);".to_string());
+            }
+            Type::Result(ok, err) => {
+                let ok_text = self.wrapped_text("Ok", ok, span)?;
+                let err_text = self.wrapped_text_field("Err", err, "v.error", span)?;
+                body.push(format!("return v.ok ? {} : {};", ok_text, err_text));
+            }
+            Type::Option(inner) => {
+                let some = self.wrapped_text("Some", inner, span)?;
+                body.push(format!("return v.some ? {} : \"None\";", some));
+            }
+            // YENİ: printf 128 bitlik tamsayıları bilmediği için basamaklar sondan başa doğru üretilir.
+            Type::I128 | Type::U128 => {
+                let signed = *ty == Type::I128;
+                body.push("char digits[41];".to_string());
+                body.push("int at = 40;".to_string());
+                body.push("digits[at] = '\\0';".to_string());
+                body.push(if signed { "unsigned __int128 rest = v < 0 ? -(unsigned __int128)v : (unsigned __int128)v;" } else { "unsigned __int128 rest = v;" }.to_string());
+                body.push("do {".to_string());
+                body.push("    digits[--at] = (char)('0' + (int)(rest % 10));".to_string());
+                body.push("    rest /= 10;".to_string());
+                body.push("} while (rest != 0);".to_string());
+                if signed {
+                    body.push("if (v < 0) digits[--at] = '-';".to_string());
+                }
+                body.push("return nim_format(\"%s\", digits + at);".to_string());
+            }
+            _ => unreachable!("require_show yalnızca dinamik dizi, Result, Option ve 128 bitlik tamsayılarla çağrılır"),
+        }
+        let body: Vec<String> = body.into_iter().map(|line| format!("    {}", line)).collect();
+        self.helpers.push(format!("static nim_str {}({} v) {{\n{}\n}}", name, c_type, body.join("\n")));
+        Ok(name)
+    }
+
+    fn wrapped_text(&mut self, kind: &str, ty: &Type, span: Span) -> Gen<String> {
+        self.wrapped_text_field(kind, ty, "v.value", span)
+    }
+
+    fn wrapped_text_field(&mut self, kind: &str, ty: &Type, field: &str, span: Span) -> Gen<String> {
+        let mut pieces = vec![Piece::Text(format!("{}(", kind))];
+        if !matches!(self.resolve(ty), Type::Void | Type::Never) {
+            self.format_value(ty, field.to_string(), &mut pieces, span)?;
+        }
+        pieces.push(Piece::Text(")".to_string()));
+        Ok(self.format_call(pieces))
+    }
+
+    // Parçalardan printf biçim dizgisi (yüzde işaretleri kaçışlı) ve argüman listesi üretir.
+    fn render(pieces: Vec<Piece>) -> (String, Vec<String>) {
+        let mut format = String::new();
+        let mut args = Vec::new();
+        for piece in pieces {
+            match piece {
+                Piece::Text(text) => format.push_str(&text.replace('%', "%%")),
+                Piece::Arg(spec, value) => {
+                    format.push_str(spec);
+                    args.push(value);
+                }
+                Piece::Precision(digits, value) => {
+                    format.push_str(&format!("%.{}f", digits));
+                    args.push(value);
+                }
+            }
+        }
+        (format, args)
+    }
+
+    fn printf_call(&mut self, call: &str, pieces: Vec<Piece>) -> String {
+        let (format, args) = Self::render(pieces);
+        if format.is_empty() {
+            return "((void)0)".to_string();
+        }
+        let mut parts = vec![c_string(&format)];
+        parts.extend(args);
+        format!("{}{})", call, parts.join(", "))
+    }
+
+    // Parçalardan yeni bir metin değeri üretir: argüman yoksa düz bir C dizgisi, varsa nim_format çağrısı.
+    fn format_call(&mut self, pieces: Vec<Piece>) -> String {
+        if pieces.iter().all(|p| matches!(p, Piece::Text(_))) {
+            let text: String = pieces.into_iter().map(|p| if let Piece::Text(t) = p { t } else { String::new() }).collect();
+            return c_string(&text);
+        }
+        self.uses_format = true;
+        self.printf_call("nim_format(", pieces)
+    }
+}
+
+fn is_int_like(ty: &Type) -> bool {
+    ty.is_integer() || matches!(ty, Type::Hex | Type::Bit | Type::Byte | Type::Enum(..))
+}
+
+fn is_float_like(ty: &Type) -> bool {
+    ty.is_float() || matches!(ty, Type::D32 | Type::D64 | Type::D128)
+}
+
+fn is_compound(ty: &Type) -> bool {
+    matches!(ty, Type::Custom(_) | Type::Tuple(_) | Type::Array(..) | Type::Result(..) | Type::Option(_))
+}
+
+// Tipin içinde belirlenmemiş (any) bir parça var mı (örn: Ok(5) ifadesinin tipi result<i64, any>).
+fn contains_any(ty: &Type) -> bool {
+    match ty {
+        Type::Any | Type::Unknown | Type::ArrayLiteral(_) => true,
+        Type::Array(inner, _) | Type::Option(inner) | Type::Ptr(inner) | Type::Future(inner) => contains_any(inner),
+        Type::Result(ok, err) => contains_any(ok) || contains_any(err),
+        Type::Tuple(types) => types.iter().any(contains_any),
+        _ => false,
+    }
+}
+
+// Enum değerleri için derleme zamanında hesaplanabilen tamsayı ifadeleri.
+fn const_int(expr: &Expr) -> Option<i64> {
+    match &expr.kind {
+        ExprKind::Literal(LiteralValue::Int(i) | LiteralValue::Hex(i)) => i64::try_from(*i).ok(),
+        ExprKind::Literal(LiteralValue::TypedInt(i, _)) => i64::try_from(*i).ok(),
+        ExprKind::Unary { op: UnOp::Neg, right } => const_int(right)?.checked_neg(),
+        ExprKind::Binary { left, op, right } => {
+            let (l, r) = (const_int(left)?, const_int(right)?);
+            match op {
+                BinOp::Add => l.checked_add(r),
+                BinOp::Sub => l.checked_sub(r),
+                BinOp::Mul => l.checked_mul(r),
+                BinOp::LShift => l.checked_shl(u32::try_from(r).ok()?),
+                BinOp::BitwiseOr => Some(l | r),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// Bloğun içinde (iç içe deyimler dahil) bir return deyimi var mı.
+fn contains_return(stmt: &Stmt) -> bool {
+    match &stmt.kind {
+        StmtKind::Return(_) => true,
+        StmtKind::Block(stmts) => stmts.iter().any(contains_return),
+        StmtKind::If { then_branch, else_branch, .. } => contains_return(then_branch) || else_branch.as_deref().is_some_and(contains_return),
+        StmtKind::While { body, .. } | StmtKind::Loop { body } | StmtKind::For { body, .. } => contains_return(body),
+        StmtKind::Unsafe(body) | StmtKind::FastExec(body) | StmtKind::Defer(body) => contains_return(body),
+        _ => false,
+    }
+}
+
+fn literal_value(literal: &LiteralValue, span: Span) -> Gen<String> {
+    Ok(match literal {
+        LiteralValue::Int(i) if *i > i64::MAX as u64 => format!("((nim_int){}ULL)", i),
+        LiteralValue::Int(i) => i.to_string(),
+        LiteralValue::Float(f) | LiteralValue::TypedFloat(f, _) if f.is_nan() => "NAN".to_string(),
+        LiteralValue::Float(f) | LiteralValue::TypedFloat(f, _) if f.is_infinite() => if *f > 0.0 { "INFINITY".to_string() } else { "(-INFINITY)".to_string() },
+        LiteralValue::Float(f) | LiteralValue::TypedFloat(f, _) if *f < 0.0 => format!("({:?})", f),
+        LiteralValue::Float(f) | LiteralValue::TypedFloat(f, _) => format!("{:?}", f),
+        LiteralValue::Hex(h) => format!("((nim_int)0x{:x}ULL)", h),
+        // YENİ: 128 bitlik sabitler C'de literal olarak yazılamadığı için iki 64 bitlik yarıdan kurulur.
+        LiteralValue::TypedInt(i, ty @ (Type::I128 | Type::U128)) => format!(
+            "(({})(((unsigned __int128)0x{:x}ULL << 64) | 0x{:x}ULL))",
+            if *ty == Type::I128 { "__int128" } else { "unsigned __int128" },
+            (*i >> 64) as u64,
+            *i as u64
+        ),
+        LiteralValue::TypedInt(i, ty) => match u64::try_from(*i) {
+            Ok(i) => format!("((nim_int)0x{:x}ULL)", i),
+            Err(_) => return error(format!("C arka ucu 64 bitten geniş '{}' sabitlerini ({}) desteklemiyor.", ty, i), span),
+        },
+        LiteralValue::Char(c) if c.is_ascii() => match c {
+            '\'' => "'\\''".to_string(),
+            '\\' => "'\\\\'".to_string(),
+            c if c.is_ascii_graphic() || *c == ' ' => format!("'{}'", c),
+            c => format!("'\\{:03o}'", *c as u32),
+        },
+        LiteralValue::Char(c) => return error(format!("C arka ucu ASCII dışındaki karakter değerlerini ('{}') desteklemiyor.", c), span),
+        LiteralValue::Str(text) => c_string(text),
+        LiteralValue::Bool(b) => b.to_string(),
+        LiteralValue::Null => "NULL".to_string(),
+    })
+}
+
+// Metni bir C dizgi literaline çevirir; kontrol karakterleri sekizli kaçışla yazılır, UTF-8 baytları olduğu gibi kalır.
+fn c_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    let mut previous = '\0';
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            // `??x` dizileri trigraph olarak yorumlanmasın.
+            '?' if previous == '?' => out.push_str("\\?"),
+            c if (c as u32) < 0x20 || c as u32 == 0x7f => out.push_str(&format!("\\{:03o}", c as u32)),
+            c => out.push(c),
+        }
+        previous = c;
+    }
+    out.push('"');
+    out
+}
+
+// Dıştaki parantez çifti tüm ifadeyi sarıyorsa kaldırır.
+fn unparen(text: &str) -> &str {
+    if wrapped(text) { &text[1..text.len() - 1] } else { text }
+}
+
+fn paren(text: &str) -> String {
+    if wrapped(text) { text.to_string() } else { format!("({})", text) }
+}
+
+fn wrapped(text: &str) -> bool {
+    if !text.starts_with('(') || !text.ends_with(')') {
+        return false;
+    }
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                c if c == q => quote = None,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => quote = Some(c),
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 && i != text.len() - 1 {
+                    return false;
+                }
+            }
+            _ => {}
+        }
+    }
+    depth == 0
+}
@@ -1,13 +1,40 @@
 // src/codegen.rs
 
-use crate::ast::{Decl, Stmt, Expr, LiteralValue, TargetPlatform, Type, BinOp, UnOp};
+use crate::ast::{layout_words, struct_layout, Decl, Stmt, StmtKind, Expr, ExprKind, LiteralValue, TargetArch, TargetPlatform, Type, BinOp, UnOp};
 use crate::type_checker::TypeChecker;
+use crate::diagnostic::{codegen_error_code, Diagnostic, Span, Stage};
+use crate::ir;
+use crate::ir::opt::OptLevel;
 
 // Platforma özel kod üretimi modülleri
 //mod windows;
 //mod linux;
 //mod macos;
 
+// Çağrı kuralları (Win64 / System V AMD64 / AAPCS64)
+mod abi;
+use abi::{ArgClass, ArgLoc, CallConv};
+
+// IR'den x86-64 üretimi
+mod ir_emit;
+mod regalloc;
+
+// IR'den AArch64 (Linux) üretimi
+mod aarch64;
+
+// IR'den WebAssembly (WASI) metni üretimi
+mod wasm;
+
+// Debug modunda DWARF hata ayıklama bilgisi
+mod debug;
+use debug::DebugInfo;
+
+// Tek bir fonksiyon çerçevesinin (yereller + geçiciler) üst sınırı. Windows'ta ana iş parçacığının
+// varsayılan stack'i 1 MiB'dir; daha büyük yerel alanlar bellek bozulması yerine derleme hatasıdır.
+const MAX_FRAME_SIZE: i32 = 512 * 1024;
+// Windows stack'i guard page ile büyür; bir sayfadan büyük çerçeveler sayfa sayfa yoklanmalıdır.
+const STACK_PAGE_SIZE: i32 = 4096;
+
 #[derive(Debug, Clone)]
 struct VariableLocation {
     stack_offset: i32,
@@ -18,6 +45,18 @@ struct VariableLocation {
     array_len: Option<usize>,
 }
 
+impl VariableLocation {
+    // Debug bilgisindeki tip: çerçeveye kopyalanan dizinin uzunluğu tipe işlenir, kopyalanmayan
+    // dizi (parametre, args()) yalnızca başlangıç adresi olduğundan boyutsuz kalır.
+    fn debug_type(&self) -> Type {
+        match (&self.ty, self.array_len) {
+            (Type::Array(inner, _), Some(len)) => Type::Array(inner.clone(), Some(len)),
+            (Type::Array(inner, _), None) => Type::Array(inner.clone(), None),
+            (ty, _) => ty.clone(),
+        }
+    }
+}
+
 // Veri segmentindeki farklı öğeleri temsil etmek için.
 #[derive(Debug, Clone)]
 enum DataItem {
@@ -29,6 +68,8 @@ pub struct Codegen<'a, 'b> {
     pub program: &'a [Decl], // Reference to the whole program AST
     pub type_checker: &'b mut TypeChecker<'a>, // Reference to the TypeChecker (mutable)
     pub target_platform: TargetPlatform,
+    pub arch: TargetArch, // Üretilecek kodun mimarisi (x86-64 veya AArch64)
+    pub opt_level: OptLevel, // IR'ye indirilen fonksiyonlara uygulanacak optimizasyonlar
     pub current_function_name: String, // Hangi fonksiyonun kodunu ürettiğimizi takip etmek için
     // string_literals yerine data_items kullanıyoruz.
     data_items: Vec<DataItem>,
@@ -37,6 +78,20 @@ pub struct Codegen<'a, 'b> {
     variable_locations: std::collections::HashMap<String, VariableLocation>, // Değişkenlerin konumları
     stack_pointer: i32, // Mevcut stack offset'i
     loop_labels: Vec<(String, String)>, // (start_label, end_label)
+    max_stack_pointer: i32, // Fonksiyon içinde ulaşılan en derin stack offset'i
+    temp_stack_depth: usize, // İfade değerlendirirken push/sub ile RSP'den düşülen geçici byte sayısı
+    error_span: Option<Span>, // Son hatanın oluştuğu en içteki düğümün konumu
+    current_decl_span: Span, // Kodu üretilen fonksiyonun konumu (hata konumu bulunamazsa)
+    debug_info: Option<DebugInfo>, // Debug modunda satır/CFI/değişken bilgisi (yalnızca Linux)
+    pub ast_fallbacks: Vec<ir::Skipped>, // IR'ye indirilemeyip AST'den üretilen fonksiyonlar (nedenleriyle)
+}
+
+// Çağrı için değerlendirilmiş bir argüman.
+// Skaler argümanlarda geçici slot değerin kendisini, struct'larda ise adresini tutar.
+struct EvaluatedArg {
+    offset: i32,
+    classes: Vec<ArgClass>,
+    is_address: bool,
 }
 
 
@@ -45,21 +100,87 @@ impl<'a, 'b> Codegen<'a, 'b> {
         program: &'a [Decl],
         type_checker: &'b mut TypeChecker<'a>, // Changed to &mut
         target_platform: TargetPlatform,
+        opt_level: OptLevel,
     ) -> Self {
         Self {
             program,
             type_checker,
             target_platform,
+            arch: TargetArch::X86_64,
+            opt_level,
             current_function_name: String::new(),
             data_items: Vec::new(),
             label_counter: 0,
             variable_locations: std::collections::HashMap::new(),
             stack_pointer: 0,
             loop_labels: Vec::new(),
+            max_stack_pointer: 0,
+            temp_stack_depth: 0,
+            error_span: None,
+            current_decl_span: Span::dummy(),
+            debug_info: None,
+            ast_fallbacks: Vec::new(),
         }
     }
 
-    pub fn generate(&mut self) -> Result<String, String> {
+    // Hedef mimariyi seçer (varsayılan x86-64). AArch64 yalnızca Linux, Wasm32 yalnızca WASI hedefinde desteklenir.
+    pub fn with_arch(mut self, arch: TargetArch) -> Self {
+        self.arch = arch;
+        self
+    }
+
+    // DWARF hata ayıklama bilgisi üretimini açar. `files`, FileId sırasıyla kaynak dosya adlarıdır.
+    // Debug bilgisi yalnızca x86-64 ELF (Linux) hedefinde üretilir; diğer hedeflerde çağrının etkisi yoktur.
+    pub fn with_debug_info(mut self, files: Vec<String>, comp_dir: String) -> Self {
+        if self.target_platform == TargetPlatform::Linux && self.arch == TargetArch::X86_64 {
+            let mut debug_info = DebugInfo::new(files, comp_dir);
+            // Somut struct'ların alan ofsetleri çerçevedeki kelime düzenine göre hesaplanır.
+            for decl in self.program {
+                let Decl::Struct { name, type_params, fields, .. } = decl else { continue };
+                if !type_params.is_empty() {
+                    continue;
+                }
+                let mut offset = 0;
+                let members = fields
+                    .iter()
+                    .map(|(f_name, f_ty)| {
+                        let member = (f_name.clone(), f_ty.clone(), offset);
+                        offset += layout_words(self.program, f_ty) * 8;
+                        member
+                    })
+                    .collect();
+                debug_info.add_struct(name, members, offset.max(8));
+            }
+            self.debug_info = Some(debug_info);
+        }
+        self
+    }
+
+    pub fn generate(&mut self) -> Result<String, Diagnostic> {
+        self.error_span = None;
+        self.generate_program().map_err(|message| {
+            let span = self.error_span.take().unwrap_or(self.current_decl_span);
+            let code = codegen_error_code(&message);
+            Diagnostic::error(message).with_stage(Stage::Codegen).with_code(code).with_fallback_span(span)
+        })
+    }
+
+    // Alt düğümlerden gelen hatanın konumunu kaydeder (TypeChecker::record_error_span ile aynı kural).
+    fn record_error_span(&mut self, failed: bool, span: Span) {
+        if !failed {
+            self.error_span = None;
+        } else if self.error_span.is_none() && !span.is_dummy() {
+            self.error_span = Some(span);
+        }
+    }
+
+    fn generate_program(&mut self) -> Result<String, String> {
+        if self.arch == TargetArch::Aarch64 {
+            return self.generate_aarch64_program();
+        }
+        if self.arch == TargetArch::Wasm32 {
+            return self.generate_wasm_program();
+        }
         let mut full_asm = String::new();
 
         // 1. GAS (GNU Assembler) için Intel sözdizimi ve prefix ayarları
@@ -72,22 +193,28 @@ impl<'a, 'b> Codegen<'a, 'b> {
         full_asm.push_str(&self.generate_data_segment());
         full_asm.push_str(&text_segment);
 
+        if let Some(debug_info) = &self.debug_info {
+            full_asm.push_str(&debug_info.sections());
+        }
+
+        // Linux: yığının çalıştırılabilir olmadığını linker'a bildir
+        if self.target_platform == TargetPlatform::Linux {
+            full_asm.push_str("\n.section .note.GNU-stack,\"\",@progbits\n");
+        }
+
         Ok(full_asm)
     }
 
     fn generate_data_segment(&mut self) -> String {
         let mut asm = String::new();
         asm.push_str(".section .data\n");
+        asm.push_str("_nim_argc: .quad 0\n");
+        asm.push_str("_nim_argv: .quad 0\n");
         for (i, item) in self.data_items.iter().enumerate() {
             match item {
                 DataItem::String(s) => {
                     // GAS için .asciz kullanımı (null-terminated string)
-                    let escaped = s.replace("\\", "\\\\")
-                                    .replace("\"", "\\\"")
-                                    .replace("\n", "\\n")
-                                    .replace("\r", "\\r")
-                                    .replace("\x1b", "\\033"); // GAS için octal escape
-                    asm.push_str(&format!("str_{}: .asciz \"{}\"\n", i, escaped));
+                    asm.push_str(&format!("str_{}: .asciz \"{}\"\n", i, asm_string_literal(s)));
                 }
                 DataItem::Float64(f) => {
                     // GAS için .double (8 byte float)
@@ -95,7 +222,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 }
             }
         }
-        asm.push_str("\n");
+        asm.push('\n');
         asm
     }
 
@@ -118,101 +245,302 @@ impl<'a, 'b> Codegen<'a, 'b> {
             }
             _ => {}
         }
+        if let Some(debug_info) = &self.debug_info {
+            asm.push_str(".Ltext0:\n");
+            asm.push_str(&debug_info.file_directives());
+            asm.push('\n');
+        }
 
-        // Ana program AST'sini gez ve tüm fonksiyonları üret
+        // Ana program AST'sini gez ve tüm fonksiyonları üret. Program önce bütün olarak IR'ye
+        // indirilip optimize edilir (inline için tüm fonksiyonlar gerekir); indirilemeyen
+        // yapılar içeren fonksiyonların kodu doğrudan AST'den üretilir.
+        let mut module = ir::lower::lower_program(self.program, self.type_checker, self.target_platform);
+        ir::opt::optimize(&mut module, self.opt_level);
+        // Modüldeki fonksiyonlar programdaki bildirim sırasını izler.
+        let mut lowered = module.functions.into_iter();
         for decl in self.program.iter() {
-            if let Decl::Function { name, params, body, .. } = decl {
+            if let Decl::Function { name, params, return_type, body, .. } = decl {
+                self.current_decl_span = decl.span();
                 self.current_function_name = name.clone();
                 self.stack_pointer = 0;
+                self.max_stack_pointer = 0;
+                self.temp_stack_depth = 0;
                 self.variable_locations.clear();
 
                 let label = if name == "main" { self.get_entry_point_label() } else { name.clone() };
-                asm.push_str(&format!("{}:\n", label));
-                
-                // Fonksiyon başlangıcı (prologue)
-                asm.push_str("    push rbp\n");
-                asm.push_str("    mov rbp, rsp\n");
-                asm.push_str("    sub rsp, 256 # Geçici sabit stack alanı\n\n");
-
-                if name == "main" && self.target_platform == TargetPlatform::Windows {
-                    asm.push_str("    mov ecx, 65001 # CP_UTF8\n    call SetConsoleOutputCP\n");
-                    
-                    asm.push_str("    # ANSI Renk Desteğini Etkinleştir (Virtual Terminal Processing)\n");
-                    asm.push_str("    mov rcx, -11 # STD_OUTPUT_HANDLE\n");
-                    asm.push_str("    call GetStdHandle\n");
-                    asm.push_str("    mov [rbp - 248], rax # Handle'ı sakla\n");
-                    asm.push_str("    lea rdx, [rbp - 240] # Mode için geçici alan\n");
-                    asm.push_str("    mov rcx, [rbp - 248]\n");
-                    asm.push_str("    call GetConsoleMode\n");
-                    asm.push_str("    mov eax, [rbp - 240]\n");
-                    asm.push_str("    or eax, 4 # ENABLE_VIRTUAL_TERMINAL_PROCESSING\n");
-                    asm.push_str("    mov rdx, rax\n");
-                    asm.push_str("    mov rcx, [rbp - 248]\n");
-                    asm.push_str("    call SetConsoleMode\n");
+                let lowered_function = lowered.next();
+                if let Some(debug_info) = &mut self.debug_info {
+                    debug_info.begin_function(name, &label, decl.span(), return_type);
                 }
+                let (body_asm, restore_asm) = match lowered_function {
+                    Some(Ok(function)) => self.generate_ir_function_body(&function, params)?,
+                    skipped => {
+                        self.ast_fallbacks.extend(skipped.and_then(Result::err));
+                        (self.generate_ast_function_body(name, params, body)?, String::new())
+                    }
+                };
+                asm.push_str(&self.finish_function(name, &label, &body_asm, &restore_asm)?);
+            }
+        }
 
-                // Kod üretimi sırasında tip kontrolcü kapsamını da yönetiyoruz.
-                self.type_checker.push_scope();
+        // NOT: Artık harici _print.obj kullanılıyor.
+        
+        // Yardımcı kütüphaneleri (atoi, itoa vb.) ekle
+        asm.push_str(&self.generate_builtins_library());
+        if self.debug_info.is_some() {
+            asm.push_str(".Letext0:\n");
+        }
 
-                // Nimble'ın özel main yapısı (argc, argv)
-                if name == "main" {
-                    // RCX = argc, RDX = argv (Windows x64 ABI)
-                    self.stack_pointer += 16;
-                    let argc_offset = self.stack_pointer - 8;
-                    let argv_offset = self.stack_pointer;
-                    
-                    self.variable_locations.insert("argc".to_string(), VariableLocation { stack_offset: argc_offset, ty: Type::I32, array_len: None });
-                    self.variable_locations.insert("argv".to_string(), VariableLocation { stack_offset: argv_offset, ty: Type::Ptr(Box::new(Type::Str(None))), array_len: None });
-                    
-                    self.type_checker.define_variable("argc".to_string(), crate::type_checker::VarInfo { ty: Type::I32, is_const: false, _is_mutable: false })?;
-                    self.type_checker.define_variable("argv".to_string(), crate::type_checker::VarInfo { ty: Type::Ptr(Box::new(Type::Str(None))), is_const: false, _is_mutable: false })?;
+        Ok(asm)
+    }
 
-                    asm.push_str(&format!("    mov [rbp - {}], rcx # Store argc\n", argc_offset));
-                    asm.push_str(&format!("    mov [rbp - {}], rdx # Store argv\n", argv_offset));
-                } else {
-                    // Normal Fonksiyon Parametreleri
-                    let arg_regs = ["rcx", "rdx", "r8", "r9"];
-                    for (i, (p_name, p_ty, _)) in params.iter().enumerate() {
-                        self.stack_pointer += 8;
-                        let offset = self.stack_pointer;
-                        self.variable_locations.insert(p_name.clone(), VariableLocation { stack_offset: offset, ty: p_ty.clone(), array_len: None });
-                        
-                        self.type_checker.define_variable(p_name.clone(), crate::type_checker::VarInfo { ty: p_ty.clone(), is_const: false, _is_mutable: true })?;
+    // Fonksiyon gövdesini doğrudan AST'den üretir (IR'ye indirilemeyen fonksiyonlar için).
+    fn generate_ast_function_body(&mut self, name: &str, params: &[(String, Type, Option<Expr>)], body: &Stmt) -> Result<String, String> {
+        let mut body_asm = String::new();
 
-                        if i < 4 {
-                            asm.push_str(&format!("    mov [rbp - {}], {} # Store parameter '{}'\n", offset, arg_regs[i], p_name));
-                        } else {
-                            // 5. ve sonrası stack: [rbp + 16], [rbp + 24]...
-                            let param_offset = 16 + (i - 4) * 8;
-                            asm.push_str(&format!("    mov rax, [rbp + {}]\n", param_offset));
-                            asm.push_str(&format!("    mov [rbp - {}], rax # Store stack parameter '{}'\n", offset, p_name));
-                        }
+        // Kod üretimi sırasında tip kontrolcü kapsamını da yönetiyoruz.
+        self.type_checker.push_scope();
+
+        // Nimble'ın özel main yapısı (argc, argv)
+        if name == "main" {
+            let (entry_asm, argc_offset, argv_offset) = self.generate_main_entry();
+            body_asm.push_str(&entry_asm);
+
+            self.bind_parameter("argc", VariableLocation { stack_offset: argc_offset, ty: Type::I32, array_len: None });
+            self.bind_parameter("argv", VariableLocation { stack_offset: argv_offset, ty: Type::Ptr(Box::new(Type::Str(None))), array_len: None });
+
+            self.type_checker.define_variable("argc".to_string(), crate::type_checker::VarInfo { ty: Type::I32, is_const: false, _is_mutable: false, span: Span::dummy() })?;
+            self.type_checker.define_variable("argv".to_string(), crate::type_checker::VarInfo { ty: Type::Ptr(Box::new(Type::Str(None))), is_const: false, _is_mutable: false, span: Span::dummy() })?;
+        } else {
+            if let Some((p_name, _, _)) = params.iter().find(|(_, p_ty, _)| matches!(p_ty, Type::I128 | Type::U128)) {
+                return Err(format!("128 bitlik '{}' parametresi henüz desteklenmiyor.", p_name));
+            }
+            let (param_asm, offsets) = self.generate_param_stores(params);
+            body_asm.push_str(&param_asm);
+            for ((p_name, p_ty, _), offset) in params.iter().zip(offsets) {
+                self.bind_parameter(p_name, VariableLocation { stack_offset: offset, ty: p_ty.clone(), array_len: None });
+                self.type_checker.define_variable(p_name.clone(), crate::type_checker::VarInfo { ty: p_ty.clone(), is_const: false, _is_mutable: true, span: Span::dummy() })?;
+            }
+        }
+
+        if name == "main" && self.target_platform == TargetPlatform::Windows {
+            body_asm.push_str(&self.generate_console_setup());
+        }
+
+        body_asm.push_str(&self.generate_stmt(body)?);
+        self.type_checker.pop_scope()?;
+        Ok(body_asm)
+    }
+
+    // Yerel değişkenin stack yuvasını kaydeder; debug modunda DWARF değişken listesine de ekler.
+    fn bind_variable(&mut self, name: String, location: VariableLocation, span: Span) {
+        if let Some(debug_info) = &mut self.debug_info {
+            debug_info.add_variable(&name, &location.debug_type(), location.stack_offset, span, false);
+        }
+        self.variable_locations.insert(name, location);
+    }
+
+    fn bind_parameter(&mut self, name: &str, location: VariableLocation) {
+        if let Some(debug_info) = &mut self.debug_info {
+            debug_info.add_variable(name, &location.debug_type(), location.stack_offset, self.current_decl_span, true);
+        }
+        self.variable_locations.insert(name.to_string(), location);
+    }
+
+    // main girişi: argc/argv'yi çerçeveye ve args()/arg_count() için global kopyalarına yazar.
+    // Dönen değerler: (kod, argc ofseti, argv ofseti).
+    fn generate_main_entry(&mut self) -> (String, i32, i32) {
+        let conv = self.abi();
+        let mut code = String::new();
+        let argv_offset = self.alloc_stack(16);
+        let argc_offset = argv_offset - 8;
+
+        if self.target_platform == TargetPlatform::Linux {
+            // Linux _start bir fonksiyon gibi çağrılmaz: [rsp] = argc, ardından argv[0..argc], NULL.
+            // `push rbp` sonrası argc [rbp + 8]'de, argv dizisi rbp + 16'dan başlar.
+            code.push_str("    mov rax, [rbp + 8]\n");
+            code.push_str(&format!("    mov [rbp - {}], rax # Store argc\n", argc_offset));
+            code.push_str("    lea rax, [rbp + 16]\n");
+            code.push_str(&format!("    mov [rbp - {}], rax # Store argv\n", argv_offset));
+        } else {
+            // argc, argv ilk iki tamsayı argüman register'ında gelir (Win64: RCX, RDX / SysV: RDI, RSI)
+            code.push_str(&format!("    mov [rbp - {}], {} # Store argc\n", argc_offset, conv.int_arg_regs()[0]));
+            code.push_str(&format!("    mov [rbp - {}], {} # Store argv\n", argv_offset, conv.int_arg_regs()[1]));
+        }
+        // args() / arg_count() her fonksiyondan erişebilsin diye global kopyalar
+        code.push_str(&format!("    mov rax, [rbp - {}]\n    mov [_nim_argc], rax\n", argc_offset));
+        code.push_str(&format!("    mov rax, [rbp - {}]\n    mov [_nim_argv], rax\n", argv_offset));
+        (code, argc_offset, argv_offset)
+    }
+
+    // Parametreleri ABI'nin koyduğu yerden (register/stack) çerçevedeki alanlarına kopyalar.
+    // Her parametre için [rbp - ofset] ofsetini döndürür; struct'lar alan sayısı kadar yer kaplar.
+    fn generate_param_stores(&mut self, params: &[(String, Type, Option<Expr>)]) -> (String, Vec<i32>) {
+        let conv = self.abi();
+        let mut code = String::new();
+        let mut offsets = Vec::new();
+        let classes: Vec<Vec<ArgClass>> = params.iter().map(|(_, p_ty, _)| self.arg_classes(p_ty)).collect();
+        let layout = conv.layout_args(&classes);
+        for (((p_name, _, _), placement), p_classes) in params.iter().zip(&layout.args).zip(&classes) {
+            let size = (p_classes.len().max(1) * 8) as i32;
+            let offset = self.alloc_stack(size);
+            offsets.push(offset);
+
+            if placement.by_reference {
+                // Win64: büyük struct'ın kopyasının adresi geldi, yerel alana kopyala.
+                match placement.parts[0] {
+                    ArgLoc::IntReg(reg) => code.push_str(&format!("    mov r11, {}\n", reg)),
+                    ArgLoc::Stack(slot) => code.push_str(&format!("    mov r11, [rbp + {}]\n", conv.stack_arg_base() + slot * 8)),
+                    ArgLoc::SseReg(_) => unreachable!(),
+                }
+                for j in 0..p_classes.len() {
+                    code.push_str(&format!("    mov rax, [r11 + {}]\n", j * 8));
+                    code.push_str(&format!("    mov [rbp - {}], rax # Store parameter '{}'\n", offset - (j * 8) as i32, p_name));
+                }
+                continue;
+            }
+
+            for (j, part) in placement.parts.iter().enumerate() {
+                let dest = offset - (j * 8) as i32;
+                match part {
+                    ArgLoc::IntReg(reg) => {
+                        code.push_str(&format!("    mov [rbp - {}], {} # Store parameter '{}'\n", dest, reg, p_name));
+                    }
+                    ArgLoc::SseReg(reg) => {
+                        code.push_str(&format!("    movsd [rbp - {}], {} # Store float parameter '{}'\n", dest, reg, p_name));
+                    }
+                    ArgLoc::Stack(slot) => {
+                        // Stack argümanları çağıranın çerçevesinde: [rbp + 16 (+ gölge alan) + slot*8]
+                        code.push_str(&format!("    mov rax, [rbp + {}]\n", conv.stack_arg_base() + slot * 8));
+                        code.push_str(&format!("    mov [rbp - {}], rax # Store stack parameter '{}'\n", dest, p_name));
                     }
                 }
+            }
+        }
+        (code, offsets)
+    }
 
-                asm.push_str(&self.generate_stmt(body)?);
-                self.type_checker.pop_scope()?;
+    // Windows konsolunu UTF-8 ve ANSI renk kodları için hazırlar (main girişinde).
+    fn generate_console_setup(&mut self) -> String {
+        let mut code = String::new();
+        code.push_str("    mov ecx, 65001 # CP_UTF8\n    call SetConsoleOutputCP\n");
+
+        code.push_str("    # ANSI Renk Desteğini Etkinleştir (Virtual Terminal Processing)\n");
+        code.push_str("    mov rcx, -11 # STD_OUTPUT_HANDLE\n");
+        code.push_str("    call GetStdHandle\n");
+        let handle_offset = self.alloc_stack(16);
+        let mode_offset = handle_offset - 8;
+        code.push_str(&format!("    mov [rbp - {}], rax # Handle'ı sakla\n", handle_offset));
+        code.push_str(&format!("    lea rdx, [rbp - {}] # Mode için geçici alan\n", mode_offset));
+        code.push_str(&format!("    mov rcx, [rbp - {}]\n", handle_offset));
+        code.push_str("    call GetConsoleMode\n");
+        code.push_str(&format!("    mov eax, [rbp - {}]\n", mode_offset));
+        code.push_str("    or eax, 4 # ENABLE_VIRTUAL_TERMINAL_PROCESSING\n");
+        code.push_str("    mov rdx, rax\n");
+        code.push_str(&format!("    mov rcx, [rbp - {}]\n", handle_offset));
+        code.push_str("    call SetConsoleMode\n");
+        code
+    }
 
-                // Fonksiyonu sonlandır (epilogue)
-                if name == "main" {
-                    asm.push_str(&self.generate_exit_code());
-                } else {
-                    asm.push_str(&format!(".fn_exit_{}:\n", name)); // Return'lerin atlaması için
-                    asm.push_str("    add rsp, 256\n");
-                    asm.push_str("    pop rbp\n");
-                    asm.push_str("    ret\n");
-                }
+    // Üretilmiş gövdeyi prologue ve epilogue ile sarar. `restore_asm`, gövdenin sakladığı callee-saved
+    // register'ları dönüşten önce geri yükler.
+    fn finish_function(&self, name: &str, label: &str, body_asm: &str, restore_asm: &str) -> Result<String, String> {
+        let conv = self.abi();
+        let mut asm = format!("{}:\n", label);
+        // Debug modunda çerçeve bilgisi (CFI): hata ayıklayıcı bununla çağrı yığınını çözer.
+        let cfi = self.debug_info.is_some();
+        if let Some(debug_info) = &self.debug_info {
+            asm.push_str("    .cfi_startproc\n");
+            // Prologue fonksiyonun bildirim satırına aittir (hata ayıklayıcı prologue'u bununla atlar).
+            asm.push_str(&debug_info.loc(self.current_decl_span));
+            if name == "main" && self.target_platform == TargetPlatform::Linux {
+                // _start en dış çerçevedir; dönüş adresi yoktur.
+                asm.push_str("    .cfi_undefined rip\n");
             }
         }
 
-        // NOT: Artık harici _print.obj kullanılıyor.
-        
-        // Yardımcı kütüphaneleri (atoi, itoa vb.) ekle
-        asm.push_str(&self.generate_builtins_library());
+        // Çerçeve boyutu: gövde üretilirken ulaşılan en derin ofset (yereller, parametre kopyaları,
+        // argüman geçicileri). Stack argümanları çağrı anında push edilip temizlendiğinden ayrı bir
+        // giden argüman alanı gerekmez. 16'nın katına yuvarlanır; böylece prologue sonrası RSP
+        // hizalı kalır ve emit_call yalnızca geçici push'ları (temp_stack_depth) hesaba katar.
+        let frame_size = (self.max_stack_pointer + 15) & !15;
+        if frame_size > MAX_FRAME_SIZE {
+            return Err(format!(
+                "Hata: '{}' fonksiyonunun yerel değişken alanı ({} byte) izin verilen {} byte sınırını aşıyor. Büyük diziler için global veya heap bellek kullanın.",
+                name, frame_size, MAX_FRAME_SIZE
+            ));
+        }
 
+        // SysV: hiç çağrı yapmayan ve push kullanmayan küçük fonksiyonlar yerellerini
+        // red zone'da (RSP altındaki 128 byte) tutabilir; RSP'yi düşürmeye gerek yok.
+        let use_red_zone = name != "main"
+            && conv.red_zone() > 0
+            && self.max_stack_pointer as usize <= conv.red_zone()
+            && is_leaf_body(body_asm);
+
+        // Fonksiyon başlangıcı (prologue)
+        asm.push_str("    push rbp\n");
+        if cfi {
+            asm.push_str("    .cfi_def_cfa_offset 16\n    .cfi_offset rbp, -16\n");
+        }
+        asm.push_str("    mov rbp, rsp\n");
+        if cfi {
+            asm.push_str("    .cfi_def_cfa_register rbp\n");
+        }
+        if name == "main" && self.target_platform == TargetPlatform::Linux {
+            // _start'a call ile girilmez; RSP'yi çağrılar için 16 byte'a hizala.
+            asm.push_str("    and rsp, -16\n");
+        }
+        if use_red_zone {
+            asm.push_str("    # Yaprak fonksiyon: yereller red zone'da\n\n");
+        } else {
+            asm.push_str(&self.generate_frame_allocation(label, frame_size));
+        }
+        asm.push_str(body_asm);
+
+        // Fonksiyonu sonlandır (epilogue)
+        if name == "main" {
+            asm.push_str(&self.generate_exit_code(false));
+        } else {
+            asm.push_str(&format!(".fn_exit_{}:\n", name)); // Return'lerin atlaması için
+            asm.push_str(restore_asm);
+            if !use_red_zone && frame_size > 0 {
+                asm.push_str(&format!("    add rsp, {}\n", frame_size));
+            }
+            asm.push_str("    pop rbp\n");
+            if cfi {
+                asm.push_str("    .cfi_def_cfa rsp, 8\n");
+            }
+            asm.push_str("    ret\n");
+        }
+        if let Some(debug_info) = &self.debug_info {
+            asm.push_str(&format!("{}:\n    .cfi_endproc\n", debug_info.function_end_label()));
+        }
         Ok(asm)
     }
 
+    // Prologue'da `frame_size` byte'lık çerçeveyi ayırır. Windows'ta bir sayfadan büyük çerçeveler,
+    // guard page atlanmasın diye sayfa sayfa yoklanarak (__chkstk gibi) ayrılır.
+    fn generate_frame_allocation(&self, label: &str, frame_size: i32) -> String {
+        if frame_size == 0 {
+            return "\n".to_string();
+        }
+        if self.target_platform != TargetPlatform::Windows || frame_size <= STACK_PAGE_SIZE {
+            return format!("    sub rsp, {} # Çerçeve: yereller ve geçiciler\n\n", frame_size);
+        }
+        let mut code = format!("    mov r11, {} # Stack yoklaması: {} byte\n", frame_size / STACK_PAGE_SIZE, frame_size);
+        code.push_str(&format!(".fn_probe_{}:\n", label));
+        code.push_str(&format!("    sub rsp, {}\n", STACK_PAGE_SIZE));
+        code.push_str("    or qword ptr [rsp], 0\n");
+        code.push_str("    dec r11\n");
+        code.push_str(&format!("    jnz .fn_probe_{}\n", label));
+        if frame_size % STACK_PAGE_SIZE != 0 {
+            code.push_str(&format!("    sub rsp, {}\n", frame_size % STACK_PAGE_SIZE));
+        }
+        code.push('\n');
+        code
+    }
+
     // Platforma özel giriş noktası etiketini döndürür
     fn get_entry_point_label(&self) -> String {
         match self.target_platform {
@@ -223,26 +551,41 @@ impl<'a, 'b> Codegen<'a, 'b> {
         }
     }
 
-    // Programı sonlandıran platforma özel assembly kodu
-    fn generate_exit_code(&self) -> String {
+    // Programı sonlandıran platforma özel assembly kodu.
+    // `status_in_rax` true ise çıkış kodu RAX'tadır (main'den `return kod;`), değilse 0'dır.
+    fn generate_exit_code(&self, status_in_rax: bool) -> String {
+        let reg = match self.target_platform {
+            TargetPlatform::Windows => "ecx",
+            _ => "edi",
+        };
+        let mut code = if status_in_rax {
+            format!("    mov {}, eax      # çıkış kodu main'in dönüş değeri\n", reg)
+        } else {
+            format!("    xor {0}, {0}     # çıkış kodu 0\n", reg)
+        };
         match self.target_platform {
-            TargetPlatform::Windows => {
-                "    xor rcx, rcx     # ExitProcess için çıkış kodu 0\n    call ExitProcess\n".to_string()
-            }
-            TargetPlatform::Linux => {
-                "    mov rax, 60        # exit için syscall numarası\n    xor rdi, rdi       # çıkış kodu 0\n    syscall\n".to_string()
-            }
-            TargetPlatform::Macos => {
-                "    xor rdi, rdi     # exit için çıkış kodu 0\n    call _exit\n".to_string()
-            }
-            _ => "".to_string(),
+            TargetPlatform::Windows => code.push_str("    call ExitProcess\n"),
+            TargetPlatform::Linux => code.push_str("    mov eax, 231       # exit_group için syscall numarası\n    syscall\n"),
+            TargetPlatform::Macos => code.push_str("    call _exit\n"),
+            _ => return String::new(),
         }
+        code
     }
 
     // Deyimleri assembly koduna çevirir
     fn generate_stmt(&mut self, stmt: &Stmt) -> Result<String, String> {
-        match stmt {
-            Stmt::Block(stmts) => {
+        let result = self.generate_stmt_inner(stmt);
+        self.record_error_span(result.is_err(), stmt.span);
+        // Debug modunda deyimin kodu kaynak satırına bağlanır (bloklar kendi deyimlerine bırakır).
+        match &self.debug_info {
+            Some(debug_info) if !matches!(stmt.kind, StmtKind::Block(_)) => result.map(|code| debug_info.loc(stmt.span) + &code),
+            _ => result,
+        }
+    }
+
+    fn generate_stmt_inner(&mut self, stmt: &Stmt) -> Result<String, String> {
+        match &stmt.kind {
+            StmtKind::Block(stmts) => {
                 self.type_checker.push_scope();
                 let mut asm = String::new();
                 for s in stmts {
@@ -251,28 +594,44 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 self.type_checker.pop_scope()?;
                 Ok(asm)
             }
-            Stmt::VarDecl { name, ty, init, .. } => {
+            StmtKind::VarDecl { name, ty, init, .. } => {
                 let mut code = String::new();
+                // YENİ: Tipi yazılmamış tamsayı değişkenleri başlangıç değerinin tipini alır
+                // (örn: u64 aralığındaki bir sabit işaretsiz yazdırılmalıdır).
+                let inferred = match (ty, init) {
+                    (Type::Any, Some(init_expr)) => self.type_checker.type_of_expr(init_expr).ok().filter(|t| t.int_range().is_some()),
+                    _ => None,
+                };
+                let ty = inferred.as_ref().unwrap_or(ty);
+                if matches!(ty, Type::I128 | Type::U128) {
+                    return Err(format!("128 bitlik '{}' değişkeni yalnızca IR'ye indirilebilen fonksiyonlarda destekleniyor.", name));
+                }
                 if let Some(init_expr) = init {
                     // 1. Evaluate the initializer expression. The result will be in RAX.
                     code.push_str(&self.generate_expr(init_expr)?);
                     
-                    // Type::Arr veya Type::Array için array logic
-                    if *ty == Type::Arr || matches!(ty, Type::Array(_, _)) {
+                    // Literal olmayan dizi ifadeleri (örn: args()) kopyalanmaz; başlangıç adresi saklanır.
+                    let is_array = *ty == Type::Arr || matches!(ty, Type::Array(_, _));
+                    if is_array && !matches!(init_expr.kind, ExprKind::ArrayLiteral(_)) {
+                        let offset = self.alloc_stack(8);
+                        self.bind_variable(name.clone(), VariableLocation { stack_offset: offset, ty: ty.clone(), array_len: None }, stmt.span);
+                        self.type_checker.define_variable(name.clone(), crate::type_checker::VarInfo { ty: ty.clone(), is_const: false, _is_mutable: true, span: Span::dummy() })?;
+                        code.push_str(&format!("    mov [rbp - {}], rax # Store array pointer '{}'\n", offset, name));
+                    } else if is_array {
                         // ArrayLiteral'den boyutu al
-                        let len = if let Expr::ArrayLiteral(elements) = init_expr {
+                        let len = if let ExprKind::ArrayLiteral(elements) = &init_expr.kind {
                             elements.len()
                         } else {
                             0 // Boş array veya başka bir ifade
                         };
                         
                         // Değişken için alan ayır: len * 8 byte
-                        let array_start_offset = self.stack_pointer + 8; // İlk eleman
-                        self.stack_pointer += (len * 8) as i32;
+                        // arr[0] en düşük adreste: [rbp - array_start_offset]
+                        let array_start_offset = self.alloc_stack((len * 8) as i32);
                         
                         let location = VariableLocation { stack_offset: array_start_offset, ty: ty.clone(), array_len: Some(len) };
-                        self.variable_locations.insert(name.clone(), location);
-                        self.type_checker.define_variable(name.clone(), crate::type_checker::VarInfo { ty: ty.clone(), is_const: false, _is_mutable: true })?;
+                        self.bind_variable(name.clone(), location, stmt.span);
+                        self.type_checker.define_variable(name.clone(), crate::type_checker::VarInfo { ty: ty.clone(), is_const: false, _is_mutable: true, span: Span::dummy() })?;
                         
                         // RAX: Source Address (ArrayLiteral sonucu)
                         // Kopyalama döngüsü
@@ -295,12 +654,15 @@ impl<'a, 'b> Codegen<'a, 'b> {
                         code.push_str(&format!("{}:\n", copy_done));
                         
                     } else {
-                        // Tamsayı, Pointer veya Float
-                        self.stack_pointer += 8;
-                        let offset = self.stack_pointer;
+                        // Tamsayı, Pointer veya Float (struct'lar tüm alanları kadar yer kaplar)
+                        let size = match ty {
+                            Type::Custom(struct_name) => self.struct_field_types(struct_name).map_or(8, |f| (f.len().max(1) * 8) as i32),
+                            _ => 8,
+                        };
+                        let offset = self.alloc_stack(size);
                         let location = VariableLocation { stack_offset: offset, ty: ty.clone(), array_len: None };
-                        self.variable_locations.insert(name.clone(), location);
-                        self.type_checker.define_variable(name.clone(), crate::type_checker::VarInfo { ty: ty.clone(), is_const: false, _is_mutable: true })?;
+                        self.bind_variable(name.clone(), location, stmt.span);
+                        self.type_checker.define_variable(name.clone(), crate::type_checker::VarInfo { ty: ty.clone(), is_const: false, _is_mutable: true, span: Span::dummy() })?;
 
                         if ty.is_float() {
                             code.push_str(&format!("    movsd [rbp - {}], xmm0 # Store float variable '{}'\n", offset, name));
@@ -308,10 +670,28 @@ impl<'a, 'b> Codegen<'a, 'b> {
                             code.push_str(&format!("    mov [rbp - {}], rax # Store integer/pointer variable '{}'\n", offset, name));
                         }
                     }
+                } else {
+                    // Başlangıç değeri olmayan değişken: çerçevede tam boyutu ayrılır ve sıfırlanır.
+                    let (words, array_len) = match ty {
+                        Type::Array(_, Some(len)) => (*len, Some(*len)),
+                        Type::Custom(struct_name) => (self.struct_field_types(struct_name).map_or(1, |f| f.len().max(1)), None),
+                        _ => (1, None),
+                    };
+                    let offset = self.alloc_stack((words * 8) as i32);
+                    self.bind_variable(name.clone(), VariableLocation { stack_offset: offset, ty: ty.clone(), array_len }, stmt.span);
+                    self.type_checker.define_variable(name.clone(), crate::type_checker::VarInfo { ty: ty.clone(), is_const: false, _is_mutable: true, span: Span::dummy() })?;
+                    if words == 1 {
+                        code.push_str(&format!("    mov qword ptr [rbp - {}], 0 # Zero-init '{}'\n", offset, name));
+                    } else {
+                        code.push_str(&format!("    lea rdi, [rbp - {}] # Zero-init '{}'\n", offset, name));
+                        code.push_str("    xor eax, eax\n");
+                        code.push_str(&format!("    mov rcx, {}\n", words));
+                        code.push_str("    rep stosq\n");
+                    }
                 }
                 Ok(code)
             }
-            Stmt::Return(expr_opt) => {
+            StmtKind::Return(expr_opt) => {
                 let mut code = String::new();
                 if let Some(expr) = expr_opt {
                     // Dönüş değerini RAX (veya XMM0) üzerine yükle
@@ -319,21 +699,25 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 }
                 
                 if self.current_function_name == "main" {
-                    code.push_str(&self.generate_exit_code());
+                    let has_status = match expr_opt {
+                        Some(expr) => !self.type_checker.type_of_expr(expr).map_err(|e| format!("Return hatası: {e}"))?.is_float(),
+                        None => false,
+                    };
+                    code.push_str(&self.generate_exit_code(has_status));
                 } else {
                     // Fonksiyon epiloguna atla
                     code.push_str(&format!("    jmp .fn_exit_{}\n", self.current_function_name));
                 }
                 Ok(code)
             }
-            Stmt::Echo(expr) => self.generate_echo_call(expr),
-            Stmt::Assign { left, value } => {
+            StmtKind::Echo(expr) => self.generate_echo_call(expr),
+            StmtKind::Assign { left, value } => {
                 let mut code = String::new();
                 // 1. Sağ tarafı değerlendir
                 code.push_str(&self.generate_expr(value)?);
                 
                 // 2. Sol tarafın konumunu bul ve ata
-                if let Expr::Variable(name) = &*left {
+                if let ExprKind::Variable(name) = &left.kind {
                     let loc = self.variable_locations.get(name).ok_or_else(|| format!("Atama hatası: Bilinmeyen değişken '{}'", name))?;
                     if loc.ty.is_float() {
                         code.push_str(&format!("    movsd [rbp - {}], xmm0 # Assign to float variable '{}'\n", loc.stack_offset, name));
@@ -345,7 +729,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 }
                 Ok(code)
             }
-            Stmt::If { cond, then_branch, else_branch } => {
+            StmtKind::If { cond, then_branch, else_branch } => {
                 let mut code = String::new();
                 let else_label = self.generate_label("L_else");
                 let end_label = self.generate_label("L_if_end");
@@ -375,7 +759,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 code.push_str(&format!("{}:\n", end_label));
                 Ok(code)
             }
-            Stmt::While { condition, body } => {
+            StmtKind::While { condition, body } => {
                 let start_label = self.generate_label("L_while_start");
                 let end_label = self.generate_label("L_while_end");
                 let mut code = String::new();
@@ -393,7 +777,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 code.push_str(&format!("{}:\n", end_label));
                 Ok(code)
             }
-            Stmt::For { initializer, condition, increment, variable, iterable, body } => {
+            StmtKind::For { initializer, condition, increment, variable, iterable, body } => {
                 let start_label = self.generate_label("L_for_start");
                 let end_label = self.generate_label("L_for_end");
                 let mut code = String::new();
@@ -401,15 +785,14 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 // 1. Initializer / Iterator Setup
                 if let (Some(var_name), Some(iter_expr)) = (variable, iterable) {
                     // Range for: for i in 0..10
-                    if let Expr::Range { start, end } = iter_expr {
+                    if let ExprKind::Range { start, end } = &iter_expr.kind {
                         // Mevcut Range mantığı
                         let old_location = self.variable_locations.get(var_name).cloned();
                         let target_offset = if let Some(loc) = &old_location {
                             loc.stack_offset
                         } else {
-                            self.stack_pointer += 8;
-                            let offset = self.stack_pointer;
-                            self.variable_locations.insert(var_name.clone(), VariableLocation { stack_offset: offset, ty: Type::I32, array_len: None });
+                            let offset = self.alloc_stack(8);
+                            self.bind_variable(var_name.clone(), VariableLocation { stack_offset: offset, ty: Type::I32, array_len: None }, stmt.span);
                             offset
                         };
                         
@@ -439,7 +822,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                         self.type_checker.pop_scope()?;
 
                         if let Some(loc) = old_location {
-                            self.variable_locations.insert(var_name.clone(), loc);
+                            self.bind_variable(var_name.clone(), loc, stmt.span);
                         } else {
                             self.variable_locations.remove(var_name);
                         }
@@ -452,7 +835,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                             Type::Array(inner, len_opt) => (*inner, len_opt.unwrap_or(0)),
                             Type::Arr => {
                                 // Type::Arr için: variable_locations'dan boyut bilgisini al
-                                if let Expr::Variable(arr_var_name) = iter_expr {
+                                if let ExprKind::Variable(arr_var_name) = &iter_expr.kind {
                                     let var_loc = self.variable_locations.get(arr_var_name)
                                         .ok_or_else(|| format!("Dizi değişkeni bulunamadı: {arr_var_name}"))?;
                                     
@@ -467,11 +850,11 @@ impl<'a, 'b> Codegen<'a, 'b> {
                                     return Err("Type::Arr sadece değişkenler için kullanılabilir.".to_string());
                                 }
                             },
-                            _ => return Err(format!("For in döngüsü {:?} tipi üzerinde çalışmaz. Sadece dizi veya range.", iter_type))
+                            _ => return Err(format!("For in döngüsü {} tipi üzerinde çalışmaz. Sadece dizi veya range.", iter_type))
                         };
                         
                         // 1. Array başlangıç adresini al
-                        let array_base_loc = if let Expr::Variable(arr_var_name) = iter_expr {
+                        let array_base_loc = if let ExprKind::Variable(arr_var_name) = &iter_expr.kind {
                             self.variable_locations.get(arr_var_name).ok_or_else(|| format!("Dizi bulunamadı: {arr_var_name}"))?.clone()
                         } else {
                             return Err("For in şimdilik sadece dizi değişkenleri üzerinde çalışıyor.".to_string());
@@ -482,8 +865,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                         self.loop_labels.push((start_label.clone(), end_label.clone()));
 
                         // Gizli indeks değişkeni (idx)
-                        self.stack_pointer += 8;
-                        let idx_offset = self.stack_pointer;
+                        let idx_offset = self.alloc_stack(8);
                         
                         // idx = 0
                         code.push_str("    xor rax, rax\n");
@@ -492,10 +874,9 @@ impl<'a, 'b> Codegen<'a, 'b> {
                         self.type_checker.push_scope();
 
                         // Loop değişkeni (x) için alan
-                        self.stack_pointer += 8;
-                        let loop_var_offset = self.stack_pointer;
-                        self.variable_locations.insert(var_name.clone(), VariableLocation { stack_offset: loop_var_offset, ty: elem_type.clone(), array_len: None });
-                        self.type_checker.define_variable(var_name.clone(), crate::type_checker::VarInfo{ ty: elem_type.clone(), is_const: false, _is_mutable: false })?;
+                        let loop_var_offset = self.alloc_stack(8);
+                        self.bind_variable(var_name.clone(), VariableLocation { stack_offset: loop_var_offset, ty: elem_type.clone(), array_len: None }, stmt.span);
+                        self.type_checker.define_variable(var_name.clone(), crate::type_checker::VarInfo{ ty: elem_type.clone(), is_const: false, _is_mutable: false, span: Span::dummy() })?;
 
                         // LABEL START
                         code.push_str(&format!("{}:\n", start_label));
@@ -542,7 +923,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 } else if let Some(init) = initializer {
                     // Nim Style for: for (i=0, i<10, i++)
                     // Eğer başlatıcı sadece bir değişken ismiyse (i), onu 0'a init edelim.
-                    if let Stmt::ExprStmt(Expr::Variable(name)) = init.as_ref() {
+                    if let StmtKind::ExprStmt(Expr { kind: ExprKind::Variable(name), .. }) = &init.kind {
                         let loc = self.variable_locations.get(name).ok_or_else(|| format!("Döngü değişkeni bulunamadı: {name}"))?;
                         code.push_str("    xor rax, rax\n");
                         code.push_str(&format!("    mov [rbp - {}], rax # Varsayılan 0 ilklendirmesi\n", loc.stack_offset));
@@ -570,8 +951,8 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 code.push_str(&format!("{}:\n", end_label));
                 Ok(code)
             }
-            Stmt::ExprStmt(expr) => self.generate_expr(expr),
-            Stmt::Loop { body } => {
+            StmtKind::ExprStmt(expr) => self.generate_expr(expr),
+            StmtKind::Loop { body } => {
                 let start_label = self.generate_label("L_loop_start");
                 let mut code = String::new();
                 code.push_str(&format!("{}:\n", start_label));
@@ -579,7 +960,8 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 code.push_str(&format!("    jmp {}\n", start_label));
                 Ok(code)
             }
-            Stmt::Empty => Ok("".to_string()),
+            StmtKind::Empty => Ok("".to_string()),
+            StmtKind::Defer(_) => Err("'defer' için kod üretimi henüz desteklenmiyor ('nim run --interp' ile çalıştırılabilir).".to_string()),
             _ => Ok("".to_string()),
         }
     }
@@ -601,188 +983,345 @@ impl<'a, 'b> Codegen<'a, 'b> {
         let mut code = String::new();
         
         let expr_type = self.type_checker.type_of_expr(expr).map_err(|e| format!("Print hatası: {e}"))?;
-        let format_spec = self.get_format_specifier(&expr_type).to_string();
+        let format_spec = format_specifier(&expr_type).to_string();
         
         // Stil Kodlarını Belirle (ANSI)
-        let mut prefix = String::new();
-        let mut suffix = String::new();
-        
-        if let Some(style_e) = style_expr {
-             if let Expr::Literal(LiteralValue::Str(style_name)) = style_e {
-                 let style_str = style_name.as_str();
-                 
-                 // 1. Önce kullanıcı tanımlı stillere bak
-                 if let Some(custom_code) = self.type_checker.styles.get(style_str) {
-                     prefix = custom_code.clone();
-                     suffix = "\x1b[0m".to_string();
-                 } 
-                 // 2. Doğrudan ANSI kodu mu? (\x1b ile başlıyorsa)
-                 else if style_str.starts_with("\x1b") {
-                     prefix = style_str.to_string();
-                     suffix = "\x1b[0m".to_string();
-                 }
-                 // 3. Yerleşik stilleri kontrol et
-                 else {
-                     match style_str {
-                         "error" => { prefix = "\x1b[31m".to_string(); suffix = "\x1b[0m".to_string(); },
-                         "warn"  => { prefix = "\x1b[33m".to_string(); suffix = "\x1b[0m".to_string(); },
-                         "info"  => { prefix = "\x1b[36m".to_string(); suffix = "\x1b[0m".to_string(); },
-                         "success" => { prefix = "\x1b[32m".to_string(); suffix = "\x1b[0m".to_string(); },
-                         _ => {}
-                     }
-                 }
-             }
-        }
+        let (prefix, suffix) = match style_expr.map(|e| &e.kind) {
+            Some(ExprKind::Literal(LiteralValue::Str(style_name))) => print_style(&self.type_checker.styles, style_name),
+            _ => (String::new(), String::new()),
+        };
 
-        let line_end = if newline { "\r\n" } else { "" };
+        let line_end = if newline { line_end(self.target_platform) } else { "" };
         
-        match expr {
-            Expr::InterpolatedString(parts) => {
+        // Argümanlar sırayla değerlendirilip kendi geçici slotlarına yazılır; böylece
+        // sonraki argümanın kodu önceki argümanın değerini ezmez.
+        let saved_stack_pointer = self.stack_pointer;
+        match &expr.kind {
+            ExprKind::InterpolatedString(parts) => {
                 let mut full_format = prefix;
-                let mut args_to_pass = Vec::new();
+                let mut arg_slots = Vec::new();
                 for part in parts {
-                    match part {
-                        Expr::Literal(LiteralValue::Str(s)) => full_format.push_str(s),
+                    let precision = part.numeric_member().filter(|(object, _)| self.type_checker.type_of_expr(object).is_ok_and(|t| t.is_float()));
+                    match (&part.kind, precision) {
+                        (ExprKind::Literal(LiteralValue::Str(s)), _) => full_format.push_str(s),
+                        // `{x.2}`: float değer %.2f ile yazılır.
+                        (_, Some((object, digits))) => {
+                            let p_ty = self.type_checker.type_of_expr(object).unwrap_or(Type::F64);
+                            full_format.push_str(&format!("%.{}f", digits));
+                            code.push_str(&self.generate_expr(object)?);
+                            arg_slots.push((p_ty.clone(), self.store_temp(&p_ty, &mut code)));
+                        }
                         _ => {
                             let p_ty = self.type_checker.type_of_expr(part).unwrap_or(Type::Str(None));
-                            full_format.push_str(self.get_format_specifier(&p_ty));
-                            args_to_pass.push((p_ty, self.generate_expr(part)?));
+                            full_format.push_str(format_specifier(&p_ty));
+                            code.push_str(&self.generate_expr(part)?);
+                            arg_slots.push((p_ty.clone(), self.store_temp(&p_ty, &mut code)));
                         }
                     }
                 }
                 full_format.push_str(&suffix);
                 full_format.push_str(line_end);
                 let fmt_idx = self.add_string_literal(full_format);
-                code.push_str(&self.generate_printf_call_multi_arg(fmt_idx, args_to_pass)?);
+                code.push_str(&self.generate_printf_call_multi_arg(fmt_idx, arg_slots)?);
             }
             _ => {
                 code.push_str(&self.generate_expr(expr)?);
-                let mut args = Vec::new();
-                let load_code = if expr_type.is_float() {
-                    // Float değeri (XMM0) stringe çevir (_ftoa)
-                    // _print/_printf %s bekliyor olacak (aşağıda format çevrilecek)
-                    let mut c = String::new();
-                    c.push_str("    movq xmm0, xmm0\n"); // (Gereksiz ama açıklayıcı: değer xmm0'da)
-                    c.push_str("    call _ftoa\n");      // RAX <- String pointer
-                    // Şimdi RAX'ta string pointer var.
-                    // printf çağrısı için standart integer/pointer argümanı gibi davranacağız.
-                    c
+                let arg_ty = if expr_type.is_float() {
+                    // Float değeri (XMM0) stringe çevir (_ftoa), RAX <- String pointer
+                    code.push_str(&self.emit_direct_call("_ftoa"));
+                    Type::Str(None)
                 } else {
-                    "    # RAX'ta\n".to_string()
+                    expr_type
                 };
-                
+                let arg_slot = self.store_temp(&arg_ty, &mut code);
+
                 // Float ise format %s olmalı, çünkü stringe çevirdik.
-                let effective_format = if expr_type.is_float() { "%s" } else { format_spec.as_str() };
-                
-                // args listesine push ederken tipini de Str olarak kandırabiliriz veya ignore edilir.
-                // printf üreticisi sadece load_code'u kullanıyor.
-                args.push((expr_type, load_code));
-                
+                let effective_format = if arg_ty == Type::Str(None) { "%s" } else { format_spec.as_str() };
+
                 let final_format = format!("{}{}{}{}", prefix, effective_format, suffix, line_end);
                 let format_str_index = self.add_string_literal(final_format);
-                code.push_str(&self.generate_printf_call_multi_arg(format_str_index, args)?);
+                code.push_str(&self.generate_printf_call_multi_arg(format_str_index, vec![(arg_ty, arg_slot)])?);
             }
         }
+        self.stack_pointer = saved_stack_pointer;
         Ok(code)
     }
 
-    fn get_format_specifier(&self, ty: &crate::ast::Type) -> &'static str {
-        match ty {
-            Type::Str(_) => "%s",
-            Type::Char => "%c",
-            t if t.is_float() => "%f", // float sting olarak gösteriliyor o yüzden %s, ama bu kezde var olmayan döngüye giriyor.. özellikle echo içerisinde  işlem yapılırken.
-            _ => "%d",
+    // RAX/XMM0'daki değeri yeni bir geçici slota yazar ve slotun ofsetini döndürür.
+    fn store_temp(&mut self, ty: &Type, code: &mut String) -> i32 {
+        let offset = self.alloc_stack(8);
+        if ty.is_float() {
+            code.push_str(&format!("    movsd qword ptr [rbp - {}], xmm0\n", offset));
+        } else {
+            code.push_str(&format!("    mov qword ptr [rbp - {}], rax\n", offset));
         }
+        offset
     }
 
     // format_str_index: format string'in data_items listesindeki indeksi.
-    // args_to_pass: (tip, değeri RAX/XMM0'a yükleyen assembly kodu) çiftlerinin vektörü.
-    fn generate_printf_call_multi_arg(&mut self, format_str_index: usize, args_to_pass: Vec<(crate::ast::Type, String)>) -> Result<String, String> {
+    // arg_slots: (tip, değerin saklandığı geçici slotun ofseti) çiftlerinin vektörü.
+    fn generate_printf_call_multi_arg(&mut self, format_str_index: usize, arg_slots: Vec<(crate::ast::Type, i32)>) -> Result<String, String> {
         let mut code = String::new();
-        
-        let arg_int_regs = ["rcx", "rdx", "r8", "r9"];
-        let arg_float_regs = ["xmm0", "xmm1", "xmm2", "xmm3"];
+        let saved_stack_pointer = self.stack_pointer;
+
+        // Format string'in adresi de diğer argümanlar gibi bir slota yazılır
+        let fmt_offset = self.alloc_stack(8);
+        code.push_str(&format!("    lea rax, [str_{}]\n", format_str_index));
+        code.push_str(&format!("    mov qword ptr [rbp - {}], rax\n", fmt_offset));
+        let mut evaluated = vec![EvaluatedArg { offset: fmt_offset, classes: vec![ArgClass::Integer], is_address: false }];
+        for (ty, offset) in &arg_slots {
+            evaluated.push(EvaluatedArg { offset: *offset, classes: vec![abi::classify_scalar(ty)], is_address: false });
+        }
 
-        // 1. AŞAMA: Hesaplama ve Stack'e Doğru Register'dan Kaydetme
-        let mut temp_stack_offsets = Vec::new();
-        for (ty, arg_gen_code) in &args_to_pass {
-            code.push_str(arg_gen_code); // Hesaplama kodunu bas (Sonuç RAX veya XMM0'da)
-            
-            self.stack_pointer += 8;
-            let offset = self.stack_pointer;
-            temp_stack_offsets.push(offset);
+        // ABI'ye göre yerleştir ve çağır (_print variadic)
+        code.push_str(&self.emit_call("_print", &evaluated, true));
 
-            if ty.is_float() {
-                // Float ise XMM0'daki 64-bit değeri stack'e kopyala
-                code.push_str(&format!("    movq qword ptr [rbp - {}], xmm0\n", offset));
-            } else {
-                // Int ise RAX'ı kopyala
-                code.push_str(&format!("    mov qword ptr [rbp - {}], rax\n", offset));
+        self.stack_pointer = saved_stack_pointer;
+        Ok(code)
+    }
+
+    // Kullanılan çağrı kuralı (hedef platforma göre)
+    fn abi(&self) -> CallConv {
+        CallConv::for_target(self.target_platform)
+    }
+
+    // Stack çerçevesinde `size` byte ayırır ve bloğun [rbp - offset] ofsetini döndürür.
+    fn alloc_stack(&mut self, size: i32) -> i32 {
+        self.stack_pointer += size;
+        self.max_stack_pointer = self.max_stack_pointer.max(self.stack_pointer);
+        self.stack_pointer
+    }
+
+    // Bir tipin argüman olarak geçirilirken eightbyte sınıfları
+    fn arg_classes(&self, ty: &Type) -> Vec<ArgClass> {
+        if let Type::Custom(name) = ty {
+            if let Some(fields) = self.struct_field_types(name) {
+                return abi::classify_struct(&fields);
             }
         }
+        vec![abi::classify_scalar(ty)]
+    }
+
+    // Struct'ın kelime düzenindeki alan tipleri (struct değilse None)
+    fn struct_field_types(&self, struct_name: &str) -> Option<Vec<Type>> {
+        struct_layout(self.program, struct_name)
+    }
+
+    // Çağrı argümanını değerlendirir ve geçici bir slota kaydeder.
+    // Struct argümanlarının değeri değil adresi saklanır; parçaları çağrı anında okunur.
+    fn evaluate_call_arg(&mut self, arg_expr: &Expr) -> Result<(String, EvaluatedArg), String> {
+        let mut code = String::new();
+        let ty = self.type_checker.type_of_expr(arg_expr).unwrap_or(Type::I64);
+        let classes = self.arg_classes(&ty);
 
-        // 2. AŞAMA: Argümanları Yerleştirme
-        // Not: 5. ve sonraki argümanlar stack'te olmalı (sağdan sola doğru pushlanmalı).
-        let mut stack_args_pushed = 0;
-        if args_to_pass.len() > 3 { // 1 format + 3 regs = 4 total
-            for i in (3..args_to_pass.iter().len()).rev() {
-                 let offset = temp_stack_offsets[i];
-                 code.push_str(&format!("    mov rax, [rbp - {}]\n", offset));
-                 code.push_str("    push rax\n");
-                 stack_args_pushed += 1;
+        if let Type::Custom(struct_name) = &ty {
+            if self.struct_field_types(struct_name).is_some() {
+                let ExprKind::Variable(var_name) = &arg_expr.kind else {
+                    return Err(format!("Kod üretimi hatası: '{}' struct'ı değer olarak sadece bir değişkenden geçirilebilir.", struct_name));
+                };
+                let loc = self.variable_locations.get(var_name).ok_or_else(|| format!("Değişken bulunamadı: {var_name}"))?;
+                code.push_str(&format!("    lea rax, [rbp - {}] # &{}\n", loc.stack_offset, var_name));
+                let offset = self.alloc_stack(8);
+                code.push_str(&format!("    mov [rbp - {}], rax\n", offset));
+                return Ok((code, EvaluatedArg { offset, classes, is_address: true }));
             }
         }
 
-        // Format ve ilk 3 argümanı yükle
-        code.push_str(&format!("    lea rcx, [str_{}]\n", format_str_index));
-        for i in 0..std::cmp::min(args_to_pass.len(), 3) {
-            let offset = temp_stack_offsets[i];
-            let int_reg = arg_int_regs[i + 1];
-            let float_reg = arg_float_regs[i + 1];
-            let is_float = args_to_pass[i].0.is_float();
-
-            if is_float {
-                code.push_str(&format!("    movsd {}, [rbp - {}]\n", float_reg, offset));
-                // Windows x64 ABI Varargs: Float argümanlar hem XMM hem de Int register'ına (shadow) kopyalanmalı.
-                if self.target_platform == TargetPlatform::Windows {
-                    code.push_str(&format!("    movq {}, {}\n", int_reg, float_reg));
+        code.push_str(&self.generate_expr(arg_expr)?);
+        let offset = self.alloc_stack(8);
+        if ty.is_float() {
+            code.push_str(&format!("    movsd [rbp - {}], xmm0\n", offset));
+        } else {
+            code.push_str(&format!("    mov [rbp - {}], rax\n", offset));
+        }
+        Ok((code, EvaluatedArg { offset, classes, is_address: false }))
+    }
+
+    // Değerlendirilmiş argümanları ABI'ye göre register/stack'e yerleştirip `target`'ı çağırır.
+    // Stack 16 byte hizalı tutulur, gölge alan (Win64) ayrılır ve çağrıdan sonra temizlenir.
+    fn emit_call(&mut self, target: &str, args: &[EvaluatedArg], variadic: bool) -> String {
+        let conv = self.abi();
+        let mut code = String::new();
+        let classes: Vec<Vec<ArgClass>> = args.iter().map(|a| a.classes.clone()).collect();
+        let layout = conv.layout_args(&classes);
+
+        // Win64: büyük struct'lar için çağıran bir kopya oluşturur ve adresini geçirir.
+        let mut copies = Vec::new();
+        for (arg, placement) in args.iter().zip(&layout.args) {
+            if placement.by_reference {
+                let copy_offset = self.alloc_stack((arg.classes.len().max(1) * 8) as i32);
+                code.push_str(&format!("    mov r11, [rbp - {}]\n", arg.offset));
+                for j in 0..arg.classes.len() {
+                    code.push_str(&format!("    mov rax, [r11 + {}]\n", j * 8));
+                    code.push_str(&format!("    mov [rbp - {}], rax\n", copy_offset - (j * 8) as i32));
                 }
+                copies.push(Some(copy_offset));
             } else {
-                code.push_str(&format!("    mov {}, [rbp - {}]\n", int_reg, offset));
+                copies.push(None);
             }
         }
 
-        // 3. ÇAĞRI (Windows x64 için düzeltilmiş)
-        if self.target_platform == TargetPlatform::Windows {
-            // Toplam gereken alan: 32 (Shadow) + Ekstra Argümanlar (stack_args_pushed * 8)
-            // Bunu 16 byte hizasına uydurmalıyız. 
-            let extra_space = stack_args_pushed * 8;
-            let total_sub = 32 + extra_space;
-            
-            // Hizalama Kontrolü: Eğer total_sub 16'nın katı değilse, 8 byte daha ekle (Padding)
-            let padding = if (total_sub + 8) % 16 != 0 { 0 } else { 8 }; // +8 call dönüş adresinden gelir
-            let final_sub = total_sub + padding;
+        // Hizalama: geçici push'lar + stack argümanları + gölge alan toplamı 16'nın katı olmalı.
+        let stack_bytes = layout.stack_slots * 8;
+        let misalign = (self.temp_stack_depth + stack_bytes + conv.shadow_space()) % 16;
+        let padding = if misalign == 0 { 0 } else { 16 - misalign };
+        if padding > 0 {
+            code.push_str(&format!("    sub rsp, {} # Hizalama\n", padding));
+        }
+
+        // Stack argümanları sağdan sola (en yüksek slot önce) push edilir.
+        let mut stack_parts = Vec::new();
+        for (i, placement) in layout.args.iter().enumerate() {
+            for (j, part) in placement.parts.iter().enumerate() {
+                if let ArgLoc::Stack(slot) = part {
+                    stack_parts.push((*slot, i, j));
+                }
+            }
+        }
+        stack_parts.sort_by_key(|part| std::cmp::Reverse(part.0));
+        for (_, i, j) in stack_parts {
+            let arg = &args[i];
+            if let Some(copy_offset) = copies[i] {
+                code.push_str(&format!("    lea rax, [rbp - {}]\n    push rax\n", copy_offset));
+            } else if arg.is_address {
+                code.push_str(&format!("    mov r11, [rbp - {}]\n    push qword ptr [r11 + {}]\n", arg.offset, j * 8));
+            } else {
+                code.push_str(&format!("    push qword ptr [rbp - {}]\n", arg.offset));
+            }
+        }
+
+        // Register argümanları
+        for (i, placement) in layout.args.iter().enumerate() {
+            let arg = &args[i];
+            for (j, part) in placement.parts.iter().enumerate() {
+                let source = if let Some(copy_offset) = copies[i] {
+                    if let ArgLoc::IntReg(reg) = part {
+                        code.push_str(&format!("    lea {}, [rbp - {}]\n", reg, copy_offset));
+                    }
+                    continue;
+                } else if arg.is_address {
+                    code.push_str(&format!("    mov r11, [rbp - {}]\n", arg.offset));
+                    format!("[r11 + {}]", j * 8)
+                } else {
+                    format!("[rbp - {}]", arg.offset)
+                };
+                match part {
+                    ArgLoc::IntReg(reg) => code.push_str(&format!("    mov {}, {}\n", reg, source)),
+                    ArgLoc::SseReg(reg) => {
+                        code.push_str(&format!("    movsd {}, {}\n", reg, source));
+                        // Win64 variadic: float hem XMM hem de aynı konumdaki tamsayı register'ına konur.
+                        if variadic && conv.variadic_floats_in_int_regs() {
+                            code.push_str(&format!("    mov {}, {}\n", conv.int_arg_regs()[i], source));
+                        }
+                    }
+                    ArgLoc::Stack(_) => {}
+                }
+            }
+        }
+
+        // SysV: variadic fonksiyonlar AL'de kullanılan vektör register sayısını bekler.
+        if conv.needs_sse_count_in_al() {
+            code.push_str(&format!("    mov eax, {}\n", layout.sse_regs_used));
+        }
+        if conv.shadow_space() > 0 {
+            code.push_str(&format!("    sub rsp, {} # Shadow space\n", conv.shadow_space()));
+        }
+        code.push_str(&format!("    call {}\n", target));
 
-            code.push_str(&format!("    sub rsp, {}      # Shadow + Args + Alignment\n", final_sub));
-            code.push_str("    call _print\n");
-            code.push_str(&format!("    add rsp, {}      # Cleanup\n", final_sub));
+        let cleanup = conv.shadow_space() + stack_bytes + padding;
+        if cleanup > 0 {
+            code.push_str(&format!("    add rsp, {} # Cleanup\n", cleanup));
         }
+        code
+    }
 
-        self.stack_pointer -= (temp_stack_offsets.len() * 8) as i32;
+    // Argümanları zaten register'larda olan dahili bir rutini hizalı ve gölge alanlı çağırır.
+    fn emit_direct_call(&self, target: &str) -> String {
+        let conv = self.abi();
+        let misalign = (self.temp_stack_depth + conv.shadow_space()) % 16;
+        let reserve = conv.shadow_space() + if misalign == 0 { 0 } else { 16 - misalign };
+        if reserve == 0 {
+            format!("    call {}\n", target)
+        } else {
+            format!("    sub rsp, {}\n    call {}\n    add rsp, {}\n", reserve, target, reserve)
+        }
+    }
+
+    // Tip dönüşümü builtin'leri (_int, _float, _str) ve arrlen: çağrı yapılmadan yerinde üretilir.
+    fn generate_conversion_call(&mut self, fn_name: &str, args: &[(Option<String>, Expr)]) -> Result<String, String> {
+        if args.len() != 1 { return Err(format!("{} için 1 argüman bekleniyor.", fn_name)); }
+        let arg_expr = &args[0].1;
+        let arg_ty = self.type_checker.type_of_expr(arg_expr).map_err(|e| format!("{fn_name} hatası: {e}"))?;
+        let arg_reg = self.abi().int_arg_regs()[0];
+        let mut code = String::new();
+
+        if fn_name == "arrlen" {
+            if let Type::Array(_, Some(len)) = arg_ty {
+                // Statik boyut biliniyor, direkt sabiti RAX'a yükle.
+                code.push_str(&format!("    mov rax, {}\n", len));
+                return Ok(code);
+            } else if let Type::Array(_, None) = arg_ty {
+                // Boyut bilinmiyor (Dinamik/Slice) - Şu an desteklenmiyor ama
+                // Header'dan okuma eklenebilir.
+                return Err("Dinamik boyutu bilinmeyen diziler için arrlen henüz desteklenmiyor.".to_string());
+            } else {
+                return Err("arrlen sadece diziler için kullanılabilir.".to_string());
+            }
+        }
+
+        // Argüman RAX veya XMM0'a değerlendirilir
+        code.push_str(&self.generate_expr(arg_expr)?);
+        match fn_name {
+            "_int" => {
+                if arg_ty.is_float() {
+                    code.push_str("    cvttsd2si rax, xmm0 # Float to Int\n");
+                } else if arg_ty == Type::Str(None) {
+                    code.push_str(&format!("    mov {}, rax # String pointer\n", arg_reg));
+                    code.push_str(&self.emit_direct_call("_atoi"));
+                } else if !(arg_ty.is_integer() || arg_ty == Type::Char) {
+                    return Err(format!("_int: {} tipi desteklenmiyor.", arg_ty));
+                }
+            }
+            "_float" => {
+                if arg_ty.is_integer() || arg_ty == Type::Char {
+                    code.push_str("    cvtsi2sd xmm0, rax # Int to Float\n");
+                } else if !arg_ty.is_float() {
+                    return Err(format!("_float: {} tipi desteklenmiyor.", arg_ty));
+                }
+            }
+            _ => {
+                // _str
+                if arg_ty.is_float() {
+                    code.push_str(&self.emit_direct_call("_ftoa")); // Değer XMM0'da
+                } else {
+                    code.push_str(&format!("    mov {}, rax\n", arg_reg));
+                    code.push_str(&self.emit_direct_call("_itoa"));
+                }
+            }
+        }
         Ok(code)
     }
-    
 
     fn generate_expr(&mut self, expr: &Expr) -> Result<String, String> {
+        let result = self.generate_expr_inner(expr);
+        self.record_error_span(result.is_err(), expr.span);
+        result
+    }
+
+    fn generate_expr_inner(&mut self, expr: &Expr) -> Result<String, String> {
         //eprintln!("DEBUG: Codegen: Generating expression: {:?}", expr);
-        match expr {
-            Expr::Literal(LiteralValue::Int(val)) => {
-                Ok(format!("    mov rax, {}\n", val))
+        match &expr.kind {
+            ExprKind::Literal(LiteralValue::Int(val)) => {
+                Ok(format!("    mov rax, {}\n", *val as i64))
             }
-            Expr::Literal(LiteralValue::Char(c)) => {
+            ExprKind::Literal(LiteralValue::TypedInt(val, ty)) => match u64::try_from(*val) {
+                Ok(val) => Ok(format!("    mov rax, {}\n", val as i64)),
+                Err(_) => Err(format!("Hata: 64 bitten geniş '{}' sabitleri ({}) henüz desteklenmiyor.", ty, val)),
+            },
+            ExprKind::Literal(LiteralValue::Char(c)) => {
                 Ok(format!("    mov rax, {}\n", *c as u32))
             }
-            Expr::Literal(LiteralValue::Float(val)) => { // f32, f64, f128 için
+            ExprKind::Literal(LiteralValue::Float(val) | LiteralValue::TypedFloat(val, _)) => { // f32, f64, f128 için
                 // Kayan noktalı literali bellekte bir yere koyup oradan XMM0'a yükle.
                 // Tüm float tiplerini şimdilik f64 olarak işliyoruz.
                 // Değeri data_items'a ekle ve indeksini al.
@@ -790,15 +1329,15 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 // Etiketi indekse göre oluştur ve değeri yükle.
                 Ok(format!("    movsd xmm0, [float_{}]\n", float_index)) // burada etiketleri float_float_index olarak kaydediyoruz. Ama aşağıda name olarak ele alınıyor.!!
             }
-            Expr::Literal(LiteralValue::Str(s)) => {
+            ExprKind::Literal(LiteralValue::Str(s)) => {
                 let str_index = self.add_string_literal(s.clone());
                 Ok(format!("    lea rax, [str_{}]\n", str_index))
             }
-            Expr::Literal(LiteralValue::Bool(b)) => {
+            ExprKind::Literal(LiteralValue::Bool(b)) => {
                 let val = if *b { 1 } else { 0 };
                 Ok(format!("    mov rax, {}\n", val))
             }
-            Expr::Variable(name) => {
+            ExprKind::Variable(name) => {
                 //eprintln!("DEBUG: Codegen: Looking up variable '{}'", name);
                 if let Some(loc) = self.variable_locations.get(name) {
                     //eprintln!("DEBUG: Codegen: Found variable '{}' at offset {}", name, loc.stack_offset);
@@ -812,29 +1351,27 @@ impl<'a, 'b> Codegen<'a, 'b> {
                     Err(format!("Kod üretimi hatası: Bilinmeyen değişken '{}'", name))
                 }
             }
-            Expr::Input(prompt_opt) => {
+            ExprKind::Input(prompt_opt) => {
                 let mut input_code = String::new();
 
-                // 1. Prompt (Mesaj) varsa değerlendir ve RCX'e yükle
+                // 1. Prompt (Mesaj) varsa değerlendir ve ilk argüman register'ına yükle
+                let arg_reg = self.abi().int_arg_regs()[0];
                 if let Some(prompt_expr) = prompt_opt {
                     // prompt_expr bir Box<Expr> olduğu için generate_expr'e referansını gönderiyoruz
                     input_code.push_str(&self.generate_expr(prompt_expr)?);
-                    input_code.push_str("    mov rcx, rax     # Prompt adresini RCX'e al\n");
+                    input_code.push_str(&format!("    mov {}, rax     # Prompt adresi\n", arg_reg));
                 } else {
-                    // Prompt yoksa RCX = 0 (NULL)
-                    input_code.push_str("    xor rcx, rcx     # Prompt yok\n");
+                    // Prompt yoksa NULL
+                    input_code.push_str(&format!("    xor {0}, {0}     # Prompt yok\n", arg_reg));
                 }
 
-                // 2. Windows x64 ABI Shadow Space (32 byte)
-                // Dış fonksiyon çağrılmadan önce stack hizalaması ve gölge alan
-                input_code.push_str("    sub rsp, 32\n");
-                input_code.push_str("    call _input\n");
-                input_code.push_str("    add rsp, 32\n");
+                // 2. Çağrı (hizalama ve gerekiyorsa gölge alan ile)
+                input_code.push_str(&self.emit_direct_call("_input"));
 
                 // Sonuç zaten _input'tan RAX register'ında döner.
                 Ok(input_code)
             },
-            Expr::Binary { left, op, right } => {
+            ExprKind::Binary { left, op, right } => {
                 // TypeChecker'ı Codegen'in mevcut durumuyla senkronize et.
                 // doğru değişkenlerle doldurulmasını sağlar.
                 self.type_checker.push_scope(); // Yeni bir kapsam aç
@@ -843,6 +1380,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                         ty: loc.ty.clone(),
                         is_const: false, // Bu aşamada const/mut bilgisi kritik değil
                         _is_mutable: true,
+                        span: Span::dummy(),
                     };
                     self.type_checker.define_variable(name.clone(), var_info).unwrap(); // Hata beklemiyoruz
                 }
@@ -859,11 +1397,12 @@ impl<'a, 'b> Codegen<'a, 'b> {
                     
                     code.push_str(&self.generate_expr(right)?);
                     code.push_str("    sub rsp, 8\n    movsd [rsp], xmm0\n"); 
-                    //
+                    self.temp_stack_depth += 8;
 
                     // 2. Sol tarafı değerlendir.
                     code.push_str(&self.generate_expr(left)?);
                     code.push_str("    movsd xmm1, [rsp]\n    add rsp, 8\n");
+                    self.temp_stack_depth -= 8;
 
                     // 2. ve 1. argümanlar yer değiştirdi (sol XMM0, sağ XMM1)
                     match op {
@@ -873,12 +1412,10 @@ impl<'a, 'b> Codegen<'a, 'b> {
                         BinOp::Div => code.push_str("    divsd xmm0, xmm1\n"),
                         // YENİ: Mod operatörü desteği
                         BinOp::Mod => {
-                            // Windows x64 ABI gereği fonksiyon çağrısından önce stack hizalama ve shadow space
-                            code.push_str("    sub rsp, 32\n"); 
-                            code.push_str("    call _fmod\n");
-                            code.push_str("    add rsp, 32\n");
+                            // _fmod(xmm0, xmm1): iki ABI'de de ilk iki float argüman register'ı
+                            code.push_str(&self.emit_direct_call("_fmod"));
                         },
-                        _ => return Err(format!("Desteklenmeyen ikili operatör (float): {:?}. Sadece +, -, *, / desteklenir.", op)),
+                        _ => return Err(format!("Desteklenmeyen ikili operatör (float): '{}'. Sadece +, -, *, / desteklenir.", op.symbol())),
                     }
                     self.type_checker.pop_scope()?;
                     return Ok(code);
@@ -887,11 +1424,13 @@ impl<'a, 'b> Codegen<'a, 'b> {
                     // 1. Sağ tarafı değerlendir ve stack'e push'la.
                     code.push_str(&self.generate_expr(right)?);
                     code.push_str("    push rax\n");
+                    self.temp_stack_depth += 8;
 
                     // 2. Sol tarafı değerlendir. Sonuç RAX'ta.
                     code.push_str(&self.generate_expr(left)?);
                     // 3. Sağ tarafı stack'ten RBX'e pop'la.
                     code.push_str("    pop rbx\n");
+                    self.temp_stack_depth -= 8;
 
                     // 4. İşlemi yap.
                     match op {
@@ -991,10 +1530,10 @@ impl<'a, 'b> Codegen<'a, 'b> {
                             self.type_checker.pop_scope()?;
                             return Ok(or_code);
                         }
-                        _ => return Err(format!("Desteklenmeyen ikili operatör (int): {:?}", op)),
+                        _ => return Err(format!("Desteklenmeyen ikili operatör (int): '{}'", op.symbol())),
                     }
                 } else {
-                    return Err(format!("Desteklenmeyen ikili operatör tipleri: {:?} ve {:?}.", left_type, right_type));
+                    return Err(format!("Desteklenmeyen ikili operatör tipleri: {} ve {}.", left_type, right_type));
                 }
 
                 // Senkronizasyon için açılan kapsamı temizle.
@@ -1002,169 +1541,81 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 Ok(code)
             }
             
-            Expr::Call { callee, args } => {
-                let mut code = String::new();
-                
-                if let Expr::Variable(fn_name) = &**callee {
-                    match fn_name.as_str() {
-                        "print" => {
-                            if args.len() < 1 { return Err("print en az 1 argüman bekler.".to_string()); }
-                            let msg_expr = &args[0].1;
-                            let style_expr = if args.len() > 1 { Some(&args[1].1) } else { None };
-                            return self.generate_print_op(msg_expr, style_expr, false);
-                        }
-                        "println" => {
-                            if args.len() < 1 { return Err("println en az 1 argüman bekler.".to_string()); }
-                            let msg_expr = &args[0].1;
-                            let style_expr = if args.len() > 1 { Some(&args[1].1) } else { None };
-                            return self.generate_print_op(msg_expr, style_expr, true);
-                        }
-                        "eprint" => {
-                            if args.len() < 1 { return Err("eprint en az 1 argüman bekler.".to_string()); }
-                            return self.generate_print_op(&args[0].1, Some(&Expr::Literal(LiteralValue::Str("error".to_string()))), true);
-                        }
-                        _ => {}
+            ExprKind::Call { callee, args } => {
+                let fn_name = if let ExprKind::Variable(fn_name) = &callee.kind {
+                    fn_name.clone()
+                } else {
+                    return Err("Sadece doğrudan fonksiyon isimleri ile çağrı destekleniyor.".to_string());
+                };
+
+                match fn_name.as_str() {
+                    "print" => {
+                        if args.is_empty() { return Err("print en az 1 argüman bekler.".to_string()); }
+                        let msg_expr = &args[0].1;
+                        let style_expr = if args.len() > 1 { Some(&args[1].1) } else { None };
+                        return self.generate_print_op(msg_expr, style_expr, false);
+                    }
+                    "println" => {
+                        if args.is_empty() { return Err("println en az 1 argüman bekler.".to_string()); }
+                        let msg_expr = &args[0].1;
+                        let style_expr = if args.len() > 1 { Some(&args[1].1) } else { None };
+                        return self.generate_print_op(msg_expr, style_expr, true);
                     }
+                    "eprint" => {
+                        if args.is_empty() { return Err("eprint en az 1 argüman bekler.".to_string()); }
+                        return self.generate_print_op(&args[0].1, Some(&Expr::synthetic(ExprKind::Literal(LiteralValue::Str("error".to_string())))), true);
+                    }
+                    "_int" | "_float" | "_str" | "arrlen" => {
+                        return self.generate_conversion_call(&fn_name, args);
+                    }
+                    // Komut satırı: main'in girişte sakladığı argc/argv
+                    "arg_count" => return Ok("    mov rax, [_nim_argc]\n".to_string()),
+                    "args" => return Ok("    mov rax, [_nim_argv]\n".to_string()),
+                    _ => {}
                 }
 
-                // Normal Fonksiyonlar veya diğer Builtinler için mevcut mantık:
+                // Çağrılacak sembol (bazı builtin'ler runtime rutinlerine eşlenir)
+                let target = match fn_name.as_str() {
+                    "strlen" => {
+                        if args.len() != 1 { return Err("strlen için 1 argüman bekleniyor.".to_string()); }
+                        "_strlen".to_string()
+                    }
+                    "exit" => {
+                        if args.len() != 1 { return Err("exit için 1 argüman bekleniyor.".to_string()); }
+                        exit_symbol(self.target_platform).to_string()
+                    }
+                    "panic" => {
+                        if args.len() != 1 { return Err("panic için 1 argüman bekleniyor.".to_string()); }
+                        "_print".to_string()
+                    }
+                    _ => fn_name.clone(),
+                };
+
+                let mut code = String::new();
+                let saved_stack_pointer = self.stack_pointer;
+
                 // 1. Argümanları değerlendir ve geçici olarak stack'e sakla
-                let mut temp_offsets = Vec::new();
+                let mut evaluated = Vec::new();
                 for (_, arg_expr) in args {
-                    code.push_str(&self.generate_expr(arg_expr)?);
-                    self.stack_pointer += 8;
-                    let offset = self.stack_pointer;
-                    temp_offsets.push(offset);
-                    code.push_str(&format!("    mov [rbp - {}], rax\n", offset));
+                    let (arg_code, arg) = self.evaluate_call_arg(arg_expr)?;
+                    code.push_str(&arg_code);
+                    evaluated.push(arg);
                 }
 
-                // 2. Register ve Stack argümanlarını hazırla
-                let arg_regs = ["rcx", "rdx", "r8", "r9"];
-                let mut stack_pushed_count = 0;
-                
-                // 5+ argümanlar stack'e (sağdan sola)
-                if args.len() > 4 {
-                    for i in (4..args.len()).rev() {
-                        let offset = temp_offsets[i];
-                        code.push_str(&format!("    mov rax, [rbp - {}]\n", offset));
-                        code.push_str("    push rax\n");
-                        stack_pushed_count += 1;
-                    }
-                }
-
-                // İlk 4 argüman registerlara
-                for i in 0..std::cmp::min(args.len(), 4) {
-                    let offset = temp_offsets[i];
-                    code.push_str(&format!("    mov {}, [rbp - {}]\n", arg_regs[i], offset));
-                }
+                // 2. ABI'ye göre yerleştir ve çağır
+                code.push_str(&self.emit_call(&target, &evaluated, fn_name == "panic"));
 
-                // 3. Shadow Space
-                code.push_str("    sub rsp, 32\n");
-                
-                // 4. Call
-                if let Expr::Variable(fn_name) = &**callee {
-                    match fn_name.as_str() {
-                        "strlen" => {
-                            if args.len() != 1 { return Err("strlen için 1 argüman bekleniyor.".to_string()); }
-                            code.push_str("    call _strlen\n");
-                        }
-                        "exit" => {
-                            if args.len() != 1 { return Err("exit için 1 argüman bekleniyor.".to_string()); }
-                            code.push_str("    call ExitProcess\n");
-                        }
-                        "panic" => {
-                            if args.len() != 1 { return Err("panic için 1 argüman bekleniyor.".to_string()); }
-                            code.push_str("    call _print\n");
-                            code.push_str("    mov rcx, 1\n    call ExitProcess\n");
-                        }
-                        "print" | "println" | "eprint" => unreachable!(), // Yukarıda halledildi
-                        "_int" => {
-                            if args.len() != 1 { return Err("_int için 1 argüman bekleniyor.".to_string()); }
-                            let arg_ty = self.type_checker.type_of_expr(&args[0].1).map_err(|e| format!("_int hatası: {e}"))?;
-                            // Argüman zaten değerlendirildi ve register'da (RAX veya XMM0)
-                            if arg_ty.is_float() {
-                                code.push_str("    cvttsd2si rax, xmm0 # Float to Int\n");
-                            } else if arg_ty == Type::Char {
-                                // RAX zaten char değerini tutuyor.
-                            } else if arg_ty == Type::Str(None) {
-                                code.push_str("    mov rcx, rax # String pointer\n");
-                                code.push_str("    call _atoi\n");
-                            } else if arg_ty.is_integer() {
-                                // Değişiklik gerekmez.
-                            } else {
-                                return Err(format!("_int: {:?} tipi desteklenmiyor.", arg_ty));
-                            }
-                            // Cleanup call stack (shadow space vs) argüman değerlendirilirken yapılmamıştı, 
-                            // normal function call mantığından çıkıp inline dönüşüm yaptık.
-                            // Ancak biz Call şablonu içindeyiz. Bu yüzden özel dönüş yapmalıyız.
-                            code.push_str(&format!("    add rsp, {}\n", 32 + stack_pushed_count * 8));
-                            self.stack_pointer -= (args.len() * 8) as i32;
-                            return Ok(code);
-                        }
-                        "_float" => {
-                            if args.len() != 1 { return Err("_float için 1 argüman bekleniyor.".to_string()); }
-                            let arg_ty = self.type_checker.type_of_expr(&args[0].1).map_err(|e| format!("_float hatası: {e}"))?;
-                            if arg_ty.is_integer() || arg_ty == Type::Char {
-                                code.push_str("    cvtsi2sd xmm0, rax # Int to Float\n");
-                            } else if arg_ty.is_float() {
-                                // Zaten XMM0'da.
-                            } else {
-                                return Err(format!("_float: {:?} tipi desteklenmiyor.", arg_ty));
-                            }
-                            code.push_str(&format!("    add rsp, {}\n", 32 + stack_pushed_count * 8));
-                            self.stack_pointer -= (args.len() * 8) as i32;
-                            return Ok(code);
-                        }
-                        "_str" => {
-                            if args.len() != 1 { return Err("_str için 1 argüman bekleniyor.".to_string()); }
-                            let arg_ty = self.type_checker.type_of_expr(&args[0].1).map_err(|e| format!("_str hatası: {e}"))?;
-                            if arg_ty.is_float() {
-                                code.push_str("    movq rdx, xmm0\n"); // Arg2: float bits
-                                code.push_str("    call _ftoa\n");
-                            } else {
-                                code.push_str("    mov rcx, rax\n");
-                                code.push_str("    call _itoa\n");
-                            }
-                            code.push_str(&format!("    add rsp, {}\n", 32 + stack_pushed_count * 8));
-                            self.stack_pointer -= (args.len() * 8) as i32;
-                            return Ok(code);
-                        }
-						"arrlen" => {
-                             if args.len() != 1 { return Err("arrlen için 1 argüman bekleniyor.".to_string()); }
-                             let arg_ty = self.type_checker.type_of_expr(&args[0].1).map_err(|e| format!("arrlen hatası: {e}"))?;
-                             
-                             if let Type::Array(_, Some(len)) = arg_ty {
-                                 // Statik boyut biliniyor, direkt sabiti RAX'a yükle.
-                                 code.push_str(&format!("    mov rax, {}\n", len));
-                             } else if let Type::Array(_, None) = arg_ty {
-                                 // Boyut bilinmiyor (Dinamik/Slice) - Şu an desteklenmiyor ama
-                                 // Header'dan okuma eklenebilir.
-                                 return Err("Dinamik boyutu bilinmeyen diziler için arrlen henüz desteklenmiyor.".to_string());
-                             } else {
-                                 return Err("arrlen sadece diziler için kullanılabilir.".to_string());
-                             }
-                             // Cleanup
-                             code.push_str(&format!("    add rsp, {}\n", 32 + stack_pushed_count * 8));
-                             self.stack_pointer -= (args.len() * 8) as i32;
-                             return Ok(code);
-                        }
-                        _ => {
-                            code.push_str(&format!("    call {}\n", fn_name));
-                        }
-                    }
-                } else {
-                    return Err("Sadece doğrudan fonksiyon isimleri ile çağrı destekleniyor.".to_string());
+                if fn_name == "panic" {
+                    code.push_str(&format!("    mov {}, 1\n", self.abi().int_arg_regs()[0]));
+                    code.push_str(&self.emit_direct_call(exit_symbol(self.target_platform)));
                 }
 
-                // 5. Cleanup
-                code.push_str(&format!("    add rsp, {}\n", 32 + stack_pushed_count * 8));
-                
                 // Geçici stack imlecini geri al
-                self.stack_pointer -= (args.len() * 8) as i32;
-                
+                self.stack_pointer = saved_stack_pointer;
+
                 Ok(code)
             }
-            Expr::Unary { op, right } => {
+            ExprKind::Unary { op, right } => {
                 let mut code = String::new();
                 code.push_str(&self.generate_expr(right)?);
                 match op {
@@ -1177,7 +1628,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                         code.push_str("    neg rax\n");
                     }
                     UnOp::PostInc => {
-                        if let Expr::Variable(name) = right.as_ref() {
+                        if let ExprKind::Variable(name) = &right.kind {
                             let loc = self.variable_locations.get(name).ok_or_else(|| format!("Değişken bulunamadı: {name}"))?;
                             code.push_str(&format!("    mov rax, [rbp - {}]\n", loc.stack_offset));
                             code.push_str("    push rax\n");
@@ -1189,7 +1640,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                         }
                     }
                     UnOp::PostDec => {
-                        if let Expr::Variable(name) = right.as_ref() {
+                        if let ExprKind::Variable(name) = &right.kind {
                             let loc = self.variable_locations.get(name).ok_or_else(|| format!("Değişken bulunamadı: {name}"))?;
                             code.push_str(&format!("    mov rax, [rbp - {}]\n", loc.stack_offset));
                             code.push_str("    push rax\n");
@@ -1200,16 +1651,15 @@ impl<'a, 'b> Codegen<'a, 'b> {
                             return Err("Post-decrement sadece değişkenlere uygulanabilir.".to_string());
                         }
                     }
-                    _ => return Err(format!("Desteklenmeyen tekli operatör: {:?}", op)),
+                    _ => return Err(format!("Desteklenmeyen tekli operatör: '{}'", op.symbol())),
                 }
                 Ok(code)
             }
-            Expr::ArrayLiteral(elements) => {
+            ExprKind::ArrayLiteral(elements) => {
                  let mut code = String::new();
                  let len = elements.len();
                  // Elemanlar için stack alanı ayır
-                 self.stack_pointer += (len * 8) as i32;
-                 let array_base_offset = self.stack_pointer;
+                 let array_base_offset = self.alloc_stack((len * 8) as i32);
                  
                  // array_base_offset şu an dizinin "son" (en düşük adresli - stack aşağı büyüyor) elemanını işaret ediyor olabilir mi?
                  // Sistemimizde: stack_pointer ofset olarak tutuluyor. Erişim [rbp - offset].
@@ -1238,7 +1688,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                  code.push_str(&format!("    lea rax, [rbp - {}]\n", array_base_offset));
                  Ok(code)
             }
-            Expr::MemberAccess { object, member } => {
+            ExprKind::MemberAccess { object, member } => {
                 let mut code = String::new();
                 let obj_type = self.type_checker.type_of_expr(object).map_err(|e| format!("Üye erişim hatası: {e}"))?;
                 
@@ -1247,7 +1697,7 @@ impl<'a, 'b> Codegen<'a, 'b> {
                     let m_type = self.get_struct_member_type(&struct_name, member)?;
                     
                     // Eğer object bir değişkense, adresini al. Değerini değil.
-                    if let Expr::Variable(name) = &**object {
+                    if let ExprKind::Variable(name) = &object.kind {
                         let loc = self.variable_locations.get(name).ok_or_else(|| format!("Değişken bulunamadı: {name}"))?;
                         if m_type.is_float() {
                             code.push_str(&format!("    movsd xmm0, [rbp - {} + {}] # {}.{}\n", loc.stack_offset, offset, name, member));
@@ -1267,12 +1717,23 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 }
                 Ok(code)
             }
-            Expr::ArrayAccess { name, index } => {
+            ExprKind::ArrayAccess { name, index } => {
                 let mut code = String::new();
                 let loc = self.variable_locations.get(name).cloned().ok_or_else(|| format!("Dizi bulunamadı: {name}"))?;
                 
                 // İndeksi RAX'a yükle
                 code.push_str(&self.generate_expr(index)?);
+
+                // Boyutu bilinmeyen diziler (parametreler, args()) stack'te sadece adres olarak durur.
+                if loc.array_len.is_none() && (loc.ty == Type::Arr || matches!(loc.ty, Type::Array(_, _))) {
+                    code.push_str(&format!("    mov r11, [rbp - {}] # Array pointer '{}'\n", loc.stack_offset, name));
+                    if matches!(&loc.ty, Type::Array(inner, _) if inner.is_float()) {
+                        code.push_str("    movsd xmm0, [r11 + rax*8]\n");
+                    } else {
+                        code.push_str("    mov rax, [r11 + rax*8]\n");
+                    }
+                    return Ok(code);
+                }
                 
                 // Adres = Başlangıç + (İndeks * 8)
                 if loc.ty.is_float() {
@@ -1282,16 +1743,16 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 }
                 Ok(code)
             }
-            Expr::Assign { left, value } => {
+            ExprKind::Assign { left, value } => {
                 let mut code = String::new();
                 // 1. Sağ tarafı değerlendir (sonuç RAX veya XMM0)
                 code.push_str(&self.generate_expr(value)?);
                 
                 // 2. Sol tarafın konumunu bul ve ata
-                if let Expr::Variable(name) = &**left {
+                if let ExprKind::Variable(name) = &left.kind {
                     // Eğer for döngüsü başlatıcısında 'i' gibi bir değişken sadece adıyla geçiyorsa,
                     // ama henüz tanımlanmamışsa veya değer atanmamışsa 0'a init edelim.
-                    // (Ancak Expr::Assign zaten bir atama olduğu için burada her zaman tanımlı olmalı)
+                    // (Ancak ExprKind::Assign zaten bir atama olduğu için burada her zaman tanımlı olmalı)
                     let loc = self.variable_locations.get(name).ok_or_else(|| format!("Atama hatası: Bilinmeyen değişken '{}'", name))?;
                     if loc.ty.is_float() {
                         code.push_str(&format!("    movsd [rbp - {}], xmm0 # Assign to float variable '{}'\n", loc.stack_offset, name));
@@ -1303,7 +1764,10 @@ impl<'a, 'b> Codegen<'a, 'b> {
                 }
                 Ok(code)
             }
-            _ => Err(format!("Bu ifade tipi için kod üretimi henüz desteklenmiyor: {:?}", expr)),
+            _ => Err(format!(
+                "Bu ifade tipi için kod üretimi henüz desteklenmiyor: {} (satır {}, sütun {})",
+                expr.kind.name(), expr.span.line, expr.span.column
+            )),
         }
     }
 
@@ -1311,10 +1775,10 @@ impl<'a, 'b> Codegen<'a, 'b> {
     fn add_string_literal(&mut self, s: String) -> usize {
         // Sadece stringleri kontrol et
         if let Some(pos) = self.data_items.iter().position(|item| matches!(item, DataItem::String(existing_s) if existing_s == &s)) {
-            return pos;
+            pos
         } else {
             self.data_items.push(DataItem::String(s));
-            return self.data_items.len() - 1;
+            self.data_items.len() - 1
         }
     }
 
@@ -1325,17 +1789,17 @@ impl<'a, 'b> Codegen<'a, 'b> {
     }
 
 
-    // Struct üyesinin ofsetini hesaplar (Her alan 8 byte varsayılıyor)
+    // Struct üyesinin ofsetini hesaplar (her skaler alan 8 byte, iç içe struct'lar yerinde açılır)
     fn get_struct_member_offset(&self, struct_name: &str, member_name: &str) -> Result<i32, String> {
         for decl in self.program {
             if let Decl::Struct { name, fields, .. } = decl {
                 if name == struct_name {
                     let mut offset = 0;
-                    for (f_name, _) in fields {
+                    for (f_name, f_ty) in fields {
                         if f_name == member_name {
                             return Ok(offset);
                         }
-                        offset += 8;
+                        offset += layout_words(self.program, f_ty) as i32 * 8;
                     }
                     return Err(format!("Hata: '{}' struct'ında '{}' alanı bulunamadı.", struct_name, member_name));
                 }
@@ -1375,8 +1839,14 @@ impl<'a, 'b> Codegen<'a, 'b> {
         lib.push_str("    add rsp, 40\n");
         lib.push_str("    ret\n");
 
-        // _atoi: rcx = string -> rax = integer
+        let conv = self.abi();
+        let arg_reg = conv.int_arg_regs()[0];
+
+        // _atoi: ilk argüman = string -> rax = integer (gövde RCX ile çalışır)
         lib.push_str("_atoi:\n");
+        if arg_reg != "rcx" {
+            lib.push_str(&format!("    mov rcx, {}\n", arg_reg));
+        }
         lib.push_str("    xor rax, rax\n    xor r8, r8\n    mov r9, 1\n");
         lib.push_str("    movzx r8, byte ptr [rcx]\n    cmp r8b, '-'\n    jne .Latoi_loop\n");
         lib.push_str("    mov r9, -1\n    inc rcx\n");
@@ -1385,8 +1855,11 @@ impl<'a, 'b> Codegen<'a, 'b> {
         lib.push_str("    sub r8b, '0'\n    imul rax, 10\n    add rax, r8\n    inc rcx\n    jmp .Latoi_loop\n");
         lib.push_str(".Latoi_done:\n    imul rax, r9\n    ret\n\n");
 
-        // _itoa: rcx = integer -> rax = string pointer (temporary buffer)
+        // _itoa: ilk argüman = integer -> rax = string pointer (temporary buffer)
         lib.push_str("_itoa:\n");
+        if arg_reg != "rcx" {
+            lib.push_str(&format!("    mov rcx, {}\n", arg_reg));
+        }
         lib.push_str("    lea rax, [_conv_buffer]\n    add rax, 64\n    mov byte ptr [rax], 0\n");
         lib.push_str("    mov r8, rcx\n    mov r10, 10\n    mov r11, rax\n    test r8, r8\n    jns .Litoa_loop\n    neg r8\n");
         lib.push_str(".Litoa_loop:\n    xor rdx, rdx\n    mov rax, r8\n    div r10\n    mov r8, rax\n");
@@ -1398,20 +1871,92 @@ impl<'a, 'b> Codegen<'a, 'b> {
         // _ftoa: xmm0 = float -> rax = string pointer //dönüşüm problemli .
         // C standard library sprintf yerine _sprint kullanarak float dönüşümü
         lib.push_str("_ftoa:\n");
-        lib.push_str("    sub rsp, 48\n"); // Shadow space (32) + alignment
-        // _sprint(buffer, "%f", val)
-        // RCX = buffer
-        lib.push_str("    lea rcx, [_conv_buffer]\n");
-        // RDX = format string
-        lib.push_str("    lea rdx, [_fmt_float_str]\n");
-        // R8/XMM2 = val (Windows x64 calling convention: 3. argüman XMM2/R8)
-        lib.push_str("    movaps xmm2, xmm0\n"); 
-        lib.push_str("    movq r8, xmm0\n"); // Shadowing
+        // Girişte RSP 16'nın katı değil (dönüş adresi); gölge alan + 8 byte hizalama
+        let frame = conv.shadow_space() + 8;
+        lib.push_str(&format!("    sub rsp, {}\n", frame));
+        // _sprint(buffer, "%f", val): argümanların yerini ABI belirler
+        let layout = conv.layout_args(&[vec![ArgClass::Integer], vec![ArgClass::Integer], vec![ArgClass::Sse]]);
+        lib.push_str(&format!("    lea {}, [_conv_buffer]\n", conv.int_arg_regs()[0]));
+        lib.push_str(&format!("    lea {}, [_fmt_float_str]\n", conv.int_arg_regs()[1]));
+        if let ArgLoc::SseReg(reg) = layout.args[2].parts[0] {
+            if reg != "xmm0" {
+                lib.push_str(&format!("    movaps {}, xmm0\n", reg));
+            }
+        }
+        // Win64 varargs: float aynı zamanda tamsayı register'ında (R8)
+        if conv.variadic_floats_in_int_regs() {
+            lib.push_str(&format!("    movq {}, xmm0\n", conv.int_arg_regs()[2]));
+        }
+        if conv.needs_sse_count_in_al() {
+            lib.push_str(&format!("    mov eax, {}\n", layout.sse_regs_used));
+        }
         lib.push_str("    call _sprint\n");
         lib.push_str("    lea rax, [_conv_buffer]\n");
-        lib.push_str("    add rsp, 48\n");
+        lib.push_str(&format!("    add rsp, {}\n", frame));
         lib.push_str("    ret\n");
 
         lib
     }
 }
+
+// String'i GAS'ın .asciz direktifi için kaçış dizileriyle yazar.
+fn asm_string_literal(s: &str) -> String {
+    s.replace("\\", "\\\\")
+        .replace("\"", "\\\"")
+        .replace("\n", "\\n")
+        .replace("\r", "\\r")
+        .replace("\x1b", "\\033") // GAS için octal escape
+}
+
+// Gövde hiç çağrı yapmıyor ve RSP'yi değiştirmiyorsa yaprak fonksiyondur (red zone kullanılabilir).
+fn is_leaf_body(body_asm: &str) -> bool {
+    !body_asm.lines().map(str::trim_start).any(|line| {
+        line.starts_with("call ") || line.starts_with("push ") || line.starts_with("sub rsp")
+    })
+}
+
+// Süreci sonlandıran runtime sembolü (Windows: kernel32, diğerleri: core_linux.s)
+pub(crate) fn exit_symbol(target: TargetPlatform) -> &'static str {
+    match target {
+        TargetPlatform::Windows => "ExitProcess",
+        _ => "_exit_process",
+    }
+}
+
+// _print biçim dizgisinde bir değerin yer tutucusu
+pub(crate) fn format_specifier(ty: &Type) -> &'static str {
+    match ty {
+        Type::Str(_) => "%s",
+        Type::Char => "%c",
+        t if t.is_unsigned_integer() => "%u",
+        t if t.is_float() => "%f", // float sting olarak gösteriliyor o yüzden %s, ama bu kezde var olmayan döngüye giriyor.. özellikle echo içerisinde  işlem yapılırken.
+        _ => "%d",
+    }
+}
+
+// println/eprint satır sonu
+pub(crate) fn line_end(target: TargetPlatform) -> &'static str {
+    match target {
+        TargetPlatform::Windows => "\r\n",
+        _ => "\n",
+    }
+}
+
+// print stil adının ANSI ön ve son ekleri: önce kullanıcı tanımlı stiller, sonra doğrudan ANSI
+// kodu (\x1b ile başlıyorsa), sonra yerleşik stiller. Bilinmeyen stil renksiz yazılır.
+pub(crate) fn print_style(styles: &std::collections::HashMap<String, String>, style: &str) -> (String, String) {
+    let reset = "\x1b[0m".to_string();
+    if let Some(custom_code) = styles.get(style) {
+        return (custom_code.clone(), reset);
+    }
+    if style.starts_with('\x1b') {
+        return (style.to_string(), reset);
+    }
+    match style {
+        "error" => ("\x1b[31m".to_string(), reset),
+        "warn" => ("\x1b[33m".to_string(), reset),
+        "info" => ("\x1b[36m".to_string(), reset),
+        "success" => ("\x1b[32m".to_string(), reset),
+        _ => (String::new(), String::new()),
+    }
+}
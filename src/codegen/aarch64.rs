@@ -0,0 +1,922 @@
+// src/codegen/aarch64.rs
+
+// IR fonksiyonlarından AArch64 (Linux, AAPCS64) kodu üretimi. Çıktı GNU as sözdizimindedir ve
+// libs/core_linux_aarch64.s runtime'ı ile bağlanır.
+//
+// Yalnızca IR'ye indirilebilen fonksiyonlar derlenebilir; AST yolu x86-64'e özgüdür. Çerçeve x86-64
+// ile aynı düzendedir: x29 çerçeve register'ıdır ve yereller [x29 - ofset]'te durur. Geçicilerin
+// yerlerini aynı register atayıcı (regalloc.rs) AAPCS64 register listeleriyle belirler. x9/x10
+// (float'larda d0/d1) işlenen taşımak, x16/x17 adres hesaplamak için kullanılır; bu register'lar
+// hiçbir geçiciye atanmaz.
+
+use super::abi::{self, ArgClass, ArgLoc, CallConv};
+use super::ir_emit::scalar_type;
+use super::{asm_string_literal, regalloc, Codegen, DataItem, EvaluatedArg};
+use crate::ast::{expand_asm_body, Decl, Expr, TargetPlatform, Type};
+use crate::ir::{
+    self, Address, AsmOperand, Base, BinaryOp, BlockId, CallArg, CmpOp, ConvertOp, Function, Inst, IrType, Operand, Param, Temp,
+    Terminator, UnaryOp,
+};
+
+// Linux AArch64 syscall numaraları (asm-generic)
+const SYS_EXIT_GROUP: i64 = 94;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Loc {
+    Reg(&'static str),
+    // [x29 - ofset]
+    Stack(i32),
+}
+
+fn is_fp(reg: &str) -> bool {
+    reg.starts_with('d')
+}
+
+struct Frame {
+    locs: Vec<Loc>,
+    slots: Vec<i32>,
+    uses: Vec<usize>,
+}
+
+impl Frame {
+    fn loc(&self, temp: Temp) -> Loc {
+        self.locs[temp.0 as usize]
+    }
+
+    fn int_target(&self, dst: Temp) -> &'static str {
+        match self.loc(dst) {
+            Loc::Reg(reg) if !is_fp(reg) => reg,
+            _ => "x9",
+        }
+    }
+
+    fn float_target(&self, dst: Temp) -> &'static str {
+        match self.loc(dst) {
+            Loc::Reg(reg) if is_fp(reg) => reg,
+            _ => "d0",
+        }
+    }
+}
+
+// İşaretli karşılaştırmanın koşul kodu ve tersi
+fn condition_codes(op: CmpOp) -> (&'static str, &'static str) {
+    match op {
+        CmpOp::Eq => ("eq", "ne"),
+        CmpOp::Ne => ("ne", "eq"),
+        CmpOp::Lt => ("lt", "ge"),
+        CmpOp::Le => ("le", "gt"),
+        CmpOp::Gt => ("gt", "le"),
+        CmpOp::Ge => ("ge", "lt"),
+    }
+}
+
+// Sabiti register'a yükler: 16 bitlik değerler tek `mov`, diğerleri movz/movk dizisi.
+fn load_imm(reg: &str, value: i64, code: &mut String) {
+    if (-65536..=65535).contains(&value) {
+        code.push_str(&format!("    mov {}, #{}\n", reg, value));
+        return;
+    }
+    let bits = value as u64;
+    code.push_str(&format!("    movz {}, #{}\n", reg, bits & 0xffff));
+    for shift in [16, 32, 48] {
+        let chunk = (bits >> shift) & 0xffff;
+        if chunk != 0 {
+            code.push_str(&format!("    movk {}, #{}, lsl #{}\n", reg, chunk, shift));
+        }
+    }
+}
+
+// `dst = base + offset`; 12 bitlik anlık değere sığmayan ofsetler x17 üzerinden eklenir.
+fn add_offset(dst: &str, base: &str, offset: i64, code: &mut String) {
+    match offset {
+        0 if dst == base => {}
+        0 => code.push_str(&format!("    mov {}, {}\n", dst, base)),
+        1..=4095 => code.push_str(&format!("    add {}, {}, #{}\n", dst, base, offset)),
+        -4095..=-1 => code.push_str(&format!("    sub {}, {}, #{}\n", dst, base, -offset)),
+        _ => {
+            load_imm("x17", offset, code);
+            code.push_str(&format!("    add {}, {}, x17\n", dst, base));
+        }
+    }
+}
+
+// `[base, #ofset]` bellek işleneni. ldr/str'nin kodlayamadığı ofsetler için adres x16'da hesaplanır.
+fn memory(base: &str, offset: i64, code: &mut String) -> String {
+    if (-256..=255).contains(&offset) || (offset >= 0 && offset % 8 == 0 && offset <= 32760) {
+        return format!("[{}, #{}]", base, offset);
+    }
+    add_offset("x16", base, offset, code);
+    "[x16]".to_string()
+}
+
+// Çerçevedeki [x29 - ofset] yuvası
+fn frame_slot(offset: i32, code: &mut String) -> String {
+    memory("x29", -(offset as i64), code)
+}
+
+// asm bloğunda geçen ilk x86-64 register adı (`%isim` operandları ve yorumlar hariç). asm blokları
+// hedefin assembly'si olarak aynen yazıldığından x86 kodu AArch64'te GCC'ye ulaşmadan reddedilir.
+fn x86_register(body: &str) -> Option<String> {
+    const NAMED: [&str; 28] = [
+        "rax", "rbx", "rcx", "rdx", "rsi", "rdi", "rbp", "rsp", "rip", "eax", "ebx", "ecx", "edx", "esi", "edi", "ebp",
+        "esp", "ax", "bx", "cx", "dx", "si", "di", "al", "bl", "cl", "dl", "ptr",
+    ];
+    let is_x86 = |word: &str| {
+        // r8-r15 (r8d/r8w/r8b alt register'larıyla) ve xmm0-xmm15
+        let extended = word.strip_prefix('r').map(|n| n.trim_end_matches(['d', 'w', 'b'])).and_then(|n| n.parse::<u8>().ok());
+        let vector = word.strip_prefix("xmm").and_then(|n| n.parse::<u8>().ok());
+        NAMED.contains(&word) || extended.is_some_and(|n| (8..=15).contains(&n)) || vector.is_some_and(|n| n < 16)
+    };
+    let lines = expand_asm_body(body, |_| String::new());
+    lines
+        .iter()
+        .flat_map(|line| line.split(|c: char| !c.is_ascii_alphanumeric()))
+        .map(str::to_ascii_lowercase)
+        .find(|word| is_x86(word))
+}
+
+// Bir sembolün adresini register'a yükler.
+fn load_address(reg: &str, symbol: &str, code: &mut String) {
+    code.push_str(&format!("    adrp {}, {}\n    add {}, {}, :lo12:{}\n", reg, symbol, reg, reg, symbol));
+}
+
+impl<'a, 'b> Codegen<'a, 'b> {
+    pub(super) fn generate_aarch64_program(&mut self) -> Result<String, String> {
+        if self.target_platform != TargetPlatform::Linux {
+            return Err("Hata: AArch64 kod üretimi şimdilik yalnızca Linux hedefinde destekleniyor.".to_string());
+        }
+        let mut text = String::new();
+        text.push_str(".text\n");
+        text.push_str(".global _start\n\n");
+
+        // x86-64 yolunda olduğu gibi program önce bütün olarak IR'ye indirilip optimize edilir.
+        let mut module = ir::lower::lower_program(self.program, self.type_checker, self.target_platform);
+        ir::opt::optimize(&mut module, self.opt_level);
+        let mut lowered = module.functions.into_iter();
+        for decl in self.program.iter() {
+            if let Decl::Function { name, params, .. } = decl {
+                self.current_decl_span = decl.span();
+                self.current_function_name = name.clone();
+                self.stack_pointer = 0;
+                self.max_stack_pointer = 0;
+                self.temp_stack_depth = 0;
+                self.variable_locations.clear();
+
+                let function = match lowered.next() {
+                    Some(Ok(function)) => function,
+                    Some(Err(skipped)) => {
+                        return Err(format!(
+                            "Hata: '{}' fonksiyonu AArch64 hedefinde derlenemiyor: {} (AArch64 kod üreticisi yalnızca IR'ye indirilebilen fonksiyonları destekler.)",
+                            name, skipped.reason
+                        ));
+                    }
+                    None => return Err(format!("Hata: '{}' fonksiyonunun IR'si bulunamadı.", name)),
+                };
+                let (body_asm, restore_asm) = self.generate_aarch64_function_body(&function, params)?;
+                let label = if name == "main" { "_start".to_string() } else { name.clone() };
+                text.push_str(&self.finish_aarch64_function(name, &label, &body_asm, &restore_asm)?);
+            }
+        }
+        text.push_str(&aarch64_builtins_library());
+
+        let mut full_asm = String::new();
+        full_asm.push_str(&self.generate_aarch64_data_segment());
+        full_asm.push_str(&text);
+        full_asm.push_str("\n.section .note.GNU-stack,\"\",@progbits\n");
+        Ok(full_asm)
+    }
+
+    fn generate_aarch64_data_segment(&self) -> String {
+        let mut asm = String::new();
+        asm.push_str(".section .data\n");
+        asm.push_str(".balign 8\n");
+        asm.push_str("_nim_argc: .quad 0\n");
+        asm.push_str("_nim_argv: .quad 0\n");
+        for (i, item) in self.data_items.iter().enumerate() {
+            match item {
+                DataItem::String(s) => asm.push_str(&format!("str_{}: .asciz \"{}\"\n", i, asm_string_literal(s))),
+                // ldr d, [x, :lo12:sym] 8 byte hizalı adres ister.
+                DataItem::Float64(f) => asm.push_str(&format!(".balign 8\nfloat_{}: .double {}\n", i, f)),
+            }
+        }
+        asm.push('\n');
+        asm
+    }
+
+    // Gövdeyi prologue ve epilogue ile sarar. Çerçeve: [x29] = eski x29, [x29 + 8] = dönüş adresi.
+    fn finish_aarch64_function(&self, name: &str, label: &str, body_asm: &str, restore_asm: &str) -> Result<String, String> {
+        let frame_size = (self.max_stack_pointer + 15) & !15;
+        if frame_size > super::MAX_FRAME_SIZE {
+            return Err(format!(
+                "Hata: '{}' fonksiyonunun yerel değişken alanı ({} byte) izin verilen {} byte sınırını aşıyor. Büyük diziler için global veya heap bellek kullanın.",
+                name,
+                frame_size,
+                super::MAX_FRAME_SIZE
+            ));
+        }
+
+        let mut asm = format!("{}:\n", label);
+        asm.push_str("    stp x29, x30, [sp, #-16]!\n");
+        asm.push_str("    mov x29, sp\n");
+        if frame_size > 0 {
+            if frame_size <= 4095 {
+                asm.push_str(&format!("    sub sp, sp, #{} // Çerçeve: yereller ve geçiciler\n", frame_size));
+            } else {
+                load_imm("x16", frame_size as i64, &mut asm);
+                asm.push_str("    sub sp, sp, x16 // Çerçeve: yereller ve geçiciler\n");
+            }
+        }
+        asm.push('\n');
+        asm.push_str(body_asm);
+
+        if name == "main" {
+            asm.push_str(&aarch64_exit_code(false));
+        } else {
+            asm.push_str(&format!(".fn_exit_{}:\n", name));
+            asm.push_str(restore_asm);
+            asm.push_str("    mov sp, x29\n");
+            asm.push_str("    ldp x29, x30, [sp], #16\n");
+            asm.push_str("    ret\n");
+        }
+        Ok(asm)
+    }
+
+    // main girişi: _start'ta stack'in tepesinde argc, ardından argv[0..argc] ve NULL durur.
+    // Prologue sonrası argc [x29 + 16]'da, argv dizisi x29 + 24'ten başlar.
+    fn generate_aarch64_main_entry(&mut self) -> (String, i32, i32) {
+        let mut code = String::new();
+        let argv_offset = self.alloc_stack(16);
+        let argc_offset = argv_offset - 8;
+        code.push_str("    ldr x9, [x29, #16]\n");
+        code.push_str(&format!("    str x9, [x29, #-{}] // Store argc\n", argc_offset));
+        load_address("x16", "_nim_argc", &mut code);
+        code.push_str("    str x9, [x16]\n");
+        code.push_str("    add x9, x29, #24\n");
+        code.push_str(&format!("    str x9, [x29, #-{}] // Store argv\n", argv_offset));
+        load_address("x16", "_nim_argv", &mut code);
+        code.push_str("    str x9, [x16]\n");
+        (code, argc_offset, argv_offset)
+    }
+
+    // Parametreleri AAPCS64'ün koyduğu yerden çerçeveye kopyalar (generate_param_stores'un karşılığı).
+    fn generate_aarch64_param_stores(&mut self, params: &[(String, Type, Option<Expr>)]) -> (String, Vec<i32>) {
+        let conv = CallConv::Aapcs64;
+        let mut code = String::new();
+        let mut offsets = Vec::new();
+        let classes: Vec<Vec<ArgClass>> = params.iter().map(|(_, p_ty, _)| self.arg_classes(p_ty)).collect();
+        let layout = conv.layout_args(&classes);
+        for (((p_name, _, _), placement), p_classes) in params.iter().zip(&layout.args).zip(&classes) {
+            let size = (p_classes.len().max(1) * 8) as i32;
+            let offset = self.alloc_stack(size);
+            offsets.push(offset);
+
+            if placement.by_reference {
+                // Büyük struct'ın kopyasının adresi geldi, yerel alana kopyala.
+                match placement.parts[0] {
+                    ArgLoc::IntReg(reg) => code.push_str(&format!("    mov x17, {}\n", reg)),
+                    ArgLoc::Stack(slot) => code.push_str(&format!("    ldr x17, [x29, #{}]\n", conv.stack_arg_base() + slot * 8)),
+                    ArgLoc::SseReg(_) => unreachable!(),
+                }
+                for j in 0..p_classes.len() {
+                    code.push_str(&format!("    ldr x9, [x17, #{}]\n", j * 8));
+                    let dest = frame_slot(offset - (j * 8) as i32, &mut code);
+                    code.push_str(&format!("    str x9, {} // Store parameter '{}'\n", dest, p_name));
+                }
+                continue;
+            }
+
+            for (j, part) in placement.parts.iter().enumerate() {
+                let source = match part {
+                    ArgLoc::IntReg(reg) | ArgLoc::SseReg(reg) => *reg,
+                    ArgLoc::Stack(slot) => {
+                        code.push_str(&format!("    ldr x9, [x29, #{}]\n", conv.stack_arg_base() + slot * 8));
+                        "x9"
+                    }
+                };
+                let dest = frame_slot(offset - (j * 8) as i32, &mut code);
+                code.push_str(&format!("    str {}, {} // Store parameter '{}'\n", source, dest, p_name));
+            }
+        }
+        (code, offsets)
+    }
+
+    // IR fonksiyonunun gövdesi ve epilogue'da çalışacak callee-saved geri yükleme kodu
+    // (generate_ir_function_body'nin AArch64 karşılığı).
+    fn generate_aarch64_function_body(&mut self, func: &Function, params: &[(String, Type, Option<Expr>)]) -> Result<(String, String), String> {
+        let mut code = String::new();
+        let allocation = regalloc::allocate(func, CallConv::Aapcs64, false);
+
+        let mut homes: Vec<Option<i32>> = vec![None; func.temps.len()];
+        let mut param_slots = Vec::new();
+        if let Some((argc, argv)) = func.entry_args {
+            let (entry_asm, argc_offset, argv_offset) = self.generate_aarch64_main_entry();
+            code.push_str(&entry_asm);
+            homes[argc.0 as usize] = Some(argc_offset);
+            homes[argv.0 as usize] = Some(argv_offset);
+        } else {
+            let (param_asm, offsets) = self.generate_aarch64_param_stores(params);
+            code.push_str(&param_asm);
+            for (param, offset) in func.params.iter().zip(offsets) {
+                match param {
+                    Param::Scalar(temp) => homes[temp.0 as usize] = Some(offset),
+                    Param::Struct { slot, .. } => param_slots.push((slot.0 as usize, offset)),
+                }
+            }
+        }
+
+        // main hiç dönmez; callee-saved register'ları saklamasına gerek yoktur.
+        let mut restore = String::new();
+        if func.entry_args.is_none() {
+            for reg in &allocation.callee_saved {
+                let offset = self.alloc_stack(8);
+                let slot = frame_slot(offset, &mut code);
+                code.push_str(&format!("    str {}, {}\n", reg, slot));
+                let slot = frame_slot(offset, &mut restore);
+                restore.push_str(&format!("    ldr {}, {}\n", reg, slot));
+            }
+        }
+
+        let mut locs = Vec::with_capacity(func.temps.len());
+        for (i, reg) in allocation.regs.iter().enumerate() {
+            locs.push(match (reg, homes[i]) {
+                (Some(reg), _) => Loc::Reg(reg),
+                (None, Some(home)) => Loc::Stack(home),
+                (None, None) => Loc::Stack(self.alloc_stack(8)),
+            });
+        }
+        let mut slots = Vec::new();
+        for (i, slot) in func.slots.iter().enumerate() {
+            match param_slots.iter().find(|(s, _)| *s == i) {
+                Some((_, offset)) => slots.push(*offset),
+                None => slots.push(self.alloc_stack(slot.size)),
+            }
+        }
+        let mut uses = vec![0; func.temps.len()];
+        for block in &func.blocks {
+            for temp in block.insts.iter().flat_map(Inst::uses).chain(block.term.uses()) {
+                uses[temp.0 as usize] += 1;
+            }
+        }
+        let frame = Frame { locs, slots, uses };
+
+        let assigned: Vec<String> = allocation
+            .regs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, reg)| reg.map(|reg| format!("{}={}", Temp(i as u32), reg)))
+            .collect();
+        if !assigned.is_empty() {
+            code.push_str(&format!("    // Register ataması: {}\n", assigned.join(" ")));
+        }
+
+        for (i, home) in homes.iter().enumerate() {
+            if frame.uses[i] == 0 {
+                continue;
+            }
+            if let (Some(home), Loc::Reg(reg)) = (home, frame.locs[i]) {
+                let slot = frame_slot(*home, &mut code);
+                code.push_str(&format!("    ldr {}, {}\n", reg, slot));
+            }
+        }
+
+        for (i, block) in func.blocks.iter().enumerate() {
+            code.push_str(&format!("{}:\n", aarch64_block_label(func, BlockId(i as u32))));
+            let next = BlockId(i as u32 + 1);
+
+            // Sonucu yalnızca dallanmada okunan karşılaştırma, cset yerine doğrudan b.cond olur.
+            let fused = match (block.insts.last(), &block.term) {
+                (Some(Inst::Cmp { dst, op, lhs, rhs }), Terminator::Branch { cond: Operand::Temp(cond), then_block, else_block })
+                    if cond == dst && frame.uses[dst.0 as usize] == 1 =>
+                {
+                    Some((*op, lhs, rhs, *then_block, *else_block))
+                }
+                _ => None,
+            };
+            let count = block.insts.len() - usize::from(fused.is_some());
+            for inst in &block.insts[..count] {
+                if let Inst::SourceLine(_) = inst {
+                    continue;
+                }
+                code.push_str(&format!("    // {}\n", inst));
+                self.emit_aarch64_inst(func, &frame, inst, &mut code)?;
+            }
+            match fused {
+                Some((op, lhs, rhs, then_block, else_block)) => {
+                    code.push_str(&format!("    // {}\n", block.insts[count]));
+                    self.emit_aarch64_compare(&frame, lhs, rhs, &mut code);
+                    emit_aarch64_branch(func, condition_codes(op), then_block, else_block, next, &mut code);
+                }
+                None => self.emit_aarch64_terminator(func, &frame, &block.term, next, &mut code),
+            }
+        }
+        Ok((code, restore))
+    }
+
+    // İşleneni 64 bitlik tamsayı register'ına yükler (float'lar ham bitleriyle taşınır).
+    fn load_aarch64_int(&mut self, frame: &Frame, op: &Operand, reg: &str, code: &mut String) {
+        match op {
+            Operand::Temp(t) => match frame.loc(*t) {
+                Loc::Reg(src) if src == reg => {}
+                Loc::Reg(src) if is_fp(src) => code.push_str(&format!("    fmov {}, {}\n", reg, src)),
+                Loc::Reg(src) => code.push_str(&format!("    mov {}, {}\n", reg, src)),
+                Loc::Stack(offset) => {
+                    let slot = frame_slot(offset, code);
+                    code.push_str(&format!("    ldr {}, {}\n", reg, slot));
+                }
+            },
+            Operand::Int(v) => load_imm(reg, *v, code),
+            Operand::Float(v) => load_imm(reg, v.to_bits() as i64, code),
+            Operand::Str(s) => {
+                let index = self.add_string_literal(s.clone());
+                load_address(reg, &format!("str_{}", index), code);
+            }
+        }
+    }
+
+    fn load_aarch64_float(&mut self, frame: &Frame, op: &Operand, reg: &str, code: &mut String) {
+        match op {
+            Operand::Temp(t) => match frame.loc(*t) {
+                Loc::Reg(src) if src == reg => {}
+                Loc::Reg(src) => code.push_str(&format!("    fmov {}, {}\n", reg, src)),
+                Loc::Stack(offset) => {
+                    let slot = frame_slot(offset, code);
+                    code.push_str(&format!("    ldr {}, {}\n", reg, slot));
+                }
+            },
+            Operand::Float(v) if v.to_bits() == 0 => code.push_str(&format!("    fmov {}, xzr\n", reg)),
+            Operand::Float(v) => {
+                let index = self.add_data_item(DataItem::Float64(*v));
+                code.push_str(&format!("    adrp x16, float_{}\n    ldr {}, [x16, :lo12:float_{}]\n", index, reg, index));
+            }
+            _ => {
+                self.load_aarch64_int(frame, op, "x9", code);
+                code.push_str(&format!("    fmov {}, x9\n", reg));
+            }
+        }
+    }
+
+    // Tamsayı işleneninin bulunduğu register; register'da değilse `scratch`'e yüklenir.
+    fn aarch64_int_reg(&mut self, frame: &Frame, op: &Operand, scratch: &'static str, code: &mut String) -> &'static str {
+        match op {
+            Operand::Temp(t) => match frame.loc(*t) {
+                Loc::Reg(reg) if !is_fp(reg) => reg,
+                _ => {
+                    self.load_aarch64_int(frame, op, scratch, code);
+                    scratch
+                }
+            },
+            _ => {
+                self.load_aarch64_int(frame, op, scratch, code);
+                scratch
+            }
+        }
+    }
+
+    // Belleğe yazılacak değerin register'ı: register'daki geçiciler (float'lar dahil) olduğu gibi,
+    // sıfır xzr ile, diğerleri x9 üzerinden. (xzr anlık değerli komutlarda sp anlamına geldiği için
+    // yalnızca str'de kullanılır.)
+    fn aarch64_value_reg(&mut self, frame: &Frame, op: &Operand, code: &mut String) -> &'static str {
+        match op {
+            Operand::Temp(t) => match frame.loc(*t) {
+                Loc::Reg(reg) => reg,
+                Loc::Stack(_) => self.aarch64_int_reg(frame, op, "x9", code),
+            },
+            Operand::Int(0) => "xzr",
+            _ => self.aarch64_int_reg(frame, op, "x9", code),
+        }
+    }
+
+    fn aarch64_float_reg(&mut self, frame: &Frame, op: &Operand, scratch: &'static str, code: &mut String) -> &'static str {
+        match op {
+            Operand::Temp(t) => match frame.loc(*t) {
+                Loc::Reg(reg) if is_fp(reg) => reg,
+                _ => {
+                    self.load_aarch64_float(frame, op, scratch, code);
+                    scratch
+                }
+            },
+            _ => {
+                self.load_aarch64_float(frame, op, scratch, code);
+                scratch
+            }
+        }
+    }
+
+    // `reg`'deki değeri geçicinin yerine yazar.
+    fn store_aarch64(&self, frame: &Frame, dst: Temp, reg: &str, code: &mut String) {
+        match frame.loc(dst) {
+            Loc::Reg(target) if target == reg => {}
+            Loc::Reg(target) if is_fp(target) || is_fp(reg) => code.push_str(&format!("    fmov {}, {}\n", target, reg)),
+            Loc::Reg(target) => code.push_str(&format!("    mov {}, {}\n", target, reg)),
+            Loc::Stack(offset) => {
+                let slot = frame_slot(offset, code);
+                code.push_str(&format!("    str {}, {}\n", reg, slot));
+            }
+        }
+    }
+
+    // Adresi (taban register'ı, ofset) çiftine indirger. Sembol ve stack'teki temp tabanları x16'ya,
+    // register'da olmayan indeks x17'ye yüklenir; indeksli adresler x16'da toplanır.
+    fn aarch64_address(&mut self, frame: &Frame, addr: &Address, code: &mut String) -> (&'static str, i64) {
+        let mut offset = addr.offset as i64;
+        let mut base = match &addr.base {
+            Base::Slot(slot) => {
+                offset -= frame.slots[slot.0 as usize] as i64;
+                "x29"
+            }
+            Base::Temp(t) => self.aarch64_int_reg(frame, &Operand::Temp(*t), "x16", code),
+            Base::Symbol(name) => {
+                load_address("x16", name, code);
+                "x16"
+            }
+        };
+        match &addr.index {
+            Some(Operand::Int(v)) => offset += v * 8,
+            Some(op) => {
+                let index = self.aarch64_int_reg(frame, op, "x17", code);
+                code.push_str(&format!("    add x16, {}, {}, lsl #3\n", base, index));
+                base = "x16";
+            }
+            None => {}
+        }
+        (base, offset)
+    }
+
+    fn aarch64_memory_operand(&mut self, frame: &Frame, addr: &Address, code: &mut String) -> String {
+        let (base, offset) = self.aarch64_address(frame, addr, code);
+        memory(base, offset, code)
+    }
+
+    fn emit_aarch64_compare(&mut self, frame: &Frame, lhs: &Operand, rhs: &Operand, code: &mut String) {
+        let lhs = self.aarch64_int_reg(frame, lhs, "x9", code);
+        match rhs {
+            Operand::Int(v) if (0..=4095).contains(v) => code.push_str(&format!("    cmp {}, #{}\n", lhs, v)),
+            Operand::Int(v) if (-4095..0).contains(v) => code.push_str(&format!("    cmn {}, #{}\n", lhs, -v)),
+            _ => {
+                let rhs = self.aarch64_int_reg(frame, rhs, "x10", code);
+                code.push_str(&format!("    cmp {}, {}\n", lhs, rhs));
+            }
+        }
+    }
+
+    fn emit_aarch64_inst(&mut self, func: &Function, frame: &Frame, inst: &Inst, code: &mut String) -> Result<(), String> {
+        match inst {
+            Inst::Copy { dst, src } => match frame.loc(*dst) {
+                Loc::Reg(reg) if is_fp(reg) => self.load_aarch64_float(frame, src, reg, code),
+                Loc::Reg(reg) => self.load_aarch64_int(frame, src, reg, code),
+                Loc::Stack(_) => {
+                    let reg = self.aarch64_value_reg(frame, src, code);
+                    self.store_aarch64(frame, *dst, reg, code);
+                }
+            },
+            Inst::Binary { dst, op, lhs, rhs } if func.temp_type(*dst) == IrType::F64 => {
+                let mnemonic = match op {
+                    BinaryOp::Add => "fadd",
+                    BinaryOp::Sub => "fsub",
+                    BinaryOp::Mul => "fmul",
+                    BinaryOp::Div => "fdiv",
+                    BinaryOp::Rem => return Err("Float mod IR'de _fmod çağrısı olmalı.".to_string()),
+                    _ => return Err(format!("'{}' float üzerinde tanımsız.", op)),
+                };
+                let target = frame.float_target(*dst);
+                let lhs = self.aarch64_float_reg(frame, lhs, "d0", code);
+                let rhs = self.aarch64_float_reg(frame, rhs, "d1", code);
+                code.push_str(&format!("    {} {}, {}, {}\n", mnemonic, target, lhs, rhs));
+                self.store_aarch64(frame, *dst, target, code);
+            }
+            Inst::Binary { dst, op, lhs, rhs } => {
+                let target = frame.int_target(*dst);
+                let lhs = self.aarch64_int_reg(frame, lhs, "x9", code);
+                match (op, rhs) {
+                    (BinaryOp::Add | BinaryOp::Sub, Operand::Int(v)) if (-4095..=4095).contains(v) => {
+                        let negate = (*op == BinaryOp::Sub) != (*v < 0);
+                        let mnemonic = if negate { "sub" } else { "add" };
+                        code.push_str(&format!("    {} {}, {}, #{}\n", mnemonic, target, lhs, v.abs()));
+                    }
+                    _ => {
+                        let rhs = self.aarch64_int_reg(frame, rhs, "x10", code);
+                        match op {
+                            BinaryOp::Add => code.push_str(&format!("    add {}, {}, {}\n", target, lhs, rhs)),
+                            BinaryOp::Sub => code.push_str(&format!("    sub {}, {}, {}\n", target, lhs, rhs)),
+                            BinaryOp::Mul => code.push_str(&format!("    mul {}, {}, {}\n", target, lhs, rhs)),
+                            BinaryOp::Div => code.push_str(&format!("    sdiv {}, {}, {}\n", target, lhs, rhs)),
+                            BinaryOp::Rem => {
+                                code.push_str(&format!("    sdiv x17, {}, {}\n", lhs, rhs));
+                                code.push_str(&format!("    msub {}, x17, {}, {}\n", target, rhs, lhs));
+                            }
+                            // lslv/asrv kaydırma miktarını 64'e göre alır.
+                            BinaryOp::And => code.push_str(&format!("    and {}, {}, {}\n", target, lhs, rhs)),
+                            BinaryOp::Or => code.push_str(&format!("    orr {}, {}, {}\n", target, lhs, rhs)),
+                            BinaryOp::Xor => code.push_str(&format!("    eor {}, {}, {}\n", target, lhs, rhs)),
+                            BinaryOp::Shl => code.push_str(&format!("    lsl {}, {}, {}\n", target, lhs, rhs)),
+                            BinaryOp::Shr => code.push_str(&format!("    asr {}, {}, {}\n", target, lhs, rhs)),
+                        }
+                    }
+                }
+                self.store_aarch64(frame, *dst, target, code);
+            }
+            Inst::Cmp { dst, op, lhs, rhs } => {
+                self.emit_aarch64_compare(frame, lhs, rhs, code);
+                let target = frame.int_target(*dst);
+                code.push_str(&format!("    cset {}, {}\n", target, condition_codes(*op).0));
+                self.store_aarch64(frame, *dst, target, code);
+            }
+            Inst::Unary { dst, op, src } => {
+                let target = frame.int_target(*dst);
+                let src = self.aarch64_int_reg(frame, src, "x9", code);
+                match op {
+                    UnaryOp::Neg => code.push_str(&format!("    neg {}, {}\n", target, src)),
+                    UnaryOp::Not => code.push_str(&format!("    cmp {}, #0\n    cset {}, eq\n", src, target)),
+                }
+                self.store_aarch64(frame, *dst, target, code);
+            }
+            Inst::Convert { dst, op: ConvertOp::IntToFloat, src } => {
+                let target = frame.float_target(*dst);
+                let src = self.aarch64_int_reg(frame, src, "x9", code);
+                code.push_str(&format!("    scvtf {}, {}\n", target, src));
+                self.store_aarch64(frame, *dst, target, code);
+            }
+            Inst::Convert { dst, op: ConvertOp::FloatToInt, src } => {
+                let target = frame.int_target(*dst);
+                let src = self.aarch64_float_reg(frame, src, "d0", code);
+                code.push_str(&format!("    fcvtzs {}, {}\n", target, src));
+                self.store_aarch64(frame, *dst, target, code);
+            }
+            Inst::Load { dst, addr } => {
+                let target = match frame.loc(*dst) {
+                    Loc::Reg(reg) => reg,
+                    Loc::Stack(_) => "x9",
+                };
+                let mem = self.aarch64_memory_operand(frame, addr, code);
+                code.push_str(&format!("    ldr {}, {}\n", target, mem));
+                self.store_aarch64(frame, *dst, target, code);
+            }
+            Inst::Store { addr, src } => {
+                let value = self.aarch64_value_reg(frame, src, code);
+                let mem = self.aarch64_memory_operand(frame, addr, code);
+                code.push_str(&format!("    str {}, {}\n", value, mem));
+            }
+            Inst::AddrOf { dst, addr } => {
+                let target = frame.int_target(*dst);
+                let (base, offset) = self.aarch64_address(frame, addr, code);
+                add_offset(target, base, offset, code);
+                self.store_aarch64(frame, *dst, target, code);
+            }
+            Inst::Zero { slot } => {
+                let words = func.slots[slot.0 as usize].size / 8;
+                let offset = frame.slots[slot.0 as usize];
+                if words <= 4 {
+                    for word in 0..words {
+                        let mem = frame_slot(offset - word * 8, code);
+                        code.push_str(&format!("    str xzr, {}\n", mem));
+                    }
+                } else {
+                    let label = format!(".L{}", self.generate_label("zero"));
+                    add_offset("x16", "x29", -(offset as i64), code);
+                    load_imm("x17", words as i64, code);
+                    code.push_str(&format!("{}:\n", label));
+                    code.push_str("    str xzr, [x16], #8\n");
+                    code.push_str("    subs x17, x17, #1\n");
+                    code.push_str(&format!("    b.ne {}\n", label));
+                }
+            }
+            Inst::Call { dst, callee, args, .. } => {
+                let saved_stack_pointer = self.stack_pointer;
+                let mut evaluated = Vec::new();
+                for arg in args {
+                    evaluated.push(self.evaluate_aarch64_call_arg(frame, arg, code));
+                }
+                // Linux AAPCS64'te variadic argümanlar da sabit argümanlar gibi geçirilir.
+                code.push_str(&self.emit_aarch64_call(callee, &evaluated));
+                self.stack_pointer = saved_stack_pointer;
+                match dst {
+                    Some(dst) if func.temp_type(*dst) == IrType::F64 => self.store_aarch64(frame, *dst, "d0", code),
+                    Some(dst) => self.store_aarch64(frame, *dst, "x0", code),
+                    None => {}
+                }
+            }
+            Inst::Asm { body, operands, span } => {
+                if let Some(register) = x86_register(body) {
+                    self.error_span = Some(*span);
+                    return Err(format!(
+                        "Hata: '{}' fonksiyonundaki asm bloğu x86-64 kodu içeriyor ('{}' register'ı) ve AArch64 hedefinde derlenemez. Bloğu `#ifdef ARCH_X86_64` ile koşullu derleyin.",
+                        func.name, register
+                    ));
+                }
+                let lines = expand_asm_body(body, |name| match operands.iter().find(|(n, _)| n == name) {
+                    Some((_, AsmOperand::Temp(t))) => match frame.loc(*t) {
+                        Loc::Stack(offset) => format!("[x29, #-{}]", offset),
+                        Loc::Reg(reg) => reg.to_string(),
+                    },
+                    Some((_, AsmOperand::Slot(slot))) => format!("[x29, #-{}]", frame.slots[slot.0 as usize]),
+                    None => format!("%{}", name),
+                });
+                for line in lines {
+                    code.push_str(&format!("    {}\n", line));
+                }
+            }
+            Inst::SourceLine(_) => {}
+        }
+        Ok(())
+    }
+
+    // Çağrı argümanını çerçevede çağrı süresince yaşayan bir yuvaya yazar (evaluate_ir_call_arg'ın
+    // AArch64 karşılığı); argüman register'ları yüklenirken birbirlerini ezmezler.
+    fn evaluate_aarch64_call_arg(&mut self, frame: &Frame, arg: &CallArg, code: &mut String) -> EvaluatedArg {
+        match arg {
+            CallArg::Value(op, ty) => {
+                let classes = vec![abi::classify_scalar(&scalar_type(*ty))];
+                if let Operand::Temp(t) = op {
+                    if let Loc::Stack(offset) = frame.loc(*t) {
+                        return EvaluatedArg { offset, classes, is_address: false };
+                    }
+                }
+                let value = self.aarch64_value_reg(frame, op, code);
+                let offset = self.alloc_stack(8);
+                let slot = frame_slot(offset, code);
+                code.push_str(&format!("    str {}, {}\n", value, slot));
+                EvaluatedArg { offset, classes, is_address: false }
+            }
+            CallArg::Struct { addr, name } => {
+                let (base, offset) = self.aarch64_address(frame, addr, code);
+                add_offset("x9", base, offset, code);
+                let slot_offset = self.alloc_stack(8);
+                let slot = frame_slot(slot_offset, code);
+                code.push_str(&format!("    str x9, {}\n", slot));
+                let classes: Vec<ArgClass> = self.arg_classes(&Type::Custom(name.clone()));
+                EvaluatedArg { offset: slot_offset, classes, is_address: true }
+            }
+        }
+    }
+
+    // Değerlendirilmiş argümanları AAPCS64'e göre yerleştirip `target`'ı çağırır. Stack argümanları
+    // için 16 byte'a yuvarlanmış bir alan ayrılır ve çağrıdan sonra geri verilir.
+    fn emit_aarch64_call(&mut self, target: &str, args: &[EvaluatedArg]) -> String {
+        let conv = CallConv::Aapcs64;
+        let mut code = String::new();
+        let classes: Vec<Vec<ArgClass>> = args.iter().map(|a| a.classes.clone()).collect();
+        let layout = conv.layout_args(&classes);
+
+        // Büyük struct'lar için çağıran bir kopya oluşturur ve adresini geçirir.
+        let mut copies = Vec::new();
+        for (arg, placement) in args.iter().zip(&layout.args) {
+            if placement.by_reference {
+                let copy_offset = self.alloc_stack((arg.classes.len().max(1) * 8) as i32);
+                let slot = frame_slot(arg.offset, &mut code);
+                code.push_str(&format!("    ldr x17, {}\n", slot));
+                for j in 0..arg.classes.len() {
+                    code.push_str(&format!("    ldr x9, [x17, #{}]\n", j * 8));
+                    let dest = frame_slot(copy_offset - (j * 8) as i32, &mut code);
+                    code.push_str(&format!("    str x9, {}\n", dest));
+                }
+                copies.push(Some(copy_offset));
+            } else {
+                copies.push(None);
+            }
+        }
+
+        // Parçanın değerini `reg`'e yükler.
+        let load_part = |code: &mut String, i: usize, j: usize, reg: &str| {
+            let arg = &args[i];
+            if let Some(copy_offset) = copies[i] {
+                add_offset(reg, "x29", -(copy_offset as i64), code);
+            } else if arg.is_address {
+                let slot = frame_slot(arg.offset, code);
+                code.push_str(&format!("    ldr x17, {}\n", slot));
+                code.push_str(&format!("    ldr {}, [x17, #{}]\n", reg, j * 8));
+            } else {
+                let slot = frame_slot(arg.offset, code);
+                code.push_str(&format!("    ldr {}, {}\n", reg, slot));
+            }
+        };
+
+        let stack_bytes = (layout.stack_slots * 8).next_multiple_of(16);
+        if stack_bytes > 0 {
+            code.push_str(&format!("    sub sp, sp, #{} // Stack argümanları\n", stack_bytes));
+        }
+        for (i, placement) in layout.args.iter().enumerate() {
+            for (j, part) in placement.parts.iter().enumerate() {
+                if let ArgLoc::Stack(slot) = part {
+                    load_part(&mut code, i, j, "x9");
+                    code.push_str(&format!("    str x9, [sp, #{}]\n", slot * 8));
+                }
+            }
+        }
+        for (i, placement) in layout.args.iter().enumerate() {
+            for (j, part) in placement.parts.iter().enumerate() {
+                match part {
+                    ArgLoc::IntReg(reg) | ArgLoc::SseReg(reg) => load_part(&mut code, i, j, reg),
+                    ArgLoc::Stack(_) => {}
+                }
+            }
+        }
+
+        code.push_str(&format!("    bl {}\n", target));
+        if stack_bytes > 0 {
+            code.push_str(&format!("    add sp, sp, #{} // Cleanup\n", stack_bytes));
+        }
+        code
+    }
+
+    fn emit_aarch64_terminator(&mut self, func: &Function, frame: &Frame, term: &Terminator, next: BlockId, code: &mut String) {
+        match term {
+            Terminator::Jump(target) => {
+                if *target != next {
+                    code.push_str(&format!("    b {}\n", aarch64_block_label(func, *target)));
+                }
+            }
+            Terminator::Branch { cond, then_block, else_block } => {
+                let value = self.aarch64_int_reg(frame, cond, "x9", code);
+                if *else_block == next {
+                    code.push_str(&format!("    cbnz {}, {}\n", value, aarch64_block_label(func, *then_block)));
+                } else {
+                    code.push_str(&format!("    cbz {}, {}\n", value, aarch64_block_label(func, *else_block)));
+                    if *then_block != next {
+                        code.push_str(&format!("    b {}\n", aarch64_block_label(func, *then_block)));
+                    }
+                }
+            }
+            Terminator::Return(value) if func.entry_args.is_some() => {
+                // main'den dönüş süreci sonlandırır; değer çıkış kodudur.
+                if let Some(value) = value {
+                    self.load_aarch64_int(frame, value, "x0", code);
+                }
+                code.push_str(&aarch64_exit_code(value.is_some()));
+            }
+            Terminator::Return(value) => {
+                match (value, func.ret) {
+                    (Some(value), Some(IrType::F64)) => self.load_aarch64_float(frame, value, "d0", code),
+                    (Some(value), _) => self.load_aarch64_int(frame, value, "x0", code),
+                    (None, _) => {}
+                }
+                code.push_str(&format!("    b .fn_exit_{}\n", func.name));
+            }
+            Terminator::Unreachable => {}
+        }
+    }
+}
+
+fn aarch64_block_label(func: &Function, block: BlockId) -> String {
+    format!(".L{}_{}", func.name, block)
+}
+
+// Koşul kodu `codes.0` doğruysa `then_block`'a, değilse `else_block`'a atlar.
+fn emit_aarch64_branch(func: &Function, codes: (&str, &str), then_block: BlockId, else_block: BlockId, next: BlockId, code: &mut String) {
+    let (taken, inverse) = codes;
+    if else_block == next {
+        code.push_str(&format!("    b.{} {}\n", taken, aarch64_block_label(func, then_block)));
+        return;
+    }
+    code.push_str(&format!("    b.{} {}\n", inverse, aarch64_block_label(func, else_block)));
+    if then_block != next {
+        code.push_str(&format!("    b {}\n", aarch64_block_label(func, then_block)));
+    }
+}
+
+// Süreci exit_group ile sonlandırır. `status_in_x0` true ise çıkış kodu x0'dadır.
+fn aarch64_exit_code(status_in_x0: bool) -> String {
+    let mut code = String::new();
+    if !status_in_x0 {
+        code.push_str("    mov x0, #0 // çıkış kodu 0\n");
+    }
+    code.push_str(&format!("    mov x8, #{} // exit_group için syscall numarası\n    svc #0\n", SYS_EXIT_GROUP));
+    code
+}
+
+// Dahili yardımcı rutinler (_atoi, _itoa, _ftoa); x86-64'teki generate_builtins_library'nin karşılığı.
+fn aarch64_builtins_library() -> String {
+    let mut lib = String::new();
+    lib.push_str("\n// --- Built-in Helpers ---\n");
+    lib.push_str(".section .data\n");
+    lib.push_str("_conv_buffer: .space 1024\n");
+    lib.push_str("_fmt_float_str: .asciz \"%f\"\n");
+    lib.push_str(".text\n");
+
+    // _atoi: x0 = string -> x0 = integer
+    lib.push_str("_atoi:\n");
+    lib.push_str("    mov x9, #0\n    mov x11, #1\n    mov x12, #10\n");
+    lib.push_str("    ldrb w10, [x0]\n    cmp w10, #45 // '-'\n    b.ne .Latoi_loop\n");
+    lib.push_str("    mov x11, #-1\n    add x0, x0, #1\n");
+    lib.push_str(".Latoi_loop:\n    ldrb w10, [x0], #1\n    sub w10, w10, #48 // '0'\n    cmp w10, #9\n    b.hi .Latoi_done\n");
+    lib.push_str("    madd x9, x9, x12, x10\n    b .Latoi_loop\n");
+    lib.push_str(".Latoi_done:\n    mul x0, x9, x11\n    ret\n\n");
+
+    // _itoa: x0 = integer -> x0 = string pointer (geçici buffer)
+    lib.push_str("_itoa:\n");
+    lib.push_str("    adrp x9, _conv_buffer\n    add x9, x9, :lo12:_conv_buffer\n    add x9, x9, #64\n    strb wzr, [x9]\n");
+    lib.push_str("    cmp x0, #0\n    cneg x10, x0, lt\n    mov x12, #10\n");
+    lib.push_str(".Litoa_loop:\n    udiv x13, x10, x12\n    msub x14, x13, x12, x10\n    add x14, x14, #48\n");
+    lib.push_str("    strb w14, [x9, #-1]!\n    mov x10, x13\n    cbnz x10, .Litoa_loop\n");
+    lib.push_str("    cmp x0, #0\n    b.ge .Litoa_done\n    mov x14, #45\n    strb w14, [x9, #-1]!\n");
+    lib.push_str(".Litoa_done:\n    mov x0, x9\n    ret\n\n");
+
+    // _ftoa: d0 = float -> x0 = string pointer; _sprint(buffer, "%f", d0)
+    lib.push_str("_ftoa:\n");
+    lib.push_str("    stp x29, x30, [sp, #-16]!\n    mov x29, sp\n");
+    lib.push_str("    adrp x0, _conv_buffer\n    add x0, x0, :lo12:_conv_buffer\n");
+    lib.push_str("    adrp x1, _fmt_float_str\n    add x1, x1, :lo12:_fmt_float_str\n");
+    lib.push_str("    bl _sprint\n");
+    lib.push_str("    adrp x0, _conv_buffer\n    add x0, x0, :lo12:_conv_buffer\n");
+    lib.push_str("    ldp x29, x30, [sp], #16\n    ret\n");
+    lib
+}
@@ -0,0 +1,272 @@
+// src/codegen/abi.rs
+//
+// Çağrı kuralları (calling convention) katmanı.
+// Codegen hangi register'a hangi argümanın gideceğini, gölge alanı (shadow space),
+// stack argümanlarının nerede başladığını ve struct'ların nasıl geçirileceğini
+// buradan öğrenir. x86-64'te Windows hedefi Win64 ABI'sini, diğer tüm hedefler
+// System V AMD64 ABI'sini kullanır; AArch64 hedefi AAPCS64'ü kullanır.
+
+use crate::ast::{TargetPlatform, Type};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallConv {
+    Win64,
+    SysV,
+    Aapcs64,
+}
+
+// Bir argümanın 8 byte'lık parçasının (eightbyte) sınıfı.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgClass {
+    Integer,
+    Sse,
+    Memory,
+}
+
+// Argüman parçasının çağrı anındaki yeri.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArgLoc {
+    IntReg(&'static str),
+    SseReg(&'static str),
+    Stack(usize), // Stack argüman alanındaki 8 byte'lık slot indeksi
+}
+
+// Tek bir argümanın yerleşimi.
+// `by_reference` true ise (Win64 büyük struct) argümanın kendisi değil,
+// kopyasının adresi tek bir tamsayı parçası olarak geçirilir.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArgPlacement {
+    pub parts: Vec<ArgLoc>,
+    pub by_reference: bool,
+}
+
+// Bir çağrının tüm argümanlarının yerleşimi.
+#[derive(Debug, Clone)]
+pub struct CallLayout {
+    pub args: Vec<ArgPlacement>,
+    pub stack_slots: usize,
+    pub sse_regs_used: usize,
+}
+
+const WIN64_INT_REGS: [&str; 4] = ["rcx", "rdx", "r8", "r9"];
+const WIN64_SSE_REGS: [&str; 4] = ["xmm0", "xmm1", "xmm2", "xmm3"];
+const SYSV_INT_REGS: [&str; 6] = ["rdi", "rsi", "rdx", "rcx", "r8", "r9"];
+const SYSV_SSE_REGS: [&str; 8] = ["xmm0", "xmm1", "xmm2", "xmm3", "xmm4", "xmm5", "xmm6", "xmm7"];
+const AAPCS64_INT_REGS: [&str; 8] = ["x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7"];
+const AAPCS64_FP_REGS: [&str; 8] = ["d0", "d1", "d2", "d3", "d4", "d5", "d6", "d7"];
+
+// Register atayıcının dağıttığı register'lar. rax, rcx, rdx, r11, xmm0 ve xmm1 IR kod üreticisinin
+// geçici register'larıdır ve hiçbir listede yer almaz.
+const WIN64_ALLOC_INT_CALLER: [&str; 3] = ["r8", "r9", "r10"];
+const WIN64_ALLOC_INT_CALLEE: [&str; 7] = ["rbx", "rsi", "rdi", "r12", "r13", "r14", "r15"];
+const WIN64_ALLOC_SSE_CALLER: [&str; 4] = ["xmm2", "xmm3", "xmm4", "xmm5"];
+const WIN64_ALLOC_SSE_CALLEE: [&str; 10] = ["xmm6", "xmm7", "xmm8", "xmm9", "xmm10", "xmm11", "xmm12", "xmm13", "xmm14", "xmm15"];
+const SYSV_ALLOC_INT_CALLER: [&str; 5] = ["rsi", "rdi", "r8", "r9", "r10"];
+const SYSV_ALLOC_INT_CALLEE: [&str; 5] = ["rbx", "r12", "r13", "r14", "r15"];
+const SYSV_ALLOC_SSE_CALLER: [&str; 14] = [
+    "xmm2", "xmm3", "xmm4", "xmm5", "xmm6", "xmm7", "xmm8", "xmm9", "xmm10", "xmm11", "xmm12", "xmm13", "xmm14", "xmm15",
+];
+// AArch64: x0, x9, x10, x16, x17, d0, d1, d16 ve d17 kod üreticisinin geçici register'larıdır; x18
+// (platform register'ı), x29 (çerçeve) ve x30 (dönüş adresi) hiç kullanılmaz.
+const AAPCS64_ALLOC_INT_CALLER: [&str; 13] = ["x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x11", "x12", "x13", "x14", "x15"];
+const AAPCS64_ALLOC_INT_CALLEE: [&str; 10] = ["x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27", "x28"];
+const AAPCS64_ALLOC_FP_CALLER: [&str; 20] = [
+    "d2", "d3", "d4", "d5", "d6", "d7", "d18", "d19", "d20", "d21", "d22", "d23", "d24", "d25", "d26", "d27", "d28", "d29", "d30", "d31",
+];
+// v8-v15'in yalnızca alt 64 biti korunur; f64 değerleri için bu yeterlidir.
+const AAPCS64_ALLOC_FP_CALLEE: [&str; 8] = ["d8", "d9", "d10", "d11", "d12", "d13", "d14", "d15"];
+
+impl CallConv {
+    pub fn for_target(target: TargetPlatform) -> Self {
+        match target {
+            TargetPlatform::Windows => CallConv::Win64,
+            _ => CallConv::SysV,
+        }
+    }
+
+    pub fn int_arg_regs(self) -> &'static [&'static str] {
+        match self {
+            CallConv::Win64 => &WIN64_INT_REGS,
+            CallConv::SysV => &SYSV_INT_REGS,
+            CallConv::Aapcs64 => &AAPCS64_INT_REGS,
+        }
+    }
+
+    // Çağıranın call'dan hemen önce ayırması gereken gölge alan.
+    pub fn shadow_space(self) -> usize {
+        match self {
+            CallConv::Win64 => 32,
+            CallConv::SysV | CallConv::Aapcs64 => 0,
+        }
+    }
+
+    // RSP'nin altında, sinyal/kesme işleyicilerin dokunmadığı alan.
+    // Win64'te böyle bir alan yoktur; RSP altındaki her şey her an bozulabilir.
+    pub fn red_zone(self) -> usize {
+        match self {
+            CallConv::Win64 | CallConv::Aapcs64 => 0,
+            CallConv::SysV => 128,
+        }
+    }
+
+    // `push rbp; mov rbp, rsp` (AArch64: `stp x29, x30, [sp, -16]!; mov x29, sp`) sonrası ilk stack
+    // argümanının çerçeve register'ına göre ofseti.
+    // [rbp] = eski rbp, [rbp + 8] = dönüş adresi, sonra (varsa) gölge alan gelir.
+    pub fn stack_arg_base(self) -> usize {
+        16 + self.shadow_space()
+    }
+
+    // Variadic çağrılarda float argümanların tamsayı register'ına da kopyalanması (Win64).
+    pub fn variadic_floats_in_int_regs(self) -> bool {
+        self == CallConv::Win64
+    }
+
+    // Variadic çağrıdan önce AL'ye kullanılan vektör register sayısı yazılmalı mı (SysV).
+    pub fn needs_sse_count_in_al(self) -> bool {
+        self == CallConv::SysV
+    }
+
+    // Atanabilir tamsayı register'ları: (çağrıda bozulanlar, çağrılanın koruması gerekenler).
+    pub fn allocatable_int_regs(self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            CallConv::Win64 => (&WIN64_ALLOC_INT_CALLER, &WIN64_ALLOC_INT_CALLEE),
+            CallConv::SysV => (&SYSV_ALLOC_INT_CALLER, &SYSV_ALLOC_INT_CALLEE),
+            CallConv::Aapcs64 => (&AAPCS64_ALLOC_INT_CALLER, &AAPCS64_ALLOC_INT_CALLEE),
+        }
+    }
+
+    // Atanabilir SSE register'ları. SysV'de tüm XMM register'ları çağrıda bozulur.
+    pub fn allocatable_sse_regs(self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            CallConv::Win64 => (&WIN64_ALLOC_SSE_CALLER, &WIN64_ALLOC_SSE_CALLEE),
+            CallConv::SysV => (&SYSV_ALLOC_SSE_CALLER, &[]),
+            CallConv::Aapcs64 => (&AAPCS64_ALLOC_FP_CALLER, &AAPCS64_ALLOC_FP_CALLEE),
+        }
+    }
+
+    // Argümanları (her biri eightbyte sınıfları listesi) register/stack slotlarına yerleştirir.
+    pub fn layout_args(self, args: &[Vec<ArgClass>]) -> CallLayout {
+        match self {
+            CallConv::Win64 => self.layout_win64(args),
+            CallConv::SysV => self.layout_sysv(args),
+            CallConv::Aapcs64 => self.layout_aapcs64(args),
+        }
+    }
+
+    // Win64: argümanlar konuma göre register alır (1. argüman RCX veya XMM0, ...).
+    // 8 byte'tan büyük struct'lar kopyalanıp adresleriyle geçirilir.
+    fn layout_win64(self, args: &[Vec<ArgClass>]) -> CallLayout {
+        let mut placements = Vec::new();
+        let mut stack_slots = 0;
+        let mut sse_regs_used = 0;
+        for (pos, classes) in args.iter().enumerate() {
+            let by_reference = classes.len() != 1 || classes[0] == ArgClass::Memory;
+            let class = if by_reference { ArgClass::Integer } else { classes[0] };
+            let loc = if pos < WIN64_INT_REGS.len() {
+                if class == ArgClass::Sse {
+                    sse_regs_used += 1;
+                    ArgLoc::SseReg(WIN64_SSE_REGS[pos])
+                } else {
+                    ArgLoc::IntReg(WIN64_INT_REGS[pos])
+                }
+            } else {
+                stack_slots += 1;
+                ArgLoc::Stack(stack_slots - 1)
+            };
+            placements.push(ArgPlacement { parts: vec![loc], by_reference });
+        }
+        CallLayout { args: placements, stack_slots, sse_regs_used }
+    }
+
+    // SysV: tamsayı ve SSE register'ları ayrı ayrı sayılır. Bir argümanın tüm parçaları
+    // register'a sığmazsa argümanın tamamı stack'e gider (parçalanmaz).
+    fn layout_sysv(self, args: &[Vec<ArgClass>]) -> CallLayout {
+        let mut placements = Vec::new();
+        let mut next_int = 0;
+        let mut next_sse = 0;
+        let mut stack_slots = 0;
+        for classes in args {
+            let in_memory = classes.is_empty() || classes.len() > 2 || classes.contains(&ArgClass::Memory);
+            let need_int = classes.iter().filter(|c| **c == ArgClass::Integer).count();
+            let need_sse = classes.iter().filter(|c| **c == ArgClass::Sse).count();
+            let fits = next_int + need_int <= SYSV_INT_REGS.len() && next_sse + need_sse <= SYSV_SSE_REGS.len();
+
+            let mut parts = Vec::new();
+            if !in_memory && fits {
+                for class in classes {
+                    if *class == ArgClass::Sse {
+                        parts.push(ArgLoc::SseReg(SYSV_SSE_REGS[next_sse]));
+                        next_sse += 1;
+                    } else {
+                        parts.push(ArgLoc::IntReg(SYSV_INT_REGS[next_int]));
+                        next_int += 1;
+                    }
+                }
+            } else {
+                for _ in 0..classes.len().max(1) {
+                    parts.push(ArgLoc::Stack(stack_slots));
+                    stack_slots += 1;
+                }
+            }
+            placements.push(ArgPlacement { parts, by_reference: false });
+        }
+        CallLayout { args: placements, stack_slots, sse_regs_used: next_sse }
+    }
+
+    // AAPCS64: x0-x7 ve d0-d7 ayrı sayılır; variadic argümanlar da (Linux'ta) aynı kurala uyar.
+    // Alanlarının hepsi float olan struct'lar (HFA) FP register'larında, diğer 16 byte'a kadar
+    // struct'lar ardışık tamsayı register'larında geçirilir; register'lar yetmezse tamamı stack'e
+    // gider ve o sınıfın register'ları tükenmiş sayılır. Daha büyük struct'lar kopyalanıp adresleriyle
+    // geçirilir. (Üç-dört alanlı HFA'lar da MEMORY sınıfından geldiği için adresle geçirilir.)
+    fn layout_aapcs64(self, args: &[Vec<ArgClass>]) -> CallLayout {
+        let mut placements = Vec::new();
+        let mut next_int = 0;
+        let mut next_fp = 0;
+        let mut stack_slots = 0;
+        for classes in args {
+            let by_reference = classes.is_empty() || classes.len() > 2 || classes.contains(&ArgClass::Memory);
+            let in_fp = !by_reference && classes.iter().all(|c| *c == ArgClass::Sse);
+            let need = if by_reference { 1 } else { classes.len() };
+
+            let mut parts = Vec::new();
+            if in_fp && next_fp + need <= AAPCS64_FP_REGS.len() {
+                for _ in 0..need {
+                    parts.push(ArgLoc::SseReg(AAPCS64_FP_REGS[next_fp]));
+                    next_fp += 1;
+                }
+            } else if !in_fp && next_int + need <= AAPCS64_INT_REGS.len() {
+                for _ in 0..need {
+                    parts.push(ArgLoc::IntReg(AAPCS64_INT_REGS[next_int]));
+                    next_int += 1;
+                }
+            } else {
+                if in_fp {
+                    next_fp = AAPCS64_FP_REGS.len();
+                } else {
+                    next_int = AAPCS64_INT_REGS.len();
+                }
+                for _ in 0..need {
+                    parts.push(ArgLoc::Stack(stack_slots));
+                    stack_slots += 1;
+                }
+            }
+            placements.push(ArgPlacement { parts, by_reference });
+        }
+        CallLayout { args: placements, stack_slots, sse_regs_used: next_fp }
+    }
+}
+
+// Skaler bir tipin sınıfı.
+pub fn classify_scalar(ty: &Type) -> ArgClass {
+    if ty.is_float() { ArgClass::Sse } else { ArgClass::Integer }
+}
+
+// Struct'ı eightbyte'lara ayırarak sınıflandırır. Codegen'de her alan 8 byte
+// yer kapladığı için her alan kendi eightbyte'ına denk gelir.
+// 16 byte'tan büyük struct'lar MEMORY sınıfındadır (SysV) ve stack'te kopyalanır.
+pub fn classify_struct(field_types: &[Type]) -> Vec<ArgClass> {
+    if field_types.len() > 2 {
+        return vec![ArgClass::Memory; field_types.len()];
+    }
+    field_types.iter().map(classify_scalar).collect()
+}
@@ -0,0 +1,532 @@
+// src/codegen/debug.rs
+
+// Debug modunda üretilen DWARF hata ayıklama bilgisi (yalnızca ELF/Linux hedefi).
+// Satır tablosu assembler'a bırakılır: her deyimden önce `.loc` yazılır, .debug_line'ı
+// yerleşik assembler (bkz. assembler/dwarf.rs) üretir.
+// Çerçeve bilgisi (.eh_frame/.debug_frame) prologue/epilogue'daki CFI direktiflerinden oluşur.
+// Struct'lar DW_TAG_structure_type (alanları DW_TAG_member), boyutu bilinen diziler DW_TAG_array_type
+// (DW_TAG_subrange_type ile eleman sayısı) olarak, boyutu bilinmeyen diziler eleman işaretçisi olarak tanımlanır.
+// Değişkenler .debug_info'da rbp'ye göre sabit ofsetleriyle (DW_OP_fbreg) tanımlanır. Optimize
+// edilen debug derlemelerinde register'a atanan değişkenler, canlı oldukları kod aralığında o
+// register'da bulunduklarını söyleyen bir konum listesiyle (.debug_loc, DW_OP_regN) tanımlanır.
+
+use std::collections::HashMap;
+
+use crate::ast::Type;
+use crate::diagnostic::Span;
+
+// DWARF sabitleri (DWARF 4)
+const DW_TAG_ARRAY_TYPE: u8 = 0x01;
+const DW_TAG_MEMBER: u8 = 0x0d;
+const DW_TAG_STRUCTURE_TYPE: u8 = 0x13;
+const DW_TAG_SUBRANGE_TYPE: u8 = 0x21;
+const DW_TAG_COMPILE_UNIT: u8 = 0x11;
+const DW_TAG_SUBPROGRAM: u8 = 0x2e;
+const DW_TAG_FORMAL_PARAMETER: u8 = 0x05;
+const DW_TAG_VARIABLE: u8 = 0x34;
+const DW_TAG_BASE_TYPE: u8 = 0x24;
+const DW_TAG_POINTER_TYPE: u8 = 0x0f;
+
+const DW_AT_LOCATION: u8 = 0x02;
+const DW_AT_NAME: u8 = 0x03;
+const DW_AT_BYTE_SIZE: u8 = 0x0b;
+const DW_AT_STMT_LIST: u8 = 0x10;
+const DW_AT_LOW_PC: u8 = 0x11;
+const DW_AT_HIGH_PC: u8 = 0x12;
+const DW_AT_LANGUAGE: u8 = 0x13;
+const DW_AT_COMP_DIR: u8 = 0x1b;
+const DW_AT_PRODUCER: u8 = 0x25;
+const DW_AT_COUNT: u8 = 0x37;
+const DW_AT_DATA_MEMBER_LOCATION: u8 = 0x38;
+const DW_AT_DECL_FILE: u8 = 0x3a;
+const DW_AT_DECL_LINE: u8 = 0x3b;
+const DW_AT_ENCODING: u8 = 0x3e;
+const DW_AT_EXTERNAL: u8 = 0x3f;
+const DW_AT_FRAME_BASE: u8 = 0x40;
+const DW_AT_TYPE: u8 = 0x49;
+const DW_AT_BYTE_STRIDE: u8 = 0x51;
+
+const DW_FORM_ADDR: u8 = 0x01;
+const DW_FORM_DATA2: u8 = 0x05;
+const DW_FORM_DATA8: u8 = 0x07;
+const DW_FORM_STRING: u8 = 0x08;
+const DW_FORM_DATA1: u8 = 0x0b;
+const DW_FORM_UDATA: u8 = 0x0f;
+const DW_FORM_REF4: u8 = 0x13;
+const DW_FORM_SEC_OFFSET: u8 = 0x17;
+const DW_FORM_EXPRLOC: u8 = 0x18;
+const DW_FORM_FLAG_PRESENT: u8 = 0x19;
+
+const DW_ATE_BOOLEAN: u8 = 0x02;
+const DW_ATE_FLOAT: u8 = 0x04;
+const DW_ATE_SIGNED: u8 = 0x05;
+const DW_ATE_SIGNED_CHAR: u8 = 0x06;
+const DW_ATE_UNSIGNED: u8 = 0x07;
+
+const DW_OP_REG0: u8 = 0x50;
+const DW_OP_REG6: u8 = 0x56; // rbp
+const DW_OP_REGX: u8 = 0x90;
+const DW_OP_FBREG: u8 = 0x91;
+
+// Hata ayıklayıcıların ifade değerlendirmesi için en yakın dil: C99.
+const DW_LANG_C99: u16 = 0x000c;
+
+// Kısaltma kodları
+const ABBREV_COMPILE_UNIT: u8 = 1;
+const ABBREV_SUBPROGRAM: u8 = 2;
+const ABBREV_SUBPROGRAM_VOID: u8 = 3;
+const ABBREV_PARAMETER: u8 = 4;
+const ABBREV_VARIABLE: u8 = 5;
+const ABBREV_BASE_TYPE: u8 = 6;
+const ABBREV_POINTER_TYPE: u8 = 7;
+const ABBREV_PARAMETER_LOCLIST: u8 = 8;
+const ABBREV_VARIABLE_LOCLIST: u8 = 9;
+const ABBREV_VARIABLE_OPTIMIZED_OUT: u8 = 10;
+const ABBREV_STRUCTURE_TYPE: u8 = 11;
+const ABBREV_MEMBER: u8 = 12;
+const ABBREV_ARRAY_TYPE: u8 = 13;
+const ABBREV_SUBRANGE_TYPE: u8 = 14;
+
+// Dizi elemanları ve struct alanları çerçevede 8 byte'lık kelimelerde durur.
+const WORD_SIZE: usize = 8;
+
+#[derive(PartialEq)]
+enum VariableLocation {
+    // [rbp - offset]
+    Stack(i32),
+    // `start` ve `end` etiketleri arasındaki kodda verilen DWARF register numarasında
+    Register { dwarf_reg: u16, start: String, end: String },
+    // Optimizasyonla silinmiş (DW_AT_location yok; hata ayıklayıcı "optimized out" gösterir)
+    OptimizedOut,
+}
+
+// Bir parametre veya yerel değişken.
+struct DebugVariable {
+    name: String,
+    ty: Type,
+    location: VariableLocation,
+    span: Span,
+    is_param: bool,
+}
+
+struct DebugFunction {
+    name: String,
+    label: String,
+    span: Span,
+    return_type: Type,
+    variables: Vec<DebugVariable>,
+}
+
+// Bir struct'ın bellek düzeni: (alan adı, tipi, struct başından byte ofseti) ve toplam boyut.
+struct DebugStruct {
+    members: Vec<(String, Type, usize)>,
+    size: usize,
+}
+
+pub(crate) struct DebugInfo {
+    files: Vec<String>, // FileId sırasıyla kaynak dosya adları (.file numarası = indeks + 1)
+    comp_dir: String,
+    functions: Vec<DebugFunction>,
+    structs: HashMap<String, DebugStruct>,
+}
+
+impl DebugInfo {
+    pub(crate) fn new(files: Vec<String>, comp_dir: String) -> Self {
+        Self { files, comp_dir, functions: Vec::new(), structs: HashMap::new() }
+    }
+
+    // Struct tipinin alanlarını (byte ofsetleriyle) kaydeder; bu tipteki değişkenler DW_TAG_structure_type ile tanımlanır.
+    pub(crate) fn add_struct(&mut self, name: &str, members: Vec<(String, Type, usize)>, size: usize) {
+        self.structs.insert(name.to_string(), DebugStruct { members, size });
+    }
+
+    // Metin segmentinin başında: kaynak dosyaların satır tablosuna kaydı.
+    pub(crate) fn file_directives(&self) -> String {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(i, name)| format!("    .file {} \"{}\"\n", i + 1, escape(name)))
+            .collect()
+    }
+
+    // Bir deyimin makine kodunu kaynak satırına bağlayan `.loc` direktifi.
+    pub(crate) fn loc(&self, span: Span) -> String {
+        if span.is_dummy() || span.file.0 >= self.files.len() {
+            return String::new();
+        }
+        format!("    .loc {} {} {}\n", span.file.0 + 1, span.line, span.column)
+    }
+
+    pub(crate) fn begin_function(&mut self, name: &str, label: &str, span: Span, return_type: &Type) {
+        self.functions.push(DebugFunction {
+            name: name.to_string(),
+            label: label.to_string(),
+            span,
+            return_type: return_type.clone(),
+            variables: Vec::new(),
+        });
+    }
+
+    pub(crate) fn add_variable(&mut self, name: &str, ty: &Type, stack_offset: i32, span: Span, is_param: bool) {
+        self.push_variable(name, ty, VariableLocation::Stack(stack_offset), span, is_param);
+    }
+
+    // Register'da yaşayan değişken: `start` ve `end` konumları `position_label` etiketleridir.
+    // DWARF numarası bilinmeyen register'lar (örn. aarch64) atlanır.
+    pub(crate) fn add_register_variable(&mut self, name: &str, ty: &Type, reg: &str, (start, end): (usize, usize), span: Span, is_param: bool) {
+        let Some(dwarf_reg) = dwarf_register(reg) else { return };
+        let location = VariableLocation::Register { dwarf_reg, start: self.position_label(start), end: self.position_label(end) };
+        self.push_variable(name, ty, location, span, is_param);
+    }
+
+    pub(crate) fn add_optimized_out_variable(&mut self, name: &str, ty: &Type, span: Span) {
+        self.push_variable(name, ty, VariableLocation::OptimizedOut, span, false);
+    }
+
+    // Aynı ada ve yere sahip tekrar kayıtlar (örn. iki döngüde de `i`) bir kez listelenir.
+    fn push_variable(&mut self, name: &str, ty: &Type, location: VariableLocation, span: Span, is_param: bool) {
+        let Some(function) = self.functions.last_mut() else { return };
+        if function.variables.iter().any(|v| v.name == name && v.location == location) {
+            return;
+        }
+        function.variables.push(DebugVariable { name: name.to_string(), ty: ty.clone(), location, span, is_param });
+    }
+
+    // Geçerli fonksiyonda IR konumunu (bkz. ir/liveness.rs) işaretleyen etiket.
+    pub(crate) fn position_label(&self, position: usize) -> String {
+        format!(".Ldebug_pos{}_{}", self.functions.len().saturating_sub(1), position)
+    }
+
+    // Fonksiyonun sonunu işaretleyen etiket (DW_AT_high_pc için).
+    pub(crate) fn function_end_label(&self) -> String {
+        format!(".Ldebug_func_end{}", self.functions.len().saturating_sub(1))
+    }
+
+    // .debug_abbrev, .debug_info ve satır tablosunun başlangıcını içeren bölümler.
+    pub(crate) fn sections(&self) -> String {
+        let mut types: Vec<Type> = Vec::new();
+        for function in &self.functions {
+            if self.describable(&function.return_type) {
+                self.collect_type(&function.return_type, &mut types);
+            }
+            for var in function.variables.iter().filter(|v| self.describable(&v.ty)) {
+                self.collect_type(&var.ty, &mut types);
+            }
+        }
+
+        let mut asm = String::new();
+        let mut location_lists = String::new();
+        let mut list_count = 0;
+        asm.push_str("\n.section .debug_abbrev,\"\",@progbits\n");
+        asm.push_str(&abbreviations());
+
+        asm.push_str("\n.section .debug_info,\"\",@progbits\n");
+        asm.push_str(".Ldebug_info0:\n");
+        asm.push_str("    .long .Ldebug_info_end - .Ldebug_info_start # Birim uzunluğu\n");
+        asm.push_str(".Ldebug_info_start:\n");
+        asm.push_str("    .value 4 # DWARF sürümü\n");
+        asm.push_str("    .long .Ldebug_abbrev0\n");
+        asm.push_str("    .byte 8 # Adres boyutu\n");
+
+        asm.push_str(&format!("    .uleb128 {}\n", ABBREV_COMPILE_UNIT));
+        asm.push_str("    .string \"NIMBLE (nim) v0.0.1\"\n");
+        asm.push_str(&format!("    .value {:#x}\n", DW_LANG_C99));
+        asm.push_str(&format!("    .string \"{}\"\n", escape(self.files.first().map_or("", String::as_str))));
+        asm.push_str(&format!("    .string \"{}\"\n", escape(&self.comp_dir)));
+        asm.push_str("    .quad .Ltext0\n");
+        asm.push_str("    .quad .Letext0 - .Ltext0\n");
+        asm.push_str("    .long .Ldebug_line0\n");
+
+        for (index, ty) in types.iter().enumerate() {
+            asm.push_str(&format!(".Ldebug_type{}:\n", index));
+            match ty {
+                Type::Ptr(inner) | Type::Ref(inner) | Type::Array(inner, None) => {
+                    asm.push_str(&format!("    .uleb128 {}\n    .byte 8\n", ABBREV_POINTER_TYPE));
+                    asm.push_str(&format!("    .long {}\n", type_ref(inner, &types)));
+                }
+                Type::Array(inner, Some(len)) => {
+                    asm.push_str(&format!("    .uleb128 {}\n", ABBREV_ARRAY_TYPE));
+                    asm.push_str(&format!("    .long {}\n    .byte {}\n", type_ref(inner, &types), WORD_SIZE));
+                    asm.push_str(&format!("    .uleb128 {}\n    .uleb128 {}\n", ABBREV_SUBRANGE_TYPE, len));
+                    asm.push_str("    .byte 0 # Dizi tipinin çocuklarının sonu\n");
+                }
+                Type::Custom(name) => {
+                    let layout = &self.structs[name];
+                    asm.push_str(&format!("    .uleb128 {}\n", ABBREV_STRUCTURE_TYPE));
+                    asm.push_str(&format!("    .string \"{}\"\n    .uleb128 {}\n", escape(name), layout.size));
+                    for (member, member_ty, offset) in layout.members.iter().filter(|(_, t, _)| self.describable_member(t)) {
+                        asm.push_str(&format!("    .uleb128 {}\n", ABBREV_MEMBER));
+                        asm.push_str(&format!("    .string \"{}\"\n", escape(member)));
+                        asm.push_str(&format!("    .long {}\n    .uleb128 {}\n", type_ref(member_ty, &types), offset));
+                    }
+                    asm.push_str("    .byte 0 # Struct tipinin çocuklarının sonu\n");
+                }
+                Type::Str(_) => {
+                    asm.push_str(&format!("    .uleb128 {}\n    .byte 8\n", ABBREV_POINTER_TYPE));
+                    asm.push_str(&format!("    .long {}\n", type_ref(&Type::Char, &types)));
+                }
+                _ => {
+                    let (encoding, size) = base_type(ty);
+                    asm.push_str(&format!("    .uleb128 {}\n", ABBREV_BASE_TYPE));
+                    asm.push_str(&format!("    .string \"{}\"\n", ty));
+                    asm.push_str(&format!("    .byte {:#x}\n    .byte {}\n", encoding, size));
+                }
+            }
+        }
+
+        for (index, function) in self.functions.iter().enumerate() {
+            let has_type = self.describable(&function.return_type);
+            let abbrev = if has_type { ABBREV_SUBPROGRAM } else { ABBREV_SUBPROGRAM_VOID };
+            asm.push_str(&format!("    .uleb128 {} # {}\n", abbrev, function.name));
+            asm.push_str(&format!("    .string \"{}\"\n", escape(&function.name)));
+            asm.push_str(&format!("    .byte {}\n", function.span.file.0 + 1));
+            asm.push_str(&format!("    .uleb128 {}\n", function.span.line));
+            asm.push_str(&format!("    .quad {}\n", function.label));
+            asm.push_str(&format!("    .quad .Ldebug_func_end{} - {}\n", index, function.label));
+            asm.push_str(&format!("    .uleb128 1\n    .byte {:#x} # DW_OP_reg6 (rbp)\n", DW_OP_REG6));
+            if has_type {
+                asm.push_str(&format!("    .long {}\n", type_ref(&function.return_type, &types)));
+            }
+            for var in function.variables.iter().filter(|v| self.describable(&v.ty)) {
+                let abbrev = match (&var.location, var.is_param) {
+                    (VariableLocation::Stack(_), true) => ABBREV_PARAMETER,
+                    (VariableLocation::Stack(_), false) => ABBREV_VARIABLE,
+                    (VariableLocation::Register { .. }, true) => ABBREV_PARAMETER_LOCLIST,
+                    (VariableLocation::Register { .. }, false) => ABBREV_VARIABLE_LOCLIST,
+                    (VariableLocation::OptimizedOut, _) => ABBREV_VARIABLE_OPTIMIZED_OUT,
+                };
+                asm.push_str(&format!("    .uleb128 {} # {}\n", abbrev, var.name));
+                asm.push_str(&format!("    .string \"{}\"\n", escape(&var.name)));
+                asm.push_str(&format!("    .byte {}\n", var.span.file.0 + 1));
+                asm.push_str(&format!("    .uleb128 {}\n", var.span.line));
+                asm.push_str(&format!("    .long {}\n", type_ref(&var.ty, &types)));
+                match &var.location {
+                    VariableLocation::Stack(stack_offset) => {
+                        let offset = -(*stack_offset as i64);
+                        asm.push_str(&format!("    .uleb128 {}\n", 1 + sleb128_len(offset)));
+                        asm.push_str(&format!("    .byte {:#x}\n    .sleb128 {} # DW_OP_fbreg\n", DW_OP_FBREG, offset));
+                    }
+                    VariableLocation::Register { dwarf_reg, start, end } => {
+                        // Konum listesi girdileri derleme biriminin taban adresine (.Ltext0) göredir.
+                        let list = format!(".Ldebug_loc{}", list_count);
+                        list_count += 1;
+                        asm.push_str(&format!("    .long {}\n", list));
+                        location_lists.push_str(&format!("{}:\n", list));
+                        location_lists.push_str(&format!("    .quad {} - .Ltext0\n    .quad {} - .Ltext0\n", start, end));
+                        if *dwarf_reg < 32 {
+                            location_lists.push_str(&format!("    .value 1\n    .byte {:#x} # DW_OP_reg{}\n", DW_OP_REG0 + *dwarf_reg as u8, dwarf_reg));
+                        } else {
+                            location_lists.push_str(&format!("    .value 2\n    .byte {:#x}\n    .uleb128 {} # DW_OP_regx\n", DW_OP_REGX, dwarf_reg));
+                        }
+                        location_lists.push_str("    .quad 0\n    .quad 0\n");
+                    }
+                    VariableLocation::OptimizedOut => {}
+                }
+            }
+            asm.push_str("    .byte 0 # Fonksiyonun çocuklarının sonu\n");
+        }
+        asm.push_str("    .byte 0 # Derleme biriminin çocuklarının sonu\n");
+        asm.push_str(".Ldebug_info_end:\n");
+
+        if !location_lists.is_empty() {
+            asm.push_str("\n.section .debug_loc,\"\",@progbits\n");
+            asm.push_str(&location_lists);
+        }
+
+        // GAS `.loc` direktiflerinden ürettiği satır programını bu bölüme yazar.
+        asm.push_str("\n.section .debug_line,\"\",@progbits\n");
+        asm.push_str(".Ldebug_line0:\n");
+        asm
+    }
+
+    // DWARF'ta tanımlanabilen tipler: skalerler, işaretçiler, kayıtlı struct'lar ve elemanları
+    // skaler olan diziler (boyutu bilinmeyen diziler yalnızca başlangıç adresi olarak durur).
+    fn describable(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Ptr(inner) | Type::Ref(inner) | Type::Array(inner, None) => self.describable(inner),
+            Type::Array(inner, Some(len)) => *len > 0 && !matches!(**inner, Type::Custom(_) | Type::Array(..)) && self.describable(inner),
+            Type::Custom(name) => self.structs.contains_key(name),
+            Type::Str(_) => true,
+            _ => base_type(ty).1 > 0,
+        }
+    }
+
+    // Struct düzeni alanları tek kelimede tutar; boyutu bilinen dizi alanları bu düzene uymadığı için atlanır.
+    fn describable_member(&self, ty: &Type) -> bool {
+        !matches!(ty, Type::Array(_, Some(_))) && self.describable(ty)
+    }
+
+    // Tipi ve bağımlı olduğu tipleri (işaretçinin gösterdiği tip, dizi elemanı, struct alanları) listeye ekler.
+    fn collect_type(&self, ty: &Type, types: &mut Vec<Type>) {
+        match ty {
+            Type::Ptr(inner) | Type::Ref(inner) | Type::Array(inner, _) => self.collect_type(inner, types),
+            Type::Str(_) => self.collect_type(&Type::Char, types),
+            Type::Custom(name) => {
+                for (_, member_ty, _) in self.structs[name].members.iter().filter(|(_, t, _)| self.describable_member(t)) {
+                    self.collect_type(member_ty, types);
+                }
+            }
+            _ => {}
+        }
+        if !types.contains(ty) {
+            types.push(ty.clone());
+        }
+    }
+}
+
+// (kısaltma kodu, etiket, çocukları var mı, (nitelik, biçim) listesi)
+type Abbreviation = (u8, u8, bool, &'static [(u8, u8)]);
+
+const ABBREVIATIONS: [Abbreviation; 14] = [
+    (ABBREV_COMPILE_UNIT, DW_TAG_COMPILE_UNIT, true, &[
+        (DW_AT_PRODUCER, DW_FORM_STRING),
+        (DW_AT_LANGUAGE, DW_FORM_DATA2),
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_COMP_DIR, DW_FORM_STRING),
+        (DW_AT_LOW_PC, DW_FORM_ADDR),
+        (DW_AT_HIGH_PC, DW_FORM_DATA8),
+        (DW_AT_STMT_LIST, DW_FORM_SEC_OFFSET),
+    ]),
+    (ABBREV_SUBPROGRAM, DW_TAG_SUBPROGRAM, true, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_DECL_FILE, DW_FORM_DATA1),
+        (DW_AT_DECL_LINE, DW_FORM_UDATA),
+        (DW_AT_LOW_PC, DW_FORM_ADDR),
+        (DW_AT_HIGH_PC, DW_FORM_DATA8),
+        (DW_AT_FRAME_BASE, DW_FORM_EXPRLOC),
+        (DW_AT_TYPE, DW_FORM_REF4),
+        (DW_AT_EXTERNAL, DW_FORM_FLAG_PRESENT),
+    ]),
+    (ABBREV_SUBPROGRAM_VOID, DW_TAG_SUBPROGRAM, true, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_DECL_FILE, DW_FORM_DATA1),
+        (DW_AT_DECL_LINE, DW_FORM_UDATA),
+        (DW_AT_LOW_PC, DW_FORM_ADDR),
+        (DW_AT_HIGH_PC, DW_FORM_DATA8),
+        (DW_AT_FRAME_BASE, DW_FORM_EXPRLOC),
+        (DW_AT_EXTERNAL, DW_FORM_FLAG_PRESENT),
+    ]),
+    (ABBREV_PARAMETER, DW_TAG_FORMAL_PARAMETER, false, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_DECL_FILE, DW_FORM_DATA1),
+        (DW_AT_DECL_LINE, DW_FORM_UDATA),
+        (DW_AT_TYPE, DW_FORM_REF4),
+        (DW_AT_LOCATION, DW_FORM_EXPRLOC),
+    ]),
+    (ABBREV_VARIABLE, DW_TAG_VARIABLE, false, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_DECL_FILE, DW_FORM_DATA1),
+        (DW_AT_DECL_LINE, DW_FORM_UDATA),
+        (DW_AT_TYPE, DW_FORM_REF4),
+        (DW_AT_LOCATION, DW_FORM_EXPRLOC),
+    ]),
+    (ABBREV_BASE_TYPE, DW_TAG_BASE_TYPE, false, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_ENCODING, DW_FORM_DATA1),
+        (DW_AT_BYTE_SIZE, DW_FORM_DATA1),
+    ]),
+    (ABBREV_POINTER_TYPE, DW_TAG_POINTER_TYPE, false, &[
+        (DW_AT_BYTE_SIZE, DW_FORM_DATA1),
+        (DW_AT_TYPE, DW_FORM_REF4),
+    ]),
+    (ABBREV_PARAMETER_LOCLIST, DW_TAG_FORMAL_PARAMETER, false, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_DECL_FILE, DW_FORM_DATA1),
+        (DW_AT_DECL_LINE, DW_FORM_UDATA),
+        (DW_AT_TYPE, DW_FORM_REF4),
+        (DW_AT_LOCATION, DW_FORM_SEC_OFFSET),
+    ]),
+    (ABBREV_VARIABLE_LOCLIST, DW_TAG_VARIABLE, false, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_DECL_FILE, DW_FORM_DATA1),
+        (DW_AT_DECL_LINE, DW_FORM_UDATA),
+        (DW_AT_TYPE, DW_FORM_REF4),
+        (DW_AT_LOCATION, DW_FORM_SEC_OFFSET),
+    ]),
+    (ABBREV_VARIABLE_OPTIMIZED_OUT, DW_TAG_VARIABLE, false, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_DECL_FILE, DW_FORM_DATA1),
+        (DW_AT_DECL_LINE, DW_FORM_UDATA),
+        (DW_AT_TYPE, DW_FORM_REF4),
+    ]),
+    (ABBREV_STRUCTURE_TYPE, DW_TAG_STRUCTURE_TYPE, true, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_BYTE_SIZE, DW_FORM_UDATA),
+    ]),
+    (ABBREV_MEMBER, DW_TAG_MEMBER, false, &[
+        (DW_AT_NAME, DW_FORM_STRING),
+        (DW_AT_TYPE, DW_FORM_REF4),
+        (DW_AT_DATA_MEMBER_LOCATION, DW_FORM_UDATA),
+    ]),
+    (ABBREV_ARRAY_TYPE, DW_TAG_ARRAY_TYPE, true, &[
+        (DW_AT_TYPE, DW_FORM_REF4),
+        (DW_AT_BYTE_STRIDE, DW_FORM_DATA1),
+    ]),
+    (ABBREV_SUBRANGE_TYPE, DW_TAG_SUBRANGE_TYPE, false, &[
+        (DW_AT_COUNT, DW_FORM_UDATA),
+    ]),
+];
+
+fn abbreviations() -> String {
+    let mut asm = String::from(".Ldebug_abbrev0:\n");
+    for (code, tag, children, attrs) in ABBREVIATIONS {
+        asm.push_str(&format!("    .uleb128 {}\n    .uleb128 {:#x}\n    .byte {}\n", code, tag, children as u8));
+        for (attr, form) in attrs {
+            asm.push_str(&format!("    .uleb128 {:#x}\n    .uleb128 {:#x}\n", attr, form));
+        }
+        asm.push_str("    .byte 0\n    .byte 0\n");
+    }
+    asm.push_str("    .byte 0\n");
+    asm
+}
+
+// (kodlama, byte boyutu); tanımlanamayan tiplerde boyut 0'dır.
+// Kayan noktalı değerler çerçevede her zaman double olarak tutulur.
+fn base_type(ty: &Type) -> (u8, u8) {
+    match ty {
+        Type::I8 => (DW_ATE_SIGNED, 1),
+        Type::I16 => (DW_ATE_SIGNED, 2),
+        Type::I32 => (DW_ATE_SIGNED, 4),
+        Type::I64 => (DW_ATE_SIGNED, 8),
+        Type::U8 | Type::Byte => (DW_ATE_UNSIGNED, 1),
+        Type::U16 => (DW_ATE_UNSIGNED, 2),
+        Type::U32 => (DW_ATE_UNSIGNED, 4),
+        Type::U64 => (DW_ATE_UNSIGNED, 8),
+        Type::F32 | Type::F64 => (DW_ATE_FLOAT, 8),
+        Type::Bool => (DW_ATE_BOOLEAN, 1),
+        Type::Char => (DW_ATE_SIGNED_CHAR, 1),
+        _ => (0, 0),
+    }
+}
+
+// Derleme biriminin başına göre tip DIE'sinin ofseti (tip `collect_type` ile eklenmiş olmalı).
+fn type_ref(ty: &Type, types: &[Type]) -> String {
+    let index = types.iter().position(|t| t == ty).expect("DWARF tipi toplanmış olmalı");
+    format!(".Ldebug_type{} - .Ldebug_info0", index)
+}
+
+// x86-64 System V DWARF register numaraları
+fn dwarf_register(reg: &str) -> Option<u16> {
+    const GPRS: [&str; 16] = ["rax", "rdx", "rcx", "rbx", "rsi", "rdi", "rbp", "rsp", "r8", "r9", "r10", "r11", "r12", "r13", "r14", "r15"];
+    if let Some(index) = GPRS.iter().position(|r| *r == reg) {
+        return Some(index as u16);
+    }
+    let xmm: u16 = reg.strip_prefix("xmm")?.parse().ok()?;
+    (xmm < 16).then_some(17 + xmm)
+}
+
+fn sleb128_len(mut value: i64) -> usize {
+    let mut len = 0;
+    loop {
+        let byte = value & 0x7f;
+        value >>= 7;
+        len += 1;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            return len;
+        }
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
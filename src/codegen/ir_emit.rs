@@ -0,0 +1,743 @@
+// src/codegen/ir_emit.rs
+
+// IR fonksiyonlarından x86-64 (GAS Intel) kodu üretimi.
+//
+// Geçicilerin yeri register atayıcısından (regalloc.rs) gelir: ya bir register'dadır ya da çerçevede
+// 8 byte'lık bir yuvada (spill). İşlenenler mümkün olduğunda doğrudan register, bellek veya anlık
+// değer olarak kullanılır; gerekirse RAX/RCX (float'larda XMM0/XMM1) üzerinden taşınır. RDX bölmede,
+// R11 adres hesabında kullanılır; bu register'lar hiçbir geçiciye atanmaz. Prologue/epilogue ve
+// çağrı yerleşimi AST yolu ile ortaktır.
+
+use std::collections::BTreeMap;
+
+use super::regalloc;
+use super::{abi, ArgClass, Codegen, DataItem, EvaluatedArg};
+use crate::ast::{expand_asm_body, Expr, TargetPlatform, Type};
+use crate::ir::liveness::block_starts;
+use crate::ir::opt::OptLevel;
+use crate::ir::{
+    Address, AsmOperand, Base, BinaryOp, BlockId, CallArg, CmpOp, ConvertOp, Function, Inst, IrType, Operand, Param, Temp,
+    Terminator, UnaryOp,
+};
+
+// Geçicinin yeri
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Loc {
+    Reg(&'static str),
+    // [rbp - ofset]
+    Stack(i32),
+}
+
+fn is_sse(reg: &str) -> bool {
+    reg.starts_with("xmm")
+}
+
+// Geçicilerin ve slotların yerleri, geçicilerin okunma sayıları
+struct Frame {
+    locs: Vec<Loc>,
+    slots: Vec<i32>,
+    uses: Vec<usize>,
+}
+
+impl Frame {
+    fn loc(&self, temp: Temp) -> Loc {
+        self.locs[temp.0 as usize]
+    }
+
+    // İşlenen `reg` register'ında duran bir geçici mi?
+    fn holds(&self, op: &Operand, reg: &str) -> bool {
+        self.reg_of(op) == Some(reg)
+    }
+
+    fn reg_of(&self, op: &Operand) -> Option<&'static str> {
+        match op {
+            Operand::Temp(t) => match self.loc(*t) {
+                Loc::Reg(reg) => Some(reg),
+                Loc::Stack(_) => None,
+            },
+            _ => None,
+        }
+    }
+
+    // Tamsayı işleneninin register veya 32 bitlik anlık değer olarak doğrudan yazımı
+    fn reg_or_imm(&self, op: &Operand) -> Option<String> {
+        match op {
+            Operand::Temp(t) => match self.loc(*t) {
+                Loc::Reg(reg) if !is_sse(reg) => Some(reg.to_string()),
+                _ => None,
+            },
+            Operand::Int(v) if i32::try_from(*v).is_ok() => Some(v.to_string()),
+            _ => None,
+        }
+    }
+
+    // Tamsayı işleneninin register, bellek veya anlık değer olarak doğrudan yazımı
+    fn int_operand(&self, op: &Operand) -> Option<String> {
+        match op {
+            Operand::Temp(t) => match self.loc(*t) {
+                Loc::Stack(offset) => Some(format!("qword ptr [rbp - {}]", offset)),
+                _ => self.reg_or_imm(op),
+            },
+            _ => self.reg_or_imm(op),
+        }
+    }
+
+    // Sonucun hesaplanacağı tamsayı register'ı: hedef bir GPR'daysa doğrudan orası
+    fn int_target(&self, dst: Temp) -> &'static str {
+        match self.loc(dst) {
+            Loc::Reg(reg) if !is_sse(reg) => reg,
+            _ => "rax",
+        }
+    }
+
+    fn float_target(&self, dst: Temp) -> &'static str {
+        match self.loc(dst) {
+            Loc::Reg(reg) if is_sse(reg) => reg,
+            _ => "xmm0",
+        }
+    }
+}
+
+pub(super) fn scalar_type(ty: IrType) -> Type {
+    match ty {
+        IrType::I64 => Type::I64,
+        IrType::F64 => Type::F64,
+    }
+}
+
+// İşaretli karşılaştırmanın koşul kodu ve tersi
+fn condition_codes(op: CmpOp) -> (&'static str, &'static str) {
+    match op {
+        CmpOp::Eq => ("e", "ne"),
+        CmpOp::Ne => ("ne", "e"),
+        CmpOp::Lt => ("l", "ge"),
+        CmpOp::Le => ("le", "g"),
+        CmpOp::Gt => ("g", "le"),
+        CmpOp::Ge => ("ge", "l"),
+    }
+}
+
+impl<'a, 'b> Codegen<'a, 'b> {
+    // IR fonksiyonunun gövdesini (prologue/epilogue hariç) üretir. İkinci değer, epilogue'da
+    // `ret`'ten önce çalışacak callee-saved register geri yükleme kodudur.
+    pub(super) fn generate_ir_function_body(&mut self, func: &Function, params: &[(String, Type, Option<Expr>)]) -> Result<(String, String), String> {
+        let mut code = String::new();
+        // Optimize edilmeyen debug derlemelerinde adlandırılmış değişkenler register'a atanmaz;
+        // hata ayıklayıcı onları çerçevedeki yuvalarından okur.
+        let keep_variables = self.debug_info.is_some() && self.opt_level == OptLevel::O0;
+        let allocation = regalloc::allocate(func, self.abi(), keep_variables);
+
+        // Parametreler ve main'in argc/argv'si önce ABI'nin koyduğu yerden çerçeveye yazılır;
+        // register'a atananlar oradan register'larına yüklenir.
+        let mut homes: Vec<Option<i32>> = vec![None; func.temps.len()];
+        let mut param_slots = Vec::new();
+        if let Some((argc, argv)) = func.entry_args {
+            let (entry_asm, argc_offset, argv_offset) = self.generate_main_entry();
+            code.push_str(&entry_asm);
+            homes[argc.0 as usize] = Some(argc_offset);
+            homes[argv.0 as usize] = Some(argv_offset);
+        } else {
+            let (param_asm, offsets) = self.generate_param_stores(params);
+            code.push_str(&param_asm);
+            for (param, offset) in func.params.iter().zip(offsets) {
+                match param {
+                    Param::Scalar(temp) => homes[temp.0 as usize] = Some(offset),
+                    Param::Struct { slot, .. } => param_slots.push((slot.0 as usize, offset)),
+                }
+            }
+        }
+
+        // main hiç dönmez (süreci sonlandırır); callee-saved register'ları saklamasına gerek yoktur.
+        let mut restore = String::new();
+        if func.entry_args.is_none() {
+            for reg in &allocation.callee_saved {
+                if is_sse(reg) {
+                    let offset = self.alloc_stack(16);
+                    code.push_str(&format!("    movdqu [rbp - {}], {}\n", offset, reg));
+                    restore.push_str(&format!("    movdqu {}, [rbp - {}]\n", reg, offset));
+                } else {
+                    let offset = self.alloc_stack(8);
+                    code.push_str(&format!("    mov [rbp - {}], {}\n", offset, reg));
+                    restore.push_str(&format!("    mov {}, [rbp - {}]\n", reg, offset));
+                }
+            }
+        }
+
+        let mut locs = Vec::with_capacity(func.temps.len());
+        for (i, reg) in allocation.regs.iter().enumerate() {
+            locs.push(match (reg, homes[i]) {
+                (Some(reg), _) => Loc::Reg(reg),
+                (None, Some(home)) => Loc::Stack(home),
+                (None, None) => Loc::Stack(self.alloc_stack(8)),
+            });
+        }
+        let mut slots = Vec::new();
+        for (i, slot) in func.slots.iter().enumerate() {
+            match param_slots.iter().find(|(s, _)| *s == i) {
+                Some((_, offset)) => slots.push(*offset),
+                None => slots.push(self.alloc_stack(slot.size)),
+            }
+        }
+        let mut uses = vec![0; func.temps.len()];
+        for block in &func.blocks {
+            for temp in block.insts.iter().flat_map(Inst::uses).chain(block.term.uses()) {
+                uses[temp.0 as usize] += 1;
+            }
+        }
+        let frame = Frame { locs, slots, uses };
+
+        // Register'daki değişkenlerin konum listesi sınırları: IR konumu -> etiket
+        let mut position_labels = BTreeMap::new();
+        if let Some(debug_info) = &mut self.debug_info {
+            let params: Vec<Temp> = func
+                .params
+                .iter()
+                .filter_map(|p| match p {
+                    Param::Scalar(temp) => Some(*temp),
+                    Param::Struct { .. } => None,
+                })
+                .chain(func.entry_args.iter().flat_map(|(argc, argv)| [*argc, *argv]))
+                .collect();
+            for (i, var) in func.temp_vars.iter().enumerate() {
+                let Some(var) = var else { continue };
+                let is_param = params.contains(&Temp(i as u32));
+                match (frame.locs[i], allocation.intervals[i]) {
+                    // Hiç okunup yazılmayan geçicinin yuvası boş kalır.
+                    (_, None) if !keep_variables => debug_info.add_optimized_out_variable(&var.name, &var.ty, var.span),
+                    (Loc::Stack(offset), _) => debug_info.add_variable(&var.name, &var.ty, offset, var.span, is_param),
+                    (Loc::Reg(reg), Some(iv)) => {
+                        debug_info.add_register_variable(&var.name, &var.ty, reg, (iv.start, iv.end + 1), var.span, is_param);
+                        for position in [iv.start, iv.end + 1] {
+                            position_labels.insert(position, debug_info.position_label(position));
+                        }
+                    }
+                    (Loc::Reg(_), None) => {}
+                }
+            }
+            // Struct ve dizi değişkenleri slotlarının çerçeve adresinde durur.
+            for (i, slot) in func.slots.iter().enumerate() {
+                let Some(var) = &slot.var else { continue };
+                let is_param = func.params.iter().any(|p| matches!(p, Param::Struct { slot, .. } if slot.0 as usize == i));
+                debug_info.add_variable(&var.name, &var.ty, frame.slots[i], var.span, is_param);
+            }
+        }
+
+        let assigned: Vec<String> = allocation
+            .regs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, reg)| reg.map(|reg| format!("{}={}", Temp(i as u32), reg)))
+            .collect();
+        if !assigned.is_empty() {
+            code.push_str(&format!("    # Register ataması: {}\n", assigned.join(" ")));
+        }
+
+        if func.name == "main" && self.target_platform == TargetPlatform::Windows {
+            code.push_str(&self.generate_console_setup());
+        }
+
+        for (i, home) in homes.iter().enumerate() {
+            if frame.uses[i] == 0 {
+                continue;
+            }
+            if let (Some(home), Loc::Reg(reg)) = (home, frame.locs[i]) {
+                let mnemonic = if is_sse(reg) { "movsd" } else { "mov" };
+                code.push_str(&format!("    {} {}, [rbp - {}]\n", mnemonic, reg, home));
+            }
+        }
+
+        let starts = block_starts(func);
+        let mark = |position: usize, code: &mut String| {
+            if let Some(label) = position_labels.get(&position) {
+                code.push_str(&format!("{}:\n", label));
+            }
+        };
+        for (i, block) in func.blocks.iter().enumerate() {
+            code.push_str(&format!("{}:\n", self.block_label(func, BlockId(i as u32))));
+            let next = BlockId(i as u32 + 1);
+
+            // Sonucu yalnızca bloğun dallanmasında okunan karşılaştırma, setcc yerine bayraklarla
+            // doğrudan koşullu atlamaya dönüşür.
+            let fused = match (block.insts.last(), &block.term) {
+                (Some(Inst::Cmp { dst, op, lhs, rhs }), Terminator::Branch { cond: Operand::Temp(cond), then_block, else_block })
+                    if cond == dst && frame.uses[dst.0 as usize] == 1 =>
+                {
+                    Some((*op, lhs, rhs, *then_block, *else_block))
+                }
+                _ => None,
+            };
+            let count = block.insts.len() - usize::from(fused.is_some());
+            for (j, inst) in block.insts[..count].iter().enumerate() {
+                mark(starts[i] + j, &mut code);
+                if let Inst::SourceLine(span) = inst {
+                    if let Some(debug_info) = &self.debug_info {
+                        code.push_str(&debug_info.loc(*span));
+                    }
+                    continue;
+                }
+                code.push_str(&format!("    # {}\n", inst));
+                self.emit_inst(func, &frame, inst, &mut code)?;
+            }
+            match fused {
+                Some((op, lhs, rhs, then_block, else_block)) => {
+                    mark(starts[i] + count, &mut code);
+                    code.push_str(&format!("    # {}\n", block.insts[count]));
+                    self.emit_compare(&frame, lhs, rhs, &mut code);
+                    mark(starts[i] + count + 1, &mut code);
+                    self.emit_branch(func, condition_codes(op), then_block, else_block, next, &mut code);
+                }
+                None => {
+                    mark(starts[i] + block.insts.len(), &mut code);
+                    self.emit_terminator(func, &frame, &block.term, next, &mut code);
+                }
+            }
+        }
+        // Son sonlandırıcıdan sonra biten aralıklar
+        let end = starts.last().zip(func.blocks.last()).map_or(0, |(start, block)| start + block.insts.len() + 1);
+        for label in position_labels.range(end..).map(|(_, label)| label) {
+            code.push_str(&format!("{}:\n", label));
+        }
+        Ok((code, restore))
+    }
+
+    fn block_label(&self, func: &Function, block: BlockId) -> String {
+        format!(".L{}_{}", func.name, block)
+    }
+
+    // İşleneni 64 bitlik tamsayı register'ına yükler (float'lar ham bitleriyle taşınır).
+    fn load_int(&mut self, frame: &Frame, op: &Operand, reg: &str, code: &mut String) {
+        match op {
+            Operand::Temp(t) => match frame.loc(*t) {
+                Loc::Reg(src) if src == reg => {}
+                Loc::Reg(src) if is_sse(src) => code.push_str(&format!("    movq {}, {}\n", reg, src)),
+                Loc::Reg(src) => code.push_str(&format!("    mov {}, {}\n", reg, src)),
+                Loc::Stack(offset) => code.push_str(&format!("    mov {}, [rbp - {}]\n", reg, offset)),
+            },
+            Operand::Int(v) => code.push_str(&format!("    mov {}, {}\n", reg, v)),
+            Operand::Float(v) => {
+                let index = self.add_data_item(DataItem::Float64(*v));
+                code.push_str(&format!("    mov {}, [float_{}]\n", reg, index));
+            }
+            Operand::Str(s) => {
+                let index = self.add_string_literal(s.clone());
+                code.push_str(&format!("    lea {}, [str_{}]\n", reg, index));
+            }
+        }
+    }
+
+    fn load_float(&mut self, frame: &Frame, op: &Operand, reg: &str, code: &mut String) {
+        match op {
+            Operand::Temp(t) => match frame.loc(*t) {
+                Loc::Reg(src) if src == reg => {}
+                Loc::Reg(src) if is_sse(src) => code.push_str(&format!("    movapd {}, {}\n", reg, src)),
+                Loc::Reg(src) => code.push_str(&format!("    movq {}, {}\n", reg, src)),
+                Loc::Stack(offset) => code.push_str(&format!("    movsd {}, [rbp - {}]\n", reg, offset)),
+            },
+            Operand::Float(v) => {
+                let index = self.add_data_item(DataItem::Float64(*v));
+                code.push_str(&format!("    movsd {}, [float_{}]\n", reg, index));
+            }
+            _ => {
+                self.load_int(frame, op, "rax", code);
+                code.push_str(&format!("    movq {}, rax\n", reg));
+            }
+        }
+    }
+
+    // `reg`'deki değeri geçicinin yerine yazar.
+    fn store(&self, frame: &Frame, dst: Temp, reg: &str, code: &mut String) {
+        match frame.loc(dst) {
+            Loc::Reg(target) if target == reg => {}
+            Loc::Reg(target) => {
+                let mnemonic = match (is_sse(target), is_sse(reg)) {
+                    (false, false) => "mov",
+                    (true, true) => "movapd",
+                    _ => "movq",
+                };
+                code.push_str(&format!("    {} {}, {}\n", mnemonic, target, reg));
+            }
+            Loc::Stack(offset) => {
+                let mnemonic = if is_sse(reg) { "movsd" } else { "mov" };
+                code.push_str(&format!("    {} [rbp - {}], {}\n", mnemonic, offset, reg));
+            }
+        }
+    }
+
+    // Tamsayı işleneninin doğrudan yazımı; mümkün değilse `scratch`'e yüklenir.
+    fn int_source(&mut self, frame: &Frame, op: &Operand, scratch: &'static str, code: &mut String) -> String {
+        match frame.int_operand(op) {
+            Some(text) => text,
+            None => {
+                self.load_int(frame, op, scratch, code);
+                scratch.to_string()
+            }
+        }
+    }
+
+    // Float işleneninin doğrudan yazımı (XMM register'ı veya bellek); mümkün değilse `scratch`'e yüklenir.
+    fn float_source(&mut self, frame: &Frame, op: &Operand, scratch: &'static str, code: &mut String) -> String {
+        match op {
+            Operand::Temp(t) => match frame.loc(*t) {
+                Loc::Reg(reg) if is_sse(reg) => return reg.to_string(),
+                Loc::Stack(offset) => return format!("qword ptr [rbp - {}]", offset),
+                Loc::Reg(_) => {}
+            },
+            Operand::Float(v) => {
+                let index = self.add_data_item(DataItem::Float64(*v));
+                return format!("qword ptr [float_{}]", index);
+            }
+            _ => {}
+        }
+        self.load_float(frame, op, scratch, code);
+        scratch.to_string()
+    }
+
+    // Adresin bellek işlenenini döndürür; register'da olmayan indeks RCX'e, temp tabanı R11'e yüklenir.
+    fn memory_operand(&mut self, frame: &Frame, addr: &Address, code: &mut String) -> String {
+        let mut offset = addr.offset as i64;
+        let index = match &addr.index {
+            Some(Operand::Int(v)) => {
+                offset += v * 8;
+                String::new()
+            }
+            Some(op) => match frame.reg_or_imm(op) {
+                Some(reg) => format!(" + {}*8", reg),
+                None => {
+                    self.load_int(frame, op, "rcx", code);
+                    " + rcx*8".to_string()
+                }
+            },
+            None => String::new(),
+        };
+        let base = match &addr.base {
+            Base::Slot(slot) => {
+                offset -= frame.slots[slot.0 as usize] as i64;
+                "rbp".to_string()
+            }
+            Base::Temp(t) => match frame.reg_or_imm(&Operand::Temp(*t)) {
+                Some(reg) => reg,
+                None => {
+                    self.load_int(frame, &Operand::Temp(*t), "r11", code);
+                    "r11".to_string()
+                }
+            },
+            Base::Symbol(name) => name.clone(),
+        };
+        let offset = match offset {
+            0 => String::new(),
+            o if o < 0 => format!(" - {}", -o),
+            o => format!(" + {}", o),
+        };
+        format!("[{}{}{}]", base, offset, index)
+    }
+
+    fn emit_compare(&mut self, frame: &Frame, lhs: &Operand, rhs: &Operand, code: &mut String) {
+        let lhs = match frame.reg_or_imm(lhs).filter(|_| matches!(lhs, Operand::Temp(_))) {
+            Some(reg) => reg,
+            None => {
+                self.load_int(frame, lhs, "rax", code);
+                "rax".to_string()
+            }
+        };
+        let rhs = self.int_source(frame, rhs, "rcx", code);
+        code.push_str(&format!("    cmp {}, {}\n", lhs, rhs));
+    }
+
+    fn emit_inst(&mut self, func: &Function, frame: &Frame, inst: &Inst, code: &mut String) -> Result<(), String> {
+        match inst {
+            Inst::Copy { dst, src } => match frame.loc(*dst) {
+                Loc::Reg(reg) if is_sse(reg) => self.load_float(frame, src, reg, code),
+                Loc::Reg(reg) => self.load_int(frame, src, reg, code),
+                Loc::Stack(offset) => match (frame.reg_or_imm(src), src) {
+                    (Some(value), _) => code.push_str(&format!("    mov qword ptr [rbp - {}], {}\n", offset, value)),
+                    (None, Operand::Temp(t)) if matches!(frame.loc(*t), Loc::Reg(_)) => {
+                        let reg = self.float_source(frame, src, "xmm0", code);
+                        code.push_str(&format!("    movsd [rbp - {}], {}\n", offset, reg));
+                    }
+                    _ => {
+                        self.load_int(frame, src, "rax", code);
+                        self.store(frame, *dst, "rax", code);
+                    }
+                },
+            },
+            Inst::Binary { dst, op, lhs, rhs } if func.temp_type(*dst) == IrType::F64 => {
+                let mnemonic = match op {
+                    BinaryOp::Add => "addsd",
+                    BinaryOp::Sub => "subsd",
+                    BinaryOp::Mul => "mulsd",
+                    BinaryOp::Div => "divsd",
+                    BinaryOp::Rem => return Err("Float mod IR'de _fmod çağrısı olmalı.".to_string()),
+                    _ => return Err(format!("'{}' float üzerinde tanımsız.", op)),
+                };
+                let target = match frame.float_target(*dst) {
+                    reg if frame.holds(rhs, reg) => "xmm0",
+                    reg => reg,
+                };
+                self.load_float(frame, lhs, target, code);
+                let rhs = self.float_source(frame, rhs, "xmm1", code);
+                code.push_str(&format!("    {} {}, {}\n", mnemonic, target, rhs));
+                self.store(frame, *dst, target, code);
+            }
+            Inst::Binary { dst, op: op @ (BinaryOp::Div | BinaryOp::Rem), lhs, rhs } => {
+                self.load_int(frame, lhs, "rax", code);
+                // idiv anlık değer almaz.
+                let divisor = match rhs {
+                    Operand::Temp(_) => self.int_source(frame, rhs, "rcx", code),
+                    _ => {
+                        self.load_int(frame, rhs, "rcx", code);
+                        "rcx".to_string()
+                    }
+                };
+                code.push_str(&format!("    cqo\n    idiv {}\n", divisor));
+                let result = if *op == BinaryOp::Div { "rax" } else { "rdx" };
+                self.store(frame, *dst, result, code);
+            }
+            Inst::Binary { dst, op: op @ (BinaryOp::Shl | BinaryOp::Shr), lhs, rhs } => {
+                let mnemonic = if *op == BinaryOp::Shl { "shl" } else { "sar" };
+                let target = match frame.int_target(*dst) {
+                    reg if frame.holds(rhs, reg) => "rax",
+                    reg => reg,
+                };
+                self.load_int(frame, lhs, target, code);
+                // Kaydırma miktarı anlık değer ya da CL olabilir; işlemci onu 64'e göre alır.
+                match rhs {
+                    Operand::Int(v) => code.push_str(&format!("    {} {}, {}
+", mnemonic, target, v & 63)),
+                    _ => {
+                        self.load_int(frame, rhs, "rcx", code);
+                        code.push_str(&format!("    {} {}, cl\n", mnemonic, target));
+                    }
+                }
+                self.store(frame, *dst, target, code);
+            }
+            Inst::Binary { dst, op, lhs, rhs } => {
+                let target = match frame.int_target(*dst) {
+                    reg if frame.holds(rhs, reg) => "rax",
+                    reg => reg,
+                };
+                self.load_int(frame, lhs, target, code);
+                match (op, rhs) {
+                    (BinaryOp::Mul, Operand::Int(v)) if i32::try_from(*v).is_ok() => {
+                        code.push_str(&format!("    imul {}, {}, {}\n", target, target, v));
+                    }
+                    _ => {
+                        let mnemonic = match op {
+                            BinaryOp::Add => "add",
+                            BinaryOp::Sub => "sub",
+                            BinaryOp::And => "and",
+                            BinaryOp::Or => "or",
+                            BinaryOp::Xor => "xor",
+                            _ => "imul",
+                        };
+                        let rhs = self.int_source(frame, rhs, "rcx", code);
+                        code.push_str(&format!("    {} {}, {}\n", mnemonic, target, rhs));
+                    }
+                }
+                self.store(frame, *dst, target, code);
+            }
+            Inst::Cmp { dst, op, lhs, rhs } => {
+                self.emit_compare(frame, lhs, rhs, code);
+                code.push_str(&format!("    set{} al\n    movzx eax, al\n", condition_codes(*op).0));
+                self.store(frame, *dst, "rax", code);
+            }
+            Inst::Unary { dst, op: UnaryOp::Neg, src } => {
+                let target = frame.int_target(*dst);
+                self.load_int(frame, src, target, code);
+                code.push_str(&format!("    neg {}\n", target));
+                self.store(frame, *dst, target, code);
+            }
+            Inst::Unary { dst, op: UnaryOp::Not, src } => {
+                let value = match frame.reg_or_imm(src).filter(|_| matches!(src, Operand::Temp(_))) {
+                    Some(reg) => reg,
+                    None => {
+                        self.load_int(frame, src, "rax", code);
+                        "rax".to_string()
+                    }
+                };
+                code.push_str(&format!("    test {}, {}\n    sete al\n    movzx eax, al\n", value, value));
+                self.store(frame, *dst, "rax", code);
+            }
+            Inst::Convert { dst, op: ConvertOp::IntToFloat, src } => {
+                let target = frame.float_target(*dst);
+                let src = match frame.int_operand(src).filter(|_| matches!(src, Operand::Temp(_))) {
+                    Some(text) => text,
+                    None => {
+                        self.load_int(frame, src, "rax", code);
+                        "rax".to_string()
+                    }
+                };
+                code.push_str(&format!("    cvtsi2sd {}, {}\n", target, src));
+                self.store(frame, *dst, target, code);
+            }
+            Inst::Convert { dst, op: ConvertOp::FloatToInt, src } => {
+                let target = frame.int_target(*dst);
+                let src = self.float_source(frame, src, "xmm0", code);
+                code.push_str(&format!("    cvttsd2si {}, {}\n", target, src));
+                self.store(frame, *dst, target, code);
+            }
+            Inst::Load { dst, addr } => {
+                let mem = self.memory_operand(frame, addr, code);
+                match frame.loc(*dst) {
+                    Loc::Reg(reg) if is_sse(reg) => code.push_str(&format!("    movsd {}, qword ptr {}\n", reg, mem)),
+                    Loc::Reg(reg) => code.push_str(&format!("    mov {}, qword ptr {}\n", reg, mem)),
+                    Loc::Stack(_) => {
+                        code.push_str(&format!("    mov rax, qword ptr {}\n", mem));
+                        self.store(frame, *dst, "rax", code);
+                    }
+                }
+            }
+            Inst::Store { addr, src } => {
+                let (mnemonic, value) = match (frame.reg_or_imm(src), src) {
+                    (Some(value), _) => ("mov", value),
+                    (None, Operand::Temp(t)) if matches!(frame.loc(*t), Loc::Reg(_)) => ("movsd", self.float_source(frame, src, "xmm0", code)),
+                    _ => {
+                        self.load_int(frame, src, "rax", code);
+                        ("mov", "rax".to_string())
+                    }
+                };
+                let mem = self.memory_operand(frame, addr, code);
+                code.push_str(&format!("    {} qword ptr {}, {}\n", mnemonic, mem, value));
+            }
+            Inst::AddrOf { dst, addr } => {
+                let target = frame.int_target(*dst);
+                let mem = self.memory_operand(frame, addr, code);
+                code.push_str(&format!("    lea {}, {}\n", target, mem));
+                self.store(frame, *dst, target, code);
+            }
+            Inst::Zero { slot } => {
+                let words = func.slots[slot.0 as usize].size / 8;
+                let offset = frame.slots[slot.0 as usize];
+                if words == 1 {
+                    code.push_str(&format!("    mov qword ptr [rbp - {}], 0\n", offset));
+                } else {
+                    // RDI bir geçiciye atanmış olabilir; R11'de saklanır.
+                    code.push_str("    mov r11, rdi\n");
+                    code.push_str(&format!("    lea rdi, [rbp - {}]\n", offset));
+                    code.push_str("    xor eax, eax\n");
+                    code.push_str(&format!("    mov rcx, {}\n", words));
+                    code.push_str("    rep stosq\n");
+                    code.push_str("    mov rdi, r11\n");
+                }
+            }
+            Inst::Call { dst, callee, args, variadic } => {
+                let saved_stack_pointer = self.stack_pointer;
+                let mut evaluated = Vec::new();
+                for arg in args {
+                    evaluated.push(self.evaluate_ir_call_arg(frame, arg, code));
+                }
+                code.push_str(&self.emit_call(callee, &evaluated, *variadic));
+                self.stack_pointer = saved_stack_pointer;
+                match dst {
+                    Some(dst) if func.temp_type(*dst) == IrType::F64 => self.store(frame, *dst, "xmm0", code),
+                    Some(dst) => self.store(frame, *dst, "rax", code),
+                    None => {}
+                }
+            }
+            Inst::Asm { body, operands, .. } => {
+                let lines = expand_asm_body(body, |name| match operands.iter().find(|(n, _)| n == name) {
+                    Some((_, AsmOperand::Temp(t))) => match frame.loc(*t) {
+                        Loc::Stack(offset) => format!("qword ptr [rbp - {}]", offset),
+                        Loc::Reg(reg) => reg.to_string(),
+                    },
+                    Some((_, AsmOperand::Slot(slot))) => format!("[rbp - {}]", frame.slots[slot.0 as usize]),
+                    None => format!("%{}", name),
+                });
+                for line in lines {
+                    code.push_str(&format!("    {}\n", line));
+                }
+            }
+            Inst::SourceLine(_) => {}
+        }
+        Ok(())
+    }
+
+    // Çağrı argümanının bulunduğu çerçeve yuvası. Stack'teki geçiciler yuvalarından okunur;
+    // register'daki geçiciler, sabitler ve struct adresleri çağrı süresince yaşayan geçici bir yuvaya
+    // yazılır (argüman register'ları yüklenirken birbirlerini ezmesinler diye).
+    fn evaluate_ir_call_arg(&mut self, frame: &Frame, arg: &CallArg, code: &mut String) -> EvaluatedArg {
+        match arg {
+            CallArg::Value(op, ty) => {
+                let classes = vec![abi::classify_scalar(&scalar_type(*ty))];
+                if let Operand::Temp(t) = op {
+                    match frame.loc(*t) {
+                        Loc::Stack(offset) => return EvaluatedArg { offset, classes, is_address: false },
+                        Loc::Reg(reg) => {
+                            let offset = self.alloc_stack(8);
+                            let mnemonic = if is_sse(reg) { "movsd" } else { "mov" };
+                            code.push_str(&format!("    {} [rbp - {}], {}\n", mnemonic, offset, reg));
+                            return EvaluatedArg { offset, classes, is_address: false };
+                        }
+                    }
+                }
+                let offset = self.alloc_stack(8);
+                match frame.reg_or_imm(op) {
+                    Some(value) => code.push_str(&format!("    mov qword ptr [rbp - {}], {}\n", offset, value)),
+                    None => {
+                        self.load_int(frame, op, "rax", code);
+                        code.push_str(&format!("    mov qword ptr [rbp - {}], rax\n", offset));
+                    }
+                }
+                EvaluatedArg { offset, classes, is_address: false }
+            }
+            CallArg::Struct { addr, name } => {
+                let mem = self.memory_operand(frame, addr, code);
+                code.push_str(&format!("    lea rax, {}\n", mem));
+                let offset = self.alloc_stack(8);
+                code.push_str(&format!("    mov [rbp - {}], rax\n", offset));
+                let classes: Vec<ArgClass> = self.arg_classes(&Type::Custom(name.clone()));
+                EvaluatedArg { offset, classes, is_address: true }
+            }
+        }
+    }
+
+    // Koşul kodu `codes.0` doğruysa `then_block`'a, değilse `else_block`'a atlar; sıradaki bloğa
+    // düşen dal için atlama üretilmez.
+    fn emit_branch(&self, func: &Function, codes: (&str, &str), then_block: BlockId, else_block: BlockId, next: BlockId, code: &mut String) {
+        let (taken, inverse) = codes;
+        if else_block == next {
+            code.push_str(&format!("    j{} {}\n", taken, self.block_label(func, then_block)));
+            return;
+        }
+        code.push_str(&format!("    j{} {}\n", inverse, self.block_label(func, else_block)));
+        if then_block != next {
+            code.push_str(&format!("    jmp {}\n", self.block_label(func, then_block)));
+        }
+    }
+
+    fn emit_terminator(&mut self, func: &Function, frame: &Frame, term: &Terminator, next: BlockId, code: &mut String) {
+        match term {
+            Terminator::Jump(target) => {
+                if *target != next {
+                    code.push_str(&format!("    jmp {}\n", self.block_label(func, *target)));
+                }
+            }
+            Terminator::Branch { cond, then_block, else_block } => {
+                let value = match frame.reg_or_imm(cond).filter(|_| matches!(cond, Operand::Temp(_))) {
+                    Some(reg) => reg,
+                    None => {
+                        self.load_int(frame, cond, "rax", code);
+                        "rax".to_string()
+                    }
+                };
+                code.push_str(&format!("    test {}, {}\n", value, value));
+                self.emit_branch(func, ("nz", "z"), *then_block, *else_block, next, code);
+            }
+            Terminator::Return(value) if func.entry_args.is_some() => {
+                // main'den dönüş süreci sonlandırır; değer çıkış kodudur.
+                if let Some(value) = value {
+                    self.load_int(frame, value, "rax", code);
+                }
+                code.push_str(&self.generate_exit_code(value.is_some()));
+            }
+            Terminator::Return(value) => {
+                match (value, func.ret) {
+                    (Some(value), Some(IrType::F64)) => self.load_float(frame, value, "xmm0", code),
+                    (Some(value), _) => self.load_int(frame, value, "rax", code),
+                    (None, _) => {}
+                }
+                code.push_str(&format!("    jmp .fn_exit_{}\n", func.name));
+            }
+            Terminator::Unreachable => {}
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// src/codegen/regalloc.rs
+
+// IR geçicileri için linear-scan register ataması.
+//
+// Canlılık aralıkları (ir/liveness.rs) başlangıçlarına göre sıralanır ve sırayla boş bir register'a
+// yerleştirilir. Tamsayı geçiciler genel amaçlı register'lara, float geçiciler XMM register'larına
+// gider. Bir çağrının üzerinden geçen aralıklar yalnızca çağrılanın koruduğu (callee-saved)
+// register'ları alabilir; bu register'lar fonksiyon girişinde saklanıp çıkışta geri yüklenir. Boş
+// register yoksa en geç biten aralık stack'e taşınır (spill). asm blokları her register'ı bozabildiği
+// için asm'nin başvurduğu ve asm'nin üzerinden geçen geçiciler her zaman stack'te tutulur.
+// Optimize edilmeyen debug derlemelerinde (`keep_variables`) kaynak koddaki değişkenlerin
+// geçicileri de stack'te kalır; optimize edilenlerde register'a atanıp konum listesiyle tanımlanır.
+//
+// Ayırıcı yalnızca IR'ye indirilen fonksiyonlarda çalışır. Struct değerleri, defer ve bitsel işlemler
+// içeren fonksiyonlar da IR'den geçer: struct değişkenleri çerçevedeki yuvalarında durur, alanlardan
+// yüklenen geçiciler ise register alır. IR'ye indirilemeyen fonksiyonlar (match, lambda, tuple vb.)
+// AST'den üretilir ve bütün değerlerini stack'te tutar; bunlar verbose çıktıda raporlanır. Hangi
+// örneklerin IR'den geçtiğini tests/*.ir dosyaları gösterir (bkz. tests/35_regalloc.ir).
+
+use super::abi::CallConv;
+use crate::ir::liveness::{self, Interval};
+use crate::ir::{AsmOperand, Function, Inst, IrType};
+
+pub(super) struct Allocation {
+    // Geçicinin register'ı; None ise çerçevedeki yuvasında durur
+    pub regs: Vec<Option<&'static str>>,
+    // Kullanılan callee-saved register'lar (girişte saklanır, çıkışta geri yüklenir)
+    pub callee_saved: Vec<&'static str>,
+    // Geçicilerin canlılık aralıkları (register'daki değişkenlerin debug konum listeleri için)
+    pub intervals: Vec<Option<Interval>>,
+}
+
+pub(super) fn allocate(func: &Function, conv: CallConv, keep_variables: bool) -> Allocation {
+    let liveness = liveness::analyze(func);
+    let intervals = liveness::intervals(func, &liveness);
+    let starts = liveness::block_starts(func);
+
+    let mut call_positions = Vec::new();
+    let mut asm_positions = Vec::new();
+    let mut on_stack: Vec<bool> = func.temp_vars.iter().map(|var| keep_variables && var.is_some()).collect();
+    for (b, block) in func.blocks.iter().enumerate() {
+        for (i, inst) in block.insts.iter().enumerate() {
+            match inst {
+                Inst::Call { .. } => call_positions.push(starts[b] + i),
+                Inst::Asm { operands, .. } => {
+                    asm_positions.push(starts[b] + i);
+                    for (_, operand) in operands {
+                        if let AsmOperand::Temp(temp) = operand {
+                            on_stack[temp.0 as usize] = true;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut regs = vec![None; func.temps.len()];
+    let mut callee_saved = Vec::new();
+    let classes = [(IrType::I64, conv.allocatable_int_regs()), (IrType::F64, conv.allocatable_sse_regs())];
+    for (ty, (caller_regs, callee_regs)) in classes {
+        let mut order: Vec<(usize, Interval)> = intervals
+            .iter()
+            .enumerate()
+            .filter_map(|(temp, iv)| iv.map(|iv| (temp, iv)))
+            .filter(|(temp, iv)| {
+                func.temps[*temp] == ty && !on_stack[*temp] && !asm_positions.iter().any(|pos| iv.crosses(*pos))
+            })
+            .collect();
+        order.sort_by_key(|(temp, iv)| (iv.start, *temp));
+
+        // Şu an register'da yaşayan aralıklar: (geçici, aralık, register)
+        let mut active: Vec<(usize, Interval, &'static str)> = Vec::new();
+        for (temp, iv) in order {
+            active.retain(|(_, other, _)| other.end >= iv.start);
+
+            let crosses_call = call_positions.iter().any(|pos| iv.crosses(*pos));
+            let allowed: Vec<&'static str> = if crosses_call {
+                callee_regs.to_vec()
+            } else {
+                caller_regs.iter().chain(callee_regs).copied().collect()
+            };
+
+            if let Some(reg) = allowed.iter().find(|reg| !active.iter().any(|(_, _, used)| used == *reg)) {
+                regs[temp] = Some(*reg);
+                active.push((temp, iv, reg));
+                continue;
+            }
+
+            // Boş register yok: uygun register'ı tutanlar arasından en geç biteni, bu aralıktan da
+            // geç bitiyorsa stack'e taşınır; aksi halde bu aralığın kendisi stack'te kalır.
+            let victim = active
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, _, reg))| allowed.contains(reg))
+                .max_by_key(|(_, (_, other, _))| other.end)
+                .map(|(index, _)| index);
+            if let Some(index) = victim {
+                let (spilled, other, reg) = active[index];
+                if other.end > iv.end {
+                    regs[spilled] = None;
+                    active.remove(index);
+                    regs[temp] = Some(reg);
+                    active.push((temp, iv, reg));
+                }
+            }
+        }
+
+        for reg in callee_regs {
+            if regs.contains(&Some(*reg)) {
+                callee_saved.push(*reg);
+            }
+        }
+    }
+    Allocation { regs, callee_saved, intervals }
+}
@@ -0,0 +1,606 @@
+// src/codegen/wasm.rs
+
+// IR fonksiyonlarından WebAssembly metni (WAT) üretimi (--target wasm32-wasi). Çıktı tek bir
+// `(module ...)` metnidir; libs/core_wasi.wat runtime'ı modülün içine gömülür ve G/Ç WASI
+// (wasi_snapshot_preview1) içe aktarımlarıyla yapılır. İkili modülü assembler::wasm üretir.
+//
+// Yalnızca IR'ye indirilebilen fonksiyonlar derlenebilir (AArch64 hedefiyle aynı kural). Geçiciler
+// Wasm yerelleri ($tN) olur; struct'lar ve diziler gibi adresi alınan yuvalar, `$__stack_pointer`
+// global'inin gösterdiği gölge stack'te ayrılan çerçevede durur ($fp). Wasm'da serbest atlama
+// olmadığı için birden fazla bloklu fonksiyonlar `$bb` yereli üzerinden br_table ile dağıtılan
+// bir döngüye indirilir; ardışık bloklara geçiş doğrudan akışla yapılır.
+
+use std::collections::HashMap;
+
+use super::{Codegen, DataItem};
+use crate::ast::{Decl, Type};
+use crate::ir::{
+    self, Address, Base, BinaryOp, BlockId, CallArg, CmpOp, ConvertOp, Function, Inst, IrType, Operand, Param, Terminator, UnaryOp,
+};
+
+// Program fonksiyonlarından önce modüle eklenen runtime (memory ve $__heap_base hariç)
+const RUNTIME: &str = include_str!("../../libs/core_wasi.wat");
+
+// Bellek haritası: libs/core_wasi.wat başındaki tabloyla aynı olmalıdır.
+const ARGC_ADDR: i64 = 0x102400;
+const ARGV_ADDR: i64 = 0x102408;
+const DATA_BASE: u32 = 0x103000;
+const PAGE_SIZE: u32 = 65536;
+
+// Bir fonksiyonun Wasm imzası (tamsayılar ve pointer'lar i64, float'lar f64)
+#[derive(Debug, Clone, PartialEq)]
+struct Signature {
+    params: Vec<IrType>,
+    ret: Option<IrType>,
+}
+
+fn wasm_type(ty: IrType) -> &'static str {
+    match ty {
+        IrType::I64 => "i64",
+        IrType::F64 => "f64",
+    }
+}
+
+fn signature_type(ty: &Type) -> IrType {
+    if ty.is_float() { IrType::F64 } else { IrType::I64 }
+}
+
+// Runtime'ın program tarafından çağrılabilen fonksiyonları: `(func $ad (param ...) (result ...)`
+// başlık satırlarından okunur. `_rt_` önekli iç yardımcılar ve i32 kullananlar dışarıda kalır.
+fn runtime_signatures() -> HashMap<String, Signature> {
+    let mut signatures = HashMap::new();
+    for line in RUNTIME.lines() {
+        let Some(rest) = line.trim().strip_prefix("(func $") else { continue };
+        let name: String = rest.chars().take_while(|c| !c.is_whitespace() && *c != ')').collect();
+        if name.starts_with("_rt_") {
+            continue;
+        }
+        let mut params = Vec::new();
+        let mut ret = None;
+        for group in rest.split('(').skip(1) {
+            let mut words = group.trim_end_matches([')', ' ']).split_whitespace();
+            let kind = words.next();
+            let mut types = words.filter(|w| !w.starts_with('$')).map(|w| match w {
+                "i64" => Some(IrType::I64),
+                "f64" => Some(IrType::F64),
+                _ => None,
+            });
+            match kind {
+                Some("param") => params.extend(types),
+                Some("result") => ret = types.next_back().flatten(),
+                _ => {}
+            }
+        }
+        if let Some(params) = params.into_iter().collect::<Option<Vec<IrType>>>() {
+            signatures.insert(name, Signature { params, ret });
+        }
+    }
+    signatures
+}
+
+// f64 sabiti: sonlu değerler ondalık, diğerleri ham bitleriyle yazılır.
+fn f64_const(value: f64, code: &mut String) {
+    if value.is_finite() {
+        code.push_str(&format!("    f64.const {:?}\n", value));
+    } else {
+        code.push_str(&format!("    i64.const {}\n    f64.reinterpret_i64\n", value.to_bits() as i64));
+    }
+}
+
+// Yığındaki `from` tipindeki değeri `to` tipine ham bitleriyle çevirir (x86-64/AArch64'teki
+// register taşımalarıyla aynı anlam).
+fn reinterpret(from: IrType, to: IrType, code: &mut String) {
+    match (from, to) {
+        (IrType::I64, IrType::F64) => code.push_str("    f64.reinterpret_i64\n"),
+        (IrType::F64, IrType::I64) => code.push_str("    i64.reinterpret_f64\n"),
+        _ => {}
+    }
+}
+
+// WAT string literali: yazdırılabilir ASCII dışındaki byte'lar \HH olarak kaçışlanır.
+fn wat_string(bytes: &[u8]) -> String {
+    let mut out = String::new();
+    for &b in bytes {
+        match b {
+            b'"' => out.push_str("\\\""),
+            b'\\' => out.push_str("\\\\"),
+            0x20..=0x7e => out.push(b as char),
+            _ => out.push_str(&format!("\\{:02x}", b)),
+        }
+    }
+    out
+}
+
+struct Frame {
+    // Yuvaların $fp'ye göre ofsetleri
+    slots: Vec<u32>,
+    // Variadic argümanların yazıldığı alanın ofseti
+    varargs: u32,
+    size: u32,
+}
+
+impl<'a, 'b> Codegen<'a, 'b> {
+    pub(super) fn generate_wasm_program(&mut self) -> Result<String, String> {
+        let mut module = ir::lower::lower_program(self.program, self.type_checker, self.target_platform);
+        ir::opt::optimize(&mut module, self.opt_level);
+
+        let runtime = runtime_signatures();
+        let mut signatures = runtime.clone();
+        let mut functions = Vec::new();
+        let mut lowered = module.functions.into_iter();
+        for decl in self.program.iter() {
+            if let Decl::Function { name, is_public, .. } = decl {
+                let function = match lowered.next() {
+                    Some(Ok(function)) => function,
+                    Some(Err(skipped)) => {
+                        self.current_decl_span = decl.span();
+                        return Err(format!(
+                            "Hata: '{}' fonksiyonu WebAssembly hedefinde derlenemiyor: {} (WebAssembly kod üreticisi yalnızca IR'ye indirilebilen fonksiyonları destekler.)",
+                            name, skipped.reason
+                        ));
+                    }
+                    None => return Err(format!("Hata: '{}' fonksiyonunun IR'si bulunamadı.", name)),
+                };
+                let params = function
+                    .params
+                    .iter()
+                    .map(|param| match param {
+                        Param::Scalar(temp) => function.temp_type(*temp),
+                        Param::Struct { .. } => IrType::I64,
+                    })
+                    .collect();
+                signatures.insert(name.clone(), Signature { params, ret: function.ret });
+                functions.push((decl.span(), *is_public, function));
+            }
+        }
+
+        // Runtime'da karşılığı olmayan dış fonksiyonlar host'tan ("env" modülü) içe aktarılır.
+        let mut imports = String::new();
+        for decl in self.program.iter() {
+            if let Decl::ExternFn { name, params, return_type, .. } = decl {
+                if runtime.contains_key(name) || signatures.contains_key(name) {
+                    continue;
+                }
+                let signature = Signature {
+                    params: params.iter().map(|(_, ty, _)| signature_type(ty)).collect(),
+                    ret: if *return_type == Type::Void { None } else { Some(signature_type(return_type)) },
+                };
+                imports.push_str(&format!("(import \"env\" \"{}\" (func ${}{}))\n", name, name, signature_text(&signature)));
+                signatures.insert(name.clone(), signature);
+            }
+        }
+
+        let mut text = String::new();
+        let mut exports = String::new();
+        for (span, is_public, function) in &functions {
+            self.current_decl_span = *span;
+            self.current_function_name = function.name.clone();
+            text.push_str(&self.generate_wasm_function(function, &signatures)?);
+            if function.entry_args.is_some() {
+                exports.push_str(&format!("(export \"_start\" (func ${}))\n", function.name));
+            } else if *is_public {
+                // Eklenti olarak kullanılan modüllerin `pub` fonksiyonları host'a açılır.
+                exports.push_str(&format!("(export \"{}\" (func ${}))\n", function.name, function.name));
+            }
+        }
+        if exports.is_empty() || !functions.iter().any(|(_, _, f)| f.entry_args.is_some()) {
+            return Err("Hata: Programda 'main' fonksiyonu bulunamadı.".to_string());
+        }
+
+        let mut wat = String::from("(module\n");
+        wat.push_str(&imports);
+        wat.push_str(&RUNTIME.replace("\r\n", "\n"));
+        wat.push_str("\n;; --- Program ---\n");
+        wat.push_str(&self.generate_wasm_data_segment());
+        wat.push_str(&text);
+        wat.push_str("(export \"memory\" (memory 0))\n");
+        wat.push_str(&exports);
+        wat.push_str(")\n");
+        Ok(wat)
+    }
+
+    // String literalleri DATA_BASE'den itibaren sıralanır; her biri `$str_N` sabit global'iyle
+    // adreslenir. Heap, verinin bittiği yerden başlar; başlangıç belleği bunu kapsayacak kadardır.
+    fn generate_wasm_data_segment(&self) -> String {
+        let mut data = String::new();
+        let mut globals = String::new();
+        let mut address = DATA_BASE;
+        for (i, item) in self.data_items.iter().enumerate() {
+            if let DataItem::String(s) = item {
+                globals.push_str(&format!("(global $str_{} i64 (i64.const {}))\n", i, address));
+                data.push_str(&format!("(data (i32.const {}) \"{}\\00\")\n", address, wat_string(s.as_bytes())));
+                address += s.len() as u32 + 1;
+            }
+        }
+        let heap_base = address.next_multiple_of(16);
+        let pages = heap_base.div_ceil(PAGE_SIZE) + 16;
+        let mut wat = format!("(memory {})\n", pages);
+        wat.push_str(&format!("(global $__heap_base i32 (i32.const {}))\n", heap_base));
+        wat.push_str(&globals);
+        wat.push_str(&data);
+        wat
+    }
+
+    fn generate_wasm_function(&mut self, func: &Function, signatures: &HashMap<String, Signature>) -> Result<String, String> {
+        // Çerçeve: yuvalar 8 byte hizalı, en sonda en büyük variadic çağrının argüman alanı
+        let mut slots = Vec::new();
+        let mut size = 0u32;
+        for slot in &func.slots {
+            slots.push(size);
+            size += (slot.size.max(0) as u32).next_multiple_of(8);
+        }
+        let varargs = size;
+        for inst in func.blocks.iter().flat_map(|b| &b.insts) {
+            if let Inst::Call { callee, args, variadic: true, .. } = inst {
+                let fixed = signatures.get(callee).map_or(0, |s| s.params.len().saturating_sub(1));
+                size = size.max(varargs + (args.len().saturating_sub(fixed) * 8) as u32);
+            }
+        }
+        let frame = Frame { slots, varargs, size: size.next_multiple_of(16) };
+        if frame.size as i32 > super::MAX_FRAME_SIZE {
+            return Err(format!(
+                "Hata: '{}' fonksiyonunun yerel değişken alanı ({} byte) izin verilen {} byte sınırını aşıyor. Büyük diziler için global veya heap bellek kullanın.",
+                func.name,
+                frame.size,
+                super::MAX_FRAME_SIZE
+            ));
+        }
+
+        let mut header = format!("(func ${}", func.name);
+        let mut locals = String::new();
+        let mut is_param = vec![false; func.temps.len()];
+        for (i, param) in func.params.iter().enumerate() {
+            match param {
+                Param::Scalar(temp) => {
+                    is_param[temp.0 as usize] = true;
+                    header.push_str(&format!(" (param $t{} {})", temp.0, wasm_type(func.temp_type(*temp))));
+                }
+                Param::Struct { .. } => header.push_str(&format!(" (param $arg{} i64)", i)),
+            }
+        }
+        if let Some(ret) = func.ret {
+            if func.entry_args.is_none() {
+                header.push_str(&format!(" (result {})", wasm_type(ret)));
+            }
+        }
+        for (i, ty) in func.temps.iter().enumerate() {
+            if !is_param[i] {
+                locals.push_str(&format!(" (local $t{} {})", i, wasm_type(*ty)));
+            }
+        }
+        let dispatch = func.blocks.len() > 1 || !matches!(func.blocks[0].term, Terminator::Return(_) | Terminator::Unreachable);
+        locals.push_str(" (local $fp i32)");
+        if dispatch {
+            locals.push_str(" (local $bb i32)");
+        }
+
+        let mut code = String::new();
+        if frame.size > 0 {
+            code.push_str("    global.get $__stack_pointer\n");
+            code.push_str(&format!("    i32.const {}\n", frame.size));
+            code.push_str("    i32.sub\n");
+            code.push_str("    local.tee $fp\n");
+            code.push_str("    global.set $__stack_pointer\n");
+        }
+        if let Some((argc, argv)) = func.entry_args {
+            code.push_str("    call $_rt_init_args\n");
+            code.push_str(&format!("    i32.const {}\n    i64.load\n    local.set $t{}\n", ARGC_ADDR, argc.0));
+            code.push_str(&format!("    i32.const {}\n    i64.load\n    local.set $t{}\n", ARGV_ADDR, argv.0));
+        }
+        // Değerle geçirilen struct'lar çağıranın kopyasından çerçeveye alınır.
+        for (i, param) in func.params.iter().enumerate() {
+            if let Param::Struct { slot, .. } = param {
+                code.push_str(&format!("    local.get $fp\n    i32.const {}\n    i32.add\n", frame.slots[slot.0 as usize]));
+                code.push_str(&format!("    local.get $arg{}\n    i32.wrap_i64\n", i));
+                code.push_str(&format!("    i32.const {}\n    memory.copy\n", func.slots[slot.0 as usize].size));
+            }
+        }
+
+        let count = func.blocks.len();
+        if dispatch {
+            code.push_str("    loop $dispatch\n");
+            for i in (0..count).rev() {
+                code.push_str(&format!("    block $b{}\n", i));
+            }
+            code.push_str("    local.get $bb\n    br_table");
+            for i in 0..count {
+                code.push_str(&format!(" $b{}", i));
+            }
+            code.push_str(" $b0\n");
+        }
+        for (i, block) in func.blocks.iter().enumerate() {
+            if dispatch {
+                code.push_str(&format!("    end ;; {}\n", BlockId(i as u32)));
+            }
+            for inst in &block.insts {
+                if let Inst::SourceLine(_) = inst {
+                    continue;
+                }
+                code.push_str(&format!("    ;; {}\n", inst));
+                self.emit_wasm_inst(func, &frame, signatures, inst, &mut code)?;
+            }
+            self.emit_wasm_terminator(func, &frame, &block.term, BlockId(i as u32 + 1), &mut code);
+        }
+        if dispatch {
+            code.push_str("    end\n    unreachable\n");
+        }
+
+        Ok(format!("{}{}\n{})\n\n", header, locals, code))
+    }
+
+    // İşleneni `ty` tipinde Wasm yığınına koyar.
+    fn emit_wasm_operand(&mut self, func: &Function, op: &Operand, ty: IrType, code: &mut String) {
+        match op {
+            Operand::Temp(t) => {
+                code.push_str(&format!("    local.get $t{}\n", t.0));
+                reinterpret(func.temp_type(*t), ty, code);
+            }
+            Operand::Int(v) => {
+                code.push_str(&format!("    i64.const {}\n", v));
+                reinterpret(IrType::I64, ty, code);
+            }
+            Operand::Float(v) if ty == IrType::F64 => f64_const(*v, code),
+            Operand::Float(v) => code.push_str(&format!("    i64.const {}\n", v.to_bits() as i64)),
+            Operand::Str(s) => {
+                let index = self.add_string_literal(s.clone());
+                code.push_str(&format!("    global.get $str_{}\n", index));
+                reinterpret(IrType::I64, ty, code);
+            }
+        }
+    }
+
+    fn operand_type(func: &Function, op: &Operand) -> IrType {
+        match op {
+            Operand::Temp(t) => func.temp_type(*t),
+            Operand::Float(_) => IrType::F64,
+            _ => IrType::I64,
+        }
+    }
+
+    // Adresin taban kısmını (i32) yığına koyar; load/store'un `offset=` alanına yazılacak sabit
+    // ofseti döndürür. Negatif ofsetler tabana eklenir.
+    fn emit_wasm_address(&mut self, func: &Function, frame: &Frame, addr: &Address, code: &mut String) -> u32 {
+        let mut offset = addr.offset as i64;
+        match &addr.base {
+            Base::Slot(slot) => {
+                code.push_str("    local.get $fp\n");
+                offset += frame.slots[slot.0 as usize] as i64;
+            }
+            Base::Temp(t) => {
+                self.emit_wasm_operand(func, &Operand::Temp(*t), IrType::I64, code);
+                code.push_str("    i32.wrap_i64\n");
+            }
+            Base::Symbol(name) => {
+                let address = match name.as_str() {
+                    "_nim_argc" => ARGC_ADDR,
+                    "_nim_argv" => ARGV_ADDR,
+                    _ => 0,
+                };
+                code.push_str(&format!("    i32.const {}\n", address));
+            }
+        }
+        match &addr.index {
+            Some(Operand::Int(v)) => offset += v * 8,
+            Some(op) => {
+                self.emit_wasm_operand(func, op, IrType::I64, code);
+                code.push_str("    i32.wrap_i64\n    i32.const 3\n    i32.shl\n    i32.add\n");
+            }
+            None => {}
+        }
+        if (0..=u32::MAX as i64).contains(&offset) {
+            return offset as u32;
+        }
+        code.push_str(&format!("    i32.const {}\n    i32.add\n", offset as i32));
+        0
+    }
+
+    fn emit_wasm_inst(&mut self, func: &Function, frame: &Frame, signatures: &HashMap<String, Signature>, inst: &Inst, code: &mut String) -> Result<(), String> {
+        match inst {
+            Inst::Copy { dst, src } => {
+                self.emit_wasm_operand(func, src, func.temp_type(*dst), code);
+                code.push_str(&format!("    local.set $t{}\n", dst.0));
+            }
+            Inst::Binary { dst, op, lhs, rhs } if func.temp_type(*dst) == IrType::F64 => {
+                let mnemonic = match op {
+                    BinaryOp::Add => "f64.add",
+                    BinaryOp::Sub => "f64.sub",
+                    BinaryOp::Mul => "f64.mul",
+                    BinaryOp::Div => "f64.div",
+                    BinaryOp::Rem => return Err("Float mod IR'de _fmod çağrısı olmalı.".to_string()),
+                    _ => return Err(format!("'{}' float üzerinde tanımsız.", op)),
+                };
+                self.emit_wasm_operand(func, lhs, IrType::F64, code);
+                self.emit_wasm_operand(func, rhs, IrType::F64, code);
+                code.push_str(&format!("    {}\n    local.set $t{}\n", mnemonic, dst.0));
+            }
+            Inst::Binary { dst, op, lhs, rhs } => {
+                let mnemonic = match op {
+                    BinaryOp::Add => "i64.add",
+                    BinaryOp::Sub => "i64.sub",
+                    BinaryOp::Mul => "i64.mul",
+                    BinaryOp::Div => "i64.div_s",
+                    BinaryOp::Rem => "i64.rem_s",
+                    BinaryOp::And => "i64.and",
+                    BinaryOp::Or => "i64.or",
+                    BinaryOp::Xor => "i64.xor",
+                    // Wasm kaydırma miktarını 64'e göre alır.
+                    BinaryOp::Shl => "i64.shl",
+                    BinaryOp::Shr => "i64.shr_s",
+                };
+                self.emit_wasm_operand(func, lhs, IrType::I64, code);
+                self.emit_wasm_operand(func, rhs, IrType::I64, code);
+                code.push_str(&format!("    {}\n    local.set $t{}\n", mnemonic, dst.0));
+            }
+            Inst::Cmp { dst, op, lhs, rhs } => {
+                let mnemonic = match op {
+                    CmpOp::Eq => "i64.eq",
+                    CmpOp::Ne => "i64.ne",
+                    CmpOp::Lt => "i64.lt_s",
+                    CmpOp::Le => "i64.le_s",
+                    CmpOp::Gt => "i64.gt_s",
+                    CmpOp::Ge => "i64.ge_s",
+                };
+                self.emit_wasm_operand(func, lhs, IrType::I64, code);
+                self.emit_wasm_operand(func, rhs, IrType::I64, code);
+                code.push_str(&format!("    {}\n    i64.extend_i32_u\n    local.set $t{}\n", mnemonic, dst.0));
+            }
+            Inst::Unary { dst, op: UnaryOp::Neg, src } => {
+                code.push_str("    i64.const 0\n");
+                self.emit_wasm_operand(func, src, IrType::I64, code);
+                code.push_str(&format!("    i64.sub\n    local.set $t{}\n", dst.0));
+            }
+            Inst::Unary { dst, op: UnaryOp::Not, src } => {
+                self.emit_wasm_operand(func, src, IrType::I64, code);
+                code.push_str(&format!("    i64.eqz\n    i64.extend_i32_u\n    local.set $t{}\n", dst.0));
+            }
+            Inst::Convert { dst, op: ConvertOp::IntToFloat, src } => {
+                self.emit_wasm_operand(func, src, IrType::I64, code);
+                code.push_str(&format!("    f64.convert_i64_s\n    local.set $t{}\n", dst.0));
+            }
+            Inst::Convert { dst, op: ConvertOp::FloatToInt, src } => {
+                self.emit_wasm_operand(func, src, IrType::F64, code);
+                code.push_str(&format!("    i64.trunc_sat_f64_s\n    local.set $t{}\n", dst.0));
+            }
+            Inst::Load { dst, addr } => {
+                let offset = self.emit_wasm_address(func, frame, addr, code);
+                code.push_str(&format!("    {}.load{}\n", wasm_type(func.temp_type(*dst)), memarg(offset)));
+                code.push_str(&format!("    local.set $t{}\n", dst.0));
+            }
+            Inst::Store { addr, src } => {
+                let ty = Self::operand_type(func, src);
+                let offset = self.emit_wasm_address(func, frame, addr, code);
+                self.emit_wasm_operand(func, src, ty, code);
+                code.push_str(&format!("    {}.store{}\n", wasm_type(ty), memarg(offset)));
+            }
+            Inst::AddrOf { dst, addr } => {
+                let offset = self.emit_wasm_address(func, frame, addr, code);
+                if offset != 0 {
+                    code.push_str(&format!("    i32.const {}\n    i32.add\n", offset));
+                }
+                code.push_str(&format!("    i64.extend_i32_u\n    local.set $t{}\n", dst.0));
+            }
+            Inst::Zero { slot } => {
+                code.push_str(&format!("    local.get $fp\n    i32.const {}\n    i32.add\n", frame.slots[slot.0 as usize]));
+                code.push_str(&format!("    i32.const 0\n    i32.const {}\n    memory.fill\n", func.slots[slot.0 as usize].size));
+            }
+            Inst::Call { dst, callee, args, variadic } => {
+                let Some(signature) = signatures.get(callee) else {
+                    return Err(format!("Hata: '{}' fonksiyonu WebAssembly hedefinde bulunamadı (runtime'da veya programda tanımlı değil).", callee));
+                };
+                // Variadic çağrılarda son parametre, argümanların 8'er byte yazıldığı alanın adresidir.
+                let fixed = if *variadic { signature.params.len().saturating_sub(1) } else { signature.params.len() };
+                if args.len() < fixed || (!*variadic && args.len() != fixed) {
+                    return Err(format!("Hata: '{}' fonksiyonu {} argüman bekler, {} verildi.", callee, fixed, args.len()));
+                }
+                for (j, arg) in args[fixed..].iter().enumerate() {
+                    let CallArg::Value(op, ty) = arg else {
+                        return Err(format!("Hata: '{}' çağrısında struct variadic argüman olarak geçirilemez.", callee));
+                    };
+                    code.push_str("    local.get $fp\n");
+                    self.emit_wasm_operand(func, op, *ty, code);
+                    code.push_str(&format!("    {}.store{}\n", wasm_type(*ty), memarg(frame.varargs + j as u32 * 8)));
+                }
+                for (arg, ty) in args[..fixed].iter().zip(&signature.params) {
+                    match arg {
+                        CallArg::Value(op, _) => self.emit_wasm_operand(func, op, *ty, code),
+                        CallArg::Struct { addr, .. } => {
+                            let offset = self.emit_wasm_address(func, frame, addr, code);
+                            if offset != 0 {
+                                code.push_str(&format!("    i32.const {}\n    i32.add\n", offset));
+                            }
+                            code.push_str("    i64.extend_i32_u\n");
+                        }
+                    }
+                }
+                if *variadic {
+                    code.push_str(&format!("    local.get $fp\n    i32.const {}\n    i32.add\n    i64.extend_i32_u\n", frame.varargs));
+                }
+                code.push_str(&format!("    call ${}\n", callee));
+                match (dst, signature.ret) {
+                    (Some(dst), Some(ret)) => {
+                        reinterpret(ret, func.temp_type(*dst), code);
+                        code.push_str(&format!("    local.set $t{}\n", dst.0));
+                    }
+                    (Some(dst), None) => {
+                        self.emit_wasm_operand(func, &Operand::Int(0), func.temp_type(*dst), code);
+                        code.push_str(&format!("    local.set $t{}\n", dst.0));
+                    }
+                    (None, Some(_)) => code.push_str("    drop\n"),
+                    (None, None) => {}
+                }
+            }
+            Inst::Asm { span, .. } => {
+                self.error_span = Some(*span);
+                return Err(format!("Hata: '{}' fonksiyonundaki asm bloğu WebAssembly hedefinde derlenemez.", func.name));
+            }
+            Inst::SourceLine(_) => {}
+        }
+        Ok(())
+    }
+
+    fn emit_wasm_terminator(&mut self, func: &Function, frame: &Frame, term: &Terminator, next: BlockId, code: &mut String) {
+        let jump = |code: &mut String, target: BlockId| {
+            code.push_str(&format!("    i32.const {}\n    local.set $bb\n    br $dispatch\n", target.0));
+        };
+        match term {
+            Terminator::Jump(target) => {
+                if *target != next {
+                    jump(code, *target);
+                }
+            }
+            Terminator::Branch { cond, then_block, else_block } => {
+                self.emit_wasm_operand(func, cond, IrType::I64, code);
+                if *else_block == next {
+                    code.push_str("    i64.eqz\n    i32.eqz\n    if\n");
+                    jump(code, *then_block);
+                    code.push_str("    end\n");
+                } else if *then_block == next {
+                    code.push_str("    i64.eqz\n    if\n");
+                    jump(code, *else_block);
+                    code.push_str("    end\n");
+                } else {
+                    code.push_str("    i64.eqz\n    if\n");
+                    jump(code, *else_block);
+                    code.push_str("    end\n");
+                    jump(code, *then_block);
+                }
+            }
+            Terminator::Return(value) if func.entry_args.is_some() => {
+                // main'den dönüş süreci sonlandırır; değer çıkış kodudur.
+                match value {
+                    Some(value) => self.emit_wasm_operand(func, value, IrType::I64, code),
+                    None => code.push_str("    i64.const 0\n"),
+                }
+                code.push_str("    call $_exit_process\n    unreachable\n");
+            }
+            Terminator::Return(value) => {
+                match (value, func.ret) {
+                    (Some(value), Some(ret)) => self.emit_wasm_operand(func, value, ret, code),
+                    (None, Some(ret)) => self.emit_wasm_operand(func, &Operand::Int(0), ret, code),
+                    _ => {}
+                }
+                if frame.size > 0 {
+                    code.push_str(&format!("    local.get $fp\n    i32.const {}\n    i32.add\n    global.set $__stack_pointer\n", frame.size));
+                }
+                code.push_str("    return\n");
+            }
+            Terminator::Unreachable => code.push_str("    unreachable\n"),
+        }
+    }
+}
+
+// Bellek erişiminin `offset=` eki
+fn memarg(offset: u32) -> String {
+    if offset == 0 { String::new() } else { format!(" offset={}", offset) }
+}
+
+fn signature_text(signature: &Signature) -> String {
+    let mut text = String::new();
+    for ty in &signature.params {
+        text.push_str(&format!(" (param {})", wasm_type(*ty)));
+    }
+    if let Some(ret) = signature.ret {
+        text.push_str(&format!(" (result {})", wasm_type(ret)));
+    }
+    text
+}
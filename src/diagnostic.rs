@@ -0,0 +1,422 @@
+// src/diagnostic.rs
+//
+// Kaynak konumları (Span) ve derleyicinin tüm aşamalarının ürettiği ortak hata tipi (Diagnostic).
+// Lexer her token'a, Parser her AST düğümüne bir Span verir; Parser, TypeChecker ve Codegen
+// hatalarını Diagnostic olarak döndürür ve sürücü (main.rs) bunları kaynak satırı ve
+// altı çizili işaretlerle (^^^) ekrana basar. `--error-format json` ile her Diagnostic tek satırlık
+// bir JSON nesnesi olarak yazılır (editör eklentileri ve CI araçları için).
+
+use std::fmt;
+
+// SourceMap içindeki bir dosyanın indeksi.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct FileId(pub usize);
+
+// Kaynak koddaki bir aralık. `start`/`end` byte ofsetleridir (end hariç),
+// `line`/`column` 1'den başlar. `line == 0` konumu bilinmeyen (sentetik) düğümler içindir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Span {
+    pub file: FileId,
+    pub line: usize,
+    pub column: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(file: FileId, line: usize, column: usize, start: usize, end: usize) -> Self {
+        Self { file, line, column, start, end }
+    }
+
+    // Konumu olmayan düğümler için (örn: derleyicinin kendi ürettiği ifadeler).
+    pub fn dummy() -> Self {
+        Self::default()
+    }
+
+    pub fn is_dummy(&self) -> bool {
+        self.line == 0
+    }
+
+    // Bu span'in başından `other`'ın sonuna kadar uzanan span.
+    pub fn to(self, other: Span) -> Span {
+        if self.is_dummy() { return other; }
+        if other.is_dummy() || other.file != self.file || other.end < self.start { return self; }
+        Span { end: other.end, ..self }
+    }
+}
+
+pub struct SourceFile {
+    pub name: String,
+    pub source: String,
+}
+
+// Derlemeye katılan tüm kaynak dosyalar (ana dosya + `use` ile yüklenen modüller).
+#[derive(Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, name: String, source: String) -> FileId {
+        self.files.push(SourceFile { name, source });
+        FileId(self.files.len() - 1)
+    }
+
+    pub fn get(&self, id: FileId) -> Option<&SourceFile> {
+        self.files.get(id.0)
+    }
+
+    // FileId sırasıyla kayıtlı dosyaların adları.
+    pub fn names(&self) -> Vec<String> {
+        self.files.iter().map(|f| f.name.clone()).collect()
+    }
+
+    // Byte ofsetinin 1'den başlayan (satır, sütun) karşılığı. Sütun, lexer'daki gibi karakter sayısıdır.
+    pub fn position(&self, id: FileId, offset: usize) -> Option<(usize, usize)> {
+        let source = &self.get(id)?.source;
+        let before = source.get(..offset.min(source.len()))?;
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Some((line, before[line_start..].chars().count() + 1))
+    }
+
+    // 1'den başlayan satır numarasına göre satır metni (satır sonu karakterleri olmadan).
+    pub fn line_text(&self, id: FileId, line: usize) -> Option<&str> {
+        let file = self.get(id)?;
+        file.source.lines().nth(line.checked_sub(1)?).map(|l| l.trim_end_matches('\r'))
+    }
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "hata",
+            Severity::Warning => "uyarı",
+            Severity::Note => "not",
+        }
+    }
+
+    // JSON çıktısındaki sabit (dilden bağımsız) ad.
+    pub fn json_name(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        }
+    }
+}
+
+// Hatayı üreten derleyici aşaması.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Lexer,
+    Preprocessor, // YENİ: #define/#ifdef direktifleri
+    Parser,
+    Type,
+    Codegen,
+    Link,
+    Runtime, // YENİ: Yorumlayıcının (`run --interp`) çalışma zamanı hataları
+}
+
+impl Stage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Stage::Lexer => "lexer",
+            Stage::Preprocessor => "preprocessor",
+            Stage::Parser => "parser",
+            Stage::Type => "type",
+            Stage::Codegen => "codegen",
+            Stage::Link => "link",
+            Stage::Runtime => "runtime",
+        }
+    }
+}
+
+// Kaynak üzerinde işaretlenen bir aralık. Birincil etiket '^', ikincil etiket '-' ile çizilir.
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+    pub primary: bool,
+}
+
+// Hata kodları: her aşamanın kendi aralığı vardır. Araçlar bu kodlara güvenebilir; değiştirmeyin.
+// Aşamanın genel kodu (E0100, E0200) daha belirli bir türe girmeyen hatalar içindir.
+pub const E_SYNTAX: &str = "E0001";
+pub const E_LEXER: &str = "E0002";
+pub const E_PREPROCESSOR: &str = "E0003";
+pub const E_TYPE: &str = "E0100";
+pub const E_UNDEFINED: &str = "E0101"; // Tanımlanmamış değişken, fonksiyon, tip veya etiket
+pub const E_MISMATCH: &str = "E0102"; // Tip uyuşmazlığı
+pub const E_ARITY: &str = "E0103"; // Eksik, fazla veya tekrarlanan argüman
+pub const E_REDEFINED: &str = "E0104"; // Aynı kapsamda yeniden tanımlama
+pub const E_UNKNOWN_TYPE: &str = "E0105"; // Bilinmeyen tip adı
+pub const E_NO_MEMBER: &str = "E0106"; // Olmayan veya eksik alan, üye ya da metot
+pub const E_IMMUTABLE: &str = "E0107"; // Sabit veya değiştirilemeyen değişkene atama
+pub const E_LITERAL_RANGE: &str = "E0108"; // Sabitin hedef tipin aralığı dışında olması
+pub const E_MODULE: &str = "E0109"; // Bulunamayan modül veya dışa aktarılmamış öğe
+pub const E_CODEGEN: &str = "E0200";
+pub const E_UNSUPPORTED: &str = "E0201"; // Arka uçta veya hedefte henüz desteklenmeyen özellik
+pub const E_LINK: &str = "E0300";
+pub const E_RUNTIME: &str = "E0400";
+
+// Tip denetleyicisi hataları mesaj olarak taşınır; kod, mesajın türünden (ilk eşleşen satır) bulunur.
+// Yeni bir hata mesajı eklerken ilgili türün ifadelerinden birini kullanın.
+const TYPE_ERROR_KINDS: [(&str, &[&str]); 9] = [
+    (E_MODULE, &["modülü", "modülünde"]),
+    (E_REDEFINED, &["zaten tanım"]),
+    (E_LITERAL_RANGE, &["aralığı dışında", "aralığının altında"]),
+    (E_IMMUTABLE, &["yeniden atanamaz", "değiştirilemeyen (immutable)"]),
+    (E_UNDEFINED, &["Tanımlanmamış", "bulunamadı", "tanımlı değil", "tanımsız"]),
+    (E_UNKNOWN_TYPE, &["Bilinmeyen tip '"]),
+    (E_NO_MEMBER, &["isminde bir alanı", "isminde bir üyesi", "isminde bir metodu", "alanı eksik"]),
+    (E_ARITY, &["argüman", "parametresi sağlanmadı", "birden fazla kez sağlandı", "hem pozisyonel", "isminde bir parametresi yok"]),
+    (E_MISMATCH, &["uyuşmuyor", "uyumsuz", "uyuşmazlığı", "bulundu", "bulunan", "aynı tip", "uygulanamaz", "karşılamıyor"]),
+];
+
+// Tip hatası mesajının kararlı hata kodu (bkz. TYPE_ERROR_KINDS); türü bilinmeyenler E0100 alır.
+pub fn type_error_code(message: &str) -> &'static str {
+    if message.contains("çıkarılamadı") {
+        // Tip parametresi çıkarımı argüman sayısıyla ilgili değildir.
+        return E_TYPE;
+    }
+    TYPE_ERROR_KINDS
+        .iter()
+        .find(|(_, phrases)| phrases.iter().any(|phrase| message.contains(phrase)))
+        .map_or(E_TYPE, |(code, _)| code)
+}
+
+// Kod üretimi hatasının kodu: desteklenmeyen özellikler E0201, diğerleri E0200. Arka uçlar bunu
+// farklı kiplerle yazar ("desteklenmiyor", "desteklemiyor", "yalnızca ... destekler",
+// "Desteklenmeyen ..."); hepsi aynı kökü paylaşır.
+pub fn codegen_error_code(message: &str) -> &'static str {
+    if message.to_lowercase().contains("destekle") {
+        E_UNSUPPORTED
+    } else {
+        E_CODEGEN
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub stage: Option<Stage>,
+    pub severity: Severity,
+    pub code: Option<String>,
+    pub message: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self { stage: None, severity, code: None, message: message.into(), labels: Vec::new(), notes: Vec::new() }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    pub fn with_stage(mut self, stage: Stage) -> Self {
+        self.stage = Some(stage);
+        self
+    }
+
+    pub fn with_code(mut self, code: &str) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    pub fn with_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label { span, message: message.into(), primary: true });
+        self
+    }
+
+    pub fn with_secondary(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push(Label { span, message: message.into(), primary: false });
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn primary_span(&self) -> Option<Span> {
+        self.labels.iter().find(|l| l.primary && !l.span.is_dummy()).map(|l| l.span)
+    }
+
+    // Birincil etiket yoksa verilen span'i birincil etiket olarak ekler.
+    pub fn with_fallback_span(self, span: Span) -> Self {
+        if self.primary_span().is_some() || span.is_dummy() {
+            return self;
+        }
+        self.with_label(span, "")
+    }
+
+    // Mesajın başındaki eski "Hata: " önekini atar; önek zaten şiddet seviyesiyle yazılıyor.
+    fn display_message(&self) -> &str {
+        self.message.strip_prefix("Hata: ").unwrap_or(&self.message)
+    }
+
+    // Diagnostic'i kaynak satırı ve işaretleriyle birlikte metne çevirir:
+    //
+    // hata[E0100]: Tanımlanmamış isim: 'y'.
+    //   --> ornek.n:3:13
+    //    |
+    //  3 |     var x = y + 1;
+    //    |             ^
+    //
+    // İkincil etiketler ('-') aynı biçimde, kaynaktaki sıralarına göre çizilir; başka bir dosyadaki
+    // etiketten önce o dosyanın adı `::: dosya:satır:sütun` olarak yazılır.
+    pub fn render(&self, sources: &SourceMap) -> String {
+        let mut out = String::new();
+        match &self.code {
+            Some(code) => out.push_str(&format!("{}[{}]: {}\n", self.severity.as_str(), code, self.display_message())),
+            None => out.push_str(&format!("{}: {}\n", self.severity.as_str(), self.display_message())),
+        }
+
+        // Etiketler kaynaktaki sırayla çizilir; aynı satırdaki etiketler satırı bir kez yazdırır.
+        let mut located: Vec<&Label> = self.labels.iter().filter(|l| !l.span.is_dummy()).collect();
+        located.sort_by_key(|l| (l.span.file.0, l.span.line, l.span.column));
+        let gutter = located.iter().map(|l| l.span.line.to_string().len()).max().unwrap_or(1);
+        let pad = " ".repeat(gutter);
+
+        let file_name = |file| sources.get(file).map_or("<bilinmeyen>", |f| f.name.as_str());
+        let mut current_file = None;
+        if let Some(primary) = located.iter().find(|l| l.primary).or(located.first()) {
+            out.push_str(&format!("{}--> {}:{}:{}\n", pad, file_name(primary.span.file), primary.span.line, primary.span.column));
+            out.push_str(&format!("{} |\n", pad));
+            current_file = Some(primary.span.file);
+        }
+
+        let mut previous_line = None;
+        for label in &located {
+            let Some(text) = sources.line_text(label.span.file, label.span.line) else { continue };
+            // Başka bir dosyadaki etiket (örn. içe aktarılan modül) kendi dosya başlığıyla yazılır.
+            if current_file != Some(label.span.file) {
+                out.push_str(&format!("{}::: {}:{}:{}\n", pad, file_name(label.span.file), label.span.line, label.span.column));
+                current_file = Some(label.span.file);
+            }
+            if previous_line != Some((label.span.file, label.span.line)) {
+                out.push_str(&format!("{:>width$} | {}\n", label.span.line, text, width = gutter));
+                previous_line = Some((label.span.file, label.span.line));
+            }
+
+            // İşaret genişliği: span'in bu satırda kalan kısmı (en az 1 karakter)
+            let col = label.span.column.max(1) - 1;
+            let line_chars = text.chars().count();
+            let span_chars = sources
+                .get(label.span.file)
+                .and_then(|f| f.source.get(label.span.start..label.span.end))
+                .map_or(1, |s| s.lines().next().unwrap_or("").chars().count());
+            let width = span_chars.clamp(1, line_chars.saturating_sub(col).max(1));
+            let indent: String = text.chars().take(col).map(|c| if c == '\t' { '\t' } else { ' ' }).collect();
+            let marker = if label.primary { "^" } else { "-" }.repeat(width);
+            if label.message.is_empty() {
+                out.push_str(&format!("{} | {}{}\n", pad, indent, marker));
+            } else {
+                out.push_str(&format!("{} | {}{} {}\n", pad, indent, marker, label.message));
+            }
+        }
+
+        for note in &self.notes {
+            out.push_str(&format!("{} = not: {}\n", pad, note));
+        }
+        out
+    }
+
+    // Diagnostic'i tek satırlık bir JSON nesnesine çevirir. Alanlar her zaman aynı sırada ve
+    // aynı adlarla yazılır; bilinmeyen değerler `null` olur. Satır/sütunlar 1'den başlar,
+    // `end_line`/`end_column` ve `end` (byte) aralığın bittiği yeri gösterir (hariç).
+    //
+    // {"stage":"type","severity":"error","code":"E0100","message":"...","file":"a.n",
+    //  "span":{"line":3,"column":13,"end_line":3,"end_column":14,"start":40,"end":41},
+    //  "labels":[{"file":"a.n","span":{...},"message":"","primary":true}],"notes":[]}
+    pub fn to_json(&self, sources: &SourceMap) -> String {
+        let primary = self.labels.iter().find(|l| l.primary && !l.span.is_dummy());
+        let labels: Vec<String> = self
+            .labels
+            .iter()
+            .filter(|l| !l.span.is_dummy())
+            .map(|l| {
+                format!(
+                    "{{\"file\":{},\"span\":{},\"message\":{},\"primary\":{}}}",
+                    json_file(sources, l.span),
+                    json_span(sources, l.span),
+                    json_string(&l.message),
+                    l.primary
+                )
+            })
+            .collect();
+        let notes: Vec<String> = self.notes.iter().map(|n| json_string(n)).collect();
+
+        format!(
+            "{{\"stage\":{},\"severity\":{},\"code\":{},\"message\":{},\"file\":{},\"span\":{},\"labels\":[{}],\"notes\":[{}]}}",
+            self.stage.map_or("null".to_string(), |s| json_string(s.as_str())),
+            json_string(self.severity.json_name()),
+            self.code.as_deref().map_or("null".to_string(), json_string),
+            json_string(self.display_message()),
+            primary.map_or("null".to_string(), |l| json_file(sources, l.span)),
+            primary.map_or("null".to_string(), |l| json_span(sources, l.span)),
+            labels.join(","),
+            notes.join(",")
+        )
+    }
+}
+
+fn json_file(sources: &SourceMap, span: Span) -> String {
+    sources.get(span.file).map_or("null".to_string(), |f| json_string(&f.name))
+}
+
+fn json_span(sources: &SourceMap, span: Span) -> String {
+    let (end_line, end_column) = sources.position(span.file, span.end).unwrap_or((span.line, span.column));
+    format!(
+        "{{\"line\":{},\"column\":{},\"end_line\":{},\"end_column\":{},\"start\":{},\"end\":{}}}",
+        span.line, span.column, end_line, end_column, span.start, span.end
+    )
+}
+
+// JSON string literali (tırnaklar dahil); kontrol karakterleri \uXXXX olarak kaçışlanır.
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+// Konumsuz eski String hataları için köprü.
+impl From<String> for Diagnostic {
+    fn from(message: String) -> Self {
+        Diagnostic::error(message)
+    }
+}
@@ -0,0 +1,617 @@
+// src/doc.rs
+
+// YENİ: `nim doc` alt komutu. Bir modülün genel (pub) API'sini, bildirimlerin önündeki `///` ve
+// `/** */` doküman yorumlarıyla birlikte build/doc altına Markdown (.md) veya HTML (.html) olarak yazar.
+// İmzalar tip kontrolcüsünün tablolarından (function_signatures, method_signatures, group_definitions,
+// type_aliases) alınır; AST yalnızca sıralama ve varsayılan değerlerin kaynak metni için kullanılır.
+//
+// `--test` bayrağıyla doküman yorumlarındaki kod örnekleri (doctest) modülün kaynağına eklenip
+// yorumlayıcıyla çalıştırılır. Kod bloğu etiketleri:
+//   ```nim / ```     Örnek çalıştırılır; 0 dışında bir çıkış kodu testi başarısız yapar.
+//   ```nim,no_run    Örnek yalnızca tip kontrolünden geçirilir.
+//   ```ignore, ```text veya başka bir dil: Örnek test edilmez.
+// `fn main` içermeyen örnekler `fn main(): i32 { ... return 0; }` içine sarılır. Modülün kendi `main`i
+// doctest programından çıkarılır; örnekler yalnızca modülün diğer bildirimlerini görür.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use crate::ast::{type_params_str, Decl, ExprKind, StmtKind, Type};
+use crate::diagnostic::{Diagnostic, SourceMap, Span};
+use crate::session::{Options, Session};
+use crate::type_checker::{FnSignature, TypeChecker};
+use crate::test_runner::{self, Status};
+
+// Bir doctest'in derlenip çalışabileceği en uzun süre.
+const DOCTEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Markdown,
+    Html,
+}
+
+struct DocOptions {
+    file: String,
+    format: Format,
+    out_dir: String,
+    test: bool,
+    include_paths: Vec<String>,
+}
+
+// Dokümana girecek tek bir öğe (fonksiyon, struct, enum, typedef veya grup). Struct metotları ve
+// grup üyeleri kendi imza ve yorumlarıyla `members` altında, öğenin imzasından ayrı gösterilir.
+struct Item {
+    kind: &'static str,
+    name: String,
+    signature: String,
+    doc: Option<String>,
+    members: Vec<Item>,
+}
+
+// Doküman yorumundan çıkarılmış bir kod örneği.
+struct Example {
+    code: String,
+    no_run: bool,
+}
+
+fn parse_options(args: &[String]) -> Result<DocOptions, String> {
+    let mut options = DocOptions { file: String::new(), format: Format::Markdown, out_dir: "build/doc".to_string(), test: false, include_paths: Vec::new() };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--test" => options.test = true,
+            "--format" => {
+                options.format = match iter.next().map(String::as_str) {
+                    Some("md") | Some("markdown") => Format::Markdown,
+                    Some("html") => Format::Html,
+                    Some(other) => return Err(format!("Geçersiz doküman biçimi: '{}'. Geçerli seçenekler: md, html.", other)),
+                    None => return Err("'--format' bayrağı bir biçim (md, html) bekliyor.".to_string()),
+                };
+            }
+            "-o" => {
+                options.out_dir = iter.next().cloned().ok_or("'-o' bayrağı bir çıktı dizini bekliyor.")?;
+            }
+            _ if arg.starts_with("-I") => {
+                if arg.len() > 2 {
+                    options.include_paths.push(arg[2..].to_string());
+                } else {
+                    options.include_paths.push(iter.next().cloned().ok_or("'-I' bayrağı bir yol (path) bekliyor.")?);
+                }
+            }
+            _ if !arg.starts_with('-') && options.file.is_empty() => options.file = arg.clone(),
+            _ => return Err(format!("Bilinmeyen argüman veya bayrak: '{}'", arg)),
+        }
+    }
+    if options.file.is_empty() {
+        return Err("Dokümante edilecek kaynak dosya belirtilmedi. Kullanım: nim doc <dosya> [--format md|html] [--test]".to_string());
+    }
+    Ok(options)
+}
+
+pub fn run(args: &[String]) -> i32 {
+    let options = match parse_options(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Yapılandırma Hatası: {}", e);
+            return 1;
+        }
+    };
+
+    let mut session_options = Options::default();
+    session_options.include_paths.extend(options.include_paths.iter().cloned());
+    let mut session = match Session::from_path(&options.file, session_options) {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("{}", e.message);
+            return 1;
+        }
+    };
+    let program = match session.lex().and_then(|tokens| session.preprocess(tokens)).and_then(|tokens| session.parse(tokens)) {
+        Ok(program) => program,
+        Err(errors) => {
+            report(&errors, session.sources());
+            return 1;
+        }
+    };
+    let checked = session.check(&program);
+    report(&session.take_warnings(), session.sources());
+    let items = match checked {
+        Ok(checker) => collect_items(&program, &checker, session.sources()),
+        Err(errors) => {
+            report(&errors, session.sources());
+            return 1;
+        }
+    };
+
+    let module = session.base_name();
+    let (text, extension) = match options.format {
+        Format::Markdown => (render_markdown(&module, &items), "md"),
+        Format::Html => (render_html(&module, &items), "html"),
+    };
+    let out_path = Path::new(&options.out_dir).join(format!("{}.{}", module, extension));
+    if let Err(e) = fs::create_dir_all(&options.out_dir).and_then(|_| fs::write(&out_path, text)) {
+        eprintln!("Hata: Doküman '{}' dosyasına yazılamadı: {}", out_path.display(), e);
+        return 1;
+    }
+    println!("✅ Doküman '{}' dosyasına yazıldı ({} öğe).", out_path.display(), items.len());
+
+    if options.test {
+        let source = fs::read_to_string(&options.file).unwrap_or_default();
+        let main = program.iter().find_map(|decl| match decl {
+            Decl::Function { name, span, .. } if name == "main" => Some(*span),
+            _ => None,
+        });
+        return run_doctests(&options, &module, &source, main, &items);
+    }
+    0
+}
+
+// Hataları ve uyarıları kaynak satırlarıyla stderr'e yazar.
+fn report(diagnostics: &[Diagnostic], sources: &SourceMap) {
+    for diagnostic in diagnostics {
+        eprintln!("{}", diagnostic.render(sources));
+    }
+}
+
+// Programdaki genel öğeleri kaynak sırasıyla toplar.
+fn collect_items(program: &[Decl], checker: &TypeChecker<'_>, sources: &SourceMap) -> Vec<Item> {
+    let mut items = Vec::new();
+    for decl in program {
+        match decl {
+            Decl::Program(decls) => items.extend(collect_items(decls, checker, sources)),
+            Decl::Function { name, type_params, params, is_async, is_public: true, doc, .. } => {
+                let Some(signature) = checker.function_signatures.get(name) else { continue };
+                let defaults = default_texts(params.iter().map(|(_, _, d)| d.as_ref().map(|e| e.span)), sources);
+                let qualifier = if *is_async { "async " } else if signature.2 { "inline " } else { "" };
+                let generic_name = format!("{}{}", name, type_params_str(type_params));
+                let text = format!("pub {}fn {}", qualifier, function_signature(&generic_name, signature, &defaults, *is_async));
+                items.push(Item { kind: "fn", name: name.clone(), signature: text, doc: doc.clone(), members: Vec::new() });
+            }
+            Decl::ExternFn { name, params, is_public: true, doc, .. } => {
+                let Some(signature) = checker.function_signatures.get(name) else { continue };
+                let defaults = default_texts(params.iter().map(|(_, _, d)| d.as_ref().map(|e| e.span)), sources);
+                let text = format!("pub extern fn {};", function_signature(name, signature, &defaults, false));
+                items.push(Item { kind: "fn", name: name.clone(), signature: text, doc: doc.clone(), members: Vec::new() });
+            }
+            Decl::Struct { name, type_params, fields, is_public: true, doc, .. } => {
+                let mut text = format!("pub struct {}{} {{\n", name, type_params_str(type_params));
+                for (field, ty) in fields {
+                    text.push_str(&format!("    {}: {};\n", field, ty));
+                }
+                text.push('}');
+                // Struct adıyla açılmış group bloklarındaki pub metotlar struct'ın altında listelenir.
+                let mut members = Vec::new();
+                if let Some(methods) = checker.method_signatures.get(name) {
+                    let mut public: Vec<_> = methods.iter().filter(|(_, (_, _, is_public))| *is_public).collect();
+                    public.sort_by(|a, b| a.0.cmp(b.0));
+                    for (method, (params, ret, _)) in public {
+                        let signature: FnSignature = (params.clone(), ret.clone(), false, true);
+                        let full_name = format!("{}.{}", name, method);
+                        let text = format!("pub fn {}", function_signature(&full_name, &signature, &[], false));
+                        members.push(Item { kind: "fn", name: full_name, signature: text, doc: member_doc(program, name, method), members: Vec::new() });
+                    }
+                }
+                items.push(Item { kind: "struct", name: name.clone(), signature: text, doc: doc.clone(), members });
+            }
+            Decl::Enum { name, variants, is_public: true, doc, .. } => {
+                let variants: Vec<String> = variants
+                    .iter()
+                    .map(|(variant, value)| match value.as_ref().and_then(|e| source_text(e.span, sources)) {
+                        Some(value) => format!("{} = {}", variant, value),
+                        None => variant.clone(),
+                    })
+                    .collect();
+                let text = format!("pub enum {} {{ {} }}", name, variants.join(", "));
+                items.push(Item { kind: "enum", name: name.clone(), signature: text, doc: doc.clone(), members: Vec::new() });
+            }
+            Decl::Typedef { name, type_params, target, is_public: true, doc, .. } => {
+                let target = checker.type_aliases.get(name).unwrap_or(target);
+                let text = format!("pub typedef {}{}: {};", name, type_params_str(type_params), target);
+                items.push(Item { kind: "typedef", name: name.clone(), signature: text, doc: doc.clone(), members: Vec::new() });
+            }
+            Decl::Group { name, type_params, is_export, body, doc, .. } => {
+                let Some(content) = checker.group_definitions.get(name) else { continue };
+                let mut members = Vec::new();
+                for inner in body {
+                    // Grup gövdesindeki `pub fn` bildirimleri; tip kontrolcüsü bunları kaydetmediyse imza AST'den kurulur.
+                    if let Decl::Function { name: member, params, return_type, is_public: true, doc: member_doc, .. } = inner {
+                        let signature = content.functions.get(member).cloned().unwrap_or_else(|| {
+                            let params = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
+                            (params, return_type.clone(), false, true)
+                        });
+                        let defaults = default_texts(params.iter().map(|(_, _, d)| d.as_ref().map(|e| e.span)), sources);
+                        let full_name = format!("{}.{}", name, member);
+                        let text = format!("pub fn {}", function_signature(&full_name, &signature, &defaults, false));
+                        members.push(Item { kind: "fn", name: full_name, signature: text, doc: member_doc.clone(), members: Vec::new() });
+                        continue;
+                    }
+                    let Decl::StmtDecl(stmt) = inner else { continue };
+                    match &stmt.kind {
+                        StmtKind::LabeledStmt { label, stmt: member, is_public: true, doc: member_doc } => {
+                            let (Some(signature), StmtKind::ExprStmt(expr)) = (content.functions.get(label), &member.kind) else { continue };
+                            let defaults = match &expr.kind {
+                                ExprKind::Lambda { params, .. } => default_texts(params.iter().map(|(_, _, d)| d.as_ref().map(|e| e.span)), sources),
+                                _ => Vec::new(),
+                            };
+                            let full_name = format!("{}.{}", name, label);
+                            let text = format!("pub fn {}", function_signature(&full_name, signature, &defaults, false));
+                            members.push(Item { kind: "fn", name: full_name, signature: text, doc: member_doc.clone(), members: Vec::new() });
+                        }
+                        StmtKind::VarDecl { name: constant, is_public: true, .. } => {
+                            if let Some(info) = content.constants.get(constant) {
+                                let full_name = format!("{}.{}", name, constant);
+                                let text = format!("pub const {}: {};", full_name, info.ty);
+                                members.push(Item { kind: "const", name: full_name, signature: text, doc: None, members: Vec::new() });
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                if members.is_empty() && !is_export {
+                    continue;
+                }
+                let keyword = if *is_export { "export group" } else { "group" };
+                let text = format!("{} {}{}", keyword, name, type_params_str(type_params));
+                items.push(Item { kind: "group", name: name.clone(), signature: text, doc: doc.clone(), members });
+            }
+            _ => {}
+        }
+    }
+    items
+}
+
+// Struct adıyla açılmış group bloğundaki `ad => fn(...)` metodunun doküman yorumu.
+fn member_doc(program: &[Decl], group: &str, member: &str) -> Option<String> {
+    program.iter().find_map(|decl| match decl {
+        Decl::Program(decls) => member_doc(decls, group, member),
+        Decl::Group { name, body, .. } if name == group => body.iter().find_map(|inner| match inner {
+            Decl::StmtDecl(stmt) => match &stmt.kind {
+                StmtKind::LabeledStmt { label, doc, .. } if label == member => doc.clone(),
+                _ => None,
+            },
+            _ => None,
+        }),
+        _ => None,
+    })
+}
+
+// `ad(a: i32, b: i32 = 2): i32` biçiminde imza. `async` fonksiyonların Future<T> dönüş tipi T olarak yazılır.
+fn function_signature(name: &str, signature: &FnSignature, defaults: &[Option<String>], is_async: bool) -> String {
+    let (params, ret, _, _) = signature;
+    let params: Vec<String> = params
+        .iter()
+        .enumerate()
+        .map(|(i, (param, ty, has_default))| match defaults.get(i).cloned().flatten() {
+            Some(default) => format!("{}: {} = {}", param, ty, default),
+            None if *has_default => format!("{}: {} = …", param, ty),
+            None => format!("{}: {}", param, ty),
+        })
+        .collect();
+    let ret = match (is_async, ret) {
+        (true, Type::Future(inner)) => inner.as_ref(),
+        _ => ret,
+    };
+    format!("{}({}): {}", name, params.join(", "), ret)
+}
+
+fn default_texts(spans: impl Iterator<Item = Option<Span>>, sources: &SourceMap) -> Vec<Option<String>> {
+    spans.map(|span| span.and_then(|span| source_text(span, sources))).collect()
+}
+
+// Bir ifadenin kaynaktaki metni (varsayılan değerler ve enum değerleri için).
+fn source_text(span: Span, sources: &SourceMap) -> Option<String> {
+    let text = sources.get(span.file)?.source.get(span.start..span.end)?.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn section_title(kind: &str) -> &'static str {
+    match kind {
+        "fn" => "Fonksiyonlar",
+        "struct" => "Struct'lar",
+        "enum" => "Enum'lar",
+        "typedef" => "Tip Takma Adları",
+        _ => "Gruplar",
+    }
+}
+
+const SECTIONS: [&str; 5] = ["fn", "struct", "enum", "typedef", "group"];
+
+// Öğenin altındaki üye listesinin başlığı.
+fn members_title(kind: &str) -> &'static str {
+    if kind == "struct" { "Metotlar" } else { "Üyeler" }
+}
+
+fn render_markdown(module: &str, items: &[Item]) -> String {
+    let mut out = format!("# Modül `{}`\n", module);
+    if items.is_empty() {
+        out.push_str("\nBu modül genel (pub) öğe içermiyor.\n");
+    }
+    for kind in SECTIONS {
+        let section: Vec<&Item> = items.iter().filter(|item| item.kind == kind).collect();
+        if section.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n## {}\n", section_title(kind)));
+        for item in section {
+            out.push_str(&format!("\n### `{}`\n\n```nim\n{}\n```\n", item.name, item.signature));
+            if let Some(doc) = &item.doc {
+                out.push_str(&format!("\n{}\n", doc.trim_end()));
+            }
+            if !item.members.is_empty() {
+                out.push_str(&format!("\n#### {}\n", members_title(item.kind)));
+            }
+            for member in &item.members {
+                out.push_str(&format!("\n```nim\n{}\n```\n", member.signature));
+                if let Some(doc) = &member.doc {
+                    out.push_str(&format!("\n{}\n", doc.trim_end()));
+                }
+            }
+        }
+    }
+    out
+}
+
+fn render_html(module: &str, items: &[Item]) -> String {
+    let mut nav = String::new();
+    let mut body = String::new();
+    for kind in SECTIONS {
+        let section: Vec<&Item> = items.iter().filter(|item| item.kind == kind).collect();
+        if section.is_empty() {
+            continue;
+        }
+        nav.push_str(&format!("<li>{}<ul>\n", section_title(kind)));
+        body.push_str(&format!("<h2>{}</h2>\n", section_title(kind)));
+        for item in section {
+            let anchor = format!("{}.{}", item.kind, item.name);
+            nav.push_str(&format!("<li><a href=\"#{}\">{}</a></li>\n", anchor, escape_html(&item.name)));
+            body.push_str(&format!("<section id=\"{}\">\n<h3><code>{}</code></h3>\n", anchor, escape_html(&item.name)));
+            body.push_str(&format!("<pre class=\"signature\"><code>{}</code></pre>\n", escape_html(&item.signature)));
+            if let Some(doc) = &item.doc {
+                body.push_str(&format!("<div class=\"doc\">\n{}</div>\n", markdown_to_html(doc)));
+            }
+            if !item.members.is_empty() {
+                body.push_str(&format!("<h4>{}</h4>\n", members_title(item.kind)));
+            }
+            for member in &item.members {
+                body.push_str(&format!("<div class=\"member\" id=\"{}.{}\">\n", item.kind, escape_html(&member.name)));
+                body.push_str(&format!("<pre class=\"signature\"><code>{}</code></pre>\n", escape_html(&member.signature)));
+                if let Some(doc) = &member.doc {
+                    body.push_str(&format!("<div class=\"doc\">\n{}</div>\n", markdown_to_html(doc)));
+                }
+                body.push_str("</div>\n");
+            }
+            body.push_str("</section>\n");
+        }
+        nav.push_str("</ul></li>\n");
+    }
+    if items.is_empty() {
+        body.push_str("<p>Bu modül genel (pub) öğe içermiyor.</p>\n");
+    }
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"tr\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{style}</style>\n</head>\n<body>\n<nav>\n<h1>{title}</h1>\n<ul>\n{nav}</ul>\n</nav>\n<main>\n{body}</main>\n</body>\n</html>\n",
+        title = escape_html(module),
+        style = HTML_STYLE,
+        nav = nav,
+        body = body
+    )
+}
+
+const HTML_STYLE: &str = "body { display: flex; font-family: sans-serif; margin: 0; }\n\
+nav { width: 16rem; padding: 1rem; background: #f4f4f4; min-height: 100vh; }\n\
+main { flex: 1; padding: 1rem 2rem; max-width: 50rem; }\n\
+pre { background: #f0f0f0; padding: 0.5rem; overflow-x: auto; }\n\
+section { border-top: 1px solid #ddd; margin-top: 1rem; }\n\
+.member { margin-left: 1rem; }\n";
+
+// Doküman yorumlarında kullanılan küçük Markdown alt kümesini (başlıklar, listeler, kod blokları,
+// satır içi kod, kalın yazı ve paragraflar) HTML'e çevirir.
+fn markdown_to_html(text: &str) -> String {
+    let mut out = String::new();
+    let mut paragraph: Vec<String> = Vec::new();
+    let mut in_list = false;
+    let mut code: Option<String> = None;
+
+    let flush = |out: &mut String, paragraph: &mut Vec<String>, in_list: &mut bool| {
+        if !paragraph.is_empty() {
+            out.push_str(&format!("<p>{}</p>\n", paragraph.join(" ")));
+            paragraph.clear();
+        }
+        if *in_list {
+            out.push_str("</ul>\n");
+            *in_list = false;
+        }
+    };
+
+    for line in text.lines() {
+        if let Some(block) = code.as_mut() {
+            if line.trim_start().starts_with("```") {
+                out.push_str(&format!("<pre><code>{}</code></pre>\n", escape_html(block)));
+                code = None;
+            } else {
+                block.push_str(line);
+                block.push('\n');
+            }
+            continue;
+        }
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") {
+            flush(&mut out, &mut paragraph, &mut in_list);
+            code = Some(String::new());
+        } else if trimmed.is_empty() {
+            flush(&mut out, &mut paragraph, &mut in_list);
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            flush(&mut out, &mut paragraph, &mut in_list);
+            let level = (heading.chars().take_while(|c| *c == '#').count() + 4).min(6);
+            out.push_str(&format!("<h{0}>{1}</h{0}>\n", level, inline_html(heading.trim_start_matches('#').trim())));
+        } else if let Some(entry) = trimmed.strip_prefix("- ").or_else(|| trimmed.strip_prefix("* ")) {
+            if !paragraph.is_empty() {
+                flush(&mut out, &mut paragraph, &mut in_list);
+            }
+            if !in_list {
+                out.push_str("<ul>\n");
+                in_list = true;
+            }
+            out.push_str(&format!("<li>{}</li>\n", inline_html(entry)));
+        } else {
+            if in_list {
+                flush(&mut out, &mut paragraph, &mut in_list);
+            }
+            paragraph.push(inline_html(trimmed));
+        }
+    }
+    if let Some(block) = code {
+        out.push_str(&format!("<pre><code>{}</code></pre>\n", escape_html(&block)));
+    }
+    flush(&mut out, &mut paragraph, &mut in_list);
+    out
+}
+
+// Satır içi `kod` ve **kalın** işaretlerini HTML'e çevirir.
+fn inline_html(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('`') {
+            if let Some(end) = after.find('`') {
+                out.push_str(&format!("<code>{}</code>", escape_html(&after[..end])));
+                rest = &after[end + 1..];
+                continue;
+            }
+        }
+        if let Some(after) = rest.strip_prefix("**") {
+            if let Some(end) = after.find("**") {
+                out.push_str(&format!("<strong>{}</strong>", inline_html(&after[..end])));
+                rest = &after[end + 2..];
+                continue;
+            }
+        }
+        let c = rest.chars().next().unwrap_or_default();
+        out.push_str(&escape_html(&c.to_string()));
+        rest = &rest[c.len_utf8()..];
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Doküman yorumundaki test edilecek kod bloklarını çıkarır.
+fn examples(doc: &str) -> Vec<Example> {
+    let mut examples = Vec::new();
+    let mut current: Option<Example> = None;
+    let mut skipping = false;
+    for line in doc.lines() {
+        let trimmed = line.trim();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            if let Some(example) = current.take() {
+                examples.push(example);
+            } else if skipping {
+                skipping = false;
+            } else {
+                let tags: Vec<&str> = info.split(',').map(str::trim).filter(|t| !t.is_empty()).collect();
+                let language_ok = tags.first().is_none_or(|lang| matches!(*lang, "nim" | "no_run"));
+                if language_ok && !tags.contains(&"ignore") {
+                    current = Some(Example { code: String::new(), no_run: tags.contains(&"no_run") });
+                } else {
+                    skipping = true;
+                }
+            }
+            continue;
+        }
+        if let Some(example) = current.as_mut() {
+            example.code.push_str(line);
+            example.code.push('\n');
+        }
+    }
+    examples
+}
+
+// Her örneği modül kaynağıyla birleştirip `nim run --interp` (no_run için `--emit typed-ast`) ile çalıştırır.
+fn run_doctests(options: &DocOptions, module: &str, source: &str, main: Option<Span>, items: &[Item]) -> i32 {
+    let compiler = match std::env::current_exe() {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("Hata: Derleyicinin yolu bulunamadı: {}", e);
+            return 1;
+        }
+    };
+    let test_dir = Path::new(&options.out_dir).join("doctest");
+    if let Err(e) = fs::create_dir_all(&test_dir) {
+        eprintln!("Hata: Doctest dizini oluşturulamadı: {}: {}", test_dir.display(), e);
+        return 1;
+    }
+    let module_dir = Path::new(&options.file).parent().filter(|p| !p.as_os_str().is_empty()).unwrap_or(Path::new("."));
+
+    let mut results = Vec::new();
+    for item in items.iter().flat_map(|item| std::iter::once(item).chain(&item.members)) {
+        let Some(doc) = &item.doc else { continue };
+        for (index, example) in examples(doc).into_iter().enumerate() {
+            let name = format!("{}::{} ({})", module, item.name, index + 1);
+            let path = test_dir.join(format!("{}_{}_{}.nim", module, item.name, index + 1));
+            let status = match fs::write(&path, doctest_program(source, main, &example.code)) {
+                Ok(()) => run_doctest(&compiler, &path, module_dir, example.no_run, options),
+                Err(e) => Status::Fail(format!("'{}' yazılamadı: {}", path.display(), e)),
+            };
+            results.push((name, status));
+        }
+    }
+    if results.is_empty() {
+        println!("Doküman yorumlarında test edilecek kod örneği bulunamadı.");
+        return 0;
+    }
+    test_runner::print_table(&results);
+    let failed = results.iter().filter(|(_, s)| matches!(s, Status::Fail(_))).count();
+    if failed > 0 { 1 } else { 0 }
+}
+
+// Örnek, modül kaynağının sonuna eklenir. Modülün kendi `main`i (önündeki `pub`/`export` ile) çıkarılır;
+// aksi halde örneğin veya sarmalayıcının `main`iyle ikinci bir tanım oluşurdu.
+fn doctest_program(source: &str, main: Option<Span>, code: &str) -> String {
+    let source = match main {
+        Some(span) => {
+            let head = source[..span.start].trim_end();
+            let head = ["pub", "export"]
+                .iter()
+                .find_map(|keyword| head.strip_suffix(keyword).filter(|rest| !rest.ends_with(|c: char| c.is_alphanumeric() || c == '_')))
+                .unwrap_or(head);
+            format!("{}\n{}", head, &source[span.end..])
+        }
+        None => source.to_string(),
+    };
+    if code.contains("fn main") {
+        format!("{}\n{}", source, code)
+    } else {
+        let body: String = code.lines().map(|line| format!("    {}\n", line)).collect();
+        format!("{}\nfn main(): i32 {{\n{}    return 0;\n}}\n", source, body)
+    }
+}
+
+fn run_doctest(compiler: &PathBuf, path: &Path, module_dir: &Path, no_run: bool, options: &DocOptions) -> Status {
+    let mut command = Command::new(compiler);
+    if no_run {
+        command.arg(path).args(["--emit", "typed-ast"]);
+    } else {
+        command.args(["run", "--interp"]).arg(path);
+    }
+    command.args(["--error-format", "json"]).arg("-I").arg(module_dir);
+    for include in &options.include_paths {
+        command.arg("-I").arg(include);
+    }
+    match test_runner::run_with_timeout(command, DOCTEST_TIMEOUT) {
+        Ok(Some(output)) if output.status.success() => Status::Pass,
+        Ok(Some(output)) => {
+            let message = output
+                .stderr
+                .lines()
+                .find_map(|line| test_runner::json_field(line, "message"))
+                .map(|message| format!("derleme hatası: {}", message))
+                .unwrap_or_else(|| format!("çıkış kodu {}", test_runner::exit_code(output.status)));
+            Status::Fail(message)
+        }
+        Ok(None) => Status::Fail(format!("zaman aşımı ({} sn)", DOCTEST_TIMEOUT.as_secs())),
+        Err(e) => Status::Fail(format!("derleyici çalıştırılamadı: {}", e)),
+    }
+}
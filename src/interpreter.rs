@@ -0,0 +1,1384 @@
+// src/interpreter.rs
+
+// YENİ: Ağaç üzerinde yürüyen yorumlayıcı (`nim run --interp dosya.n`).
+//
+// Tip kontrolünden geçmiş AST'yi (Decl/Stmt/Expr) doğrudan çalıştırır; assembler, linker veya
+// hedef işletim sistemi gerektirmez. Native kod üretiminin çıktısı bununla karşılaştırılabilsin
+// diye yazdırma biçimleri runtime ile aynıdır: bool 1/0, float "%f" (6 basamak) olarak yazılır,
+// `panic` mesajı stdout'a yazılır ve süreç 1 koduyla biter.
+//
+// Değerler kopyalanarak taşınır (struct, dizi ve tuple'lar dahil). `defer` blokları, içinde
+// bulundukları blok bittiğinde (return ve `?` ile erken çıkışlar dahil) ters sırayla çalışır.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::{self, BufRead, BufWriter, StdoutLock, Write};
+use std::rc::Rc;
+
+use crate::ast::{BinOp, Decl, Expr, ExprKind, LiteralValue, Stmt, StmtKind, Type, UnOp};
+use crate::diagnostic::{Diagnostic, Span, Stage, E_RUNTIME};
+
+// İç içe çağrı sınırı. Sonsuz özyineleme, yorumlayıcının kendi yığınını taşırmadan hata olarak raporlanır.
+const MAX_CALL_DEPTH: usize = 10_000;
+// Yorumlayıcının çalıştığı iş parçacığının yığın boyutu (derin özyinelemeler için).
+const INTERPRETER_STACK_SIZE: usize = 512 * 1024 * 1024;
+
+type Param = (String, Type, Option<Expr>);
+
+#[derive(Debug, Clone)]
+pub enum Value<'a> {
+    Int(i64),
+    // i128/u128 değerleri (u128 bit deseni olarak saklanır).
+    Wide(i128),
+    Float(f64),
+    Bool(bool),
+    Char(char),
+    Str(String),
+    Array(Vec<Value<'a>>),
+    Tuple(Vec<Value<'a>>),
+    Struct { name: String, fields: Vec<(String, Value<'a>)> },
+    Enum { name: String, value: i64 },
+    Function(String),
+    Lambda(Rc<Closure<'a>>),
+    Ok(Box<Value<'a>>),
+    Err(Box<Value<'a>>),
+    Some(Box<Value<'a>>),
+    None,
+    Null,
+    Void,
+}
+
+// Bir lambda ifadesi ve oluşturulduğu andaki görünür değişkenler.
+#[derive(Debug)]
+pub struct Closure<'a> {
+    params: &'a [Param],
+    return_type: &'a Type,
+    body: &'a Expr,
+    captured: HashMap<String, Value<'a>>,
+}
+
+// Çağrılabilir kullanıcı tanımları: fonksiyon gövdesi bir blok deyimi, grup üyeleri ise lambda ifadesidir.
+#[derive(Clone, Copy)]
+enum Body<'a> {
+    Stmt(&'a Stmt),
+    Expr(&'a Expr),
+}
+
+#[derive(Clone, Copy)]
+struct FnDef<'a> {
+    params: &'a [Param],
+    return_type: &'a Type,
+    body: Body<'a>,
+}
+
+// Bir deyimin ardından akışın nasıl devam edeceği.
+enum Flow<'a> {
+    Normal,
+    Break,
+    Continue,
+    Return(Value<'a>),
+}
+
+// Normal akışı kesen durumlar.
+enum Halt<'a> {
+    Exit(i32),
+    Error(String, Span),
+    // `?` operatörünün Err/None değerini fonksiyondan erken döndürmesi.
+    Propagate(Value<'a>),
+}
+
+type Exec<'a, T> = Result<T, Halt<'a>>;
+
+#[derive(Default)]
+struct Scope<'a> {
+    vars: HashMap<String, Value<'a>>,
+    defers: Vec<&'a Stmt>,
+    // İşaretsiz tamsayı tipiyle bildirilmiş değişkenler; değerler i64 taşındığından yazdırılırken
+    // işaretsiz gösterilmeleri için tutulur.
+    unsigned: HashSet<String>,
+}
+
+// Bir atama hedefinin kök değişkenden itibaren yolu (örn: `p.pos.x` -> p, [pos, x]).
+enum Step {
+    Field(String),
+    Index(i64),
+}
+
+pub struct Interpreter<'a> {
+    program: &'a [Decl],
+    functions: HashMap<String, FnDef<'a>>,
+    // Grup fonksiyonları ve struct metotları: Grup/Struct Adı -> Üye Adı -> Tanım
+    groups: HashMap<String, HashMap<String, FnDef<'a>>>,
+    group_constants: HashMap<String, HashMap<String, Value<'a>>>,
+    structs: HashMap<String, &'a [(String, Type)]>,
+    enums: HashMap<String, Vec<(String, i64)>>,
+    type_aliases: HashMap<String, &'a Type>,
+    styles: HashMap<String, String>,
+    // frames[0] global kapsamdır; her çağrı yeni bir çerçeve açar.
+    frames: Vec<Vec<Scope<'a>>>,
+    program_args: Vec<String>,
+    out: BufWriter<StdoutLock<'static>>,
+}
+
+// Programı ayrı (geniş yığınlı) bir iş parçacığında çalıştırır ve çıkış kodunu döner.
+pub fn run(program: &[Decl], program_args: Vec<String>) -> Result<i32, Diagnostic> {
+    std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .stack_size(INTERPRETER_STACK_SIZE)
+            .spawn_scoped(scope, || Interpreter::new(program, program_args).run_main())
+            .expect("Yorumlayıcı iş parçacığı başlatılamadı.")
+            .join()
+            .unwrap_or_else(|_| Err(runtime_error("Yorumlayıcı beklenmedik şekilde sonlandı.".to_string(), Span::dummy())))
+    })
+}
+
+fn runtime_error(message: String, span: Span) -> Diagnostic {
+    let diagnostic = Diagnostic::error(message).with_stage(Stage::Runtime).with_code(E_RUNTIME);
+    if span.is_dummy() { diagnostic } else { diagnostic.with_label(span, "") }
+}
+
+fn error<'a, T>(message: impl Into<String>, span: Span) -> Exec<'a, T> {
+    Err(Halt::Error(message.into(), span))
+}
+
+impl<'a> Interpreter<'a> {
+    pub fn new(program: &'a [Decl], program_args: Vec<String>) -> Self {
+        let mut interpreter = Interpreter {
+            program,
+            functions: HashMap::new(),
+            groups: HashMap::new(),
+            group_constants: HashMap::new(),
+            structs: HashMap::new(),
+            enums: HashMap::new(),
+            type_aliases: HashMap::new(),
+            styles: HashMap::new(),
+            frames: vec![vec![Scope::default()]],
+            program_args,
+            out: BufWriter::new(io::stdout().lock()),
+        };
+        for decl in program {
+            interpreter.register_decl(decl);
+        }
+        interpreter
+    }
+
+    fn register_decl(&mut self, decl: &'a Decl) {
+        match decl {
+            Decl::Function { name, params, return_type, body, .. } => {
+                self.functions.insert(name.clone(), FnDef { params, return_type, body: Body::Stmt(body) });
+            }
+            Decl::Struct { name, fields, .. } => {
+                self.structs.insert(name.clone(), fields);
+            }
+            Decl::Typedef { name, target, .. } => {
+                self.type_aliases.insert(name.clone(), target);
+            }
+            Decl::Style { name, code, .. } => {
+                self.styles.insert(name.clone(), code.clone());
+            }
+            // Grup üyeleri (ve struct metotları) `isim => fn(...) { ... }` lambdalarıdır; metotlarda `self` ilk parametredir.
+            Decl::Group { name, body, .. } => {
+                let members = self.groups.entry(name.clone()).or_default();
+                for member in body {
+                    match member {
+                        Decl::StmtDecl(stmt) => {
+                            if let StmtKind::LabeledStmt { label, stmt: inner, .. } = &stmt.kind {
+                                if let StmtKind::ExprStmt(lambda @ Expr { kind: ExprKind::Lambda { params, return_type, .. }, .. }) = &inner.kind {
+                                    members.insert(label.clone(), FnDef { params, return_type, body: Body::Expr(lambda) });
+                                }
+                            }
+                        }
+                        Decl::Function { name, params, return_type, body, .. } => {
+                            members.insert(name.clone(), FnDef { params, return_type, body: Body::Stmt(body) });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Decl::Program(decls) => {
+                for decl in decls {
+                    self.register_decl(decl);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Enum değerlerini, grup sabitlerini ve global deyimleri hesaplar, ardından `main`'i çağırır.
+    fn run_main(mut self) -> Result<i32, Diagnostic> {
+        let result = self.initialize().and_then(|_| {
+            let main = self.functions.get("main").copied();
+            match main {
+                Some(main) => self.call(main, Vec::new(), None, Span::dummy()),
+                None => error("Hata: Programda 'main' fonksiyonu bulunamadı.", Span::dummy()),
+            }
+        });
+        let code = match result {
+            Ok(Value::Int(code)) => Ok(code as i32),
+            Ok(_) => Ok(0),
+            Err(Halt::Exit(code)) => Ok(code),
+            Err(Halt::Error(message, span)) => Err(runtime_error(message, span)),
+            Err(Halt::Propagate(value)) => Err(runtime_error(format!("Hata: '?' ile yükseltilen değer main'den çıktı: {}", value), Span::dummy())),
+        };
+        let _ = self.out.flush();
+        code
+    }
+
+    fn initialize(&mut self) -> Exec<'a, ()> {
+        for decl in self.program {
+            self.initialize_decl(decl)?;
+        }
+        Ok(())
+    }
+
+    fn initialize_decl(&mut self, decl: &'a Decl) -> Exec<'a, ()> {
+        match decl {
+            // Değeri verilmeyen enum üyesi bir öncekinin değerinin bir fazlasını alır.
+            Decl::Enum { name, variants, .. } => {
+                let mut values = Vec::new();
+                let mut next = 0;
+                for (variant, value) in variants {
+                    if let Some(value) = value {
+                        next = self.eval_int(value)?;
+                    }
+                    values.push((variant.clone(), next));
+                    next += 1;
+                }
+                self.enums.insert(name.clone(), values);
+            }
+            Decl::Group { name, body, .. } => {
+                for member in body {
+                    if let Decl::StmtDecl(stmt) = member {
+                        if let StmtKind::VarDecl { name: constant, ty, init: Some(init), is_const: true, .. } = &stmt.kind {
+                            let value = self.eval(init)?;
+                            let value = self.coerce(value, ty);
+                            self.group_constants.entry(name.clone()).or_default().insert(constant.clone(), value);
+                        }
+                    }
+                }
+            }
+            Decl::StmtDecl(stmt) => {
+                self.exec_stmt(stmt)?;
+            }
+            Decl::Program(decls) => {
+                for decl in decls {
+                    self.initialize_decl(decl)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    // --- Kapsamlar ---
+
+    fn current_frame(&mut self) -> &mut Vec<Scope<'a>> {
+        self.frames.last_mut().expect("Çağrı yığını boş olamaz.")
+    }
+
+    fn push_scope(&mut self) {
+        self.current_frame().push(Scope::default());
+    }
+
+    // Kapsamı kapatır; kapanmadan önce `defer` blokları ters sırayla çalıştırılır.
+    fn pop_scope(&mut self) -> Exec<'a, ()> {
+        let defers = std::mem::take(&mut self.current_frame().last_mut().expect("Kapsam yığını boş olamaz.").defers);
+        let mut result = Ok(());
+        for stmt in defers.into_iter().rev() {
+            if let Err(halt) = self.exec_stmt(stmt) {
+                result = Err(halt);
+                break;
+            }
+        }
+        self.current_frame().pop();
+        result
+    }
+
+    fn define(&mut self, name: &str, value: Value<'a>) {
+        let scope = self.current_frame().last_mut().expect("Kapsam yığını boş olamaz.");
+        scope.vars.insert(name.to_string(), value);
+    }
+
+    // Değişkeni tanımlar ve işaretsiz tamsayı tipinde olup olmadığını kaydeder.
+    fn define_typed(&mut self, name: &str, value: Value<'a>, unsigned: bool) {
+        self.define(name, value);
+        let scope = self.current_frame().last_mut().expect("Kapsam yığını boş olamaz.");
+        if unsigned {
+            scope.unsigned.insert(name.to_string());
+        } else {
+            scope.unsigned.remove(name);
+        }
+    }
+
+    // İfadenin statik tipi işaretsiz bir tamsayı mı? Yorumlayıcı tamsayıları i64 taşıdığından yalnızca
+    // yazdırma için sabit sonekinden, değişkenin bildirilen tipinden ve fonksiyonun dönüş tipinden çıkarılır.
+    fn is_unsigned(&self, expr: &Expr) -> bool {
+        match &expr.kind {
+            ExprKind::Literal(LiteralValue::TypedInt(_, ty)) => ty.is_unsigned_integer(),
+            // i64'e sığmayan soneksiz sabitlerin tipi u64'tür
+            ExprKind::Literal(LiteralValue::Int(value)) => *value > i64::MAX as u64,
+            ExprKind::Variable(name) => {
+                let frame = self.frames.last().into_iter().flatten().rev();
+                let global = self.frames.first().filter(|_| self.frames.len() > 1).into_iter().flatten().rev();
+                frame.chain(global).find(|scope| scope.vars.contains_key(name)).is_some_and(|scope| scope.unsigned.contains(name))
+            }
+            ExprKind::Binary {
+                left,
+                op: BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod | BinOp::BitwiseAnd | BinOp::BitwiseOr | BinOp::BitwiseXor | BinOp::LShift | BinOp::RShift,
+                right,
+            } => self.is_unsigned(left) || self.is_unsigned(right),
+            ExprKind::Unary { op: UnOp::Neg | UnOp::BitwiseNot, right } => self.is_unsigned(right),
+            ExprKind::Call { callee, .. } => match &callee.kind {
+                ExprKind::Variable(name) => self.functions.get(name).is_some_and(|def| self.resolve(def.return_type).is_unsigned_integer()),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    // Yazdırılacak değer: işaretsiz tipteki tamsayılar işaretsiz gösterilir.
+    fn displayed(&self, expr: &Expr, value: Value<'a>) -> Value<'a> {
+        match value {
+            Value::Int(i) if self.is_unsigned(expr) => Value::Str((i as u64).to_string()),
+            Value::Wide(i) if self.is_unsigned(expr) => Value::Str((i as u128).to_string()),
+            value => value,
+        }
+    }
+
+    fn lookup(&self, name: &str) -> Option<&Value<'a>> {
+        let frame = self.frames.last()?;
+        let global = if self.frames.len() > 1 { self.frames.first() } else { None };
+        frame
+            .iter()
+            .rev()
+            .chain(global.into_iter().flat_map(|g| g.iter().rev()))
+            .find_map(|scope| scope.vars.get(name))
+    }
+
+
+    // --- Deyimler ---
+
+    fn exec_block(&mut self, stmts: &'a [Stmt]) -> Exec<'a, Flow<'a>> {
+        self.push_scope();
+        let mut flow = Ok(Flow::Normal);
+        for stmt in stmts {
+            match self.exec_stmt(stmt) {
+                Ok(Flow::Normal) => {}
+                other => {
+                    flow = other;
+                    break;
+                }
+            }
+        }
+        // `exit` süreci hemen sonlandırır; diğer tüm çıkışlarda defer blokları çalışır.
+        if matches!(flow, Err(Halt::Exit(_))) {
+            self.current_frame().pop();
+            return flow;
+        }
+        let popped = self.pop_scope();
+        match (flow, popped) {
+            (Err(halt), _) => Err(halt),
+            (Ok(_), Err(halt)) => Err(halt),
+            (Ok(flow), Ok(())) => Ok(flow),
+        }
+    }
+
+    fn exec_stmt(&mut self, stmt: &'a Stmt) -> Exec<'a, Flow<'a>> {
+        match &stmt.kind {
+            StmtKind::VarDecl { name, ty, init, .. } => {
+                let value = match init {
+                    Some(init) => match self.eval(init)? {
+                        // İşaretsiz 64 bitlik değer 128 bitlik değişkene işaret uzatılmadan genişletilir.
+                        Value::Int(i) if matches!(self.resolve(ty), Type::I128 | Type::U128) && self.is_unsigned(init) => Value::Wide(i as u64 as i128),
+                        value => self.coerce(value, ty),
+                    },
+                    None => self.default_value(ty),
+                };
+                let unsigned = match self.resolve(ty) {
+                    Type::Any => init.as_ref().is_some_and(|init| self.is_unsigned(init)),
+                    ty => ty.is_unsigned_integer(),
+                };
+                self.define_typed(name, value, unsigned);
+                Ok(Flow::Normal)
+            }
+            StmtKind::Assign { left, value } => {
+                let value = self.eval(value)?;
+                self.assign(left, value)?;
+                Ok(Flow::Normal)
+            }
+            StmtKind::Block(stmts) => self.exec_block(stmts),
+            StmtKind::If { cond, then_branch, else_branch } => {
+                if self.eval_bool(cond)? {
+                    self.exec_stmt(then_branch)
+                } else if let Some(else_branch) = else_branch {
+                    self.exec_stmt(else_branch)
+                } else {
+                    Ok(Flow::Normal)
+                }
+            }
+            StmtKind::Return(expr) => {
+                let value = match expr {
+                    Some(expr) => self.eval(expr)?,
+                    None => Value::Void,
+                };
+                Ok(Flow::Return(value))
+            }
+            StmtKind::Break => Ok(Flow::Break),
+            StmtKind::Continue => Ok(Flow::Continue),
+            StmtKind::ExprStmt(expr) => {
+                self.eval(expr)?;
+                Ok(Flow::Normal)
+            }
+            // Rutinler yorumlayıcıda sırayla (eşzamanlı) çalıştırılır.
+            StmtKind::Routine(expr) => {
+                self.eval(expr)?;
+                Ok(Flow::Normal)
+            }
+            StmtKind::While { condition, body } => {
+                while self.eval_bool(condition)? {
+                    match self.exec_stmt(body)? {
+                        Flow::Break => break,
+                        Flow::Return(value) => return Ok(Flow::Return(value)),
+                        Flow::Normal | Flow::Continue => {}
+                    }
+                }
+                Ok(Flow::Normal)
+            }
+            StmtKind::Loop { body } => loop {
+                match self.exec_stmt(body)? {
+                    Flow::Break => return Ok(Flow::Normal),
+                    Flow::Return(value) => return Ok(Flow::Return(value)),
+                    Flow::Normal | Flow::Continue => {}
+                }
+            },
+            StmtKind::For { initializer, condition, increment, variable, iterable, body } => {
+                self.push_scope();
+                let flow = self.exec_for(initializer.as_deref(), condition.as_ref(), increment.as_ref(), variable.as_ref(), iterable.as_ref(), body);
+                let popped = self.pop_scope();
+                let flow = flow?;
+                popped?;
+                Ok(flow)
+            }
+            StmtKind::Echo(expr) => {
+                let value = self.eval(expr)?;
+                self.write(&value.to_string(), stmt.span)?;
+                Ok(Flow::Normal)
+            }
+            StmtKind::Empty => Ok(Flow::Normal),
+            StmtKind::Defer(body) => {
+                let scope = self.current_frame().last_mut().expect("Kapsam yığını boş olamaz.");
+                scope.defers.push(body);
+                Ok(Flow::Normal)
+            }
+            StmtKind::Unsafe(body) | StmtKind::FastExec(body) => self.exec_stmt(body),
+            StmtKind::Tag { .. } | StmtKind::Rolling(_) | StmtKind::LabeledExpr { .. } | StmtKind::LabeledStmt { .. } => {
+                error("Hata: Etiketli grup deyimleri yorumlayıcıda henüz desteklenmiyor.", stmt.span)
+            }
+            StmtKind::Asm { .. } => error("Hata: 'asm' blokları yorumlayıcıda çalıştırılamaz.", stmt.span),
+        }
+    }
+
+    fn exec_for(
+        &mut self,
+        initializer: Option<&'a Stmt>,
+        condition: Option<&'a Expr>,
+        increment: Option<&'a Expr>,
+        variable: Option<&'a String>,
+        iterable: Option<&'a Expr>,
+        body: &'a Stmt,
+    ) -> Exec<'a, Flow<'a>> {
+        // for (x in ...) döngüsü
+        if let (Some(variable), Some(iterable)) = (variable, iterable) {
+            let items: Vec<Value<'a>> = match &iterable.kind {
+                ExprKind::Range { start, end } => {
+                    let start = self.eval_int(start)?;
+                    let end = self.eval_int(end)?;
+                    (start..end).map(Value::Int).collect()
+                }
+                _ => match self.eval(iterable)? {
+                    Value::Array(items) => items,
+                    Value::Str(s) => s.chars().map(Value::Char).collect(),
+                    other => return error(format!("Hata: 'for-in' döngüsü bu değer üzerinde çalışamaz: {}.", other), iterable.span),
+                },
+            };
+            for item in items {
+                self.define(variable, item);
+                match self.exec_stmt(body)? {
+                    Flow::Break => break,
+                    Flow::Return(value) => return Ok(Flow::Return(value)),
+                    Flow::Normal | Flow::Continue => {}
+                }
+            }
+            return Ok(Flow::Normal);
+        }
+
+        // C-stili for (başlangıç; koşul; artış) döngüsü
+        if let Some(initializer) = initializer {
+            match &initializer.kind {
+                // `for (i = 0; ...)` örtük olarak döngü değişkeni tanımlar.
+                StmtKind::ExprStmt(Expr { kind: ExprKind::Assign { left, value }, .. }) if matches!(left.kind, ExprKind::Variable(_)) => {
+                    let ExprKind::Variable(name) = &left.kind else { unreachable!() };
+                    let value = self.eval(value)?;
+                    self.define(name, value);
+                }
+                _ => {
+                    self.exec_stmt(initializer)?;
+                }
+            }
+        }
+        loop {
+            if let Some(condition) = condition {
+                if !self.eval_bool(condition)? {
+                    break;
+                }
+            }
+            match self.exec_stmt(body)? {
+                Flow::Break => break,
+                Flow::Return(value) => return Ok(Flow::Return(value)),
+                Flow::Normal | Flow::Continue => {}
+            }
+            if let Some(increment) = increment {
+                self.eval(increment)?;
+            }
+        }
+        Ok(Flow::Normal)
+    }
+
+    // --- İfadeler ---
+
+    fn eval_bool(&mut self, expr: &'a Expr) -> Exec<'a, bool> {
+        match self.eval(expr)? {
+            Value::Bool(b) => Ok(b),
+            other => error(format!("Hata: Koşul bool olmalıdır, bulundu: {}.", other), expr.span),
+        }
+    }
+
+    fn eval_int(&mut self, expr: &'a Expr) -> Exec<'a, i64> {
+        let value = self.eval(expr)?;
+        value.as_int().ok_or_else(|| Halt::Error(format!("Hata: Tamsayı bekleniyordu, bulundu: {}.", value), expr.span))
+    }
+
+    fn eval(&mut self, expr: &'a Expr) -> Exec<'a, Value<'a>> {
+        match &expr.kind {
+            ExprKind::Literal(literal) => Ok(match literal {
+                LiteralValue::Int(i) | LiteralValue::Hex(i) => Value::Int(*i as i64),
+                LiteralValue::Float(f) => Value::Float(*f),
+                LiteralValue::TypedInt(i, Type::I128 | Type::U128) => Value::Wide(*i as i128),
+                LiteralValue::TypedInt(i, _) => Value::Int(*i as i64),
+                LiteralValue::TypedFloat(f, _) => Value::Float(*f),
+                LiteralValue::Char(c) => Value::Char(*c),
+                LiteralValue::Str(s) => Value::Str(s.clone()),
+                LiteralValue::Bool(b) => Value::Bool(*b),
+                LiteralValue::Null => Value::Null,
+            }),
+            ExprKind::Variable(name) => self.eval_variable(name, expr.span),
+            ExprKind::Tuple(elements) => Ok(Value::Tuple(self.eval_list(elements)?)),
+            ExprKind::ArrayLiteral(elements) => Ok(Value::Array(self.eval_list(elements)?)),
+            ExprKind::Match { discriminant, cases } => {
+                let value = self.eval(discriminant)?;
+                for (pattern, result) in cases {
+                    let matched = match &pattern.kind {
+                        ExprKind::DefaultCase => true,
+                        _ => {
+                            let pattern = self.eval(pattern)?;
+                            values_equal(&value, &pattern)
+                        }
+                    };
+                    if matched {
+                        return self.eval(result);
+                    }
+                }
+                Ok(Value::Void)
+            }
+            ExprKind::Input(prompt) => {
+                if let Some(prompt) = prompt {
+                    let prompt = self.eval(prompt)?;
+                    self.write(&prompt.to_string(), expr.span)?;
+                }
+                let _ = self.out.flush();
+                let mut line = String::new();
+                io::stdin().lock().read_line(&mut line).map_err(|e| Halt::Error(format!("Hata: Girdi okunamadı: {}", e), expr.span))?;
+                let line = line.strip_suffix('\n').unwrap_or(&line);
+                Ok(Value::Str(line.strip_suffix('\r').unwrap_or(line).to_string()))
+            }
+            ExprKind::Block { statements } => match self.exec_block(statements)? {
+                Flow::Return(value) => Ok(value),
+                Flow::Normal => Ok(Value::Void),
+                Flow::Break | Flow::Continue => error("Hata: 'break'/'continue' bir ifade bloğundan dışarı çıkamaz.", expr.span),
+            },
+            ExprKind::DefaultCase => error("Hata: 'def' sadece match kollarında kullanılabilir.", expr.span),
+            ExprKind::ArrayAccess { name, index } => {
+                let index = self.eval_int(index)?;
+                let array = self.eval_variable(name, expr.span)?;
+                match array {
+                    Value::Array(items) => Ok(items[checked_index(index, items.len(), expr.span)?].clone()),
+                    Value::Str(s) => {
+                        let bytes = s.as_bytes();
+                        Ok(Value::Char(bytes[checked_index(index, bytes.len(), expr.span)?] as char))
+                    }
+                    other => error(format!("Hata: '{}' bir dizi değil: {}.", name, other), expr.span),
+                }
+            }
+            ExprKind::MemberAccess { object, member } => self.eval_member(object, member, expr.span),
+            ExprKind::Range { start, end } => {
+                let start = self.eval_int(start)?;
+                let end = self.eval_int(end)?;
+                Ok(Value::Array((start..end).map(Value::Int).collect()))
+            }
+            ExprKind::Binary { left, op, right } => {
+                // Mantıksal operatörler kısa devre ile değerlendirilir.
+                if matches!(op, BinOp::And | BinOp::Or) {
+                    let left = self.eval_bool(left)?;
+                    if left == matches!(op, BinOp::Or) {
+                        return Ok(Value::Bool(left));
+                    }
+                    return Ok(Value::Bool(self.eval_bool(right)?));
+                }
+                let unsigned = self.is_unsigned(left) || self.is_unsigned(right);
+                let left = self.eval(left)?;
+                let right = self.eval(right)?;
+                binary(op, &left, &right, unsigned).map_err(|message| Halt::Error(message, expr.span))
+            }
+            ExprKind::Unary { op, right } => self.eval_unary(op, right, expr.span),
+            ExprKind::Conditional { cond, then_branch, else_branch } => {
+                if self.eval_bool(cond)? { self.eval(then_branch) } else { self.eval(else_branch) }
+            }
+            // Fonksiyonlar eşzamanlı çalıştırıldığından 'await' değeri olduğu gibi döndürür.
+            ExprKind::Await(inner) => self.eval(inner),
+            ExprKind::Assign { left, value } => {
+                let value = self.eval(value)?;
+                self.assign(left, value.clone())?;
+                Ok(value)
+            }
+            ExprKind::Call { callee, args } => self.eval_call(callee, args, expr.span),
+            ExprKind::Lambda { params, return_type, body } => {
+                let mut captured = HashMap::new();
+                for scope in self.frames.last().expect("Çağrı yığını boş olamaz.") {
+                    for (name, value) in &scope.vars {
+                        captured.insert(name.clone(), value.clone());
+                    }
+                }
+                Ok(Value::Lambda(Rc::new(Closure { params, return_type, body, captured })))
+            }
+            ExprKind::InterpolatedString(parts) => {
+                let mut text = String::new();
+                for part in parts {
+                    // `{x.2}`: float değer iki ondalık basamakla yazılır.
+                    let value = match part.numeric_member() {
+                        Some((object, digits)) => match self.eval(object)? {
+                            Value::Float(f) => {
+                                text.push_str(&format!("{:.*}", digits, f));
+                                continue;
+                            }
+                            value => self.member_of(value, &digits.to_string(), part.span)?,
+                        },
+                        None => self.eval(part)?,
+                    };
+                    text.push_str(&self.displayed(part, value).to_string());
+                }
+                Ok(Value::Str(text))
+            }
+            ExprKind::Try(inner) => match self.eval(inner)? {
+                Value::Ok(value) | Value::Some(value) => Ok(*value),
+                failure @ (Value::Err(_) | Value::None) => Err(Halt::Propagate(failure)),
+                other => error(format!("Hata: '?' operatörü Result veya Option bekler, bulundu: {}.", other), expr.span),
+            },
+            ExprKind::EnumAccess { enum_name, variant_name } => self.enum_value(enum_name, variant_name).ok_or_else(|| {
+                Halt::Error(format!("Hata: '{}::{}' yorumlayıcıda bulunamadı (modül erişimleri henüz desteklenmiyor).", enum_name, variant_name), expr.span)
+            }),
+            ExprKind::StructLiteral { name, fields, .. } => {
+                let Some(definition) = self.structs.get(name).copied() else {
+                    return error(format!("Hata: Tanımlanmamış struct tipi: '{}'.", name), expr.span);
+                };
+                let mut values = Vec::new();
+                for (field_name, field_type) in definition {
+                    let value = match fields.iter().find(|(n, _)| n == field_name) {
+                        Some((_, field_expr)) => {
+                            let value = self.eval(field_expr)?;
+                            self.coerce(value, field_type)
+                        }
+                        None => self.default_value(field_type),
+                    };
+                    values.push((field_name.clone(), value));
+                }
+                Ok(Value::Struct { name: name.clone(), fields: values })
+            }
+            ExprKind::SizeOf(ty) => Ok(Value::Int(self.size_of(ty) as i64)),
+            ExprKind::Send { .. } | ExprKind::Recv(_) => error("Hata: Kanal işlemleri yorumlayıcıda henüz desteklenmiyor.", expr.span),
+        }
+    }
+
+    fn eval_list(&mut self, elements: &'a [Expr]) -> Exec<'a, Vec<Value<'a>>> {
+        elements.iter().map(|element| self.eval(element)).collect()
+    }
+
+    fn eval_variable(&mut self, name: &str, span: Span) -> Exec<'a, Value<'a>> {
+        if let Some(value) = self.lookup(name) {
+            return Ok(value.clone());
+        }
+        if name == "None" {
+            return Ok(Value::None);
+        }
+        if self.functions.contains_key(name) || is_builtin(name) {
+            return Ok(Value::Function(name.to_string()));
+        }
+        error(format!("Hata: Tanımlanmamış isim: '{}'.", name), span)
+    }
+
+    fn eval_member(&mut self, object: &'a Expr, member: &str, span: Span) -> Exec<'a, Value<'a>> {
+        // `Renk.Yesil` enum üyesi veya `Grup.SABIT` grup sabiti (değişken değilse).
+        if let ExprKind::Variable(name) = &object.kind {
+            if self.lookup(name).is_none() {
+                if let Some(value) = self.enum_value(name, member) {
+                    return Ok(value);
+                }
+                if let Some(value) = self.group_constants.get(name).and_then(|c| c.get(member)) {
+                    return Ok(value.clone());
+                }
+            }
+        }
+        let value = self.eval(object)?;
+        self.member_of(value, member, span)
+    }
+
+    // Değerlendirilmiş bir struct veya tuple değerinin alanı.
+    fn member_of(&self, value: Value<'a>, member: &str, span: Span) -> Exec<'a, Value<'a>> {
+        match value {
+            Value::Struct { name, fields } => match fields.into_iter().find(|(field, _)| field == member) {
+                Some((_, value)) => Ok(value),
+                None => error(format!("Hata: '{}' struct'ının '{}' isminde bir alanı yok.", name, member), span),
+            },
+            Value::Tuple(items) => match member.parse::<usize>().ok().and_then(|i| items.get(i).cloned()) {
+                Some(value) => Ok(value),
+                None => error(format!("Hata: Tuple'ın '{}' isminde bir elemanı yok.", member), span),
+            },
+            other => error(format!("Hata: '{}' üyesine bu değer üzerinden erişilemez: {}.", member, other), span),
+        }
+    }
+
+    fn enum_value(&self, enum_name: &str, variant_name: &str) -> Option<Value<'a>> {
+        let variants = self.enums.get(enum_name)?;
+        let (_, value) = variants.iter().find(|(name, _)| name == variant_name)?;
+        Some(Value::Enum { name: enum_name.to_string(), value: *value })
+    }
+
+    fn eval_unary(&mut self, op: &UnOp, right: &'a Expr, span: Span) -> Exec<'a, Value<'a>> {
+        match op {
+            UnOp::Neg => match self.eval(right)? {
+                Value::Int(i) => Ok(Value::Int(i.wrapping_neg())),
+                Value::Wide(i) => Ok(Value::Wide(i.wrapping_neg())),
+                Value::Float(f) => Ok(Value::Float(-f)),
+                other => error(format!("Hata: Negatifleştirme sayısal olmayan değere uygulanamaz: {}.", other), span),
+            },
+            UnOp::Not => Ok(Value::Bool(!self.eval_bool(right)?)),
+            UnOp::BitwiseNot => match self.eval(right)? {
+                Value::Wide(i) => Ok(Value::Wide(!i)),
+                other => match other.as_int() {
+                    Some(i) => Ok(Value::Int(!i)),
+                    None => error(format!("Hata: Tamsayı bekleniyordu, bulundu: {}.", other), right.span),
+                },
+            },
+            UnOp::PreInc | UnOp::PreDec | UnOp::PostInc | UnOp::PostDec => {
+                let old = self.eval(right)?;
+                let delta = if matches!(op, UnOp::PreInc | UnOp::PostInc) { 1 } else { -1 };
+                let new = match &old {
+                    Value::Int(i) => Value::Int(i.wrapping_add(delta)),
+                    Value::Wide(i) => Value::Wide(i.wrapping_add(delta as i128)),
+                    Value::Float(f) => Value::Float(f + delta as f64),
+                    other => return error(format!("Hata: Artırma/azaltma sayısal olmayan değere uygulanamaz: {}.", other), span),
+                };
+                self.assign(right, new.clone())?;
+                Ok(if matches!(op, UnOp::PreInc | UnOp::PreDec) { new } else { old })
+            }
+            UnOp::AddressOf | UnOp::Deref => error("Hata: İşaretçi işlemleri yorumlayıcıda desteklenmiyor.", span),
+        }
+    }
+
+    // --- Atama ---
+
+    fn assign(&mut self, target: &'a Expr, value: Value<'a>) -> Exec<'a, ()> {
+        let mut steps = Vec::new();
+        let root = self.place(target, &mut steps)?;
+        let span = target.span;
+        let Some(mut slot) = lookup_mut(&mut self.frames, &root) else {
+            // Tanımlanmamış bir değişkene atama onu mevcut kapsamda tanımlar (tip kontrolcüsüyle aynı).
+            if steps.is_empty() {
+                self.define(&root, value);
+                return Ok(());
+            }
+            return error(format!("Hata: Tanımlanmamış değişken: '{}'.", root), span);
+        };
+        for step in &steps {
+            slot = match (slot, step) {
+                (Value::Struct { fields, .. }, Step::Field(field)) => match fields.iter_mut().find(|(name, _)| name == field) {
+                    Some((_, value)) => value,
+                    None => return error(format!("Hata: '{}' isminde bir alan yok.", field), span),
+                },
+                (Value::Tuple(items), Step::Field(field)) => match field.parse::<usize>().ok().and_then(|i| items.get_mut(i)) {
+                    Some(value) => value,
+                    None => return error(format!("Hata: Tuple'ın '{}' isminde bir elemanı yok.", field), span),
+                },
+                (Value::Array(items), Step::Index(index)) => {
+                    let index = checked_index(*index, items.len(), span)?;
+                    &mut items[index]
+                }
+                (other, _) => return error(format!("Hata: Bu değerin bir alanına veya elemanına atama yapılamaz: {}.", other), span),
+            };
+        }
+        // Değişkenin mevcut tipi korunur (örn: f64 değişkenine tamsayı atanması).
+        *slot = match (&*slot, value) {
+            (Value::Float(_), Value::Int(i)) => Value::Float(i as f64),
+            (Value::Enum { name, .. }, Value::Int(i)) => enum_from_int(&self.enums, name, i),
+            (_, value) => value,
+        };
+        Ok(())
+    }
+
+    // Atama hedefinin kök değişkenini döner ve ona giden yolu `steps`'e yazar.
+    fn place(&mut self, target: &'a Expr, steps: &mut Vec<Step>) -> Exec<'a, String> {
+        match &target.kind {
+            ExprKind::Variable(name) => Ok(name.clone()),
+            ExprKind::ArrayAccess { name, index } => {
+                let index = self.eval_int(index)?;
+                steps.push(Step::Index(index));
+                Ok(name.clone())
+            }
+            ExprKind::MemberAccess { object, member } => {
+                let root = self.place(object, steps)?;
+                steps.push(Step::Field(member.clone()));
+                Ok(root)
+            }
+            _ => error("Hata: Atama ifadesinin sol tarafı bir değişken, struct alanı veya dizi elemanı olmalıdır.", target.span),
+        }
+    }
+
+    // --- Çağrılar ---
+
+    fn eval_call(&mut self, callee: &'a Expr, args: &'a [(Option<String>, Expr)], span: Span) -> Exec<'a, Value<'a>> {
+        // Metot ve grup fonksiyonu çağrıları: nesne.metot(...), Grup.fonksiyon(...)
+        if let ExprKind::MemberAccess { object, member } = &callee.kind {
+            if let ExprKind::Variable(group) = &object.kind {
+                if self.lookup(group).is_none() {
+                    if let Some(def) = self.groups.get(group).and_then(|g| g.get(member)).copied() {
+                        let args = self.eval_args(args)?;
+                        return self.call(def, args, None, span);
+                    }
+                }
+            }
+            let receiver = self.eval(object)?;
+            match &receiver {
+                Value::Ok(_) | Value::Err(_) | Value::Some(_) | Value::None => {
+                    let args = self.eval_args(args)?;
+                    return self.call_wrapper_method(receiver, member, args, span);
+                }
+                Value::Struct { name, .. } => {
+                    if let Some(def) = self.groups.get(name).and_then(|g| g.get(member)).copied() {
+                        let args = self.eval_args(args)?;
+                        return self.call(def, args, Some(receiver), span);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let function = self.eval(callee)?;
+        let mut values = self.eval_args(args)?;
+        if matches!(&function, Value::Function(name) if is_print(name) && !self.functions.contains_key(name)) {
+            if let (Some((_, expr)), Some((_, value))) = (args.first(), values.first_mut()) {
+                *value = self.displayed(expr, std::mem::replace(value, Value::Void));
+            }
+        }
+        self.call_value(function, values, span)
+    }
+
+    fn eval_args(&mut self, args: &'a [(Option<String>, Expr)]) -> Exec<'a, Vec<(Option<&'a str>, Value<'a>)>> {
+        args.iter().map(|(name, expr)| Ok((name.as_deref(), self.eval(expr)?))).collect()
+    }
+
+    fn call_value(&mut self, function: Value<'a>, args: Vec<(Option<&'a str>, Value<'a>)>, span: Span) -> Exec<'a, Value<'a>> {
+        match function {
+            Value::Function(name) => match self.functions.get(&name).copied() {
+                Some(def) => self.call(def, args, None, span),
+                None => self.call_builtin(&name, args, span),
+            },
+            Value::Lambda(closure) => {
+                let def = FnDef { params: closure.params, return_type: closure.return_type, body: Body::Expr(closure.body) };
+                let captured = Scope { vars: closure.captured.clone(), ..Scope::default() };
+                self.call_with_scope(def, args, None, captured, span)
+            }
+            other => error(format!("Hata: Çağrılabilir olmayan bir değer çağrılamaz: {}.", other), span),
+        }
+    }
+
+    fn call(&mut self, def: FnDef<'a>, args: Vec<(Option<&'a str>, Value<'a>)>, receiver: Option<Value<'a>>, span: Span) -> Exec<'a, Value<'a>> {
+        self.call_with_scope(def, args, receiver, Scope::default(), span)
+    }
+
+    // Yeni bir çağrı çerçevesi açar, parametreleri bağlar ve gövdeyi çalıştırır.
+    fn call_with_scope(
+        &mut self,
+        def: FnDef<'a>,
+        args: Vec<(Option<&'a str>, Value<'a>)>,
+        receiver: Option<Value<'a>>,
+        outer: Scope<'a>,
+        span: Span,
+    ) -> Exec<'a, Value<'a>> {
+        if self.frames.len() > MAX_CALL_DEPTH {
+            return error(format!("Hata: Çağrı derinliği {} sınırını aştı (sonsuz özyineleme?).", MAX_CALL_DEPTH), span);
+        }
+
+        // Argümanları parametrelerle eşleştir: önce isimliler, sonra pozisyoneller sırayla.
+        let mut bound: Vec<Option<Value<'a>>> = vec![None; def.params.len()];
+        let mut next_positional = 0;
+        if let Some(receiver) = receiver {
+            bound[0] = Some(receiver);
+            next_positional = 1;
+        }
+        for (name, value) in args {
+            let index = match name {
+                Some(name) => match def.params.iter().position(|(p, _, _)| p == name) {
+                    Some(index) => index,
+                    None => return error(format!("Hata: Fonksiyonun '{}' isminde bir parametresi yok.", name), span),
+                },
+                None => {
+                    while next_positional < bound.len() && bound[next_positional].is_some() {
+                        next_positional += 1;
+                    }
+                    next_positional
+                }
+            };
+            if index >= bound.len() {
+                return error(format!("Hata: Fonksiyona çok fazla argüman verildi. Beklenen: {}.", def.params.len()), span);
+            }
+            bound[index] = Some(value);
+        }
+
+        self.frames.push(vec![outer, Scope::default()]);
+        let result = self.run_body(def, bound, span);
+        self.frames.pop();
+        match result {
+            Ok(value) | Err(Halt::Propagate(value)) => Ok(self.coerce(value, def.return_type)),
+            Err(halt) => Err(halt),
+        }
+    }
+
+    fn run_body(&mut self, def: FnDef<'a>, bound: Vec<Option<Value<'a>>>, span: Span) -> Exec<'a, Value<'a>> {
+        for ((name, ty, default), value) in def.params.iter().zip(bound) {
+            let value = match (value, default) {
+                (Some(value), _) => value,
+                (None, Some(default)) => self.eval(default)?,
+                (None, None) => return error(format!("Hata: Gerekli olan '{}' parametresi sağlanmadı.", name), span),
+            };
+            let value = self.coerce(value, ty);
+            self.define_typed(name, value, self.resolve(ty).is_unsigned_integer());
+        }
+        match def.body {
+            Body::Stmt(body) => match self.exec_stmt(body)? {
+                Flow::Return(value) => Ok(value),
+                _ => Ok(Value::Void),
+            },
+            Body::Expr(body) => self.eval(body),
+        }
+    }
+
+    fn call_wrapper_method(&mut self, receiver: Value<'a>, method: &str, args: Vec<(Option<&'a str>, Value<'a>)>, span: Span) -> Exec<'a, Value<'a>> {
+        let mut args = args.into_iter().map(|(_, value)| value);
+        let (inner, is_success) = match receiver {
+            Value::Ok(inner) | Value::Some(inner) => (Some(*inner), true),
+            Value::Err(inner) => (Some(*inner), false),
+            _ => (None, false),
+        };
+        match method {
+            "is_ok" | "is_some" => Ok(Value::Bool(is_success)),
+            "is_err" | "is_none" => Ok(Value::Bool(!is_success)),
+            "unwrap" if is_success => Ok(inner.unwrap_or(Value::Void)),
+            "unwrap" => match inner {
+                Some(error_value) => error(format!("Hata: unwrap() bir Err değerine uygulandı: {}", error_value), span),
+                None => error("Hata: unwrap() bir None değerine uygulandı.", span),
+            },
+            "unwrap_or" if is_success => Ok(inner.unwrap_or(Value::Void)),
+            "unwrap_or" => Ok(args.next().unwrap_or(Value::Void)),
+            "expect" if is_success => Ok(inner.unwrap_or(Value::Void)),
+            "expect" => error(args.next().map_or_else(|| "expect() başarısız oldu.".to_string(), |m| m.to_string()), span),
+            _ => error(format!("Hata: Result/Option değerinin '{}' isminde bir metodu yok.", method), span),
+        }
+    }
+
+    fn call_builtin(&mut self, name: &str, args: Vec<(Option<&'a str>, Value<'a>)>, span: Span) -> Exec<'a, Value<'a>> {
+        let mut args: Vec<Value<'a>> = args.into_iter().map(|(_, value)| value).collect();
+        let first = if args.is_empty() { Value::Void } else { args.remove(0) };
+        match name {
+            name if is_print(name) => {
+                let style = match (name, args.first()) {
+                    ("eprint", _) => "error".to_string(),
+                    (_, Some(style)) => style.to_string(),
+                    _ => String::new(),
+                };
+                let (prefix, suffix) = self.style_codes(&style);
+                let newline = if matches!(name, "println" | "eprint") { "\n" } else { "" };
+                self.write(&format!("{}{}{}{}", prefix, first, suffix, newline), span)?;
+                Ok(Value::Void)
+            }
+            "strlen" => Ok(Value::Int(first.to_string().len() as i64)),
+            "arrlen" => match first {
+                Value::Array(items) => Ok(Value::Int(items.len() as i64)),
+                other => error(format!("Hata: 'arrlen' bir dizi bekler, bulundu: {}.", other), span),
+            },
+            "exit" => {
+                let _ = self.out.flush();
+                Err(Halt::Exit(first.as_int().unwrap_or(0) as i32))
+            }
+            "panic" => {
+                self.write(&first.to_string(), span)?;
+                let _ = self.out.flush();
+                Err(Halt::Exit(1))
+            }
+            "args" => {
+                Ok(Value::Array(self.program_args.iter().cloned().map(Value::Str).collect()))
+            }
+            "arg_count" => Ok(Value::Int(self.program_args.len() as i64)),
+            "_int" => Ok(Value::Int(match first {
+                Value::Float(f) => f as i64,
+                Value::Str(s) => parse_leading_int(&s),
+                other => other.as_int().unwrap_or(0),
+            })),
+            "_float" => Ok(Value::Float(match first {
+                Value::Float(f) => f,
+                Value::Str(s) => s.trim().parse().unwrap_or(0.0),
+                other => other.as_int().unwrap_or(0) as f64,
+            })),
+            "_str" => Ok(Value::Str(first.to_string())),
+            "clone" => Ok(first),
+            "Ok" => Ok(Value::Ok(Box::new(first))),
+            "Err" => Ok(Value::Err(Box::new(first))),
+            "Some" => Ok(Value::Some(Box::new(first))),
+            _ => error(format!("Hata: Tanımlanmamış fonksiyon: '{}'.", name), span),
+        }
+    }
+
+    // print/println stil argümanının ANSI ön ve son ekleri (native runtime ile aynı kurallar).
+    fn style_codes(&self, style: &str) -> (String, &'static str) {
+        const RESET: &str = "\x1b[0m";
+        if let Some(code) = self.styles.get(style) {
+            return (code.clone(), RESET);
+        }
+        if style.starts_with('\x1b') {
+            return (style.to_string(), RESET);
+        }
+        match style {
+            "error" => ("\x1b[31m".to_string(), RESET),
+            "warn" => ("\x1b[33m".to_string(), RESET),
+            "info" => ("\x1b[36m".to_string(), RESET),
+            "success" => ("\x1b[32m".to_string(), RESET),
+            _ => (String::new(), ""),
+        }
+    }
+
+    fn write(&mut self, text: &str, span: Span) -> Exec<'a, ()> {
+        self.out.write_all(text.as_bytes()).map_err(|e| Halt::Error(format!("Hata: Çıktı yazılamadı: {}", e), span))
+    }
+
+    // --- Tipler ---
+
+    fn resolve<'t>(&self, ty: &'t Type) -> &'t Type
+    where
+        'a: 't,
+    {
+        match ty {
+            Type::Custom(name) => self.type_aliases.get(name).map_or(ty, |target| self.resolve(target)),
+            _ => ty,
+        }
+    }
+
+    // Değeri bildirilen tipe uyarlar (örn: f64 değişkenine verilen tamsayı).
+    fn coerce(&self, value: Value<'a>, ty: &Type) -> Value<'a> {
+        match (self.resolve(ty), value) {
+            (ty, Value::Int(i)) if ty.is_float() => Value::Float(i as f64),
+            (ty, Value::Wide(i)) if ty.is_float() => Value::Float(i as f64),
+            (Type::I128 | Type::U128, Value::Int(i)) => Value::Wide(i as i128),
+            (Type::I128 | Type::U128, value @ Value::Wide(_)) => value,
+            (ty, Value::Wide(i)) if ty.is_integer() => Value::Int(i as i64),
+            (Type::Custom(name), Value::Int(i)) if self.enums.contains_key(name) => enum_from_int(&self.enums, name, i),
+            (Type::Array(inner, size), Value::Array(mut items)) => {
+                items = items.into_iter().map(|item| self.coerce(item, inner)).collect();
+                if let Some(size) = size {
+                    while items.len() < *size {
+                        items.push(self.default_value(inner));
+                    }
+                }
+                Value::Array(items)
+            }
+            (_, value) => value,
+        }
+    }
+
+    // Başlangıç değeri verilmemiş değişkenlerin sıfır değeri.
+    fn default_value(&self, ty: &Type) -> Value<'a> {
+        let ty = self.resolve(ty);
+        match ty {
+            Type::I128 | Type::U128 => Value::Wide(0),
+            t if t.is_integer() => Value::Int(0),
+            t if t.is_float() => Value::Float(0.0),
+            Type::D32 | Type::D64 | Type::D128 => Value::Float(0.0),
+            Type::Bool => Value::Bool(false),
+            Type::Char => Value::Char('\0'),
+            Type::Bit | Type::Byte | Type::Hex => Value::Int(0),
+            Type::Str(_) => Value::Str(String::new()),
+            Type::Array(inner, Some(size)) => Value::Array((0..*size).map(|_| self.default_value(inner)).collect()),
+            Type::Array(_, None) | Type::Arr => Value::Array(Vec::new()),
+            Type::Tuple(types) => Value::Tuple(types.iter().map(|t| self.default_value(t)).collect()),
+            Type::Option(_) => Value::None,
+            Type::Custom(name) | Type::Enum(name, _) => {
+                if let Some(fields) = self.structs.get(name) {
+                    let fields = fields.iter().map(|(n, t)| (n.clone(), self.default_value(t))).collect();
+                    Value::Struct { name: name.clone(), fields }
+                } else if let Some((_, value)) = self.enums.get(name).and_then(|v| v.first()) {
+                    Value::Enum { name: name.clone(), value: *value }
+                } else {
+                    Value::Null
+                }
+            }
+            _ => Value::Null,
+        }
+    }
+
+    fn size_of(&self, ty: &Type) -> usize {
+        match self.resolve(ty) {
+            Type::I8 | Type::U8 | Type::Bool | Type::Char | Type::Bit | Type::Byte | Type::Hex => 1,
+            Type::I16 | Type::U16 => 2,
+            Type::I32 | Type::U32 | Type::F32 | Type::D32 => 4,
+            Type::I128 | Type::U128 | Type::F128 | Type::D128 | Type::F80 => 16,
+            Type::Array(inner, Some(size)) => self.size_of(inner) * size,
+            Type::Tuple(types) => types.iter().map(|t| self.size_of(t)).sum(),
+            Type::Custom(name) => match self.structs.get(name) {
+                Some(fields) => fields.iter().map(|(_, t)| self.size_of(t)).sum(),
+                None => 8,
+            },
+            Type::Void | Type::Never => 0,
+            _ => 8,
+        }
+    }
+}
+
+impl<'a> Value<'a> {
+    fn as_int(&self) -> Option<i64> {
+        match self {
+            Value::Int(i) => Some(*i),
+            Value::Wide(i) => Some(*i as i64),
+            Value::Enum { value, .. } => Some(*value),
+            Value::Char(c) => Some(*c as i64),
+            Value::Bool(b) => Some(*b as i64),
+            _ => None,
+        }
+    }
+
+    // Tamsayı değeri 128 bite genişletilmiş olarak (karşılaştırma ve 128 bitlik aritmetik için).
+    fn as_wide(&self) -> Option<i128> {
+        match self {
+            Value::Wide(i) => Some(*i),
+            other => other.as_int().map(i128::from),
+        }
+    }
+
+    // İşaretsiz tipteki tamsayının değeri (i64/i128 bit deseninden).
+    fn as_unsigned_wide(&self) -> Option<u128> {
+        match self {
+            Value::Wide(i) => Some(*i as u128),
+            other => other.as_int().map(|i| u128::from(i as u64)),
+        }
+    }
+}
+
+// Native runtime'ın printf biçimleriyle aynı metin gösterimi.
+impl fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |items: &[Value]| items.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(", ");
+        match self {
+            Value::Int(i) => write!(f, "{}", i),
+            Value::Wide(i) => write!(f, "{}", i),
+            Value::Float(x) => write!(f, "{:.6}", x),
+            Value::Bool(b) => write!(f, "{}", *b as i32),
+            Value::Char(c) => write!(f, "{}", c),
+            Value::Str(s) => write!(f, "{}", s),
+            Value::Array(items) => write!(f, "[{}]", list(items)),
+            Value::Tuple(items) => write!(f, "({})", list(items)),
+            Value::Struct { name, fields } => {
+                let fields = fields.iter().map(|(n, v)| format!("{}: {}", n, v)).collect::<Vec<_>>().join(", ");
+                write!(f, "{} {{ {} }}", name, fields)
+            }
+            Value::Enum { value, .. } => write!(f, "{}", value),
+            Value::Function(name) => write!(f, "<fn {}>", name),
+            Value::Lambda(_) => write!(f, "<lambda>"),
+            Value::Ok(inner) => write!(f, "Ok({})", inner),
+            Value::Err(inner) => write!(f, "Err({})", inner),
+            Value::Some(inner) => write!(f, "Some({})", inner),
+            Value::None => write!(f, "None"),
+            Value::Null => write!(f, "null"),
+            Value::Void => Ok(()),
+        }
+    }
+}
+
+// Değişkeni önce mevcut çağrı çerçevesinde, sonra global kapsamda (frames[0]) arar.
+fn lookup_mut<'f, 'a>(frames: &'f mut [Vec<Scope<'a>>], name: &str) -> Option<&'f mut Value<'a>> {
+    let last = frames.len().checked_sub(1)?;
+    let frame = if frames[last].iter().any(|scope| scope.vars.contains_key(name)) { last } else { 0 };
+    frames[frame].iter_mut().rev().find_map(|scope| scope.vars.get_mut(name))
+}
+
+// Tamsayı değeri, aynı değere sahip enum üyesine çevirir (yoksa tamsayı olarak kalır).
+fn enum_from_int<'a>(enums: &HashMap<String, Vec<(String, i64)>>, name: &str, value: i64) -> Value<'a> {
+    match enums.get(name).is_some_and(|variants| variants.iter().any(|(_, v)| *v == value)) {
+        true => Value::Enum { name: name.to_string(), value },
+        false => Value::Int(value),
+    }
+}
+
+fn is_builtin(name: &str) -> bool {
+    matches!(
+        name,
+        "print" | "println" | "echo" | "eprint" | "strlen" | "arrlen" | "exit" | "panic" | "args" | "arg_count"
+            | "_int" | "_float" | "_str" | "clone" | "Ok" | "Err" | "Some"
+    )
+}
+
+fn is_print(name: &str) -> bool {
+    matches!(name, "print" | "println" | "echo" | "eprint")
+}
+
+fn checked_index<'a>(index: i64, len: usize, span: Span) -> Exec<'a, usize> {
+    if index < 0 || index as usize >= len {
+        return error(format!("Hata: Dizi indeksi sınırların dışında: {} (uzunluk {}).", index, len), span);
+    }
+    Ok(index as usize)
+}
+
+// C'deki atoi gibi: baştaki boşlukları atlar, işaret ve rakamları okur, gerisini yok sayar.
+fn parse_leading_int(text: &str) -> i64 {
+    let text = text.trim_start();
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let digits: String = digits.chars().take_while(|c| c.is_ascii_digit()).collect();
+    sign * digits.parse::<i64>().unwrap_or(0)
+}
+
+fn number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Float(f) => Some(*f),
+        other => other.as_int().map(|i| i as f64),
+    }
+}
+
+fn values_equal(left: &Value, right: &Value) -> bool {
+    match (left, right) {
+        (Value::Float(_), _) | (_, Value::Float(_)) => number(left).zip(number(right)).is_some_and(|(l, r)| l == r),
+        (Value::Str(l), Value::Str(r)) => l == r,
+        (Value::Array(l), Value::Array(r)) | (Value::Tuple(l), Value::Tuple(r)) => {
+            l.len() == r.len() && l.iter().zip(r).all(|(a, b)| values_equal(a, b))
+        }
+        (Value::Struct { name: ln, fields: lf }, Value::Struct { name: rn, fields: rf }) => {
+            ln == rn && lf.iter().zip(rf).all(|((_, a), (_, b))| values_equal(a, b))
+        }
+        (Value::Ok(l), Value::Ok(r)) | (Value::Err(l), Value::Err(r)) | (Value::Some(l), Value::Some(r)) => values_equal(l, r),
+        (Value::None, Value::None) | (Value::Null, Value::Null) | (Value::Void, Value::Void) => true,
+        (Value::Function(l), Value::Function(r)) => l == r,
+        _ => left.as_wide().zip(right.as_wide()).is_some_and(|(l, r)| l == r),
+    }
+}
+
+// `unsigned`: işlenenlerden biri işaretsiz tipte; tamsayılar bit desenleriyle işaretsiz karşılaştırılır.
+fn binary<'a>(op: &BinOp, left: &Value<'a>, right: &Value<'a>, unsigned: bool) -> Result<Value<'a>, String> {
+    use std::cmp::Ordering;
+    let ordering = || -> Result<Ordering, String> {
+        let ordering = match (left, right) {
+            (Value::Str(l), Value::Str(r)) => Some(l.cmp(r)),
+            (Value::Float(_), _) | (_, Value::Float(_)) => number(left).zip(number(right)).and_then(|(l, r)| l.partial_cmp(&r)),
+            _ if unsigned => left.as_unsigned_wide().zip(right.as_unsigned_wide()).map(|(l, r)| l.cmp(&r)),
+            _ => left.as_wide().zip(right.as_wide()).map(|(l, r)| l.cmp(&r)),
+        };
+        ordering.ok_or_else(|| format!("Hata: Bu değerler karşılaştırılamaz: {} ve {}.", left, right))
+    };
+    match op {
+        BinOp::Equal | BinOp::Eq | BinOp::Identical => Ok(Value::Bool(values_equal(left, right))),
+        BinOp::NotEqual | BinOp::Ne | BinOp::NotIdentical => Ok(Value::Bool(!values_equal(left, right))),
+        BinOp::Greater | BinOp::Gt => Ok(Value::Bool(ordering()? == Ordering::Greater)),
+        BinOp::Less | BinOp::Lt => Ok(Value::Bool(ordering()? == Ordering::Less)),
+        BinOp::GreaterEqual | BinOp::Ge => Ok(Value::Bool(ordering()? != Ordering::Less)),
+        BinOp::LessEqual | BinOp::Le => Ok(Value::Bool(ordering()? != Ordering::Greater)),
+        BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod => arithmetic(op, left, right),
+        BinOp::BitwiseAnd | BinOp::BitwiseOr | BinOp::BitwiseXor | BinOp::LShift | BinOp::RShift => {
+            if let (Value::Wide(_), _) | (_, Value::Wide(_)) = (left, right) {
+                let (Some(l), Some(r)) = (left.as_wide(), right.as_wide()) else {
+                    return Err(format!("Hata: Bitsel işlem yalnızca tamsayılara uygulanabilir: {} ve {}.", left, right));
+                };
+                return Ok(Value::Wide(match op {
+                    BinOp::BitwiseAnd => l & r,
+                    BinOp::BitwiseOr => l | r,
+                    BinOp::BitwiseXor => l ^ r,
+                    BinOp::LShift => l.wrapping_shl(r as u32),
+                    _ => l.wrapping_shr(r as u32),
+                }));
+            }
+            let (Some(l), Some(r)) = (left.as_int(), right.as_int()) else {
+                return Err(format!("Hata: Bitsel işlem yalnızca tamsayılara uygulanabilir: {} ve {}.", left, right));
+            };
+            Ok(Value::Int(match op {
+                BinOp::BitwiseAnd => l & r,
+                BinOp::BitwiseOr => l | r,
+                BinOp::BitwiseXor => l ^ r,
+                BinOp::LShift => l.wrapping_shl(r as u32),
+                _ => l.wrapping_shr(r as u32),
+            }))
+        }
+        BinOp::And | BinOp::Or => match (left, right) {
+            (Value::Bool(l), Value::Bool(r)) => Ok(Value::Bool(if matches!(op, BinOp::And) { *l && *r } else { *l || *r })),
+            _ => Err(format!("Hata: Mantıksal işlem Bool değerler bekler: {} ve {}.", left, right)),
+        },
+    }
+}
+
+fn arithmetic<'a>(op: &BinOp, left: &Value<'a>, right: &Value<'a>) -> Result<Value<'a>, String> {
+    if let (Value::Float(_), _) | (_, Value::Float(_)) = (left, right) {
+        let (Some(l), Some(r)) = (number(left), number(right)) else {
+            return Err(format!("Hata: Aritmetik işlem sayısal olmayan değere uygulanamaz: {} {} {}.", left, op.symbol(), right));
+        };
+        return Ok(Value::Float(match op {
+            BinOp::Add => l + r,
+            BinOp::Sub => l - r,
+            BinOp::Mul => l * r,
+            BinOp::Div => l / r,
+            _ => l % r,
+        }));
+    }
+    // 128 bitlik bir işlenen varsa işlem 128 bitte yapılır.
+    if let (Value::Wide(_), _) | (_, Value::Wide(_)) = (left, right) {
+        let (Some(l), Some(r)) = (left.as_wide(), right.as_wide()) else {
+            return Err(format!("Hata: Aritmetik işlem sayısal olmayan değere uygulanamaz: {} {} {}.", left, op.symbol(), right));
+        };
+        if matches!(op, BinOp::Div | BinOp::Mod) && r == 0 {
+            return Err("Hata: Sıfıra bölme.".to_string());
+        }
+        return Ok(Value::Wide(match op {
+            BinOp::Add => l.wrapping_add(r),
+            BinOp::Sub => l.wrapping_sub(r),
+            BinOp::Mul => l.wrapping_mul(r),
+            BinOp::Div => l.wrapping_div(r),
+            _ => l.wrapping_rem(r),
+        }));
+    }
+    let (Some(l), Some(r)) = (left.as_int(), right.as_int()) else {
+        return Err(format!("Hata: Aritmetik işlem sayısal olmayan değere uygulanamaz: {} {} {}.", left, op.symbol(), right));
+    };
+    if matches!(op, BinOp::Div | BinOp::Mod) && r == 0 {
+        return Err("Hata: Sıfıra bölme.".to_string());
+    }
+    Ok(Value::Int(match op {
+        BinOp::Add => l.wrapping_add(r),
+        BinOp::Sub => l.wrapping_sub(r),
+        BinOp::Mul => l.wrapping_mul(r),
+        BinOp::Div => l.wrapping_div(r),
+        _ => l.wrapping_rem(r),
+    }))
+}
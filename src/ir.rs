@@ -0,0 +1,566 @@
+// src/ir.rs
+
+// Tip kontrolü ile assembly üretimi arasındaki ara gösterim (IR).
+//
+// Tipli, üç adresli bir gösterimdir: her fonksiyon temel bloklardan (basic block) oluşur, her blok
+// düz bir komut listesi ve tek bir sonlandırıcı (jump/branch/ret) içerir. Ara değerler açık
+// geçicilerde (`%N`) tutulur; skaler yereller de birer geçicidir ve atamalarla yeniden yazılır
+// (SSA değildir). Diziler ve struct'lar gibi bellekte duran yereller çerçeve slotlarındadır (`$N`)
+// ve yalnızca load/store ile okunup yazılır.
+//
+// AST'den IR'ye indirme ir/lower.rs'te, IR'den x86-64 üretimi codegen/ir_emit.rs'tedir.
+// `--emit ir` çıktısı bu dosyadaki Display gerçeklemeleridir.
+
+use std::fmt;
+
+use crate::ast::{expand_asm_body, Type};
+use crate::diagnostic::Span;
+
+pub mod liveness;
+pub mod lower;
+pub mod opt;
+
+// IR değer tipleri. Pointer'lar, bool ve char 64 bitlik tamsayı olarak taşınır.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IrType {
+    I64,
+    F64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Temp(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SlotId(pub u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockId(pub u32);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Temp(Temp),
+    Int(i64),
+    Float(f64),
+    // String literalinin adresi (veri segmentine codegen yerleştirir)
+    Str(String),
+}
+
+// Bellek adresi: taban + indeks*8 + sabit ofset
+#[derive(Debug, Clone, PartialEq)]
+pub struct Address {
+    pub base: Base,
+    pub index: Option<Operand>,
+    pub offset: i32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Base {
+    Slot(SlotId),
+    // Değeri bir adres olan geçici (örn: boyutu bilinmeyen dizi parametresi)
+    Temp(Temp),
+    // Veri segmentindeki global sembol (örn: _nim_argc)
+    Symbol(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    // Bitsel işlemler yalnızca tamsayılarda; kaydırma miktarı 64'e göre modüler, sağa kaydırma
+    // işaretlidir (C arka ucu ve yorumlayıcıyla aynı).
+    And,
+    Or,
+    Xor,
+    Shl,
+    Shr,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnaryOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvertOp {
+    IntToFloat,
+    FloatToInt,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CallArg {
+    Value(Operand, IrType),
+    // Struct değeri: alanları bu adresten okunur, yerleşimi çağrı kuralı belirler
+    Struct { addr: Address, name: String },
+}
+
+// asm bloğunun `%isim` ile başvurduğu değişkenin yeri
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AsmOperand {
+    Temp(Temp),
+    Slot(SlotId),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Inst {
+    Copy { dst: Temp, src: Operand },
+    // Tipi hedef geçicinin tipidir (I64 veya F64)
+    Binary { dst: Temp, op: BinaryOp, lhs: Operand, rhs: Operand },
+    // Sonuç 0/1 (I64); işlenenler tamsayıdır
+    Cmp { dst: Temp, op: CmpOp, lhs: Operand, rhs: Operand },
+    Unary { dst: Temp, op: UnaryOp, src: Operand },
+    Convert { dst: Temp, op: ConvertOp, src: Operand },
+    Load { dst: Temp, addr: Address },
+    Store { addr: Address, src: Operand },
+    // Adresin kendisini hesaplar (lea)
+    AddrOf { dst: Temp, addr: Address },
+    // Slotun tamamını sıfırlar
+    Zero { slot: SlotId },
+    Call { dst: Option<Temp>, callee: String, args: Vec<CallArg>, variadic: bool },
+    // Satır içi asm: gövde olduğu gibi yazılır, `%isim` operandları değişkenlerin bellek adresleriyle
+    // değiştirilir. Başvurulan geçicileri hem okur hem yazar. `span`, bloğun (veya asmcall'ın)
+    // kaynaktaki yeridir; gövdeyi derleyemeyen arka uçların hatası oraya işaret eder.
+    Asm { body: String, operands: Vec<(String, AsmOperand)>, span: Span },
+    // Sonraki komutların ait olduğu kaynak deyimi (debug modunda `.loc`); kod üretmez.
+    // Optimizasyon geçişleri öncesinde silinir.
+    SourceLine(Span),
+}
+
+fn operand_temps(op: &Operand, out: &mut Vec<Temp>) {
+    if let Operand::Temp(t) = op {
+        out.push(*t);
+    }
+}
+
+fn address_temps(addr: &Address, out: &mut Vec<Temp>) {
+    if let Base::Temp(t) = &addr.base {
+        out.push(*t);
+    }
+    if let Some(index) = &addr.index {
+        operand_temps(index, out);
+    }
+}
+
+impl Inst {
+    // Komutun yazdığı geçiciler
+    pub fn defs(&self) -> Vec<Temp> {
+        match self {
+            Inst::Copy { dst, .. }
+            | Inst::Binary { dst, .. }
+            | Inst::Cmp { dst, .. }
+            | Inst::Unary { dst, .. }
+            | Inst::Convert { dst, .. }
+            | Inst::Load { dst, .. }
+            | Inst::AddrOf { dst, .. } => vec![*dst],
+            Inst::Call { dst, .. } => dst.iter().copied().collect(),
+            Inst::Asm { .. } => self.asm_temps(),
+            Inst::Store { .. } | Inst::Zero { .. } | Inst::SourceLine(_) => Vec::new(),
+        }
+    }
+
+    // Komutun okuduğu geçiciler
+    pub fn uses(&self) -> Vec<Temp> {
+        let mut out = Vec::new();
+        match self {
+            Inst::Copy { src, .. } | Inst::Unary { src, .. } | Inst::Convert { src, .. } => operand_temps(src, &mut out),
+            Inst::Binary { lhs, rhs, .. } | Inst::Cmp { lhs, rhs, .. } => {
+                operand_temps(lhs, &mut out);
+                operand_temps(rhs, &mut out);
+            }
+            Inst::Load { addr, .. } | Inst::AddrOf { addr, .. } => address_temps(addr, &mut out),
+            Inst::Store { addr, src } => {
+                address_temps(addr, &mut out);
+                operand_temps(src, &mut out);
+            }
+            Inst::Call { args, .. } => {
+                for arg in args {
+                    match arg {
+                        CallArg::Value(op, _) => operand_temps(op, &mut out),
+                        CallArg::Struct { addr, .. } => address_temps(addr, &mut out),
+                    }
+                }
+            }
+            Inst::Asm { .. } => out = self.asm_temps(),
+            Inst::Zero { .. } | Inst::SourceLine(_) => {}
+        }
+        out
+    }
+
+    fn asm_temps(&self) -> Vec<Temp> {
+        match self {
+            Inst::Asm { operands, .. } => operands
+                .iter()
+                .filter_map(|(_, op)| match op {
+                    AsmOperand::Temp(t) => Some(*t),
+                    AsmOperand::Slot(_) => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Terminator {
+    Jump(BlockId),
+    Branch { cond: Operand, then_block: BlockId, else_block: BlockId },
+    Return(Option<Operand>),
+    // Buraya ulaşılamaz (örn: exit çağrısından sonra)
+    Unreachable,
+}
+
+impl Terminator {
+    pub fn uses(&self) -> Vec<Temp> {
+        let mut out = Vec::new();
+        match self {
+            Terminator::Branch { cond, .. } | Terminator::Return(Some(cond)) => operand_temps(cond, &mut out),
+            Terminator::Jump(_) | Terminator::Return(None) | Terminator::Unreachable => {}
+        }
+        out
+    }
+
+    pub fn successors(&self) -> Vec<BlockId> {
+        match self {
+            Terminator::Jump(target) => vec![*target],
+            Terminator::Branch { then_block, else_block, .. } => vec![*then_block, *else_block],
+            Terminator::Return(_) | Terminator::Unreachable => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Block {
+    pub insts: Vec<Inst>,
+    pub term: Terminator,
+}
+
+#[derive(Debug, Clone)]
+pub struct Slot {
+    pub name: String,
+    pub size: i32,
+    // Slotta yaşayan kaynak değişken (struct ve dizi değişkenleri); geçici slotlarda None.
+    pub var: Option<SourceVar>,
+}
+
+// Bir geçicide yaşayan kaynak kod değişkeni: debug bilgisinde kaynak tipiyle ve bildirildiği
+// satırla tanımlanır.
+#[derive(Debug, Clone)]
+pub struct SourceVar {
+    pub name: String,
+    pub ty: Type,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone)]
+pub enum Param {
+    Scalar(Temp),
+    // Struct parametreleri girişte bu slota kopyalanır
+    Struct { slot: SlotId, name: String },
+}
+
+#[derive(Debug, Clone)]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<Param>,
+    pub ret: Option<IrType>,
+    // main: argc/argv'nin girişte yazılacağı geçiciler
+    pub entry_args: Option<(Temp, Temp)>,
+    // `inline` ile işaretli (-O2'de çağrı noktalarına açılır)
+    pub inline: bool,
+    pub temps: Vec<IrType>,
+    // Geçicinin kaynak koddaki değişkeni (ara değerlerde None)
+    pub temp_vars: Vec<Option<SourceVar>>,
+    pub slots: Vec<Slot>,
+    pub blocks: Vec<Block>,
+}
+
+impl Function {
+    pub fn temp_type(&self, temp: Temp) -> IrType {
+        self.temps[temp.0 as usize]
+    }
+
+    // Giriş bloğundan ulaşılamayan blokları siler ve kalanları sırasıyla yeniden numaralar.
+    pub fn remove_unreachable_blocks(&mut self) {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut worklist = vec![0usize];
+        while let Some(b) = worklist.pop() {
+            if std::mem::replace(&mut reachable[b], true) {
+                continue;
+            }
+            worklist.extend(self.blocks[b].term.successors().iter().map(|s| s.0 as usize));
+        }
+
+        let mut new_ids = vec![BlockId(0); self.blocks.len()];
+        let mut next = 0;
+        for (i, keep) in reachable.iter().enumerate() {
+            if *keep {
+                new_ids[i] = BlockId(next);
+                next += 1;
+            }
+        }
+        let blocks = std::mem::take(&mut self.blocks);
+        for (i, mut block) in blocks.into_iter().enumerate() {
+            if !reachable[i] {
+                continue;
+            }
+            match &mut block.term {
+                Terminator::Jump(target) => *target = new_ids[target.0 as usize],
+                Terminator::Branch { then_block, else_block, .. } => {
+                    *then_block = new_ids[then_block.0 as usize];
+                    *else_block = new_ids[else_block.0 as usize];
+                }
+                Terminator::Return(_) | Terminator::Unreachable => {}
+            }
+            self.blocks.push(block);
+        }
+    }
+}
+
+// IR'ye indirilemeyen fonksiyon; kodu doğrudan AST'den üretilir.
+#[derive(Debug, Clone)]
+pub struct Skipped {
+    pub name: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Module {
+    pub functions: Vec<Result<Function, Skipped>>,
+}
+
+// --- Metin gösterimi (--emit ir) ---
+
+impl fmt::Display for IrType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IrType::I64 => write!(f, "i64"),
+            IrType::F64 => write!(f, "f64"),
+        }
+    }
+}
+
+impl fmt::Display for Temp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+impl fmt::Display for SlotId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "${}", self.0)
+    }
+}
+
+impl fmt::Display for BlockId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bb{}", self.0)
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Temp(t) => write!(f, "{}", t),
+            Operand::Int(v) => write!(f, "{}", v),
+            Operand::Float(v) => write!(f, "{:?}", v),
+            Operand::Str(s) => write!(f, "{:?}", s),
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.base {
+            Base::Slot(slot) => write!(f, "[{}", slot)?,
+            Base::Temp(t) => write!(f, "[{}", t)?,
+            Base::Symbol(name) => write!(f, "[@{}", name)?,
+        }
+        if let Some(index) = &self.index {
+            write!(f, " + {}*8", index)?;
+        }
+        if self.offset != 0 {
+            write!(f, " + {}", self.offset)?;
+        }
+        write!(f, "]")
+    }
+}
+
+impl fmt::Display for BinaryOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            BinaryOp::Add => "add",
+            BinaryOp::Sub => "sub",
+            BinaryOp::Mul => "mul",
+            BinaryOp::Div => "div",
+            BinaryOp::Rem => "rem",
+            BinaryOp::And => "and",
+            BinaryOp::Or => "or",
+            BinaryOp::Xor => "xor",
+            BinaryOp::Shl => "shl",
+            BinaryOp::Shr => "shr",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for CmpOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            CmpOp::Eq => "eq",
+            CmpOp::Ne => "ne",
+            CmpOp::Lt => "lt",
+            CmpOp::Le => "le",
+            CmpOp::Gt => "gt",
+            CmpOp::Ge => "ge",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl fmt::Display for CallArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CallArg::Value(op, ty) => write!(f, "{} {}", ty, op),
+            CallArg::Struct { addr, name } => write!(f, "{} {}", name, addr),
+        }
+    }
+}
+
+impl fmt::Display for Inst {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Inst::Copy { dst, src } => write!(f, "{} = {}", dst, src),
+            Inst::Binary { dst, op, lhs, rhs } => write!(f, "{} = {} {}, {}", dst, op, lhs, rhs),
+            Inst::Cmp { dst, op, lhs, rhs } => write!(f, "{} = cmp {} {}, {}", dst, op, lhs, rhs),
+            Inst::Unary { dst, op, src } => {
+                let name = match op {
+                    UnaryOp::Neg => "neg",
+                    UnaryOp::Not => "not",
+                };
+                write!(f, "{} = {} {}", dst, name, src)
+            }
+            Inst::Convert { dst, op, src } => {
+                let name = match op {
+                    ConvertOp::IntToFloat => "itof",
+                    ConvertOp::FloatToInt => "ftoi",
+                };
+                write!(f, "{} = {} {}", dst, name, src)
+            }
+            Inst::Load { dst, addr } => write!(f, "{} = load {}", dst, addr),
+            Inst::Store { addr, src } => write!(f, "store {}, {}", addr, src),
+            Inst::AddrOf { dst, addr } => write!(f, "{} = addr {}", dst, addr),
+            Inst::Zero { slot } => write!(f, "zero {}", slot),
+            Inst::SourceLine(span) => write!(f, "; satır {}:{}", span.line, span.column),
+            Inst::Call { dst, callee, args, variadic } => {
+                if let Some(dst) = dst {
+                    write!(f, "{} = ", dst)?;
+                }
+                write!(f, "call {}(", callee)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                if *variadic {
+                    write!(f, ", ...")?;
+                }
+                write!(f, ")")
+            }
+            Inst::Asm { body, operands, .. } => {
+                let lines = expand_asm_body(body, |name| format!("%{}", name));
+                write!(f, "asm {:?}", lines.join("; "))?;
+                for (i, (name, op)) in operands.iter().enumerate() {
+                    let sep = if i == 0 { " [" } else { ", " };
+                    match op {
+                        AsmOperand::Temp(t) => write!(f, "{}{} = {}", sep, name, t)?,
+                        AsmOperand::Slot(s) => write!(f, "{}{} = {}", sep, name, s)?,
+                    }
+                }
+                if !operands.is_empty() {
+                    write!(f, "]")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for Terminator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Terminator::Jump(target) => write!(f, "jump {}", target),
+            Terminator::Branch { cond, then_block, else_block } => write!(f, "branch {}, {}, {}", cond, then_block, else_block),
+            Terminator::Return(Some(value)) => write!(f, "ret {}", value),
+            Terminator::Return(None) => write!(f, "ret"),
+            Terminator::Unreachable => write!(f, "unreachable"),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.inline {
+            write!(f, "inline ")?;
+        }
+        write!(f, "fn {}(", self.name)?;
+        for (i, param) in self.params.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            match param {
+                Param::Scalar(t) => write!(f, "{}: {}", t, self.temp_type(*t))?,
+                Param::Struct { slot, name } => write!(f, "{}: {}", slot, name)?,
+            }
+        }
+        write!(f, ")")?;
+        if let Some(ret) = self.ret {
+            write!(f, " -> {}", ret)?;
+        }
+        writeln!(f, " {{")?;
+        for (i, slot) in self.slots.iter().enumerate() {
+            writeln!(f, "    {} = slot {} ; {}", SlotId(i as u32), slot.size, slot.name)?;
+        }
+        for (i, var) in self.temp_vars.iter().enumerate() {
+            if let Some(var) = var {
+                writeln!(f, "    ; {}: {} {}", Temp(i as u32), self.temps[i], var.name)?;
+            }
+        }
+        for (i, block) in self.blocks.iter().enumerate() {
+            writeln!(f, "{}:", BlockId(i as u32))?;
+            for inst in &block.insts {
+                writeln!(f, "    {}", inst)?;
+            }
+            writeln!(f, "    {}", block.term)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, item) in self.functions.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            match item {
+                Ok(function) => write!(f, "{}", function)?,
+                Err(skipped) => writeln!(f, "; fn {}: IR'ye indirilemedi, AST'den üretilir: {}", skipped.name, skipped.reason)?,
+            }
+        }
+        Ok(())
+    }
+}
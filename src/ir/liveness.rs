@@ -0,0 +1,129 @@
+// src/ir/liveness.rs
+
+// Geçicilerin canlılık analizi (register ataması için).
+//
+// Önce her bloğun girişinde ve çıkışında canlı olan geçiciler geri yönlü veri akışıyla (sabit nokta
+// iterasyonu) bulunur. Ardından bloklar sırayla düzleştirilerek her komuta bir konum verilir ve her
+// geçici için tek parça bir canlılık aralığı [start, end] çıkarılır. Aralıklar deliksizdir: döngü
+// boyunca canlı bir geçicinin aralığı döngünün tamamını kapsar.
+
+use std::collections::HashSet;
+
+use super::{Function, Param, Temp};
+
+pub struct Liveness {
+    pub live_in: Vec<HashSet<Temp>>,
+    pub live_out: Vec<HashSet<Temp>>,
+}
+
+pub fn analyze(func: &Function) -> Liveness {
+    let count = func.blocks.len();
+    // Her blok için: tanımlanmadan önce okunanlar (gen) ve yazılanlar (kill)
+    let mut gen = vec![HashSet::new(); count];
+    let mut kill = vec![HashSet::new(); count];
+    for (b, block) in func.blocks.iter().enumerate() {
+        for inst in &block.insts {
+            for temp in inst.uses() {
+                if !kill[b].contains(&temp) {
+                    gen[b].insert(temp);
+                }
+            }
+            kill[b].extend(inst.defs());
+        }
+        for temp in block.term.uses() {
+            if !kill[b].contains(&temp) {
+                gen[b].insert(temp);
+            }
+        }
+    }
+
+    let mut live_in: Vec<HashSet<Temp>> = vec![HashSet::new(); count];
+    let mut live_out: Vec<HashSet<Temp>> = vec![HashSet::new(); count];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for b in (0..count).rev() {
+            let mut out = HashSet::new();
+            for succ in func.blocks[b].term.successors() {
+                out.extend(live_in[succ.0 as usize].iter().copied());
+            }
+            let mut inn: HashSet<Temp> = out.difference(&kill[b]).copied().collect();
+            inn.extend(gen[b].iter().copied());
+            if inn != live_in[b] || out != live_out[b] {
+                live_in[b] = inn;
+                live_out[b] = out;
+                changed = true;
+            }
+        }
+    }
+    Liveness { live_in, live_out }
+}
+
+// Düzleştirilmiş konumlar: `b` bloğunun `i`. komutu `block_start[b] + i`, sonlandırıcısı
+// `block_start[b] + insts.len()` konumundadır.
+pub fn block_starts(func: &Function) -> Vec<usize> {
+    let mut starts = Vec::with_capacity(func.blocks.len());
+    let mut pos = 0;
+    for block in &func.blocks {
+        starts.push(pos);
+        pos += block.insts.len() + 1;
+    }
+    starts
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Interval {
+    // Aralık `pos` konumunun üzerinden geçiyor mu (orada başlamadan ve bitmeden)?
+    pub fn crosses(&self, pos: usize) -> bool {
+        self.start < pos && pos < self.end
+    }
+}
+
+// Her geçicinin canlılık aralığı; hiç kullanılmayan geçiciler için None.
+pub fn intervals(func: &Function, liveness: &Liveness) -> Vec<Option<Interval>> {
+    let mut result: Vec<Option<Interval>> = vec![None; func.temps.len()];
+    let mut extend = |temp: Temp, pos: usize| {
+        let slot = &mut result[temp.0 as usize];
+        *slot = Some(match *slot {
+            Some(iv) => Interval { start: iv.start.min(pos), end: iv.end.max(pos) },
+            None => Interval { start: pos, end: pos },
+        });
+    };
+
+    // Parametreler ve main'in argc/argv'si fonksiyon girişinde yazılır.
+    for param in &func.params {
+        if let Param::Scalar(temp) = param {
+            extend(*temp, 0);
+        }
+    }
+    if let Some((argc, argv)) = func.entry_args {
+        extend(argc, 0);
+        extend(argv, 0);
+    }
+
+    let starts = block_starts(func);
+    for (b, block) in func.blocks.iter().enumerate() {
+        let start = starts[b];
+        let end = start + block.insts.len();
+        for temp in &liveness.live_in[b] {
+            extend(*temp, start);
+        }
+        for temp in &liveness.live_out[b] {
+            extend(*temp, end);
+        }
+        for (i, inst) in block.insts.iter().enumerate() {
+            for temp in inst.defs().into_iter().chain(inst.uses()) {
+                extend(temp, start + i);
+            }
+        }
+        for temp in block.term.uses() {
+            extend(temp, end);
+        }
+    }
+    result
+}
@@ -0,0 +1,1316 @@
+// src/ir/lower.rs
+
+// Tip kontrolünden geçmiş AST'yi IR'ye indirir. Desteklenmeyen bir yapı görülürse fonksiyon
+// indirilemez (Err) ve codegen o fonksiyonun kodunu eskisi gibi doğrudan AST'den üretir.
+//
+// İfade tipleri codegen'de olduğu gibi TypeChecker'a sorulur; bu yüzden indirme sırasında
+// kapsamlar TypeChecker'da da açılıp kapatılır ve yereller orada da tanımlanır.
+
+use std::collections::HashMap;
+
+use crate::ast::{asm_operands, layout_words, struct_layout, BinOp, Decl, Expr, ExprKind, LiteralValue, Stmt, StmtKind, TargetPlatform, Type, UnOp};
+use crate::codegen::{exit_symbol, format_specifier, line_end, print_style};
+use crate::type_checker::{TypeChecker, VarInfo};
+
+use super::{
+    Address, AsmOperand, Base, BinaryOp, Block, BlockId, CallArg, CmpOp, ConvertOp, Function, Inst, IrType, Module, Operand, Param,
+    Skipped, Slot, SlotId, SourceVar, Temp, Terminator, UnaryOp,
+};
+use crate::diagnostic::Span;
+
+// Programdaki tüm fonksiyonları indirir (`--emit ir` için).
+pub fn lower_program<'a>(program: &'a [Decl], checker: &mut TypeChecker<'a>, target: TargetPlatform) -> Module {
+    let mut module = Module::default();
+    for decl in program {
+        if let Decl::Function { name, .. } = decl {
+            let item = lower_function(program, checker, target, decl).map_err(|reason| Skipped { name: name.clone(), reason });
+            module.functions.push(item);
+        }
+    }
+    module
+}
+
+// Tek bir `Decl::Function`'ı indirir. Hata durumunda TypeChecker'ın kapsam yığını eski haline döner.
+pub fn lower_function<'a>(
+    program: &'a [Decl],
+    checker: &mut TypeChecker<'a>,
+    target: TargetPlatform,
+    decl: &Decl,
+) -> Result<Function, String> {
+    let Decl::Function { name, params, return_type, body, is_inline, span, .. } = decl else {
+        return Err("Fonksiyon bildirimi bekleniyordu.".to_string());
+    };
+    let (scope_depth, label_depth) = (checker.scopes.len(), checker.labels.len());
+    let mut lowerer = Lowerer {
+        program,
+        checker,
+        target,
+        func: Function {
+            name: name.clone(),
+            params: Vec::new(),
+            ret: if *return_type == Type::Void || name == "main" { None } else { Some(ir_type(return_type)) },
+            entry_args: None,
+            inline: *is_inline,
+            temps: Vec::new(),
+            temp_vars: Vec::new(),
+            slots: Vec::new(),
+            blocks: Vec::new(),
+        },
+        current: BlockId(0),
+        locals: Vec::new(),
+        defers: Vec::new(),
+        loops: Vec::new(),
+        asm_blocks: HashMap::new(),
+    };
+    if let Err(reason) = lowerer.lower_body(params, body, *span) {
+        lowerer.checker.scopes.truncate(scope_depth);
+        lowerer.checker.labels.truncate(label_depth);
+        return Err(reason);
+    }
+    let mut func = lowerer.func;
+    func.remove_unreachable_blocks();
+    Ok(func)
+}
+
+// AST tipinin IR karşılığı
+fn ir_type(ty: &Type) -> IrType {
+    if ty.is_float() { IrType::F64 } else { IrType::I64 }
+}
+
+// Tamsayı gibi işlenen tipler (karşılaştırma ve aritmetik)
+fn is_int_like(ty: &Type) -> bool {
+    ty.is_integer() || matches!(ty, Type::Bool | Type::Char)
+}
+
+// YENİ: i128/u128 değerleri IR'de iki 64 bitlik kelime (alt, üst) olarak taşınır.
+fn is_wide(ty: &Type) -> bool {
+    matches!(ty, Type::I128 | Type::U128)
+}
+
+// Bir yerelin IR'deki yeri
+#[derive(Debug, Clone)]
+enum Local {
+    // Skaler değer (pointer'lar dahil)
+    Temp(Temp),
+    // Boyutu bilinen, çerçevede duran dizi
+    Array { slot: SlotId, len: usize },
+    Struct { slot: SlotId, name: String },
+    // i128/u128 değişkeni: alt ve üst 64 bit ayrı geçicilerde durur.
+    Wide { lo: Temp, hi: Temp },
+}
+
+struct Lowerer<'c, 'a> {
+    program: &'a [Decl],
+    checker: &'c mut TypeChecker<'a>,
+    target: TargetPlatform,
+    func: Function,
+    current: BlockId,
+    locals: Vec<HashMap<String, Local>>,
+    // Her kapsamın kayıtlı defer gövdeleri (yazılış sırasıyla); `locals` ile aynı derinliktedir.
+    defers: Vec<Vec<Stmt>>,
+    loops: Vec<(BlockId, BlockId, usize)>, // (continue hedefi, break hedefi, döngü gövdesinin kapsam derinliği)
+    asm_blocks: HashMap<String, String>, // etiket -> asm gövdesi (asmcall için)
+}
+
+impl<'c, 'a> Lowerer<'c, 'a> {
+    fn lower_body(&mut self, params: &[(String, Type, Option<Expr>)], body: &Stmt, span: Span) -> Result<(), String> {
+        self.current = self.new_block();
+        self.push_scope();
+
+        if self.func.name == "main" {
+            let argc = self.new_var("argc", &Type::I32, span);
+            let argv = self.new_var("argv", &Type::Ptr(Box::new(Type::Str(None))), span);
+            self.define("argc", Type::I32, Local::Temp(argc))?;
+            self.define("argv", Type::Ptr(Box::new(Type::Str(None))), Local::Temp(argv))?;
+            self.func.entry_args = Some((argc, argv));
+        } else {
+            for (p_name, p_ty, _) in params {
+                if let Some(fields) = self.struct_field_count(p_ty) {
+                    let slot = self.new_slot(p_name, (fields * 8) as i32);
+                    self.bind_slot(slot, p_name, p_ty.clone(), span);
+                    let struct_name = if let Type::Custom(n) = p_ty { n.clone() } else { unreachable!() };
+                    self.func.params.push(Param::Struct { slot, name: struct_name.clone() });
+                    self.define(p_name, p_ty.clone(), Local::Struct { slot, name: struct_name })?;
+                } else if is_wide(p_ty) {
+                    return Err(format!("128 bitlik '{}' parametresi henüz IR'ye indirilemiyor.", p_name));
+                } else {
+                    let temp = self.new_var(p_name, p_ty, span);
+                    self.func.params.push(Param::Scalar(temp));
+                    self.define(p_name, p_ty.clone(), Local::Temp(temp))?;
+                }
+            }
+        }
+
+        self.lower_stmt(body)?;
+        self.terminate(Terminator::Return(None));
+        self.pop_scope()
+    }
+
+    // --- Bloklar, geçiciler ve kapsamlar ---
+
+    fn new_block(&mut self) -> BlockId {
+        self.func.blocks.push(Block { insts: Vec::new(), term: Terminator::Unreachable });
+        BlockId(self.func.blocks.len() as u32 - 1)
+    }
+
+    fn new_temp(&mut self, ty: IrType) -> Temp {
+        self.func.temps.push(ty);
+        self.func.temp_vars.push(None);
+        Temp(self.func.temps.len() as u32 - 1)
+    }
+
+    // Kaynak koddaki bir değişkenin geçicisi; tipi ve bildirim konumu debug bilgisine taşınır.
+    fn new_var(&mut self, name: &str, ty: &Type, span: Span) -> Temp {
+        self.func.temps.push(ir_type(ty));
+        self.func.temp_vars.push(Some(SourceVar { name: name.to_string(), ty: ty.clone(), span }));
+        Temp(self.func.temps.len() as u32 - 1)
+    }
+
+    fn new_slot(&mut self, name: &str, size: i32) -> SlotId {
+        self.func.slots.push(Slot { name: name.to_string(), size, var: None });
+        SlotId(self.func.slots.len() as u32 - 1)
+    }
+
+    // Slotu bir kaynak değişkenine bağlar; değişken debug bilgisinde slotun çerçeve adresiyle tanımlanır.
+    fn bind_slot(&mut self, slot: SlotId, name: &str, ty: Type, span: Span) {
+        self.func.slots[slot.0 as usize].var = Some(SourceVar { name: name.to_string(), ty, span });
+    }
+
+    fn emit(&mut self, inst: Inst) {
+        self.func.blocks[self.current.0 as usize].insts.push(inst);
+    }
+
+    // Geçerli bloğu sonlandırır. Sonraki komutlar ulaşılamaz yeni bir bloğa düşer
+    // (örn: `return`'den sonraki kod); bu bloklar indirmenin sonunda silinir.
+    fn terminate(&mut self, term: Terminator) {
+        self.func.blocks[self.current.0 as usize].term = term;
+        self.current = self.new_block();
+    }
+
+    fn switch_to(&mut self, block: BlockId) {
+        self.current = block;
+    }
+
+    fn push_scope(&mut self) {
+        self.checker.push_scope();
+        self.locals.push(HashMap::new());
+        self.defers.push(Vec::new());
+    }
+
+    fn pop_scope(&mut self) -> Result<(), String> {
+        self.locals.pop();
+        self.defers.pop();
+        self.checker.pop_scope()
+    }
+
+    fn define(&mut self, name: &str, ty: Type, local: Local) -> Result<(), String> {
+        self.checker.define_variable(name.to_string(), VarInfo { ty, is_const: false, _is_mutable: true, span: Span::dummy() })?;
+        self.locals.last_mut().expect("Kapsam yığını boş olamaz.").insert(name.to_string(), local);
+        Ok(())
+    }
+
+    fn local(&self, name: &str) -> Option<Local> {
+        self.locals.iter().rev().find_map(|scope| scope.get(name).cloned())
+    }
+
+    fn expr_type(&mut self, expr: &Expr) -> Result<Type, String> {
+        self.checker.type_of_expr(expr).map_err(|e| format!("Kod üretimi hatası: {}", e))
+    }
+
+    // Struct tipiyse kapladığı kelime sayısı (iç içe struct alanları dahil)
+    fn struct_field_count(&self, ty: &Type) -> Option<usize> {
+        let Type::Custom(struct_name) = ty else { return None };
+        struct_layout(self.program, struct_name).map(|words| words.len().max(1))
+    }
+
+    fn struct_fields(&self, struct_name: &str) -> Result<&'a [(String, Type)], String> {
+        self.program
+            .iter()
+            .find_map(|decl| match decl {
+                Decl::Struct { name, fields, .. } if name == struct_name => Some(fields.as_slice()),
+                _ => None,
+            })
+            .ok_or_else(|| format!("Hata: '{}' struct'ı tanımlanmamış.", struct_name))
+    }
+
+    // Alanın struct başından ofseti ve tipi
+    fn struct_field(&self, struct_name: &str, member: &str) -> Result<(i32, Type), String> {
+        let mut offset = 0;
+        for (f_name, f_ty) in self.struct_fields(struct_name)? {
+            if f_name == member {
+                return Ok((offset, f_ty.clone()));
+            }
+            offset += layout_words(self.program, f_ty) as i32 * 8;
+        }
+        Err(format!("Hata: '{}' struct'ında '{}' alanı bulunamadı.", struct_name, member))
+    }
+
+    // `op`'u hedef tipe getirir (tamsayıdan float'a dönüşüm gerekiyorsa ekler)
+    fn coerce(&mut self, op: Operand, from: &Type, to: IrType) -> Operand {
+        if to == IrType::F64 && !from.is_float() {
+            let dst = self.new_temp(IrType::F64);
+            self.emit(Inst::Convert { dst, op: ConvertOp::IntToFloat, src: op });
+            Operand::Temp(dst)
+        } else {
+            op
+        }
+    }
+
+    // --- Deyimler ---
+
+    fn lower_stmt(&mut self, stmt: &Stmt) -> Result<(), String> {
+        if !matches!(stmt.kind, StmtKind::Block(_)) {
+            self.emit(Inst::SourceLine(stmt.span));
+        }
+        match &stmt.kind {
+            StmtKind::Block(stmts) => {
+                self.push_scope();
+                for s in stmts {
+                    self.lower_stmt(s)?;
+                }
+                self.lower_defers(self.defers.len() - 1)?;
+                self.pop_scope()
+            }
+            StmtKind::VarDecl { name, ty, init, .. } => self.lower_var_decl(name, ty, init.as_ref(), stmt.span),
+            StmtKind::Assign { left, value } => self.lower_assign(left, value).map(|_| ()),
+            StmtKind::ExprStmt(expr) => self.lower_expr(expr).map(|_| ()),
+            StmtKind::Echo(expr) => self.lower_print(expr, None, false),
+            StmtKind::Return(expr_opt) => {
+                let value = match expr_opt {
+                    Some(expr) => {
+                        let ty = self.expr_type(expr)?;
+                        let op = self.lower_expr(expr)?;
+                        match self.func.ret {
+                            Some(ret) => Some(self.coerce(op, &ty, ret)),
+                            // main: float dönüş değeri çıkış kodu olmaz
+                            None if self.func.name == "main" && !ty.is_float() => Some(op),
+                            None => None,
+                        }
+                    }
+                    None => None,
+                };
+                if self.defers.iter().any(|scope| !scope.is_empty()) {
+                    // Dönüş değeri defer'lar çalışmadan önce alınır (defer değişkeni değiştirse de).
+                    let value = match value {
+                        Some(op @ Operand::Temp(temp)) => {
+                            let copy = self.new_temp(self.func.temp_type(temp));
+                            self.emit(Inst::Copy { dst: copy, src: op });
+                            Some(Operand::Temp(copy))
+                        }
+                        other => other,
+                    };
+                    self.lower_defers(0)?;
+                    self.terminate(Terminator::Return(value));
+                    return Ok(());
+                }
+                self.terminate(Terminator::Return(value));
+                Ok(())
+            }
+            StmtKind::If { cond, then_branch, else_branch } => {
+                let then_block = self.new_block();
+                let end_block = self.new_block();
+                let else_block = if else_branch.is_some() { self.new_block() } else { end_block };
+
+                let cond = self.lower_expr(cond)?;
+                self.terminate(Terminator::Branch { cond, then_block, else_block });
+
+                self.switch_to(then_block);
+                self.lower_stmt(then_branch)?;
+                self.terminate(Terminator::Jump(end_block));
+
+                if let Some(else_stmt) = else_branch {
+                    self.switch_to(else_block);
+                    self.lower_stmt(else_stmt)?;
+                    self.terminate(Terminator::Jump(end_block));
+                }
+                self.switch_to(end_block);
+                Ok(())
+            }
+            StmtKind::While { condition, body } => {
+                let header = self.new_block();
+                let body_block = self.new_block();
+                let end_block = self.new_block();
+                self.terminate(Terminator::Jump(header));
+
+                self.switch_to(header);
+                let cond = self.lower_expr(condition)?;
+                self.terminate(Terminator::Branch { cond, then_block: body_block, else_block: end_block });
+
+                self.switch_to(body_block);
+                self.lower_loop_body(body, header, end_block)?;
+                self.terminate(Terminator::Jump(header));
+                self.switch_to(end_block);
+                Ok(())
+            }
+            StmtKind::Loop { body } => {
+                let body_block = self.new_block();
+                let end_block = self.new_block();
+                self.terminate(Terminator::Jump(body_block));
+
+                self.switch_to(body_block);
+                self.lower_loop_body(body, body_block, end_block)?;
+                self.terminate(Terminator::Jump(body_block));
+                self.switch_to(end_block);
+                Ok(())
+            }
+            StmtKind::For { initializer, condition, increment, variable, iterable, body } => {
+                match (variable, iterable) {
+                    (Some(var_name), Some(iter_expr)) => match &iter_expr.kind {
+                        ExprKind::Range { start, end } => self.lower_for_range(var_name, start, end, body, stmt.span),
+                        _ => self.lower_for_in(var_name, iter_expr, body, stmt.span),
+                    },
+                    _ => self.lower_for_c(initializer.as_deref(), condition.as_ref(), increment.as_ref(), body),
+                }
+            }
+            StmtKind::Break | StmtKind::Continue => {
+                let &(continue_block, break_block, depth) = self.loops.last().ok_or("Döngü dışında break/continue kullanılamaz.")?;
+                self.lower_defers(depth)?;
+                let target = if matches!(stmt.kind, StmtKind::Break) { break_block } else { continue_block };
+                self.terminate(Terminator::Jump(target));
+                Ok(())
+            }
+            StmtKind::Empty => Ok(()),
+            StmtKind::Defer(body) => {
+                self.defers.last_mut().expect("Kapsam yığını boş olamaz.").push((**body).clone());
+                Ok(())
+            }
+            StmtKind::FastExec(body) | StmtKind::Unsafe(body) => self.lower_stmt(body),
+            StmtKind::Asm { tag, body } => {
+                self.asm_blocks.insert(tag.clone(), body.clone());
+                self.lower_asm(body, stmt.span)
+            }
+            _ => Err("Bu deyim tipi IR'ye indirilemiyor.".to_string()),
+        }
+    }
+
+    // `from` derinliğinden en içteki kapsama kadar kayıtlı defer'ları içten dışa, her kapsamda ters
+    // sırayla indirir. Gövdeler indirilirken kayıtlı defer'lar kaldırılır (gövdedeki return onları
+    // yeniden çalıştırmasın).
+    fn lower_defers(&mut self, from: usize) -> Result<(), String> {
+        let pending: Vec<Stmt> = self.defers[from..].iter().rev().flat_map(|scope| scope.iter().rev().cloned()).collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        let depth = self.defers.len();
+        let saved = std::mem::replace(&mut self.defers, vec![Vec::new(); depth]);
+        let result = pending.iter().try_for_each(|stmt| self.lower_stmt(stmt));
+        self.defers = saved;
+        result
+    }
+
+    // asm bloğunu yazıldığı yere yerleştirir; `%isim` operandları yerellerin IR'deki yerlerine bağlanır.
+    fn lower_asm(&mut self, body: &str, span: Span) -> Result<(), String> {
+        let mut operands = Vec::new();
+        for name in asm_operands(body) {
+            let operand = match self.local(&name) {
+                Some(Local::Temp(temp)) => AsmOperand::Temp(temp),
+                Some(Local::Array { slot, .. } | Local::Struct { slot, .. }) => AsmOperand::Slot(slot),
+                Some(Local::Wide { .. }) => return Err(format!("asm bloğu 128 bitlik '%{}' değişkenine başvuruyor; bu henüz IR'ye indirilemiyor.", name)),
+                None => return Err(format!("asm bloğu IR'de yerel olmayan bir değişkene başvuruyor: '%{}'.", name)),
+            };
+            operands.push((name, operand));
+        }
+        self.emit(Inst::Asm { body: body.to_string(), operands, span });
+        Ok(())
+    }
+
+    fn lower_loop_body(&mut self, body: &Stmt, continue_block: BlockId, break_block: BlockId) -> Result<(), String> {
+        self.loops.push((continue_block, break_block, self.defers.len()));
+        let result = self.lower_stmt(body);
+        self.loops.pop();
+        result
+    }
+
+    fn lower_var_decl(&mut self, name: &str, ty: &Type, init: Option<&Expr>, span: Span) -> Result<(), String> {
+        // `var p = Point { ... }` gibi tipini ilk değerinden alan struct değişkenleri
+        let init_struct = match (ty, init) {
+            (Type::Any, Some(expr)) => Some(self.expr_type(expr)?).filter(|t| self.struct_field_count(t).is_some()),
+            _ => None,
+        };
+        let ty = init_struct.as_ref().unwrap_or(ty);
+        let is_array = *ty == Type::Arr || matches!(ty, Type::Array(_, _));
+        if is_array {
+            match init.map(|e| &e.kind) {
+                // Literal dizi çerçeveye kopyalanır
+                Some(ExprKind::ArrayLiteral(elements)) => {
+                    let slot = self.lower_array_literal(name, elements)?;
+                    let elem_ty = match ty {
+                        Type::Array(inner, _) => Some((**inner).clone()),
+                        _ => match self.expr_type(init.unwrap())? {
+                            Type::Array(inner, _) => Some(*inner),
+                            Type::ArrayLiteral(items) => items.into_iter().next(),
+                            _ => None,
+                        },
+                    };
+                    if let Some(elem_ty) = elem_ty {
+                        self.bind_slot(slot, name, Type::Array(Box::new(elem_ty), Some(elements.len())), span);
+                    }
+                    self.define(name, ty.clone(), Local::Array { slot, len: elements.len() })
+                }
+                // Literal olmayan dizi ifadeleri (örn: args()) kopyalanmaz; başlangıç adresi saklanır.
+                Some(_) => {
+                    let value = self.lower_expr(init.unwrap())?;
+                    let temp = self.new_var(name, ty, span);
+                    self.emit(Inst::Copy { dst: temp, src: value });
+                    self.define(name, ty.clone(), Local::Temp(temp))
+                }
+                None => match ty {
+                    Type::Array(_, Some(len)) => {
+                        let slot = self.new_slot(name, (*len * 8) as i32);
+                        self.bind_slot(slot, name, ty.clone(), span);
+                        self.emit(Inst::Zero { slot });
+                        self.define(name, ty.clone(), Local::Array { slot, len: *len })
+                    }
+                    _ => {
+                        let temp = self.new_var(name, ty, span);
+                        self.emit(Inst::Copy { dst: temp, src: Operand::Int(0) });
+                        self.define(name, ty.clone(), Local::Temp(temp))
+                    }
+                },
+            }
+        } else if let Some(fields) = self.struct_field_count(ty) {
+            // İlk değer önce kelimelerine ayrılır, sonra yeni slota yazılır.
+            let words = init.map(|expr| self.lower_struct_words(expr)).transpose()?;
+            let slot = self.new_slot(name, (fields * 8) as i32);
+            self.bind_slot(slot, name, ty.clone(), span);
+            let addr = Address { base: Base::Slot(slot), index: None, offset: 0 };
+            match words {
+                Some(words) => self.store_struct_words(addr, words),
+                None => self.emit(Inst::Zero { slot }),
+            }
+            let struct_name = if let Type::Custom(n) = ty { n.clone() } else { unreachable!() };
+            self.define(name, ty.clone(), Local::Struct { slot, name: struct_name })
+        } else {
+            let init_ty = init.map(|expr| self.expr_type(expr)).transpose()?;
+            // Tipi belirtilmemiş değişken (`var x = ...`) ilk değerinin tipini alır.
+            let var_ty = match (ty, &init_ty) {
+                (Type::Any, Some(init_ty)) => init_ty.clone(),
+                _ => ty.clone(),
+            };
+            if is_wide(&var_ty) {
+                let (lo, hi) = match init {
+                    Some(expr) => self.lower_wide(expr)?,
+                    None => (Operand::Int(0), Operand::Int(0)),
+                };
+                let lo_temp = self.new_temp(IrType::I64);
+                let hi_temp = self.new_temp(IrType::I64);
+                self.emit(Inst::Copy { dst: lo_temp, src: lo });
+                self.emit(Inst::Copy { dst: hi_temp, src: hi });
+                return self.define(name, var_ty, Local::Wide { lo: lo_temp, hi: hi_temp });
+            }
+            let ir_ty = ir_type(&var_ty);
+            let value = match (init, &init_ty) {
+                (Some(expr), Some(init_ty)) => {
+                    let op = self.lower_expr(expr)?;
+                    self.coerce(op, init_ty, ir_ty)
+                }
+                _ => Operand::Int(0),
+            };
+            let temp = self.new_var(name, &var_ty, span);
+            self.emit(Inst::Copy { dst: temp, src: value });
+            self.define(name, var_ty, Local::Temp(temp))
+        }
+    }
+
+    fn lower_array_literal(&mut self, name: &str, elements: &[Expr]) -> Result<SlotId, String> {
+        let slot = self.new_slot(name, (elements.len() * 8) as i32);
+        for (i, elem) in elements.iter().enumerate() {
+            let value = self.lower_expr(elem)?;
+            self.emit(Inst::Store { addr: Address { base: Base::Slot(slot), index: None, offset: (i * 8) as i32 }, src: value });
+        }
+        Ok(slot)
+    }
+
+    // for i in start..end: bitiş değeri her turda yeniden hesaplanır.
+    fn lower_for_range(&mut self, var_name: &str, start: &Expr, end: &Expr, body: &Stmt, span: Span) -> Result<(), String> {
+        self.push_scope();
+        let counter = match self.local(var_name) {
+            Some(Local::Temp(temp)) => temp,
+            _ => {
+                let temp = self.new_var(var_name, &Type::I32, span);
+                self.define(var_name, Type::I32, Local::Temp(temp))?;
+                temp
+            }
+        };
+        let start = self.lower_expr(start)?;
+        self.emit(Inst::Copy { dst: counter, src: start });
+
+        let header = self.new_block();
+        let body_block = self.new_block();
+        let step = self.new_block();
+        let end_block = self.new_block();
+        self.terminate(Terminator::Jump(header));
+
+        self.switch_to(header);
+        let end = self.lower_expr(end)?;
+        let cond = self.new_temp(IrType::I64);
+        self.emit(Inst::Cmp { dst: cond, op: CmpOp::Lt, lhs: Operand::Temp(counter), rhs: end });
+        self.terminate(Terminator::Branch { cond: Operand::Temp(cond), then_block: body_block, else_block: end_block });
+
+        self.switch_to(body_block);
+        self.lower_loop_body(body, step, end_block)?;
+        self.terminate(Terminator::Jump(step));
+
+        self.switch_to(step);
+        self.emit(Inst::Binary { dst: counter, op: BinaryOp::Add, lhs: Operand::Temp(counter), rhs: Operand::Int(1) });
+        self.terminate(Terminator::Jump(header));
+
+        self.switch_to(end_block);
+        self.pop_scope()
+    }
+
+    // for x in dizi: dizi değişkeni olmayan ifadeler (literaller, boyutu bilinen dizi değerleri)
+    // döngüden önce bir kez hesaplanır.
+    fn lower_for_in(&mut self, var_name: &str, iter_expr: &Expr, body: &Stmt, span: Span) -> Result<(), String> {
+        let iter_ty = self.expr_type(iter_expr)?;
+        let local_array = match &iter_expr.kind {
+            ExprKind::Variable(name) => match self.local(name) {
+                Some(Local::Array { slot, len }) => Some((Base::Slot(slot), len)),
+                _ => None,
+            },
+            _ => None,
+        };
+        let (elem_type, base, len) = match (local_array, iter_ty) {
+            (Some((base, len)), Type::Array(inner, _)) => (*inner, base, len),
+            (Some((base, len)), _) => (Type::Any, base, len),
+            (None, Type::Array(inner, Some(len))) => (*inner, self.lower_fixed_base(iter_expr)?, len),
+            (None, Type::ArrayLiteral(items)) => {
+                let elem_type = match items.split_first() {
+                    Some((first, rest)) if rest.iter().all(|t| t == first) => first.clone(),
+                    _ => Type::Any,
+                };
+                (elem_type, self.lower_fixed_base(iter_expr)?, items.len())
+            }
+            (None, other) => return Err(format!("For in döngüsü '{}' tipi üzerinde IR'ye indirilemiyor (uzunluğu bilinmeyen dizi).", other)),
+        };
+
+        let index = self.new_temp(IrType::I64);
+        self.emit(Inst::Copy { dst: index, src: Operand::Int(0) });
+        self.push_scope();
+        let element = self.new_var(var_name, &elem_type, span);
+        self.define(var_name, elem_type, Local::Temp(element))?;
+
+        let header = self.new_block();
+        let body_block = self.new_block();
+        let step = self.new_block();
+        let end_block = self.new_block();
+        self.terminate(Terminator::Jump(header));
+
+        self.switch_to(header);
+        let cond = self.new_temp(IrType::I64);
+        self.emit(Inst::Cmp { dst: cond, op: CmpOp::Lt, lhs: Operand::Temp(index), rhs: Operand::Int(len as i64) });
+        self.terminate(Terminator::Branch { cond: Operand::Temp(cond), then_block: body_block, else_block: end_block });
+
+        self.switch_to(body_block);
+        self.emit(Inst::Load { dst: element, addr: Address { base, index: Some(Operand::Temp(index)), offset: 0 } });
+        self.lower_loop_body(body, step, end_block)?;
+        self.terminate(Terminator::Jump(step));
+
+        self.switch_to(step);
+        self.emit(Inst::Binary { dst: index, op: BinaryOp::Add, lhs: Operand::Temp(index), rhs: Operand::Int(1) });
+        self.terminate(Terminator::Jump(header));
+
+        self.switch_to(end_block);
+        self.pop_scope()
+    }
+
+    // Döngü boyunca değişmeyecek bir başlangıç adresi (ifadenin değeri yeni bir geçiciye kopyalanır)
+    fn lower_fixed_base(&mut self, expr: &Expr) -> Result<Base, String> {
+        let value = self.lower_expr(expr)?;
+        let start = self.new_temp(IrType::I64);
+        self.emit(Inst::Copy { dst: start, src: value });
+        Ok(Base::Temp(start))
+    }
+
+    // for (init, cond, inc)
+    fn lower_for_c(&mut self, init: Option<&Stmt>, condition: Option<&Expr>, increment: Option<&Expr>, body: &Stmt) -> Result<(), String> {
+        if let Some(init) = init {
+            // Başlatıcı sadece bir değişken ismiyse (i), 0'a ilklendirilir.
+            if let StmtKind::ExprStmt(Expr { kind: ExprKind::Variable(name), .. }) = &init.kind {
+                let Some(Local::Temp(temp)) = self.local(name) else {
+                    return Err(format!("Döngü değişkeni bulunamadı: {name}"));
+                };
+                self.emit(Inst::Copy { dst: temp, src: Operand::Int(0) });
+            } else {
+                self.lower_stmt(init)?;
+            }
+        }
+
+        let header = self.new_block();
+        let body_block = self.new_block();
+        let step = self.new_block();
+        let end_block = self.new_block();
+        self.terminate(Terminator::Jump(header));
+
+        self.switch_to(header);
+        match condition {
+            Some(cond) => {
+                let cond = self.lower_expr(cond)?;
+                self.terminate(Terminator::Branch { cond, then_block: body_block, else_block: end_block });
+            }
+            None => self.terminate(Terminator::Jump(body_block)),
+        }
+
+        self.switch_to(body_block);
+        self.lower_loop_body(body, step, end_block)?;
+        self.terminate(Terminator::Jump(step));
+
+        self.switch_to(step);
+        if let Some(inc) = increment {
+            self.lower_expr(inc)?;
+        }
+        self.terminate(Terminator::Jump(header));
+        self.switch_to(end_block);
+        Ok(())
+    }
+
+    // Atama; atanan değeri döndürür.
+    fn lower_assign(&mut self, left: &Expr, value: &Expr) -> Result<Operand, String> {
+        let value_ty = self.expr_type(value)?;
+        if self.struct_field_count(&value_ty).is_some() {
+            // Struct ataması kelime kelime kopyalanır; sağ taraf önce tamamen okunur ki
+            // `p = Point { x: p.y, y: p.x }` gibi atamalar kendi üzerine yazmasın.
+            let words = self.lower_struct_words(value)?;
+            let (addr, _) = self.struct_place(left)?;
+            self.store_struct_words(addr, words);
+            return Ok(Operand::Int(0));
+        }
+        if let ExprKind::Variable(name) = &left.kind {
+            if let Some(Local::Wide { lo, hi }) = self.local(name) {
+                let (lo_value, hi_value) = self.lower_wide(value)?;
+                self.emit(Inst::Copy { dst: lo, src: lo_value });
+                self.emit(Inst::Copy { dst: hi, src: hi_value });
+                return Ok(Operand::Temp(lo));
+            }
+        }
+        let op = self.lower_expr(value)?;
+        match &left.kind {
+            ExprKind::Variable(name) => match self.local(name) {
+                Some(Local::Temp(temp)) => {
+                    let op = self.coerce(op, &value_ty, self.func.temp_type(temp));
+                    self.emit(Inst::Copy { dst: temp, src: op.clone() });
+                    Ok(op)
+                }
+                Some(_) => Err(format!("'{}' dizi değişkenine bütün olarak atama IR'ye indirilemiyor.", name)),
+                None => Err(format!("Atama hatası: Bilinmeyen değişken '{}'", name)),
+            },
+            ExprKind::MemberAccess { .. } | ExprKind::ArrayAccess { .. } => {
+                let addr = self.lvalue_address(left)?;
+                let target_ty = self.expr_type(left)?;
+                let op = self.coerce(op, &value_ty, ir_type(&target_ty));
+                self.emit(Inst::Store { addr, src: op.clone() });
+                Ok(op)
+            }
+            _ => Err("Şimdilik sadece değişkenlere, struct alanlarına ve dizi elemanlarına atama destekleniyor.".to_string()),
+        }
+    }
+
+    // Bellekteki bir değerin (struct alanı, dizi elemanı) adresi
+    fn lvalue_address(&mut self, expr: &Expr) -> Result<Address, String> {
+        match &expr.kind {
+            ExprKind::MemberAccess { object, member } => {
+                let (addr, struct_name) = self.struct_place(object)?;
+                let (offset, f_ty) = self.struct_field(&struct_name, member)?;
+                if self.struct_field_count(&f_ty).is_some() {
+                    return Err(format!("'{}' struct alanı IR'de yalnızca bütün olarak kopyalanabilir.", member));
+                }
+                Ok(Address { offset: addr.offset + offset, ..addr })
+            }
+            ExprKind::ArrayAccess { name, index } => {
+                let index = self.lower_expr(index)?;
+                match self.local(name) {
+                    Some(Local::Array { slot, .. }) => Ok(Address { base: Base::Slot(slot), index: Some(index), offset: 0 }),
+                    // Boyutu bilinmeyen diziler (parametreler, args()) sadece adres olarak durur.
+                    Some(Local::Temp(temp)) => Ok(Address { base: Base::Temp(temp), index: Some(index), offset: 0 }),
+                    _ => Err(format!("Dizi bulunamadı: {name}")),
+                }
+            }
+            _ => Err("Bellek adresi olmayan ifade.".to_string()),
+        }
+    }
+
+    // Struct değerinin (değişken ya da iç içe alan) başlangıç adresi ve struct adı
+    fn struct_place(&mut self, expr: &Expr) -> Result<(Address, String), String> {
+        match &expr.kind {
+            ExprKind::Variable(var_name) => match self.local(var_name) {
+                Some(Local::Struct { slot, name }) => Ok((Address { base: Base::Slot(slot), index: None, offset: 0 }, name)),
+                _ => Err(format!("'{}' bir struct değişkeni değil.", var_name)),
+            },
+            ExprKind::MemberAccess { object, member } => {
+                let (addr, struct_name) = self.struct_place(object)?;
+                let (offset, f_ty) = self.struct_field(&struct_name, member)?;
+                match f_ty {
+                    Type::Custom(inner) if self.struct_field_count(&f_ty).is_some() => {
+                        Ok((Address { offset: addr.offset + offset, ..addr }, inner))
+                    }
+                    _ => Err(format!("'{}' alanı bir struct değil.", member)),
+                }
+            }
+            _ => Err("Üye erişimi yalnızca struct değişkenleri üzerinde IR'ye indirilebiliyor.".to_string()),
+        }
+    }
+
+    // Struct değerini kelime düzeninde (iç içe alanlar yerinde açılmış) okur.
+    fn lower_struct_words(&mut self, expr: &Expr) -> Result<Vec<Operand>, String> {
+        match &expr.kind {
+            ExprKind::StructLiteral { name, fields, .. } => {
+                // Alanlar yazıldıkları sırada değerlendirilir, tanım sırasına göre yerleştirilir.
+                let mut values = HashMap::new();
+                for (f_name, f_expr) in fields {
+                    let (_, f_ty) = self.struct_field(name, f_name)?;
+                    let words = if self.struct_field_count(&f_ty).is_some() {
+                        self.lower_struct_words(f_expr)?
+                    } else {
+                        let value_ty = self.expr_type(f_expr)?;
+                        let op = self.lower_expr(f_expr)?;
+                        vec![self.coerce(op, &value_ty, ir_type(&f_ty))]
+                    };
+                    values.insert(f_name.as_str(), words);
+                }
+                let mut words = Vec::new();
+                for (f_name, _) in self.struct_fields(name)? {
+                    words.extend(values.remove(f_name.as_str()).ok_or_else(|| format!("'{}' alanı eksik.", f_name))?);
+                }
+                Ok(words)
+            }
+            ExprKind::Variable(_) | ExprKind::MemberAccess { .. } => {
+                let (addr, struct_name) = self.struct_place(expr)?;
+                let layout = struct_layout(self.program, &struct_name).unwrap_or_default();
+                let mut words = Vec::new();
+                for (i, word_ty) in layout.iter().enumerate() {
+                    let dst = self.new_temp(ir_type(word_ty));
+                    self.emit(Inst::Load { dst, addr: Address { offset: addr.offset + (i * 8) as i32, ..addr.clone() } });
+                    words.push(Operand::Temp(dst));
+                }
+                Ok(words)
+            }
+            _ => Err(format!("Struct değeri olarak {} IR'ye indirilemiyor.", expr_kind_name(&expr.kind))),
+        }
+    }
+
+    fn store_struct_words(&mut self, addr: Address, words: Vec<Operand>) {
+        for (i, src) in words.into_iter().enumerate() {
+            self.emit(Inst::Store { addr: Address { offset: addr.offset + (i * 8) as i32, ..addr.clone() }, src });
+        }
+    }
+
+    // --- İfadeler ---
+
+    fn lower_expr(&mut self, expr: &Expr) -> Result<Operand, String> {
+        match &expr.kind {
+            ExprKind::Literal(LiteralValue::Int(v) | LiteralValue::Hex(v)) => Ok(Operand::Int(*v as i64)),
+            ExprKind::Literal(LiteralValue::Char(c)) => Ok(Operand::Int(*c as i64)),
+            ExprKind::Literal(LiteralValue::Bool(b)) => Ok(Operand::Int(*b as i64)),
+            ExprKind::Literal(LiteralValue::TypedInt(v, ty)) => u64::try_from(*v)
+                .map(|v| Operand::Int(v as i64))
+                .map_err(|_| format!("Hata: 64 bitten geniş '{}' sabitleri ({}) henüz desteklenmiyor.", ty, v)),
+            ExprKind::Literal(LiteralValue::Float(v) | LiteralValue::TypedFloat(v, _)) => Ok(Operand::Float(*v)),
+            ExprKind::Literal(LiteralValue::Str(s)) => Ok(Operand::Str(s.clone())),
+            ExprKind::Variable(name) => match self.local(name) {
+                Some(Local::Temp(temp)) => Ok(Operand::Temp(temp)),
+                Some(Local::Array { slot, .. }) => {
+                    let dst = self.new_temp(IrType::I64);
+                    self.emit(Inst::AddrOf { dst, addr: Address { base: Base::Slot(slot), index: None, offset: 0 } });
+                    Ok(Operand::Temp(dst))
+                }
+                Some(Local::Struct { .. }) => Err(format!("'{}' struct değeri IR'de yalnızca çağrı argümanı olabilir.", name)),
+                Some(Local::Wide { .. }) => Err(format!("128 bitlik '{}' değeri IR'de yalnızca atama, karşılaştırma ve yazdırmada kullanılabilir.", name)),
+                None => Err(format!("Kod üretimi hatası: Bilinmeyen değişken '{}'", name)),
+            },
+            ExprKind::Binary { left, op, right } => self.lower_binary(left, op, right),
+            ExprKind::Unary { op, right } => self.lower_unary(op, right),
+            ExprKind::Conditional { cond, then_branch, else_branch } => {
+                let ty = self.expr_type(expr)?;
+                let result = self.new_temp(ir_type(&ty));
+                let then_block = self.new_block();
+                let else_block = self.new_block();
+                let end_block = self.new_block();
+                let cond = self.lower_expr(cond)?;
+                self.terminate(Terminator::Branch { cond, then_block, else_block });
+                for (block, branch) in [(then_block, then_branch), (else_block, else_branch)] {
+                    self.switch_to(block);
+                    let branch_ty = self.expr_type(branch)?;
+                    let value = self.lower_expr(branch)?;
+                    let value = self.coerce(value, &branch_ty, self.func.temp_type(result));
+                    self.emit(Inst::Copy { dst: result, src: value });
+                    self.terminate(Terminator::Jump(end_block));
+                }
+                self.switch_to(end_block);
+                Ok(Operand::Temp(result))
+            }
+            ExprKind::Assign { left, value } => self.lower_assign(left, value),
+            ExprKind::Call { callee, args } => self.lower_call(expr, callee, args),
+            ExprKind::Input(prompt) => {
+                let prompt = match prompt {
+                    Some(p) => self.lower_expr(p)?,
+                    None => Operand::Int(0),
+                };
+                let dst = self.new_temp(IrType::I64);
+                self.emit(Inst::Call { dst: Some(dst), callee: "_input".to_string(), args: vec![CallArg::Value(prompt, IrType::I64)], variadic: false });
+                Ok(Operand::Temp(dst))
+            }
+            ExprKind::ArrayLiteral(elements) => {
+                let slot = self.lower_array_literal("<dizi literali>", elements)?;
+                let dst = self.new_temp(IrType::I64);
+                self.emit(Inst::AddrOf { dst, addr: Address { base: Base::Slot(slot), index: None, offset: 0 } });
+                Ok(Operand::Temp(dst))
+            }
+            ExprKind::MemberAccess { .. } | ExprKind::ArrayAccess { .. } => {
+                let ty = self.expr_type(expr)?;
+                let addr = self.lvalue_address(expr)?;
+                let dst = self.new_temp(ir_type(&ty));
+                self.emit(Inst::Load { dst, addr });
+                Ok(Operand::Temp(dst))
+            }
+            _ => Err(format!("Bu ifade tipi IR'ye indirilemiyor: {}", expr_kind_name(&expr.kind))),
+        }
+    }
+
+    fn lower_binary(&mut self, left: &Expr, op: &BinOp, right: &Expr) -> Result<Operand, String> {
+        if matches!(op, BinOp::And | BinOp::Or) {
+            return self.lower_logical(left, matches!(op, BinOp::And), right);
+        }
+        let left_ty = self.expr_type(left)?;
+        let right_ty = self.expr_type(right)?;
+
+        if is_wide(&left_ty) || is_wide(&right_ty) {
+            let unsigned = left_ty == Type::U128 || right_ty == Type::U128;
+            return self.lower_wide_compare(left, op, right, unsigned);
+        }
+
+        if left_ty.is_float() || right_ty.is_float() {
+            let op = match op {
+                BinOp::Add => BinaryOp::Add,
+                BinOp::Sub => BinaryOp::Sub,
+                BinOp::Mul => BinaryOp::Mul,
+                BinOp::Div => BinaryOp::Div,
+                BinOp::Mod => BinaryOp::Rem,
+                _ => return Err(format!("Desteklenmeyen ikili operatör (float): '{}'. Sadece +, -, *, / desteklenir.", op.symbol())),
+            };
+            let lhs = self.lower_expr(left)?;
+            let lhs = self.coerce(lhs, &left_ty, IrType::F64);
+            let rhs = self.lower_expr(right)?;
+            let rhs = self.coerce(rhs, &right_ty, IrType::F64);
+            let dst = self.new_temp(IrType::F64);
+            if op == BinaryOp::Rem {
+                // float mod runtime'daki _fmod ile hesaplanır
+                let args = vec![CallArg::Value(lhs, IrType::F64), CallArg::Value(rhs, IrType::F64)];
+                self.emit(Inst::Call { dst: Some(dst), callee: "_fmod".to_string(), args, variadic: false });
+            } else {
+                self.emit(Inst::Binary { dst, op, lhs, rhs });
+            }
+            return Ok(Operand::Temp(dst));
+        }
+
+        if !(is_int_like(&left_ty) || is_int_like(&right_ty)) {
+            return Err(format!("Desteklenmeyen ikili operatör tipleri: {} ve {}.", left_ty, right_ty));
+        }
+        let lhs = self.lower_expr(left)?;
+        let rhs = self.lower_expr(right)?;
+        let dst = self.new_temp(IrType::I64);
+        let arith = match op {
+            BinOp::Add => Some(BinaryOp::Add),
+            BinOp::Sub => Some(BinaryOp::Sub),
+            BinOp::Mul => Some(BinaryOp::Mul),
+            BinOp::Div => Some(BinaryOp::Div),
+            BinOp::Mod => Some(BinaryOp::Rem),
+            BinOp::BitwiseAnd => Some(BinaryOp::And),
+            BinOp::BitwiseOr => Some(BinaryOp::Or),
+            BinOp::BitwiseXor => Some(BinaryOp::Xor),
+            BinOp::LShift => Some(BinaryOp::Shl),
+            BinOp::RShift => Some(BinaryOp::Shr),
+            _ => None,
+        };
+        if let Some(op) = arith {
+            self.emit(Inst::Binary { dst, op, lhs, rhs });
+            return Ok(Operand::Temp(dst));
+        }
+        let cmp = match op {
+            BinOp::Equal | BinOp::Eq => CmpOp::Eq,
+            BinOp::NotEqual | BinOp::Ne => CmpOp::Ne,
+            BinOp::Less | BinOp::Lt => CmpOp::Lt,
+            BinOp::Greater | BinOp::Gt => CmpOp::Gt,
+            BinOp::LessEqual | BinOp::Le => CmpOp::Le,
+            BinOp::GreaterEqual | BinOp::Ge => CmpOp::Ge,
+            _ => return Err(format!("Desteklenmeyen ikili operatör (int): '{}'", op.symbol())),
+        };
+        self.emit(Inst::Cmp { dst, op: cmp, lhs, rhs });
+        Ok(Operand::Temp(dst))
+    }
+
+    // 128 bitlik değeri (alt, üst) kelimelerine ayırır. Sabitler, 128 bitlik değişkenler ve
+    // genişletilen 64 bitlik tamsayı ifadeleri desteklenir; 128 bitlik aritmetik henüz yoktur.
+    fn lower_wide(&mut self, expr: &Expr) -> Result<(Operand, Operand), String> {
+        let split = |v: u128| (Operand::Int(v as u64 as i64), Operand::Int((v >> 64) as u64 as i64));
+        match &expr.kind {
+            ExprKind::Literal(LiteralValue::TypedInt(v, _)) => return Ok(split(*v)),
+            ExprKind::Literal(LiteralValue::Int(v) | LiteralValue::Hex(v)) => return Ok(split(*v as u128)),
+            // Eksili sabit 128 bit üzerinde katlanır.
+            ExprKind::Unary { op: UnOp::Neg, right } => match &right.kind {
+                ExprKind::Literal(LiteralValue::TypedInt(v, _)) => return Ok(split((*v as i128).wrapping_neg() as u128)),
+                ExprKind::Literal(LiteralValue::Int(v) | LiteralValue::Hex(v)) => return Ok(split((*v as i128).wrapping_neg() as u128)),
+                _ => {}
+            },
+            ExprKind::Variable(name) => {
+                if let Some(Local::Wide { lo, hi }) = self.local(name) {
+                    return Ok((Operand::Temp(lo), Operand::Temp(hi)));
+                }
+            }
+            _ => {}
+        }
+        let ty = self.expr_type(expr)?;
+        if is_wide(&ty) || !is_int_like(&ty) {
+            return Err(format!("128 bitlik değer olarak {} henüz IR'ye indirilemiyor.", expr_kind_name(&expr.kind)));
+        }
+        let lo = self.lower_expr(expr)?;
+        if ty.is_unsigned_integer() {
+            return Ok((lo, Operand::Int(0)));
+        }
+        // İşaretli değerin üst kelimesi işaret bitiyle doldurulur: negatifse -1, değilse 0.
+        let negative = self.new_temp(IrType::I64);
+        self.emit(Inst::Cmp { dst: negative, op: CmpOp::Lt, lhs: lo.clone(), rhs: Operand::Int(0) });
+        let hi = self.new_temp(IrType::I64);
+        self.emit(Inst::Unary { dst: hi, op: UnaryOp::Neg, src: Operand::Temp(negative) });
+        Ok((lo, Operand::Temp(hi)))
+    }
+
+    // 128 bitlik karşılaştırma kelime kelime yapılır: üst kelimeler farklıysa onlar karar verir,
+    // eşitse alt kelimeler işaretsiz olarak karşılaştırılır.
+    fn lower_wide_compare(&mut self, left: &Expr, op: &BinOp, right: &Expr, unsigned: bool) -> Result<Operand, String> {
+        let (left_lo, left_hi) = self.lower_wide(left)?;
+        let (right_lo, right_hi) = self.lower_wide(right)?;
+        let (equal, joiner) = match op {
+            BinOp::Equal | BinOp::Eq => (CmpOp::Eq, BinaryOp::And),
+            BinOp::NotEqual | BinOp::Ne => (CmpOp::Ne, BinaryOp::Or),
+            BinOp::Less | BinOp::Lt | BinOp::Greater | BinOp::Gt | BinOp::LessEqual | BinOp::Le | BinOp::GreaterEqual | BinOp::Ge => {
+                // a > b == b < a, a <= b == !(b < a), a >= b == !(a < b)
+                let swap = matches!(op, BinOp::Greater | BinOp::Gt | BinOp::LessEqual | BinOp::Le);
+                let negate = matches!(op, BinOp::LessEqual | BinOp::Le | BinOp::GreaterEqual | BinOp::Ge);
+                let (a, b) = if swap { ((right_lo, right_hi), (left_lo, left_hi)) } else { ((left_lo, left_hi), (right_lo, right_hi)) };
+                let hi_less = self.lower_word_less(a.1.clone(), b.1.clone(), unsigned);
+                let hi_equal = self.new_temp(IrType::I64);
+                self.emit(Inst::Cmp { dst: hi_equal, op: CmpOp::Eq, lhs: a.1, rhs: b.1 });
+                let lo_less = self.lower_word_less(a.0, b.0, true);
+                let tie = self.new_temp(IrType::I64);
+                self.emit(Inst::Binary { dst: tie, op: BinaryOp::And, lhs: Operand::Temp(hi_equal), rhs: lo_less });
+                let less = self.new_temp(IrType::I64);
+                self.emit(Inst::Binary { dst: less, op: BinaryOp::Or, lhs: hi_less, rhs: Operand::Temp(tie) });
+                if !negate {
+                    return Ok(Operand::Temp(less));
+                }
+                let dst = self.new_temp(IrType::I64);
+                self.emit(Inst::Binary { dst, op: BinaryOp::Xor, lhs: Operand::Temp(less), rhs: Operand::Int(1) });
+                return Ok(Operand::Temp(dst));
+            }
+            _ => return Err(format!("128 bitlik '{}' işlemi henüz IR'ye indirilemiyor; yalnızca karşılaştırma destekleniyor.", op.symbol())),
+        };
+        let lo = self.new_temp(IrType::I64);
+        self.emit(Inst::Cmp { dst: lo, op: equal, lhs: left_lo, rhs: right_lo });
+        let hi = self.new_temp(IrType::I64);
+        self.emit(Inst::Cmp { dst: hi, op: equal, lhs: left_hi, rhs: right_hi });
+        let dst = self.new_temp(IrType::I64);
+        self.emit(Inst::Binary { dst, op: joiner, lhs: Operand::Temp(lo), rhs: Operand::Temp(hi) });
+        Ok(Operand::Temp(dst))
+    }
+
+    // Kelime karşılaştırması (0/1). IR'de yalnızca işaretli karşılaştırma olduğu için işaretsiz
+    // karşılaştırmada iki tarafın işaret biti çevrilir.
+    fn lower_word_less(&mut self, lhs: Operand, rhs: Operand, unsigned: bool) -> Operand {
+        let (lhs, rhs) = if unsigned {
+            let flip = |this: &mut Self, op: Operand| {
+                let dst = this.new_temp(IrType::I64);
+                this.emit(Inst::Binary { dst, op: BinaryOp::Xor, lhs: op, rhs: Operand::Int(i64::MIN) });
+                Operand::Temp(dst)
+            };
+            (flip(self, lhs), flip(self, rhs))
+        } else {
+            (lhs, rhs)
+        };
+        let dst = self.new_temp(IrType::I64);
+        self.emit(Inst::Cmp { dst, op: CmpOp::Lt, lhs, rhs });
+        Operand::Temp(dst)
+    }
+
+    // Kısa devre && / ||: sağ taraf yalnızca gerekiyorsa değerlendirilir, sonuç 0/1'dir.
+    fn lower_logical(&mut self, left: &Expr, is_and: bool, right: &Expr) -> Result<Operand, String> {
+        let result = self.new_temp(IrType::I64);
+        let rhs_block = self.new_block();
+        let short_block = self.new_block();
+        let end_block = self.new_block();
+
+        let lhs = self.lower_expr(left)?;
+        let (then_block, else_block) = if is_and { (rhs_block, short_block) } else { (short_block, rhs_block) };
+        self.terminate(Terminator::Branch { cond: lhs, then_block, else_block });
+
+        self.switch_to(rhs_block);
+        let rhs = self.lower_expr(right)?;
+        self.emit(Inst::Cmp { dst: result, op: CmpOp::Ne, lhs: rhs, rhs: Operand::Int(0) });
+        self.terminate(Terminator::Jump(end_block));
+
+        self.switch_to(short_block);
+        self.emit(Inst::Copy { dst: result, src: Operand::Int(if is_and { 0 } else { 1 }) });
+        self.terminate(Terminator::Jump(end_block));
+
+        self.switch_to(end_block);
+        Ok(Operand::Temp(result))
+    }
+
+    fn lower_unary(&mut self, op: &UnOp, right: &Expr) -> Result<Operand, String> {
+        match op {
+            UnOp::Not | UnOp::Neg => {
+                // Eksili tamsayı sabiti doğrudan katlanır: `128i8` tek başına aralık dışıdır, `-128i8` değildir.
+                if let (UnOp::Neg, ExprKind::Literal(LiteralValue::Int(_) | LiteralValue::TypedInt(..))) = (op, &right.kind) {
+                    if let Operand::Int(v) = self.lower_expr(right)? {
+                        return Ok(Operand::Int(v.wrapping_neg()));
+                    }
+                }
+                let ty = self.expr_type(right)?;
+                let src = self.lower_expr(right)?;
+                if matches!(op, UnOp::Neg) && ty.is_float() {
+                    let dst = self.new_temp(IrType::F64);
+                    self.emit(Inst::Binary { dst, op: BinaryOp::Sub, lhs: Operand::Float(0.0), rhs: src });
+                    return Ok(Operand::Temp(dst));
+                }
+                let dst = self.new_temp(IrType::I64);
+                let op = if matches!(op, UnOp::Neg) { UnaryOp::Neg } else { UnaryOp::Not };
+                self.emit(Inst::Unary { dst, op, src });
+                Ok(Operand::Temp(dst))
+            }
+            UnOp::PostInc | UnOp::PostDec => {
+                let ExprKind::Variable(name) = &right.kind else {
+                    return Err("Post-increment/decrement sadece değişkenlere uygulanabilir.".to_string());
+                };
+                let Some(Local::Temp(var)) = self.local(name) else {
+                    return Err(format!("Değişken bulunamadı: {name}"));
+                };
+                if self.func.temp_type(var) != IrType::I64 {
+                    return Err("Post-increment/decrement yalnızca tamsayı değişkenlerde IR'ye indirilebiliyor.".to_string());
+                }
+                let old = self.new_temp(IrType::I64);
+                self.emit(Inst::Copy { dst: old, src: Operand::Temp(var) });
+                let op = if matches!(op, UnOp::PostInc) { BinaryOp::Add } else { BinaryOp::Sub };
+                self.emit(Inst::Binary { dst: var, op, lhs: Operand::Temp(var), rhs: Operand::Int(1) });
+                Ok(Operand::Temp(old))
+            }
+            // ~x == x ^ -1
+            UnOp::BitwiseNot => {
+                let src = self.lower_expr(right)?;
+                let dst = self.new_temp(IrType::I64);
+                self.emit(Inst::Binary { dst, op: BinaryOp::Xor, lhs: src, rhs: Operand::Int(-1) });
+                Ok(Operand::Temp(dst))
+            }
+            _ => Err(format!("Desteklenmeyen tekli operatör: '{}'", op.symbol())),
+        }
+    }
+
+    fn lower_call(&mut self, expr: &Expr, callee: &Expr, args: &[(Option<String>, Expr)]) -> Result<Operand, String> {
+        let ExprKind::Variable(fn_name) = &callee.kind else {
+            return Err("Sadece doğrudan fonksiyon isimleri ile çağrı destekleniyor.".to_string());
+        };
+        let expect_args = |n: usize| {
+            if args.len() < n { Err(format!("{} en az {} argüman bekler.", fn_name, n)) } else { Ok(()) }
+        };
+
+        match fn_name.as_str() {
+            "print" | "println" => {
+                expect_args(1)?;
+                self.lower_print(&args[0].1, args.get(1).map(|a| &a.1), fn_name == "println")?;
+                return Ok(Operand::Int(0));
+            }
+            "eprint" => {
+                expect_args(1)?;
+                let style = Expr::synthetic(ExprKind::Literal(LiteralValue::Str("error".to_string())));
+                self.lower_print(&args[0].1, Some(&style), true)?;
+                return Ok(Operand::Int(0));
+            }
+            "_int" | "_float" | "_str" | "arrlen" => {
+                if args.len() != 1 { return Err(format!("{} için 1 argüman bekleniyor.", fn_name)); }
+                return self.lower_conversion(fn_name, &args[0].1);
+            }
+            // Etiketli asm bloğunu çağrı noktasında yeniden çalıştırır
+            "asmcall" => {
+                let body = match args {
+                    [(None, Expr { kind: ExprKind::Variable(tag), .. })] => self.asm_blocks.get(tag).cloned(),
+                    _ => None,
+                };
+                let body = body.ok_or("asmcall tanımlı bir asm etiketi bekler.")?;
+                self.lower_asm(&body, expr.span)?;
+                return Ok(Operand::Int(0));
+            }
+            // Komut satırı: main'in girişte sakladığı argc/argv
+            "arg_count" | "args" => {
+                let symbol = if fn_name == "arg_count" { "_nim_argc" } else { "_nim_argv" };
+                let dst = self.new_temp(IrType::I64);
+                self.emit(Inst::Load { dst, addr: Address { base: Base::Symbol(symbol.to_string()), index: None, offset: 0 } });
+                return Ok(Operand::Temp(dst));
+            }
+            _ => {}
+        }
+
+        // Çağrılacak sembol (bazı builtin'ler runtime rutinlerine eşlenir)
+        let target = match fn_name.as_str() {
+            "strlen" | "exit" | "panic" if args.len() != 1 => return Err(format!("{} için 1 argüman bekleniyor.", fn_name)),
+            "strlen" => "_strlen".to_string(),
+            "exit" => exit_symbol(self.target).to_string(),
+            "panic" => "_print".to_string(),
+            _ => fn_name.clone(),
+        };
+
+        let mut call_args = Vec::new();
+        for (_, arg_expr) in args {
+            let ty = self.checker.type_of_expr(arg_expr).unwrap_or(Type::I64);
+            if self.struct_field_count(&ty).is_some() {
+                // Değişken ya da alan olmayan struct değerleri (literaller) önce geçici bir slota yazılır.
+                let (addr, struct_name) = match &arg_expr.kind {
+                    ExprKind::Variable(_) | ExprKind::MemberAccess { .. } => self.struct_place(arg_expr)?,
+                    _ => {
+                        let words = self.lower_struct_words(arg_expr)?;
+                        let slot = self.new_slot("<struct argümanı>", (words.len().max(1) * 8) as i32);
+                        let addr = Address { base: Base::Slot(slot), index: None, offset: 0 };
+                        self.store_struct_words(addr.clone(), words);
+                        (addr, ty.to_string())
+                    }
+                };
+                call_args.push(CallArg::Struct { addr, name: struct_name });
+            } else {
+                let value = self.lower_expr(arg_expr)?;
+                call_args.push(CallArg::Value(value, ir_type(&ty)));
+            }
+        }
+
+        if fn_name == "panic" {
+            self.emit(Inst::Call { dst: None, callee: target, args: call_args, variadic: true });
+            let exit_args = vec![CallArg::Value(Operand::Int(1), IrType::I64)];
+            self.emit(Inst::Call { dst: None, callee: exit_symbol(self.target).to_string(), args: exit_args, variadic: false });
+            return Ok(Operand::Int(0));
+        }
+
+        let ret_ty = self.expr_type(expr)?;
+        let dst = if matches!(ret_ty, Type::Void | Type::Never) { None } else { Some(self.new_temp(ir_type(&ret_ty))) };
+        self.emit(Inst::Call { dst, callee: target, args: call_args, variadic: false });
+        Ok(dst.map_or(Operand::Int(0), Operand::Temp))
+    }
+
+    // Tip dönüşümü builtin'leri (_int, _float, _str) ve arrlen
+    fn lower_conversion(&mut self, fn_name: &str, arg: &Expr) -> Result<Operand, String> {
+        let arg_ty = self.checker.type_of_expr(arg).map_err(|e| format!("{fn_name} hatası: {e}"))?;
+        if fn_name == "arrlen" {
+            return match arg_ty {
+                Type::Array(_, Some(len)) => Ok(Operand::Int(len as i64)),
+                Type::Array(_, None) => Err("Dinamik boyutu bilinmeyen diziler için arrlen henüz desteklenmiyor.".to_string()),
+                _ => Err("arrlen sadece diziler için kullanılabilir.".to_string()),
+            };
+        }
+
+        let value = self.lower_expr(arg)?;
+        let call = |lowerer: &mut Self, callee: &str, arg_ty: IrType| {
+            let dst = lowerer.new_temp(IrType::I64);
+            let args = vec![CallArg::Value(value.clone(), arg_ty)];
+            lowerer.emit(Inst::Call { dst: Some(dst), callee: callee.to_string(), args, variadic: false });
+            Ok(Operand::Temp(dst))
+        };
+        match fn_name {
+            "_int" => {
+                if arg_ty.is_float() {
+                    let dst = self.new_temp(IrType::I64);
+                    self.emit(Inst::Convert { dst, op: ConvertOp::FloatToInt, src: value });
+                    Ok(Operand::Temp(dst))
+                } else if arg_ty == Type::Str(None) {
+                    call(self, "_atoi", IrType::I64)
+                } else if is_int_like(&arg_ty) {
+                    Ok(value)
+                } else {
+                    Err(format!("_int: {} tipi desteklenmiyor.", arg_ty))
+                }
+            }
+            "_float" => {
+                if is_int_like(&arg_ty) {
+                    Ok(self.coerce(value, &arg_ty, IrType::F64))
+                } else if arg_ty.is_float() {
+                    Ok(value)
+                } else {
+                    Err(format!("_float: {} tipi desteklenmiyor.", arg_ty))
+                }
+            }
+            _ if arg_ty.is_float() => call(self, "_ftoa", IrType::F64),
+            _ => call(self, "_itoa", IrType::I64),
+        }
+    }
+
+    // print/println/eprint/echo: biçim dizgisi derleme zamanında kurulur ve variadic _print çağrılır.
+    fn lower_print(&mut self, expr: &Expr, style_expr: Option<&Expr>, newline: bool) -> Result<(), String> {
+        let expr_type = self.checker.type_of_expr(expr).map_err(|e| format!("Print hatası: {e}"))?;
+        let (prefix, suffix) = match style_expr.map(|e| &e.kind) {
+            Some(ExprKind::Literal(LiteralValue::Str(style))) => print_style(&self.checker.styles, style),
+            _ => (String::new(), String::new()),
+        };
+        let line_end = if newline { line_end(self.target) } else { "" };
+
+        let mut format = prefix;
+        let mut args = vec![];
+        match &expr.kind {
+            ExprKind::InterpolatedString(parts) => {
+                for part in parts {
+                    let precision = part.numeric_member().filter(|(object, _)| self.checker.type_of_expr(object).is_ok_and(|t| t.is_float()));
+                    match (&part.kind, precision) {
+                        (ExprKind::Literal(LiteralValue::Str(s)), _) => format.push_str(s),
+                        // `{x.2}`: float değer %.2f ile yazılır.
+                        (_, Some((object, digits))) => {
+                            let object_type = self.checker.type_of_expr(object).unwrap_or(Type::F64);
+                            format.push_str(&format!("%.{}f", digits));
+                            let value = self.lower_expr(object)?;
+                            args.push(CallArg::Value(value, ir_type(&object_type)));
+                        }
+                        _ => {
+                            let p_ty = self.checker.type_of_expr(part).unwrap_or(Type::Str(None));
+                            if is_wide(&p_ty) {
+                                self.lower_wide_print(part, &p_ty, &mut format, &mut args)?;
+                                continue;
+                            }
+                            format.push_str(format_specifier(&p_ty));
+                            let value = self.lower_expr(part)?;
+                            args.push(CallArg::Value(value, ir_type(&p_ty)));
+                        }
+                    }
+                }
+            }
+            _ if is_wide(&expr_type) => self.lower_wide_print(expr, &expr_type, &mut format, &mut args)?,
+            _ => {
+                let value = self.lower_expr(expr)?;
+                if expr_type.is_float() {
+                    // Float değer stringe çevrilip %s ile yazılır
+                    let text = self.new_temp(IrType::I64);
+                    let ftoa_args = vec![CallArg::Value(value, IrType::F64)];
+                    self.emit(Inst::Call { dst: Some(text), callee: "_ftoa".to_string(), args: ftoa_args, variadic: false });
+                    format.push_str("%s");
+                    args.push(CallArg::Value(Operand::Temp(text), IrType::I64));
+                } else {
+                    format.push_str(format_specifier(&expr_type));
+                    args.push(CallArg::Value(value, IrType::I64));
+                }
+            }
+        }
+        format.push_str(&suffix);
+        format.push_str(line_end);
+        args.insert(0, CallArg::Value(Operand::Str(format), IrType::I64));
+        self.emit(Inst::Call { dst: None, callee: "_print".to_string(), args, variadic: true });
+        Ok(())
+    }
+
+    // 128 bitlik değer runtime'a %D (işaretli) / %U (işaretsiz) ile iki kelime olarak verilir.
+    fn lower_wide_print(&mut self, expr: &Expr, ty: &Type, format: &mut String, args: &mut Vec<CallArg>) -> Result<(), String> {
+        let (lo, hi) = self.lower_wide(expr)?;
+        format.push_str(if *ty == Type::U128 { "%U" } else { "%D" });
+        args.push(CallArg::Value(lo, IrType::I64));
+        args.push(CallArg::Value(hi, IrType::I64));
+        Ok(())
+    }
+}
+
+// Hata mesajları için ifade türünün adı; interpolasyon yalnızca print çağrılarında indirilir.
+fn expr_kind_name(kind: &ExprKind) -> &'static str {
+    match kind {
+        ExprKind::InterpolatedString(_) => "string interpolasyonu (print dışında)",
+        _ => kind.name(),
+    }
+}
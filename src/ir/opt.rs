@@ -0,0 +1,735 @@
+// src/ir/opt.rs
+
+// IR optimizasyon geçişleri. Release modunda (veya -O1/-O2 ile) kod üretiminden önce çalışır.
+//
+// -O1: sabit katlama, sabit ve kopya yayma, sabit koşullu dallanmaların sadeleştirilmesi, boş
+//      blokların atlanıp tek öncüllü blokların birleştirilmesi ve ölü kod eleme.
+// -O2: -O1'e ek olarak blok içi ortak alt ifade eleme (CSE), adresi alınmayan slotlarda (struct
+//      değişkenleri) blok içi yükleme iletimi ve hiç okunmayan slotlara yazmaların silinmesi, ve
+//      `inline` işaretli fonksiyonların çağrı noktalarına açılması.
+//
+// Geçiciler SSA olmadığından global yayma yalnızca bir kez yazılan geçicilere uygulanır. Her yerel
+// bildirildiği yerde bir ilk değer aldığından bu tek yazma, geçicinin tüm okumalarından önce gelir.
+
+use std::collections::{HashMap, HashSet};
+
+use super::liveness;
+use super::{
+    Address, AsmOperand, Base, BinaryOp, Block, BlockId, CallArg, CmpOp, ConvertOp, Function, Inst, IrType, Module, Operand, Param,
+    SlotId, Temp, Terminator, UnaryOp,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptLevel {
+    O0,
+    O1,
+    O2,
+}
+
+// Geçişlerin sabit noktaya ulaşmadan en fazla kaç tur tekrarlanacağı
+const MAX_ROUNDS: usize = 16;
+
+pub fn optimize(module: &mut Module, level: OptLevel) {
+    if level == OptLevel::O0 {
+        return;
+    }
+    // Satır işaretleri boş blokların atlanmasını engeller; optimize edilen kod kaynak satırlarını izlemez.
+    for func in module.functions.iter_mut().flatten() {
+        for block in &mut func.blocks {
+            block.insts.retain(|inst| !matches!(inst, Inst::SourceLine(_)));
+        }
+        simplify(func, level);
+    }
+    if level < OptLevel::O2 {
+        return;
+    }
+    // Çağrılanların sadeleştirilmiş gövdeleri, çağıranlara birer kopya olarak açılır.
+    let callees: HashMap<String, Function> =
+        module.functions.iter().flatten().filter(|f| can_inline(f)).map(|f| (f.name.clone(), f.clone())).collect();
+    if callees.is_empty() {
+        return;
+    }
+    for func in module.functions.iter_mut().flatten() {
+        if inline_calls(func, &callees) {
+            simplify(func, level);
+        }
+    }
+}
+
+fn simplify(func: &mut Function, level: OptLevel) {
+    for _ in 0..MAX_ROUNDS {
+        let mut changed = fold_constants(func);
+        changed |= propagate(func);
+        if level >= OptLevel::O2 {
+            changed |= eliminate_common_subexpressions(func);
+            changed |= forward_slot_values(func);
+            changed |= eliminate_dead_slot_stores(func);
+        }
+        changed |= simplify_branches(func);
+        changed |= eliminate_dead_code(func);
+        if !changed {
+            break;
+        }
+    }
+}
+
+// --- Komut işlenenlerine erişim ---
+
+// Komutun okuduğu değer işlenenleri (adres tabanları ve asm operandları hariç)
+fn operands_mut(inst: &mut Inst) -> Vec<&mut Operand> {
+    match inst {
+        Inst::Copy { src, .. } | Inst::Unary { src, .. } | Inst::Convert { src, .. } => vec![src],
+        Inst::Binary { lhs, rhs, .. } | Inst::Cmp { lhs, rhs, .. } => vec![lhs, rhs],
+        Inst::Load { addr, .. } | Inst::AddrOf { addr, .. } => addr.index.iter_mut().collect(),
+        Inst::Store { addr, src } => addr.index.iter_mut().chain(std::iter::once(src)).collect(),
+        Inst::Call { args, .. } => args
+            .iter_mut()
+            .filter_map(|arg| match arg {
+                CallArg::Value(op, _) => Some(op),
+                CallArg::Struct { addr, .. } => addr.index.as_mut(),
+            })
+            .collect(),
+        Inst::Zero { .. } | Inst::Asm { .. } | Inst::SourceLine(_) => Vec::new(),
+    }
+}
+
+fn base_temp_mut(inst: &mut Inst) -> Option<&mut Temp> {
+    let addr = match inst {
+        Inst::Load { addr, .. } | Inst::AddrOf { addr, .. } | Inst::Store { addr, .. } => addr,
+        _ => return None,
+    };
+    match &mut addr.base {
+        Base::Temp(t) => Some(t),
+        _ => None,
+    }
+}
+
+fn terminator_operand_mut(term: &mut Terminator) -> Option<&mut Operand> {
+    match term {
+        Terminator::Branch { cond, .. } => Some(cond),
+        Terminator::Return(value) => value.as_mut(),
+        Terminator::Jump(_) | Terminator::Unreachable => None,
+    }
+}
+
+// Yan etkisi olmayan, sonucu kullanılmıyorsa silinebilecek komut mu?
+fn is_pure(inst: &Inst) -> bool {
+    match inst {
+        // Sıfıra bölme çalışma zamanında hata verir; bölen sıfır olmayan bir sabit değilse korunur.
+        Inst::Binary { op: BinaryOp::Div | BinaryOp::Rem, rhs, .. } => matches!(rhs, Operand::Int(v) if *v != 0 && *v != -1),
+        Inst::Copy { .. } | Inst::Binary { .. } | Inst::Cmp { .. } | Inst::Unary { .. } | Inst::Convert { .. } => true,
+        Inst::Load { .. } | Inst::AddrOf { .. } => true,
+        Inst::Store { .. } | Inst::Zero { .. } | Inst::Call { .. } | Inst::Asm { .. } | Inst::SourceLine(_) => false,
+    }
+}
+
+fn is_constant(op: &Operand) -> bool {
+    !matches!(op, Operand::Temp(_))
+}
+
+// --- Sabit katlama ---
+
+fn fold_constants(func: &mut Function) -> bool {
+    let mut changed = false;
+    for block in &mut func.blocks {
+        for inst in &mut block.insts {
+            if let Some(folded) = fold(inst, &func.temps) {
+                *inst = folded;
+                changed = true;
+            }
+        }
+    }
+    changed
+}
+
+fn fold(inst: &Inst, types: &[IrType]) -> Option<Inst> {
+    let copy = |dst: &Temp, src: Operand| Some(Inst::Copy { dst: *dst, src });
+    match inst {
+        Inst::Binary { dst, op, lhs: Operand::Float(a), rhs: Operand::Float(b) } if types[dst.0 as usize] == IrType::F64 => {
+            let value = match op {
+                BinaryOp::Add => a + b,
+                BinaryOp::Sub => a - b,
+                BinaryOp::Mul => a * b,
+                BinaryOp::Div => a / b,
+                _ => return None,
+            };
+            copy(dst, Operand::Float(value))
+        }
+        Inst::Binary { dst, .. } if types[dst.0 as usize] == IrType::F64 => None,
+        Inst::Binary { dst, op, lhs, rhs } => match (op, lhs, rhs) {
+            (_, Operand::Int(a), Operand::Int(b)) => {
+                let value = match op {
+                    BinaryOp::Add => a.wrapping_add(*b),
+                    BinaryOp::Sub => a.wrapping_sub(*b),
+                    BinaryOp::Mul => a.wrapping_mul(*b),
+                    // Sıfıra bölme ve taşan bölme çalışma zamanına bırakılır.
+                    BinaryOp::Div => a.checked_div(*b)?,
+                    BinaryOp::Rem => a.checked_rem(*b)?,
+                    BinaryOp::And => a & b,
+                    BinaryOp::Or => a | b,
+                    BinaryOp::Xor => a ^ b,
+                    BinaryOp::Shl => a.wrapping_shl(*b as u32),
+                    BinaryOp::Shr => a.wrapping_shr(*b as u32),
+                };
+                copy(dst, Operand::Int(value))
+            }
+            (BinaryOp::Add | BinaryOp::Sub | BinaryOp::Or | BinaryOp::Xor | BinaryOp::Shl | BinaryOp::Shr, x @ Operand::Temp(_), Operand::Int(0)) => {
+                copy(dst, x.clone())
+            }
+            (BinaryOp::Add | BinaryOp::Or | BinaryOp::Xor, Operand::Int(0), x @ Operand::Temp(_)) => copy(dst, x.clone()),
+            (BinaryOp::And, _, Operand::Int(0)) | (BinaryOp::And, Operand::Int(0), _) => copy(dst, Operand::Int(0)),
+            (BinaryOp::Mul | BinaryOp::Div, x @ Operand::Temp(_), Operand::Int(1)) => copy(dst, x.clone()),
+            (BinaryOp::Mul, Operand::Int(1), x @ Operand::Temp(_)) => copy(dst, x.clone()),
+            _ => None,
+        },
+        Inst::Cmp { dst, op, lhs: Operand::Int(a), rhs: Operand::Int(b) } => {
+            let value = match op {
+                CmpOp::Eq => a == b,
+                CmpOp::Ne => a != b,
+                CmpOp::Lt => a < b,
+                CmpOp::Le => a <= b,
+                CmpOp::Gt => a > b,
+                CmpOp::Ge => a >= b,
+            };
+            copy(dst, Operand::Int(value as i64))
+        }
+        Inst::Unary { dst, op, src: Operand::Int(v) } => match op {
+            UnaryOp::Neg => copy(dst, Operand::Int(v.wrapping_neg())),
+            UnaryOp::Not => copy(dst, Operand::Int((*v == 0) as i64)),
+        },
+        Inst::Convert { dst, op: ConvertOp::IntToFloat, src: Operand::Int(v) } => copy(dst, Operand::Float(*v as f64)),
+        // Aralık dışı değerlerde cvttsd2si'nin sonucu Rust'ın dönüşümünden farklıdır; katlanmaz.
+        Inst::Convert { dst, op: ConvertOp::FloatToInt, src: Operand::Float(v) } if v.abs() < 9.0e18 => {
+            copy(dst, Operand::Int(v.trunc() as i64))
+        }
+        _ => None,
+    }
+}
+
+// --- Sabit ve kopya yayma ---
+
+// Her geçicinin kaç yerde yazıldığı (parametreler ve main'in argc/argv'si girişte yazılır)
+fn def_counts(func: &Function) -> Vec<usize> {
+    let mut defs = vec![0; func.temps.len()];
+    for param in &func.params {
+        if let Param::Scalar(t) = param {
+            defs[t.0 as usize] += 1;
+        }
+    }
+    if let Some((argc, argv)) = func.entry_args {
+        defs[argc.0 as usize] += 1;
+        defs[argv.0 as usize] += 1;
+    }
+    for inst in func.blocks.iter().flat_map(|b| &b.insts) {
+        for temp in inst.defs() {
+            defs[temp.0 as usize] += 1;
+        }
+    }
+    defs
+}
+
+fn propagate(func: &mut Function) -> bool {
+    let defs = def_counts(func);
+    // Bir kez yazılan ve değeri bir sabit ya da yine bir kez yazılan başka bir geçici olanlar
+    let mut values: HashMap<Temp, Operand> = HashMap::new();
+    for inst in func.blocks.iter().flat_map(|b| &b.insts) {
+        if let Inst::Copy { dst, src } = inst {
+            let single = |t: &Temp| defs[t.0 as usize] == 1;
+            match src {
+                Operand::Temp(s) if s != dst && single(s) && single(dst) => {
+                    values.insert(*dst, src.clone());
+                }
+                Operand::Temp(_) => {}
+                _ if single(dst) => {
+                    values.insert(*dst, src.clone());
+                }
+                _ => {}
+            }
+        }
+    }
+    let resolve = |values: &HashMap<Temp, Operand>, mut op: Operand| {
+        for _ in 0..values.len() {
+            match &op {
+                Operand::Temp(t) if values.contains_key(t) => op = values[t].clone(),
+                _ => break,
+            }
+        }
+        op
+    };
+
+    let mut changed = false;
+    for block in &mut func.blocks {
+        // Blok içinde: çok kez yazılan geçicilerin son yazılan sabit değerleri
+        let mut known: HashMap<Temp, Operand> = HashMap::new();
+        for inst in &mut block.insts {
+            for op in operands_mut(inst) {
+                if let Operand::Temp(t) = op {
+                    let value = known.get(t).cloned().unwrap_or_else(|| resolve(&values, op.clone()));
+                    if value != *op {
+                        *op = value;
+                        changed = true;
+                    }
+                }
+            }
+            if let Some(base) = base_temp_mut(inst) {
+                if let Operand::Temp(t) = resolve(&values, Operand::Temp(*base)) {
+                    if t != *base {
+                        *base = t;
+                        changed = true;
+                    }
+                }
+            }
+            for temp in inst.defs() {
+                known.remove(&temp);
+            }
+            if let Inst::Copy { dst, src } = inst {
+                if is_constant(src) {
+                    known.insert(*dst, src.clone());
+                }
+            }
+        }
+        if let Some(op) = terminator_operand_mut(&mut block.term) {
+            if let Operand::Temp(t) = op {
+                let value = known.get(t).cloned().unwrap_or_else(|| resolve(&values, op.clone()));
+                if value != *op {
+                    *op = value;
+                    changed = true;
+                }
+            }
+        }
+    }
+    changed
+}
+
+// --- Ortak alt ifade eleme (blok içi) ---
+
+fn eliminate_common_subexpressions(func: &mut Function) -> bool {
+    let mut changed = false;
+    let types = func.temps.clone();
+    for block in &mut func.blocks {
+        // (ifade anahtarı, okuduğu geçiciler, sonucu tutan geçici)
+        let mut available: Vec<(String, Vec<Temp>, Temp)> = Vec::new();
+        for inst in &mut block.insts {
+            let key = match inst {
+                Inst::Binary { dst, op, lhs, rhs } => Some(format!("{} {} {} {}", types[dst.0 as usize], op, lhs, rhs)),
+                Inst::Cmp { op, lhs, rhs, .. } => Some(format!("cmp {} {} {}", op, lhs, rhs)),
+                Inst::Unary { op, src, .. } => Some(format!("{:?} {}", op, src)),
+                Inst::Convert { op, src, .. } => Some(format!("{:?} {}", op, src)),
+                Inst::AddrOf { addr, .. } => Some(format!("addr {}", addr)),
+                _ => None,
+            };
+            let uses = inst.uses();
+            if let (Some(key), [dst]) = (&key, inst.defs().as_slice()) {
+                if let Some((_, _, prev)) = available.iter().find(|(k, _, _)| k == key) {
+                    *inst = Inst::Copy { dst: *dst, src: Operand::Temp(*prev) };
+                    changed = true;
+                }
+            }
+            for temp in inst.defs() {
+                available.retain(|(_, reads, value)| *value != temp && !reads.contains(&temp));
+            }
+            if let (Some(key), [dst]) = (key, inst.defs().as_slice()) {
+                if !matches!(inst, Inst::Copy { .. }) && !uses.contains(dst) {
+                    available.push((key, uses, *dst));
+                }
+            }
+        }
+    }
+    changed
+}
+
+// --- Slot değerleri (blok içi) ---
+
+// Adresi alınan, indeksle erişilen veya asm bloklarının başvurduğu slotlar; bunlara işaretçiler ya da
+// hesaplanan adresler üzerinden de erişilebilir. Struct argümanları çağrıda kopyalandığından slotu
+// kaçırmaz.
+fn escaping_slots(func: &Function) -> HashSet<SlotId> {
+    let mut escaping = HashSet::new();
+    for inst in func.blocks.iter().flat_map(|b| &b.insts) {
+        match inst {
+            Inst::AddrOf { addr, .. } => {
+                if let Base::Slot(slot) = &addr.base {
+                    escaping.insert(*slot);
+                }
+            }
+            Inst::Load { addr, .. } | Inst::Store { addr, .. } if addr.index.is_some() => {
+                if let Base::Slot(slot) = &addr.base {
+                    escaping.insert(*slot);
+                }
+            }
+            Inst::Asm { operands, .. } => {
+                escaping.extend(operands.iter().filter_map(|(_, op)| match op {
+                    AsmOperand::Slot(slot) => Some(*slot),
+                    AsmOperand::Temp(_) => None,
+                }));
+            }
+            _ => {}
+        }
+    }
+    escaping
+}
+
+fn operand_type(op: &Operand, types: &[IrType]) -> IrType {
+    match op {
+        Operand::Temp(t) => types[t.0 as usize],
+        Operand::Float(_) => IrType::F64,
+        Operand::Int(_) | Operand::Str(_) => IrType::I64,
+    }
+}
+
+// Kaçmayan bir slotun sabit ofsetine yazılan (ya da oradan okunan) değer, aynı blokta tekrar
+// okunduğunda bellekten yüklenmek yerine doğrudan kullanılır.
+fn forward_slot_values(func: &mut Function) -> bool {
+    let escaping = escaping_slots(func);
+    let types = func.temps.clone();
+    let mut changed = false;
+    for block in &mut func.blocks {
+        let mut known: HashMap<(SlotId, i32), Operand> = HashMap::new();
+        for inst in &mut block.insts {
+            // Yeniden yazılan geçicilerin eski değerleri artık slotları temsil etmez.
+            for temp in inst.defs() {
+                known.retain(|_, value| *value != Operand::Temp(temp));
+            }
+            match inst {
+                Inst::Store { addr, src } => {
+                    if let (&Base::Slot(slot), None) = (&addr.base, &addr.index) {
+                        if !escaping.contains(&slot) {
+                            known.insert((slot, addr.offset), src.clone());
+                        }
+                    }
+                }
+                Inst::Zero { slot } => {
+                    let slot = *slot;
+                    known.retain(|(s, _), _| *s != slot);
+                }
+                Inst::Load { dst, addr } => {
+                    if let (&Base::Slot(slot), None) = (&addr.base, &addr.index) {
+                        let key = (slot, addr.offset);
+                        match known.get(&key) {
+                            Some(value) if operand_type(value, &types) == types[dst.0 as usize] => {
+                                *inst = Inst::Copy { dst: *dst, src: value.clone() };
+                                changed = true;
+                            }
+                            Some(_) => {}
+                            None if !escaping.contains(&slot) => {
+                                known.insert(key, Operand::Temp(*dst));
+                            }
+                            None => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    changed
+}
+
+// Kaçmayan slotlara yapılan yazmalardan ölü olanlar silinir: slot hiç okunmuyorsa (yüklenmiyor,
+// argüman olarak geçirilmiyorsa) hepsi, okunuyorsa aynı blokta okunmadan üzerine yazılanlar.
+fn eliminate_dead_slot_stores(func: &mut Function) -> bool {
+    let escaping = escaping_slots(func);
+    let mut read: HashSet<SlotId> = escaping.clone();
+    for inst in func.blocks.iter().flat_map(|b| &b.insts) {
+        match inst {
+            Inst::Load { addr, .. } => {
+                if let Base::Slot(slot) = &addr.base {
+                    read.insert(*slot);
+                }
+            }
+            Inst::Call { args, .. } => {
+                read.extend(args.iter().filter_map(|arg| match arg {
+                    CallArg::Struct { addr: Address { base: Base::Slot(slot), .. }, .. } => Some(*slot),
+                    _ => None,
+                }));
+            }
+            _ => {}
+        }
+    }
+    let mut changed = false;
+    for block in &mut func.blocks {
+        let before = block.insts.len();
+        block.insts.retain(|inst| match inst {
+            Inst::Store { addr: Address { base: Base::Slot(slot), .. }, .. } | Inst::Zero { slot } => read.contains(slot),
+            _ => true,
+        });
+        // Sondan başa: bir yazmadan sonra okunmadan tekrar yazılan adresler
+        let mut overwritten: HashSet<(SlotId, i32)> = HashSet::new();
+        let mut kept = Vec::with_capacity(block.insts.len());
+        for inst in block.insts.drain(..).rev() {
+            match &inst {
+                Inst::Store { addr: Address { base: Base::Slot(slot), index: None, offset }, .. }
+                    if !escaping.contains(slot) && !overwritten.insert((*slot, *offset)) =>
+                {
+                    continue;
+                }
+                Inst::Load { addr: Address { base: Base::Slot(slot), offset, .. }, .. } => {
+                    overwritten.remove(&(*slot, *offset));
+                }
+                Inst::Call { args, .. } => {
+                    for arg in args {
+                        if let CallArg::Struct { addr: Address { base: Base::Slot(slot), .. }, .. } = arg {
+                            overwritten.retain(|(s, _)| s != slot);
+                        }
+                    }
+                }
+                _ => {}
+            }
+            kept.push(inst);
+        }
+        kept.reverse();
+        block.insts = kept;
+        changed |= block.insts.len() != before;
+    }
+    changed
+}
+
+// --- Dallanma sadeleştirme ---
+
+fn simplify_branches(func: &mut Function) -> bool {
+    let mut changed = false;
+    for block in &mut func.blocks {
+        match &block.term {
+            Terminator::Branch { cond: Operand::Int(v), then_block, else_block } => {
+                block.term = Terminator::Jump(if *v != 0 { *then_block } else { *else_block });
+                changed = true;
+            }
+            Terminator::Branch { then_block, else_block, .. } if then_block == else_block => {
+                block.term = Terminator::Jump(*then_block);
+                changed = true;
+            }
+            _ => {}
+        }
+    }
+
+    // Yalnızca `jump X` içeren boş bloklara giden kenarlar doğrudan X'e bağlanır.
+    let forward: Vec<Option<BlockId>> = func
+        .blocks
+        .iter()
+        .enumerate()
+        .map(|(i, block)| match block.term {
+            Terminator::Jump(target) if block.insts.is_empty() && target.0 as usize != i => Some(target),
+            _ => None,
+        })
+        .collect();
+    let thread = |mut target: BlockId| {
+        for _ in 0..forward.len() {
+            match forward[target.0 as usize] {
+                Some(next) => target = next,
+                None => break,
+            }
+        }
+        target
+    };
+    for block in &mut func.blocks {
+        let targets: Vec<&mut BlockId> = match &mut block.term {
+            Terminator::Jump(target) => vec![target],
+            Terminator::Branch { then_block, else_block, .. } => vec![then_block, else_block],
+            Terminator::Return(_) | Terminator::Unreachable => Vec::new(),
+        };
+        for target in targets {
+            let threaded = thread(*target);
+            if threaded != *target {
+                *target = threaded;
+                changed = true;
+            }
+        }
+    }
+
+    // Tek öncülü olan ve koşulsuz atlamayla ulaşılan blok, öncülünün sonuna eklenir.
+    let mut preds = vec![0usize; func.blocks.len()];
+    for block in &func.blocks {
+        for succ in block.term.successors() {
+            preds[succ.0 as usize] += 1;
+        }
+    }
+    for b in 0..func.blocks.len() {
+        while let Terminator::Jump(target) = func.blocks[b].term {
+            let t = target.0 as usize;
+            if t == b || t == 0 || preds[t] != 1 {
+                break;
+            }
+            let merged = std::mem::replace(&mut func.blocks[t], Block { insts: Vec::new(), term: Terminator::Unreachable });
+            preds[t] = 0;
+            func.blocks[b].insts.extend(merged.insts);
+            func.blocks[b].term = merged.term;
+            changed = true;
+        }
+    }
+
+    if changed {
+        func.remove_unreachable_blocks();
+    }
+    changed
+}
+
+// --- Ölü kod eleme ---
+
+fn eliminate_dead_code(func: &mut Function) -> bool {
+    let liveness = liveness::analyze(func);
+    let mut changed = false;
+    for (b, block) in func.blocks.iter_mut().enumerate() {
+        let mut live: HashSet<Temp> = liveness.live_out[b].clone();
+        live.extend(block.term.uses());
+        let mut kept = Vec::with_capacity(block.insts.len());
+        for inst in block.insts.drain(..).rev() {
+            let defs = inst.defs();
+            let self_copy = matches!(&inst, Inst::Copy { dst, src: Operand::Temp(src) } if dst == src);
+            if self_copy || (is_pure(&inst) && defs.iter().all(|d| !live.contains(d))) {
+                changed = true;
+                continue;
+            }
+            for temp in &defs {
+                live.remove(temp);
+            }
+            live.extend(inst.uses());
+            kept.push(inst);
+        }
+        kept.reverse();
+        block.insts = kept;
+    }
+    changed
+}
+
+// --- Satır içine alma (inlining) ---
+
+fn can_inline(func: &Function) -> bool {
+    let calls_itself = func.blocks.iter().flat_map(|b| &b.insts).any(|inst| matches!(inst, Inst::Call { callee, .. } if *callee == func.name));
+    let has_asm = func.blocks.iter().flat_map(|b| &b.insts).any(|inst| matches!(inst, Inst::Asm { .. }));
+    func.inline
+        && func.entry_args.is_none()
+        && func.params.iter().all(|p| matches!(p, Param::Scalar(_)))
+        && !calls_itself
+        && !has_asm
+}
+
+// Çağrıları açar. Yalnızca fonksiyonun kendi blokları taranır; açılan gövdelerdeki çağrılar olduğu
+// gibi kalır (karşılıklı özyinelemeli inline fonksiyonlar sonsuza kadar açılmasın diye).
+fn inline_calls(func: &mut Function, callees: &HashMap<String, Function>) -> bool {
+    let mut worklist: Vec<usize> = (0..func.blocks.len()).collect();
+    let mut changed = false;
+    while let Some(b) = worklist.pop() {
+        let site = func.blocks[b].insts.iter().position(|inst| match inst {
+            Inst::Call { callee, args, .. } => {
+                *callee != func.name && callees.get(callee).is_some_and(|c| c.params.len() == args.len())
+            }
+            _ => false,
+        });
+        let Some(index) = site else { continue };
+        let Inst::Call { callee, .. } = &func.blocks[b].insts[index] else { continue };
+        let callee = &callees[callee];
+        // Çağrıdan sonraki komutlar devam bloğuna taşınır ve orada taramaya devam edilir.
+        worklist.push(inline_call(func, b, index, callee));
+        changed = true;
+    }
+    changed
+}
+
+// `b` bloğunun `index`. komutundaki çağrıyı `callee`'nin gövdesiyle değiştirir. Çağrıdan sonraki
+// komutlar yeni bir devam bloğuna taşınır; devam bloğunun indeksi döner.
+fn inline_call(func: &mut Function, b: usize, index: usize, callee: &Function) -> usize {
+    let tail = func.blocks[b].insts.split_off(index + 1);
+    let Some(Inst::Call { dst, args, .. }) = func.blocks[b].insts.pop() else {
+        unreachable!("inline_call bir çağrı komutu bekler")
+    };
+
+    let temp_base = func.temps.len() as u32;
+    let slot_base = func.slots.len() as u32;
+    let block_base = func.blocks.len() as u32;
+    func.temps.extend(&callee.temps);
+    func.temp_vars.extend(callee.temp_vars.iter().cloned());
+    func.slots.extend(callee.slots.iter().cloned());
+    let cont = BlockId(block_base + callee.blocks.len() as u32);
+    let temp = |t: Temp| Temp(t.0 + temp_base);
+    let slot = |s: SlotId| SlotId(s.0 + slot_base);
+    let block_id = |id: BlockId| BlockId(id.0 + block_base);
+
+    // Argümanlar parametre geçicilerine kopyalanır.
+    for (param, arg) in callee.params.iter().zip(args) {
+        if let (Param::Scalar(t), CallArg::Value(op, _)) = (param, arg) {
+            func.blocks[b].insts.push(Inst::Copy { dst: temp(*t), src: op });
+        }
+    }
+    let term = std::mem::replace(&mut func.blocks[b].term, Terminator::Jump(BlockId(block_base)));
+
+    for block in &callee.blocks {
+        let mut insts: Vec<Inst> = block
+            .insts
+            .iter()
+            .map(|inst| {
+                let mut inst = inst.clone();
+                remap(&mut inst, &temp, &slot);
+                inst
+            })
+            .collect();
+        let term = match &block.term {
+            Terminator::Return(value) => {
+                if let (Some(dst), Some(value)) = (dst, value) {
+                    insts.push(Inst::Copy { dst, src: remap_operand(value, &temp) });
+                }
+                Terminator::Jump(cont)
+            }
+            Terminator::Jump(target) => Terminator::Jump(block_id(*target)),
+            Terminator::Branch { cond, then_block, else_block } => Terminator::Branch {
+                cond: remap_operand(cond, &temp),
+                then_block: block_id(*then_block),
+                else_block: block_id(*else_block),
+            },
+            Terminator::Unreachable => Terminator::Unreachable,
+        };
+        func.blocks.push(Block { insts, term });
+    }
+    func.blocks.push(Block { insts: tail, term });
+    cont.0 as usize
+}
+
+fn remap_operand(op: &Operand, temp: &dyn Fn(Temp) -> Temp) -> Operand {
+    match op {
+        Operand::Temp(t) => Operand::Temp(temp(*t)),
+        other => other.clone(),
+    }
+}
+
+// Açılan gövdenin geçici ve slot numaralarını çağıranınkilere kaydırır.
+fn remap(inst: &mut Inst, temp: &dyn Fn(Temp) -> Temp, slot: &dyn Fn(SlotId) -> SlotId) {
+    for op in operands_mut(inst) {
+        *op = remap_operand(op, temp);
+    }
+    let remap_base = |base: &mut Base| match base {
+        Base::Temp(t) => *t = temp(*t),
+        Base::Slot(s) => *s = slot(*s),
+        Base::Symbol(_) => {}
+    };
+    match inst {
+        Inst::Copy { dst, .. }
+        | Inst::Binary { dst, .. }
+        | Inst::Cmp { dst, .. }
+        | Inst::Unary { dst, .. }
+        | Inst::Convert { dst, .. } => *dst = temp(*dst),
+        Inst::Load { dst, addr } | Inst::AddrOf { dst, addr } => {
+            *dst = temp(*dst);
+            remap_base(&mut addr.base);
+        }
+        Inst::Store { addr, .. } => remap_base(&mut addr.base),
+        Inst::Zero { slot: s } => *s = slot(*s),
+        Inst::Call { dst, args, .. } => {
+            if let Some(dst) = dst {
+                *dst = temp(*dst);
+            }
+            for arg in args {
+                if let CallArg::Struct { addr, .. } = arg {
+                    remap_base(&mut addr.base);
+                }
+            }
+        }
+        Inst::Asm { operands, .. } => {
+            for (_, operand) in operands {
+                match operand {
+                    AsmOperand::Temp(t) => *t = temp(*t),
+                    AsmOperand::Slot(s) => *s = slot(*s),
+                }
+            }
+        }
+        Inst::SourceLine(_) => {}
+    }
+}
@@ -1,20 +1,75 @@
 use crate::token::{Token, TokenType};
+use crate::diagnostic::{Diagnostic, FileId, Span, Stage, E_LEXER};
 
 pub struct Lexer {
     input: Vec<char>,
     pos: usize,
     line: usize,
+    // YENİ: Span bilgisi için sütun ve byte ofseti takibi
+    column: usize,
+    byte_pos: usize,
+    file: FileId,
+    // Mevcut token'ın başlangıcı: (byte ofseti, satır, sütun)
+    token_start: (usize, usize, usize),
+    // Ayarlıysa tüm token'lar bu span'i alır (örn: interpolasyonlu string içindeki ifadeler)
+    fixed_span: Option<Span>,
+    // Ayarlıysa token span'leri bu span'in başlangıcına göre konumlanır (girdinin ilk karakteri `origin`'dedir)
+    origin: Option<Span>,
+    // `asm: TAG {` dizisinin ne kadarının görüldüğü (0..=4); 4 ise sıradaki token ham asm gövdesidir
+    asm_state: u8,
+    // Henüz bir token'a eklenmemiş doküman yorumları (/// ve /** */)
+    pending_doc: Option<String>,
 }
 
 impl Lexer {
-    pub fn new(input: &str) -> Self {
+    pub fn with_file(input: &str, file: FileId) -> Self {
         Self {
             input: input.chars().collect(),
             pos: 0,
             line: 1,
+            column: 1,
+            byte_pos: 0,
+            file,
+            token_start: (0, 1, 1),
+            fixed_span: None,
+            origin: None,
+            asm_state: 0,
+            pending_doc: None,
         }
     }
 
+    // Başka bir token'ın içinden çıkarılan kaynak için: üretilen tüm token'lar `span`'i taşır.
+    pub fn with_fixed_span(input: &str, span: Span) -> Self {
+        let mut lexer = Self::with_file(input, span.file);
+        lexer.fixed_span = Some(span);
+        lexer
+    }
+
+    // Başka bir token'ın içinden çıkarılan kaynak için: span'ler `origin`'e göre kaydırılır, böylece
+    // aynı string içindeki ifadeler ayrı konumlar alır.
+    pub fn with_origin(input: &str, origin: Span) -> Self {
+        let mut lexer = Self::with_file(input, origin.file);
+        lexer.origin = Some(origin);
+        lexer
+    }
+
+    fn make_token(&self, kind: TokenType) -> Token {
+        let span = self.fixed_span.unwrap_or_else(|| {
+            let (start, line, column) = self.token_start;
+            match self.origin {
+                Some(origin) => Span::new(
+                    origin.file,
+                    origin.line + line - 1,
+                    if line == 1 { origin.column + column - 1 } else { column },
+                    origin.start + start,
+                    origin.start + self.byte_pos,
+                ),
+                None => Span::new(self.file, line, column, start, self.byte_pos),
+            }
+        });
+        Token::new(kind, span)
+    }
+
     fn peek(&self) -> char {
         if self.pos >= self.input.len() { '\0' } else { self.input[self.pos] }
     }
@@ -27,7 +82,13 @@ impl Lexer {
         let c = self.peek();
         if c != '\0' {
             self.pos += 1;
-            if c == '\n' { self.line += 1; }
+            self.byte_pos += c.len_utf8();
+            if c == '\n' {
+                self.line += 1;
+                self.column = 1;
+            } else {
+                self.column += 1;
+            }
         }
         c
     }
@@ -39,18 +100,29 @@ impl Lexer {
                 ' ' | '\t' | '\r' | '\n' => { self.advance(); }
                 '/' => {
                     if self.peek_next() == '/' {
-                        // Tek satırlık yorum //
-                        while self.peek() != '\n' && self.peek() != '\0' { self.advance(); }
+                        // Tek satırlık yorum //. `///` (ama `////` değil) doküman yorumudur.
+                        let is_doc = self.input.get(self.pos + 2) == Some(&'/') && self.input.get(self.pos + 3) != Some(&'/');
+                        let mut text = String::new();
+                        while self.peek() != '\n' && self.peek() != '\0' { text.push(self.advance()); }
+                        if is_doc {
+                            let line = text[3..].strip_prefix(' ').unwrap_or(&text[3..]).trim_end();
+                            self.add_doc(line.to_string());
+                        }
                     } else if self.peek_next() == '*' {
-                        // Çok satırlık yorum /* ... */
+                        // Çok satırlık yorum /* ... */. `/** */` (ama `/***` ve `/**/` değil) doküman yorumudur.
+                        let is_doc = self.input.get(self.pos + 2) == Some(&'*') && !matches!(self.input.get(self.pos + 3), Some('*' | '/'));
                         self.advance(); self.advance();
+                        let mut text = String::new();
                         loop {
                             if self.peek() == '\0' { break; }
                             if self.peek() == '*' && self.peek_next() == '/' {
                                 self.advance(); self.advance();
                                 break;
                             }
-                            self.advance();
+                            text.push(self.advance());
+                        }
+                        if is_doc {
+                            self.add_doc(block_doc(text.get(1..).unwrap_or("")));
                         }
                     } else {
                         break;
@@ -83,7 +155,7 @@ impl Lexer {
             "while" => TokenType::While, "for" => TokenType::For, "loop" => TokenType::Loop, "return" => TokenType::Return,
             "break" => TokenType::Break, "continue" => TokenType::Continue,
             "self" => TokenType::Self_, "super" => TokenType::Super,
-            "match" => TokenType::Match, "def" => TokenType::Def,
+            "match" => TokenType::Match, "def" => TokenType::Def, "defer" => TokenType::Defer,
             "struct" => TokenType::Struct, "enum" => TokenType::Enum, "group" => TokenType::Group, "typedef" => TokenType::Typedef,
             "as" => TokenType::As,
             "pub" => TokenType::Pub, "export" => TokenType::Export, "use" => TokenType::Use, "extern" => TokenType::Extern, "inline" => TokenType::Inline,
@@ -106,107 +178,176 @@ impl Lexer {
             
             _ => TokenType::Ident(text),
         };
-        Token::new(kind, self.line)
+        self.make_token(kind)
     }
 
+    // Sayı sabitleri: ondalık, 0x (hex), 0b (ikili) ve 0o (sekizli) tabanlar, `_` basamak ayırıcıları,
+    // üslü gösterim (1.5e-3) ve tip sonekleri (10u8, 3.0f32, 100i128). Geçersiz veya aralık dışı
+    // sabitler sessizce 0 olmaz; Error token'ı olarak lexer hatası raporlanır.
     fn scan_number(&mut self) -> Token {
+        let radix = match (self.peek(), self.peek_next()) {
+            ('0', 'x' | 'X') => 16,
+            ('0', 'b' | 'B') => 2,
+            ('0', 'o' | 'O') => 8,
+            _ => 10,
+        };
         let mut text = String::new();
-        let mut is_float = false;
-        
-        // Hexadecimal kontrolü
-        if self.peek() == '0' && (self.peek_next() == 'x' || self.peek_next() == 'X') {
+        if radix != 10 {
             self.advance(); // '0' atla
-            self.advance(); // 'x' veya 'X' atla
-            while self.peek().is_ascii_hexdigit() { // Hex rakamlarını oku
-                text.push(self.advance());
-            }
-            if !text.is_empty() {
-                let val = i64::from_str_radix(&text, 16).unwrap_or(0);
-                return Token::new(TokenType::HexLit(val), self.line);
+            self.advance(); // önek harfini atla
+            if radix == 16 {
+                while self.peek().is_ascii_hexdigit() || self.peek() == '_' {
+                    text.push(self.advance());
+                }
             } else {
-                // Sadece "0x" varsa veya geçersiz hex karakteri varsa
-                panic!("Geçersiz hexadecimal sayı: 0x{}", text);
+                // İkili/sekizli tabanda geçersiz rakamlar (0b102) da okunur ve aşağıda raporlanır.
+                self.scan_digits(&mut text);
             }
+        } else {
+            self.scan_digits(&mut text);
         }
 
-        while self.peek().is_ascii_digit() {
-            text.push(self.advance());
-        }
-
-        if self.peek() == '.' && self.peek_next().is_ascii_digit() {
+        let mut is_float = false;
+        if radix == 10 && self.peek() == '.' && self.peek_next().is_ascii_digit() {
             is_float = true;
             text.push(self.advance()); // .
-            while self.peek().is_ascii_digit() {
-                text.push(self.advance());
-            }
+            self.scan_digits(&mut text);
         }
-        
-        // Bilimsel gösterim (10e3)
-        if self.peek() == 'e' || self.peek() == 'E' {
+        // Bilimsel gösterim (10e3, 1.5e-3). Ardından rakam gelmeyen 'e' bir sonekin başıdır.
+        let exponent_follows = match self.peek_next() {
+            '+' | '-' => self.input.get(self.pos + 2).is_some_and(|c| c.is_ascii_digit()),
+            c => c.is_ascii_digit(),
+        };
+        if radix == 10 && (self.peek() == 'e' || self.peek() == 'E') && exponent_follows {
             is_float = true;
             text.push(self.advance());
             if self.peek() == '+' || self.peek() == '-' {
                 text.push(self.advance());
             }
-            while self.peek().is_ascii_digit() {
-                text.push(self.advance());
+            self.scan_digits(&mut text);
+        }
+
+        let mut suffix = String::new();
+        while self.peek().is_alphanumeric() || self.peek() == '_' {
+            suffix.push(self.advance());
+        }
+        let kind = number_literal(&text, radix, is_float, &suffix).unwrap_or_else(TokenType::Error);
+        self.make_token(kind)
+    }
+
+    // Art arda gelen doküman yorumları tek metinde birleşir.
+    fn add_doc(&mut self, text: String) {
+        match &mut self.pending_doc {
+            Some(doc) => {
+                doc.push('\n');
+                doc.push_str(&text);
             }
+            None => self.pending_doc = Some(text),
         }
+    }
 
-        if is_float {
-            let val = text.parse::<f64>().unwrap_or(0.0);
-            Token::new(TokenType::FloatLit(val), self.line)
-        } else {
-            let val = text.parse::<i64>().unwrap_or(0);
-            Token::new(TokenType::IntLit(val), self.line)
+    // Ondalık rakamlar ve `_` ayırıcıları.
+    fn scan_digits(&mut self, text: &mut String) {
+        while self.peek().is_ascii_digit() || self.peek() == '_' {
+            text.push(self.advance());
         }
     }
 
+    // Normal ("...") ve üç tırnaklı ("""...""") stringler. Kaçış dizileri ham içerik toplandıktan sonra
+    // çözülür; `{ifade}` yer tutucusu içeren stringler InterpolatedStr olur.
     fn scan_string(&mut self) -> Token {
+        if self.peek_next() == '"' && self.input.get(self.pos + 2) == Some(&'"') {
+            return self.scan_triple_string();
+        }
         self.advance(); // " atla
-        let mut text = String::new();
-        while self.peek() != '"' && self.peek() != '\0' {
+        let mut raw = String::new();
+        while self.peek() != '"' {
+            if self.peek() == '\0' {
+                return self.unterminated_string();
+            }
             if self.peek() == '\\' {
-                self.advance(); // '\' karakterini atla
-                // Sonraki karaktere göre doğru kaçış dizisini ekle
-                match self.advance() {
-                    'n' => text.push('\n'),
-                    'r' => text.push('\r'),
-                    't' => text.push('\t'),
-                    '\\' => text.push('\\'),
-                    '"' => text.push('"'),
-                    '\'' => text.push('\''),
-                    '0' => text.push('\0'),
-                    'x' => {
-                        // Hexadecimal escape sequence: \xHH
-                        let h1 = self.advance();
-                        let h2 = self.advance();
-                        if let Some(d1) = h1.to_digit(16) {
-                            if let Some(d2) = h2.to_digit(16) {
-                                let val = (d1 * 16 + d2) as u8;
-                                text.push(val as char);
-                            } else {
-                                // Hata durumu: xH? -> x, H, ? (Basitçe geri ekle veya hata ver, şimdilik esnek olalım)
-                                text.push('x'); text.push(h1); text.push(h2);
-                            }
-                        } else {
-                             text.push('x'); text.push(h1); text.push(h2);
-                        }
-                    },
-                    c => text.push(c),
-                }
-            } else {
-                text.push(self.advance());
+                raw.push(self.advance()); // Kaçırılmış tırnak string'i bitirmesin
             }
+            raw.push(self.advance());
         }
-        
-        if self.peek() == '"' { self.advance(); }
-        
-        if text.contains('{') && text.contains('}') {
-             Token::new(TokenType::InterpolatedStr(text), self.line)
-        } else {
-             Token::new(TokenType::StrLit(text), self.line)
+        self.advance(); // Kapanış " atla
+        self.string_token(&raw)
+    }
+
+    // """ ... """: çok satırlı string. Açılış satırının geri kalanı boşsa atlanır; kapanış kendi
+    // satırındaysa son satır sonu düşer. Boş olmayan satırların ortak girintisi kaldırılır.
+    fn scan_triple_string(&mut self) -> Token {
+        for _ in 0..3 {
+            self.advance();
+        }
+        let mut raw = String::new();
+        loop {
+            if self.peek() == '\0' {
+                return self.unterminated_string();
+            }
+            if self.peek() == '"' && self.peek_next() == '"' && self.input.get(self.pos + 2) == Some(&'"') {
+                break;
+            }
+            if self.peek() == '\\' {
+                raw.push(self.advance());
+            }
+            raw.push(self.advance());
+        }
+        for _ in 0..3 {
+            self.advance();
+        }
+        self.string_token(&strip_indentation(&raw))
+    }
+
+    // Ham stringler: r"..." ve r#"..."# (istenen sayıda #). Kaçış dizisi ve interpolasyon yoktur;
+    // içerik olduğu gibi alınır ve birden çok satıra yayılabilir.
+    fn scan_raw_string(&mut self) -> Token {
+        self.advance(); // r atla
+        let mut hashes = 0;
+        while self.peek() == '#' {
+            self.advance();
+            hashes += 1;
+        }
+        self.advance(); // " atla
+        let mut text = String::new();
+        loop {
+            if self.peek() == '\0' {
+                return self.unterminated_string();
+            }
+            if self.peek() == '"' && (1..=hashes).all(|i| self.input.get(self.pos + i) == Some(&'#')) {
+                break;
+            }
+            text.push(self.advance());
+        }
+        for _ in 0..=hashes {
+            self.advance();
+        }
+        self.make_token(TokenType::StrLit(text))
+    }
+
+    // r"  veya  r#..#"  dizisi bir ham string başlatıyor mu.
+    fn at_raw_string(&self) -> bool {
+        let mut i = self.pos + 1;
+        while self.input.get(i) == Some(&'#') {
+            i += 1;
         }
+        self.input.get(i) == Some(&'"')
+    }
+
+    fn string_token(&self, raw: &str) -> Token {
+        match unescape(raw) {
+            Ok(text) if has_placeholder(&text) => self.make_token(TokenType::InterpolatedStr(text)),
+            // Yer tutucusu olmayan stringlerde `{{` ve `}}` tek paranteze iner.
+            Ok(text) => self.make_token(TokenType::StrLit(text.replace("{{", "{").replace("}}", "}"))),
+            Err(message) => self.make_token(TokenType::Error(message)),
+        }
+    }
+
+    // Kapatılmamış string: hata, dosyanın sonuna kadar değil açılış tırnağının konumunda raporlanır.
+    fn unterminated_string(&self) -> Token {
+        let (start, line, column) = self.token_start;
+        let span = self.fixed_span.unwrap_or_else(|| Span::new(self.file, line, column, start, start + 1));
+        Token::new(TokenType::Error("Hata: Kapatılmamış string sabiti; kapanış tırnağı bulunamadı.".to_string()), span)
     }
 
     // Char ('a') okuma fonksiyonu
@@ -214,7 +355,7 @@ impl Lexer {
         self.advance(); // ' atla
         let c = self.advance(); // Karakteri al
         if self.peek() == '\'' { self.advance(); } // Kapanış ' atla
-        Token::new(TokenType::CharLit(c), self.line)
+        self.make_token(TokenType::CharLit(c))
     }
 
     fn scan_preprocessor(&mut self) -> Token {
@@ -225,15 +366,53 @@ impl Lexer {
         }
         // Eğer sadece # varsa ve metin yoksa Hash döndürebiliriz ama
         // NIMBLE'da # genelde direktif. Şimdilik Preprocessor döndürüyoruz.
-        Token::new(TokenType::Preprocessor(text), self.line)
+        self.make_token(TokenType::Preprocessor(text))
     }
 
     pub fn next_token(&mut self) -> Token {
+        if self.asm_state == 4 {
+            self.asm_state = 0;
+            return self.scan_asm_body();
+        }
+        let mut token = self.scan_token();
+        token.doc = self.pending_doc.take();
+        self.asm_state = match (self.asm_state, &token.kind) {
+            (_, TokenType::Asm) => 1,
+            (1, TokenType::Colon) => 2,
+            (2, TokenType::Ident(_)) => 3,
+            (3, TokenType::LBrace) => 4,
+            _ => 0,
+        };
+        token
+    }
+
+    // asm gövdesi NIMBLE sözdizimine uymaz; eşleşen '}' karakterine kadar olan her şey ham metin olarak
+    // alınır. Kapanış '}' ayrı bir token olarak kalır.
+    fn scan_asm_body(&mut self) -> Token {
+        self.token_start = (self.byte_pos, self.line, self.column);
+        let mut text = String::new();
+        let mut depth = 0;
+        loop {
+            match self.peek() {
+                '\0' => break,
+                '}' if depth == 0 => break,
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            text.push(self.advance());
+        }
+        self.make_token(TokenType::AsmBody(text))
+    }
+
+    fn scan_token(&mut self) -> Token {
         self.skip_whitespace();
+        self.token_start = (self.byte_pos, self.line, self.column);
         let c = self.peek();
 
-        if c == '\0' { return Token::new(TokenType::Eof, self.line); }
+        if c == '\0' { return self.make_token(TokenType::Eof); }
 
+        if c == 'r' && self.at_raw_string() { return self.scan_raw_string(); }
         if c.is_alphabetic() || c == '_' { return self.scan_identifier(); }
         if c.is_ascii_digit() { return self.scan_number(); }
         if c == '"' { return self.scan_string(); }
@@ -255,13 +434,10 @@ impl Lexer {
             '%' => if self.peek() == '=' { self.advance(); TokenType::PercentEq } else { TokenType::Modulo },
             
             '=' => {
-				//
-				let line_for_token = self.line; 
-				
 				// 1. '=>' (FatArrow) Kontrolü (Yüksek Öncelik)
 				if self.peek() == '>' {
 					self.advance(); // '>' tüketildi
-					return Token::new(TokenType::FatArrow, line_for_token); 
+					return self.make_token(TokenType::FatArrow); 
 				} 
 				
 				// 2. '==' veya '===' Kontrolü
@@ -270,14 +446,14 @@ impl Lexer {
 					
 					if self.peek() == '=' {
 						self.advance(); // Üçüncü '=' tüketildi
-						return Token::new(TokenType::Identical, line_for_token); // ===
+						return self.make_token(TokenType::Identical); // ===
 					} else {
-						return Token::new(TokenType::Eq, line_for_token); // ==
+						return self.make_token(TokenType::Eq); // ==
 					}
 				} 
 				
 				// 3. Hiçbir kombinasyon eşleşmezse, tek '=' (Assign) döndürülür
-				return Token::new(TokenType::Assign, line_for_token); // =
+				return self.make_token(TokenType::Assign); // =
 			},
 
             '!' => if self.peek() == '=' {
@@ -332,6 +508,197 @@ impl Lexer {
             _ => TokenType::Illegal(c.to_string()),
         };
 
-        Token::new(kind, self.line)
+        self.make_token(kind)
+    }
+}
+
+// Taranan bir sayı sabitini (`_` ayırıcılı rakamlar, taban ve sonek) token'a çevirir. Soneksiz
+// tamsayılar ve hex sabitleri u64'e sığmalıdır; değerin bildirilen tipin aralığında olduğunu tip
+// denetleyicisi denetler. Sonekli sabitler 128 bit saklanır ve sonekin aralığında olmalıdır; işaretli
+// sonekte önündeki eksiyle yazılabilsin diye (-128i8) en küçük değerin mutlak değeri de kabul edilir.
+fn number_literal(text: &str, radix: u32, is_float: bool, suffix: &str) -> Result<TokenType, String> {
+    let prefix = match radix { 16 => "0x", 2 => "0b", 8 => "0o", _ => "" };
+    let shown = format!("{}{}{}", prefix, text, suffix);
+    if text.contains("__") {
+        return Err(format!("Hata: '{}' sabitinde art arda '_' ayırıcısı kullanılamaz.", shown));
+    }
+    let before_fraction = radix == 10 && (text.contains("_.") || text.contains("_e") || text.contains("_E"));
+    if text.ends_with('_') || before_fraction {
+        return Err(format!("Hata: '{}' sabitinde '_' ayırıcısı rakamlardan sonra gelemez; iki rakamın arasında olmalıdır.", shown));
+    }
+    let digits = text.replace('_', "");
+    let digits = digits.as_str();
+    if digits.is_empty() {
+        return Err(format!("Hata: '{}' önekinden sonra en az bir rakam bekleniyor.", prefix));
+    }
+    if let Some(bad) = digits.chars().find(|c| (radix == 2 || radix == 8) && !c.is_digit(radix)) {
+        let base = if radix == 2 { "ikili" } else { "sekizli" };
+        return Err(format!("Hata: '{}' sabitinde geçersiz {} rakam: '{}'.", shown, base, bad));
+    }
+    // Sonekin tipi: (kayan noktalı mı, işaretli mi, bit genişliği)
+    let suffix_type = match suffix {
+        "" => None,
+        "i8" => Some((false, true, 8)), "i16" => Some((false, true, 16)), "i32" => Some((false, true, 32)),
+        "i64" => Some((false, true, 64)), "i128" => Some((false, true, 128)),
+        "u8" => Some((false, false, 8)), "u16" => Some((false, false, 16)), "u32" => Some((false, false, 32)),
+        "u64" => Some((false, false, 64)), "u128" => Some((false, false, 128)),
+        "f32" => Some((true, true, 32)), "f64" => Some((true, true, 64)),
+        _ => return Err(format!("Hata: '{}' sabitinde geçersiz tip soneki: '{}'. Geçerli sonekler: i8-i128, u8-u128, f32, f64.", shown, suffix)),
+    };
+
+    if is_float || matches!(suffix_type, Some((true, _, _))) {
+        if radix != 10 {
+            return Err(format!("Hata: '{}': kayan noktalı sabitler yalnızca ondalık yazılabilir.", shown));
+        }
+        if let Some((false, _, _)) = suffix_type {
+            return Err(format!("Hata: '{}': kayan noktalı sabit '{}' tamsayı sonekini alamaz.", shown, suffix));
+        }
+        let mut value: f64 = digits.parse().map_err(|_| format!("Hata: Geçersiz kayan noktalı sabit: '{}'.", shown))?;
+        if let Some((_, _, 32)) = suffix_type {
+            // f32 sabitleri tüm arka uçlarda aynı değeri görsün diye f32 hassasiyetine yuvarlanır.
+            value = value as f32 as f64;
+        }
+        if value.is_infinite() {
+            return Err(format!("Hata: '{}' sabiti {} aralığını aşıyor.", shown, if suffix.is_empty() { "f64" } else { suffix }));
+        }
+        return Ok(match suffix_type {
+            Some(_) => TokenType::TypedFloatLit(value, suffix.to_string()),
+            None => TokenType::FloatLit(value),
+        });
+    }
+
+    let value = u128::from_str_radix(digits, radix).map_err(|_| format!("Hata: '{}' sabiti 128 bitlik aralığı aşıyor.", shown))?;
+    match suffix_type {
+        Some((_, signed, bits)) => {
+            let max = if signed { (1u128 << (bits - 1)) - 1 } else { u128::MAX >> (128 - bits) };
+            if value > max && !(signed && value == max + 1) {
+                return Err(format!("Hata: '{}' sabiti {} aralığını aşıyor (en büyük değer: {}).", shown, suffix, max));
+            }
+            Ok(TokenType::TypedIntLit(value, suffix.to_string()))
+        }
+        None if radix == 16 => u64::try_from(value)
+            .map(TokenType::HexLit)
+            .map_err(|_| format!("Hata: '{}' sabiti 64 bitlik hex aralığını aşıyor; daha büyük değerler için 'u128' soneki kullanın.", shown)),
+        None => u64::try_from(value)
+            .map(TokenType::IntLit)
+            .map_err(|_| format!("Hata: '{}' sabiti u64 aralığını aşıyor.", shown)),
+    }
+}
+
+// Token listesindeki lexer hataları: tanınmayan karakterler ve geçersiz sayı sabitleri.
+pub fn errors(tokens: &[Token]) -> Vec<Diagnostic> {
+    tokens
+        .iter()
+        .filter_map(|token| {
+            let message = match &token.kind {
+                TokenType::Illegal(text) => format!("Hata: Geçersiz karakter: '{}'.", text),
+                TokenType::Error(message) => message.clone(),
+                _ => return None,
+            };
+            Some(Diagnostic::error(message).with_stage(Stage::Lexer).with_code(E_LEXER).with_label(token.span, ""))
+        })
+        .collect()
+}
+
+// String kaçış dizilerini çözer: \n \r \t \\ \" \' \0, \xHH (bayt) ve \u{H..H} (Unicode skaler değeri).
+// \xHH baytları ardışık olarak UTF-8 dizisi oluşturabilir ("\xC3\xA9" == "é"); sonuç geçerli UTF-8 olmalıdır.
+fn unescape(raw: &str) -> Result<String, String> {
+    let mut bytes = Vec::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    let push = |bytes: &mut Vec<u8>, c: char| bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            push(&mut bytes, c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => bytes.push(b'\n'),
+            Some('r') => bytes.push(b'\r'),
+            Some('t') => bytes.push(b'\t'),
+            Some('0') => bytes.push(0),
+            Some('x') => {
+                let digits: String = chars.by_ref().take(2).collect();
+                match u8::from_str_radix(&digits, 16) {
+                    Ok(byte) if digits.len() == 2 => bytes.push(byte),
+                    _ => return Err(format!("Hata: Geçersiz '\\x' kaçış dizisi: '\\x{}'. İki hex rakam bekleniyor.", digits)),
+                }
+            }
+            Some('u') => {
+                let rest = chars.as_str();
+                let value = rest
+                    .strip_prefix('{')
+                    .and_then(|rest| rest.split_once('}'))
+                    .filter(|(digits, _)| (1..=6).contains(&digits.len()))
+                    .and_then(|(digits, _)| u32::from_str_radix(digits, 16).ok());
+                let Some(value) = value else {
+                    return Err("Hata: Geçersiz '\\u' kaçış dizisi. Biçim: \\u{1F600} (1-6 hex rakam).".to_string());
+                };
+                let c = char::from_u32(value).ok_or_else(|| format!("Hata: '\\u{{{:X}}}' geçerli bir Unicode skaler değeri değil.", value))?;
+                push(&mut bytes, c);
+                let consumed = rest.find('}').expect("kapanış parantezi yukarıda bulundu") + 1;
+                chars = rest[consumed..].chars();
+            }
+            Some(other) => push(&mut bytes, other), // \\ \" \' ve bilinmeyenler: karakterin kendisi
+            None => bytes.push(b'\\'),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| "Hata: '\\x' kaçış dizileri geçerli bir UTF-8 dizisi oluşturmuyor.".to_string())
+}
+
+// `{{` kaçışı olmayan bir `{` var mı. Kaçışsız her `{` bir `{ifade}` yer tutucusu başlatır; düz
+// parantez `{{` ile yazılır. Kapanmamış yer tutucular parser'da hata olarak raporlanır.
+fn has_placeholder(text: &str) -> bool {
+    let mut rest = text;
+    while let Some(index) = rest.find('{') {
+        match rest[index + 1..].strip_prefix('{') {
+            Some(escaped) => rest = escaped,
+            None => return true,
+        }
     }
-}
\ No newline at end of file
+    false
+}
+
+// Üç tırnaklı stringlerin girinti temizliği (bkz. Lexer::scan_triple_string).
+fn strip_indentation(raw: &str) -> String {
+    let mut lines: Vec<&str> = raw.split('\n').collect();
+    if lines.len() > 1 && lines[0].trim().is_empty() {
+        lines.remove(0);
+    }
+    // Kapanış """ kendi satırındaysa o satırın girintisi de ortak girintiye katılır.
+    let closing_indent = match lines.last() {
+        Some(last) if lines.len() > 1 && last.trim().is_empty() => Some(last.len()),
+        _ => None,
+    };
+    if closing_indent.is_some() {
+        lines.pop();
+    }
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .chain(closing_indent)
+        .min()
+        .unwrap_or(0);
+    let stripped: Vec<&str> = lines.iter().map(|line| line.get(indent..).unwrap_or("").trim_end_matches('\r')).collect();
+    stripped.join("\n")
+}
+
+// `/** ... */` yorumunun metni: satır başlarındaki `*` süsleri ve ortak girinti kaldırılır.
+fn block_doc(text: &str) -> String {
+    let decorated = text.lines().skip(1).filter(|line| !line.trim().is_empty()).all(|line| line.trim_start().starts_with('*'));
+    let lines: Vec<String> = text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match line.trim_start().strip_prefix('*') {
+            Some(rest) if decorated && i > 0 => rest.strip_prefix(' ').unwrap_or(rest).to_string(),
+            _ => line.to_string(),
+        })
+        .collect();
+    // İlk satır (`/**` ile aynı satırdaki metin) ortak girintiye katılmaz.
+    let Some(first) = lines.first().map(|line| line.trim()) else {
+        return String::new();
+    };
+    let rest = strip_indentation(&format!("\n{}", lines[1..].join("\n")));
+    let doc = if first.is_empty() { rest } else { format!("{}\n{}", first, rest) };
+    doc.trim_matches('\n').trim_end().to_string()
+}
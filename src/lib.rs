@@ -0,0 +1,26 @@
+// src/lib.rs
+
+// NIMBLE derleyicisinin kütüphane kökü. Derleme aşamaları modüller olarak dışa açılır;
+// gömülü kullanım için giriş noktası `Session`'dır (bkz. session.rs).
+
+pub mod token;
+pub mod lexer;
+pub mod preprocessor; // #define/#ifdef koşullu derleme (lexer ile parser arasında)
+pub mod ast;
+pub mod parser;
+pub mod type_checker;
+pub mod mono; // Jenerik öğelerin tip argümanlarına göre somut kopyalara açılması
+pub mod ir; // Tip kontrolü ile assembly arasındaki ara gösterim
+pub mod codegen;
+pub mod c_backend; // --backend c: kontrol edilmiş AST'den C11 kaynak üretimi
+pub mod assembler; // Yerleşik x86-64 assembler'ı ve ELF64 nesne yazıcısı
+pub mod linker; // Bağımsız Linux programları için yerleşik statik linker
+pub mod diagnostic; // Span ve Diagnostic tipleri
+pub mod ast_dump; // --emit ast/typed-ast ağaç çıktısı
+pub mod interpreter; // `nim run --interp` ağaç yorumlayıcısı
+pub mod session; // Programatik derleme API'si
+pub mod test_runner; // `nim test` golden test çalıştırıcısı
+pub mod doc; // `nim doc` doküman üreticisi ve doctest çalıştırıcısı
+
+pub use diagnostic::{Diagnostic, SourceMap};
+pub use session::{Artifact, Backend, BuildMode, Compiled, DirSink, EmitKind, Options, OutputSink, OutputType, Session};
@@ -0,0 +1,312 @@
+// src/linker.rs
+
+// Yerleşik statik linker: assembler'ın ürettiği nesneleri (`assembler::Object`) birleştirip
+// bağımsız (libc'siz, statik) bir x86-64 Linux ELF çalıştırılabilir dosyası yazar.
+//
+// Aynı isimli bölümler nesne sırasıyla art arda eklenir ve izinlerine göre üç PT_LOAD segmentine
+// yerleşir: salt okunur (ELF başlıkları, .rodata, .eh_frame), çalıştırılabilir (.text) ve yazılabilir
+// (.data, .bss). Global semboller tüm nesneler arasında çözülür, relocation'lar son adreslere göre
+// uygulanır. Debug bölümleri yüklenmeden dosyaya eklenir; gdb/lldb onları bölüm tablosundan bulur.
+// Dış (C) fonksiyonlar ve paylaşımlı kütüphaneler desteklenmez; o durumda sistem linker'ı kullanılır.
+
+use std::collections::HashMap;
+
+use crate::assembler::{Object, RelocKind, RelocTarget, SectionKind};
+
+// Klasik statik yükleme adresi (ld -no-pie ile aynı)
+const BASE_ADDRESS: u64 = 0x400000;
+const PAGE_SIZE: u64 = 0x1000;
+
+const PT_LOAD: u32 = 1;
+const PT_GNU_STACK: u32 = 0x6474_e551;
+const PF_X: u32 = 1;
+const PF_W: u32 = 2;
+const PF_R: u32 = 4;
+
+const SHT_PROGBITS: u32 = 1;
+const SHT_SYMTAB: u32 = 2;
+const SHT_STRTAB: u32 = 3;
+const SHT_NOBITS: u32 = 8;
+const SHT_X86_64_UNWIND: u32 = 0x7000_0001;
+
+// Çıktı bölümü: aynı isimli girdi bölümlerinin birleşimi
+struct OutputSection {
+    name: String,
+    kind: SectionKind,
+    align: u64,
+    data: Vec<u8>,
+    size: u64,
+    address: u64,
+    offset: u64,
+}
+
+// Segment yerleşimindeki sıra: salt okunur, kod, veri, bss; debug en sonda ve yüklenmez.
+fn rank(kind: SectionKind) -> Option<usize> {
+    match kind {
+        SectionKind::ReadOnly | SectionKind::Unwind => Some(0),
+        SectionKind::Code => Some(1),
+        SectionKind::Data => Some(2),
+        SectionKind::Bss => Some(3),
+        SectionKind::Debug => Some(4),
+        SectionKind::Note => None,
+    }
+}
+
+// Nesneleri bağlar ve çalıştırılabilir dosyanın içeriğini döner. `entry` giriş noktası sembolüdür.
+pub fn link_executable(objects: &[Object], entry: &str) -> Result<Vec<u8>, String> {
+    // 1. Bölümleri birleştir: placement[nesne][bölüm] = (çıktı bölümü, bölüm içi ofset).
+    // Çıktı bölümleri segment sırasıyla oluşur.
+    let mut outputs: Vec<OutputSection> = Vec::new();
+    let mut placement: Vec<Vec<Option<(usize, u64)>>> = objects.iter().map(|o| vec![None; o.sections.len()]).collect();
+    for kind_rank in 0..5 {
+        for (object_index, object) in objects.iter().enumerate() {
+            for (section_index, section) in object.sections.iter().enumerate() {
+                if rank(section.kind) != Some(kind_rank) {
+                    continue;
+                }
+                let output_index = match outputs.iter().position(|o| o.name == section.name) {
+                    Some(index) => index,
+                    None => {
+                        outputs.push(OutputSection {
+                            name: section.name.clone(),
+                            kind: section.kind,
+                            align: 1,
+                            data: Vec::new(),
+                            size: 0,
+                            address: 0,
+                            offset: 0,
+                        });
+                        outputs.len() - 1
+                    }
+                };
+                let output = &mut outputs[output_index];
+                let offset = output.size.next_multiple_of(section.align.max(1));
+                output.align = output.align.max(section.align);
+                if section.kind != SectionKind::Bss {
+                    output.data.resize(offset as usize, 0);
+                    output.data.extend_from_slice(&section.data);
+                }
+                output.size = offset + section.size();
+                placement[object_index][section_index] = Some((output_index, offset));
+            }
+        }
+    }
+
+    // 2. Adresleri ata: her segment yeni bir sayfada başlar, dosya ofseti adresle aynı hizadadır.
+    // Salt okunur segment ELF başlıklarını da taşıdığı için her zaman vardır; diğerleri boşsa atlanır.
+    let segment_ranks: Vec<(usize, u32)> = [(0, PF_R), (1, PF_R | PF_X), (2, PF_R | PF_W)]
+        .into_iter()
+        .filter(|(r, _)| *r == 0 || outputs.iter().any(|o| rank(o.kind) == Some(*r) || (*r == 2 && o.kind == SectionKind::Bss)))
+        .collect();
+    let segment_count = segment_ranks.len();
+    let headers_size = 64 + 56 * (segment_count + 1) as u64;
+    let mut offset = headers_size;
+    let mut segments: Vec<(u32, u64, u64, u64, u64)> = Vec::new(); // (bayraklar, ofset, adres, dosya boyutu, bellek boyutu)
+    for (segment_rank, flags) in segment_ranks {
+        if segment_rank > 0 {
+            offset = offset.next_multiple_of(PAGE_SIZE);
+        }
+        let start = if segment_rank == 0 { 0 } else { offset };
+        let mut end_in_file = offset;
+        let mut end_in_memory = BASE_ADDRESS + offset;
+        for output in outputs.iter_mut() {
+            let output_rank = rank(output.kind);
+            let belongs = output_rank == Some(segment_rank) || (segment_rank == 2 && output_rank == Some(3));
+            if !belongs {
+                continue;
+            }
+            if output.kind == SectionKind::Bss {
+                let address = end_in_memory.next_multiple_of(output.align);
+                output.address = address;
+                output.offset = end_in_file;
+                end_in_memory = address + output.size;
+            } else {
+                offset = offset.next_multiple_of(output.align);
+                output.offset = offset;
+                output.address = BASE_ADDRESS + offset;
+                offset += output.size;
+                end_in_file = offset;
+                end_in_memory = BASE_ADDRESS + offset;
+            }
+        }
+        segments.push((flags, start, BASE_ADDRESS + start, end_in_file - start, end_in_memory - BASE_ADDRESS - start));
+    }
+    // Debug bölümleri yüklenmez; adresleri bölüm içi ofset olarak 0'dan başlar.
+    for output in outputs.iter_mut().filter(|o| o.kind == SectionKind::Debug) {
+        offset = offset.next_multiple_of(output.align);
+        output.offset = offset;
+        output.address = 0;
+        offset += output.size;
+    }
+
+    // 3. Sembolleri çöz: global semboller tek tanımlı olmalı
+    let addresses: Vec<Vec<Option<u64>>> = placement
+        .iter()
+        .map(|sections| sections.iter().map(|p| p.map(|(output, offset)| outputs[output].address + offset)).collect())
+        .collect();
+    let section_address = |object: usize, section: usize| addresses[object][section];
+    let mut globals: HashMap<&str, u64> = HashMap::new();
+    for (object_index, object) in objects.iter().enumerate() {
+        for symbol in object.symbols.iter().filter(|s| s.global) {
+            let Some(section) = symbol.section else { continue };
+            let address = section_address(object_index, section).ok_or_else(|| format!("'{}' sembolü yüklenmeyen bir bölümde", symbol.name))?;
+            if globals.insert(&symbol.name, address + symbol.value).is_some() {
+                return Err(format!("'{}' sembolü birden fazla kez tanımlanmış", symbol.name));
+            }
+        }
+    }
+    let entry_address = *globals.get(entry).ok_or_else(|| format!("Giriş noktası '{}' tanımlı değil", entry))?;
+
+    // 4. Relocation'ları uygula
+    for (object_index, object) in objects.iter().enumerate() {
+        let locals: HashMap<&str, u64> = object
+            .symbols
+            .iter()
+            .filter(|s| !s.global)
+            .filter_map(|s| Some((s.name.as_str(), section_address(object_index, s.section?)? + s.value)))
+            .collect();
+        for (section_index, section) in object.sections.iter().enumerate() {
+            let Some((output, base)) = placement[object_index][section_index] else { continue };
+            for reloc in &section.relocs {
+                let target = match &reloc.target {
+                    RelocTarget::Section(index) => {
+                        section_address(object_index, *index).ok_or("Relocation yüklenmeyen bir bölüme başvuruyor")?
+                    }
+                    RelocTarget::Symbol(name) => *locals
+                        .get(name.as_str())
+                        .or_else(|| globals.get(name.as_str()))
+                        .ok_or_else(|| format!("Tanımsız sembol: '{}'", name))?,
+                };
+                let place = outputs[output].address + base + reloc.offset;
+                let value = (target as i64).wrapping_add(reloc.addend);
+                let (bytes, fits) = match reloc.kind {
+                    RelocKind::Abs64 => (value.to_le_bytes().to_vec(), true),
+                    RelocKind::Abs32 => ((value as u32).to_le_bytes().to_vec(), u32::try_from(value).is_ok()),
+                    RelocKind::Abs32S => ((value as i32).to_le_bytes().to_vec(), i32::try_from(value).is_ok()),
+                    RelocKind::Pc32 | RelocKind::Plt32 => {
+                        let relative = value.wrapping_sub(place as i64);
+                        ((relative as i32).to_le_bytes().to_vec(), i32::try_from(relative).is_ok())
+                    }
+                };
+                if !fits {
+                    return Err(format!("'{}' bölümündeki relocation hedefe ulaşamıyor (ofset {:#x})", section.name, reloc.offset));
+                }
+                let start = (base + reloc.offset) as usize;
+                outputs[output].data[start..start + bytes.len()].copy_from_slice(&bytes);
+            }
+        }
+    }
+
+    // 5. Dosyayı yaz
+    let mut out = vec![0u8; headers_size as usize];
+    for output in outputs.iter().filter(|o| o.kind != SectionKind::Bss) {
+        out.resize(output.offset as usize, 0);
+        out.extend_from_slice(&output.data);
+    }
+
+    // Symtab (gdb/nm için): nesnelerin yerel ve global sembolleri mutlak adresleriyle
+    let mut strtab = vec![0u8];
+    let mut symtab = vec![0u8; 24];
+    let mut local_count = 1;
+    for global in [false, true] {
+        for (object_index, object) in objects.iter().enumerate() {
+            for symbol in object.symbols.iter().filter(|s| s.global == global) {
+                let Some(section) = symbol.section else { continue };
+                let Some((output, _)) = placement[object_index][section] else { continue };
+                let address = section_address(object_index, section).expect("yerleşmiş bölüm") + symbol.value;
+                let name = strtab.len() as u32;
+                strtab.extend_from_slice(symbol.name.as_bytes());
+                strtab.push(0);
+                symtab.extend_from_slice(&name.to_le_bytes());
+                symtab.push((global as u8) << 4 | if symbol.function { 2 } else { 0 });
+                symtab.push(0);
+                symtab.extend_from_slice(&(output as u16 + 1).to_le_bytes());
+                symtab.extend_from_slice(&address.to_le_bytes());
+                symtab.extend_from_slice(&0u64.to_le_bytes());
+                if !global {
+                    local_count += 1;
+                }
+            }
+        }
+    }
+
+    let mut shstrtab = vec![0u8];
+    let mut add_name = |name: &str| {
+        let offset = shstrtab.len() as u32;
+        shstrtab.extend_from_slice(name.as_bytes());
+        shstrtab.push(0);
+        offset
+    };
+    // (isim, tür, bayraklar, adres, ofset, boyut, link, info, hizalama, girdi boyutu)
+    type Header = (u32, u32, u64, u64, u64, u64, u32, u32, u64, u64);
+    let mut headers: Vec<Header> = vec![(0, 0, 0, 0, 0, 0, 0, 0, 0, 0)];
+    for output in &outputs {
+        let (kind, flags) = match output.kind {
+            SectionKind::Code => (SHT_PROGBITS, 0x6),
+            SectionKind::Data => (SHT_PROGBITS, 0x3),
+            SectionKind::Bss => (SHT_NOBITS, 0x3),
+            SectionKind::ReadOnly => (SHT_PROGBITS, 0x2),
+            SectionKind::Unwind => (SHT_X86_64_UNWIND, 0x2),
+            SectionKind::Debug | SectionKind::Note => (SHT_PROGBITS, 0),
+        };
+        headers.push((add_name(&output.name), kind, flags, output.address, output.offset, output.size, 0, 0, output.align, 0));
+    }
+    let symtab_index = headers.len() as u32;
+    out.resize(out.len().next_multiple_of(8), 0);
+    headers.push((add_name(".symtab"), SHT_SYMTAB, 0, 0, out.len() as u64, symtab.len() as u64, symtab_index + 1, local_count, 8, 24));
+    out.extend_from_slice(&symtab);
+    headers.push((add_name(".strtab"), SHT_STRTAB, 0, 0, out.len() as u64, strtab.len() as u64, 0, 0, 1, 0));
+    out.extend_from_slice(&strtab);
+    let shstrtab_name = add_name(".shstrtab");
+    headers.push((shstrtab_name, SHT_STRTAB, 0, 0, out.len() as u64, shstrtab.len() as u64, 0, 0, 1, 0));
+    out.extend_from_slice(&shstrtab);
+
+    out.resize(out.len().next_multiple_of(8), 0);
+    let section_headers = out.len() as u64;
+    for (name, kind, flags, address, offset, size, link, info, align, entsize) in &headers {
+        out.extend_from_slice(&name.to_le_bytes());
+        out.extend_from_slice(&kind.to_le_bytes());
+        out.extend_from_slice(&flags.to_le_bytes());
+        out.extend_from_slice(&address.to_le_bytes());
+        out.extend_from_slice(&offset.to_le_bytes());
+        out.extend_from_slice(&size.to_le_bytes());
+        out.extend_from_slice(&link.to_le_bytes());
+        out.extend_from_slice(&info.to_le_bytes());
+        out.extend_from_slice(&align.to_le_bytes());
+        out.extend_from_slice(&entsize.to_le_bytes());
+    }
+
+    // ELF başlığı ve program başlıkları
+    let mut header = Vec::with_capacity(headers_size as usize);
+    header.extend_from_slice(&[0x7f, b'E', b'L', b'F', 2, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    header.extend_from_slice(&2u16.to_le_bytes()); // ET_EXEC
+    header.extend_from_slice(&62u16.to_le_bytes()); // EM_X86_64
+    header.extend_from_slice(&1u32.to_le_bytes());
+    header.extend_from_slice(&entry_address.to_le_bytes());
+    header.extend_from_slice(&64u64.to_le_bytes()); // program başlıkları hemen ELF başlığından sonra
+    header.extend_from_slice(&section_headers.to_le_bytes());
+    header.extend_from_slice(&0u32.to_le_bytes());
+    header.extend_from_slice(&64u16.to_le_bytes());
+    header.extend_from_slice(&56u16.to_le_bytes());
+    header.extend_from_slice(&(segment_count as u16 + 1).to_le_bytes());
+    header.extend_from_slice(&64u16.to_le_bytes());
+    header.extend_from_slice(&(headers.len() as u16).to_le_bytes());
+    header.extend_from_slice(&(headers.len() as u16 - 1).to_le_bytes());
+    for (flags, offset, address, file_size, memory_size) in &segments {
+        header.extend_from_slice(&PT_LOAD.to_le_bytes());
+        header.extend_from_slice(&flags.to_le_bytes());
+        header.extend_from_slice(&offset.to_le_bytes());
+        header.extend_from_slice(&address.to_le_bytes());
+        header.extend_from_slice(&address.to_le_bytes());
+        header.extend_from_slice(&file_size.to_le_bytes());
+        header.extend_from_slice(&memory_size.to_le_bytes());
+        header.extend_from_slice(&PAGE_SIZE.to_le_bytes());
+    }
+    // Çalıştırılamaz stack
+    header.extend_from_slice(&PT_GNU_STACK.to_le_bytes());
+    header.extend_from_slice(&(PF_R | PF_W).to_le_bytes());
+    header.extend_from_slice(&[0; 40]);
+    header.extend_from_slice(&16u64.to_le_bytes());
+    out[..headers_size as usize].copy_from_slice(&header);
+    Ok(out)
+}
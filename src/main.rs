@@ -1,46 +1,40 @@
 // src/main.rs
 
-// Tüm modüller
-mod token;
-mod lexer;
-mod ast;
-mod parser;
-mod type_checker;
-mod codegen; // YENİ: Codegen modülünü ekle
+// Komut satırı aracı: argümanları ayrıştırır ve derleme işini kütüphanedeki `Session`'a devreder.
 
-// doğrudan use ifadeleri
-use lexer::Lexer;
-use parser::Parser;
-use token::TokenType;
 use std::env;
 use std::fs;
+use std::path::Path;
 use std::process;
-use crate::type_checker::TypeChecker;
-use crate::ast::{Decl, TargetPlatform}; // YENİ: TargetPlatform'u ast'den al.
-use crate::codegen::Codegen; // YENİ: Codegen'i içeri aktar.
-use std::process::Command; // YENİ: Dış komutları çalıştırmak için.
+use nim::ast::{TargetArch, TargetPlatform};
+use nim::diagnostic::{Diagnostic, SourceMap, Stage};
+use nim::ir::opt::OptLevel;
+use nim::{doc, test_runner};
+use nim::session::{host_platform, Artifact, Backend, BuildMode, DirSink, EmitKind, Options, OutputType, Session};
 
-// YENİ: Derleme modunu belirten enum.
+// YENİ: Hata çıktısının biçimi (--error-format).
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub enum BuildMode {
-    Debug,
-    Release,
-}
-
-// YENİ: Çıktı tipini belirten enum.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum OutputType {
-    Executable,
-    SharedLibrary,
+pub enum ErrorFormat {
+    Human,
+    Json,
 }
 
 pub struct Config {
     pub include_paths: Vec<String>,
     pub input_file: String,
     pub target_platform: TargetPlatform,
+    pub arch: TargetArch, // YENİ: Hedef mimari (--arch)
     pub show_help: bool,
     pub build_mode: BuildMode, // YENİ: Derleme modu
+    pub opt_level: Option<OptLevel>, // YENİ: -O0/-O1/-O2 ile modun varsayılanını değiştirir
     pub output_type: OutputType, // YENİ: Çıktı tipi
+    pub error_format: ErrorFormat, // YENİ: Hata çıktı biçimi
+    pub emit: EmitKind, // YENİ: Durulacak aşama
+    pub backend: Backend, // YENİ: Kod üretimi arka ucu (--backend)
+    pub interpret: bool, // YENİ: `nim run --interp` - native kod yerine yorumlayıcıyla çalıştır
+    pub program_args: Vec<String>, // YENİ: `--` sonrasında programa iletilecek argümanlar
+    pub defines: Vec<(String, String)>, // YENİ: -D NAME[=VALUE] önişlemci sembolleri
+    pub link_inputs: Vec<String>, // YENİ: --link ile programla bağlanacak C kaynakları/nesne dosyaları
 }
 
 fn parse_config(args: Vec<String>) -> Result<Config, String> {
@@ -66,12 +60,27 @@ fn parse_config(args: Vec<String>) -> Result<Config, String> {
     // 2. Adım: Komut satırı argümanlarını ayrıştır (config dosyasını geçersiz kılabilir)
     let mut iter = args.into_iter().skip(1);
     let mut target_platform = TargetPlatform::Unknown;
+    let mut arch = None;
     let mut show_help = false;
     let mut build_mode = BuildMode::Release;
+    let mut opt_level = None;
     let mut output_type = OutputType::Executable; // Varsayılan olarak çalıştırılabilir dosya
+    let mut error_format = ErrorFormat::Human;
+    let mut emit = EmitKind::Exe;
+    let mut backend = Backend::Native;
+    let mut run = false;
+    let mut interpret = false;
+    let mut program_args = Vec::new();
+    let mut defines = Vec::new();
+    let mut link_inputs = Vec::new();
 
     while let Some(arg) = iter.next() {
         match arg.as_str() {
+            "run" if !run && input_file.is_empty() => run = true,
+            "--interp" => interpret = true,
+            "--" if run => {
+                program_args.extend(iter.by_ref());
+            }
             "-h" | "-help" | "--help" => {
                 show_help = true;
                 break; // Yardım bayrağı her şeyi geçersiz kılar.
@@ -82,10 +91,23 @@ fn parse_config(args: Vec<String>) -> Result<Config, String> {
                         "windows" => TargetPlatform::Windows,
                         "linux" => TargetPlatform::Linux,
                         "macos" => TargetPlatform::Macos,
-                        _ => return Err(format!("Bilinmeyen hedef platform: '{}'. Geçerli olanlar: windows, linux, macos.", target_str)),
+                        "wasm32-wasi" | "wasi" => TargetPlatform::Wasi,
+                        _ => return Err(format!("Bilinmeyen hedef platform: '{}'. Geçerli olanlar: windows, linux, macos, wasm32-wasi.", target_str)),
                     };
                 } else {
-                    return Err("'--target' bayrağı bir platform (windows, linux, macos) bekliyor.".to_string());
+                    return Err("'--target' bayrağı bir platform (windows, linux, macos, wasm32-wasi) bekliyor.".to_string());
+                }
+            }
+            "--arch" => { // Hedef mimari bayrağı
+                if let Some(arch_str) = iter.next() {
+                    arch = Some(match arch_str.to_lowercase().as_str() {
+                        "x86_64" | "x86-64" | "amd64" => TargetArch::X86_64,
+                        "aarch64" | "arm64" => TargetArch::Aarch64,
+                        "wasm32" => TargetArch::Wasm32,
+                        _ => return Err(format!("Bilinmeyen mimari: '{}'. Geçerli olanlar: x86_64, aarch64, wasm32.", arch_str)),
+                    });
+                } else {
+                    return Err("'--arch' bayrağı bir mimari (x86_64, aarch64) bekliyor.".to_string());
                 }
             }
             "--mode" => { // Derleme modu bayrağı
@@ -99,6 +121,9 @@ fn parse_config(args: Vec<String>) -> Result<Config, String> {
                     return Err("'--mode' bayrağı bir mod (debug, release) bekliyor.".to_string());
                 }
             }
+            "-O0" => opt_level = Some(OptLevel::O0),
+            "-O1" => opt_level = Some(OptLevel::O1),
+            "-O2" => opt_level = Some(OptLevel::O2),
             "--output-type" => { // Çıktı tipi bayrağı
                 if let Some(type_str) = iter.next() {
                     output_type = match type_str.to_lowercase().as_str() {
@@ -110,6 +135,51 @@ fn parse_config(args: Vec<String>) -> Result<Config, String> {
                     return Err("'--output-type' bayrağı bir tip (exe, dll, so, shared) bekliyor.".to_string());
                 }
             }
+            "--error-format" => { // Hata çıktı biçimi bayrağı
+                if let Some(format_str) = iter.next() {
+                    error_format = match format_str.to_lowercase().as_str() {
+                        "human" => ErrorFormat::Human,
+                        "json" => ErrorFormat::Json,
+                        _ => return Err(format!("Bilinmeyen hata biçimi: '{}'. Geçerli olanlar: human, json.", format_str)),
+                    };
+                } else {
+                    return Err("'--error-format' bayrağı bir biçim (human, json) bekliyor.".to_string());
+                }
+            }
+            "--emit" => { // Aşama çıktısı bayrağı
+                if let Some(emit_str) = iter.next() {
+                    emit = match emit_str.to_lowercase().as_str() {
+                        "tokens" => EmitKind::Tokens,
+                        "ast" => EmitKind::Ast,
+                        "typed-ast" => EmitKind::TypedAst,
+                        "ir" => EmitKind::Ir,
+                        "asm" => EmitKind::Asm,
+                        "obj" => EmitKind::Obj,
+                        "exe" => EmitKind::Exe,
+                        _ => return Err(format!("Bilinmeyen --emit değeri: '{}'. Geçerli olanlar: tokens, ast, typed-ast, ir, asm, obj, exe.", emit_str)),
+                    };
+                } else {
+                    return Err("'--emit' bayrağı bir aşama (tokens, ast, typed-ast, ir, asm, obj, exe) bekliyor.".to_string());
+                }
+            }
+            "--backend" => { // Kod üretimi arka ucu bayrağı
+                if let Some(backend_str) = iter.next() {
+                    backend = match backend_str.to_lowercase().as_str() {
+                        "native" | "asm" => Backend::Native,
+                        "c" => Backend::C,
+                        _ => return Err(format!("Bilinmeyen arka uç: '{}'. Geçerli olanlar: native, c.", backend_str)),
+                    };
+                } else {
+                    return Err("'--backend' bayrağı bir arka uç (native, c) bekliyor.".to_string());
+                }
+            }
+            "--link" => { // Programla birlikte bağlanacak C kaynağı veya nesne dosyası
+                if let Some(path) = iter.next() {
+                    link_inputs.push(path);
+                } else {
+                    return Err("'--link' bayrağı bir dosya (.c, .o veya .a) bekliyor.".to_string());
+                }
+            }
             _ if arg.starts_with("-I") => {
                 // Hem -I/path hem de -I /path formatlarını destekle
                 if arg.len() > 2 {
@@ -120,7 +190,22 @@ fn parse_config(args: Vec<String>) -> Result<Config, String> {
                     return Err("'-I' bayrağı bir yol (path) bekliyor.".to_string());
                 }
             }
-            _ if arg.ends_with(".nim") || arg.ends_with(".n") => {
+            _ if arg.starts_with("-D") => {
+                // -DNAME, -DNAME=VALUE veya -D NAME=VALUE
+                let definition = if arg.len() > 2 {
+                    arg[2..].to_string()
+                } else {
+                    iter.next().ok_or("'-D' bayrağı bir sembol adı (NAME veya NAME=VALUE) bekliyor.")?
+                };
+                let (name, value) = definition.split_once('=').unwrap_or((&definition, "1"));
+                let is_identifier = name.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+                    && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+                if !is_identifier {
+                    return Err(format!("'-D' bayrağı için geçersiz sembol adı: '{}'.", name));
+                }
+                defines.push((name.to_string(), value.to_string()));
+            }
+            _ if arg.ends_with(".nim") || arg.ends_with(".n") || arg.ends_with(".oc") => {
                 if input_file.is_empty() {
                     input_file = arg;
                 } else {
@@ -135,15 +220,37 @@ fn parse_config(args: Vec<String>) -> Result<Config, String> {
 
     // Eğer hedef platform belirtilmemişse, derleyicinin çalıştığı platformu varsay.
     if target_platform == TargetPlatform::Unknown {
-        target_platform = match env::consts::OS {
-            "windows" => TargetPlatform::Windows,
-            "linux" => TargetPlatform::Linux,
-            "macos" => TargetPlatform::Macos,
-            unsupported_os => {
-                println!("Uyarı: Bilinmeyen veya desteklenmeyen bir platformda ('{}') çalışılıyor. Platforma özel modüller yüklenirken hata oluşabilir.", unsupported_os);
-                TargetPlatform::Unknown
-            }
-        };
+        target_platform = host_platform();
+        if target_platform == TargetPlatform::Unknown {
+            println!("Uyarı: Bilinmeyen veya desteklenmeyen bir platformda ('{}') çalışılıyor. Platforma özel modüller yüklenirken hata oluşabilir.", env::consts::OS);
+        }
+    }
+
+    // WASI hedefinin tek mimarisi wasm32'dir; '--arch' verilmemişse hedeften çıkarılır.
+    let arch = match (arch, target_platform) {
+        (None, TargetPlatform::Wasi) => TargetArch::Wasm32,
+        (None, _) => TargetArch::X86_64,
+        (Some(arch), _) => arch,
+    };
+    if arch == TargetArch::Aarch64 && target_platform != TargetPlatform::Linux {
+        return Err("'--arch aarch64' şimdilik yalnızca '--target linux' ile kullanılabilir.".to_string());
+    }
+    if (arch == TargetArch::Wasm32) != (target_platform == TargetPlatform::Wasi) {
+        return Err("'--arch wasm32' ve '--target wasm32-wasi' yalnızca birlikte kullanılabilir.".to_string());
+    }
+    if target_platform == TargetPlatform::Wasi && !link_inputs.is_empty() {
+        return Err("'--link' wasm32-wasi hedefinde kullanılamaz; WASI modülleri C koduyla bağlanmaz.".to_string());
+    }
+    if target_platform == TargetPlatform::Wasi && backend == Backend::C {
+        return Err("'--target wasm32-wasi' şimdilik yalnızca native arka uçla ('--backend native') kullanılabilir.".to_string());
+    }
+
+    // Şimdilik `nim run` sadece yorumlayıcı ile çalışır.
+    if run && !interpret {
+        return Err("'nim run' şimdilik sadece '--interp' ile kullanılabilir: nim run --interp <dosya.n>".to_string());
+    }
+    if interpret && !run {
+        return Err("'--interp' bayrağı sadece 'nim run' ile kullanılabilir.".to_string());
     }
 
     // Eğer hiç kaynak dosya belirtilmemişse veya yardım istenmişse, yardım göster.
@@ -151,27 +258,77 @@ fn parse_config(args: Vec<String>) -> Result<Config, String> {
         show_help = true;
     }
 
-    Ok(Config { include_paths, input_file, target_platform, show_help, build_mode, output_type })
+    Ok(Config { include_paths, input_file, target_platform, arch, show_help, build_mode, opt_level, output_type, error_format, emit, backend, interpret, program_args, defines, link_inputs })
 }
 
 // YENİ: Yardım mesajını gösteren fonksiyon.
 fn print_help() {
     println!("NIMBLE Derleyici v0.0.1 - Kullanım Kılavuzu");
     println!("----------------------------------------");
-    println!("Kullanım: nim <kaynak_dosya.n> [seçenekler]\n");
+    println!("Kullanım: nim <kaynak_dosya.n> [seçenekler]");
+    println!("          nim run --interp <kaynak_dosya.n> [-- argümanlar]");
+    println!("          nim test [dizin] [--bless] [--interp] [--target <platform>] [--arch <mimari>] [--backend <arka_uç>] [-I <yol>]\n");
     println!("Seçenekler:");
     println!("  -h, -help, --help      Bu yardım mesajını gösterir.");
-    println!("  --target <platform>    Derleme hedefini belirtir. Platformlar: windows, linux, macos.");
+    println!("  --target <platform>    Derleme hedefini belirtir. Platformlar: windows, linux, macos, wasm32-wasi.");
     println!("                         (Varsayılan: Çalıştırıldığı sistem)");
+    println!("                         wasm32-wasi: WebAssembly metni (build/obj/<ad>.wat) ve ikili modül (<ad>.wasm)");
+    println!("                         üretir; harici araç gerekmez. Dosya yolları ilk önceden açılmış dizine görelidir.");
+    println!("  --arch <mimari>        Hedef mimari: x86_64, aarch64, wasm32 (Varsayılan: x86_64, WASI'de wasm32).");
+    println!("                         aarch64: yalnızca Linux; assembly ve bağlama aarch64-linux-gnu-gcc ile yapılır.");
     println!("  --output-type <type>   Üretilecek çıktı tipini belirtir. Tipler: exe, dll, so, shared (Varsayılan: exe).");
     println!("  --mode <mode>          Derleme modunu belirtir. Modlar: debug, release (Varsayılan: release).");
+    println!("                         debug: Linux hedefinde gdb/lldb için DWARF hata ayıklama bilgisi üretir.");
+    println!("  -O0, -O1, -O2          IR optimizasyon seviyesi (Varsayılan: debug için -O0, release için -O2).");
+    println!("                         -O1: sabit katlama/yayma, dal sadeleştirme, ölü kod; -O2: ayrıca CSE, struct alanı iletimi ve inline.");
+    println!("  --backend <arka_uç>    Kod üretimi arka ucu: native, c (Varsayılan: native).");
+    println!("                         c: tek başına derlenebilen bir C11 dosyası (build/obj/<ad>.c) üretir ve");
+    println!("                         onu $CC (yoksa cc) ile derler; '--emit asm' C kaynağında durur.");
     println!("  -I <yol>               Modül arama yollarına ek bir dizin ekler.");
+    println!("  --link <dosya>         C kaynağını (.c) veya nesne dosyasını (.o, .a) programla birlikte GCC ile bağlar;");
+    println!("                         'extern fn' bildirimleri bu dosyadaki fonksiyonları çağırabilir.");
+    println!("  -D <AD>[=<değer>]      Önişlemci sembolü tanımlar (Varsayılan değer: 1). Kaynakta #ifdef, #ifndef,");
+    println!("                         #if, #elif, #else, #endif ve #define ile kullanılır. Öntanımlı semboller:");
+    println!("                         TARGET_LINUX/TARGET_WINDOWS/TARGET_MACOS/TARGET_WASI, ARCH_X86_64/ARCH_AARCH64/ARCH_WASM32,");
+    println!("                         BUILD_DEBUG/BUILD_RELEASE.");
+    println!("  --error-format <biçim> Hataların biçimi: human, json (Varsayılan: human).");
+    println!("                         json: stderr'e her hata için tek satırlık bir JSON nesnesi yazar.");
+    println!("  --emit <aşama>         Verilen aşamadan sonra durur ve çıktısını build/obj altına yazar.");
+    println!("                         Aşamalar: tokens, ast, typed-ast, ir, asm, obj, exe (Varsayılan: exe).");
+    println!("\nYorumlayıcı:");
+    println!("  nim run --interp <dosya> Programı native kod üretmeden ağaç yorumlayıcısıyla çalıştırır.");
+    println!("                         '--' sonrasındaki argümanlar programa iletilir; çıkış kodu main'in dönüş değeridir.");
+    println!("\nTest:");
+    println!("  nim test [dizin]       Dizindeki (Varsayılan: tests) her .oc dosyasını derleyip çalıştırır ve");
+    println!("                         stdout/stderr/çıkış kodunu <ad>.stdout, <ad>.stderr, <ad>.exit dosyalarıyla,");
+    println!("                         derleme hatası beklenen testlerde hata kodunu <ad>.error dosyasıyla karşılaştırır.");
+    println!("  --bless                Beklenti dosyalarını mevcut sonuçlarla günceller.");
+    println!("  --interp               Testleri derlemek yerine yorumlayıcıyla (nim run --interp) çalıştırır ve");
+    println!("                         native çıktının kaydedildiği aynı beklenti dosyalarıyla karşılaştırır.");
+    println!("  --arch aarch64         Testleri AArch64 için derler; x86-64 sistemde programlar qemu-aarch64 ile çalıştırılır.");
+    println!("  --target wasm32-wasi   Testleri WebAssembly'ye derler; modüller $NIM_WASM_RUNNER (örn.");
+    println!("                         'wasmtime run --dir=.') ile çalıştırılır, tanımlı değilse atlanır.");
+    println!("\nDoküman:");
+    println!("  nim doc <dosya>        Modülün pub API'sini '///' ve '/** */' yorumlarıyla build/doc/<ad>.md dosyasına yazar.");
+    println!("  --format html          Markdown yerine içindekiler listeli tek sayfalık HTML üretir.");
+    println!("  -o <dizin>             Çıktı dizini (Varsayılan: build/doc).");
+    println!("  --test                 Yorumlardaki ```nim kod örneklerini (doctest) yorumlayıcıyla çalıştırır.");
     println!("\nÖrnek:");
     println!("  nim programim.n --target windows -I ./ek_kutuphaneler");
 }
 
 fn main() {
-    let config = match parse_config(env::args().collect()) {
+    let args: Vec<String> = env::args().collect();
+    // YENİ: `nim test` alt komutu.
+    if args.get(1).map(String::as_str) == Some("test") {
+        process::exit(test_runner::run(&args[2..]));
+    }
+    // YENİ: `nim doc` alt komutu.
+    if args.get(1).map(String::as_str) == Some("doc") {
+        process::exit(doc::run(&args[2..]));
+    }
+
+    let config = match parse_config(args) {
         Ok(cfg) => cfg,
         Err(e) => {
             eprintln!("Yapılandırma Hatası: {}", e);
@@ -185,164 +342,134 @@ fn main() {
         process::exit(0);
     }
 
-    let source_code = fs::read_to_string(&config.input_file).unwrap_or_else(|_| {
-        eprintln!("Hata: Dosya okunamadı: {}", &config.input_file);
+    let error_format = config.error_format;
+    let options = Options {
+        include_paths: config.include_paths,
+        target_platform: config.target_platform,
+        arch: config.arch,
+        build_mode: config.build_mode,
+        opt_level: config.opt_level,
+        output_type: config.output_type,
+        backend: config.backend,
+        defines: config.defines,
+        link_inputs: config.link_inputs,
+        // Yorumlayıcı modunda stdout sadece programın çıktısına aittir.
+        verbose: !config.interpret,
+    };
+    let mut session = Session::from_path(&config.input_file, options).unwrap_or_else(|e| {
+        eprintln!("{}", e.message);
         process::exit(1);
     });
 
-    println!(">>> NIMBLE (nim) Derleyicisi v0.0.1");
-    println!(">>> Aşama 1: Lexer (Sözcük Analizi)");
-    
-    // Lexer
-    let mut lexer = Lexer::new(&source_code);
-    let mut tokens = Vec::new();
-    
-    loop {
-        let token = lexer.next_token();
-        tokens.push(token.clone()); 
-        if token.kind == TokenType::Eof { 
-            break; 
+    // YENİ: `nim run --interp` - kontrol edilmiş AST doğrudan yorumlanır.
+    if config.interpret {
+        let program = session
+            .lex()
+            .and_then(|tokens| session.preprocess(tokens))
+            .and_then(|tokens| session.parse(tokens))
+            .unwrap_or_else(|errors| fail(&errors, session.sources(), error_format));
+        let instantiated = session.check(&program).and_then(|checker| session.monomorphize(&program, &checker));
+        report_diagnostics(&session.take_warnings(), session.sources(), error_format);
+        let instantiated = instantiated.unwrap_or_else(|errors| fail(&errors, session.sources(), error_format));
+        let program = instantiated.unwrap_or(program);
+        match session.interpret(&program, config.program_args) {
+            Ok(code) => process::exit(code),
+            Err(e) => fail(&[e], session.sources(), error_format),
         }
     }
-    //println!("  {} token üretildi.", tokens.len());
-    //println!("-------------------------------------\n");
 
-    // Parser
-    println!(">>> Aşama 2: Parser (Sözdizimi Analizi)");
-    let mut parser = Parser::new(tokens); 
-    let (program_root, errors) = parser.parse();
+    let obj_dir = Path::new("build/obj");
+    let mut sink = DirSink::new(obj_dir, session.base_name());
+    let compiled = session.compile(config.emit, &mut sink);
+    report_diagnostics(&session.take_warnings(), session.sources(), error_format);
+    let compiled = compiled.unwrap_or_else(|errors| fail(&errors, session.sources(), error_format));
 
-    if !errors.is_empty() {
-        println!("\n--- Parser Hataları ---");
-        for error in &errors {
-            eprintln!("{}", error);
-        }
-        println!("-----------------------\n");
-        eprintln!("Derleme, sözdizimi hataları nedeniyle durduruldu.");
-        process::exit(1);
-    }
-  
-    let program_decls: Vec<Decl> = match program_root {
-        Decl::Program(decls) => decls, 
-        _ => {
-            eprintln!("Hata: Parser, Program kök yapısı yerine beklenmeyen bir Decl döndürdü.");
-            process::exit(1);
-        }
+    let artifact = match config.emit {
+        EmitKind::Tokens => Some(Artifact::Tokens),
+        EmitKind::Ast => Some(Artifact::Ast),
+        EmitKind::TypedAst => Some(Artifact::TypedAst),
+        EmitKind::Ir => Some(Artifact::Ir),
+        _ => None,
     };
-	
-    //println!("✅ Parser başarıyla tamamlandı.");
-    
-    // Testler bitene kadar AST çıktısını geçici olarak devre dışı bırakıyoruz.
-    // println!("\n--- Parser Çıktısı (AST) ---\n{:#?}\n---------------------------", program_decls);
-    // println!("-------------------------------------\n");
-    // Type Checker
-    println!(">>> Aşama 3: Semantik Analiz (Tip Kontrolü)");
-    let mut type_checker = TypeChecker::new(&program_decls, config.include_paths, config.target_platform);
-
-    match type_checker.check_program() {
-        Ok(_) => println!(" "), //println!("✅ Tip Kontrolü Başarılı!"),
-        Err(e) => {
-            eprintln!("Tip Kontrolü Hatası: {}", e);
-            process::exit(1);
-        }
+    if let Some(artifact) = artifact {
+        println!("✅ Çıktı '{}' dosyasına yazıldı.", sink.path(artifact).display());
+        return;
     }
 
-    // Kod Üretimi Aşaması
-    println!("\n>>> Aşama 4: Kod Üretimi (Codegen)");
-    let mut codegen = Codegen::new(&program_decls, &mut type_checker, config.target_platform);
-    match codegen.generate() {
-        Ok(asm_code) => {
-            // YENİ: Çıktı dizinlerini oluştur
-            let obj_dir = "build/obj";
-            let exe_base_dir = match config.build_mode {
-                BuildMode::Debug => "build/debug",
-                BuildMode::Release => "build/release",
-            };
-
-            fs::create_dir_all(obj_dir).expect("Nesne dizini oluşturulamadı.");
-            fs::create_dir_all(exe_base_dir).expect("Çalıştırılabilir dizini oluşturulamadı.");
-
-            // Giriş dosyasından temel adı al (örn: "test1.n" -> "test1")
-            let base_name = std::path::Path::new(&config.input_file)
-                .file_stem()
-                .and_then(|s| s.to_str())
-                .unwrap_or("output")
-                .to_string();
-
-            // Hedef platforma göre dosya uzantılarını ve isimlerini belirle
-            let (_platform_suffix, _obj_ext, output_ext) = match config.target_platform {
-                TargetPlatform::Windows => ("windows", "obj", if config.output_type == OutputType::Executable { ".exe" } else { ".dll" }),
-                TargetPlatform::Linux => ("linux", "o", if config.output_type == OutputType::Executable { "" } else { ".so" }),
-                TargetPlatform::Macos => ("macos", "o", if config.output_type == OutputType::Executable { "" } else { ".dylib" }),
-                _ => ("unknown", "o", ""), // Bilinmeyen platformlar için varsayılan
-            };
-
-            let output_asm_file = format!("{}/{}.s", obj_dir, base_name); // GAS typically uses .s or .asm
-            let output_obj_file = format!("{}/{}.o", obj_dir, base_name); // Object file
-            let output_final_file = format!("{}/{}{}", exe_base_dir, base_name, output_ext); // Output File
-
-            fs::write(&output_asm_file, asm_code).expect("Assembly dosyası yazılamadı.");
-            println!("✅ GAS (Intel) kodu başarıyla '{}' dosyasına yazıldı.", output_asm_file);
-
-            // 1. AŞAMA: GCC ile Assembly'den Nesne Dosyası (.o) Üretme
-            println!("⚙️ GCC ile Assembly derleniyor...");
-            let assemble_status = Command::new("gcc")
-                .args(&["-x", "assembler", "-c", &output_asm_file, "-o", &output_obj_file])
-                .status()
-                .expect("GCC (assembler) çalıştırılamadı. GCC'nin sistem PATH'inde olduğundan emin olun.");
+    let asm_path = sink.path(session.source_artifact());
+    if config.backend == Backend::C {
+        println!("✅ C11 kaynağı başarıyla '{}' dosyasına yazıldı.", asm_path.display());
+    } else if config.arch == TargetArch::Wasm32 {
+        println!("✅ WebAssembly metni (WAT) başarıyla '{}' dosyasına yazıldı.", asm_path.display());
+    } else {
+        let syntax = match config.arch {
+            TargetArch::Aarch64 => "AArch64",
+            _ => "Intel",
+        };
+        println!("✅ GAS ({}) kodu başarıyla '{}' dosyasına yazıldı.", syntax, asm_path.display());
+    }
+    if config.emit == EmitKind::Asm {
+        return;
+    }
 
-            if !assemble_status.success() {
-                eprintln!("❌ Assembly derlemesi başarısız oldu.");
-                process::exit(1);
-            }
+    // 1. AŞAMA: Assembly'den Nesne Dosyası (.o) Üretme (x86-64 Linux'ta yerleşik assembler, diğer hedeflerde GCC;
+    // WASI'de WAT metni yerleşik assembler ile ikili .wasm modülüne çevrilir)
+    let obj_path = obj_dir.join(session.object_file_name());
+    if let Err(d) = session.assemble(&asm_path, &obj_path) {
+        fail(&[d], session.sources(), error_format);
+    }
+    if config.emit == EmitKind::Obj {
+        println!("✅ Nesne dosyası oluşturuldu: {}", obj_path.display());
+        return;
+    }
 
-            // 2. AŞAMA: Linkleme
-            let (linker_cmd, linker_args) = match config.target_platform {
-                TargetPlatform::Windows => (
-                    "gcc",
-                    if config.output_type == OutputType::Executable {
-                        vec![output_obj_file.to_string(), "libs/core.obj".to_string(), "-o".to_string(), output_final_file.to_string()]
-                    } else { // SharedLibrary (DLL)
-                        vec!["-shared".to_string(), output_obj_file.to_string(), "libs/core.obj".to_string(), "-o".to_string(), output_final_file.to_string()]
-                    }
-                ),
-                TargetPlatform::Linux => (
-                    "gcc",
-                    if config.output_type == OutputType::Executable {
-                        vec![output_obj_file.to_string(), "-o".to_string(), output_final_file.to_string(), "-no-pie".to_string()]
-                    } else { // SharedLibrary (SO)
-                        vec!["-shared".to_string(), output_obj_file.to_string(), "-o".to_string(), output_final_file.to_string()]
-                    }
-                ),
-                TargetPlatform::Macos => (
-                    "gcc",
-                    if config.output_type == OutputType::Executable {
-                        vec![output_obj_file.to_string(), "-o".to_string(), output_final_file.to_string()]
-                    } else { 
-                        vec!["-shared".to_string(), output_obj_file.to_string(), "-o".to_string(), output_final_file.to_string()]
-                    }
-                ),
-                _ => {
-                    println!("Uyarı: Bu platform için otomatik derleme ve linkleme desteklenmiyor.");
-                    return;
-                }
-            };
-            
-            println!("🔗 Linker ile bağlanıyor...");
-            let linker_status = Command::new(linker_cmd)
-                .args(&linker_args)
-                .status()
-                .expect("Linker (gcc) çalıştırılamadı.");
+    // 2. AŞAMA: Linkleme (Linux'ta bağımsız programlar için yerleşik linker, diğer durumlarda GCC)
+    let exe_dir = match config.build_mode {
+        BuildMode::Debug => "build/debug",
+        BuildMode::Release => "build/release",
+    };
+    fs::create_dir_all(exe_dir).expect("Çalıştırılabilir dizini oluşturulamadı.");
+    let output_path = Path::new(exe_dir).join(session.output_file_name());
+    if let Err(d) = session.link(&obj_path, &output_path, &compiled) {
+        fail(&[d], session.sources(), error_format);
+    }
+    println!("✅ Başarıyla oluşturuldu: {}", output_path.display());
+}
 
-            if !linker_status.success() { 
-                eprintln!("❌ Linkleme başarısız oldu."); 
-                process::exit(1); 
+// Hataları aşamaya uygun başlık ve özetle raporlar ve süreci sonlandırır.
+fn fail(diagnostics: &[Diagnostic], sources: &SourceMap, format: ErrorFormat) -> ! {
+    let human = format == ErrorFormat::Human;
+    let stage = diagnostics.first().and_then(|d| d.stage);
+    if human {
+        match stage {
+            Some(Stage::Parser) => println!("\n--- Parser Hataları ---"),
+            Some(Stage::Type) => eprintln!("\n--- Tip Kontrolü Hataları ---"),
+            Some(Stage::Codegen) => eprintln!("Kod Üretimi Hatası:"),
+            _ => {}
+        }
+    }
+    report_diagnostics(diagnostics, sources, format);
+    if human {
+        match stage {
+            Some(Stage::Lexer) => eprintln!("Derleme, sözcük hataları nedeniyle durduruldu."),
+            Some(Stage::Preprocessor) => eprintln!("Derleme, önişlemci hataları nedeniyle durduruldu."),
+            Some(Stage::Parser) => {
+                println!("-----------------------\n");
+                eprintln!("Derleme, sözdizimi hataları nedeniyle durduruldu.");
             }
-            println!("✅ Başarıyla oluşturuldu: {}", output_final_file);
+            Some(Stage::Type) => eprintln!("Derleme, {} tip hatası nedeniyle durduruldu.", diagnostics.len()),
+            _ => {}
         }
-        Err(e) => {
-            eprintln!("Kod Üretimi Hatası: {}", e);
-            process::exit(1);
+    }
+    process::exit(1);
+}
+
+// YENİ: Hataları ve uyarıları seçilen biçimde stderr'e yazar.
+fn report_diagnostics(diagnostics: &[Diagnostic], sources: &SourceMap, format: ErrorFormat) {
+    for diagnostic in diagnostics {
+        match format {
+            ErrorFormat::Human => eprintln!("{}", diagnostic.render(sources)),
+            ErrorFormat::Json => eprintln!("{}", diagnostic.to_json(sources)),
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,384 @@
+// src/mono.rs
+
+// YENİ: Monomorphization. Tip kontrolü jenerik çağrıların ve struct literallerinin tip argümanlarını
+// `TypeChecker::instances`'a kaydeder; bu aşama her (öğe, tip argümanları) çifti için öğenin somut bir
+// kopyasını üretir ve kullanım yerlerini bu kopyalara yönlendirir. Çıkan program tip parametresi veya
+// jenerik tip içermez, bu yüzden arka uçlar (native, C, yorumlayıcı) jeneriklerden habersizdir.
+//
+// İsimlendirme: `kutu_yap<i32>` -> `kutu_yap__i32`, `Kutu<Kutu<i32>>` -> `Kutu__Kutu__i32`,
+// `group Yigin<f64>` -> `Yigin__f64`.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::ast::{Decl, Expr, ExprKind, Stmt, StmtKind, Type, TypeParam};
+use crate::diagnostic::{type_error_code, Diagnostic, Span, Stage};
+
+// Bir programdan üretilebilecek en fazla örnek sayısı. Kendini her seferinde daha büyük bir tiple
+// çağıran jenerik fonksiyonlar (f<T> içinde f(Kutu { deger: x })) sonsuz örnek üretir.
+const INSTANCE_LIMIT: usize = 1000;
+
+// Programda jenerik bir fonksiyon, struct, grup veya typedef var mı?
+pub fn has_generics(program: &[Decl]) -> bool {
+    program.iter().any(|decl| match decl {
+        Decl::Function { type_params, .. }
+        | Decl::Struct { type_params, .. }
+        | Decl::Group { type_params, .. }
+        | Decl::Typedef { type_params, .. } => !type_params.is_empty(),
+        _ => false,
+    })
+}
+
+// Jenerik öğeleri kullanıldıkları tip argümanlarıyla örnekler. Örnekler, şablonun programdaki yerine
+// (oluşturulma sırasıyla) yerleştirilir; jenerik typedef'ler hedef tiplerine açıldığı için atılır.
+pub fn monomorphize(program: &[Decl], instances: &HashMap<Span, (String, Vec<Type>)>) -> Result<Vec<Decl>, Diagnostic> {
+    let mut mono = Mono::new(program, instances);
+    let mut rewritten = Vec::with_capacity(program.len());
+    for decl in program {
+        rewritten.push(match decl {
+            Decl::Function { type_params, .. } | Decl::Struct { type_params, .. } | Decl::Typedef { type_params, .. }
+                if !type_params.is_empty() =>
+            {
+                None
+            }
+            Decl::Group { type_params, .. } if !type_params.is_empty() => mono.group_constants(decl)?,
+            _ => {
+                let mut decl = decl.clone();
+                mono.decl(&mut decl, &HashMap::new())?;
+                Some(decl)
+            }
+        });
+    }
+    mono.drain()?;
+
+    let mut output = Vec::new();
+    for (decl, rewritten) in program.iter().zip(rewritten) {
+        if let Some(name) = template_name(decl) {
+            output.extend(mono.emitted.remove(name).unwrap_or_default());
+        }
+        output.extend(rewritten);
+    }
+    Ok(output)
+}
+
+// Örnek isimlerinde kullanılan, tanımlayıcı olarak geçerli tip adı.
+pub fn mangle(name: &str, args: &[Type]) -> String {
+    let args: Vec<String> = args.iter().map(type_key).collect();
+    format!("{}__{}", name, args.join("_"))
+}
+
+fn type_key(ty: &Type) -> String {
+    match ty {
+        Type::Custom(name) | Type::Enum(name, _) => name.clone(),
+        Type::Str(_) => "str".to_string(),
+        Type::Array(inner, _) => format!("arr_{}", type_key(inner)),
+        Type::Ptr(inner) => format!("ptr_{}", type_key(inner)),
+        Type::Ref(inner) => format!("ref_{}", type_key(inner)),
+        Type::Option(inner) => format!("opt_{}", type_key(inner)),
+        Type::Future(inner) => format!("fut_{}", type_key(inner)),
+        Type::Channel(inner) => format!("chan_{}", type_key(inner)),
+        Type::Result(ok, err) => format!("res_{}_{}", type_key(ok), type_key(err)),
+        Type::Tuple(types) => format!("tup{}_{}", types.len(), types.iter().map(type_key).collect::<Vec<_>>().join("_")),
+        Type::Fn(params, ret) => format!("fn{}_{}_{}", params.len(), params.iter().map(type_key).collect::<Vec<_>>().join("_"), type_key(ret)),
+        Type::Generic(name, args) => mangle(name, args),
+        other => other.to_string().chars().map(|c| if c.is_alphanumeric() { c } else { '_' }).collect(),
+    }
+}
+
+// Örnekleri programda şablonun yerine yerleştirilecek jenerik bildirimin adı.
+fn template_name(decl: &Decl) -> Option<&str> {
+    match decl {
+        Decl::Function { name, type_params, .. } | Decl::Struct { name, type_params, .. } | Decl::Group { name, type_params, .. }
+            if !type_params.is_empty() =>
+        {
+            Some(name)
+        }
+        _ => None,
+    }
+}
+
+fn mono_error(message: String, span: Span) -> Diagnostic {
+    let code = type_error_code(&message);
+    Diagnostic::error(message).with_stage(Stage::Type).with_code(code).with_fallback_span(span)
+}
+
+struct Mono<'p> {
+    templates: HashMap<&'p str, &'p Decl>,
+    aliases: HashMap<&'p str, (&'p [TypeParam], &'p Type)>,
+    instances: &'p HashMap<Span, (String, Vec<Type>)>,
+    queue: VecDeque<(String, Vec<Type>, Span)>,
+    requested: HashSet<String>,
+    emitted: HashMap<String, Vec<Decl>>, // Şablon adı -> üretilen örnekler
+}
+
+type Subst = HashMap<String, Type>;
+
+impl<'p> Mono<'p> {
+    fn new(program: &'p [Decl], instances: &'p HashMap<Span, (String, Vec<Type>)>) -> Self {
+        let mut templates = HashMap::new();
+        let mut aliases = HashMap::new();
+        for decl in program {
+            if let Decl::Typedef { name, type_params, target, .. } = decl {
+                if !type_params.is_empty() {
+                    aliases.insert(name.as_str(), (type_params.as_slice(), target));
+                }
+            } else if let Some(name) = template_name(decl) {
+                templates.insert(name, decl);
+            }
+        }
+        Self { templates, aliases, instances, queue: VecDeque::new(), requested: HashSet::new(), emitted: HashMap::new() }
+    }
+
+    // `name<args>` örneğini ister ve örneğin adını döner. Her örnek bir kez üretilir.
+    fn request(&mut self, name: &str, args: Vec<Type>, span: Span) -> Result<String, Diagnostic> {
+        let mangled = mangle(name, &args);
+        if self.requested.insert(mangled.clone()) {
+            if self.requested.len() > INSTANCE_LIMIT {
+                return Err(mono_error(
+                    format!("Hata: Jenerik örnekleme sınırı aşıldı ({} örnek); '{}' kendini sürekli yeni tip argümanlarıyla örnekliyor olabilir.", INSTANCE_LIMIT, name),
+                    span,
+                ));
+            }
+            self.queue.push_back((name.to_string(), args, span));
+        }
+        Ok(mangled)
+    }
+
+    // İstenen örnekleri üretir; örneklerin gövdeleri yeni örnekler isteyebilir.
+    fn drain(&mut self) -> Result<(), Diagnostic> {
+        while let Some((name, args, span)) = self.queue.pop_front() {
+            let Some(template) = self.templates.get(name.as_str()).copied() else {
+                return Err(mono_error(format!("Hata: '{}' için jenerik tanım bulunamadı.", name), span));
+            };
+            let mangled = mangle(&name, &args);
+            let mut instance = template.clone();
+            let subst: Subst = match &instance {
+                Decl::Function { type_params, .. } | Decl::Struct { type_params, .. } | Decl::Group { type_params, .. } => {
+                    type_params.iter().map(|p| p.name.clone()).zip(args).collect()
+                }
+                _ => Subst::new(),
+            };
+            match &mut instance {
+                Decl::Function { name, type_params, .. } | Decl::Struct { name, type_params, .. } | Decl::Group { name, type_params, .. } => {
+                    *name = mangled;
+                    type_params.clear();
+                }
+                _ => {}
+            }
+            self.decl(&mut instance, &subst)?;
+            self.emitted.entry(name).or_default().push(instance);
+        }
+        Ok(())
+    }
+
+    // Jenerik grubun fonksiyon dışındaki üyeleri (sabitler) grubun kendi adıyla erişilebilir kalır.
+    fn group_constants(&mut self, decl: &Decl) -> Result<Option<Decl>, Diagnostic> {
+        let mut decl = decl.clone();
+        if let Decl::Group { type_params, body, .. } = &mut decl {
+            type_params.clear();
+            body.retain(|member| !matches!(member, Decl::Function { .. }));
+            if body.is_empty() {
+                return Ok(None);
+            }
+        }
+        self.decl(&mut decl, &Subst::new())?;
+        Ok(Some(decl))
+    }
+
+    fn decl(&mut self, decl: &mut Decl, subst: &Subst) -> Result<(), Diagnostic> {
+        let span = decl.span();
+        match decl {
+            Decl::Function { params, return_type, body, .. } => {
+                self.params(params, subst, span)?;
+                *return_type = self.ty(return_type, subst, span)?;
+                self.stmt(body, subst)
+            }
+            Decl::ExternFn { params, return_type, .. } => {
+                self.params(params, subst, span)?;
+                *return_type = self.ty(return_type, subst, span)?;
+                Ok(())
+            }
+            Decl::Group { params, return_type, body, .. } => {
+                self.params(params, subst, span)?;
+                *return_type = self.ty(return_type, subst, span)?;
+                body.iter_mut().try_for_each(|member| self.decl(member, subst))
+            }
+            Decl::Struct { fields, .. } => {
+                for (_, field_type) in fields {
+                    *field_type = self.ty(field_type, subst, span)?;
+                }
+                Ok(())
+            }
+            Decl::Typedef { target, .. } => {
+                *target = self.ty(target, subst, span)?;
+                Ok(())
+            }
+            Decl::Program(decls) => decls.iter_mut().try_for_each(|d| self.decl(d, subst)),
+            Decl::StmtDecl(stmt) => self.stmt(stmt, subst),
+            Decl::Enum { variants, .. } => variants.iter_mut().filter_map(|(_, value)| value.as_mut()).try_for_each(|e| self.expr(e, subst)),
+            Decl::Module(_) | Decl::Use { .. } | Decl::Style { .. } => Ok(()),
+        }
+    }
+
+    fn params(&mut self, params: &mut [(String, Type, Option<Expr>)], subst: &Subst, span: Span) -> Result<(), Diagnostic> {
+        for (_, param_type, default) in params {
+            *param_type = self.ty(param_type, subst, span)?;
+            if let Some(default) = default {
+                self.expr(default, subst)?;
+            }
+        }
+        Ok(())
+    }
+
+    // Tip parametrelerini yerine koyar, jenerik typedef'leri açar ve jenerik struct örneklerini ister.
+    fn ty(&mut self, ty: &Type, subst: &Subst, span: Span) -> Result<Type, Diagnostic> {
+        Ok(match ty {
+            Type::Param(name) => subst.get(name).cloned().unwrap_or_else(|| ty.clone()),
+            Type::Generic(name, args) => {
+                let args = args.iter().map(|arg| self.ty(arg, subst, span)).collect::<Result<Vec<_>, _>>()?;
+                if let Some((params, target)) = self.aliases.get(name.as_str()).copied() {
+                    let bindings: Subst = params.iter().map(|p| p.name.clone()).zip(args).collect();
+                    let target = target.substitute(&bindings);
+                    self.ty(&target, &Subst::new(), span)?
+                } else {
+                    Type::Custom(self.request(name, args, span)?)
+                }
+            }
+            Type::Tuple(types) => Type::Tuple(types.iter().map(|t| self.ty(t, subst, span)).collect::<Result<_, _>>()?),
+            Type::ArrayLiteral(types) => Type::ArrayLiteral(types.iter().map(|t| self.ty(t, subst, span)).collect::<Result<_, _>>()?),
+            Type::Array(inner, size) => Type::Array(Box::new(self.ty(inner, subst, span)?), *size),
+            Type::Ptr(inner) => Type::Ptr(Box::new(self.ty(inner, subst, span)?)),
+            Type::Ref(inner) => Type::Ref(Box::new(self.ty(inner, subst, span)?)),
+            Type::Option(inner) => Type::Option(Box::new(self.ty(inner, subst, span)?)),
+            Type::Future(inner) => Type::Future(Box::new(self.ty(inner, subst, span)?)),
+            Type::Channel(inner) => Type::Channel(Box::new(self.ty(inner, subst, span)?)),
+            Type::Result(ok, err) => Type::Result(Box::new(self.ty(ok, subst, span)?), Box::new(self.ty(err, subst, span)?)),
+            Type::Fn(params, ret) => Type::Fn(
+                params.iter().map(|t| self.ty(t, subst, span)).collect::<Result<_, _>>()?,
+                Box::new(self.ty(ret, subst, span)?),
+            ),
+            _ => ty.clone(),
+        })
+    }
+
+    // Bu ifade için kaydedilmiş, `key` öğesine ait örneğin tip argümanları (içinde bulunulan örneğin
+    // tip argümanları yerine konmuş olarak).
+    fn instance_args(&mut self, span: Span, key: &str, subst: &Subst) -> Result<Option<Vec<Type>>, Diagnostic> {
+        match self.instances.get(&span) {
+            Some((recorded, args)) if recorded == key => {
+                Ok(Some(args.iter().map(|arg| self.ty(arg, subst, span)).collect::<Result<_, _>>()?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn stmt(&mut self, stmt: &mut Stmt, subst: &Subst) -> Result<(), Diagnostic> {
+        let span = stmt.span;
+        match &mut stmt.kind {
+            StmtKind::VarDecl { ty, init, .. } => {
+                *ty = self.ty(ty, subst, span)?;
+                init.iter_mut().try_for_each(|e| self.expr(e, subst))
+            }
+            StmtKind::Assign { left, value } => {
+                self.expr(left, subst)?;
+                self.expr(value, subst)
+            }
+            StmtKind::Block(stmts) => stmts.iter_mut().try_for_each(|s| self.stmt(s, subst)),
+            StmtKind::If { cond, then_branch, else_branch } => {
+                self.expr(cond, subst)?;
+                self.stmt(then_branch, subst)?;
+                else_branch.iter_mut().try_for_each(|s| self.stmt(s, subst))
+            }
+            StmtKind::Return(value) => value.iter_mut().try_for_each(|e| self.expr(e, subst)),
+            StmtKind::ExprStmt(expr) | StmtKind::Echo(expr) | StmtKind::LabeledExpr { expr, .. } => self.expr(expr, subst),
+            StmtKind::Routine(expr) => self.expr(expr, subst),
+            StmtKind::While { condition, body } => {
+                self.expr(condition, subst)?;
+                self.stmt(body, subst)
+            }
+            StmtKind::For { initializer, condition, increment, iterable, body, .. } => {
+                initializer.iter_mut().try_for_each(|s| self.stmt(s, subst))?;
+                condition.iter_mut().chain(increment.iter_mut()).chain(iterable.iter_mut()).try_for_each(|e| self.expr(e, subst))?;
+                self.stmt(body, subst)
+            }
+            StmtKind::Loop { body }
+            | StmtKind::Tag { body, .. }
+            | StmtKind::LabeledStmt { stmt: body, .. }
+            | StmtKind::Unsafe(body)
+            | StmtKind::FastExec(body)
+            | StmtKind::Defer(body) => self.stmt(body, subst),
+            StmtKind::Break | StmtKind::Continue | StmtKind::Empty | StmtKind::Rolling(_) | StmtKind::Asm { .. } => Ok(()),
+        }
+    }
+
+    fn expr(&mut self, expr: &mut Expr, subst: &Subst) -> Result<(), Diagnostic> {
+        let span = expr.span;
+        match &mut expr.kind {
+            ExprKind::Call { callee, args } => {
+                // Jenerik fonksiyon çağrısı `f(..)` -> `f__T(..)`; jenerik grup üyesi `G.f(..)` -> `G__T.f(..)`.
+                match &mut callee.kind {
+                    ExprKind::Variable(name) => {
+                        if let Some(type_args) = self.instance_args(span, name, subst)? {
+                            *name = self.request(name, type_args, span)?;
+                        }
+                    }
+                    ExprKind::MemberAccess { object, member } => {
+                        if let ExprKind::Variable(group) = &mut object.kind {
+                            let key = format!("{}.{}", group, member);
+                            if let Some(type_args) = self.instance_args(span, &key, subst)? {
+                                *group = self.request(group, type_args, span)?;
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+                self.expr(callee, subst)?;
+                args.iter_mut().try_for_each(|(_, arg)| self.expr(arg, subst))
+            }
+            ExprKind::StructLiteral { name, type_args: explicit_args, fields } => {
+                if let Some(type_args) = self.instance_args(span, name, subst)? {
+                    *name = self.request(name, type_args, span)?;
+                    explicit_args.clear();
+                }
+                fields.iter_mut().try_for_each(|(_, field)| self.expr(field, subst))
+            }
+            ExprKind::Lambda { params, return_type, body } => {
+                self.params(params, subst, span)?;
+                *return_type = self.ty(return_type, subst, span)?;
+                self.expr(body, subst)
+            }
+            ExprKind::SizeOf(ty) => {
+                *ty = self.ty(ty, subst, span)?;
+                Ok(())
+            }
+            ExprKind::Tuple(items) | ExprKind::ArrayLiteral(items) | ExprKind::InterpolatedString(items) => {
+                items.iter_mut().try_for_each(|e| self.expr(e, subst))
+            }
+            ExprKind::Match { discriminant, cases } => {
+                self.expr(discriminant, subst)?;
+                for (pattern, result) in cases {
+                    self.expr(pattern, subst)?;
+                    self.expr(result, subst)?;
+                }
+                Ok(())
+            }
+            ExprKind::Input(prompt) => prompt.iter_mut().try_for_each(|e| self.expr(e, subst)),
+            ExprKind::Block { statements } => statements.iter_mut().try_for_each(|s| self.stmt(s, subst)),
+            ExprKind::ArrayAccess { index, .. } => self.expr(index, subst),
+            ExprKind::MemberAccess { object, .. } => self.expr(object, subst),
+            ExprKind::Unary { right: inner, .. } | ExprKind::Await(inner) | ExprKind::Try(inner) | ExprKind::Recv(inner) => self.expr(inner, subst),
+            ExprKind::Range { start: left, end: right }
+            | ExprKind::Binary { left, right, .. }
+            | ExprKind::Assign { left, value: right }
+            | ExprKind::Send { channel: left, value: right } => {
+                self.expr(left, subst)?;
+                self.expr(right, subst)
+            }
+            ExprKind::Conditional { cond, then_branch, else_branch } => {
+                self.expr(cond, subst)?;
+                self.expr(then_branch, subst)?;
+                self.expr(else_branch, subst)
+            }
+            ExprKind::Literal(_) | ExprKind::Variable(_) | ExprKind::DefaultCase | ExprKind::EnumAccess { .. } => Ok(()),
+        }
+    }
+}
@@ -1,17 +1,22 @@
 // src/parser.rs 
 use crate::token::{Token, TokenType};
-use crate::ast::{Decl, Stmt, Expr, Type, BinOp, UnOp, LiteralValue}; 
+use crate::ast::{Decl, Stmt, StmtKind, Expr, ExprKind, Type, TypeParam, BinOp, UnOp, LiteralValue}; 
+use crate::diagnostic::{Diagnostic, Span, Stage, E_SYNTAX};
 
 pub struct Parser {
     tokens: Vec<Token>,
     current: usize,
     scope_depth: u32,
-    errors: Vec<String>,
+    errors: Vec<Diagnostic>,
+    // YENİ: `match x { ... }` gibi '{' ile devam eden bağlamlarda `x {` struct literal'ı sayılmaz.
+    no_struct_literal: bool,
+    // YENİ: Ayrıştırılan jenerik bildirimlerin tip parametresi adları; tip konumunda `Type::Param` olurlar.
+    generic_scope: Vec<String>,
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Self {
-        Self { tokens, current: 0, scope_depth: 0, errors: Vec::new() }
+        Self { tokens, current: 0, scope_depth: 0, errors: Vec::new(), no_struct_literal: false, generic_scope: Vec::new() }
     }
 
     // --- Yardımcı Fonksiyonlar ---
@@ -39,7 +44,7 @@ impl Parser {
         if self.is_at_end() { 
             return false; 
         }
-        return self.tokens[self.current].kind == *kind; 
+        self.tokens[self.current].kind == *kind
     }
 
     fn advance(&mut self) -> &Token {
@@ -53,13 +58,38 @@ impl Parser {
         if self.check(&expected) {
             return self.advance();
         }
-        let current_token = &self.tokens[self.current];
-        let error_msg = format!("Sözdizimi Hatası (Satır {}): {}, Beklenen: {:?}, Bulunan: {:?}", 
-               current_token.line, message, expected, current_token.kind);
-        self.errors.push(error_msg);
-        current_token
+        // Dosya sonunda (Eof tüketilmiş olsa bile) son token'a işaret edilir.
+        let current_token = self.peek();
+        let span = current_token.span;
+        let error_msg = format!("Sözdizimi Hatası: {}, Beklenen: {:?}, Bulunan: {:?}", message, expected, current_token.kind);
+        self.error_at(span, error_msg);
+        self.peek()
     }
     
+    // `pub method => ...` veya `method => ...` ile başlayan bir üye mi?
+    fn at_labeled_member(&self) -> bool {
+        let ident_pos = if self.check(&TokenType::Pub) { 1 } else { 0 };
+        self.tokens.get(self.current + ident_pos).is_some_and(|t| matches!(t.kind, TokenType::Ident(_)))
+            && self.tokens.get(self.current + ident_pos + 1).is_some_and(|t| t.kind == TokenType::FatArrow)
+    }
+
+    // Son tüketilen token'ın konumu.
+    fn prev_span(&self) -> Span {
+        match self.current.checked_sub(1).and_then(|i| self.tokens.get(i)) {
+            Some(token) => token.span,
+            None => self.peek().span,
+        }
+    }
+
+    // `start` token'ından son tüketilen token'a kadar olan aralık.
+    fn span_from(&self, start: Span) -> Span {
+        start.to(self.prev_span())
+    }
+
+    fn error_at(&mut self, span: Span, message: String) {
+        self.errors.push(Diagnostic::error(message).with_stage(Stage::Parser).with_code(E_SYNTAX).with_label(span, ""));
+    }
+
     fn check_next(&self, kind: &TokenType) -> bool {
         if self.current + 1 >= self.tokens.len() {
             return false;
@@ -67,9 +97,58 @@ impl Parser {
         self.tokens[self.current + 1].kind == *kind
     }
 
+    // YENİ: Tip argüman listesini kapatan '>'. İç içe listelerde (`Kutu<Kutu<i32>>`) lexer'ın ürettiği
+    // '>>' token'ının yalnızca ilk yarısı tüketilir.
+    fn consume_type_close(&mut self) {
+        if self.check(&TokenType::RShift) {
+            self.tokens[self.current].kind = TokenType::Gt;
+            return;
+        }
+        self.consume(TokenType::Gt, "'>' bekleniyor");
+    }
+
+    // YENİ: `<T, U: Num + Ord>` tip parametre listesi (liste yoksa boş döner).
+    fn parse_type_params(&mut self) -> Vec<TypeParam> {
+        let mut params = Vec::new();
+        if !self.check(&TokenType::Lt) {
+            return params;
+        }
+        self.advance();
+        while !self.check(&TokenType::Gt) && !self.is_at_end() {
+            let TokenType::Ident(name) = self.peek_kind() else {
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Tip parametresi adı bekleniyor.".to_string());
+                break;
+            };
+            self.advance();
+            let mut bounds = Vec::new();
+            if self.check(&TokenType::Colon) {
+                self.advance();
+                loop {
+                    let TokenType::Ident(bound) = self.peek_kind() else {
+                        self.error_at(self.peek().span, "Sözdizimi Hatası: Tip sınırı adı bekleniyor.".to_string());
+                        break;
+                    };
+                    self.advance();
+                    bounds.push(bound);
+                    if !self.check(&TokenType::Plus) {
+                        break;
+                    }
+                    self.advance();
+                }
+            }
+            params.push(TypeParam { name, bounds });
+            if !self.check(&TokenType::Comma) {
+                break;
+            }
+            self.advance();
+        }
+        self.consume(TokenType::Gt, "Tip parametre listesini kapatmak için '>' bekleniyor.");
+        params
+    }
+
     // --- Ana Giriş Noktası ---
 
-    pub fn parse(&mut self) -> (Decl, Vec<String>) { 
+    pub fn parse(&mut self) -> (Decl, Vec<Diagnostic>) { 
         let mut declarations = Vec::new();
         
         while !self.is_at_end() {
@@ -86,7 +165,7 @@ impl Parser {
             // Eğer parse_declaration çağrısından sonra hiç ilerleme kaydedilmediyse,
             // bu bir döngüye girdiğimiz anlamına gelir. Döngüyü manuel olarak kır.
             if self.current == start_pos {
-                self.errors.push(format!("İç Hata (Satır {}): Ayrıştırıcı beklenmedik bir token üzerinde takılı kaldı: {:?}. İlerleme sağlanamıyor.", self.peek().line, self.peek_kind()));
+                self.error_at(self.peek().span, format!("İç Hata: Ayrıştırıcı beklenmedik bir token üzerinde takılı kaldı: {:?}. İlerleme sağlanamıyor.", self.peek_kind()));
                 self.advance(); 
             }
         }
@@ -97,7 +176,7 @@ impl Parser {
     fn synchronize(&mut self) {
         while !self.is_at_end() {
             // Bir önceki token noktalı virgül ise, muhtemelen bir deyimin sonundayız.
-            if self.tokens[self.current - 1].kind == TokenType::Semi {
+            if self.current > 0 && self.tokens[self.current - 1].kind == TokenType::Semi {
                 return;
             }
             // Bir sonraki token yeni bir deyim başlatıyor olabilir.
@@ -108,10 +187,12 @@ impl Parser {
         }
     }
 
-    #[allow(dead_code)]
     // --- Declarations (Tanımlamalar) ---
-
+    #[allow(dead_code)]
     fn parse_declaration(&mut self) -> Option<Decl> {
+        // YENİ: Bildirimin ilk token'ına eklenmiş doküman yorumu (bkz. Lexer::skip_whitespace).
+        let doc = self.peek().doc.clone();
+
         // 'export' anahtar kelimesini kontrol et
         let is_export = if self.check(&TokenType::Export) {
             self.advance(); // 'export' token'ını tüket
@@ -129,7 +210,7 @@ impl Parser {
             false
         };
 
-        let result = if self.check(&TokenType::Fn) || self.check(&TokenType::Async) {
+        let result = if self.check(&TokenType::Fn) || self.check(&TokenType::Async) || self.check(&TokenType::Inline) {
             Some(self.parse_function(is_export, is_public))
         } else if self.check(&TokenType::Var) || self.check(&TokenType::Const) || self.check(&TokenType::Let) {
             let var_stmt = self.parse_var_decl_logic(is_public);
@@ -151,7 +232,7 @@ impl Parser {
             Some(self.parse_style_decl())
         } else if self.check(&TokenType::RBrace) {
             // Global alanda beklenmedik bir '}' varsa, hata ver ve tüket.
-            self.errors.push(format!("Sözdizimi Hatası (Satır {}): Global alanda beklenmedik '}}'.", self.peek().line));
+            self.error_at(self.peek().span, "Sözdizimi Hatası: Global alanda beklenmedik '}'.".to_string());
             self.advance();
             None // Hata sonrası AST'ye bir şey ekleme.
         } else {
@@ -160,8 +241,9 @@ impl Parser {
             // etmesinden kaynaklanıyor olabilir. Bu yüzden burada tekrar hata üretme.
             if self.scope_depth == 0 {
                 let bad_token = self.peek();
-                let error_msg = format!("Sözdizimi Hatası (Satır {}): '{{' eksik olabilir. Bulunan: {:?}", bad_token.line, bad_token.kind);
-                self.errors.push(error_msg);
+                let span = bad_token.span;
+                let error_msg = format!("Sözdizimi Hatası: '{{' eksik olabilir. Bulunan: {:?}", bad_token.kind);
+                self.error_at(span, error_msg);
 
                 // Hata sonrası kurtarma: Bir sonraki üst seviye bildirime kadar atla.
                 while !self.is_at_end() {
@@ -176,17 +258,18 @@ impl Parser {
             }
             None // Hata sonrası AST'ye bir şey ekleme.
         };
-        result
+        result.map(|decl| with_doc(decl, doc))
     }
 
     fn parse_style_decl(&mut self) -> Decl {
+        let start_span = self.peek().span;
         self.consume(TokenType::Style, "'style' bekleniyor");
         let name_token = self.advance().clone();
         
         let name = match name_token.kind {
             TokenType::Ident(n) => n,
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Stil adı bekleniyor.", name_token.line));
+                self.error_at(name_token.span, "Sözdizimi Hatası: Stil adı bekleniyor.".to_string());
                 "unknown".to_string()
             }
         };
@@ -198,17 +281,18 @@ impl Parser {
         let code = match value_token.kind {
             TokenType::StrLit(s) => s,
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Stil tanımı için string bekleniyor.", value_token.line));
+                self.error_at(value_token.span, "Sözdizimi Hatası: Stil tanımı için string bekleniyor.".to_string());
                 "".to_string()
             }
         };
         
         self.consume(TokenType::Semi, "';' bekleniyor");
         
-        Decl::Style { name, code }
+        Decl::Style { name, code, span: self.span_from(start_span) }
     }
 
     fn parse_function(&mut self, is_export: bool, is_public_decl: bool) -> Decl {
+        let start_span = self.peek().span;
         // `pub` veya `export` varsa, fonksiyon public'tir.
         let is_public = is_public_decl || is_export;
 
@@ -231,13 +315,17 @@ impl Parser {
         let name = match self.advance().kind.clone() {
             TokenType::Ident(n) => n,
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Fonksiyon adı bekleniyor.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Fonksiyon adı bekleniyor.".to_string());
                 // Hata durumunda senkronize et ve boş bir bildirim döndür.
                 self.synchronize();
-                return Decl::StmtDecl(Box::new(Stmt::Empty)); // Bu özel durum kalabilir.
+                return Decl::StmtDecl(Box::new(Stmt::new(StmtKind::Empty, self.span_from(start_span)))); // Bu özel durum kalabilir.
             }
         };
 
+        let type_params = self.parse_type_params();
+        let scope_len = self.generic_scope.len();
+        self.generic_scope.extend(type_params.iter().map(|p| p.name.clone()));
+
         let mut return_type = Type::Void; 
 
         if self.check(&TokenType::Colon) {
@@ -255,7 +343,8 @@ impl Parser {
         }
         
         let body = self.parse_block(); 
-        Decl::Function { name, params, return_type, body, is_inline, is_async, is_public }
+        self.generic_scope.truncate(scope_len);
+        Decl::Function { name, type_params, params, return_type, body, is_inline, is_async, is_public, doc: None, span: self.span_from(start_span) }
     }
 
     fn parse_function_params(&mut self) -> Vec<(String, Type, Option<Expr>)> {
@@ -287,17 +376,22 @@ impl Parser {
     }
 
     fn parse_group_decl(&mut self, is_export: bool, _is_public_decl: bool) -> Decl {
+        let start_span = self.peek().span;
         self.consume(TokenType::Group, "'group' bekleniyor");
 
         let name = match self.advance().kind.clone() {
             TokenType::Ident(n) => n,
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Grup adı bekleniyor.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Grup adı bekleniyor.".to_string());
                 self.synchronize();
-                return Decl::StmtDecl(Box::new(Stmt::Empty)); // Bu özel durum kalabilir.
+                return Decl::StmtDecl(Box::new(Stmt::new(StmtKind::Empty, self.span_from(start_span)))); // Bu özel durum kalabilir.
             }
         };
 
+        let type_params = self.parse_type_params();
+        let scope_len = self.generic_scope.len();
+        self.generic_scope.extend(type_params.iter().map(|p| p.name.clone()));
+
         let mut params = Vec::new();
         let mut return_type = Type::Void;
 
@@ -320,7 +414,11 @@ impl Parser {
         self.consume(TokenType::LBrace, "Grup gövdesi için '{' bekleniyor.");
         let mut body_decls = Vec::new();
         while !self.check(&TokenType::RBrace) && !self.is_at_end() {
-            if let Some(decl) = self.parse_declaration() {
+            // `[pub] ad => ...` üyeleri (metotlar) bildirim değil, etiketli deyimdir.
+            if self.at_labeled_member() {
+                let stmt = self.parse_statement();
+                body_decls.push(Decl::StmtDecl(Box::new(stmt)));
+            } else if let Some(decl) = self.parse_declaration() {
                 body_decls.push(decl);
             } else {
                 // Hata durumunda ilerleme sağlamak için
@@ -328,13 +426,16 @@ impl Parser {
             }
         }
         self.consume(TokenType::RBrace, "Grup gövdesini kapatmak için '}' bekleniyor.");
+        self.generic_scope.truncate(scope_len);
 
         Decl::Group {
-            name, is_export, params, return_type, body: body_decls,
+            name, type_params, is_export, params, return_type, body: body_decls, doc: None,
+            span: self.span_from(start_span),
         }
     }
 
     fn parse_struct_decl(&mut self, is_export: bool, is_public_decl: bool) -> Decl {
+        let start_span = self.peek().span;
         let is_public = is_public_decl || is_export;
 
         self.consume(TokenType::Struct, "'struct' bekleniyor.");
@@ -344,12 +445,16 @@ impl Parser {
                 n
             }
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Struct adı bekleniyor.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Struct adı bekleniyor.".to_string());
                 self.synchronize();
-                return Decl::StmtDecl(Box::new(Stmt::Empty));
+                return Decl::StmtDecl(Box::new(Stmt::new(StmtKind::Empty, self.span_from(start_span))));
             }
         };
 
+        let type_params = self.parse_type_params();
+        let scope_len = self.generic_scope.len();
+        self.generic_scope.extend(type_params.iter().map(|p| p.name.clone()));
+
         self.consume(TokenType::LBrace, "Struct gövdesi için '{' bekleniyor.");
 
         let mut fields = Vec::new();
@@ -363,16 +468,18 @@ impl Parser {
                 fields.push((field_name, field_type));
                 self.consume(TokenType::Semi, "Struct alanı tanımından sonra ';' bekleniyor.");
             } else {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Struct içinde alan adı bekleniyor.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Struct içinde alan adı bekleniyor.".to_string());
                 self.synchronize();
                 break;
             }
         }
         self.consume(TokenType::RBrace, "Struct gövdesini kapatmak için '}' bekleniyor.");
-        Decl::Struct { name, fields, is_public }
+        self.generic_scope.truncate(scope_len);
+        Decl::Struct { name, type_params, fields, is_public, doc: None, span: self.span_from(start_span) }
     }
 
     fn parse_typedef_decl(&mut self, is_public_decl: bool) -> Decl {
+        let start_span = self.peek().span;
         let is_public = is_public_decl;
 
         self.consume(TokenType::Typedef, "'typedef' bekleniyor.");
@@ -382,12 +489,16 @@ impl Parser {
                 n
             }
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Tip takma adı bekleniyor.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Tip takma adı bekleniyor.".to_string());
                 self.synchronize();
-                return Decl::StmtDecl(Box::new(Stmt::Empty));
+                return Decl::StmtDecl(Box::new(Stmt::new(StmtKind::Empty, self.span_from(start_span))));
             }
         };
 
+        let type_params = self.parse_type_params();
+        let scope_len = self.generic_scope.len();
+        self.generic_scope.extend(type_params.iter().map(|p| p.name.clone()));
+
         // YENİ: Dizi takma adı için '[]' kontrolü. Örn: typedef MyIntArray[]: i32;
         let is_array_alias = if self.check(&TokenType::LBracket) {
             self.advance();
@@ -408,12 +519,14 @@ impl Parser {
             base_type
         };
 
+        self.generic_scope.truncate(scope_len);
         self.consume(TokenType::Semi, "';' bekleniyor.");
 
-        Decl::Typedef { name: alias_name, target: final_target_type, is_public }
+        Decl::Typedef { name: alias_name, type_params, target: final_target_type, is_public, doc: None, span: self.span_from(start_span) }
     }
 
     fn parse_enum_decl(&mut self, is_export: bool, is_public_decl: bool) -> Decl {
+        let start_span = self.peek().span;
         let is_public = is_public_decl || is_export;
 
         self.consume(TokenType::Enum, "'enum' bekleniyor.");
@@ -423,9 +536,9 @@ impl Parser {
                 n
             }
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Enum adı bekleniyor.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Enum adı bekleniyor.".to_string());
                 self.synchronize();
-                return Decl::StmtDecl(Box::new(Stmt::Empty));
+                return Decl::StmtDecl(Box::new(Stmt::new(StmtKind::Empty, self.span_from(start_span))));
             }
         };
 
@@ -439,7 +552,7 @@ impl Parser {
                     n
                 }
                 _ => {
-                    self.errors.push(format!("Sözdizimi Hatası (Satır {}): Enum üyesi adı bekleniyor.", self.peek().line));
+                    self.error_at(self.peek().span, "Sözdizimi Hatası: Enum üyesi adı bekleniyor.".to_string());
                     break;
                 }
             };
@@ -454,10 +567,11 @@ impl Parser {
             if self.check(&TokenType::Comma) { self.advance(); }
         }
         self.consume(TokenType::RBrace, "Enum gövdesini kapatmak için '}' bekleniyor.");
-        Decl::Enum { name, variants, is_public }
+        Decl::Enum { name, variants, is_public, doc: None, span: self.span_from(start_span) }
     }
 
     fn parse_use_decl(&mut self, is_export: bool, _is_public_decl: bool) -> Decl {
+        let start_span = self.peek().span;
         self.consume(TokenType::Use, "'use' bekleniyor.");
 
         let mut path = Vec::new();
@@ -477,9 +591,9 @@ impl Parser {
             } else {
                 // Eğer yolun başında bir tanımlayıcı, 'self' veya 'super' yoksa, hata ver.
                 if path.is_empty() {
-                    self.errors.push(format!("Sözdizimi Hatası (Satır {}): 'use' bildiriminde yol bölümü (identifier, 'self' veya 'super') bekleniyor.", self.peek().line));
+                    self.error_at(self.peek().span, "Sözdizimi Hatası: 'use' bildiriminde yol bölümü (identifier, 'self' veya 'super') bekleniyor.".to_string());
                     self.synchronize();
-                    return Decl::StmtDecl(Box::new(Stmt::Empty));
+                    return Decl::StmtDecl(Box::new(Stmt::new(StmtKind::Empty, self.span_from(start_span))));
                 }
                 break; // Yolun sonuna geldik.
             }
@@ -507,13 +621,13 @@ impl Parser {
                                     self.advance(); // takma adı tüket
                                     item_list.push(crate::ast::UseSpecItem::RenamedItem(item_name, alias));
                                 } else {
-                                    self.errors.push(format!("Sözdizimi Hatası (Satır {}): 'as' anahtar kelimesinden sonra bir takma ad bekleniyor.", self.peek().line));
+                                    self.error_at(self.peek().span, "Sözdizimi Hatası: 'as' anahtar kelimesinden sonra bir takma ad bekleniyor.".to_string());
                                 }
                             } else {
                                 item_list.push(crate::ast::UseSpecItem::Item(item_name));
                             }
                         } else {
-                            self.errors.push(format!("Sözdizimi Hatası (Satır {}): 'use' bloğu içinde öğe adı bekleniyor.", self.peek().line));
+                            self.error_at(self.peek().span, "Sözdizimi Hatası: 'use' bloğu içinde öğe adı bekleniyor.".to_string());
                             break;
                         }
                         if self.check(&TokenType::Comma) {
@@ -541,13 +655,13 @@ impl Parser {
                 self.advance(); // takma adı tüket
                 spec = crate::ast::UseSpec::All(Some(alias));
             } else {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): 'as' anahtar kelimesinden sonra bir modül takma adı bekleniyor.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: 'as' anahtar kelimesinden sonra bir modül takma adı bekleniyor.".to_string());
             }
         }
 
         self.consume(TokenType::Semi, "'use' bildiriminden sonra ';' bekleniyor.");
 
-        Decl::Use { path, spec, is_export }
+        Decl::Use { path, spec, is_export, span: self.span_from(start_span) }
     }
 
     // YENİ: Sadece `extern fn` için, parametre isimlerini zorunlu kılmayan ayrıştırıcı.
@@ -574,6 +688,7 @@ impl Parser {
     }
     // YENİ: `extern` bildirimlerini ayrıştırır.
     fn parse_extern_decl(&mut self, is_public_decl: bool, is_export: bool) -> Decl {
+        let start_span = self.peek().span;
         self.consume(TokenType::Extern, "'extern' bekleniyor.");
 
         let is_public = is_public_decl || is_export;
@@ -584,9 +699,9 @@ impl Parser {
             let name = match self.advance().kind.clone() {
                 TokenType::Ident(n) => n,
                 _ => {
-                    self.errors.push(format!("Sözdizimi Hatası (Satır {}): Dış fonksiyon adı bekleniyor.", self.peek().line));
+                    self.error_at(self.peek().span, "Sözdizimi Hatası: Dış fonksiyon adı bekleniyor.".to_string());
                     self.synchronize();
-                    return Decl::StmtDecl(Box::new(Stmt::Empty));
+                    return Decl::StmtDecl(Box::new(Stmt::new(StmtKind::Empty, self.span_from(start_span))));
                 }
             };
 
@@ -602,17 +717,18 @@ impl Parser {
 
             self.consume(TokenType::Semi, "Dış fonksiyon bildiriminden sonra ';' bekleniyor.");
 
-            Decl::ExternFn { name, params, return_type, is_public }
+            Decl::ExternFn { name, params, return_type, is_public, doc: None, span: self.span_from(start_span) }
         } else {
-            self.errors.push(format!("Sözdizimi Hatası (Satır {}): 'extern' sonrası sadece 'fn' desteklenmektedir.", self.peek().line));
+            self.error_at(self.peek().span, "Sözdizimi Hatası: 'extern' sonrası sadece 'fn' desteklenmektedir.".to_string());
             self.synchronize();
-            Decl::StmtDecl(Box::new(Stmt::Empty))
+            Decl::StmtDecl(Box::new(Stmt::new(StmtKind::Empty, self.span_from(start_span))))
         }
     }
 
     // --- Statements (Deyimler) ---
 
     fn parse_statement(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         match self.peek_kind() {
             TokenType::Var | TokenType::Const | TokenType::Mut => self.parse_var_decl(), 
             TokenType::If => self.parse_if_stmt(),
@@ -631,7 +747,7 @@ impl Parser {
             TokenType::Break => {
                 self.advance();
                 self.consume(TokenType::Semi, "'break' deyiminden sonra ';' bekleniyor.");
-                Stmt::Break
+                Stmt::new(StmtKind::Break, self.span_from(start_span))
             },
             TokenType::RollingTag => {
                 self.advance();
@@ -640,27 +756,32 @@ impl Parser {
                     self.advance();
                     n
                 } else {
-                    self.errors.push(format!("Sözdizimi Hatası (Satır {}): Etiket adı bekleniyor.", self.peek().line));
+                    self.error_at(self.peek().span, "Sözdizimi Hatası: Etiket adı bekleniyor.".to_string());
                     "__invalid_tag__".to_string()
                 };
                 self.consume(TokenType::Semi, "';' bekleniyor");
-                Stmt::Rolling(tag)
+                Stmt::new(StmtKind::Rolling(tag), self.span_from(start_span))
             },
             TokenType::Routine => {
                 self.advance();
                 let call = self.parse_call();
                 self.consume(TokenType::Semi, "';' bekleniyor");
-                Stmt::Routine(Box::new(call))
+                Stmt::new(StmtKind::Routine(Box::new(call)), self.span_from(start_span))
             },
             TokenType::Unsafe => {
                 self.advance();
                 let block = self.parse_block();
-                Stmt::Unsafe(Box::new(block))
+                Stmt::new(StmtKind::Unsafe(Box::new(block)), self.span_from(start_span))
+            },
+            TokenType::Defer => {
+                self.advance();
+                let body = if self.check(&TokenType::LBrace) { self.parse_block() } else { self.parse_statement() };
+                Stmt::new(StmtKind::Defer(Box::new(body)), self.span_from(start_span))
             },
             TokenType::FastExec => {
                 self.advance();
                 let block = self.parse_block();
-                Stmt::FastExec(Box::new(block))
+                Stmt::new(StmtKind::FastExec(Box::new(block)), self.span_from(start_span))
             },
             TokenType::Asm => {
                 self.advance();
@@ -668,22 +789,23 @@ impl Parser {
                 let tag = match self.peek_kind() {
                     TokenType::Ident(n) => { self.advance(); n },
                     _ => {
-                        self.errors.push(format!("Sözdizimi Hatası (Satır {}): 'asm:' sonrası bir etiket (TAG) bekleniyor.", self.peek().line));
+                        self.error_at(self.peek().span, "Sözdizimi Hatası: 'asm:' sonrası bir etiket (TAG) bekleniyor.".to_string());
                         "__invalid_asm_tag__".to_string()
                     }
                 };
                 self.consume(TokenType::LBrace, "'asm' bloğu için '{' bekleniyor.");
-                // `asm` bloğunun içeriğini ham bir string olarak alacağız.
-                // Şimdilik basitçe bir sonraki '}' karakterine kadar olan her şeyi alıyoruz.
-                // Bu, daha sonra daha akıllı bir ayrıştırma gerektirebilir.
-                let body = "/* asm body placeholder */".to_string(); // TODO: Gerçek içeriği ayrıştır.
+                // Lexer, `asm: TAG {` sonrasındaki içeriği tek bir ham AsmBody token'ı olarak verir.
+                let body = match self.peek_kind() {
+                    TokenType::AsmBody(text) => { self.advance(); text },
+                    _ => String::new(),
+                };
                 self.consume(TokenType::RBrace, "'asm' bloğunu kapatmak için '}' bekleniyor.");
-                Stmt::Asm { tag, body }
+                Stmt::new(StmtKind::Asm { tag, body }, self.span_from(start_span))
             },
             TokenType::Continue => {
                 self.advance();
                 self.consume(TokenType::Semi, "'continue' deyiminden sonra ';' bekleniyor.");
-                Stmt::Continue
+                Stmt::new(StmtKind::Continue, self.span_from(start_span))
             },
 
             TokenType::Return => self.parse_return(),   
@@ -691,16 +813,13 @@ impl Parser {
             
             TokenType::Semi => {
                  self.advance();
-                Stmt::Empty 
+                Stmt::new(StmtKind::Empty, self.span_from(start_span)) 
             },
 
             // YENİ: `pub method => ...` veya `method => ...` yapılarını işle
-            _ if {
-                let is_pub = self.check(&TokenType::Pub);
-                let ident_pos = if is_pub { 1 } else { 0 };
-                self.tokens.get(self.current + ident_pos).map_or(false, |t| matches!(t.kind, TokenType::Ident(_))) &&
-                self.tokens.get(self.current + ident_pos + 1).map_or(false, |t| t.kind == TokenType::FatArrow)
-            } => {
+            _ if self.at_labeled_member() => {
+                 // Üyenin doküman yorumu ilk token'ına (`pub` veya ad) bağlıdır.
+                 let doc = self.peek().doc.clone();
                  let is_public = if self.check(&TokenType::Pub) {
                      self.advance();
                      true
@@ -718,12 +837,12 @@ impl Parser {
                  let next_stmt = if self.check(&TokenType::LBrace) {
                      self.parse_statement()
                  } else {
-                     Stmt::ExprStmt(self.parse_expression())
+                     Stmt::new(StmtKind::ExprStmt(self.parse_expression()), self.span_from(start_span))
                  };
 
                  // `is_public` alanını AST'ye ekle.
                  // NOT: Bu, `Stmt::LabeledStmt`'in `is_public: bool` alanına sahip olmasını gerektirir.
-                 Stmt::LabeledStmt { label, stmt: Box::new(next_stmt), is_public: is_public }
+                 Stmt::new(StmtKind::LabeledStmt { label, stmt: Box::new(next_stmt), is_public, doc }, self.span_from(start_span))
             },
             // Yukarıdaki özel durumlar (if, while, var vb.) dışındaki her şey
             // bir ifade deyimi olarak kabul edilir. Bu, atamaları, fonksiyon çağrılarını,
@@ -731,12 +850,13 @@ impl Parser {
             _ => {
                 let expr = self.parse_assignment_expression();
                 self.consume(TokenType::Semi, "İfade deyiminden sonra ';' bekleniyor");
-                Stmt::ExprStmt(expr)
+                Stmt::new(StmtKind::ExprStmt(expr), self.span_from(start_span))
             },
         }
     }
 
     fn parse_block(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         self.scope_depth += 1; // Kapsam derinliğini artır
         self.consume(TokenType::LBrace, "'{' bekleniyor");
         let mut stmts = Vec::new();
@@ -747,10 +867,11 @@ impl Parser {
         
         self.consume(TokenType::RBrace, "'}' bekleniyor");
         self.scope_depth -= 1; // Kapsam derinliğini azalt
-        Stmt::Block(stmts)
+        Stmt::new(StmtKind::Block(stmts), self.span_from(start_span))
     }
 
     fn parse_var_decl_logic(&mut self, is_public_decl: bool) -> Stmt {
+        let start_span = self.peek().span;
         let mut is_mutable = false; 
         let mut is_let = false;
         // `pub` anahtar kelimesi sadece global kapsamdaki `const` için anlamlıdır.
@@ -766,7 +887,7 @@ impl Parser {
             self.advance();
             // 'const' ile 'mut' birlikte kullanılamaz.
             if is_mutable {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Bir değişken hem 'mut' hem de 'const' olamaz.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Bir değişken hem 'mut' hem de 'const' olamaz.".to_string());
             }
             is_mutable = false; 
         } else if self.check(&TokenType::Let) {
@@ -782,9 +903,9 @@ impl Parser {
         let name = match self.advance().kind.clone() {
             TokenType::Ident(n) => n,
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Değişken adı bekleniyor.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: Değişken adı bekleniyor.".to_string());
                 self.synchronize();
-                return Stmt::Empty;
+                return Stmt::new(StmtKind::Empty, self.span_from(start_span));
             }
         };
 
@@ -796,10 +917,10 @@ impl Parser {
             // Eğer `[]` ise dinamik dizi, `[5]` ise sabit boyutlu dizidir.
             if !self.check(&TokenType::RBracket) {
                 let size_expr = self.parse_expression();
-                if let Expr::Literal(LiteralValue::Int(n)) = size_expr {
+                if let ExprKind::Literal(LiteralValue::Int(n)) = size_expr.kind {
                     array_size = Some(n as usize);
                 } else {
-                    self.errors.push(format!("Sözdizimi Hatası (Satır {}): Dizi boyutu bir tamsayı literali olmalıdır.", self.peek().line));
+                    self.error_at(self.peek().span, "Sözdizimi Hatası: Dizi boyutu bir tamsayı literali olmalıdır.".to_string());
                 }
             }
             self.consume(TokenType::RBracket, "Dizi tanımı için ']' bekleniyor.");
@@ -831,7 +952,7 @@ impl Parser {
             init = Some(self.parse_expression()); // Önceden `parse_precedence(0)` idi, `parse_expression` daha genel.
 
         }        
-        Stmt::VarDecl { 
+        Stmt::new(StmtKind::VarDecl { 
             name, 
             ty: var_type, 
             init, 
@@ -839,7 +960,7 @@ impl Parser {
             is_let,
             is_mutable,
             is_public,
-        }
+        }, self.span_from(start_span))
     }
 
     fn parse_var_decl(&mut self) -> Stmt {
@@ -849,14 +970,18 @@ impl Parser {
     }
 
     fn parse_match_stmt(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         let expr = self.parse_match_expr();
-        Stmt::ExprStmt(expr)
+        Stmt::new(StmtKind::ExprStmt(expr), self.span_from(start_span))
     }
     
     fn parse_match_expr(&mut self) -> Expr {
+        let start_span = self.peek().span;
         self.consume(TokenType::Match, "Match ifadesi 'match' ile başlamalı.");
 
+        let was_no_struct_literal = std::mem::replace(&mut self.no_struct_literal, true);
         let discriminant = Box::new(self.parse_expression());
+        self.no_struct_literal = was_no_struct_literal;
 
         self.consume(TokenType::LBrace, "Match ifadesinden sonra '{' bekleniyor.");
 
@@ -867,18 +992,17 @@ impl Parser {
             
             self.consume(TokenType::FatArrow, "Match durumundan sonra '=>' bekleniyor.");
             
-            let result: Box<Expr>;
-            if self.check(&TokenType::LBrace) {
-                result = Box::new(self.parse_block_expr()); 
+            let result: Box<Expr> = if self.check(&TokenType::LBrace) {
+                Box::new(self.parse_block_expr())
             } else {
-                result = Box::new(self.parse_expression()); 
-            }
+                Box::new(self.parse_expression())
+            };
             
             cases.push((pattern, result));
             if self.check(&TokenType::Comma) {
                 self.advance();
             } else if !self.check(&TokenType::RBrace) {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Match kollarını ayırmak için ',' veya '}}' bekleniyor. Bulunan token: {:?}", self.peek().line, self.peek_kind()));
+                self.error_at(self.peek().span, format!("Sözdizimi Hatası: Match kollarını ayırmak için ',' veya '}}' bekleniyor. Bulunan token: {:?}", self.peek_kind()));
                 self.synchronize();
                 break;
             }
@@ -886,10 +1010,11 @@ impl Parser {
 
         self.consume(TokenType::RBrace, "Match ifadesi '}' ile bitmeli.");
 
-        Expr::Match { discriminant, cases }
+        Expr::new(ExprKind::Match { discriminant, cases }, self.span_from(start_span))
     }
 
     fn parse_while_stmt(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         self.advance(); 
 
         self.consume(TokenType::LParen, "'while' döngüsü için '(' bekleniyor");
@@ -898,16 +1023,18 @@ impl Parser {
 
         let body = Box::new(self.parse_block()); 
 
-        Stmt::While { condition, body }
+        Stmt::new(StmtKind::While { condition, body }, self.span_from(start_span))
     }
 
     fn parse_loop_stmt(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         self.advance(); 
         let body = Box::new(self.parse_block());
-        Stmt::Loop { body }
+        Stmt::new(StmtKind::Loop { body }, self.span_from(start_span))
     }
     
     fn parse_for_stmt(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         self.advance(); // 'for' tüket
         
         // Parantezli mi değil mi kontrol et
@@ -937,9 +1064,9 @@ impl Parser {
                     name
                 },
                 _ => {
-                    self.errors.push(format!("Sözdizimi Hatası (Satır {}): for-in döngüsü için bir değişken adı bekleniyor.", self.peek().line));
+                    self.error_at(self.peek().span, "Sözdizimi Hatası: for-in döngüsü için bir değişken adı bekleniyor.".to_string());
                     self.synchronize();
-                    return Stmt::Empty;
+                    return Stmt::new(StmtKind::Empty, self.span_from(start_span));
                 }
             };
             self.consume(TokenType::In, "for-in döngüsü için 'in' anahtar kelimesi bekleniyor.");
@@ -950,12 +1077,12 @@ impl Parser {
             }
             let body = Box::new(self.parse_block());
 
-            Stmt::For {
+            Stmt::new(StmtKind::For {
                 initializer: None, condition: None, increment: None, 
                 variable: Some(variable),
                 iterable: Some(iterable),
                 body,
-            }
+            }, self.span_from(start_span))
         } else {
             let initializer = if !self.check(&TokenType::Comma) {
                 if self.check(&TokenType::Var) || self.check(&TokenType::Const) {
@@ -965,7 +1092,7 @@ impl Parser {
                 } else {
                     let expr = self.parse_expression();
                     self.consume(TokenType::Comma, "for döngüsü başlatıcısından sonra ',' bekleniyor.");
-                    Some(Box::new(Stmt::ExprStmt(expr)))
+                    Some(Box::new(Stmt::new(StmtKind::ExprStmt(expr), self.span_from(start_span))))
                 }
             } else {
                 self.consume(TokenType::Comma, "for döngüsü başlatıcısından sonra ',' bekleniyor.");
@@ -987,32 +1114,35 @@ impl Parser {
 
             let body = Box::new(self.parse_block());
 
-            Stmt::For {
+            Stmt::new(StmtKind::For {
                 initializer, condition, increment,
                 variable: None, iterable: None, 
                 body,
-            }
+            }, self.span_from(start_span))
         }
     }
 
     fn parse_echo_stmt(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         self.advance(); // 'echo' tüket
         self.consume(TokenType::LParen, "'echo' için '(' bekleniyor");
         let expr = self.parse_expression();
         self.consume(TokenType::RParen, "'echo' için ')' bekleniyor");
         self.consume(TokenType::Semi, "'echo' deyiminden sonra ';' bekleniyor");
-        Stmt::Echo(expr)
+        Stmt::new(StmtKind::Echo(expr), self.span_from(start_span))
     }
 
     // YENİ: print, input gibi yerleşik fonksiyon çağrılarını ayrıştıran fonksiyon
     fn parse_builtin_call_stmt(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         // Bu fonksiyon bir ifade deyimi gibi çalışır: `print(...)` bir `Expr::Call`'dur.
         let expr = self.parse_expression();
         self.consume(TokenType::Semi, "İfade deyiminden sonra ';' bekleniyor");
-        Stmt::ExprStmt(expr)
+        Stmt::new(StmtKind::ExprStmt(expr), self.span_from(start_span))
     }
 
     fn parse_if_stmt(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         self.consume(TokenType::If, "if bekleniyor"); 
 
         self.consume(TokenType::LParen, "'if' ifadesinden sonra '(' bekleniyor");
@@ -1027,10 +1157,11 @@ impl Parser {
         
         let else_branch = self.parse_remaining_if_else_chain();
 
-        Stmt::If { cond, then_branch, else_branch }
+        Stmt::new(StmtKind::If { cond, then_branch, else_branch }, self.span_from(start_span))
     }
     
     fn parse_remaining_if_else_chain(&mut self) -> Option<Box<Stmt>> {
+        let start_span = self.peek().span;
         if self.check(&TokenType::ElseIf) {
             self.advance(); 
             
@@ -1046,11 +1177,11 @@ impl Parser {
 
             let elseif_else_branch = self.parse_remaining_if_else_chain();
 
-            return Some(Box::new(Stmt::If { 
+            return Some(Box::new(Stmt::new(StmtKind::If { 
                 cond: elseif_cond, 
                 then_branch: elseif_then_branch, 
                 else_branch: elseif_else_branch 
-            }));
+            }, self.span_from(start_span))));
 
         } else if self.check(&TokenType::Else) {
             self.advance(); 
@@ -1065,6 +1196,7 @@ impl Parser {
     }
 
     fn parse_block_expr(&mut self) -> Expr {
+        let start_span = self.peek().span;
         self.scope_depth += 1;
         self.consume(TokenType::LBrace, "Blok '{' ile başlamalıdır."); 
 
@@ -1077,10 +1209,11 @@ impl Parser {
         self.consume(TokenType::RBrace, "Blok '}' ile bitmelidir.");
 
         self.scope_depth -= 1;
-        Expr::Block { statements }
+        Expr::new(ExprKind::Block { statements }, self.span_from(start_span))
     }
     
     fn parse_return(&mut self) -> Stmt {
+        let start_span = self.peek().span;
         self.consume(TokenType::Return, "'return' bekleniyor");
         
         let value = if !self.check(&TokenType::Semi) {
@@ -1090,7 +1223,7 @@ impl Parser {
         };
         
         self.consume(TokenType::Semi, "';' bekleniyor");
-        Stmt::Return(value) 
+        Stmt::new(StmtKind::Return(value), self.span_from(start_span)) 
     }
 
     // --- TDOP: İfade Ayrıştırma Mekanizması ---
@@ -1129,11 +1262,11 @@ impl Parser {
             TokenType::PercentEq => BinOp::Mod, 
             
             TokenType::Assign => {
-                self.errors.push("İç Hata: Atama operatörü '=' beklenmedik bir şekilde map_op içinde işlendi.".to_string());
+                self.error_at(self.prev_span(), "İç Hata: Atama operatörü '=' beklenmedik bir şekilde map_op içinde işlendi.".to_string());
                 BinOp::Add // Hata durumunda varsayılan bir değer döndür
             },
             _ => {
-                self.errors.push(format!("İç Hata: Desteklenmeyen ikili operatör: {:?}", kind));
+                self.error_at(self.prev_span(), format!("İç Hata: Desteklenmeyen ikili operatör: {:?}", kind));
                 BinOp::Add // Hata durumunda varsayılan bir değer döndür
             }
         }
@@ -1149,13 +1282,14 @@ impl Parser {
             TokenType::Dec => UnOp::PreDec, 
             
             _ => {
-                self.errors.push(format!("İç Hata: Desteklenmeyen tekli operatör: {:?}", kind));
+                self.error_at(self.prev_span(), format!("İç Hata: Desteklenmeyen tekli operatör: {:?}", kind));
                 UnOp::Not // Hata durumunda varsayılan bir değer döndür
             }
         }
     }
 
     fn parse_assignment_expression(&mut self) -> Expr {
+        let start_span = self.peek().span;
         let left = self.parse_logical_or_expression();
 
         let token_kind = self.peek_kind();
@@ -1165,24 +1299,24 @@ impl Parser {
 
             if token_kind == TokenType::Assign {
                 // Basit atama: a = b
-                return Expr::Assign { left: Box::new(left), value: Box::new(right) };
+                return Expr::new(ExprKind::Assign { left: Box::new(left), value: Box::new(right) }, self.span_from(start_span));
             } else {
                 // Bileşik atama: a += b -> a = a + b
                 let op = self.map_op(&token_kind);
-                let new_right = Expr::Binary {
+                let new_right = Expr::new(ExprKind::Binary {
                     left: Box::new(left.clone()),
                     op,
                     right: Box::new(right),
-                };
-                return Expr::Assign { left: Box::new(left), value: Box::new(new_right) };
+                }, self.span_from(start_span));
+                return Expr::new(ExprKind::Assign { left: Box::new(left), value: Box::new(new_right) }, self.span_from(start_span));
             }
         } else if self.check(&TokenType::Recv) { // YENİ: Kanal gönderme işlemi (ch <- value)
             self.advance(); // '<-' token'ını tüket
             let value = self.parse_assignment_expression();
-            return Expr::Send {
+            return Expr::new(ExprKind::Send {
                 channel: Box::new(left),
                 value: Box::new(value),
-            }
+            }, self.span_from(start_span))
         }
         left
     }
@@ -1192,11 +1326,13 @@ impl Parser {
         while self.check(&TokenType::LogOr) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_logical_and_expression();
-            expr = Expr::Binary {
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary {
                 left: Box::new(expr),
-                op: self.map_op(&op_token),
+                op,
                 right: Box::new(right),
-            };
+            }, span);
         }
         expr
     }
@@ -1206,7 +1342,9 @@ impl Parser {
         while self.check(&TokenType::LogAnd) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_bitwise_or_expression();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
@@ -1216,7 +1354,9 @@ impl Parser {
         while self.check(&TokenType::Pipe) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_bitwise_xor_expression();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
@@ -1226,7 +1366,9 @@ impl Parser {
         while self.check(&TokenType::Caret) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_bitwise_and_expression();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
@@ -1236,7 +1378,9 @@ impl Parser {
         while self.check(&TokenType::Ampersand) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_equality_expression();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
@@ -1246,7 +1390,9 @@ impl Parser {
         while self.check(&TokenType::Eq) || self.check(&TokenType::Ne) || self.check(&TokenType::Identical) || self.check(&TokenType::NotIdentical) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_comparison_expression();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
@@ -1256,18 +1402,21 @@ impl Parser {
         while self.check(&TokenType::Gt) || self.check(&TokenType::Ge) || self.check(&TokenType::Lt) || self.check(&TokenType::Le) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_range_expression();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
 
     // YENİ: `..` (range) operatörünü ayrıştıran fonksiyon.
     fn parse_range_expression(&mut self) -> Expr {
+        let start_span = self.peek().span;
         let mut expr = self.parse_shift_expression();
         if self.check(&TokenType::Range) {
             self.advance(); // '..' token'ını tüket.
             let end = self.parse_shift_expression();
-            expr = Expr::Range { start: Box::new(expr), end: Box::new(end) };
+            expr = Expr::new(ExprKind::Range { start: Box::new(expr), end: Box::new(end) }, self.span_from(start_span));
         }
         expr
     }
@@ -1278,7 +1427,9 @@ impl Parser {
         while self.check(&TokenType::LShift) || self.check(&TokenType::RShift) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_term_expression();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
@@ -1288,7 +1439,9 @@ impl Parser {
         while self.check(&TokenType::Plus) || self.check(&TokenType::Minus) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_factor_expression();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
@@ -1298,7 +1451,9 @@ impl Parser {
         while self.check(&TokenType::Star) || self.check(&TokenType::Slash) || self.check(&TokenType::Modulo) {
             let op_token = self.advance().kind.clone();
             let right = self.parse_unary();
-            expr = Expr::Binary { left: Box::new(expr), op: self.map_op(&op_token), right: Box::new(right) };
+            let span = expr.span.to(right.span);
+            let op = self.map_op(&op_token);
+            expr = Expr::new(ExprKind::Binary { left: Box::new(expr), op, right: Box::new(right) }, span);
         }
         expr
     }
@@ -1308,54 +1463,55 @@ impl Parser {
     }
 
     fn parse_unary(&mut self) -> Expr {
+        let start_span = self.peek().span;
         match self.peek_kind() {
             TokenType::Minus => {
                 self.advance(); 
                 let right = Box::new(self.parse_unary());
-                Expr::Unary { op: UnOp::Neg, right }
+                Expr::new(ExprKind::Unary { op: UnOp::Neg, right }, self.span_from(start_span))
             },
             TokenType::Exclamation => { 
                 self.advance(); 
                 let right = Box::new(self.parse_unary());
-                Expr::Unary { op: UnOp::Not, right }
+                Expr::new(ExprKind::Unary { op: UnOp::Not, right }, self.span_from(start_span))
             },
             TokenType::Tilde => { 
                 self.advance(); 
                 let right = Box::new(self.parse_unary());
-                Expr::Unary { op: UnOp::BitwiseNot, right }
+                Expr::new(ExprKind::Unary { op: UnOp::BitwiseNot, right }, self.span_from(start_span))
             },
             TokenType::Ampersand => { // Address-of operator
                 self.advance();
                 let right = Box::new(self.parse_unary());
-                Expr::Unary { op: UnOp::AddressOf, right }
+                Expr::new(ExprKind::Unary { op: UnOp::AddressOf, right }, self.span_from(start_span))
             },
             TokenType::Star => { // Dereference operator
                 self.advance();
                 let right = Box::new(self.parse_unary());
-                Expr::Unary { op: UnOp::Deref, right }
+                Expr::new(ExprKind::Unary { op: UnOp::Deref, right }, self.span_from(start_span))
             },
             // YENİ: Kanal alma işlemi (<-ch)
             TokenType::Recv => {
                 self.advance(); // '<-' token'ını tüket
                 let channel_expr = self.parse_unary();
-                Expr::Recv(Box::new(channel_expr))
+                Expr::new(ExprKind::Recv(Box::new(channel_expr)), self.span_from(start_span))
             },
             
             TokenType::Inc => { 
                 self.advance(); 
                 let right = Box::new(self.parse_unary());
-                Expr::Unary { op: UnOp::PreInc, right } 
+                Expr::new(ExprKind::Unary { op: UnOp::PreInc, right }, self.span_from(start_span)) 
             },
             
             TokenType::Await => {
                 self.advance();
                 let expr = self.parse_unary();
-                Expr::Await(Box::new(expr))
+                Expr::new(ExprKind::Await(Box::new(expr)), self.span_from(start_span))
             },
             TokenType::Dec => { 
                 self.advance(); 
                 let right = Box::new(self.parse_unary());
-                Expr::Unary { op: UnOp::PreDec, right } 
+                Expr::new(ExprKind::Unary { op: UnOp::PreDec, right }, self.span_from(start_span)) 
             },
 
             // YENİ: print(), input() gibi yerleşik fonksiyon çağrılarını
@@ -1373,30 +1529,31 @@ impl Parser {
     }
 
     fn parse_call(&mut self) -> Expr {
+        let start_span = self.peek().span;
         let mut expr = self.parse_primary();
 
         loop {
             match self.peek_kind() {
                 TokenType::Inc => { 
                     self.advance(); 
-                    expr = Expr::Unary { 
+                    expr = Expr::new(ExprKind::Unary { 
                         op: UnOp::PostInc, 
                         right: Box::new(expr) 
-                    }; 
+                    }, self.span_from(start_span)); 
                     continue; 
                 },
                 
                 TokenType::Dec => { 
                     self.advance(); 
-                    expr = Expr::Unary { 
+                    expr = Expr::new(ExprKind::Unary { 
                         op: UnOp::PostDec, 
                         right: Box::new(expr)
-                    }; 
+                    }, self.span_from(start_span)); 
                     continue; 
                 },
                 TokenType::Question => {
                     self.advance();
-                    expr = Expr::Try(Box::new(expr));
+                    expr = Expr::new(ExprKind::Try(Box::new(expr)), self.span_from(start_span));
                     // `?` operatöründen sonra başka bir `?` veya `()` gelebilir,
                     // bu yüzden döngüye devam ediyoruz.
                     continue;
@@ -1412,19 +1569,17 @@ impl Parser {
                 
                 self.consume(TokenType::RBracket, "Dizi erişimi için ']' bekleniyor.");
 
-                let array_name = if let Expr::Variable(name) = expr {
+                let array_name = if let ExprKind::Variable(name) = expr.kind {
                     name
                 } else {
-                    self.errors.push(format!(
-                        "Sözdizimi Hatası (Satır {}): Dizi erişimi şimdilik sadece basit değişken adları üzerinden yapılabilir.", self.peek().line
-                    ));
+                    self.error_at(self.peek().span, "Sözdizimi Hatası: Dizi erişimi şimdilik sadece basit değişken adları üzerinden yapılabilir.".to_string());
                     "__invalid_array__".to_string()
                 };
                 
-                expr = Expr::ArrayAccess { 
+                expr = Expr::new(ExprKind::ArrayAccess { 
                     name: array_name,               
                     index: Box::new(index_expr)     
-                };
+                }, self.span_from(start_span));
                 
             } else if self.check(&TokenType::LParen) {
                 self.advance(); 
@@ -1458,10 +1613,10 @@ impl Parser {
                 }
                 self.consume(TokenType::RParen, "Fonksiyon çağrısı için ')' bekleniyor.");
 
-                expr = Expr::Call { 
+                expr = Expr::new(ExprKind::Call { 
                     callee: Box::new(expr), 
                     args 
-                };
+                }, self.span_from(start_span));
             } else if self.check(&TokenType::Colon) && self.check_next(&TokenType::Colon) {
                 // :: operatörü için (statik metodlar/namespace erişimi)
                 self.advance(); // :
@@ -1475,13 +1630,21 @@ impl Parser {
                 match self.peek_kind() {
                     TokenType::Ident(name) => {
                         self.advance(); 
-                        expr = Expr::MemberAccess { 
+                        expr = Expr::new(ExprKind::MemberAccess { 
                             object: Box::new(expr), 
                             member: name 
-                        };
+                        }, self.span_from(start_span));
+                    },
+                    // YENİ: Tuple elemanı (`t.0`) veya yer tutucuda ondalık hassasiyet (`"{x.2}"`).
+                    TokenType::IntLit(index) => {
+                        self.advance();
+                        expr = Expr::new(ExprKind::MemberAccess {
+                            object: Box::new(expr),
+                            member: index.to_string()
+                        }, self.span_from(start_span));
                     },
                     _ => {
-                        self.errors.push(format!("Sözdizimi Hatası (Satır {}): Üye erişiminden sonra bir tanımlayıcı bekleniyor. Bulunan token: {:?}", self.peek().line, self.peek_kind()));
+                        self.error_at(self.peek().span, format!("Sözdizimi Hatası: Üye erişiminden sonra bir tanımlayıcı bekleniyor. Bulunan token: {:?}", self.peek_kind()));
                         self.synchronize(); 
                     }
                 }
@@ -1494,7 +1657,29 @@ impl Parser {
         expr
     }
     
-    fn parse_struct_literal(&mut self, name: String) -> Expr {
+    // `Ad<...> {` jenerik struct literal'ının başında mıyız? Yalnızca token'lara bakılır; `a < b`
+    // karşılaştırmalarıyla karışmaması için kapanan '>' işaretinin hemen ardından '{' gelmelidir.
+    fn at_generic_struct_literal(&self) -> bool {
+        if self.no_struct_literal || !self.check(&TokenType::Lt) {
+            return false;
+        }
+        let mut depth = 0;
+        for (i, token) in self.tokens.iter().enumerate().skip(self.current) {
+            match token.kind {
+                TokenType::Lt => depth += 1,
+                TokenType::Gt => depth -= 1,
+                TokenType::RShift => depth -= 2,
+                TokenType::LBrace | TokenType::RBrace | TokenType::Semi | TokenType::Eof => return false,
+                _ => {}
+            }
+            if depth <= 0 {
+                return depth == 0 && self.tokens.get(i + 1).is_some_and(|t| t.kind == TokenType::LBrace);
+            }
+        }
+        false
+    }
+
+    fn parse_struct_literal(&mut self, name: String, type_args: Vec<Type>, start_span: Span) -> Expr {
         self.consume(TokenType::LBrace, "Struct literal'ı için '{' bekleniyor.");
 
         let mut fields = Vec::new();
@@ -1505,7 +1690,7 @@ impl Parser {
                     n
                 }
                 _ => {
-                    self.errors.push(format!("Sözdizimi Hatası (Satır {}): Struct literal'ında alan adı bekleniyor.", self.peek().line));
+                    self.error_at(self.peek().span, "Sözdizimi Hatası: Struct literal'ında alan adı bekleniyor.".to_string());
                     break;
                 }
             };
@@ -1519,97 +1704,148 @@ impl Parser {
         }
         self.consume(TokenType::RBrace, "Struct literal'ını kapatmak için '}' bekleniyor.");
 
-        Expr::StructLiteral { name, fields }
+        Expr::new(ExprKind::StructLiteral { name, type_args, fields }, self.span_from(start_span))
     }
 
     fn parse_primary(&mut self) -> Expr {
+        let start_span = self.peek().span;
         match self.peek_kind() {
-            TokenType::IntLit(i) => { self.advance(); Expr::Literal(LiteralValue::Int(i)) },
-            TokenType::FloatLit(f) => { self.advance(); Expr::Literal(LiteralValue::Float(f)) },
-            TokenType::HexLit(h) => { self.advance(); Expr::Literal(LiteralValue::Hex(h as u64)) },
-            TokenType::StrLit(s) => { self.advance(); Expr::Literal(LiteralValue::Str(s)) },
-            TokenType::CharLit(c) => { self.advance(); Expr::Literal(LiteralValue::Char(c)) },
-            TokenType::True => { self.advance(); Expr::Literal(LiteralValue::Bool(true)) },
-            TokenType::False => { self.advance(); Expr::Literal(LiteralValue::Bool(false)) },
-            TokenType::Null => { self.advance(); Expr::Literal(LiteralValue::Null) },
+            TokenType::IntLit(i) => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::Int(i)), self.span_from(start_span)) },
+            TokenType::FloatLit(f) => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::Float(f)), self.span_from(start_span)) },
+            TokenType::HexLit(h) => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::Hex(h)), self.span_from(start_span)) },
+            TokenType::TypedIntLit(i, suffix) => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::TypedInt(i, suffix_type(&suffix))), self.span_from(start_span)) },
+            TokenType::TypedFloatLit(f, suffix) => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::TypedFloat(f, suffix_type(&suffix))), self.span_from(start_span)) },
+            TokenType::StrLit(s) => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::Str(s)), self.span_from(start_span)) },
+            TokenType::CharLit(c) => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::Char(c)), self.span_from(start_span)) },
+            TokenType::True => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::Bool(true)), self.span_from(start_span)) },
+            TokenType::False => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::Bool(false)), self.span_from(start_span)) },
+            TokenType::Null => { self.advance(); Expr::new(ExprKind::Literal(LiteralValue::Null), self.span_from(start_span)) },
 
             TokenType::InterpolatedStr(full_string) => {
                 self.advance();
                 let mut parts = Vec::new();
-                let mut last_end = 0;
-
-                while let Some(start) = full_string[last_end..].find('{') {
-                    let absolute_start = last_end + start;
-
-                    // '{' karakterinden önceki literal kısmı ekle
-                    if absolute_start > last_end {
-                        parts.push(Expr::Literal(LiteralValue::Str(full_string[last_end..absolute_start].to_string())));
+                // Yer tutucular arasındaki metin; `{{` ve `}}` tek paranteze iner.
+                let mut text = String::new();
+                let mut rest = full_string.as_str();
+
+                while let Some(index) = rest.find(['{', '}']) {
+                    text.push_str(&rest[..index]);
+                    let brace = &rest[index..];
+                    if brace.starts_with("{{") || brace.starts_with("}}") {
+                        text.push_str(&brace[..1]);
+                        rest = &brace[2..];
+                        continue;
+                    }
+                    if let Some(after) = brace.strip_prefix('}') {
+                        text.push('}');
+                        rest = after;
+                        continue;
                     }
 
                     // Dengeli '}' karakterini bul
                     let mut balance = 1;
                     let mut end = None;
-                    for (i, c) in full_string[absolute_start + 1..].char_indices() {
+                    for (i, c) in brace[1..].char_indices() {
                         if c == '{' {
                             balance += 1;
                         } else if c == '}' {
                             balance -= 1;
                             if balance == 0 {
-                                end = Some(absolute_start + 1 + i);
+                                end = Some(1 + i);
                                 break;
                             }
                         }
                     }
 
-                    if let Some(absolute_end) = end {
-                        let expr_str = &full_string[absolute_start + 1 .. absolute_end];
-                        
-                        // İfadeyi ayrıştır
-                        let mut temp_lexer = crate::lexer::Lexer::new(expr_str.trim());
-                        let mut tokens = Vec::new();
-                        loop {
-                            let token = temp_lexer.next_token();
-                            if token.kind == TokenType::Eof { break; }
-                            tokens.push(token);
-                        }
-                        
-                        // YENİ: Geçici parser'a sadece ifadeyi ayrıştırmasını söyle.
-                        let mut temp_parser = Parser::new(tokens);
-                        let expr = temp_parser.parse_expression();
-                        parts.push(expr);
-
-                        last_end = absolute_end + 1;
-                    } else {
+                    let brace_span = placeholder_span(start_span, &full_string[..full_string.len() - brace.len()]);
+                    let Some(end) = end else {
                         // Eşleşmeyen '{' hatası
-                        self.errors.push(format!("Sözdizimi Hatası (Satır {}): İnterpolasyonlu string içinde kapanmamış '{{' bulundu.", self.peek().line));
-                        last_end = full_string.len();
+                        self.error_at(brace_span, "Sözdizimi Hatası: İnterpolasyonlu string içinde kapanmamış '{' bulundu; düz parantez için '{{' yazın.".to_string());
+                        rest = "";
                         break;
+                    };
+                    if brace[1..end].trim().is_empty() {
+                        self.error_at(brace_span, "Sözdizimi Hatası: İnterpolasyonlu string içinde boş '{}' yer tutucusu; düz parantez için '{{}}' yazın.".to_string());
+                        rest = &brace[end + 1..];
+                        continue;
+                    }
+
+                    // '{' karakterinden önceki literal kısmı ekle
+                    if !text.is_empty() {
+                        parts.push(Expr::new(ExprKind::Literal(LiteralValue::Str(std::mem::take(&mut text))), self.span_from(start_span)));
+                    }
+
+                    // İfadeyi ayrıştır
+                    let source = brace[1..end].trim();
+                    let offset = full_string.len() - brace.len() + 1 + (brace[1..end].len() - brace[1..end].trim_start().len());
+                    let mut temp_lexer = crate::lexer::Lexer::with_origin(source, placeholder_span(start_span, &full_string[..offset]));
+                    let mut tokens = Vec::new();
+                    loop {
+                        let token = temp_lexer.next_token();
+                        let is_eof = token.kind == TokenType::Eof;
+                        tokens.push(token);
+                        if is_eof { break; }
+                    }
+
+                    // Yer tutucudaki geçersiz sayı sabitleri ve karakterler lexer hatası olarak raporlanır;
+                    // bu durumda ifade ayrıştırılmaz.
+                    let lex_errors = crate::lexer::errors(&tokens);
+                    if !lex_errors.is_empty() {
+                        self.errors.extend(lex_errors);
+                        rest = &brace[end + 1..];
+                        continue;
+                    }
+
+                    // YENİ: Geçici parser'a sadece ifadeyi ayrıştırmasını söyle. Yer tutucu tek bir ifade
+                    // olmalıdır; hatalar ana parser'ın hatalarına eklenir.
+                    let mut temp_parser = Parser::new(tokens);
+                    let expr = temp_parser.parse_expression();
+                    if temp_parser.errors.is_empty() && !temp_parser.is_at_end() {
+                        let extra = temp_parser.peek().clone();
+                        temp_parser.error_at(extra.span, format!("Sözdizimi Hatası: Yer tutucudaki ifadeden sonra beklenmeyen token: {:?}. Düz parantez için '{{{{' yazın.", extra.kind));
                     }
+                    self.errors.append(&mut temp_parser.errors);
+                    parts.push(expr);
+
+                    rest = &brace[end + 1..];
                 }
 
                 // String'in geri kalanını ekle
-                if last_end < full_string.len() {
-                    parts.push(Expr::Literal(LiteralValue::Str(full_string[last_end..].to_string())));
+                text.push_str(rest);
+                if !text.is_empty() {
+                    parts.push(Expr::new(ExprKind::Literal(LiteralValue::Str(text)), self.span_from(start_span)));
                 }
-                Expr::InterpolatedString(parts)
+                Expr::new(ExprKind::InterpolatedString(parts), self.span_from(start_span))
             },
             
             TokenType::Def => {
                 self.advance(); 
-                Expr::DefaultCase 
+                Expr::new(ExprKind::DefaultCase, self.span_from(start_span)) 
             },
             TokenType::Sizeof => {
                 self.advance();
                 self.consume(TokenType::LParen, "'sizeof' sonrası '(' bekleniyor.");
                 let ty = self.parse_type();
                 self.consume(TokenType::RParen, "'sizeof' sonrası ')' bekleniyor.");
-                Expr::SizeOf(ty)
+                Expr::new(ExprKind::SizeOf(ty), self.span_from(start_span))
             },
             TokenType::Ident(name) => {
                 self.advance(); 
                 // YENİ: Struct literal için '{' kontrolü
-                if self.check(&TokenType::LBrace) {
-                    return self.parse_struct_literal(name);
+                if self.check(&TokenType::LBrace) && !self.no_struct_literal {
+                    return self.parse_struct_literal(name, Vec::new(), start_span);
+                }
+                // Tip argümanları açıkça verilmiş jenerik struct literal'ı: Kutu<i32> { ... }
+                if self.at_generic_struct_literal() {
+                    self.current -= 1;
+                    let type_args = match self.parse_type() {
+                        Type::Generic(_, args) => args,
+                        _ => {
+                            self.error_at(start_span, format!("Sözdizimi Hatası: '{}' struct literal'ında tip argümanları beklenmiyor.", name));
+                            Vec::new()
+                        }
+                    };
+                    return self.parse_struct_literal(name, type_args, start_span);
                 }
                 // YENİ: Enum üye erişimi için `::` kontrolü
                 if self.check(&TokenType::Colon) && self.check_next(&TokenType::Colon) {
@@ -1618,22 +1854,22 @@ impl Parser {
                     let variant_name = match self.peek_kind() {
                         TokenType::Ident(v_name) => { self.advance(); v_name },
                         _ => {
-                            self.errors.push(format!("Sözdizimi Hatası (Satır {}): '::' operatöründen sonra bir enum üyesi bekleniyor.", self.peek().line));
+                            self.error_at(self.peek().span, "Sözdizimi Hatası: '::' operatöründen sonra bir enum üyesi bekleniyor.".to_string());
                             "__invalid_variant__".to_string()
                         }
                     };
-                    return Expr::EnumAccess { enum_name: name, variant_name };
+                    return Expr::new(ExprKind::EnumAccess { enum_name: name, variant_name }, self.span_from(start_span));
                 }
-                Expr::Variable(name)
+                Expr::new(ExprKind::Variable(name), self.span_from(start_span))
             },
             // YENİ: 'self' anahtar kelimesini bir ifade olarak tanı.
             TokenType::Self_ => {
                 self.advance();
-                Expr::Variable("self".to_string())
+                Expr::new(ExprKind::Variable("self".to_string()), self.span_from(start_span))
             },
             // YENİ: print, input gibi yerleşik fonksiyonları bir değişken adı gibi ele al.
             // Bu, parse_call'un onları bir fonksiyon çağrısı olarak işlemesini sağlar.
-            TokenType::Print => { self.advance(); Expr::Variable("print".to_string()) },
+            TokenType::Print => { self.advance(); Expr::new(ExprKind::Variable("print".to_string()), self.span_from(start_span)) },
             TokenType::Input => {
                 self.advance(); // 'input' kelimesini tüket
                 
@@ -1648,20 +1884,20 @@ impl Parser {
                 self.consume(TokenType::RParen, "input'tan sonra ')' bekleniyor.");
                 
                 // Doğru dönüş yapısı:
-                Expr::Input(prompt) 
+                Expr::new(ExprKind::Input(prompt), self.span_from(start_span)) 
             }
-            TokenType::Strlen => { self.advance(); Expr::Variable("strlen".to_string()) },
-            TokenType::Arrlen => { self.advance(); Expr::Variable("arrlen".to_string()) },
-            TokenType::Panic => { self.advance(); Expr::Variable("panic".to_string()) },
-            TokenType::Exit => { self.advance(); Expr::Variable("exit".to_string()) },
-            TokenType::Echo => { self.advance(); Expr::Variable("echo".to_string()) },
+            TokenType::Strlen => { self.advance(); Expr::new(ExprKind::Variable("strlen".to_string()), self.span_from(start_span)) },
+            TokenType::Arrlen => { self.advance(); Expr::new(ExprKind::Variable("arrlen".to_string()), self.span_from(start_span)) },
+            TokenType::Panic => { self.advance(); Expr::new(ExprKind::Variable("panic".to_string()), self.span_from(start_span)) },
+            TokenType::Exit => { self.advance(); Expr::new(ExprKind::Variable("exit".to_string()), self.span_from(start_span)) },
+            TokenType::Echo => { self.advance(); Expr::new(ExprKind::Variable("echo".to_string()), self.span_from(start_span)) },
 
             TokenType::LParen => {
                 self.advance(); 
 
                 if self.check(&TokenType::RParen) {
                     self.consume(TokenType::RParen, "')' bekleniyor");
-                    return Expr::Tuple(Vec::new()); 
+                    return Expr::new(ExprKind::Tuple(Vec::new()), self.span_from(start_span)); 
                 }
 
                 let first_expr = self.parse_expression();
@@ -1681,17 +1917,14 @@ impl Parser {
                     }
 
                     self.consume(TokenType::RParen, "Tuple için son ')' bekleniyor");
-                    Expr::Tuple(elements)
+                    Expr::new(ExprKind::Tuple(elements), self.span_from(start_span))
                 } else {
                     self.consume(TokenType::RParen, "Normal parantezli ifade için ')' bekleniyor");
                     first_expr
                 }
             },
             
-            TokenType::Match => {
-                self.advance(); 
-                return self.parse_match_expr(); 
-            },
+            TokenType::Match => self.parse_match_expr(), // 'match' anahtar kelimesini parse_match_expr tüketir.
             
             TokenType::LBracket => {
                 self.advance(); 
@@ -1711,7 +1944,7 @@ impl Parser {
                 }
 
                 self.consume(TokenType::RBracket, "Dizi tanımı için ']' bekleniyor.");
-                Expr::ArrayLiteral(elements)
+                Expr::new(ExprKind::ArrayLiteral(elements), self.span_from(start_span))
             },
 
             TokenType::Fn => {
@@ -1730,18 +1963,23 @@ impl Parser {
                     self.parse_expression()
                 };
 
-                Expr::Lambda {
+                Expr::new(ExprKind::Lambda {
                     params,
                     return_type,
                     body: Box::new(body),
-                }
+                }, self.span_from(start_span))
             },
 
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Birincil ifade bekleniyor. Bulunan token: {:?}", self.peek().line, self.peek_kind()));
+                self.error_at(self.peek().span, format!("Sözdizimi Hatası: Birincil ifade bekleniyor. Bulunan token: {:?}", self.peek_kind()));
+                let before = self.current;
                 self.synchronize();
+                // Hatalı token bir deyim sınırındaysa synchronize ilerlemez; sonsuz döngüye girmemek için atlanır.
+                if self.current == before {
+                    self.advance();
+                }
                 // Hata durumunda Null döndürerek devam et
-                Expr::Literal(LiteralValue::Null)
+                Expr::new(ExprKind::Literal(LiteralValue::Null), self.span_from(start_span))
             }
         }
     }
@@ -1760,10 +1998,8 @@ impl Parser {
             Type::Ptr(inner_type)
             
         } else if self.check(&TokenType::LBracket) {
-            self.errors.push(format!(
-                "Sözdizimi Hatası (Satır {}): Köşeli parantez ('[') tip tanımının başında beklenmiyor. Dizi tipi 'var my_array[10]: i32;' şeklinde tanımlanmalıdır.", self.peek().line
-            ));
-            return Type::Unknown;
+            self.error_at(self.peek().span, "Sözdizimi Hatası: Köşeli parantez ('[') tip tanımının başında beklenmiyor. Dizi tipi 'var my_array[10]: i32;' şeklinde tanımlanmalıdır.".to_string());
+            Type::Unknown
         
         } else if self.check(&TokenType::LParen) {
             self.advance(); 
@@ -1819,11 +2055,11 @@ impl Parser {
             TokenType::TypeStr => Type::Str(None), 
             TokenType::TypeArr => Type::Arr, 
             TokenType::TypePtr => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): 'ptr' anahtar kelimesi yerine '*' kullanılmalıdır.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: 'ptr' anahtar kelimesi yerine '*' kullanılmalıdır.".to_string());
                 Type::Unknown
             },
             TokenType::TypeRef => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): 'ref' anahtar kelimesi yerine '&' kullanılmalıdır.", self.peek().line));
+                self.error_at(self.peek().span, "Sözdizimi Hatası: 'ref' anahtar kelimesi yerine '&' kullanılmalıdır.".to_string());
                 Type::Unknown
             },
             
@@ -1848,17 +2084,40 @@ impl Parser {
                     self.advance(); // 'Channel'
                     self.advance(); // '<'
                     let inner_type = self.parse_type();
-                    self.consume(TokenType::Gt, "'>' bekleniyor");
+                    self.consume_type_close();
                     return Type::Channel(Box::new(inner_type));
                 }
+                if s == "Option" && self.check_next(&TokenType::Lt) {
+                    self.advance(); // 'Option'
+                    self.advance(); // '<'
+                    let inner_type = self.parse_type();
+                    self.consume_type_close();
+                    return Type::Option(Box::new(inner_type));
+                }
                 if s == "Result" && self.check_next(&TokenType::Lt) {
                     self.advance(); // 'Result'
                     self.advance(); // '<'
                     let ok_type = self.parse_type();
                     self.consume(TokenType::Comma, "Result tipi için ',' bekleniyor.");
                     let err_type = self.parse_type();
-                    self.consume(TokenType::Gt, "'>' bekleniyor");
+                    self.consume_type_close();
                     return Type::Result(Box::new(ok_type), Box::new(err_type));
+                } else if self.generic_scope.contains(&s) {
+                    Type::Param(s.clone())
+                } else if self.check_next(&TokenType::Lt) {
+                    // YENİ: Jenerik struct veya typedef örneği: Kutu<i32>, Cift<Kutu<str>>
+                    self.advance(); // ad
+                    self.advance(); // '<'
+                    let mut args = Vec::new();
+                    loop {
+                        args.push(self.parse_type());
+                        if !self.check(&TokenType::Comma) {
+                            break;
+                        }
+                        self.advance();
+                    }
+                    self.consume_type_close();
+                    return Type::Generic(s.clone(), args);
                 } else {
                     // Normal bir struct veya enum adı.
                     // Type checker, bunun bir enum olup olmadığını ve temel tipini belirleyecektir.
@@ -1868,7 +2127,7 @@ impl Parser {
             },
 
             _ => {
-                self.errors.push(format!("Sözdizimi Hatası (Satır {}): Geçerli bir tip bekleniyor, bulundu: {:?}", self.peek().line, self.peek_kind()));
+                self.error_at(self.peek().span, format!("Sözdizimi Hatası: Geçerli bir tip bekleniyor, bulundu: {:?}", self.peek_kind()));
                 self.synchronize();
                 // Hata durumunda Unknown döndürerek devam et
                 return Type::Unknown;
@@ -1877,4 +2136,37 @@ impl Parser {
         self.advance();
         t
     }
-}
\ No newline at end of file
+}
+
+// Doküman yorumunu, yorumu taşıyabilen bildirimlere ekler.
+fn with_doc(mut decl: Decl, doc: Option<String>) -> Decl {
+    if let Decl::Function { doc: slot, .. }
+    | Decl::ExternFn { doc: slot, .. }
+    | Decl::Group { doc: slot, .. }
+    | Decl::Struct { doc: slot, .. }
+    | Decl::Enum { doc: slot, .. }
+    | Decl::Typedef { doc: slot, .. } = &mut decl
+    {
+        *slot = doc;
+    }
+    decl
+}
+
+// İnterpolasyonlu string içindeki bir yer tutucu ifadesinin (`{ifade}`) yaklaşık başlangıç konumu. `before`,
+// string değerinde ifadeden önceki metindir. Kaçış dizileri konumu kaydırabilir; çok satırlı stringlerde
+// satır/sütun string token'ınınki olarak kalır.
+fn placeholder_span(string_span: Span, before: &str) -> Span {
+    let start = string_span.start + 1 + before.len();
+    let column = if before.contains('\n') { string_span.column } else { string_span.column + 1 + before.chars().count() };
+    Span::new(string_span.file, string_span.line, column, start, start)
+}
+
+// Sayı sabiti sonekinin tipi. Lexer yalnızca geçerli sonekleri üretir.
+fn suffix_type(suffix: &str) -> Type {
+    match suffix {
+        "i8" => Type::I8, "i16" => Type::I16, "i32" => Type::I32, "i64" => Type::I64, "i128" => Type::I128,
+        "u8" => Type::U8, "u16" => Type::U16, "u32" => Type::U32, "u64" => Type::U64, "u128" => Type::U128,
+        "f32" => Type::F32,
+        _ => Type::F64,
+    }
+}
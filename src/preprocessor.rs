@@ -0,0 +1,381 @@
+// src/preprocessor.rs
+
+// Önişlemci: lexer ile parser arasında token listesi üzerinde çalışır. `#define`/`#undef` ile
+// sembol tanımlar, `#ifdef`/`#ifndef`/`#if`/`#elif`/`#else`/`#endif` ile etkin olmayan bölgelerin
+// token'larını atar ve tanımlı sembolleri değerlerinin token'larıyla değiştirir.
+// Bir direktifin argümanları, `#` ile aynı satırdaki token'lardır.
+
+use std::collections::HashMap;
+use crate::ast::{TargetArch, TargetPlatform};
+use crate::diagnostic::{Diagnostic, Span, Stage, E_PREPROCESSOR};
+use crate::lexer::Lexer;
+use crate::token::{Token, TokenType};
+
+// Tanımlı semboller ve değerleri. Her kaynak dosya (ana dosya ve `use` ile yüklenen modüller)
+// aynı başlangıç kümesiyle önişlenir; bir dosyadaki `#define` diğerlerini etkilemez.
+#[derive(Debug, Clone, Default)]
+pub struct Defines {
+    symbols: HashMap<String, Vec<TokenType>>,
+}
+
+impl Defines {
+    // Hedef platform, mimari ve derleme modundan gelen öntanımlı semboller:
+    // TARGET_LINUX/TARGET_WINDOWS/TARGET_MACOS/TARGET_WASI, ARCH_X86_64/ARCH_AARCH64/ARCH_WASM32
+    // ve BUILD_DEBUG veya BUILD_RELEASE. asm blokları mimariye göre ARCH_* ile seçilir.
+    pub fn predefined(target_platform: TargetPlatform, arch: TargetArch, debug: bool) -> Self {
+        let mut defines = Defines::default();
+        let target = match target_platform {
+            TargetPlatform::Linux => Some("TARGET_LINUX"),
+            TargetPlatform::Windows => Some("TARGET_WINDOWS"),
+            TargetPlatform::Macos => Some("TARGET_MACOS"),
+            TargetPlatform::Wasi => Some("TARGET_WASI"),
+            TargetPlatform::Unknown => None,
+        };
+        if let Some(target) = target {
+            defines.define(target, "1");
+        }
+        let arch = match arch {
+            TargetArch::X86_64 => "ARCH_X86_64",
+            TargetArch::Aarch64 => "ARCH_AARCH64",
+            TargetArch::Wasm32 => "ARCH_WASM32",
+        };
+        defines.define(arch, "1");
+        defines.define(if debug { "BUILD_DEBUG" } else { "BUILD_RELEASE" }, "1");
+        defines
+    }
+
+    // `value` NIMBLE kaynağı olarak token'lara ayrılır (-D NAME=VALUE); boş değer geçerlidir.
+    pub fn define(&mut self, name: &str, value: &str) {
+        let mut lexer = Lexer::with_fixed_span(value, Span::dummy());
+        let mut kinds = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            if token.kind == TokenType::Eof {
+                break;
+            }
+            kinds.push(token.kind);
+        }
+        self.symbols.insert(name.to_string(), kinds);
+    }
+
+    pub fn is_defined(&self, name: &str) -> bool {
+        self.symbols.contains_key(name)
+    }
+}
+
+// Token listesini önişler. Hatalar biriktirilir ve liste sonuna kadar işlenir.
+pub fn preprocess(tokens: Vec<Token>, defines: &Defines) -> Result<Vec<Token>, Vec<Diagnostic>> {
+    let mut preprocessor = Preprocessor { defines: defines.clone(), conditions: Vec::new(), errors: Vec::new() };
+    let output = preprocessor.run(tokens);
+    if preprocessor.errors.is_empty() { Ok(output) } else { Err(preprocessor.errors) }
+}
+
+// Açık bir `#if`/`#ifdef`/`#ifndef` grubu.
+struct Conditional {
+    directive: String, // Grubu açan direktif ve konumu (kapatılmamış grup hatası için)
+    span: Span,
+    parent_active: bool,
+    active: bool,    // Şu anki dal etkin mi (üst grup da etkinse)
+    taken: bool,     // Gruptaki dallardan biri seçildi mi
+    seen_else: bool,
+}
+
+struct Preprocessor {
+    defines: Defines,
+    conditions: Vec<Conditional>,
+    errors: Vec<Diagnostic>,
+}
+
+impl Preprocessor {
+    fn error(&mut self, message: String, span: Span) {
+        self.errors.push(Diagnostic::error(message).with_stage(Stage::Preprocessor).with_code(E_PREPROCESSOR).with_label(span, ""));
+    }
+
+    fn active(&self) -> bool {
+        self.conditions.last().is_none_or(|c| c.active)
+    }
+
+    fn run(&mut self, tokens: Vec<Token>) -> Vec<Token> {
+        let mut output = Vec::new();
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            if let TokenType::Preprocessor(name) = &token.kind {
+                let span = token.span;
+                let mut args = Vec::new();
+                while let Some(arg) = tokens.next_if(|t| t.span.line == span.line && t.span.file == span.file && t.kind != TokenType::Eof) {
+                    args.push(arg);
+                }
+                self.directive(name, span, &args);
+            } else if token.kind == TokenType::Eof || self.active() {
+                self.expand(token, &mut output, &mut Vec::new());
+            }
+        }
+        for conditional in std::mem::take(&mut self.conditions) {
+            self.error(format!("Hata: '#{}' direktifi kapatılmamış; eşleşen '#endif' bulunamadı.", conditional.directive), conditional.span);
+        }
+        output
+    }
+
+    // Tanımlı bir sembolü değerinin token'larıyla değiştirir; yeni token'lar kullanım yerinin konumunu
+    // taşır. Genişletilmekte olan bir sembol kendi değerinde tekrar görülürse olduğu gibi bırakılır.
+    fn expand(&self, token: Token, output: &mut Vec<Token>, expanding: &mut Vec<String>) {
+        let value = match &token.kind {
+            TokenType::Ident(name) if !expanding.contains(name) => self.defines.symbols.get(name).map(|value| (name.clone(), value)),
+            _ => None,
+        };
+        match value {
+            Some((name, value)) => {
+                expanding.push(name);
+                for kind in value {
+                    self.expand(Token::new(kind.clone(), token.span), output, expanding);
+                }
+                expanding.pop();
+            }
+            None => output.push(token),
+        }
+    }
+
+    fn directive(&mut self, name: &str, span: Span, args: &[Token]) {
+        match name {
+            "ifdef" | "ifndef" => {
+                let parent_active = self.active();
+                let condition = parent_active
+                    && self.symbol_arg(name, span, args).is_some_and(|symbol| self.defines.is_defined(&symbol) == (name == "ifdef"));
+                self.push(name, span, parent_active, condition);
+            }
+            "if" => {
+                let parent_active = self.active();
+                let condition = parent_active && self.evaluate(name, span, args);
+                self.push(name, span, parent_active, condition);
+            }
+            "elif" | "else" => {
+                let Some(top) = self.conditions.last() else {
+                    return self.error(format!("Hata: '#{}' için eşleşen bir '#if' yok.", name), span);
+                };
+                if top.seen_else {
+                    return self.error(format!("Hata: '#{}', aynı grubun '#else' dalından sonra gelemez.", name), span);
+                }
+                let open = top.parent_active && !top.taken;
+                let condition = if name == "else" {
+                    if let Some(extra) = args.first() {
+                        self.error("Hata: '#else' direktifi argüman almaz.".to_string(), extra.span);
+                    }
+                    open
+                } else {
+                    open && self.evaluate(name, span, args)
+                };
+                let top = self.conditions.last_mut().expect("grup yukarıda kontrol edildi");
+                top.active = condition;
+                top.taken |= condition;
+                top.seen_else = name == "else";
+            }
+            "endif" => {
+                if self.conditions.pop().is_none() {
+                    self.error("Hata: '#endif' için eşleşen bir '#if' yok.".to_string(), span);
+                } else if let Some(extra) = args.first() {
+                    self.error("Hata: '#endif' direktifi argüman almaz.".to_string(), extra.span);
+                }
+            }
+            // Etkin olmayan bölgelerdeki diğer direktifler yok sayılır.
+            _ if !self.active() => {}
+            "define" => match args.split_first() {
+                Some((Token { kind: TokenType::Ident(symbol), .. }, value)) => {
+                    let value = value.iter().map(|t| t.kind.clone()).collect();
+                    self.defines.symbols.insert(symbol.clone(), value);
+                }
+                _ => self.error("Hata: '#define' direktifi bir sembol adı bekliyor.".to_string(), span),
+            },
+            "undef" => {
+                if let Some(symbol) = self.symbol_arg(name, span, args) {
+                    self.defines.symbols.remove(&symbol);
+                }
+            }
+            "" => self.error("Hata: '#' işaretinden sonra bir direktif adı bekleniyor.".to_string(), span),
+            _ => self.error(format!("Hata: Bilinmeyen önişlemci direktifi: '#{}'.", name), span),
+        }
+    }
+
+    fn push(&mut self, name: &str, span: Span, parent_active: bool, condition: bool) {
+        self.conditions.push(Conditional { directive: name.to_string(), span, parent_active, active: condition, taken: condition, seen_else: false });
+    }
+
+    // `#ifdef`/`#ifndef`/`#undef` argümanı: tek bir sembol adı.
+    fn symbol_arg(&mut self, name: &str, span: Span, args: &[Token]) -> Option<String> {
+        match args {
+            [Token { kind: TokenType::Ident(symbol), .. }] => Some(symbol.clone()),
+            _ => {
+                self.error(format!("Hata: '#{}' direktifi tek bir sembol adı bekliyor.", name), span);
+                None
+            }
+        }
+    }
+
+    // `#if`/`#elif` koşulunu değerlendirir; sıfırdan farklı değer doğrudur.
+    fn evaluate(&mut self, name: &str, span: Span, args: &[Token]) -> bool {
+        if args.is_empty() {
+            self.error(format!("Hata: '#{}' direktifi bir koşul ifadesi bekliyor.", name), span);
+            return false;
+        }
+        let kinds: Vec<TokenType> = args.iter().map(|t| t.kind.clone()).collect();
+        match Condition::evaluate(&kinds, &self.defines, &mut Vec::new()) {
+            Ok(value) => value != 0,
+            Err(message) => {
+                self.error(format!("Hata: '#{}' koşulu geçersiz: {}.", name, message), span);
+                false
+            }
+        }
+    }
+}
+
+// `#if` koşul ifadelerinin değerlendiricisi. Desteklenenler: tamsayı/hex sabitleri, true/false,
+// `defined(AD)`, semboller (tanımsızsa 0, değeri boşsa 1), `!`, `-`, aritmetik, karşılaştırma,
+// `&&`/`||` ve parantezler.
+struct Condition<'t> {
+    tokens: &'t [TokenType],
+    pos: usize,
+    defines: &'t Defines,
+}
+
+impl<'t> Condition<'t> {
+    // `expanding`: değeri değerlendirilmekte olan semboller (kendine atıf yapan tanımlara karşı).
+    fn evaluate(tokens: &'t [TokenType], defines: &'t Defines, expanding: &mut Vec<String>) -> Result<i64, String> {
+        let mut condition = Condition { tokens, pos: 0, defines };
+        let value = condition.logic_or(expanding)?;
+        match condition.peek() {
+            Some(token) => Err(format!("beklenmeyen token: {:?}", token)),
+            None => Ok(value),
+        }
+    }
+
+    fn peek(&self) -> Option<&'t TokenType> {
+        self.tokens.get(self.pos)
+    }
+
+    fn eat(&mut self, kind: &TokenType) -> bool {
+        let matched = self.peek() == Some(kind);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn logic_or(&mut self, expanding: &mut Vec<String>) -> Result<i64, String> {
+        let mut value = self.logic_and(expanding)?;
+        while self.eat(&TokenType::LogOr) {
+            let right = self.logic_and(expanding)?;
+            value = (value != 0 || right != 0) as i64;
+        }
+        Ok(value)
+    }
+
+    fn logic_and(&mut self, expanding: &mut Vec<String>) -> Result<i64, String> {
+        let mut value = self.comparison(expanding)?;
+        while self.eat(&TokenType::LogAnd) {
+            let right = self.comparison(expanding)?;
+            value = (value != 0 && right != 0) as i64;
+        }
+        Ok(value)
+    }
+
+    fn comparison(&mut self, expanding: &mut Vec<String>) -> Result<i64, String> {
+        let mut value = self.additive(expanding)?;
+        loop {
+            let op = match self.peek() {
+                Some(op @ (TokenType::Eq | TokenType::Ne | TokenType::Lt | TokenType::Le | TokenType::Gt | TokenType::Ge)) => op,
+                _ => return Ok(value),
+            };
+            self.pos += 1;
+            let right = self.additive(expanding)?;
+            value = match op {
+                TokenType::Eq => value == right,
+                TokenType::Ne => value != right,
+                TokenType::Lt => value < right,
+                TokenType::Le => value <= right,
+                TokenType::Gt => value > right,
+                _ => value >= right,
+            } as i64;
+        }
+    }
+
+    fn additive(&mut self, expanding: &mut Vec<String>) -> Result<i64, String> {
+        let mut value = self.multiplicative(expanding)?;
+        loop {
+            if self.eat(&TokenType::Plus) {
+                value = value.wrapping_add(self.multiplicative(expanding)?);
+            } else if self.eat(&TokenType::Minus) {
+                value = value.wrapping_sub(self.multiplicative(expanding)?);
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn multiplicative(&mut self, expanding: &mut Vec<String>) -> Result<i64, String> {
+        let mut value = self.unary(expanding)?;
+        loop {
+            let op = match self.peek() {
+                Some(op @ (TokenType::Star | TokenType::Slash | TokenType::Modulo)) => op,
+                _ => return Ok(value),
+            };
+            self.pos += 1;
+            let right = self.unary(expanding)?;
+            value = match op {
+                TokenType::Star => value.wrapping_mul(right),
+                _ if right == 0 => return Err("sıfıra bölme".to_string()),
+                TokenType::Slash => value.wrapping_div(right),
+                _ => value.wrapping_rem(right),
+            };
+        }
+    }
+
+    fn unary(&mut self, expanding: &mut Vec<String>) -> Result<i64, String> {
+        if self.eat(&TokenType::Exclamation) {
+            return Ok((self.unary(expanding)? == 0) as i64);
+        }
+        if self.eat(&TokenType::Minus) {
+            return Ok(self.unary(expanding)?.wrapping_neg());
+        }
+        self.primary(expanding)
+    }
+
+    fn primary(&mut self, expanding: &mut Vec<String>) -> Result<i64, String> {
+        let token = self.peek().ok_or("ifade beklenmedik şekilde bitti")?;
+        self.pos += 1;
+        match token {
+            TokenType::IntLit(value) | TokenType::HexLit(value) => Ok(*value as i64),
+            TokenType::TypedIntLit(value, _) => Ok(*value as i64),
+            TokenType::True => Ok(1),
+            TokenType::False => Ok(0),
+            TokenType::LParen => {
+                let value = self.logic_or(expanding)?;
+                if !self.eat(&TokenType::RParen) {
+                    return Err("')' bekleniyordu".to_string());
+                }
+                Ok(value)
+            }
+            TokenType::Ident(name) if name == "defined" => {
+                let parenthesized = self.eat(&TokenType::LParen);
+                let Some(TokenType::Ident(symbol)) = self.peek() else {
+                    return Err("'defined' bir sembol adı bekliyor".to_string());
+                };
+                self.pos += 1;
+                if parenthesized && !self.eat(&TokenType::RParen) {
+                    return Err("')' bekleniyordu".to_string());
+                }
+                Ok(self.defines.is_defined(symbol) as i64)
+            }
+            TokenType::Ident(name) => match self.defines.symbols.get(name) {
+                None => Ok(0),
+                Some(value) if value.is_empty() => Ok(1),
+                Some(_) if expanding.contains(name) => Err(format!("'{}' sembolü kendi değerinde kullanılıyor", name)),
+                Some(value) => {
+                    expanding.push(name.clone());
+                    let result = Condition::evaluate(value, self.defines, expanding);
+                    expanding.pop();
+                    result.map_err(|e| format!("'{}' sembolünün değeri: {}", name, e))
+                }
+            },
+            other => Err(format!("beklenmeyen token: {:?}", other)),
+        }
+    }
+}
@@ -0,0 +1,705 @@
+// src/session.rs
+
+// YENİ: Derleyicinin programatik API'si.
+//
+// `Session`, tek bir kaynak dosyanın derleme oturumudur. Aşamalar (lex, preprocess, parse, check, codegen)
+// tek tek veya `compile` ile sırayla çalıştırılabilir. Hatalar süreci sonlandırmaz, `Diagnostic`
+// listesi olarak döner. Aşama çıktıları (token listesi, AST dökümü, assembly) çağıranın verdiği
+// bir `OutputSink`'e yazılır. Komut satırı aracı (`main.rs`) bu API'nin üzerindeki ince bir katmandır.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::assembler;
+use crate::c_backend;
+use crate::ast::{Decl, Expr, TargetArch, TargetPlatform};
+use crate::ast_dump;
+use crate::codegen::Codegen;
+use crate::diagnostic::{Diagnostic, FileId, SourceMap, Stage, E_LINK};
+use crate::interpreter;
+use crate::ir;
+use crate::ir::opt::OptLevel;
+use crate::lexer::{self, Lexer};
+use crate::linker;
+use crate::mono;
+use crate::parser::Parser;
+use crate::preprocessor::{self, Defines};
+use crate::token::{Token, TokenType};
+use crate::type_checker::TypeChecker;
+
+// Derleme modu.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuildMode {
+    Debug,
+    Release,
+}
+
+// Üretilecek çıktının tipi.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputType {
+    Executable,
+    SharedLibrary,
+}
+
+// Kod üretiminin arka ucu (--backend): assembly üreten native arka uç veya taşınabilir C11 kaynağı.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backend {
+    Native,
+    C,
+}
+
+// Derlemenin hangi aşamadan sonra durup çıktısını yazacağı (--emit).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EmitKind {
+    Tokens,
+    Ast,
+    TypedAst,
+    Ir,
+    Asm,
+    Obj,
+    Exe,
+}
+
+// Bir derleme oturumunun ayarları.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub include_paths: Vec<String>,
+    pub target_platform: TargetPlatform,
+    // Hedef mimari (--arch); AArch64 yalnızca Linux hedefinde desteklenir.
+    pub arch: TargetArch,
+    pub build_mode: BuildMode,
+    // -O0/-O1/-O2 ile verilen optimizasyon seviyesi; None ise derleme moduna göre seçilir.
+    pub opt_level: Option<OptLevel>,
+    pub output_type: OutputType,
+    pub backend: Backend,
+    // -D NAME[=VALUE] ile tanımlanan önişlemci sembolleri (değer verilmemişse "1").
+    pub defines: Vec<(String, String)>,
+    // --link ile verilen ve programla birlikte bağlanan C kaynakları veya nesne dosyaları.
+    pub link_inputs: Vec<String>,
+    // true ise aşama ilerleme mesajları stdout'a yazılır (komut satırı aracı için).
+    pub verbose: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            include_paths: vec![".".to_string(), "./libs".to_string()],
+            target_platform: host_platform(),
+            arch: TargetArch::X86_64,
+            build_mode: BuildMode::Release,
+            opt_level: None,
+            output_type: OutputType::Executable,
+            backend: Backend::Native,
+            defines: Vec::new(),
+            link_inputs: Vec::new(),
+            verbose: false,
+        }
+    }
+}
+
+impl Options {
+    // Etkin optimizasyon seviyesi: debug modunda -O0, release modunda -O2 (bayrakla değiştirilebilir).
+    pub fn opt_level(&self) -> OptLevel {
+        self.opt_level.unwrap_or(match self.build_mode {
+            BuildMode::Debug => OptLevel::O0,
+            BuildMode::Release => OptLevel::O2,
+        })
+    }
+
+    // Her kaynak dosyanın önişlemeye başladığı semboller: hedef, mimari ve moddan gelen öntanımlılar ve -D tanımları.
+    pub fn defines(&self) -> Defines {
+        let mut defines = Defines::predefined(self.target_platform, self.arch, self.build_mode == BuildMode::Debug);
+        for (name, value) in &self.defines {
+            defines.define(name, value);
+        }
+        defines
+    }
+}
+
+// Derleyicinin çalıştığı platform (bilinmiyorsa Unknown).
+pub fn host_platform() -> TargetPlatform {
+    match std::env::consts::OS {
+        "windows" => TargetPlatform::Windows,
+        "linux" => TargetPlatform::Linux,
+        "macos" => TargetPlatform::Macos,
+        _ => TargetPlatform::Unknown,
+    }
+}
+
+// Derleyicinin çalıştığı mimari (AArch64 dışındaki her şey için x86-64).
+pub fn host_arch() -> TargetArch {
+    match std::env::consts::ARCH {
+        "aarch64" => TargetArch::Aarch64,
+        _ => TargetArch::X86_64,
+    }
+}
+
+// Aşamaların `OutputSink`'e yazdığı metin çıktıları.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Artifact {
+    Tokens,
+    Ast,
+    TypedAst,
+    Ir,
+    Asm,
+    C,
+    Wat,
+}
+
+impl Artifact {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Artifact::Tokens => "tokens",
+            Artifact::Ast => "ast",
+            Artifact::TypedAst => "typed.ast",
+            Artifact::Ir => "ir",
+            Artifact::Asm => "s",
+            Artifact::C => "c",
+            Artifact::Wat => "wat",
+        }
+    }
+}
+
+// Aşama çıktılarının yazılacağı hedef.
+pub trait OutputSink {
+    fn write_artifact(&mut self, artifact: Artifact, contents: &str) -> io::Result<()>;
+}
+
+// Çıktıları bellekte toplar (derleyiciyi gömen araçlar ve testler için).
+impl OutputSink for Vec<(Artifact, String)> {
+    fn write_artifact(&mut self, artifact: Artifact, contents: &str) -> io::Result<()> {
+        self.push((artifact, contents.to_string()));
+        Ok(())
+    }
+}
+
+// Çıktıları `<dizin>/<ad>.<uzantı>` dosyalarına yazar (örn: build/obj/main.s).
+pub struct DirSink {
+    dir: PathBuf,
+    base_name: String,
+}
+
+impl DirSink {
+    pub fn new(dir: impl Into<PathBuf>, base_name: impl Into<String>) -> Self {
+        DirSink { dir: dir.into(), base_name: base_name.into() }
+    }
+
+    pub fn path(&self, artifact: Artifact) -> PathBuf {
+        self.dir.join(format!("{}.{}", self.base_name, artifact.extension()))
+    }
+}
+
+impl OutputSink for DirSink {
+    fn write_artifact(&mut self, artifact: Artifact, contents: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(artifact), contents)
+    }
+}
+
+// Başarılı bir `compile` çağrısının sonucu.
+#[derive(Debug, Clone, Default)]
+pub struct Compiled {
+    // Program dış (C) fonksiyon kullanıyorsa veya --link ile C kodu bağlanıyorsa libc ile bağlanmalıdır.
+    pub uses_libc: bool,
+}
+
+pub struct Session {
+    pub options: Options,
+    // Ana dosya ve `use` ile yüklenen modüller (hataları kaynak satırlarıyla göstermek için).
+    sources: SourceMap,
+    file: FileId,
+    name: String,
+    // Derlemeyi durdurmayan uyarılar; çağıran take_warnings ile alıp raporlar.
+    warnings: Vec<Diagnostic>,
+}
+
+impl Session {
+    pub fn from_source(name: impl Into<String>, source: impl Into<String>, options: Options) -> Self {
+        let name = name.into();
+        let mut sources = SourceMap::new();
+        let file = sources.add(name.clone(), source.into());
+        Session { options, sources, file, name, warnings: Vec::new() }
+    }
+
+    pub fn from_path(path: impl AsRef<Path>, options: Options) -> Result<Self, Diagnostic> {
+        let path = path.as_ref();
+        let source = fs::read_to_string(path)
+            .map_err(|_| Diagnostic::error(format!("Hata: Dosya okunamadı: {}", path.display())))?;
+        Ok(Self::from_source(path.display().to_string(), source, options))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn sources(&self) -> &SourceMap {
+        &self.sources
+    }
+
+    // Şimdiye kadar biriken uyarıları döndürür ve listeyi boşaltır.
+    pub fn take_warnings(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.warnings)
+    }
+
+    // Kaynak dosyanın uzantısız adı (örn: "tests/01_hello.oc" -> "01_hello").
+    pub fn base_name(&self) -> String {
+        Path::new(&self.name).file_stem().and_then(|s| s.to_str()).unwrap_or("output").to_string()
+    }
+
+    // Hedef platform ve çıktı tipine göre üretilecek dosyanın adı (örn: "main.exe", "libx.so").
+    pub fn output_file_name(&self) -> String {
+        let executable = self.options.output_type == OutputType::Executable;
+        let extension = match self.options.target_platform {
+            TargetPlatform::Windows => if executable { ".exe" } else { ".dll" },
+            TargetPlatform::Linux => if executable { "" } else { ".so" },
+            TargetPlatform::Macos => if executable { "" } else { ".dylib" },
+            // Eklenti olarak yüklenen modül de çalıştırılabilir modülle aynı biçimdedir.
+            TargetPlatform::Wasi => ".wasm",
+            TargetPlatform::Unknown => "",
+        };
+        format!("{}{}", self.base_name(), extension)
+    }
+
+    // `assemble`'ın ürettiği ara dosyanın adı: WASI'de ikili Wasm modülü, diğer hedeflerde nesne dosyası.
+    pub fn object_file_name(&self) -> String {
+        let extension = if self.options.arch == TargetArch::Wasm32 { "wasm" } else { "o" };
+        format!("{}.{}", self.base_name(), extension)
+    }
+
+    fn progress(&self, message: &str) {
+        if self.options.verbose {
+            println!("{}", message);
+        }
+    }
+
+    // Aşama 1: Kaynak kodu token listesine çevirir (son token Eof). Geçersiz karakterler
+    // Illegal token olarak listede kalır; `--emit tokens` çıktısı bu listedir.
+    pub fn tokens(&self) -> Vec<Token> {
+        let source = &self.sources.get(self.file).expect("Ana kaynak dosyası kayıtlı olmalı.").source;
+        let mut lexer = Lexer::with_file(source, self.file);
+        let mut tokens = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            let is_eof = token.kind == TokenType::Eof;
+            tokens.push(token);
+            if is_eof {
+                return tokens;
+            }
+        }
+    }
+
+    // Aşama 1: Token listesi; tanınmayan karakterler ve geçersiz sayı sabitleri lexer aşamasının
+    // hatası olarak raporlanır.
+    pub fn lex(&self) -> Result<Vec<Token>, Vec<Diagnostic>> {
+        let tokens = self.tokens();
+        let errors = lexer::errors(&tokens);
+        if errors.is_empty() { Ok(tokens) } else { Err(errors) }
+    }
+
+    // Aşama 1b: Önişlemci. Koşullu derleme direktiflerini uygular ve tanımlı sembolleri genişletir.
+    pub fn preprocess(&self, tokens: Vec<Token>) -> Result<Vec<Token>, Vec<Diagnostic>> {
+        preprocessor::preprocess(tokens, &self.options.defines())
+    }
+
+    // Aşama 2: Sözdizimi analizi. Dönen liste programın üst düzey bildirimleridir.
+    pub fn parse(&self, tokens: Vec<Token>) -> Result<Vec<Decl>, Vec<Diagnostic>> {
+        let mut parser = Parser::new(tokens);
+        let (program_root, errors) = parser.parse();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        match program_root {
+            Decl::Program(decls) => Ok(decls),
+            _ => Err(vec![Diagnostic::error("Hata: Parser, Program kök yapısı yerine beklenmeyen bir Decl döndürdü.")
+                .with_stage(Stage::Parser)]),
+        }
+    }
+
+    // Aşama 3: Tip kontrolü. `use` ile yüklenen modüller oturumun kaynak haritasına eklenir.
+    // Dönen TypeChecker, kod üretimi için tip bilgisini taşır.
+    pub fn check<'p>(&mut self, program: &'p [Decl]) -> Result<TypeChecker<'p>, Vec<Diagnostic>> {
+        let sources = std::mem::take(&mut self.sources);
+        let mut checker = TypeChecker::new(program, self.options.include_paths.clone(), self.options.target_platform, sources);
+        checker.defines = self.options.defines();
+        let result = checker.check_program();
+        self.sources = std::mem::take(&mut checker.sources);
+        for message in std::mem::take(&mut checker.progress) {
+            self.progress(&message);
+        }
+        self.warnings.append(&mut checker.warnings);
+        result.map(|_| checker)
+    }
+
+    // Aşama 3b: Monomorphization. Jenerik öğeler, tip kontrolünün kaydettiği tip argümanlarıyla somut
+    // kopyalara açılır. Dönen program jenerik içermez ve arka uçlardan önce yeniden kontrol edilmelidir;
+    // jenerik içermeyen programlarda `None` döner.
+    pub fn monomorphize(&self, program: &[Decl], checker: &TypeChecker<'_>) -> Result<Option<Vec<Decl>>, Vec<Diagnostic>> {
+        if !mono::has_generics(program) {
+            return Ok(None);
+        }
+        mono::monomorphize(program, &checker.instances).map(Some).map_err(|e| vec![e])
+    }
+
+    // Örneklenmiş programın tip kontrolü; ilk kontrolün ilerleme çıktısı ve uyarıları tekrarlanmaz.
+    fn check_instantiated<'p>(&mut self, program: &'p [Decl]) -> Result<TypeChecker<'p>, Vec<Diagnostic>> {
+        let verbose = std::mem::replace(&mut self.options.verbose, false);
+        let warnings = self.warnings.len();
+        let checker = self.check(program);
+        self.options.verbose = verbose;
+        self.warnings.truncate(warnings);
+        checker
+    }
+
+    // Aşama 4: GAS assembly üretimi (x86-64'te Intel sözdizimi, AArch64'te GNU as sözdizimi; WASI'de WAT).
+    pub fn codegen<'p>(&self, program: &'p [Decl], checker: &mut TypeChecker<'p>) -> Result<String, Diagnostic> {
+        let mut codegen = Codegen::new(program, checker, self.options.target_platform, self.options.opt_level())
+            .with_arch(self.options.arch);
+        if self.options.build_mode == BuildMode::Debug {
+            let comp_dir = std::env::current_dir().map(|dir| path_str(&dir)).unwrap_or_default();
+            codegen = codegen.with_debug_info(self.sources.names(), comp_dir);
+        }
+        let asm = codegen.generate()?;
+        // AST'den üretilen fonksiyonlar register ayırma ve optimizasyonlardan yararlanamaz.
+        for skipped in &codegen.ast_fallbacks {
+            self.progress(&format!("ℹ️ '{}' IR'ye indirilemedi, AST'den üretildi: {}", skipped.name, skipped.reason));
+        }
+        Ok(asm)
+    }
+
+    // Aşama 4 (--backend c): kontrol edilmiş programın tek başına derlenebilen C11 karşılığı.
+    pub fn codegen_c(&self, program: &[Decl], checker: &TypeChecker<'_>) -> Result<String, Diagnostic> {
+        c_backend::generate(program, checker)
+    }
+
+    // Kod üretiminin yazdığı kaynak çıktısı: native arka uçta assembly (WASI'de WAT), C arka ucunda C kaynağı.
+    pub fn source_artifact(&self) -> Artifact {
+        match self.options.backend {
+            Backend::Native if self.options.arch == TargetArch::Wasm32 => Artifact::Wat,
+            Backend::Native => Artifact::Asm,
+            Backend::C => Artifact::C,
+        }
+    }
+
+    // Kontrol edilmiş programı ağaç yorumlayıcısıyla çalıştırır ve çıkış kodunu döner.
+    // `args`, programın `args()` ile göreceği argümanlardır (kaynak dosya adı başa eklenir).
+    pub fn interpret(&self, program: &[Decl], args: Vec<String>) -> Result<i32, Diagnostic> {
+        let mut program_args = vec![self.name.clone()];
+        program_args.extend(args);
+        interpreter::run(program, program_args)
+    }
+
+    // Programı `emit` aşamasına kadar derler ve aşamanın çıktısını `sink`'e yazar. `Asm`, `Obj` ve
+    // `Exe` için assembly (C arka ucunda C kaynağı) yazılır; nesne dosyası ve bağlama `assemble` ve
+    // `link` ile yapılır.
+    pub fn compile(&mut self, emit: EmitKind, sink: &mut dyn OutputSink) -> Result<Compiled, Vec<Diagnostic>> {
+        self.progress(">>> NIMBLE (nim) Derleyicisi v0.0.1");
+        self.progress(">>> Aşama 1: Lexer (Sözcük Analizi)");
+        if emit == EmitKind::Tokens {
+            let dump: String = self
+                .tokens()
+                .iter()
+                .map(|token| format!("{}:{}\t{:?}\n", token.span.line, token.span.column, token.kind))
+                .collect();
+            write_artifact(sink, Artifact::Tokens, &dump)?;
+            return Ok(Compiled::default());
+        }
+        let tokens = self.lex()?;
+        let tokens = self.preprocess(tokens)?;
+
+        self.progress(">>> Aşama 2: Parser (Sözdizimi Analizi)");
+        let program = self.parse(tokens)?;
+        let uses_libc = !self.options.link_inputs.is_empty() || program.iter().any(|d| matches!(d, Decl::ExternFn { .. }));
+        let compiled = Compiled { uses_libc };
+        if emit == EmitKind::Ast {
+            write_artifact(sink, Artifact::Ast, &ast_dump::dump_program(&program, None))?;
+            return Ok(compiled);
+        }
+
+        self.progress(">>> Aşama 3: Semantik Analiz (Tip Kontrolü)");
+        let checker = self.check(&program)?;
+        self.progress(" ");
+        if emit == EmitKind::TypedAst {
+            let lookup = |expr: &Expr| checker.checked_type(expr);
+            write_artifact(sink, Artifact::TypedAst, &ast_dump::dump_program(&program, Some(&lookup)))?;
+            return Ok(compiled);
+        }
+        let instantiated = self.monomorphize(&program, &checker)?;
+        let (program, mut checker) = match &instantiated {
+            Some(instantiated) => (instantiated.as_slice(), self.check_instantiated(instantiated)?),
+            None => (program.as_slice(), checker),
+        };
+        if emit == EmitKind::Ir {
+            let mut module = ir::lower::lower_program(program, &mut checker, self.options.target_platform);
+            ir::opt::optimize(&mut module, self.options.opt_level());
+            write_artifact(sink, Artifact::Ir, &module.to_string())?;
+            return Ok(compiled);
+        }
+
+        if self.options.backend == Backend::C {
+            self.progress("\n>>> Aşama 4: Kod Üretimi (C11)");
+            let c_code = self.codegen_c(program, &checker).map_err(|e| vec![e])?;
+            write_artifact(sink, Artifact::C, &c_code)?;
+            return Ok(compiled);
+        }
+
+        self.progress("\n>>> Aşama 4: Kod Üretimi (Codegen)");
+        let asm_code = self.codegen(program, &mut checker).map_err(|e| vec![e])?;
+        write_artifact(sink, self.source_artifact(), &asm_code)?;
+        Ok(compiled)
+    }
+
+    // Assembly dosyasını nesne dosyasına (.o) derler. x86-64 Linux (ELF64) hedefinde yerleşik
+    // assembler kullanılır ve harici araç gerekmez; yerleşik assembler'ın kodlayamadığı bir komut
+    // (ör. asm bloğunda) olursa sistemde GCC varsa onunla yeniden denenir. AArch64 hedefi GNU araç zincirine
+    // (aarch64-linux-gnu-gcc), diğer hedefler GCC'ye bırakılır. C arka ucunda `asm_path` C
+    // kaynağıdır ve sistemin C derleyicisiyle derlenir. WASI hedefinde `asm_path` WAT metnidir ve
+    // yerleşik Wasm assembler'ı ile `obj_path`'e ikili modül olarak yazılır.
+    pub fn assemble(&self, asm_path: &Path, obj_path: &Path) -> Result<(), Diagnostic> {
+        if self.options.backend == Backend::C {
+            let cc = c_compiler(self.options.arch);
+            self.progress(&format!("⚙️ {} ile C kaynağı derleniyor...", cc));
+            let mut args = vec!["-std=c11", "-fwrapv"];
+            args.extend_from_slice(match self.options.build_mode {
+                BuildMode::Debug => &["-O0", "-g"][..],
+                BuildMode::Release => &["-O2"][..],
+            });
+            if self.options.output_type == OutputType::SharedLibrary {
+                args.push("-fPIC");
+            }
+            let (src, obj) = (path_str(asm_path), path_str(obj_path));
+            args.extend(["-c", src.as_str(), "-o", obj.as_str()]);
+            return run_tool(&cc, &args, "C derlemesi başarısız oldu.");
+        }
+        if self.options.arch == TargetArch::Wasm32 {
+            self.progress("⚙️ WAT yerleşik assembler ile WebAssembly modülüne çevriliyor...");
+            return assemble_wasm(asm_path, obj_path);
+        }
+        if self.options.arch == TargetArch::Aarch64 {
+            let gcc = aarch64_gcc();
+            self.progress(&format!("⚙️ {} ile AArch64 assembly derleniyor...", gcc));
+            let args = ["-x", "assembler", "-c", &path_str(asm_path), "-o", &path_str(obj_path)];
+            return run_tool(gcc, &args, "Assembly derlemesi başarısız oldu.");
+        }
+        if self.options.target_platform == TargetPlatform::Linux {
+            self.progress("⚙️ Assembly yerleşik assembler ile nesne dosyasına çevriliyor...");
+            let Err(builtin) = assemble_elf(asm_path, obj_path, "Assembly derlemesi başarısız oldu.") else {
+                return Ok(());
+            };
+            // GCC yoksa veya o da derleyemezse yerleşik assembler'ın (satır numaralı) hatası raporlanır.
+            self.progress("⚙️ Yerleşik assembler başarısız oldu; GCC ile yeniden deneniyor...");
+            let args = ["-x", "assembler", "-c", &path_str(asm_path), "-o", &path_str(obj_path)];
+            return run_tool("gcc", &args, "Assembly derlemesi başarısız oldu.").map_err(|_| builtin);
+        }
+        self.progress("⚙️ GCC ile Assembly derleniyor...");
+        run_tool("gcc", &["-x", "assembler", "-c", &path_str(asm_path), "-o", &path_str(obj_path)], "Assembly derlemesi başarısız oldu.")
+    }
+
+    // Nesne dosyasını bağlayarak `output_path` dosyasını üretir. Linux hedefinde syscall tabanlı
+    // runtime (libs/core_linux.s) nesne dosyasının yanına derlenir ve birlikte bağlanır. Dış (C)
+    // fonksiyon kullanmayan Linux programları yerleşik linker ile bağlanır; diğerleri GCC ile.
+    // --link ile verilen C kaynakları ve nesne dosyaları GCC'ye olduğu gibi iletilir.
+    // WASI modülü runtime'ı zaten içerdiği için bağlama, modülün çıktı dizinine kopyalanmasıdır.
+    pub fn link(&self, obj_path: &Path, output_path: &Path, compiled: &Compiled) -> Result<(), Diagnostic> {
+        if self.options.backend == Backend::C {
+            // C çıktısı kendi runtime'ını içerir; libc ve libm ile bağlanması yeterlidir.
+            let cc = c_compiler(self.options.arch);
+            let (obj, output) = (path_str(obj_path), path_str(output_path));
+            let mut args = vec![obj.as_str()];
+            args.extend(self.options.link_inputs.iter().map(String::as_str));
+            args.extend(["-o", output.as_str(), "-lm"]);
+            if self.options.output_type == OutputType::SharedLibrary {
+                args.insert(0, "-shared");
+            }
+            self.progress(&format!("🔗 {} ile bağlanıyor...", cc));
+            return run_tool(&cc, &args, "Linkleme başarısız oldu.");
+        }
+        if self.options.arch == TargetArch::Aarch64 {
+            return self.link_aarch64(obj_path, output_path, compiled);
+        }
+        if self.options.arch == TargetArch::Wasm32 {
+            return fs::copy(obj_path, output_path).map(|_| ()).map_err(|e| {
+                Diagnostic::error("Linkleme başarısız oldu.")
+                    .with_stage(Stage::Link)
+                    .with_code(E_LINK)
+                    .with_note(format!("'{}' yazılamadı: {}", output_path.display(), e))
+            });
+        }
+        let executable = self.options.output_type == OutputType::Executable;
+        let (obj, output) = (path_str(obj_path), path_str(output_path));
+        let args: Vec<String> = match self.options.target_platform {
+            TargetPlatform::Windows => {
+                let mut args = if executable { vec![] } else { vec!["-shared".to_string()] };
+                args.extend([obj, "libs/core.obj".to_string()]);
+                args.extend(self.options.link_inputs.iter().cloned());
+                args.extend(["-o".to_string(), output]);
+                args
+            }
+            TargetPlatform::Linux => {
+                let runtime_src = find_runtime(&self.options.include_paths, "core_linux.s").ok_or_else(|| {
+                    Diagnostic::error("Linux runtime'ı (core_linux.s) bulunamadı. '-I <yol>' ile libs dizinini belirtin.")
+                        .with_stage(Stage::Link)
+                        .with_code(E_LINK)
+                })?;
+                if executable && !compiled.uses_libc {
+                    self.progress("🔗 Yerleşik linker ile bağlanıyor...");
+                    return link_static(obj_path, Path::new(&runtime_src), output_path);
+                }
+                let runtime_obj = obj_path.with_file_name("core_linux.o");
+                assemble_elf(Path::new(&runtime_src), &runtime_obj, "Linux runtime derlemesi başarısız oldu.")?;
+                let runtime_obj = path_str(&runtime_obj);
+                // Dış (C) fonksiyon kullanan programlar libc ile bağlanır.
+                let mut args = vec![if executable { "-nostartfiles" } else { "-shared" }.to_string(), obj, runtime_obj];
+                args.extend(self.options.link_inputs.iter().cloned());
+                args.extend(["-o".to_string(), output]);
+                if executable {
+                    args.push("-no-pie".to_string());
+                }
+                args
+            }
+            TargetPlatform::Macos => {
+                let mut args = if executable { vec![] } else { vec!["-shared".to_string()] };
+                args.push(obj);
+                args.extend(self.options.link_inputs.iter().cloned());
+                args.extend(["-o".to_string(), output]);
+                args
+            }
+            TargetPlatform::Wasi | TargetPlatform::Unknown => {
+                return Err(Diagnostic::error("Bu platform için otomatik derleme ve linkleme desteklenmiyor.")
+                    .with_stage(Stage::Link)
+                    .with_code(E_LINK));
+            }
+        };
+
+        self.progress("🔗 Linker ile bağlanıyor...");
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        run_tool("gcc", &args, "Linkleme başarısız oldu.")
+    }
+
+    // AArch64 Linux: runtime (libs/core_linux_aarch64.s) ile birlikte GNU araç zinciriyle bağlar.
+    // Dış (C) fonksiyon kullanmayan programlar libc'siz statik olarak bağlanır.
+    fn link_aarch64(&self, obj_path: &Path, output_path: &Path, compiled: &Compiled) -> Result<(), Diagnostic> {
+        let runtime_src = find_runtime(&self.options.include_paths, "core_linux_aarch64.s").ok_or_else(|| {
+            Diagnostic::error("AArch64 Linux runtime'ı (core_linux_aarch64.s) bulunamadı. '-I <yol>' ile libs dizinini belirtin.")
+                .with_stage(Stage::Link)
+                .with_code(E_LINK)
+        })?;
+        let gcc = aarch64_gcc();
+        let runtime_obj = path_str(&obj_path.with_file_name("core_linux_aarch64.o"));
+        run_tool(gcc, &["-x", "assembler", "-c", &runtime_src, "-o", &runtime_obj], "AArch64 runtime derlemesi başarısız oldu.")?;
+
+        let (obj, output) = (path_str(obj_path), path_str(output_path));
+        let mode = match (self.options.output_type, compiled.uses_libc) {
+            (OutputType::SharedLibrary, _) => "-shared",
+            (OutputType::Executable, true) => "-nostartfiles",
+            (OutputType::Executable, false) => "-nostdlib",
+        };
+        let mut args = vec![mode, obj.as_str(), runtime_obj.as_str()];
+        args.extend(self.options.link_inputs.iter().map(String::as_str));
+        args.extend(["-o", output.as_str()]);
+        if self.options.output_type == OutputType::Executable {
+            args.push(if compiled.uses_libc { "-no-pie" } else { "-static" });
+        }
+        self.progress(&format!("🔗 {} ile bağlanıyor...", gcc));
+        run_tool(gcc, &args, "Linkleme başarısız oldu.")
+    }
+}
+
+// AArch64 için kullanılacak GCC: AArch64 makinede sistemin gcc'si, diğerlerinde çapraz derleyici.
+fn aarch64_gcc() -> &'static str {
+    match host_arch() {
+        TargetArch::Aarch64 => "gcc",
+        _ => "aarch64-linux-gnu-gcc",
+    }
+}
+
+// C arka ucunun derleyicisi: $CC ortam değişkeni, yoksa hedef mimarinin GCC'si veya sistemin `cc`'si.
+fn c_compiler(arch: TargetArch) -> String {
+    if let Some(cc) = std::env::var("CC").ok().filter(|cc| !cc.trim().is_empty()) {
+        return cc;
+    }
+    match arch {
+        TargetArch::Aarch64 => aarch64_gcc().to_string(),
+        _ => "cc".to_string(),
+    }
+}
+
+fn write_artifact(sink: &mut dyn OutputSink, artifact: Artifact, contents: &str) -> Result<(), Vec<Diagnostic>> {
+    sink.write_artifact(artifact, contents)
+        .map_err(|e| vec![Diagnostic::error(format!("Hata: '{}' çıktısı yazılamadı: {}", artifact.extension(), e))])
+}
+
+fn path_str(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+// Assembly dosyasını yerleşik assembler ile ELF64 nesne dosyasına çevirir. Hata, satır numarasıyla
+// birlikte notlara eklenmiş bir bağlama (link) hatası olarak döner.
+fn assemble_elf(asm_path: &Path, obj_path: &Path, message: &str) -> Result<(), Diagnostic> {
+    let error = |note: String| Diagnostic::error(message).with_stage(Stage::Link).with_code(E_LINK).with_note(note);
+    let source = fs::read_to_string(asm_path).map_err(|e| error(format!("'{}' okunamadı: {}", asm_path.display(), e)))?;
+    let object = assembler::assemble(&source).map_err(|e| error(format!("{}: {}", asm_path.display(), e)))?;
+    fs::write(obj_path, object.to_elf()).map_err(|e| error(format!("'{}' yazılamadı: {}", obj_path.display(), e)))
+}
+
+// WAT metnini yerleşik Wasm assembler'ı ile ikili WebAssembly modülüne çevirir.
+fn assemble_wasm(wat_path: &Path, wasm_path: &Path) -> Result<(), Diagnostic> {
+    let error = |note: String| {
+        Diagnostic::error("WebAssembly modülü oluşturulamadı.").with_stage(Stage::Link).with_code(E_LINK).with_note(note)
+    };
+    let source = fs::read_to_string(wat_path).map_err(|e| error(format!("'{}' okunamadı: {}", wat_path.display(), e)))?;
+    let module = assembler::wasm::assemble(&source).map_err(|e| error(format!("{}: {}", wat_path.display(), e)))?;
+    fs::write(wasm_path, module).map_err(|e| error(format!("'{}' yazılamadı: {}", wasm_path.display(), e)))
+}
+
+// Program nesnesini ve Linux runtime'ını yerleşik linker ile bağımsız bir çalıştırılabilir dosyaya bağlar.
+fn link_static(obj_path: &Path, runtime_src: &Path, output_path: &Path) -> Result<(), Diagnostic> {
+    let error = |note: String| Diagnostic::error("Linkleme başarısız oldu.").with_stage(Stage::Link).with_code(E_LINK).with_note(note);
+    let data = fs::read(obj_path).map_err(|e| error(format!("'{}' okunamadı: {}", obj_path.display(), e)))?;
+    let program = assembler::Object::from_elf(&data).map_err(|e| error(format!("{}: {}", obj_path.display(), e)))?;
+    let source = fs::read_to_string(runtime_src).map_err(|e| error(format!("'{}' okunamadı: {}", runtime_src.display(), e)))?;
+    let runtime = assembler::assemble(&source).map_err(|e| error(format!("{}: {}", runtime_src.display(), e)))?;
+    let image = linker::link_executable(&[program, runtime], "_start").map_err(error)?;
+    fs::write(output_path, image).map_err(|e| error(format!("'{}' yazılamadı: {}", output_path.display(), e)))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(output_path, fs::Permissions::from_mode(0o755))
+            .map_err(|e| error(format!("'{}' çalıştırılabilir yapılamadı: {}", output_path.display(), e)))?;
+    }
+    Ok(())
+}
+
+// Harici bir aracı (gcc) çalıştırır. Başarısız olursa aracın çıktısı notlara eklenmiş bir
+// bağlama (link) hatası döner; böylece JSON modunda stderr'e ham gcc çıktısı karışmaz.
+fn run_tool(program: &str, args: &[&str], message: &str) -> Result<(), Diagnostic> {
+    let output = Command::new(program).args(args).output().map_err(|e| {
+        Diagnostic::error(format!("{} çalıştırılamadı: {}. Sistem PATH'inde olduğundan emin olun.", program, e))
+            .with_stage(Stage::Link)
+            .with_code(E_LINK)
+    })?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let mut diagnostic = Diagnostic::error(message).with_stage(Stage::Link).with_code(E_LINK);
+    let tool_output = String::from_utf8_lossy(&output.stderr);
+    for line in tool_output.lines().filter(|l| !l.trim().is_empty()) {
+        diagnostic = diagnostic.with_note(line.to_string());
+    }
+    Err(diagnostic)
+}
+
+// Runtime kaynağını (`file_name`) arar: önce include yolları, sonra derleyicinin kendi libs dizini.
+fn find_runtime(include_paths: &[String], file_name: &str) -> Option<String> {
+    let mut candidates: Vec<PathBuf> = include_paths
+        .iter()
+        .map(|dir| Path::new(dir).join(file_name))
+        .collect();
+    candidates.push(Path::new("libs").join(file_name));
+    candidates.push(Path::new(env!("CARGO_MANIFEST_DIR")).join("libs").join(file_name));
+    candidates
+        .into_iter()
+        .find(|p| p.is_file())
+        .map(|p| p.to_string_lossy().into_owned())
+}
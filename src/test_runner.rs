@@ -0,0 +1,619 @@
+// src/test_runner.rs
+
+// YENİ: `nim test` alt komutu. Bir dizindeki (varsayılan: tests) her `.oc` dosyasını derler,
+// üretilen programı çalıştırır ve sonucu dosyanın yanındaki beklenti (golden) dosyalarıyla karşılaştırır.
+//
+// Beklenti dosyaları (<ad> = kaynak dosyanın uzantısız adı):
+//   <ad>.stdout  Programın beklenen standart çıktısı.
+//   <ad>.stderr  Programın beklenen hata çıktısı.
+//   <ad>.exit    Programın beklenen çıkış kodu.
+//   <ad>.error   Derlemenin hata vermesi bekleniyorsa, beklenen hata kodu (örn. E0100). Yalnızca
+//                lexer/önişlemci/parser/tip hataları için kullanılır; bunlar her arka uçta aynıdır.
+//   <ad>.xfail   Testin henüz geçemediği arka uçlar, satır başına bir anahtar ve `#` sonrası nedeni:
+//                native (x86-64), aarch64, wasm32, c, interp (yorumlayıcı) veya hepsi için `*`. Bu arka uçlarda test
+//                "BEKLENEN HATA" sayılır; geçerse anahtarın dosyadan kaldırılması için testi başarısız yapar.
+//   <ad>.ir      İsteğe bağlı; programın `--emit ir` çıktısı (varsayılan optimizasyon düzeyinde). Yalnızca
+//                native arka uçta karşılaştırılır. Hangi fonksiyonların IR'ye indirildiğini (indirilemeyenler
+//                `; fn <ad>: IR'ye indirilemedi` satırıyla görünür) ve optimizasyonların sonucunu sabitler.
+//   <ad>.c       İsteğe bağlı; programla birlikte bağlanan (`--link`) C kaynağı. `extern fn` bildirimlerinin
+//                C derleyicisinin çağrı kuralıyla uyuştuğunu sınayan ABI testleri için.
+//
+// `--bless` bayrağı beklenti dosyalarını mevcut sonuçlarla yeniden yazar (`.ir` yalnızca varsa güncellenir). Kod üretimi ve bağlama
+// hataları geçerli programların eksik arka uç desteğidir; `.error` olarak kaydedilmezler. Derleme hatası da yalnızca
+// zaten `.error` dosyası olan testlerde güncellenir: derlenemeyen geçerli bir program `.xfail` ile işaretlenmeli,
+// hata bekleyen yeni bir test ise `.error` dosyasıyla elle oluşturulmalıdır.
+//
+// `--interp` testleri derlemek yerine `nim run --interp` ile yorumlayıcıda çalıştırır ve aynı beklenti
+// dosyalarıyla karşılaştırır; böylece yorumlayıcının native arka uçla aynı çıktıyı verdiği sınanır. Beklenti
+// dosyaları native çıktıyı kaydettiği için `--interp` ile `--bless` kullanılamaz.
+//
+// `--target wasm32-wasi` ile üretilen modüller NIM_WASM_RUNNER ortam değişkenindeki komutla çalıştırılır
+// (örn. `wasmtime run --dir=.`). Değişken tanımlı değilse testler yine derlenir (`.error` beklentileri sınanır),
+// ancak çalıştırılmaları gereken testler başarısız değil "ATLANDI" sayılır.
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Derleyicinin ve üretilen programın çalışabileceği en uzun süreler.
+const COMPILE_TIMEOUT: Duration = Duration::from_secs(30);
+const RUN_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct TestOptions {
+    pub dir: String,
+    pub bless: bool,
+    pub target: Option<String>,
+    pub arch: Option<String>,
+    pub backend: Option<String>,
+    pub interp: bool,
+    pub include_paths: Vec<String>,
+}
+
+// Bir test dosyasının derlenip çalıştırılmasıyla elde edilen sonuç.
+enum Outcome {
+    Ran { stdout: String, stderr: String, exit: String },
+    CompileError { codes: Vec<String>, stages: Vec<String>, message: String },
+    CompilerTimeout,
+    RunTimeout,
+    Failed(String),
+    Skipped(String), // Program derlendi ama çalıştırılacak ortam yok (örn. NIM_WASM_RUNNER tanımsız)
+}
+
+pub enum Status {
+    Pass,
+    Fail(String),
+    Blessed(String),
+    ExpectedFailure(String), // `.xfail` ile işaretli arka uçta beklenen başarısızlık
+    Skipped(String),
+}
+
+fn parse_options(args: &[String]) -> Result<TestOptions, String> {
+    let mut options = TestOptions { dir: "tests".to_string(), bless: false, target: None, arch: None, backend: None, interp: false, include_paths: Vec::new() };
+    let mut dir_given = false;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--bless" => options.bless = true,
+            "--interp" => options.interp = true,
+            "--target" => {
+                options.target = Some(iter.next().cloned().ok_or("'--target' bayrağı bir platform (windows, linux, macos, wasm32-wasi) bekliyor.")?);
+            }
+            "--arch" => {
+                options.arch = Some(iter.next().cloned().ok_or("'--arch' bayrağı bir mimari (x86_64, aarch64) bekliyor.")?);
+            }
+            "--backend" => {
+                options.backend = Some(iter.next().cloned().ok_or("'--backend' bayrağı bir arka uç (native, c) bekliyor.")?);
+            }
+            _ if arg.starts_with("-I") => {
+                if arg.len() > 2 {
+                    options.include_paths.push(arg[2..].to_string());
+                } else {
+                    options.include_paths.push(iter.next().cloned().ok_or("'-I' bayrağı bir yol (path) bekliyor.")?);
+                }
+            }
+            _ if !arg.starts_with('-') && !dir_given => {
+                options.dir = arg.clone();
+                dir_given = true;
+            }
+            _ => return Err(format!("Bilinmeyen argüman veya bayrak: '{}'", arg)),
+        }
+    }
+    if options.interp && (options.target.is_some() || options.arch.is_some() || options.backend.is_some()) {
+        return Err("'--interp' bayrağı '--target', '--arch' ve '--backend' ile birlikte kullanılamaz.".to_string());
+    }
+    if options.interp && options.bless {
+        return Err("'--interp' ile '--bless' birlikte kullanılamaz; beklenti dosyaları native çıktıdan üretilir.".to_string());
+    }
+    Ok(options)
+}
+
+// `nim test` giriş noktası. Sürecin çıkış kodunu döner (0: tüm testler geçti).
+pub fn run(args: &[String]) -> i32 {
+    let options = match parse_options(args) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Yapılandırma Hatası: {}", e);
+            return 1;
+        }
+    };
+
+    let compiler = match std::env::current_exe() {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("Hata: Derleyicinin yolu bulunamadı: {}", e);
+            return 1;
+        }
+    };
+
+    let mut files: Vec<PathBuf> = match fs::read_dir(&options.dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "oc"))
+            .collect(),
+        Err(e) => {
+            eprintln!("Hata: Test dizini okunamadı: {}: {}", options.dir, e);
+            return 1;
+        }
+    };
+    files.sort();
+    if files.is_empty() {
+        eprintln!("Hata: '{}' dizininde .oc uzantılı test dosyası bulunamadı.", options.dir);
+        return 1;
+    }
+
+    let key = backend_key(&options);
+    let mut results = Vec::new();
+    for file in &files {
+        let name = file.file_stem().and_then(|s| s.to_str()).unwrap_or("?").to_string();
+        let outcome = run_test(&compiler, file, &options);
+        let status = match (&outcome, expected_failure(file, key)) {
+            (Outcome::Skipped(reason), _) => Status::Skipped(reason.clone()),
+            (_, Some(reason)) => check_expected_failure(file, &outcome, key, reason),
+            (_, None) if options.bless => bless(file, &outcome, key),
+            (_, None) => compare(file, &outcome),
+        };
+        let status = match status {
+            Status::Pass | Status::Blessed(_) if key == "native" => check_ir(&compiler, file, &options, status),
+            status => status,
+        };
+        results.push((name, status));
+    }
+
+    print_table(&results);
+    let failed = results.iter().filter(|(_, s)| matches!(s, Status::Fail(_))).count();
+    if failed > 0 { 1 } else { 0 }
+}
+
+// Test dosyasını derleyecek (yorumlayıcı modunda çalıştıracak) komut, test seçenekleriyle birlikte.
+fn compiler_command(compiler: &Path, file: &Path, options: &TestOptions) -> Command {
+    let mut command = Command::new(compiler);
+    if options.interp {
+        command.args(["run", "--interp"]).arg(file).args(["--error-format", "json"]);
+        for path in &options.include_paths {
+            command.arg("-I").arg(path);
+        }
+        return command;
+    }
+    command.arg(file).args(["--error-format", "json"]);
+    if let Some(target) = &options.target {
+        command.args(["--target", target]);
+    }
+    if let Some(arch) = &options.arch {
+        command.args(["--arch", arch]);
+    }
+    if let Some(backend) = &options.backend {
+        command.args(["--backend", backend]);
+    }
+    for path in &options.include_paths {
+        command.arg("-I").arg(path);
+    }
+    // WASI modülleri C koduyla bağlanamaz; bu testler wasm32'de `.xfail` ile işaretlenir.
+    let c_source = file.with_extension("c");
+    if c_source.exists() && backend_key(options) != "wasm32" {
+        command.arg("--link").arg(c_source);
+    }
+    command
+}
+
+// Test dosyasını derleyicinin kendisiyle (ayrı bir süreçte) derler ve üretilen programı çalıştırır.
+fn run_test(compiler: &Path, file: &Path, options: &TestOptions) -> Outcome {
+    if options.interp {
+        return interpret_test(compiler, file, options);
+    }
+    let compiled = match run_with_timeout(compiler_command(compiler, file, options), COMPILE_TIMEOUT) {
+        Ok(Some(output)) => output,
+        Ok(None) => return Outcome::CompilerTimeout,
+        Err(e) => return Outcome::Failed(format!("derleyici çalıştırılamadı: {}", e)),
+    };
+    if !compiled.status.success() {
+        return compile_error(&compiled.stderr);
+    }
+
+    // Derleyici, ürettiği dosyanın yolunu son satırlarından birinde bildirir.
+    let Some(binary) = compiled
+        .stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("✅ Başarıyla oluşturuldu: "))
+        .map(|path| path.trim().to_string())
+    else {
+        return Outcome::Failed("derleyici çalıştırılabilir dosya üretmedi".to_string());
+    };
+    if binary.ends_with(".wasm") && wasm_runner().is_none() {
+        return Outcome::Skipped("NIM_WASM_RUNNER tanımlı değil; WebAssembly modülü çalıştırılmadı".to_string());
+    }
+
+    match run_with_timeout(program_command(&binary, options), RUN_TIMEOUT) {
+        Ok(Some(output)) => Outcome::Ran { stdout: output.stdout, stderr: output.stderr, exit: exit_code(output.status) },
+        Ok(None) => Outcome::RunTimeout,
+        Err(e) => Outcome::Failed(format!("'{}' çalıştırılamadı: {}", binary, e)),
+    }
+}
+
+// Yorumlayıcı modu: derleme ve çalıştırma tek süreçtedir. Ön uç hataları (`runtime` dışındaki aşamalar)
+// derleme hatası sayılır; yorumlayıcının çalışma zamanı hataları programın stderr çıktısıdır.
+fn interpret_test(compiler: &Path, file: &Path, options: &TestOptions) -> Outcome {
+    let output = match run_with_timeout(compiler_command(compiler, file, options), COMPILE_TIMEOUT + RUN_TIMEOUT) {
+        Ok(Some(output)) => output,
+        Ok(None) => return Outcome::RunTimeout,
+        Err(e) => return Outcome::Failed(format!("yorumlayıcı çalıştırılamadı: {}", e)),
+    };
+    let front_end_error = output
+        .stderr
+        .lines()
+        .filter(|line| line.starts_with('{'))
+        .any(|line| json_field(line, "stage").is_some_and(|stage| stage != "runtime"));
+    if !output.status.success() && front_end_error {
+        return compile_error(&output.stderr);
+    }
+    Outcome::Ran { stdout: output.stdout, stderr: output.stderr, exit: exit_code(output.status) }
+}
+
+// Üretilen programı çalıştıracak komut. Hedef mimari sistemden farklıysa program
+// kullanıcı modu emülatörü (qemu-aarch64) üzerinden çalıştırılır. WebAssembly modülleri
+// NIM_WASM_RUNNER ortam değişkenindeki komutla çalıştırılır (run_test değişkenin tanımlı olduğunu denetler).
+fn program_command(binary: &str, options: &TestOptions) -> Command {
+    if let Some(runner) = wasm_runner().filter(|_| binary.ends_with(".wasm")) {
+        let mut words = runner.split_whitespace();
+        let mut command = Command::new(words.next().unwrap_or_default());
+        command.args(words).arg(binary);
+        return command;
+    }
+    let foreign_aarch64 = options.arch.as_deref().is_some_and(|arch| matches!(arch, "aarch64" | "arm64"))
+        && std::env::consts::ARCH != "aarch64";
+    if foreign_aarch64 {
+        let mut command = Command::new("qemu-aarch64");
+        command.arg(binary);
+        command
+    } else {
+        Command::new(binary)
+    }
+}
+
+// WebAssembly modüllerini çalıştıracak komut (NIM_WASM_RUNNER); tanımsız veya boşsa None.
+fn wasm_runner() -> Option<String> {
+    std::env::var("NIM_WASM_RUNNER").ok().filter(|runner| !runner.trim().is_empty())
+}
+
+// `.xfail` dosyalarında kullanılan arka uç anahtarı.
+fn backend_key(options: &TestOptions) -> &'static str {
+    if options.interp {
+        "interp"
+    } else if options.backend.as_deref() == Some("c") {
+        "c"
+    } else if options.target.as_deref() == Some("wasm32-wasi") || options.arch.as_deref() == Some("wasm32") {
+        "wasm32"
+    } else if options.arch.as_deref().is_some_and(|arch| matches!(arch, "aarch64" | "arm64")) {
+        "aarch64"
+    } else {
+        "native"
+    }
+}
+
+// Test bu arka uçta başarısız olmak üzere işaretlenmişse, `.xfail` satırındaki neden.
+fn expected_failure(file: &Path, key: &str) -> Option<String> {
+    let contents = read_expectation(file, "xfail")?;
+    contents.lines().find_map(|line| {
+        let (entry, reason) = line.split_once('#').unwrap_or((line, ""));
+        let entry = entry.trim();
+        (entry == key || entry == "*").then(|| reason.trim().to_string())
+    })
+}
+
+// `.xfail` ile işaretli testler: başarısızlık beklenir, geçiş ise işaretin kaldırılması gerektiğini gösterir.
+// `--bless` bu testlerin beklenti dosyalarına dokunmaz.
+fn check_expected_failure(file: &Path, outcome: &Outcome, key: &str, reason: String) -> Status {
+    let passed = matches!(compare(file, outcome), Status::Pass);
+    if passed {
+        let name = file.file_stem().and_then(|s| s.to_str()).unwrap_or("?");
+        return Status::Fail(format!("beklenen hata oluşmadı; {}.xfail dosyasından '{}' kaldırılmalı", name, key));
+    }
+    Status::ExpectedFailure(if reason.is_empty() { describe_outcome(outcome) } else { reason })
+}
+
+// Derleyicinin JSON hata çıktısından hata kodlarını, aşamalarını ve ilk hata mesajını çıkarır.
+fn compile_error(stderr: &str) -> Outcome {
+    let mut codes = Vec::new();
+    let mut stages = Vec::new();
+    let mut message = None;
+    for line in stderr.lines().filter(|l| l.starts_with('{')) {
+        if let Some(code) = json_field(line, "code") {
+            codes.push(code);
+        }
+        if let Some(stage) = json_field(line, "stage") {
+            stages.push(stage);
+        }
+        if message.is_none() {
+            message = json_field(line, "message");
+        }
+    }
+    let message = message
+        .or_else(|| stderr.lines().find(|l| !l.trim().is_empty()).map(str::to_string))
+        .unwrap_or_else(|| "derleme başarısız oldu".to_string());
+    Outcome::CompileError { codes, stages, message }
+}
+
+// Tek satırlık bir JSON nesnesinden metin tipindeki bir alanı okur.
+pub fn json_field(line: &str, key: &str) -> Option<String> {
+    let start = line.find(&format!("\"{}\":\"", key))? + key.len() + 4;
+    let mut value = String::new();
+    let mut chars = line[start..].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => return Some(value),
+            '\\' => match chars.next()? {
+                'n' => value.push('\n'),
+                'r' => value.push('\r'),
+                't' => value.push('\t'),
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    value.push(u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32).unwrap_or('?'));
+                }
+                other => value.push(other),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+fn compare(file: &Path, outcome: &Outcome) -> Status {
+    let expected_error = read_expectation(file, "error");
+    let expected_stdout = read_expectation(file, "stdout");
+    let expected_stderr = read_expectation(file, "stderr");
+    let expected_exit = read_expectation(file, "exit");
+
+    if expected_error.is_none() && expected_stdout.is_none() && expected_stderr.is_none() && expected_exit.is_none() {
+        return Status::Fail("beklenti dosyası yok (--bless ile oluşturun)".to_string());
+    }
+
+    match outcome {
+        Outcome::CompileError { codes, message, .. } => match expected_error {
+            Some(expected) if codes.iter().any(|c| c == expected.trim()) => Status::Pass,
+            Some(expected) => Status::Fail(format!("{} bekleniyordu, derleme hatası: {}", expected.trim(), describe_error(codes, message))),
+            None => Status::Fail(format!("beklenmeyen derleme hatası: {}", describe_error(codes, message))),
+        },
+        Outcome::Ran { stdout, stderr, exit } => {
+            if let Some(expected) = expected_error {
+                return Status::Fail(format!("{} hatası bekleniyordu, derleme başarılı oldu", expected.trim()));
+            }
+            let mut problems = Vec::new();
+            if let Some(expected) = expected_exit {
+                if expected.trim() != exit {
+                    problems.push(format!("çıkış kodu {} yerine {}", expected.trim(), exit));
+                }
+            }
+            if let Some(expected) = expected_stdout {
+                if let Some(diff) = first_difference(&expected, stdout) {
+                    problems.push(format!("stdout {}", diff));
+                }
+            }
+            if let Some(expected) = expected_stderr {
+                if let Some(diff) = first_difference(&expected, stderr) {
+                    problems.push(format!("stderr {}", diff));
+                }
+            }
+            if problems.is_empty() { Status::Pass } else { Status::Fail(problems.join("; ")) }
+        }
+        other => Status::Fail(describe_outcome(other)),
+    }
+}
+
+// `.ir` beklentisi olan testlerde `--emit ir` çıktısını karşılaştırır (`--bless` ile yeniden yazar).
+// `status`, testin çıktı karşılaştırmasının sonucudur; IR farkı yoksa olduğu gibi döner.
+fn check_ir(compiler: &Path, file: &Path, options: &TestOptions, status: Status) -> Status {
+    let Some(expected) = read_expectation(file, "ir") else {
+        return status;
+    };
+    let mut command = compiler_command(compiler, file, options);
+    command.args(["--emit", "ir"]);
+    let output = match run_with_timeout(command, COMPILE_TIMEOUT) {
+        Ok(Some(output)) if output.status.success() => output,
+        Ok(Some(output)) => return Status::Fail(format!("--emit ir başarısız: {}", describe_outcome(&compile_error(&output.stderr)))),
+        Ok(None) => return Status::Fail(describe_outcome(&Outcome::CompilerTimeout)),
+        Err(e) => return Status::Fail(format!("derleyici çalıştırılamadı: {}", e)),
+    };
+    let Some(actual) = output
+        .stdout
+        .lines()
+        .find_map(|line| line.strip_prefix("✅ Çıktı '")?.strip_suffix("' dosyasına yazıldı."))
+        .and_then(|path| fs::read_to_string(path).ok())
+    else {
+        return Status::Fail("derleyici IR çıktısı üretmedi".to_string());
+    };
+    if options.bless {
+        return match (fs::write(file.with_extension("ir"), &actual), status) {
+            (Err(e), _) => Status::Fail(format!("beklenti dosyası yazılamadı: {}", e)),
+            (Ok(()), Status::Blessed(detail)) => Status::Blessed(format!("{}, IR kaydedildi", detail)),
+            (Ok(()), status) => status,
+        };
+    }
+    match first_difference(&expected, &actual) {
+        Some(diff) => Status::Fail(format!("IR {}", diff)),
+        None => status,
+    }
+}
+
+fn bless(file: &Path, outcome: &Outcome, key: &str) -> Status {
+    let result = match outcome {
+        Outcome::Ran { stdout, stderr, exit } => write_expectations(file, &[("stdout", Some(stdout)), ("stderr", Some(stderr)), ("exit", Some(&format!("{}\n", exit))), ("error", None)]),
+        Outcome::CompileError { codes, stages, message } => {
+            let Some(code) = codes.first() else {
+                return Status::Fail(format!("hata kodu olmayan derleme hatası: {}", message));
+            };
+            if stages.iter().any(|stage| matches!(stage.as_str(), "codegen" | "link")) {
+                let name = file.file_stem().and_then(|s| s.to_str()).unwrap_or("?");
+                return Status::Fail(format!(
+                    "kod üretimi hatası beklenti olarak kaydedilmez ({}); arka uç desteklemiyorsa {}.xfail dosyasına '{}' ekleyin",
+                    describe_error(codes, message),
+                    name,
+                    key
+                ));
+            }
+            if read_expectation(file, "error").is_none() {
+                let name = file.file_stem().and_then(|s| s.to_str()).unwrap_or("?");
+                return Status::Fail(format!(
+                    "derleme hatası beklenti olarak kaydedilmedi ({}); hata bekleniyorsa {}.error dosyasını oluşturun, program geçerliyse {}.xfail dosyasına '*' ekleyin",
+                    describe_error(codes, message),
+                    name,
+                    name
+                ));
+            }
+            write_expectations(file, &[("stdout", None), ("stderr", None), ("exit", None), ("error", Some(&format!("{}\n", code)))])
+        }
+        other => return Status::Fail(describe_outcome(other)),
+    };
+    match result {
+        Ok(()) => Status::Blessed(match outcome {
+            Outcome::CompileError { codes, .. } => format!("derleme hatası {}", codes[0]),
+            _ => "çıktı kaydedildi".to_string(),
+        }),
+        Err(e) => Status::Fail(format!("beklenti dosyası yazılamadı: {}", e)),
+    }
+}
+
+// Verilen beklenti dosyalarını yazar; içeriği `None` olanları siler.
+fn write_expectations(file: &Path, entries: &[(&str, Option<&String>)]) -> std::io::Result<()> {
+    for (extension, content) in entries {
+        let path = file.with_extension(extension);
+        match content {
+            Some(content) => fs::write(&path, content)?,
+            None if path.exists() => fs::remove_file(&path)?,
+            None => {}
+        }
+    }
+    Ok(())
+}
+
+fn read_expectation(file: &Path, extension: &str) -> Option<String> {
+    fs::read_to_string(file.with_extension(extension)).ok()
+}
+
+fn describe_error(codes: &[String], message: &str) -> String {
+    match codes.first() {
+        Some(code) => format!("[{}] {}", code, message),
+        None => message.to_string(),
+    }
+}
+
+fn describe_outcome(outcome: &Outcome) -> String {
+    match outcome {
+        Outcome::CompilerTimeout => format!("derleyici {} saniyede bitmedi", COMPILE_TIMEOUT.as_secs()),
+        Outcome::RunTimeout => format!("program {} saniyede bitmedi", RUN_TIMEOUT.as_secs()),
+        Outcome::Failed(message) | Outcome::Skipped(message) => message.clone(),
+        Outcome::CompileError { codes, message, .. } => describe_error(codes, message),
+        Outcome::Ran { exit, .. } => format!("program {} koduyla bitti", exit),
+    }
+}
+
+// İki çıktının farklılaştığı ilk satırı tarif eder; aynıysa `None` döner.
+fn first_difference(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (Some(e), Some(a)) => return Some(format!("{}. satırda farklı: beklenen {:?}, gelen {:?}", line, e, a)),
+            (Some(e), None) => return Some(format!("{}. satır eksik: beklenen {:?}", line, e)),
+            (None, Some(a)) => return Some(format!("{}. satır fazla: gelen {:?}", line, a)),
+            (None, None) => return Some("satır sonları farklı".to_string()),
+        }
+    }
+}
+
+pub fn print_table(results: &[(String, Status)]) {
+    let width = results.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(4).max(4);
+    println!("{:<width$}  {:<11}  AYRINTI", "TEST", "SONUÇ", width = width);
+    println!("{}", "-".repeat(width + 24));
+    for (name, status) in results {
+        let (label, detail) = match status {
+            Status::Pass => ("GEÇTİ", ""),
+            Status::Fail(detail) => ("KALDI", detail.as_str()),
+            Status::Blessed(detail) => ("GÜNCELLENDİ", detail.as_str()),
+            Status::ExpectedFailure(detail) => ("BEKLENEN", detail.as_str()),
+            Status::Skipped(detail) => ("ATLANDI", detail.as_str()),
+        };
+        let row = format!("{:<width$}  {:<11}  {}", name, label, detail, width = width);
+        println!("{}", row.trim_end());
+    }
+
+    let passed = results.iter().filter(|(_, s)| matches!(s, Status::Pass)).count();
+    let failed = results.iter().filter(|(_, s)| matches!(s, Status::Fail(_))).count();
+    let blessed = results.iter().filter(|(_, s)| matches!(s, Status::Blessed(_))).count();
+    let expected = results.iter().filter(|(_, s)| matches!(s, Status::ExpectedFailure(_))).count();
+    let skipped = results.iter().filter(|(_, s)| matches!(s, Status::Skipped(_))).count();
+    let mut expected = if expected > 0 { format!(", {} beklenen hata", expected) } else { String::new() };
+    if skipped > 0 {
+        expected.push_str(&format!(", {} atlandı", skipped));
+    }
+    println!("{}", "-".repeat(width + 24));
+    if blessed > 0 {
+        println!("Toplam {} test: {} güncellendi{}, {} kaldı.", results.len(), blessed, expected, failed);
+    } else {
+        println!("Toplam {} test: {} geçti{}, {} kaldı.", results.len(), passed, expected, failed);
+    }
+}
+
+// Bir sürecin stdout/stderr çıktısı ve çıkış durumu.
+pub struct ProcessOutput {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+// Komutu stdin'i kapalı olarak çalıştırır; süre aşılırsa süreci öldürür ve `None` döner.
+pub fn run_with_timeout(mut command: Command, timeout: Duration) -> std::io::Result<Option<ProcessOutput>> {
+    let mut child = command.stdin(Stdio::null()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+    // Borular dolup süreç tıkanmasın diye çıktılar ayrı iş parçacıklarında okunur.
+    let stdout = child.stdout.take().map(read_in_background);
+    let stderr = child.stderr.take().map(read_in_background);
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            break None;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    let collect = |handle: Option<thread::JoinHandle<Vec<u8>>>| {
+        handle.and_then(|h| h.join().ok()).map(|bytes| String::from_utf8_lossy(&bytes).into_owned()).unwrap_or_default()
+    };
+    let stdout = collect(stdout);
+    let stderr = collect(stderr);
+    Ok(status.map(|status| ProcessOutput { status, stdout, stderr }))
+}
+
+fn read_in_background<R: Read + Send + 'static>(mut reader: R) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = reader.read_to_end(&mut buffer);
+        buffer
+    })
+}
+
+// Çıkış kodunu metne çevirir. Sinyalle sonlanan süreçler kabuklardaki gibi 128 + sinyal olarak yazılır.
+pub fn exit_code(status: ExitStatus) -> String {
+    if let Some(code) = status.code() {
+        return code.to_string();
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        if let Some(signal) = status.signal() {
+            return (128 + signal).to_string();
+        }
+    }
+    "?".to_string()
+}
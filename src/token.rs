@@ -1,3 +1,5 @@
+use crate::diagnostic::Span;
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum TokenType {
     // --- Single Character Tokens ---
@@ -68,9 +70,12 @@ pub enum TokenType {
     Recv,       // <- (ifade pozisyonunda)
     
     // --- Literals ---
-    IntLit(i64),     
+    IntLit(u64),     
     FloatLit(f64),
-    HexLit(i64),
+    HexLit(u64),
+    // YENİ: Tip sonekli sabitler (`10u8`, `100i128`, `3.0f32`); değer sonekin aralığında ve 128 bit saklanır.
+    TypedIntLit(u128, String),
+    TypedFloatLit(f64, String),
     StrLit(String),  // "Normal String"
     InterpolatedStr(String), // "Value: {val}" gibi
     CharLit(char),
@@ -86,7 +91,7 @@ pub enum TokenType {
     TypeStr, TypeArr, TypePtr, TypeRef, TypeBit, TypeByte, TypeHex, TypeDec,
 
     // Control Flow
-    If, Else, ElseIf, While, For, In, Loop, Return, Break, Continue, Match, Def,
+    If, Else, ElseIf, While, For, In, Loop, Return, Break, Continue, Match, Def, Defer,
     
     // Declarations & Modifiers
     Fn, Var, Const, Let, Struct, Enum, Group, Typedef,
@@ -103,20 +108,26 @@ pub enum TokenType {
 
     // --- Preprocessor ---
     Preprocessor(String), // #ifdef, #define vb.
+    AsmBody(String), // `asm: TAG { ... }` bloğunun ham içeriği
 
     // --- End of File ---
     Eof,
     Illegal(String),
+    // YENİ: Geçersiz veya aralık dışı sayı sabiti; içerik hata mesajıdır.
+    Error(String),
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct Token {
     pub kind: TokenType,
     pub line: usize,
+    pub span: Span, // YENİ: Dosya, satır, sütun ve byte aralığı
+    // YENİ: Token'dan hemen önce gelen `///` veya `/** */` doküman yorumu (parser bildirimlere ekler).
+    pub doc: Option<String>,
 }
 
 impl Token {
-    pub fn new(kind: TokenType, line: usize) -> Self {
-        Self { kind, line }
+    pub fn new(kind: TokenType, span: Span) -> Self {
+        Self { kind, line: span.line, span, doc: None }
     }
 }
\ No newline at end of file
@@ -1,7 +1,8 @@
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use crate::ast::{Decl, Expr, LiteralValue, Stmt, Type, BinOp, UnOp, TargetPlatform}; //  TargetPlatform'u ast'den al.
-use crate::{lexer::Lexer, parser::Parser};
+use crate::ast::{Decl, Expr, ExprKind, LiteralValue, Stmt, StmtKind, Type, TypeParam, BinOp, UnOp, TargetPlatform, asm_operands}; //  TargetPlatform'u ast'den al.
+use crate::{lexer::Lexer, parser::Parser, preprocessor::{self, Defines}};
+use crate::diagnostic::{type_error_code, Diagnostic, Severity, SourceMap, Span, Stage};
 
 // YENİ YAPI: Değişkenin tipini ve özelliklerini tutar
 #[derive(Debug, Clone)]
@@ -9,26 +10,33 @@ pub struct VarInfo {
     pub ty: Type,
     pub is_const: bool,
     pub _is_mutable: bool,
+    // Tanımın konumu (yeniden tanımlama ve tip uyuşmazlığı hatalarının ikincil etiketi); bilinmiyorsa dummy.
+    pub span: Span,
 }
 
+// Fonksiyon imzası: (parametreler (isim, tip, varsayılan değeri var mı), dönüş tipi, is_inline, is_public)
+pub type FnSignature = (Vec<(String, Type, bool)>, Type, bool, bool);
+// Metot imzası: (parametreler, dönüş tipi, is_public)
+pub type MethodSignature = (Vec<(String, Type, bool)>, Type, bool);
+
 //  Normal bir 'group' bloğunun içeriğini saklamak için.
 #[derive(Debug, Clone, Default)]
 pub struct GroupContent {
     // Grup içindeki fonksiyonlar: isim -> imza
-    pub functions: HashMap<String, (Vec<(String, Type, bool)>, Type, bool, bool)>,
+    pub functions: HashMap<String, FnSignature>,
     // Grup içindeki sabitler: isim -> bilgi
     pub constants: HashMap<String, VarInfo>,
 }
 // Tip Kontrolcüsü Struct'ı
 pub struct TypeChecker<'a> {
 	// Fonksiyon imzalarını (parametre tipleri, dönüş tipi) sakla
-	pub function_signatures: HashMap<String, (Vec<(String, Type, bool)>, Type, bool, bool)>, // (params, return_type, is_inline, is_public)
+	pub function_signatures: HashMap<String, FnSignature>, // (params, return_type, is_inline, is_public)
     //  Struct tanımlarını sakla: Struct Adı -> Alan Adı -> Alan Tipi
     pub struct_definitions: HashMap<String, HashMap<String, Type>>,
     //  Enum tanımlarını sakla: Enum Adı -> (Üye Adı -> Üye Tipi)
     pub enum_definitions: HashMap<String, HashMap<String, Type>>,
     //  Metot imzalarını sakla: Struct Adı -> Metot Adı -> (Parametreler, Dönüş Tipi)
-    pub method_signatures: HashMap<String, HashMap<String, (Vec<(String, Type, bool)>, Type, bool)>>, // NEW: is_public eklendi
+    pub method_signatures: HashMap<String, HashMap<String, MethodSignature>>, // NEW: is_public eklendi
     //  Tip takma adlarını sakla: Takma Ad -> Gerçek Tip
     pub type_aliases: HashMap<String, Type>,
     //  Normal grup tanımlarını sakla: Grup Adı -> Grup İçeriği
@@ -54,10 +62,49 @@ pub struct TypeChecker<'a> {
     pub labels: Vec<HashSet<String>>,
     program: &'a [Decl], // Reference to the whole program AST
     target_platform: TargetPlatform, //  Hedef platformu sakla.
+    // YENİ: Hata mesajlarında kaynak satırlarını göstermek için (ana dosya + yüklenen modüller).
+    pub sources: SourceMap,
+    // YENİ: Modül ayrıştırma hatasının konumu ve o an kontrol edilen bildirimin konumu.
+    error_span: Option<Span>,
+    current_decl_span: Span,
+    // YENİ: Raporlanacak hatalara eklenecek ikincil etiketler: (hata mesajı, konum, etiket).
+    // Err(String) konum taşıyamadığından etiket, mesajı raporlanana kadar burada bekler.
+    error_labels: Vec<(String, Span, String)>,
+    // YENİ: Hata biriktirme. check_program sırasında hatalar burada toplanır ve kontrol devam eder.
+    pub diagnostics: Vec<Diagnostic>,
+    // Raporlanan hata veya üretilen Unknown (zehirli) tip sayısı. Bir düğümün kontrolü sırasında
+    // bu sayı arttıysa, o düğümün kendi hatası önceki bir hatanın sonucudur ve raporlanmaz.
+    poison_count: usize,
+    // Sadece check_program sırasında true. Codegen'in type_of_expr çağrıları eskisi gibi Err alır.
+    recovering: bool,
+    // YENİ: Bilgi mesajları (tanımlanan fonksiyonlar, yüklenen modüller). Denetleyici bunları
+    // yazdırmaz; Session, verbose modda ilerleme çıktısı olarak gösterir.
+    pub progress: Vec<String>,
+    // YENİ: Derlemeyi durdurmayan uyarılar (Severity::Warning). Hatalardan ayrı tutulur.
+    pub warnings: Vec<Diagnostic>,
+    // YENİ: `use` ile yüklenen modüllerin önişlenmeye başladığı semboller (öntanımlılar ve -D).
+    pub defines: Defines,
+    // YENİ: check_program sırasında bulunan ifade tipleri (ifadenin adresi -> tip), `--emit typed-ast` için.
+    expr_types: HashMap<usize, Type>,
+    // YENİ: Jenerikler. Fonksiyon adı (grup üyelerinde `Grup.üye`) -> tip parametreleri.
+    pub generic_functions: HashMap<String, Vec<TypeParam>>,
+    //  Jenerik struct adı -> tip parametreleri (alan tipleri struct_definitions'ta Type::Param içerir).
+    pub generic_structs: HashMap<String, Vec<TypeParam>>,
+    //  Jenerik typedef adı -> (tip parametreleri, hedef tip).
+    pub generic_aliases: HashMap<String, (Vec<TypeParam>, Type)>,
+    //  Jenerik çağrı ve struct literal'larının yerleri -> (jenerik ad, çıkarılan tip argümanları).
+    //  Monomorphization (bkz. mono.rs) örnekleri bu kayıtlardan üretir.
+    pub instances: HashMap<Span, (String, Vec<Type>)>,
+    //  Kontrol edilen jenerik fonksiyonun (ve grubunun) kapsamdaki tip parametreleri.
+    type_params: Vec<TypeParam>,
 }
 
+// YENİ: Tip parametrelerine verilebilen yerleşik sınırlar. Dilde henüz trait sistemi olmadığından
+// sınırlar, tipin desteklediği işlem kümelerini adlandırır.
+pub const TYPE_BOUNDS: [&str; 5] = ["Num", "Int", "Float", "Eq", "Ord"];
+
 impl<'a> TypeChecker<'a> {
-    pub fn new(program: &'a [Decl], include_paths: Vec<String>, target_platform: TargetPlatform) -> Self {
+    pub fn new(program: &'a [Decl], include_paths: Vec<String>, target_platform: TargetPlatform, sources: SourceMap) -> Self {
 		let mut checker = TypeChecker {
 			function_signatures: HashMap::new(), 
             enum_definitions: HashMap::new(),
@@ -77,6 +124,22 @@ impl<'a> TypeChecker<'a> {
             labels: Vec::new(),
             program,
             target_platform,
+            sources,
+            error_span: None,
+            current_decl_span: Span::dummy(),
+            error_labels: Vec::new(),
+            diagnostics: Vec::new(),
+            poison_count: 0,
+            recovering: false,
+            progress: Vec::new(),
+            warnings: Vec::new(),
+            defines: Defines::default(),
+            expr_types: HashMap::new(),
+            generic_functions: HashMap::new(),
+            generic_structs: HashMap::new(),
+            generic_aliases: HashMap::new(),
+            instances: HashMap::new(),
+            type_params: Vec::new(),
 		};
 		
 		// Yerleşik fonksiyonları kaydet
@@ -141,8 +204,10 @@ impl<'a> TypeChecker<'a> {
     pub fn define_variable(&mut self, name: String, info: VarInfo) -> Result<(), String> {
 		let current_scope = self.scopes.last_mut().expect("Kapsam yığını boş olamaz.");
 
-		if current_scope.contains_key(&name) {
-			return Err(format!("Hata: '{}' değişkeni bu kapsamda zaten tanımlı.", name));
+		if let Some(previous) = current_scope.get(&name) {
+			let span = previous.span;
+			let message = format!("Hata: '{}' değişkeni bu kapsamda zaten tanımlı.", name);
+			return Err(self.labeled_error(message, span, format!("'{}' ilk olarak burada tanımlandı", name)));
 		}
 		current_scope.insert(name, info);
 		Ok(())
@@ -161,233 +226,366 @@ impl<'a> TypeChecker<'a> {
             let param_types = params.iter().map(|(_, ty, _)| ty.clone()).collect();
             let fn_type = Type::Fn(param_types, Box::new(ret_type.clone()));
             // Fonksiyonu bir "sabit" değişken gibi döndür.
-            return Ok(VarInfo { ty: fn_type, is_const: true, _is_mutable: false });
+            return Ok(VarInfo { ty: fn_type, is_const: true, _is_mutable: false, span: Span::dummy() });
         }
 
         // 3. Hiçbiri değilse hata ver.
         Err(format!("Hata: Tanımlanmamış isim: '{}'. Bu isim bir değişken, fonksiyon veya tip değil.", name))
     }
         
-	pub fn check_program(&mut self) -> Result<(), String> {
-    
+    // Programın tamamını kontrol eder. İlk hatada durmaz: tüm hatalar toplanır ve
+    // en az bir hata varsa hepsi (kaynak sırasına göre) döndürülür.
+    pub fn check_program(&mut self) -> Result<(), Vec<Diagnostic>> {
+        self.diagnostics.clear();
+        self.warnings.clear();
+        self.progress.clear();
+        self.error_labels.clear();
+        self.poison_count = 0;
+        self.recovering = true;
+        self.check_program_inner();
+        self.recovering = false;
+
+        if self.diagnostics.is_empty() {
+            return Ok(());
+        }
+        let mut diagnostics = std::mem::take(&mut self.diagnostics);
+        diagnostics.sort_by_key(|d| d.primary_span().map(|s| (s.file.0, s.start)));
+        Err(diagnostics)
+    }
+
+    // Bir hatayı kaydeder. Kontrol edilen düğümün alt düğümleri zaten hata verdiyse veya
+    // Unknown tip ürettiyse (poison_before'dan beri sayaç arttıysa) hata zincirleme sayılır ve atlanır.
+    fn report_error(&mut self, message: String, span: Span, poison_before: usize) {
+        let labels: Vec<_> = std::mem::take(&mut self.error_labels).into_iter().filter(|(m, ..)| *m == message).collect();
+        if self.poison_count == poison_before {
+            let code = type_error_code(&message);
+            let mut diagnostic = Diagnostic::error(message).with_stage(Stage::Type).with_code(code).with_fallback_span(span);
+            for (_, label_span, label) in labels {
+                diagnostic = diagnostic.with_secondary(label_span, label);
+            }
+            // Aynı ifade birden fazla kez kontrol edilebilir (örn: VarDecl başlangıç değeri); tekrarları atla.
+            let duplicate = self.diagnostics.iter().any(|d| d.message == diagnostic.message && d.primary_span() == diagnostic.primary_span());
+            if !duplicate {
+                self.diagnostics.push(diagnostic);
+            }
+        }
+        self.poison_count += 1;
+    }
+
+    // `message` hatası raporlandığında `span`'i ikincil etiketle ('-') işaretler ve mesajı döndürür.
+    fn labeled_error(&mut self, message: String, span: Span, label: String) -> String {
+        if !span.is_dummy() {
+            self.error_labels.push((message.clone(), span, label));
+        }
+        message
+    }
+
+    // Yeniden tanımlama hatası; önceki tanım ikincil etiketle gösterilir.
+    fn redefinition_error(&mut self, message: String, decl: &Decl) -> String {
+        let previous = self.previous_definition(decl);
+        let name = definition_name(decl).unwrap_or_default();
+        self.labeled_error(message, previous, format!("'{}' ilk olarak burada tanımlandı", name))
+    }
+
+    // Programda aynı türden ve aynı adla daha önce yapılmış üst düzey tanımın konumu (yoksa dummy).
+    fn previous_definition(&self, current: &Decl) -> Span {
+        let Some(name) = definition_name(current) else { return Span::dummy() };
+        self.program
+            .iter()
+            .take_while(|decl| !std::ptr::eq(*decl, current))
+            .find(|decl| std::mem::discriminant(*decl) == std::mem::discriminant(current) && definition_name(decl) == Some(name))
+            .map_or(Span::dummy(), Decl::span)
+    }
+
+    // Bildirim seviyesindeki bir hatayı kaydeder ve kapsamları bildirim öncesi duruma getirir.
+    fn report_decl_error(&mut self, result: Result<(), String>, scope_depth: usize, poison_before: usize) {
+        if let Err(message) = result {
+            let span = self.error_span.take().unwrap_or(self.current_decl_span);
+            self.report_error(message, span, poison_before);
+            self.scopes.truncate(scope_depth);
+            self.current_function_name = None;
+            self.current_function_params.clear();
+            self.type_params.clear();
+        }
+    }
+
+	fn check_program_inner(&mut self) {
 		for decl in self.program {
-			if let Decl::Function { name, params, return_type, is_async, is_inline, is_public, .. } = decl {
-				// (param name, param type, has default)
-				let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
-                //  Eğer fonksiyon 'async' ise, dönüş tipini Future<T> olarak sarmala.
-                let final_return_type = if *is_async {
-                    Type::Future(Box::new(return_type.clone()))
-                } else {
-                    return_type.clone()
-                };
-				self.function_signatures.insert(name.clone(), (param_info, final_return_type, *is_inline, *is_public));
-			} else if let Decl::Struct { name, fields, .. } = decl {
-                //  Struct tanımını kaydet
-                if self.struct_definitions.contains_key(name) {
-                    return Err(format!("Hata: '{}' struct'ı zaten tanımlanmış.", name));
-                }
-                let mut field_map = HashMap::new();
-                for (field_name, field_type) in fields {
-                    // TODO: Alan tiplerinin de geçerli olup olmadığını kontrol et.
-                    field_map.insert(field_name.clone(), field_type.clone());
-                }
-                self.struct_definitions.insert(name.clone(), field_map);
-			} else if let Decl::Enum { name, variants, .. } = decl {
-                if self.enum_definitions.contains_key(name) {
-                    return Err(format!("Hata: '{}' enum'u zaten tanımlanmış.", name));
-                }
-                let mut variant_map = HashMap::new();
-                let mut enum_base_type = Type::I32; // Varsayılan tip
-                for (variant_name, value_expr_opt) in variants {
-                    if let Some(value_expr) = value_expr_opt {
-                        // Değer atanmışsa, tipini kontrol et ve enum'un temel tipini belirle
-                        let variant_type = self.type_of_expr(value_expr)?;
-                        if !variant_type.is_integer() {
-                            return Err(format!("Hata: '{}' enum üyesine sadece tamsayı değer atanabilir, bulundu: {:?}.", variant_name, variant_type));
-                        }
-                        enum_base_type = variant_type; // Enum'un tipini son atanan üyenin tipi olarak al
+            self.current_decl_span = decl.span();
+            let (scope_depth, poison_before) = (self.scopes.len(), self.poison_count);
+            let result = self.register_decl(decl);
+            self.report_decl_error(result, scope_depth, poison_before);
+		}
+        //  Çıktıyı daha anlamlı hale getir. Sadece kullanıcı tanımlı ve içe aktarılan fonksiyonları listele.
+        let built_in_functions: HashSet<_> = ["echo", "print", "input", "strlen", "arrlen", "panic", "exit", "make_channel"].iter().cloned().collect();
+        let user_defined_functions: Vec<_> = self.function_signatures.keys()
+            .filter(|&name| !built_in_functions.contains(name.as_str()))
+            .collect();
+
+        if !user_defined_functions.is_empty() {
+		    self.progress.push(format!("✅ Tanımlanan Global Fonksiyonlar: {:?}", user_defined_functions));
+        }
+
+        // Grupları ve grup fonksiyonlarını da listele.
+        if !self.group_definitions.is_empty() {
+            self.progress.push("✅ Kaydedilen Gruplar ve Fonksiyonları:".to_string());
+            for (group_name, content) in &self.group_definitions {
+                self.progress.push(format!("  - Grup '{}': {:?}", group_name, content.functions.keys()));
+            }
+        }
+
+		for decl in self.program.iter() {
+            self.current_decl_span = decl.span();
+            let (scope_depth, poison_before) = (self.scopes.len(), self.poison_count);
+            let result = self.check_decl(decl);
+            self.report_decl_error(result, scope_depth, poison_before);
+		}
+    }
+
+    // Birinci geçiş: fonksiyon imzalarını, struct/enum/typedef/group tanımlarını ve `use` ile
+    // içe aktarılan öğeleri kaydeder.
+    fn register_decl(&mut self, decl: &Decl) -> Result<(), String> {
+		if let Decl::Function { name, type_params, params, return_type, is_async, is_inline, is_public, .. } = decl {
+            if !self.previous_definition(decl).is_dummy() {
+                return Err(self.redefinition_error(format!("Hata: '{}' fonksiyonu zaten tanımlanmış.", name), decl));
+            }
+            if !type_params.is_empty() {
+                check_type_params(type_params)?;
+                self.generic_functions.insert(name.clone(), type_params.clone());
+            }
+			// (param name, param type, has default)
+			let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
+            //  Eğer fonksiyon 'async' ise, dönüş tipini Future<T> olarak sarmala.
+            let final_return_type = if *is_async {
+                Type::Future(Box::new(return_type.clone()))
+            } else {
+                return_type.clone()
+            };
+			self.function_signatures.insert(name.clone(), (param_info, final_return_type, *is_inline, *is_public));
+		} else if let Decl::Struct { name, type_params, fields, .. } = decl {
+            //  Struct tanımını kaydet
+            if self.struct_definitions.contains_key(name) {
+                return Err(self.redefinition_error(format!("Hata: '{}' struct'ı zaten tanımlanmış.", name), decl));
+            }
+            if !type_params.is_empty() {
+                check_type_params(type_params)?;
+                self.generic_structs.insert(name.clone(), type_params.clone());
+            }
+            let mut field_map = HashMap::new();
+            for (field_name, field_type) in fields {
+                // TODO: Alan tiplerinin de geçerli olup olmadığını kontrol et.
+                field_map.insert(field_name.clone(), field_type.clone());
+            }
+            self.struct_definitions.insert(name.clone(), field_map);
+		} else if let Decl::Enum { name, variants, .. } = decl {
+            if self.enum_definitions.contains_key(name) {
+                return Err(self.redefinition_error(format!("Hata: '{}' enum'u zaten tanımlanmış.", name), decl));
+            }
+            let mut variant_map = HashMap::new();
+            let mut enum_base_type = Type::I32; // Varsayılan tip
+            for (variant_name, value_expr_opt) in variants {
+                if let Some(value_expr) = value_expr_opt {
+                    // Değer atanmışsa, tipini kontrol et ve enum'un temel tipini belirle
+                    let variant_type = self.type_of_expr(value_expr)?;
+                    if !variant_type.is_integer() {
+                        return Err(format!("Hata: '{}' enum üyesine sadece tamsayı değer atanabilir, bulundu: {}.", variant_name, variant_type));
                     }
-                    // Tüm üyeler aynı temel tipe sahip olmalı
-                    variant_map.insert(variant_name.clone(), Type::Enum(name.clone(), Box::new(enum_base_type.clone())));
+                    enum_base_type = variant_type; // Enum'un tipini son atanan üyenin tipi olarak al
                 }
-                self.enum_definitions.insert(name.clone(), variant_map);
+                // Tüm üyeler aynı temel tipe sahip olmalı
+                variant_map.insert(variant_name.clone(), Type::Enum(name.clone(), Box::new(enum_base_type.clone())));
+            }
+            self.enum_definitions.insert(name.clone(), variant_map);
 
-			} else if let Decl::Typedef { name, target, is_public: _ } = decl {
-                if self.type_aliases.contains_key(name) {
-                    return Err(format!("Hata: '{}' tip takma adı zaten tanımlanmış.", name));
-                }
+		} else if let Decl::Typedef { name, type_params, target, .. } = decl {
+            if self.type_aliases.contains_key(name) || self.generic_aliases.contains_key(name) {
+                return Err(self.redefinition_error(format!("Hata: '{}' tip takma adı zaten tanımlanmış.", name), decl));
+            }
+            if !type_params.is_empty() {
+                check_type_params(type_params)?;
+                self.generic_aliases.insert(name.clone(), (type_params.clone(), target.clone()));
+            } else {
                 self.type_aliases.insert(name.clone(), target.clone());
-			} else if let Decl::Group { name, body, .. } = decl {
-                //  Eğer group adı bir struct adıyla eşleşiyorsa, bunu bir metot bloğu olarak işle.
-                if self.struct_definitions.contains_key(name) {
-                    let mut new_methods = Vec::new();
-                    for decl in body {
-                        if let Decl::StmtDecl(stmt) = decl {
-                            if let Stmt::LabeledStmt { label: method_name, stmt: method_body, is_public } = &**stmt {
-                                if let Stmt::ExprStmt(expr) = &**method_body {
-                                    if let Expr::Lambda { params, return_type, .. } = expr {
-                                        if params.is_empty() || params[0].0 != "self" {
-                                            return Err(format!("Hata: '{}' struct'ının '{}' metodu 'self' parametresi ile başlamalıdır.", name, method_name.clone()));
-                                        }
-                                        // 'self' parametresinin tipinin struct'ın kendisi olduğunu doğrula
-                                        if self.resolve_type(&params[0].1)? != Type::Custom(name.clone()) {
-                                            return Err(format!("Hata: '{}' metodunun 'self' parametresi '{}' tipinde olmalıdır, bulundu: {:?}.", method_name.clone(), name, &params[0].1));
-                                        }
-                                        let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
-                                        new_methods.push((method_name.clone(), (param_info, return_type.clone(), *is_public)));
-                                    }
+            }
+		} else if let Decl::Group { name, type_params, body, .. } = decl {
+            //  Eğer group adı bir struct adıyla eşleşiyorsa, bunu bir metot bloğu olarak işle.
+            if self.struct_definitions.contains_key(name) {
+                let mut new_methods = Vec::new();
+                for decl in body {
+                    if let Decl::StmtDecl(stmt) = decl {
+                        if let StmtKind::LabeledStmt { label: method_name, stmt: method_body, is_public, .. } = &stmt.kind {
+                            if let StmtKind::ExprStmt(Expr { kind: ExprKind::Lambda { params, return_type, .. }, .. }) = &method_body.kind {
+                                if params.is_empty() || params[0].0 != "self" {
+                                    return Err(format!("Hata: '{}' struct'ının '{}' metodu 'self' parametresi ile başlamalıdır.", name, method_name.clone()));
+                                }
+                                // 'self' parametresinin tipinin struct'ın kendisi olduğunu doğrula
+                                if self.resolve_type(&params[0].1)? != Type::Custom(name.clone()) {
+                                    return Err(format!("Hata: '{}' metodunun 'self' parametresi '{}' tipinde olmalıdır, bulundu: {}.", method_name.clone(), name, &params[0].1));
                                 }
+                                let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
+                                new_methods.push((method_name.clone(), (param_info, return_type.clone(), *is_public)));
                             }
                         }
                     }
-                    // Döngü bittikten sonra toplu ekleme yaparak borrow checker hatasını çöz.
-                    let method_map = self.method_signatures.entry(name.clone()).or_insert_with(HashMap::new);
-                    for (name, signature) in new_methods {
-                        method_map.insert(name, signature);
+                }
+                // Döngü bittikten sonra toplu ekleme yaparak borrow checker hatasını çöz.
+                let method_map = self.method_signatures.entry(name.clone()).or_default();
+                for (name, signature) in new_methods {
+                    method_map.insert(name, signature);
+                }
+            } else {
+                //  Normal 'group' bloklarını işle.
+                if self.group_definitions.contains_key(name) {
+                    return Err(self.redefinition_error(format!("Hata: '{}' grubu zaten tanımlanmış.", name), decl));
+                }
+                check_type_params(type_params)?;
+                let mut content = GroupContent::default();
+                for decl in body {
+                    // YENİ: Grup gövdesindeki `fn` bildirimleri. Jenerik grubun üyeleri grubun tip parametrelerini paylaşır.
+                    if let Decl::Function { name: fn_name, type_params: own_params, params, return_type, is_inline, is_public, .. } = decl {
+                        if !own_params.is_empty() {
+                            return Err(format!("Hata: '{}' grubunun '{}' fonksiyonu kendi tip parametrelerini tanımlayamaz; tip parametreleri grup bildiriminde yazılır (group {}<T>).", name, fn_name, name));
+                        }
+                        let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
+                        content.functions.insert(fn_name.clone(), (param_info, return_type.clone(), *is_inline, *is_public));
+                        if !type_params.is_empty() {
+                            self.generic_functions.insert(format!("{}.{}", name, fn_name), type_params.clone());
+                        }
                     }
-                } else {
-                    //  Normal 'group' bloklarını işle.
-                    if self.group_definitions.contains_key(name) {
-                        return Err(format!("Hata: '{}' grubu zaten tanımlanmış.", name));
-                    }
-                    let mut content = GroupContent::default();
-                    for decl in body {
-                        if let Decl::StmtDecl(stmt) = decl {
-                            if let Stmt::LabeledStmt { label, stmt: inner_stmt, is_public } = &**stmt {
-                                if let Stmt::ExprStmt(expr) = &**inner_stmt {
-                                    if let Expr::Lambda { params, return_type, .. } = expr {
-                                        // Grup içindeki bir fonksiyon
-                                        let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
-                                        content.functions.insert(label.clone(), (param_info, return_type.clone(), false, *is_public));
-                                    }
+                    if let Decl::StmtDecl(stmt) = decl {
+                        match &stmt.kind {
+                            StmtKind::LabeledStmt { label, stmt: inner_stmt, is_public, .. } => {
+                                if let StmtKind::ExprStmt(Expr { kind: ExprKind::Lambda { params, return_type, .. }, .. }) = &inner_stmt.kind {
+                                    // Grup içindeki bir fonksiyon
+                                    let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
+                                    content.functions.insert(label.clone(), (param_info, return_type.clone(), false, *is_public));
                                 }
                             }
-                        } else if let Decl::StmtDecl(stmt) = decl {
-                            if let Stmt::VarDecl { name: var_name, ty, init: _, is_const, .. } = &**stmt {
-                                if *is_const {
-                                    // Grup içindeki bir sabit
-                                    let info = VarInfo { ty: ty.clone(), is_const: true, _is_mutable: false };
-                                    content.constants.insert(var_name.clone(), info);
-                                } else {
-                                    return Err(format!("Hata: '{}' grubu içinde sadece 'const' tanımlamalara izin verilir, 'var' veya 'let' kullanılamaz.", name));
-                                }
+                            // Grup içindeki bir sabit. `var`/`let` tanımları grubun dışa açtığı isimlere girmez.
+                            StmtKind::VarDecl { name: var_name, ty, is_const: true, .. } => {
+                                let info = VarInfo { ty: ty.clone(), is_const: true, _is_mutable: false, span: stmt.span };
+                                content.constants.insert(var_name.clone(), info);
                             }
+                            _ => {}
                         }
                     }
-                    self.group_definitions.insert(name.clone(), content);
                 }
-            } else if let Decl::ExternFn { name, params, return_type, is_public } = decl {
-                let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
-                // Dış fonksiyonlar 'async' veya 'inline' olamaz.
-                self.function_signatures.insert(name.clone(), (param_info, return_type.clone(), false, *is_public));
-
-            } else if let Decl::Use { path, spec, is_export: _is_export } = decl {
-                // `use my::module` için modül adı "module" olur.
-                let module_path_str = path.join("/");
-
-                match &spec { // spec'e referans alıyoruz çünkü taşımak istemiyoruz.
-                    crate::ast::UseSpec::All(alias_opt) => {
-                        if let Some(alias) = alias_opt {
-                            // `use my_module as my;` durumu.
-                            self.module_aliases.insert(alias.clone(), module_path_str.clone());
-                            // Sadece takma ad oluşturulur, tüm öğeler global kapsama dahil edilmez.
-                            continue;
-                        }
-                        self.import_all_from_module(&module_path_str)?;
-                    }
-                    crate::ast::UseSpec::Wildcard => {
-                        self.import_all_from_module(&module_path_str)?;
+                self.group_definitions.insert(name.clone(), content);
+            }
+        } else if let Decl::ExternFn { name, params, return_type, is_public, .. } = decl {
+            let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
+            // Dış fonksiyonlar 'async' veya 'inline' olamaz.
+            self.function_signatures.insert(name.clone(), (param_info, return_type.clone(), false, *is_public));
+
+        } else if let Decl::Use { path, spec, .. } = decl {
+            // `use my::module` için modül adı "module" olur.
+            let module_path_str = path.join("/");
+
+            match &spec { // spec'e referans alıyoruz çünkü taşımak istemiyoruz.
+                crate::ast::UseSpec::All(alias_opt) => {
+                    if let Some(alias) = alias_opt {
+                        // `use my_module as my;` durumu.
+                        self.module_aliases.insert(alias.clone(), module_path_str.clone());
+                        // Sadece takma ad oluşturulur, tüm öğeler global kapsama dahil edilmez.
+                        return Ok(());
                     }
-                    crate::ast::UseSpec::Specific(item_list) => {
-                        // `use my_module::{item1, item2};` durumu
-                        for item in item_list.iter() {
-                            match item {
-                                crate::ast::UseSpecItem::Item(item_name) => self.import_item_from_module(&module_path_str, item_name, Some(item_name))?,
-                                crate::ast::UseSpecItem::RenamedItem(original_name, alias) => self.import_item_from_module(&module_path_str, original_name, Some(alias))?,
-                            }
+                    self.import_all_from_module(&module_path_str)?;
+                }
+                crate::ast::UseSpec::Wildcard => {
+                    self.import_all_from_module(&module_path_str)?;
+                }
+                crate::ast::UseSpec::Specific(item_list) => {
+                    // `use my_module::{item1, item2};` durumu
+                    for item in item_list.iter() {
+                        match item {
+                            crate::ast::UseSpecItem::Item(item_name) => self.import_item_from_module(&module_path_str, item_name, Some(item_name))?,
+                            crate::ast::UseSpecItem::RenamedItem(original_name, alias) => self.import_item_from_module(&module_path_str, original_name, Some(alias))?,
                         }
                     }
                 }
             }
-		}
-        //  Çıktıyı daha anlamlı hale getir. Sadece kullanıcı tanımlı ve içe aktarılan fonksiyonları listele.
-        let built_in_functions: HashSet<_> = ["echo", "print", "input", "strlen", "arrlen", "panic", "exit", "make_channel"].iter().cloned().collect();
-        let user_defined_functions: Vec<_> = self.function_signatures.keys()
-            .filter(|&name| !built_in_functions.contains(name.as_str()))
-            .collect();
-
-        if !user_defined_functions.is_empty() {
-		    println!("✅ Tanımlanan Global Fonksiyonlar: {:?}", user_defined_functions);
-        }
-
-        // Grupları ve grup fonksiyonlarını yazdırmaya devam et.
-        if !self.group_definitions.is_empty() {
-            println!("✅ Kaydedilen Gruplar ve Fonksiyonları:");
-            for (group_name, content) in &self.group_definitions {
-                println!("  - Grup '{}': {:?}", group_name, content.functions.keys());
-            }
         }
+        Ok(())
+    }
 
-		for decl in self.program.iter() {
-			match decl {
-				Decl::Function { name, params, return_type, body, is_async, .. } => {
-					// 'async' bir fonksiyonun İÇİNDEKİ return'ler Future<T> değil, T döndürür.
-                    //  Mevcut fonksiyon bilgilerini güncelle.
-                    self.current_function_name = Some(name.clone());
-                    self.current_function_params = params.clone();
-					self.expected_return_type = return_type.clone();
-
-					self.push_scope(); 
-					
-					for (param_name, param_type, _) in params {
-						let info = VarInfo { 
-							ty: param_type.clone(), 
-							is_const: false, 
-							_is_mutable: false 
-						};
-						self.define_variable(param_name.clone(), info)?;
-					}
+    // İkinci geçiş: fonksiyon gövdelerini ve global deyimleri kontrol eder.
+    fn check_decl(&mut self, decl: &Decl) -> Result<(), String> {
+		match decl {
+			Decl::Function { name, type_params, params, return_type, body, is_async, .. } => {
+                // YENİ: Jenerik fonksiyonun gövdesi bir kez, tip parametreleri opak tipler olarak kontrol edilir.
+                let outer_params = self.type_params.len();
+                self.type_params.extend(type_params.iter().cloned());
+                for (_, param_type, _) in params {
+                    self.check_type_args(param_type)?;
+                }
+                self.check_type_args(return_type)?;
+				// 'async' bir fonksiyonun İÇİNDEKİ return'ler Future<T> değil, T döndürür.
+                //  Mevcut fonksiyon bilgilerini güncelle.
+                self.current_function_name = Some(name.clone());
+                self.current_function_params = params.clone();
+				self.expected_return_type = if return_type.has_generics() { self.resolve_type(return_type)? } else { return_type.clone() };
+
+				self.push_scope(); 
+				
+				for (param_name, param_type, _) in params {
+					let info = VarInfo { 
+						ty: if param_type.has_generics() { self.resolve_type(param_type)? } else { param_type.clone() },
+						is_const: false, 
+						_is_mutable: false,
+						span: self.current_decl_span,
+					};
+					self.define_variable(param_name.clone(), info)?;
+				}
 
-					if let Err(e) = self.check_stmt(body) {
-						let _ = self.pop_scope(); 
-						return Err(e);
-					}
-					
-					if !is_async && self.expected_return_type != Type::Void && !self.body_has_return(body) {
-						return Err(format!("Hata: '{}' fonksiyonu bir değer döndürmelidir, ancak bazı yollar 'return' ifadesi olmadan bitiyor.", name));
-					}
+				if let Err(e) = self.check_stmt(body) {
+					let _ = self.pop_scope(); 
+					return Err(e);
+				}
+				
+				if !is_async && self.expected_return_type != Type::Void && !self.body_has_return(body) {
+					return Err(format!("Hata: '{}' fonksiyonu bir değer döndürmelidir, ancak bazı yollar 'return' ifadesi olmadan bitiyor.", name));
+				}
 
-					self.pop_scope()?;
-                    //  Fonksiyon kontrolü bitti, bilgileri temizle.
-                    self.current_function_name = None;
-                    self.current_function_params.clear();
-				},
-                Decl::Group { body, .. } => {
-                    // Bir 'group' bloğu, kendi başına bir fonksiyon gibi davranmaz,
-                    // sadece bir kapsayıcıdır. Bu yüzden 'expected_return_type'ı
-                    // değiştirmemeli ve yeni bir fonksiyon kapsamı açmamalıyız.
-                    // Sadece içindeki deyimlerin geçerli olup olmadığını kontrol etmeliyiz.
-                    self.push_scope();
-                    for decl in body {
-                        if let Decl::StmtDecl(stmt) = decl {
-                            self.check_stmt(stmt)?;
-                        } else {
-                            // Handle other Decl types if they can appear in a group
-                        }
-                    }
-                    self.pop_scope()?;
+				self.pop_scope()?;
+                //  Fonksiyon kontrolü bitti, bilgileri temizle.
+                self.current_function_name = None;
+                self.current_function_params.clear();
+                self.type_params.truncate(outer_params);
+			},
+            Decl::Struct { type_params, fields, .. } => {
+                self.type_params = type_params.clone();
+                for (_, field_type) in fields {
+                    self.check_type_args(field_type)?;
                 }
-                Decl::Style { name, code } => {
-                    if self.styles.contains_key(name) {
-                        return Err(format!("Hata: '{}' stili zaten tanımlı.", name));
+                self.type_params.clear();
+            }
+            Decl::Group { type_params, body, .. } => {
+                // Bir 'group' bloğu, kendi başına bir fonksiyon gibi davranmaz,
+                // sadece bir kapsayıcıdır. Bu yüzden 'expected_return_type'ı
+                // değiştirmemeli ve yeni bir fonksiyon kapsamı açmamalıyız.
+                // Sadece içindeki deyimlerin geçerli olup olmadığını kontrol etmeliyiz.
+                self.push_scope();
+                self.type_params = type_params.clone();
+                for decl in body {
+                    if let Decl::StmtDecl(stmt) = decl {
+                        self.check_stmt(stmt)?;
+                    } else if let Decl::Function { .. } = decl {
+                        self.check_decl(decl)?;
                     }
-                    self.styles.insert(name.clone(), code.clone());
                 }
-				Decl::StmtDecl(stmt) => {
-					self.check_stmt(stmt)?;
-				}
-				_ => {}
+                self.type_params.clear();
+                self.pop_scope()?;
+            }
+            Decl::Style { name, code, .. } => {
+                if self.styles.contains_key(name) {
+                    return Err(format!("Hata: '{}' stili zaten tanımlı.", name));
+                }
+                self.styles.insert(name.clone(), code.clone());
+            }
+			Decl::StmtDecl(stmt) => {
+				self.check_stmt(stmt)?;
 			}
+			_ => {}
 		}
         Ok(())
     }
@@ -403,23 +601,25 @@ impl<'a> TypeChecker<'a> {
         let file_name = format!("{}.nim", module_path);
 
         //  Tüm include yollarında modülü ara.
-        let mut source: Option<String> = None;
+        let mut source: Option<(String, String)> = None;
         for path_prefix in &self.include_paths {
             let full_path = std::path::Path::new(path_prefix).join(&file_name);
             if let Ok(content) = fs::read_to_string(&full_path) {
-                source = Some(content);
-                println!("ℹ️ '{}' modülü yükleniyor...", full_path.display());
+                source = Some((full_path.display().to_string(), content));
+                self.progress.push(format!("ℹ️ '{}' modülü yükleniyor...", full_path.display()));
                 break;
             }
         }
 
-        let source = source.ok_or_else(|| {
-            format!("Hata: '{}' modülü arama yollarında bulunamadı: {:?}", file_name, self.include_paths)
+        let (full_path, source) = source.ok_or_else(|| {
+            format!("Hata: '{}' modülü arama yollarında bulunamadı: {}", file_name, self.include_paths.join(", "))
         })?;
+        //  Modül hataları da kaynak satırıyla gösterilebilsin diye dosyayı kaydet.
+        let file_id = self.sources.add(full_path, source.clone());
 
 
         // Modülün kaynak kodunu ayrıştır
-        let mut lexer = Lexer::new(&source);
+        let mut lexer = Lexer::with_file(&source, file_id);
         //  Token toplama mantığını, Eof'u da içerecek ve sonsuz döngüyü önleyecek şekilde düzelt.
         let mut tokens = Vec::new();
         loop {
@@ -430,17 +630,40 @@ impl<'a> TypeChecker<'a> {
                 break;
             }
         }
+        let lexer_errors = crate::lexer::errors(&tokens);
+        if !lexer_errors.is_empty() {
+            self.error_span = lexer_errors.iter().find_map(|e| e.primary_span());
+            let messages: Vec<String> = lexer_errors.iter().map(|e| e.message.clone()).collect();
+            return Err(format!("'{}' modülü okunurken hatalar oluştu:\n{}", file_name, messages.join("\n")));
+        }
+        let tokens = preprocessor::preprocess(tokens, &self.defines).map_err(|errors| {
+            self.error_span = errors.iter().find_map(|e| e.primary_span());
+            let messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
+            format!("'{}' modülü önişlenirken hatalar oluştu:\n{}", file_name, messages.join("\n"))
+        })?;
         let mut parser = Parser::new(tokens);
         let (ast, errors) = parser.parse();
 
         if !errors.is_empty() {
-            return Err(format!("'{}' modülü ayrıştırılırken hatalar oluştu:\n{}", file_name, errors.join("\n")));
+            self.error_span = errors.iter().find_map(|e| e.primary_span());
+            let messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
+            return Err(format!("'{}' modülü ayrıştırılırken hatalar oluştu:\n{}", file_name, messages.join("\n")));
         }
 
         self.loaded_modules.insert(module_path.to_string());
         Ok(ast)
     }
 
+    // İçe aktarılan bir öğe yok sayıldığında uyarı kaydeder: birincil konum `use` bildirimi,
+    // ikincil konum modüldeki tanımdır.
+    fn import_warning(&mut self, message: String, imported: Span) {
+        let warning = Diagnostic::new(Severity::Warning, message)
+            .with_stage(Stage::Type)
+            .with_fallback_span(self.current_decl_span)
+            .with_secondary(imported, "içe aktarılan tanım burada");
+        self.warnings.push(warning);
+    }
+
     //  Bir modüldeki tüm `pub` öğeleri içe aktarır.
     fn import_all_from_module(&mut self, module_path: &str) -> Result<(), String> {
         let ast = self.load_module(module_path)?;
@@ -448,16 +671,17 @@ impl<'a> TypeChecker<'a> {
         // AST'den `pub` öğeleri topla
         if let Decl::Program(declarations) = ast {
             for decl in declarations {
+                let decl_span = decl.span();
                 if let Decl::Function { name, params, return_type, is_async, is_inline, is_public, .. } = decl {
                     if is_public {
                         let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
                         let final_return_type = if is_async { Type::Future(Box::new(return_type.clone())) } else { return_type.clone() };
                         self.function_signatures.insert(name.clone(), (param_info, final_return_type, is_inline, is_public));
                     }
-                } else if let Decl::Struct { name, fields, is_public } = decl {
+                } else if let Decl::Struct { name, fields, is_public, .. } = decl {
                     if is_public {
                         if self.struct_definitions.contains_key(&name) {
-                            println!("Uyarı: '{}' modülünden içe aktarılan '{}' struct'ı zaten tanımlı, üzerine yazılmıyor.", module_path, name);
+                            self.import_warning(format!("'{}' modülünden içe aktarılan '{}' struct'ı zaten tanımlı, üzerine yazılmıyor.", module_path, name), decl_span);
                             continue;
                         }
                         let mut field_map = HashMap::new();
@@ -466,7 +690,7 @@ impl<'a> TypeChecker<'a> {
                         }
                         self.struct_definitions.insert(name.clone(), field_map);
                     }
-                } else if let Decl::Enum { name, variants, is_public } = decl {
+                } else if let Decl::Enum { name, variants, is_public, .. } = decl {
                     if is_public {
                         if self.enum_definitions.contains_key(&name) {
                             continue;
@@ -478,7 +702,7 @@ impl<'a> TypeChecker<'a> {
                         }
                         self.enum_definitions.insert(name.clone(), variant_map);
                     }
-                } else if let Decl::Typedef { name, target, is_public } = decl {
+                } else if let Decl::Typedef { name, target, is_public, .. } = decl {
                     if is_public {
                         if self.type_aliases.contains_key(&name) {
                             continue;
@@ -487,7 +711,7 @@ impl<'a> TypeChecker<'a> {
                     }
                 } else if let Decl::StmtDecl(stmt) = decl {
                     //  `pub const` gibi üst düzey deyimleri işle.
-                    if let Stmt::VarDecl { name, ty, is_const, is_let, is_public, .. } = &*stmt {
+                    if let StmtKind::VarDecl { name, ty, is_const, is_let, is_public, .. } = &stmt.kind {
                          if *is_public {
                             if *is_const || *is_let {
                                 // Sabitleri (`const`) ve değiştirilemez `let` değişkenlerini global kapsama ekle.
@@ -495,7 +719,7 @@ impl<'a> TypeChecker<'a> {
                                     return Err("İç Hata: Global kapsam bulunamadı.".to_string());
                                 }
                                 // `let` de değiştirilemez olduğu için `is_const: true` olarak işaretleyebiliriz.
-                                let info = VarInfo { ty: ty.clone(), is_const: *is_const || *is_let, _is_mutable: false };
+                                let info = VarInfo { ty: ty.clone(), is_const: *is_const || *is_let, _is_mutable: false, span: stmt.span };
                                 // Global kapsama (ilk kapsama) doğrudan ekle.
                                 self.scopes[0].insert(name.clone(), info);
                             } else {
@@ -503,7 +727,7 @@ impl<'a> TypeChecker<'a> {
                             }
                         }
                     }
-                } else if let Decl::Use { path, spec, is_export } = decl {
+                } else if let Decl::Use { path, spec, is_export, .. } = decl {
                     //  Eğer `export use ...` varsa, bu modülün öğelerini de içe aktar.
                     if is_export {
                         self.handle_use_declaration(&path, &spec, true)?;
@@ -512,7 +736,7 @@ impl<'a> TypeChecker<'a> {
                     //  `export group ...` bildirimini işle.
                     if is_export {
                         if self.group_definitions.contains_key(&name) {
-                            println!("Uyarı: '{}' modülünden içe aktarılan '{}' grubu zaten tanımlı, üzerine yazılmıyor.", module_path, name);
+                            self.import_warning(format!("'{}' modülünden içe aktarılan '{}' grubu zaten tanımlı, üzerine yazılmıyor.", module_path, name), decl_span);
                             continue;
                         }
                         let mut content = GroupContent::default();
@@ -520,19 +744,18 @@ impl<'a> TypeChecker<'a> {
                         for inner_decl in body {
                             match inner_decl {
                                 Decl::StmtDecl(stmt) => { // This now matches correctly
-                                    if let Stmt::VarDecl { name: var_name, ty, is_const, is_public, .. } = &*stmt {
+                                    if let StmtKind::VarDecl { name: var_name, ty, is_const, is_public, .. } = &stmt.kind {
                                         if *is_public && *is_const {
-                                            let info = VarInfo { ty: ty.clone(), is_const: true, _is_mutable: false };
+                                            let info = VarInfo { ty: ty.clone(), is_const: true, _is_mutable: false, span: stmt.span };
                                             content.constants.insert(var_name.clone(), info);
                                         }
                                     }
                                 }
-                                Decl::ExternFn { name: fn_name, params, return_type, is_public } => {
-                                    if is_public {
+                                Decl::ExternFn { name: fn_name, params, return_type, is_public, .. }
+                                    if is_public => {
                                         let param_info: Vec<(String, Type, bool)> = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
                                         content.functions.insert(fn_name.clone(), (param_info, return_type.clone(), false, is_public));
-                                    }
-                                },
+                                    },
                                 _ => {} // Diğer bildirim türleri (örn: iç içe group) şimdilik yoksayılıyor.
                             }
                         }
@@ -553,17 +776,16 @@ impl<'a> TypeChecker<'a> {
             let mut found = false;
             for decl in declarations {
                 match decl {
-                    Decl::Function { name, params, return_type, is_async, is_inline, is_public, .. } if name == *original_name => {
-                        if is_public {
+                    Decl::Function { name, params, return_type, is_async, is_inline, is_public, .. } if name == *original_name
+                        && is_public => {
                             let param_info = params.iter().map(|(n, t, d)| (n.clone(), t.clone(), d.is_some())).collect();
                             let final_return_type = if is_async { Type::Future(Box::new(return_type.clone())) } else { return_type.clone() };
                             self.function_signatures.insert(final_name, (param_info, final_return_type, is_inline, is_public));
                             found = true;
                             break;
                         }
-                    }
-                    Decl::Struct { name, fields, is_public } if name == *original_name => {
-                        if is_public {
+                    Decl::Struct { name, fields, is_public, .. } if name == *original_name
+                        && is_public => {
                             if self.struct_definitions.contains_key(&final_name) { continue; }
                             let mut field_map = HashMap::new();
                             for (field_name, field_type) in fields {
@@ -573,11 +795,10 @@ impl<'a> TypeChecker<'a> {
                             found = true;
                             break;
                         }
-                    }
                     Decl::StmtDecl(stmt) => {
-                        if let Stmt::VarDecl { name, ty, is_const, is_public, .. } = &*stmt {
+                        if let StmtKind::VarDecl { name, ty, is_const, is_public, .. } = &stmt.kind {
                             if *is_public && *is_const && *name == *original_name {
-                                let info = VarInfo { ty: ty.clone(), is_const: true, _is_mutable: false };
+                                let info = VarInfo { ty: ty.clone(), is_const: true, _is_mutable: false, span: stmt.span };
                                 self.define_variable(final_name.clone(), info)?;
                                 found = true;
                                 break;
@@ -596,15 +817,16 @@ impl<'a> TypeChecker<'a> {
     }
 
     //  `use` ve `export use` bildirimlerini işleyen merkezi fonksiyon.
-    fn handle_use_declaration(&mut self, path: &Vec<String>, spec: &crate::ast::UseSpec, is_reexport: bool) -> Result<(), String> {
+    fn handle_use_declaration(&mut self, path: &[String], spec: &crate::ast::UseSpec, is_reexport: bool) -> Result<(), String> {
         //  Platforma özel modül yükleme mantığı
-        let mut processed_path = path.clone();
+        let mut processed_path = path.to_vec();
         if let Some(last_part) = processed_path.last_mut() {
             if *last_part == "platform" {
                 *last_part = match self.target_platform {
                     TargetPlatform::Windows => "windows".to_string(),
                     TargetPlatform::Linux => "linux".to_string(),
                     TargetPlatform::Macos => "macos".to_string(),
+                    TargetPlatform::Wasi => "wasi".to_string(),
                     TargetPlatform::Unknown => return Err("Hata: Platforma özel modül yüklemek için bir hedef platform (--target) belirtilmelidir.".to_string()),
                 };
             }
@@ -638,22 +860,46 @@ impl<'a> TypeChecker<'a> {
     }
 
     fn body_has_return(&self, body: &Stmt) -> bool {
-        if let Stmt::Block(stmts) = body {
-            if let Some(last_stmt) = stmts.last() {
-                if let Stmt::Return(_) = last_stmt {
-                    return true;
-                }
+        match &body.kind {
+            StmtKind::Block(stmts) => stmts.last().is_some_and(|last| self.body_has_return(last)),
+            StmtKind::Return(_) => true,
+            //  if/else zincirinin tüm kolları dönüyorsa, deyim de her yolda döner.
+            StmtKind::If { then_branch, else_branch: Some(else_branch), .. } => {
+                self.body_has_return(then_branch) && self.body_has_return(else_branch)
             }
-        } else if let Stmt::Return(_) = body {
-            return true;
+            _ => false,
         }
-        false
     }
 
 
+    // Hata biriktirme modunda deyim hatası raporlanır ve kontrol bir sonraki deyimle devam eder.
     fn check_stmt(&mut self, stmt: &Stmt) -> Result<(), String> {
-        match stmt {
-            Stmt::Block(stmts) => {
+        if !self.recovering {
+            return self.check_stmt_inner(stmt);
+        }
+        let (scope_depth, poison_before) = (self.scopes.len(), self.poison_count);
+        if let Err(message) = self.check_stmt_inner(stmt) {
+            self.report_error(message, stmt.span, poison_before);
+            self.scopes.truncate(scope_depth);
+            // Hatalı bir tanımın değişkenini yine de tanımla; yoksa sonraki her kullanımı
+            // "tanımlanmamış değişken" hatası verir.
+            if let StmtKind::VarDecl { name, ty, is_const, is_mutable, .. } = &stmt.kind {
+                let declared_here = self.scopes.last().is_some_and(|scope| scope.contains_key(name));
+                if !declared_here {
+                    let ty = match self.resolve_type(ty) {
+                        Ok(Type::Any) | Err(_) => Type::Unknown,
+                        Ok(resolved) => resolved,
+                    };
+                    let _ = self.define_variable(name.clone(), VarInfo { ty, is_const: *is_const, _is_mutable: *is_mutable, span: stmt.span });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn check_stmt_inner(&mut self, stmt: &Stmt) -> Result<(), String> {
+        match &stmt.kind {
+            StmtKind::Block(stmts) => {
 				self.push_scope();
 				for stmt in stmts {
 					self.check_stmt(stmt)?;
@@ -661,14 +907,15 @@ impl<'a> TypeChecker<'a> {
 				self.pop_scope()?;
 				Ok(())
 			},
-			Stmt::Empty => Ok(()),
-			Stmt::VarDecl { name, ty, init, is_const, is_let: _is_let, is_mutable, .. } => {
+			StmtKind::Empty => Ok(()),
+			StmtKind::VarDecl { name, ty, init, is_const, is_let: _is_let, is_mutable, .. } => {
 				if *is_const && init.is_none() {
 					return Err(format!("Hata: Sabit (const) '{}' tanımlanırken bir başlangıç değeri atanmalıdır.", name));
 				}
 				if *is_const && *is_mutable {
 					return Err(format!("Hata: '{}' hem sabit (const) hem de değiştirilebilir (mut) olarak tanımlanamaz.", name));
 				}
+                self.check_type_args(ty)?;
                 //  Değişkenin tipi bir struct, enum veya takma ad ise, geçerli olup olmadığını kontrol et.
                 if let Type::Custom(type_name) = self.resolve_type(ty)? {
                     // Eğer bu bir enum ise, onu özel Enum tipine dönüştür.
@@ -685,51 +932,32 @@ impl<'a> TypeChecker<'a> {
                     //  Karşılaştırma yapmadan önce deklare edilen tipi çözümle.
                     // Bu, typedef'lerin (örn: UserID) temel tipleriyle (örn: u64) doğru şekilde karşılaştırılmasını sağlar.
                     let resolved_ty = self.resolve_type(ty)?;
+                    // Soneksiz tamsayı sabiti bildirilen tipin aralığında olmalıdır.
+                    let literal_fits = int_literal_fits(init_expr, &resolved_ty)?;
 
                     // Decimal tiplere float atamasını kontrol et
-                    let allow_decimal_float_assignment_var_decl = match (ty, &init_type) {
-                        (Type::D32 | Type::D64 | Type::D128, Type::F32 | Type::F64 | Type::F80 | Type::F128) => true,
-                        _ => false,
-                    };
+                    let allow_decimal_float_assignment_var_decl = matches!((ty, &init_type), (Type::D32 | Type::D64 | Type::D128, Type::F32 | Type::F64 | Type::F80 | Type::F128));
 
                     // Bit tipine integer literal atamasını kontrol et (0 veya 1)
-                    let allow_bit_int_assignment_var_decl = match (ty, init_expr) {
-                        (Type::Bit, Expr::Literal(LiteralValue::Int(val))) if *val == 0 || *val == 1 => true,
-                        _ => false,
-                    };
+                    let allow_bit_int_assignment_var_decl = matches!((ty, &init_expr.kind), (Type::Bit, ExprKind::Literal(LiteralValue::Int(val))) if *val == 0 || *val == 1);
 
                     // Bit dizisine integer literal atamasını kontrol et
-                    let allow_int_to_bit_array_assignment = match (ty, init_expr) {
-                        (Type::Array(inner, _), Expr::Literal(LiteralValue::Int(_))) if **inner == Type::Bit => true,
-                        _ => false,
-                    };
-                    
-                    // I32 literalden Byte atamasına izin ver
-                    let allow_byte_i32_assignment_var_decl = match (ty, init_expr) {
-                        (Type::Byte, Expr::Literal(LiteralValue::Int(val))) if *val >= 0 && *val <= 255 => true,
-                        _ => false,
-                    };
-                    
-                    // I32 literalden Hex atamasına izin ver
-                    let allow_hex_i32_assignment_var_decl = match (ty, init_expr) {
-                        (Type::Hex, Expr::Literal(LiteralValue::Int(val))) if *val >= 0 && *val <= 255 => true,
-                        _ => false,
-                    };
+                    let allow_int_to_bit_array_assignment = matches!((ty, &init_expr.kind), (Type::Array(inner, _), ExprKind::Literal(LiteralValue::Int(_))) if **inner == Type::Bit);
 
                     if let (Type::Array(expected_inner_type, _), Type::ArrayLiteral(element_types)) = (&resolved_ty, &init_type) {
                         if !element_types.is_empty() {
                             let first_element_type = &element_types[0];
                             for element_type in element_types.iter().skip(1) {
                                 if element_type != first_element_type {
-                                    return Err(format!("Hata: Dizi başlatıcısındaki tüm elemanlar aynı tipte olmalıdır. Bulunan tipler: {:?}.", element_types));
+                                    return Err(format!("Hata: Dizi başlatıcısındaki tüm elemanlar aynı tipte olmalıdır. Bulunan tipler: {}.", type_list(element_types)));
                                 }
                             }
                             if expected_inner_type.as_ref() != &Type::Unknown && expected_inner_type.as_ref() != first_element_type {
-                                return Err(format!("Hata: '{}' dizisine atanmaya çalışılan eleman tipi ({:?}), beklenen tip ({:?}) ile uyuşmuyor.", name, first_element_type, expected_inner_type.as_ref()));
+                                return Err(format!("Hata: '{}' dizisine atanmaya çalışılan eleman tipi ({}), beklenen tip ({}) ile uyuşmuyor.", name, first_element_type, expected_inner_type.as_ref()));
                             }
                         }
                     } else if let Type::Fn(param_types, ret_type) = &resolved_ty {
-                        if let Expr::Variable(fn_name) = init_expr {
+                        if let ExprKind::Variable(fn_name) = &init_expr.kind {
                             if let Some((expected_params, expected_ret, _, _)) = self.function_signatures.get(fn_name) {
                                 let expected_param_types: Vec<_> = expected_params.iter().map(|(_, ty, _)| ty.clone()).collect();
                                 if &expected_param_types != param_types || expected_ret != ret_type.as_ref() {
@@ -738,13 +966,14 @@ impl<'a> TypeChecker<'a> {
                             } else {
                                 return Err(format!("Hata: Atanmaya çalışılan '{}' fonksiyonu bulunamadı.", fn_name));
                             }
-                        } else if let Expr::Lambda { params, return_type, .. } = init_expr {
+                        } else if let ExprKind::Lambda { params, return_type, .. } = &init_expr.kind {
                             let lam_param_types: Vec<_> = params.iter().map(|(_, ty, _)| ty.clone()).collect();
                             if &lam_param_types != param_types || return_type != ret_type.as_ref() {
                                 return Err(format!("Hata: '{}' değişkenine atanan lambda imzası uyumsuz.", name));
                             }
                         } else if init_type != resolved_ty {
-                            return Err(format!("Hata: '{}' değişkenine atanmaya çalışılan tip ({:?}), deklare edilen tip ({:?}) ile uyuşmuyor.", name, init_type, &resolved_ty));
+                            let message = format!("Hata: '{}' değişkenine atanmaya çalışılan tip ({}), deklare edilen tip ({}) ile uyuşmuyor.", name, init_type, &resolved_ty);
+                            return Err(self.labeled_error(message, init_expr.span, format!("bu ifadenin tipi {}", init_type)));
                         }
                     } else if init_type != resolved_ty && resolved_ty != Type::Any && init_type != Type::Any && init_type != Type::Null {
                         
@@ -753,22 +982,17 @@ impl<'a> TypeChecker<'a> {
                             if other.is_integer() && base_type.is_integer() {
                                 // Tipler uyumlu, devam et.
                             } else {
-                                return Err(format!("Hata: '{}' enum değişkenine atanmaya çalışılan tip ({:?}), beklenen tamsayı tabanlı tip ile uyuşmuyor.", name, init_type));
+                                return Err(format!("Hata: '{}' enum değişkenine atanmaya çalışılan tip ({}), beklenen tamsayı tabanlı tip ile uyuşmuyor.", name, init_type));
                             }
                         } else {
-                            let allow_float_literal_narrowing = ty.is_float() && init_type.is_float() && matches!(init_expr, Expr::Literal(LiteralValue::Float(_)));
-                            
-                            // Pozitif I32 literal'den U* tiplerine (ve çözümlenmiş tipe) atamaya izin ver
-                            let allow_i32_to_unsigned_literal = resolved_ty.is_unsigned_integer() && init_type == Type::I32 && matches!(init_expr, Expr::Literal(LiteralValue::Int(val)) if *val >= 0);
+                            let allow_float_literal_narrowing = ty.is_float() && init_type.is_float() && matches!(&init_expr.kind, ExprKind::Literal(LiteralValue::Float(_)));
                             
                             //  'arr' tipine bir dizi literali atanmasına izin ver.
-                            let allow_arr_assignment = match (&resolved_ty, &init_type) {
-                                (Type::Arr, Type::ArrayLiteral(_)) => true,
-                                _ => false,
-                            };
+                            let allow_arr_assignment = matches!((&resolved_ty, &init_type), (Type::Arr, Type::ArrayLiteral(_)));
 
-                            if !resolved_ty.can_be_assigned_from(&init_type) && !allow_decimal_float_assignment_var_decl && !allow_bit_int_assignment_var_decl && !allow_int_to_bit_array_assignment && !allow_byte_i32_assignment_var_decl && !allow_hex_i32_assignment_var_decl && !allow_float_literal_narrowing && !allow_i32_to_unsigned_literal && !allow_arr_assignment {
-                                return Err(format!("Hata: '{}' değişkenine atanmaya çalışılan tip ({:?}), deklare edilen tip ({:?}) ile uyuşmuyor.", name, init_type, &resolved_ty));
+                            if !resolved_ty.can_be_assigned_from(&init_type) && !allow_decimal_float_assignment_var_decl && !allow_bit_int_assignment_var_decl && !allow_int_to_bit_array_assignment && !literal_fits && !allow_float_literal_narrowing && !allow_arr_assignment {
+                                let message = format!("Hata: '{}' değişkenine atanmaya çalışılan tip ({}), deklare edilen tip ({}) ile uyuşmuyor.", name, init_type, &resolved_ty);
+                                return Err(self.labeled_error(message, init_expr.span, format!("bu ifadenin tipi {}", init_type)));
                             }
                         }
                     }
@@ -787,14 +1011,13 @@ impl<'a> TypeChecker<'a> {
 					ty: resolved_ty,
 					is_const: *is_const,
 					_is_mutable: *is_mutable,
+					span: stmt.span,
 				};
                 //  Eğer tip bir enum ise, onu Custom'dan Enum(name, base_type)'a dönüştür.
                 if let Type::Custom(name) = &info.ty {
                     if let Some(variants) = self.enum_definitions.get(name) {
-                        if let Some(first_variant) = variants.values().next() {
-                             if let Type::Enum(_, base_type) = first_variant {
-                                info.ty = Type::Enum(name.clone(), base_type.clone());
-                             }
+                        if let Some(Type::Enum(_, base_type)) = variants.values().next() {
+                            info.ty = Type::Enum(name.clone(), base_type.clone());
                         }
                     }
                 }
@@ -804,17 +1027,15 @@ impl<'a> TypeChecker<'a> {
 						// 'var x: arr = [1, 2, 3]' gibi bir durumda tip çıkarımı yap.
 						// init_expr'in tipi Type::ArrayLiteral([I32, I32, I32]) olabilir.
 						// Buradan I32'yi çıkarıp 'inner'a atamalıyız.
-						if let Ok(init_type) = self.type_of_expr(init_expr) {
-							if let Type::ArrayLiteral(elements) = init_type {
-								*inner = Box::new(elements.get(0).cloned().unwrap_or(Type::Unknown));
-							}
+						if let Ok(Type::ArrayLiteral(elements)) = self.type_of_expr(init_expr) {
+							**inner = elements.first().cloned().unwrap_or(Type::Unknown);
 						}
 					}
 				}
 				self.define_variable(name.clone(), info)?;
 				Ok(())
 			}
-            Stmt::Assign { left, value } => {
+            StmtKind::Assign { left, value } => {
                 let left_type = self.type_of_expr(left)?;
                 let right_type = self.type_of_expr(value)?;
                 let name = format!("{:?}", left); // Hata mesajları için geçici bir isim
@@ -822,22 +1043,13 @@ impl<'a> TypeChecker<'a> {
                 match self.get_variable_info(&name) {
                     Ok(var_info) => {
                         // Decimal tiplere float atamasını kontrol et
-                        let allow_decimal_float_assignment = match (&var_info.ty, &right_type) {
-                            (Type::D32 | Type::D64 | Type::D128, Type::F32 | Type::F64 | Type::F80 | Type::F128) => true,
-                            _ => false,
-                        };
+                        let allow_decimal_float_assignment = matches!((&var_info.ty, &right_type), (Type::D32 | Type::D64 | Type::D128, Type::F32 | Type::F64 | Type::F80 | Type::F128));
                         
                         // Bit tipine integer literal atamasını kontrol et (0 veya 1)
-                        let allow_bit_int_assignment = match (&var_info.ty, value) {
-                            (Type::Bit, Expr::Literal(LiteralValue::Int(val))) if *val == 0 || *val == 1 => true,
-                            _ => false,
-                        };
+                        let allow_bit_int_assignment = matches!((&var_info.ty, &value.kind), (Type::Bit, ExprKind::Literal(LiteralValue::Int(val))) if *val == 0 || *val == 1);
 
 						// Bit dizisine integer literal atamasını kontrol et
-						let allow_int_to_bit_array_assignment = match (&var_info.ty, value) {
-							(Type::Array(inner, _), Expr::Literal(LiteralValue::Int(_))) if **inner == Type::Bit => true,
-							_ => false,
-						};
+						let allow_int_to_bit_array_assignment = matches!((&var_info.ty, &value.kind), (Type::Array(inner, _), ExprKind::Literal(LiteralValue::Int(_))) if **inner == Type::Bit);
 
                         if var_info.is_const {
                             return Err(format!("Hata: Sabit (const) değişken '{}' yeniden atanamaz.", name));
@@ -847,7 +1059,7 @@ impl<'a> TypeChecker<'a> {
                             return Err(format!("Hata: Değiştirilemeyen (immutable) değişken '{}' yeniden atanamaz. Değiştirmek için 'mut let' veya 'var' kullanın.", name));
                         }
                         if let Type::Fn(param_types, ret_type) = &var_info.ty {
-                            if let Expr::Variable(fn_name) = value {
+                            if let ExprKind::Variable(fn_name) = &value.kind {
                                 if let Some((expected_params, expected_ret, _, _)) = self.function_signatures.get(fn_name) {
                                     let expected_param_types: Vec<_> = expected_params.iter().map(|(_, ty, _)| ty.clone()).collect();
                                     if &expected_param_types != param_types || expected_ret != ret_type.as_ref() {
@@ -856,32 +1068,24 @@ impl<'a> TypeChecker<'a> {
                                 } else {
                                     return Err(format!("Hata: Atanmaya çalışılan '{}' fonksiyonu bulunamadı.", fn_name));
                                 }
-                            } else if let Expr::Lambda { params, return_type, .. } = value {
+                            } else if let ExprKind::Lambda { params, return_type, .. } = &value.kind {
                                 let lam_param_types: Vec<_> = params.iter().map(|(_, ty, _)| ty.clone()).collect();
                                 if &lam_param_types != param_types || return_type != ret_type.as_ref() {
                                     return Err(format!("Hata: '{}' değişkenine atanan lambda imzası uyumsuz.", name));
                                 }
                             } else if right_type != var_info.ty {
-                                return Err(format!("Hata: '{}' değişkenine atanmaya çalışılan tip ({:?}), beklenen tip ({:?}) ile uyuşmuyor.", name, right_type, var_info.ty));
+                                let message = format!("Hata: '{}' değişkenine atanmaya çalışılan tip ({}), beklenen tip ({}) ile uyuşmuyor.", name, right_type, var_info.ty);
+                                return Err(self.labeled_error(message, var_info.span, format!("'{}' burada {} olarak tanımlandı", name, var_info.ty)));
                             }
                         } else if right_type != left_type && left_type != Type::Any && right_type != Type::Any && right_type != Type::Null {
-                            // I32 literalden Byte atamasına izin ver
-                            let allow_byte_i32_assignment = match (&var_info.ty, value) {
-                                (Type::Byte, Expr::Literal(LiteralValue::Int(val))) if *val >= 0 && *val <= 255 => true,
-                                _ => false,
-                            };
-                            // I32 literalden Hex atamasına izin ver
-                            let allow_hex_i32_assignment = match (&var_info.ty, value) {
-                                (Type::Hex, Expr::Literal(LiteralValue::Int(val))) if *val >= 0 && *val <= 255 => true,
-                                _ => false,
-                            };
+                            // Soneksiz tamsayı sabiti hedef tipin aralığında olmalıdır
+                            let literal_fits = int_literal_fits(value, &var_info.ty)?;
                             // Float literallerinin daha dar float tiplerine atanmasına izin ver
-                            let allow_float_literal_narrowing = var_info.ty.is_float() && right_type.is_float() && matches!(value, Expr::Literal(LiteralValue::Float(_)));
-                            // Pozitif I32 literal'den U* tiplerine atamaya izin ver
-							let allow_i32_to_unsigned_literal = var_info.ty.is_unsigned_integer() && right_type == Type::I32 && matches!(value, Expr::Literal(LiteralValue::Int(val)) if *val >= 0);
+                            let allow_float_literal_narrowing = var_info.ty.is_float() && right_type.is_float() && matches!(&value.kind, ExprKind::Literal(LiteralValue::Float(_)));
 
-                            if !left_type.can_be_assigned_from(&right_type) && !allow_decimal_float_assignment && !allow_bit_int_assignment && !allow_int_to_bit_array_assignment && !allow_byte_i32_assignment && !allow_hex_i32_assignment && !allow_float_literal_narrowing && !allow_i32_to_unsigned_literal {
-                                return Err(format!("Hata: Atanmaya çalışılan tip ({:?}), beklenen tip ({:?}) ile uyuşmuyor.", right_type, left_type));
+                            if !left_type.can_be_assigned_from(&right_type) && !allow_decimal_float_assignment && !allow_bit_int_assignment && !allow_int_to_bit_array_assignment && !literal_fits && !allow_float_literal_narrowing {
+                                let message = format!("Hata: Atanmaya çalışılan tip ({}), beklenen tip ({}) ile uyuşmuyor.", right_type, left_type);
+                                return Err(self.labeled_error(message, var_info.span, format!("'{}' burada {} olarak tanımlandı", name, var_info.ty)));
                             }
                         }
                     }
@@ -890,16 +1094,17 @@ impl<'a> TypeChecker<'a> {
                             ty: right_type,
                             is_const: false,
                             _is_mutable: true,
+                            span: stmt.span,
                         };
                         self.define_variable(name.clone(), info)?;
                     }
                 }
                 Ok(())
             }
-            Stmt::If { cond, then_branch, else_branch } => {
+            StmtKind::If { cond, then_branch, else_branch } => {
                 let cond_type = self.type_of_expr(cond)?;
                 if cond_type != Type::Bool {
-                    return Err(format!("Hata: 'if' koşulu Boolean tipinde olmalıdır, bulundu: {:?}.", cond_type));
+                    return Err(format!("Hata: 'if' koşulu Boolean tipinde olmalıdır, bulundu: {}.", cond_type));
                 }
                 self.push_scope();
                 self.check_stmt(then_branch)?;
@@ -911,19 +1116,19 @@ impl<'a> TypeChecker<'a> {
                 }
                 Ok(())
             }
-            Stmt::While { condition, body } => {
+            StmtKind::While { condition, body } => {
                 let cond_type = self.type_of_expr(condition)?;
                 if cond_type != Type::Bool {
-                    return Err(format!("Hata: While koşulu bool tipinde olmalıdır, bulundu: {:?}", cond_type));
+                    return Err(format!("Hata: While koşulu bool tipinde olmalıdır, bulundu: {}", cond_type));
                 }
                 self.check_block_stmt(body)?;
                 Ok(())
             }
-            Stmt::Loop { body } => {
+            StmtKind::Loop { body } => {
                 self.check_block_stmt(body)?;
                 Ok(())
             }
-            Stmt::For { initializer, condition, increment, variable, iterable, body } => {
+            StmtKind::For { initializer, condition, increment, variable, iterable, body } => {
                 self.push_scope();
                 if let (Some(var_name), Some(iter_expr)) = (variable, iterable) {
                     let iterable_type = self.type_of_expr(iter_expr)?;
@@ -931,6 +1136,11 @@ impl<'a> TypeChecker<'a> {
                         // Durum 1: `for i in my_array`
                         Type::Array(inner, _) => *inner,
                         Type::Arr => Type::Any, // 'arr' tipiyle döngü kuruluyorsa, eleman tipini 'Any' kabul et.
+                        // YENİ: `for x in [1, 2, 3]`; elemanlar farklı tipteyse 'arr' gibi davranılır.
+                        Type::ArrayLiteral(items) => match items.split_first() {
+                            Some((first, rest)) if rest.iter().all(|t| t == first) => first.clone(),
+                            _ => Type::Any,
+                        },
                         //  Durum 2: `for i in 0..10`
                         Type::Custom(s) if s.starts_with("Range<") => {
                             // "Range<Type::I32>" gibi bir string'den I32 tipini çıkar.
@@ -941,7 +1151,7 @@ impl<'a> TypeChecker<'a> {
                         },
                         _ => {
                             return Err(format!(
-                                "Hata: 'for-in' döngüsü sadece diziler veya aralıklar (range) üzerinde çalışır, bulundu: {:?}.",
+                                "Hata: 'for-in' döngüsü sadece diziler veya aralıklar (range) üzerinde çalışır, bulundu: {}.",
                                 iterable_type
                             ));
                         }
@@ -951,17 +1161,18 @@ impl<'a> TypeChecker<'a> {
                         ty: inner_type,
                         is_const: false, // Döngü değişkeni her iterasyonda yeniden atanır.
                             _is_mutable: false,
+                            span: stmt.span,
                         };
                         self.define_variable(var_name.clone(), info)?;
                 } else {
                     if let Some(init_stmt) = initializer {
-                        if let Stmt::VarDecl { name, ty, init, is_const, is_let: _, is_mutable, .. } = &**init_stmt {
+                        if let StmtKind::VarDecl { name, ty, init, is_const, is_let: _, is_mutable, .. } = &init_stmt.kind {
                             // Durum 1: `for (var i: i32 = 0; ...)` gibi açık bir tanım varsa.
-                            self.check_and_define_variable(name, ty, init, is_const, is_mutable)?; // check_and_define_variable is_let'i kullanmıyor, bu yüzden burada görmezden gelebiliriz.
-                        } else if let Stmt::ExprStmt(expr) = &**init_stmt {
+                            self.check_and_define_variable(name, ty, init, is_const, is_mutable, init_stmt.span)?; // check_and_define_variable is_let'i kullanmıyor, bu yüzden burada görmezden gelebiliriz.
+                        } else if let StmtKind::ExprStmt(expr) = &init_stmt.kind {
                             // Durum 2: `for (i = 0; ...)` gibi örtük bir tanım varsa.
-                            if let Expr::Assign { left, value } = expr { // `expr` bir `&Expr`
-                                if let Expr::Variable(name) = &**left { // `left` bir `&Expr`
+                            if let ExprKind::Assign { left, value } = &expr.kind { // `expr` bir `&Expr`
+                                if let ExprKind::Variable(name) = &left.kind { // `left` bir `&Expr`
                                     // Sol taraf bir değişken. Sağ tarafın tipini çıkar.
                                     let var_type = self.type_of_expr(value)?;
                                     // Değişkeni döngü kapsamında 'mut' olarak tanımla.
@@ -969,6 +1180,7 @@ impl<'a> TypeChecker<'a> {
                                         ty: var_type,
                                         is_const: false,
                                         _is_mutable: true,
+                                        span: stmt.span,
                                     };
                                     self.define_variable(name.clone(), info)?;
                                 }
@@ -980,7 +1192,7 @@ impl<'a> TypeChecker<'a> {
                     if let Some(cond_expr) = condition {
                         let cond_type = self.type_of_expr(cond_expr)?;
                         if cond_type != Type::Bool {
-                            return Err(format!("Hata: 'for' döngüsü koşulu bool tipinde olmalıdır, bulundu: {:?}", cond_type));
+                            return Err(format!("Hata: 'for' döngüsü koşulu bool tipinde olmalıdır, bulundu: {}", cond_type));
                         }
                     }
                     if let Some(inc_expr) = increment {
@@ -991,11 +1203,11 @@ impl<'a> TypeChecker<'a> {
                 self.pop_scope()?;
                 Ok(())
             }
-            Stmt::Echo(expr) => {
+            StmtKind::Echo(expr) => {
                 self.type_of_expr(expr)?;
                 Ok(())
             }
-            Stmt::Return(expr) => {
+            StmtKind::Return(expr) => {
                 let actual_type = match expr {
                     Some(e) => self.type_of_expr(e)?,
                     None => Type::Void,
@@ -1009,64 +1221,72 @@ impl<'a> TypeChecker<'a> {
                     return Ok(());
                 }
 
-                if actual_type != self.expected_return_type {
+                //  Ok(x), Err(e), Some(x) ve None kurucularının eksik tarafı (Any) dönüş tipinden alınır.
+                let is_wrapper_compatible = matches!(self.expected_return_type, Type::Result(..) | Type::Option(_))
+                    && self.expected_return_type.can_be_assigned_from(&actual_type);
+                let literal_fits = match expr {
+                    Some(e) => int_literal_fits(e, &self.resolve_type(&self.expected_return_type.clone())?)?,
+                    None => false,
+                };
+                if actual_type != self.expected_return_type && !is_wrapper_compatible && !literal_fits {
                     // Hata mesajını daha anlaşılır hale getirelim.
                     //  Mevcut fonksiyon adını kullanarak doğru imzayı bul.
                     let signature_return_type = self.current_function_name.as_ref()
                         .and_then(|name| self.function_signatures.get(name))
                         .map(|(_, ret, _, _)| ret.clone());
-                    return Err(format!("Hata: Fonksiyondan dönülen tip ({:?}), beklenen tip ({:?}) ile uyuşmuyor. Fonksiyon imzası dönüş tipi: {:?}.", actual_type, self.expected_return_type, signature_return_type.unwrap_or(Type::Unknown)));
+                    return Err(format!("Hata: Fonksiyondan dönülen tip ({}), beklenen tip ({}) ile uyuşmuyor. Fonksiyon imzası dönüş tipi: {}.", actual_type, self.expected_return_type, signature_return_type.unwrap_or(Type::Unknown)));
                 }
                 Ok(())
             }
-            Stmt::Break => Ok(()),
-            Stmt::Continue => Ok(()),
-            Stmt::ExprStmt(expr) => {
-                // if let Expr::Assign { name, value } = expr {
-                //     return self.check_stmt(&Stmt::Assign { name: name.clone(), value: *value.clone() });
+            StmtKind::Break => Ok(()),
+            StmtKind::Continue => Ok(()),
+            StmtKind::ExprStmt(expr) => {
+                // if let ExprKind::Assign { name, value } = &expr.kind {
+                //     return self.check_stmt(&StmtKind::Assign { name: name.clone(), value: *value.clone() });
                 // }
                 self.type_of_expr(expr)?;
                 Ok(())
             }
-            Stmt::Routine(expr) => {
+            StmtKind::Routine(expr) => {
                 // 'routine' sadece bir fonksiyon çağrısı ile kullanılabilir.
-                if let Expr::Call { .. } = expr.as_ref() {
+                if let ExprKind::Call { .. } = &expr.kind {
                     self.type_of_expr(expr)?;
                 } else {
-                    return Err(format!("Hata: 'routine' anahtar kelimesi sadece bir fonksiyon çağrısı ile kullanılabilir, bulundu: {:?}.", expr));
+                    return Err(format!("Hata: 'routine' anahtar kelimesi sadece bir fonksiyon çağrısı ile kullanılabilir, bulundu: {}.", expr.kind.name()));
                 }
                 Ok(())
             }
-            Stmt::LabeledStmt { label, stmt, .. } => { //  'is_public' alanını .. ile yoksay
+            StmtKind::LabeledStmt { label, stmt, .. } => { //  'is_public' alanını .. ile yoksay
                 self.define_label(label.clone())?;
                 self.push_scope();
                 let info = VarInfo {
                     ty: Type::I32,
                     is_const: false,
                     _is_mutable: true,
+                    span: Span::dummy(),
                 };
                 self.define_variable("$rolling".to_string(), info)?;
                 self.check_stmt(stmt)?;
                 self.pop_scope()?;
                 Ok(())
             }
-            Stmt::Rolling(tag) => {
+            StmtKind::Rolling(tag) => {
                 if !self.get_label(tag) {
                     return Err(format!("Hata: Tanımlanmamış etiket '{}'", tag));
                 }
                 Ok(())
             }
-            Stmt::LabeledExpr { label: _, expr } => {
+            StmtKind::LabeledExpr { label: _, expr } => {
                 self.type_of_expr(expr)?;
                 Ok(())
             }
-            Stmt::Tag { .. } => Ok(()),
-            Stmt::Unsafe(block) => {
+            StmtKind::Tag { .. } => Ok(()),
+            StmtKind::Unsafe(block) => {
                 // `unsafe` bloğu, içindeki kodun tip kontrolünü etkilemez.
                 // Sadece derleyiciye "buradaki işlemlerin güvensiz olabileceğini biliyorum" mesajı verir.
                 self.check_stmt(block)
             },
-            Stmt::FastExec(block) => {
+            StmtKind::FastExec(block) => {
                 // `fastexec` bloğu da `unsafe` gibi, içindeki kodun tip kontrolünü etkilemez.
                 // Sadece kod üretimi aşamasında derleyiciye optimizasyon ipuçları verir.
                 let was_in_fastexec = self.in_fastexec_block;
@@ -1075,19 +1295,27 @@ impl<'a> TypeChecker<'a> {
                 self.in_fastexec_block = was_in_fastexec;
                 result
             },
-            Stmt::Asm { .. } => {
+            StmtKind::Defer(body) => self.check_stmt(body),
+            StmtKind::Asm { tag, body } => {
                 //  `asm` blokları sadece `fastexec` içinde kullanılabilir.
                 if !self.in_fastexec_block {
                     return Err("Hata: 'asm' blokları yalnızca bir 'fastexec' bloğu içinde kullanılabilir.".to_string());
                 }
-                // İçeriği dilin tip sisteminin dışındadır, bu yüzden başka kontrol gerekmez.
-                Ok(())
+                // İçeriği dilin tip sisteminin dışındadır; yalnızca `%isim` operandlarının kapsamdaki
+                // değişkenlere karşılık geldiği doğrulanır.
+                for name in asm_operands(body) {
+                    if self.get_variable_info(&name).is_err() {
+                        return Err(format!("Hata: asm bloğu tanımsız bir değişkene başvuruyor: '%{}'.", name));
+                    }
+                }
+                // Etiket, aynı kapsamdaki `asmcall(TAG)` çağrıları için kaydedilir.
+                self.define_label(tag.clone())
             }
         }
     }
 
     // check_stmt içindeki VarDecl mantığını dışarı taşıyan yeni yardımcı fonksiyon
-    fn check_and_define_variable(&mut self, name: &String, ty: &Type, init: &Option<Expr>, is_const: &bool, is_mutable: &bool) -> Result<(), String> {
+    fn check_and_define_variable(&mut self, name: &String, ty: &Type, init: &Option<Expr>, is_const: &bool, is_mutable: &bool, span: Span) -> Result<(), String> {
         if *is_const && init.is_none() {
             return Err(format!("Hata: Sabit (const) '{}' tanımlanırken bir başlangıç değeri atanmalıdır.", name));
         }
@@ -1102,26 +1330,10 @@ impl<'a> TypeChecker<'a> {
         if let Some(init_expr) = init {
             let init_type = self.type_of_expr(init_expr)?;
 
-            let allow_decimal_float_assignment_var_decl = match (ty, &init_type) {
-                (Type::D32 | Type::D64 | Type::D128, Type::F32 | Type::F64 | Type::F80 | Type::F128) => true,
-                _ => false,
-            };
-            let allow_bit_int_assignment_var_decl = match (ty, init_expr) {
-                (Type::Bit, Expr::Literal(LiteralValue::Int(val))) if *val == 0 || *val == 1 => true,
-                _ => false,
-            };
-            let allow_int_to_bit_array_assignment = match (ty, init_expr) {
-                (Type::Array(inner, _), Expr::Literal(LiteralValue::Int(_))) if **inner == Type::Bit => true,
-                _ => false,
-            };
-            let allow_byte_i32_assignment_var_decl = match (ty, init_expr) {
-                (Type::Byte, Expr::Literal(LiteralValue::Int(val))) if *val >= 0 && *val <= 255 => true,
-                _ => false,
-            };
-            let allow_hex_i32_assignment_var_decl = match (ty, init_expr) {
-                (Type::Hex, Expr::Literal(LiteralValue::Int(val))) if *val >= 0 && *val <= 255 => true,
-                _ => false,
-            };
+            let allow_decimal_float_assignment_var_decl = matches!((ty, &init_type), (Type::D32 | Type::D64 | Type::D128, Type::F32 | Type::F64 | Type::F80 | Type::F128));
+            let allow_bit_int_assignment_var_decl = matches!((ty, &init_expr.kind), (Type::Bit, ExprKind::Literal(LiteralValue::Int(val))) if *val == 0 || *val == 1);
+            let allow_int_to_bit_array_assignment = matches!((ty, &init_expr.kind), (Type::Array(inner, _), ExprKind::Literal(LiteralValue::Int(_))) if **inner == Type::Bit);
+            let literal_fits = int_literal_fits(init_expr, ty)?;
 
             if let (Type::Array(expected_inner_type, _), Type::ArrayLiteral(element_types)) = (ty, &init_type) {
                 if !element_types.is_empty() {
@@ -1129,20 +1341,20 @@ impl<'a> TypeChecker<'a> {
                     // Tüm elemanların aynı tipte olduğunu kontrol et (ArrayLiteral kontrolünde zaten yapılıyor ama burada da zararı olmaz)
                     for element_type in element_types.iter().skip(1) {
                         if element_type != first_element_type {
-                            return Err(format!("Hata: Dizi başlatıcısındaki tüm elemanlar aynı tipte olmalıdır. Bulunan tipler: {:?}.", element_types));
+                            return Err(format!("Hata: Dizi başlatıcısındaki tüm elemanlar aynı tipte olmalıdır. Bulunan tipler: {}.", type_list(element_types)));
                         }
                     }
                     // Atanan dizinin tipi, değişkenin beklenen iç tipiyle uyuşuyor mu?
                     if **expected_inner_type != Type::Unknown && **expected_inner_type != *first_element_type {
-                        return Err(format!("Hata: '{}' dizisine atanmaya çalışılan eleman tipi ({:?}), beklenen tip ({:?}) ile uyuşmuyor.", name, first_element_type, expected_inner_type));
+                        return Err(format!("Hata: '{}' dizisine atanmaya çalışılan eleman tipi ({}), beklenen tip ({}) ile uyuşmuyor.", name, first_element_type, expected_inner_type));
                     }
                 }
             } else if init_type != *ty && *ty != Type::Any && init_type != Type::Any && init_type != Type::Null {
-                let allow_float_literal_narrowing = ty.is_float() && init_type.is_float() && matches!(init_expr, Expr::Literal(LiteralValue::Float(_)));
-                let allow_i32_to_unsigned_literal = ty.is_unsigned_integer() && init_type == Type::I32 && matches!(init_expr, Expr::Literal(LiteralValue::Int(val)) if *val >= 0);
+                let allow_float_literal_narrowing = ty.is_float() && init_type.is_float() && matches!(&init_expr.kind, ExprKind::Literal(LiteralValue::Float(_)));
 
-                if !ty.can_be_assigned_from(&init_type) && !allow_decimal_float_assignment_var_decl && !allow_bit_int_assignment_var_decl && !allow_int_to_bit_array_assignment && !allow_byte_i32_assignment_var_decl && !allow_hex_i32_assignment_var_decl && !allow_float_literal_narrowing && !allow_i32_to_unsigned_literal {
-                    return Err(format!("Hata: '{}' değişkenine atanmaya çalışılan tip ({:?}), deklare edilen tip ({:?}) ile uyuşmuyor.", name, init_type, ty));
+                if !ty.can_be_assigned_from(&init_type) && !allow_decimal_float_assignment_var_decl && !allow_bit_int_assignment_var_decl && !allow_int_to_bit_array_assignment && !literal_fits && !allow_float_literal_narrowing {
+                    let message = format!("Hata: '{}' değişkenine atanmaya çalışılan tip ({}), deklare edilen tip ({}) ile uyuşmuyor.", name, init_type, ty);
+                    return Err(self.labeled_error(message, init_expr.span, format!("bu ifadenin tipi {}", init_type)));
                 }
             }
         }
@@ -1150,16 +1362,15 @@ impl<'a> TypeChecker<'a> {
             ty: ty.clone(),
             is_const: *is_const,
             _is_mutable: *is_mutable,
+            span,
         };
         
         // Type::Arr için özel işlem: boyut çıkarımı yap ama tipi değiştirme
         if info.ty == Type::Arr {
             if let Some(init_expr) = init {
-                if let Ok(init_type) = self.type_of_expr(init_expr) {
-                    if let Type::ArrayLiteral(_elements) = init_type {
-                        // Type::Arr olarak kalsın, sadece boyut bilgisini not et
-                        // Boyut bilgisi codegen'de ArrayLiteral'den alınacak
-                    }
+                if let Ok(Type::ArrayLiteral(_elements)) = self.type_of_expr(init_expr) {
+                    // Type::Arr olarak kalsın, sadece boyut bilgisini not et
+                    // Boyut bilgisi codegen'de ArrayLiteral'den alınacak
                 }
             }
         }
@@ -1167,15 +1378,13 @@ impl<'a> TypeChecker<'a> {
         // Type::Array için tip çıkarımı (homojen arrayler)
         if let (Type::Array(inner, _), Some(init_expr)) = (&mut info.ty, init) {
             if **inner == Type::Unknown {
-                if let Ok(init_type) = self.type_of_expr(init_expr) {
-                    if let Type::ArrayLiteral(elements) = init_type {
-                        // İlk elemanın tipini kullan (homojen array için)
-                        *inner = Box::new(elements.get(0).cloned().unwrap_or(Type::Unknown));
-                        // Dizinin boyutunu da çıkar (Inference)
-                        if let Type::Array(_, len_opt) = &mut info.ty {
-                            if len_opt.is_none() {
-                                *len_opt = Some(elements.len());
-                            }
+                if let Ok(Type::ArrayLiteral(elements)) = self.type_of_expr(init_expr) {
+                    // İlk elemanın tipini kullan (homojen array için)
+                    **inner = elements.first().cloned().unwrap_or(Type::Unknown);
+                    // Dizinin boyutunu da çıkar (Inference)
+                    if let Type::Array(_, len_opt) = &mut info.ty {
+                        if len_opt.is_none() {
+                            *len_opt = Some(elements.len());
                         }
                     }
                 }
@@ -1184,16 +1393,42 @@ impl<'a> TypeChecker<'a> {
         self.define_variable(name.clone(), info)
     }
 
+    // Hata biriktirme modunda ifade hatası raporlanır ve ifadenin tipi Unknown (zehirli) olur.
     pub fn type_of_expr(&mut self, expr: &Expr) -> Result<Type, String> {
-        match expr {
-            Expr::Block { statements } => {
+        if !self.recovering {
+            return self.type_of_expr_inner(expr);
+        }
+        let poison_before = self.poison_count;
+        let ty = match self.type_of_expr_inner(expr) {
+            Ok(Type::Unknown) => {
+                self.poison_count += 1;
+                Type::Unknown
+            }
+            Ok(ty) => ty,
+            Err(message) => {
+                self.report_error(message, expr.span, poison_before);
+                Type::Unknown
+            }
+        };
+        self.expr_types.insert(expr as *const Expr as usize, ty.clone());
+        Ok(ty)
+    }
+
+    // Programdaki bir ifadenin check_program sırasında bulunan tipi (hiç kontrol edilmediyse None).
+    pub fn checked_type(&self, expr: &Expr) -> Option<Type> {
+        self.expr_types.get(&(expr as *const Expr as usize)).cloned()
+    }
+
+    fn type_of_expr_inner(&mut self, expr: &Expr) -> Result<Type, String> {
+        match &expr.kind {
+            ExprKind::Block { statements } => {
                 self.push_scope();
                 let mut return_type = Type::Void; // Varsayılan dönüş tipi
                 let mut has_return = false;
     
                 for stmt in statements { // `statements` bir `&Vec<Stmt>`
                     self.check_stmt(stmt)?;
-                    if let Stmt::Return(Some(expr)) = stmt {
+                    if let StmtKind::Return(Some(expr)) = &stmt.kind {
                         let current_return_type = self.type_of_expr(expr)?;
                         if has_return && return_type != current_return_type {
                             let _ = self.pop_scope();
@@ -1207,41 +1442,51 @@ impl<'a> TypeChecker<'a> {
                 self.pop_scope()?;
                 Ok(return_type)
             },
-            Expr::Literal(lit) => Ok(match lit {
-                LiteralValue::Int(_) => Type::I32,
+            ExprKind::Literal(lit) => Ok(match lit {
+                LiteralValue::Int(value) => int_literal_type(*value, false)?,
                 LiteralValue::Float(_) => Type::F64,
                 LiteralValue::Hex(_) => Type::Hex,
+                LiteralValue::TypedInt(value, ty) => {
+                    // Lexer, işaretli sonekte eksiyle yazılabilsin diye en küçük değerin mutlak değerini de
+                    // kabul eder; eksi olmadan bu değer aralık dışıdır. İşaretsiz sonekleri lexer denetler.
+                    let range = ty.int_range().expect("tamsayı soneki");
+                    if !ty.is_unsigned_integer() && *value > range.1 as u128 {
+                        return Err(literal_range_error(&format!("{}{}", value, ty), ty, range));
+                    }
+                    ty.clone()
+                }
+                LiteralValue::TypedFloat(_, ty) => ty.clone(),
                 LiteralValue::Str(_) => Type::Str(None),
                 LiteralValue::Bool(_) => Type::Bool,
                 LiteralValue::Char(_) => Type::Char,
                 LiteralValue::Null => Type::Null,
             }),
-            Expr::Try(expr) => {
+            ExprKind::Try(expr) => {
                 let expr_type = self.type_of_expr(expr)?;
                 match expr_type {
                     Type::Result(ok_type, err_type) => {
                         // Fonksiyonun dönüş tipi de uyumlu bir Result olmalı.
                         if let Type::Result(_, expected_err_type) = &self.expected_return_type {
                             if *err_type != **expected_err_type {
-                                return Err(format!("Hata: '?' operatörü, fonksiyonun dönüş hatası tipiyle ({:?}) uyumsuz bir hata tipi ({:?}) döndürebilir.", expected_err_type, err_type));
+                                return Err(format!("Hata: '?' operatörü, fonksiyonun dönüş hatası tipiyle ({}) uyumsuz bir hata tipi ({}) döndürebilir.", expected_err_type, err_type));
                             }
                             // Her şey yolundaysa, ifade 'Ok' içindeki değeri döndürür.
                             Ok(*ok_type)
                         } else {
-                            Err(format!("Hata: '?' operatörü yalnızca dönüş tipi 'Result<T, E>' olan fonksiyonlar içinde kullanılabilir. Bulunan dönüş tipi: {:?}.", self.expected_return_type))
+                            Err(format!("Hata: '?' operatörü yalnızca dönüş tipi 'Result<T, E>' olan fonksiyonlar içinde kullanılabilir. Bulunan dönüş tipi: {}.", self.expected_return_type))
                         }
                     }
-                    _ => Err(format!("Hata: '?' operatörü yalnızca 'Result<T, E>' tipindeki ifadelere uygulanabilir, bulundu: {:?}.", expr_type)),
+                    _ => Err(format!("Hata: '?' operatörü yalnızca 'Result<T, E>' tipindeki ifadelere uygulanabilir, bulundu: {}.", expr_type)),
                 }
             },
-            Expr::Tuple(elements) => {
+            ExprKind::Tuple(elements) => {
                 let mut element_types = Vec::new(); // `elements` bir `&Vec<Expr>`
                 for elem in elements {
                     element_types.push(self.type_of_expr(elem)?);
                 }
                 Ok(Type::Tuple(element_types))
             }
-            Expr::ArrayLiteral(elements) => {
+            ExprKind::ArrayLiteral(elements) => {
                 let mut element_types = Vec::new(); // `elements` bir `&Vec<Expr>`
                 for elem in elements {
                     element_types.push(self.type_of_expr(elem)?);
@@ -1249,64 +1494,72 @@ impl<'a> TypeChecker<'a> {
                 // Dizi homojen olmalı, tüm elemanlar aynı tipte olmalı
                 if let Some(first_type) = element_types.first() {
                     if !element_types.iter().all(|t| t == first_type) {
-                        return Err(format!("Hata: Dizi literali içindeki tüm elemanlar aynı tipte olmalıdır. Bulunan tipler: {:?}.", element_types));
+                        return Err(format!("Hata: Dizi literali içindeki tüm elemanlar aynı tipte olmalıdır. Bulunan tipler: {}.", type_list(&element_types)));
                     }
                 }
                 Ok(Type::ArrayLiteral(element_types))
             }
-            Expr::Conditional { cond, then_branch, else_branch } => {
+            ExprKind::Conditional { cond, then_branch, else_branch } => {
                 let cond_type = self.type_of_expr(cond)?;
                 if cond_type != Type::Bool {
-                    return Err(format!("Hata: Ternary operatör koşulu Boolean tipinde olmalıdır, bulundu: {:?}.", cond_type));
+                    return Err(format!("Hata: Ternary operatör koşulu Boolean tipinde olmalıdır, bulundu: {}.", cond_type));
                 }
 
                 let then_type = self.type_of_expr(then_branch)?;
                 let else_type = self.type_of_expr(else_branch)?;
 
                 if then_type != else_type && then_type != Type::Any && else_type != Type::Any && then_type != Type::Null && else_type != Type::Null {
-                    return Err(format!("Hata: Ternary operatörünün her iki kolu da aynı tipi döndürmelidir. Bulunan tipler: {:?} ve {:?}.", then_type, else_type));
+                    return Err(format!("Hata: Ternary operatörünün her iki kolu da aynı tipi döndürmelidir. Bulunan tipler: {} ve {}.", then_type, else_type));
                 }
                 Ok(then_type)
             }
             //  'await' ifadesinin tip kontrolü.
-            Expr::Await(expr) => {
+            ExprKind::Await(expr) => {
                 let expr_type = self.type_of_expr(expr)?;
                 if let Type::Future(inner_type) = expr_type {
                     // 'await' bir Future<T> alır ve T döndürür.
                     Ok(*inner_type)
                 } else {
-                    Err(format!("Hata: 'await' sadece Future tipindeki ifadelere uygulanabilir, bulundu: {:?}", expr_type))
+                    Err(format!("Hata: 'await' sadece Future tipindeki ifadelere uygulanabilir, bulundu: {}", expr_type))
                 }
             },
-            Expr::Assign { left, value } => {
+            ExprKind::Assign { left, value } => {
                 let left_type = self.type_of_expr(left)?;
                 let right_type = self.type_of_expr(value)?;
 
                 // Atama yapılabilir mi kontrolü (l-value kontrolü)
-                if !matches!(**left, Expr::Variable(_) | Expr::MemberAccess {..} | Expr::ArrayAccess {..}) {
-                    return Err(format!("Hata: Atama ifadesinin sol tarafı bir değişkene, struct alanına veya dizi elemanına atanabilir olmalıdır."));
+                if !matches!(left.kind, ExprKind::Variable(_) | ExprKind::MemberAccess {..} | ExprKind::ArrayAccess {..}) {
+                    return Err("Hata: Atama ifadesinin sol tarafı bir değişkene, struct alanına veya dizi elemanına atanabilir olmalıdır.".to_string());
                 }
 
-                if left_type != right_type && left_type != Type::Any && right_type != Type::Any {
-                    return Err(format!("Hata: Atama işleminde tipler uyuşmuyor. Beklenen: {:?}, Bulunan: {:?}", left_type, right_type));
+                let literal_fits = int_literal_fits(value, &left_type)?;
+                if left_type != right_type && left_type != Type::Any && right_type != Type::Any && !literal_fits {
+                    let message = format!("Hata: Atama işleminde tipler uyuşmuyor. Beklenen: {}, Bulunan: {}", left_type, right_type);
+                    // Hedef bir değişkense tanımı ikincil etiketle gösterilir.
+                    if let ExprKind::Variable(name) = &left.kind {
+                        if let Ok(info) = self.get_variable_info(name) {
+                            return Err(self.labeled_error(message, info.span, format!("'{}' burada {} olarak tanımlandı", name, info.ty)));
+                        }
+                    }
+                    return Err(message);
                 }
                 // Atama ifadesi, atanan değeri döndürür.
                 Ok(right_type)
             },
-            Expr::ArrayAccess { name, index } => {
+            ExprKind::ArrayAccess { name, index } => {
                 let array_info = self.get_variable_info(name)?; // `name` bir `&String`
                 let array_type = array_info.ty;
                 if let Type::Array(inner_type, _) = array_type {
                     let index_type = self.type_of_expr(index)?;
                     if index_type != Type::I32 {
-                        return Err(format!("Hata: Dizi indeksi tam sayı (I32) olmalıdır, bulundu: {:?}.", index_type));
+                        return Err(format!("Hata: Dizi indeksi tam sayı (i32) olmalıdır, bulundu: {}.", index_type));
                     }
                     Ok(*inner_type)
                 } else {
-                    Err(format!("Hata: Array erişimi, dizi olmayan tipe ('{}' : {:?}) uygulanamaz.", name, array_type))
+                    Err(format!("Hata: Array erişimi, dizi olmayan tipe ('{}' : {}) uygulanamaz.", name, array_type))
                 }
             }
-            Expr::MemberAccess { object, member } => {
+            ExprKind::MemberAccess { object, member } => {
                 let object_type = self.type_of_expr(object)?;
                 match &object_type {
                     Type::Custom(name) => {
@@ -1343,6 +1596,37 @@ impl<'a> TypeChecker<'a> {
                         // 4. Hiçbiri değilse hata ver.
                         Err(format!("Hata: '{}' tipinin '{}' isminde bir alanı veya üyesi yok.", name, member))
                     }
+                    // YENİ: Jenerik struct örneğinin alanı; alan tipi tip argümanlarıyla örneklenir.
+                    Type::Generic(name, args) => {
+                        let field_type = self
+                            .struct_definitions
+                            .get(name)
+                            .and_then(|fields| fields.get(member))
+                            .cloned()
+                            .ok_or_else(|| format!("Hata: '{}' tipinin '{}' isminde bir alanı veya üyesi yok.", object_type, member))?;
+                        self.resolve_type(&field_type.substitute(&self.struct_bindings(name, args)))
+                    }
+                    //  Result ve Option metotları
+                    Type::Result(ok_type, _) => match member.as_str() {
+                        "is_ok" | "is_err" => Ok(Type::Fn(vec![], Box::new(Type::Bool))),
+                        "unwrap" => Ok(Type::Fn(vec![], ok_type.clone())),
+                        "unwrap_or" => Ok(Type::Fn(vec![(**ok_type).clone()], ok_type.clone())),
+                        "expect" => Ok(Type::Fn(vec![Type::Str(None)], ok_type.clone())),
+                        _ => Err(format!("Hata: Result tipinin '{}' isminde bir metodu yok.", member)),
+                    },
+                    Type::Option(inner_type) => match member.as_str() {
+                        "is_some" | "is_none" => Ok(Type::Fn(vec![], Box::new(Type::Bool))),
+                        "unwrap" => Ok(Type::Fn(vec![], inner_type.clone())),
+                        "unwrap_or" => Ok(Type::Fn(vec![(**inner_type).clone()], inner_type.clone())),
+                        "expect" => Ok(Type::Fn(vec![Type::Str(None)], inner_type.clone())),
+                        _ => Err(format!("Hata: Option tipinin '{}' isminde bir metodu yok.", member)),
+                    },
+                    // YENİ: Tuple elemanı (`t.0`).
+                    Type::Tuple(types) => member
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|index| types.get(index).cloned())
+                        .ok_or_else(|| format!("Hata: {} tipindeki tuple'ın '{}' isminde bir elemanı yok.", object_type, member)),
                     Type::Channel(inner_type) => {
                         if member == "new" {
                             Ok(Type::Channel(inner_type.clone()))
@@ -1350,10 +1634,10 @@ impl<'a> TypeChecker<'a> {
                             Err(format!("Hata: Kanal tipinin '{}' isminde bir metodu yok.", member))
                         }
                     }
-                    _ => Err(format!("Hata: Üye erişimi ('.') yalnızca struct, grup veya kanal tiplerine uygulanabilir, bulundu: {:?}.", object_type)),
+                    _ => Err(format!("Hata: Üye erişimi ('.') yalnızca struct, grup veya kanal tiplerine uygulanabilir, bulundu: {}.", object_type)),
                 }
             }
-            Expr::Variable(name) => {
+            ExprKind::Variable(name) => {
                 //  Merkezi isim çözümleme mantığını kullan.
                 match self.get_variable_info(name) {
                     Ok(info) => Ok(info.ty),
@@ -1361,13 +1645,16 @@ impl<'a> TypeChecker<'a> {
                         // Eğer `get_variable_info` bulamazsa, bu bir grup adı olabilir.
                         if self.group_definitions.contains_key(name) { // `name` bir `&str`
                             Ok(Type::Custom(name.clone()))
+                        } else if name == "None" {
+                            //  Boş Option değeri; içerik tipi atandığı yerden belirlenir.
+                            Ok(Type::Option(Box::new(Type::Any)))
                         } else {
                             Err(format!("Hata: Tanımlanmamış değişken veya grup adı: '{}'.", name))
                         }
                     }
                 }
             },
-            Expr::Input(prompt_opt) => {
+            ExprKind::Input(prompt_opt) => {
                 if let Some(prompt_expr) = prompt_opt {
                     let ty = self.type_of_expr(prompt_expr)?;
                     
@@ -1378,20 +1665,35 @@ impl<'a> TypeChecker<'a> {
                 }
                 Ok(Type::Str(None)) // input her zaman string döner
             },
-            Expr::Range { start, end } => {
+            ExprKind::Range { start, end } => {
                 let start_type = self.type_of_expr(start)?;
                 let end_type = self.type_of_expr(end)?;
                 
                 if !start_type.is_integer() || !end_type.is_integer() {
-                    return Err(format!("Hata: Aralık (range) operatörü '..' sadece tamsayılar arasında çalışır, bulundu: {:?} .. {:?}.", start_type, end_type));
+                    return Err(format!("Hata: Aralık (range) operatörü '..' sadece tamsayılar arasında çalışır, bulundu: {} .. {}.", start_type, end_type));
                 }
                 
                 // Şimdilik Range'i Custom olarak işaretliyoruz, for döngüsü bunu tanıyacak.
                 Ok(Type::Custom(format!("Range<{:?}>", start_type)))
             }
-            Expr::Binary { left, op, right } => {
+            ExprKind::Binary { left, op, right } => {
                 let left_type = self.type_of_expr(left)?;
                 let right_type = self.type_of_expr(right)?;
+                // YENİ: Tip parametreleri üzerindeki işlemler, parametrenin sınırlarına göre denetlenir.
+                if matches!(left_type, Type::Param(_)) || matches!(right_type, Type::Param(_)) {
+                    let (bound, operation) = match op {
+                        BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod => ("Num", "Aritmetik işlem"),
+                        BinOp::Equal | BinOp::NotEqual | BinOp::Identical | BinOp::NotIdentical => ("Eq", "Eşitlik karşılaştırması"),
+                        BinOp::Greater | BinOp::Less | BinOp::GreaterEqual | BinOp::LessEqual => ("Ord", "Sıralama karşılaştırması"),
+                        BinOp::BitwiseAnd | BinOp::BitwiseOr | BinOp::BitwiseXor | BinOp::LShift | BinOp::RShift => ("Int", "Bitsel işlem"),
+                        _ => return Err(format!("Hata: Mantıksal işlemde tipler Bool olmalıdır, bulundu: {} ve {}.", left_type, right_type)),
+                    };
+                    if left_type != right_type {
+                        return Err(format!("Hata: İkili işlemde tipler uyuşmuyor: {} {} {}.", left_type, op.symbol(), right_type));
+                    }
+                    self.require_bound(&left_type, bound, operation)?;
+                    return Ok(if bound == "Num" || bound == "Int" { left_type } else { Type::Bool });
+                }
                 match op {
                     BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod => {
                         // Farklı sayısal tipler arasında işlemlere izin ver (örn: f64 / i32)
@@ -1406,14 +1708,14 @@ impl<'a> TypeChecker<'a> {
                                 Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128 |
                                 Type::F32 | Type::F64 | Type::F80 | Type::F128 |
                                 Type::D32 | Type::D64 | Type::D128 => Ok(left_type),
-                                _ => Err(format!("Hata: Aritmetik işlem sayısal olmayan tipe ({:?}) uygulanamaz.", left_type)),
+                                _ => Err(format!("Hata: Aritmetik işlem sayısal olmayan tipe ({}) uygulanamaz.", left_type)),
                             }
                         } else if left_type == Type::Any || right_type == Type::Any {
                             // 'any' tipiyle esneklik sağla
                             if left_type == Type::Any { Ok(right_type) } else { Ok(left_type) }
                         } else {
                             // Diğer tüm uyumsuz tipler için hata ver
-                            Err(format!("Hata: Aritmetik işlemde tipler uyuşmuyor: {:?} {:?} {:?}.", left_type, op, right_type))
+                            Err(format!("Hata: Aritmetik işlemde tipler uyuşmuyor: {} {} {}.", left_type, op.symbol(), right_type))
                         }
                     }
                     BinOp::Equal | BinOp::NotEqual | BinOp::Greater | BinOp::Less | BinOp::GreaterEqual | BinOp::LessEqual | BinOp::Identical | BinOp::NotIdentical => {
@@ -1429,50 +1731,68 @@ impl<'a> TypeChecker<'a> {
                                 _ => false,
                             };
                             if !is_compatible {
-                                return Err(format!("Hata: Karşılaştırma işleminde tipler uyuşmuyor: {:?} {:?} {:?}.", left_type, op, right_type));
+                                return Err(format!("Hata: Karşılaştırma işleminde tipler uyuşmuyor: {} {} {}.", left_type, op.symbol(), right_type));
                             }
                         }
                         Ok(Type::Bool)
                     }
                     BinOp::And | BinOp::Or => {
                         if left_type != Type::Bool || right_type != Type::Bool {
-                            return Err(format!("Hata: Mantıksal işlemde tipler Bool olmalıdır, bulundu: {:?} ve {:?}.", left_type, right_type));
+                            return Err(format!("Hata: Mantıksal işlemde tipler Bool olmalıdır, bulundu: {} ve {}.", left_type, right_type));
                         }
                         Ok(Type::Bool)
                     }
                     BinOp::BitwiseAnd | BinOp::BitwiseOr | BinOp::BitwiseXor | BinOp::LShift | BinOp::RShift => {
                         if !left_type.is_integer() || !right_type.is_integer() {
-                            return Err(format!("Hata: Bitsel işlem yalnızca tamsayı tiplerine uygulanabilir, bulundu: {:?} ve {:?}.", left_type, right_type));
+                            return Err(format!("Hata: Bitsel işlem yalnızca tamsayı tiplerine uygulanabilir, bulundu: {} ve {}.", left_type, right_type));
                         }
                         if left_type != right_type {
                             // Şimdilik farklı tamsayı tipleri arasında işleme izin vermiyoruz.
-                            return Err(format!("Hata: Bitsel işlemde tipler uyuşmuyor: {:?} {:?} {:?}.", left_type, op, right_type));
+                            return Err(format!("Hata: Bitsel işlemde tipler uyuşmuyor: {} {} {}.", left_type, op.symbol(), right_type));
                         }
                         Ok(left_type) // Sonuç tipi, işlenenlerin tipiyle aynıdır.
                     }
-                    _ => Err(format!("Hata: Bilinmeyen veya desteklenmeyen ikili operatör: {:?}.", op)),
+                    _ => Err(format!("Hata: Bilinmeyen veya desteklenmeyen ikili operatör: {}.", op.symbol())),
                 }
             }
-            Expr::Unary { op, right } => {
+            ExprKind::Unary { op, right } => {
+                // Eksi işareti sabite katılarak aralık denetlenir (-128i8, -9223372036854775808).
+                if let (UnOp::Neg, ExprKind::Literal(literal)) = (op, &right.kind) {
+                    match literal {
+                        LiteralValue::Int(value) => return int_literal_type(*value, true),
+                        LiteralValue::TypedInt(value, ty) if !ty.is_unsigned_integer() => {
+                            let range = ty.int_range().expect("tamsayı soneki");
+                            if *value > range.0.unsigned_abs() {
+                                return Err(literal_range_error(&format!("-{}{}", value, ty), ty, range));
+                            }
+                            return Ok(ty.clone());
+                        }
+                        _ => {}
+                    }
+                }
                 let right_type = self.type_of_expr(right)?;
+                if let (UnOp::Neg, Type::Param(_)) = (op, &right_type) {
+                    self.require_bound(&right_type, "Num", "Negatifleştirme")?;
+                    return Ok(right_type);
+                }
                 match op {
                     UnOp::Neg => match right_type {
                         Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128 |
                         Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128 |
                         Type::F32 | Type::F64 | Type::F128 |
                         Type::D32 | Type::D64 | Type::D128 => Ok(right_type),
-                        _ => Err(format!("Hata: Negatifleştirme ({:?}) sayısal olmayan tipe ({:?}) uygulanamaz.", op, right_type)),
+                        _ => Err(format!("Hata: Negatifleştirme ('{}') sayısal olmayan tipe ({}) uygulanamaz.", op.symbol(), right_type)),
                     },
                     UnOp::Not => {
                         if right_type != Type::Bool {
-                            return Err(format!("Hata: Mantıksal DEĞİL (!{:?}) Bool olmayan tipe ({:?}) uygulanamaz.", op, right_type));
+                            return Err(format!("Hata: Mantıksal DEĞİL ('!') Bool olmayan tipe ({}) uygulanamaz.", right_type));
                         }
                         Ok(Type::Bool)
                     }
                     UnOp::AddressOf => {
                         // &x ifadesi, x bir l-value (atanabilir bir yer) olmalıdır.
-                        if !matches!(right.as_ref(), Expr::Variable(_)) {
-                             return Err(format!("Hata: Adres alma operatörü '&' sadece değişkenlere uygulanabilir, bulundu: {:?}.", right));
+                        if !matches!(right.kind, ExprKind::Variable(_)) {
+                             return Err(format!("Hata: Adres alma operatörü '&' sadece değişkenlere uygulanabilir, bulundu: {}.", right.kind.name()));
                         }
                         // Sonuç, ifadenin tipine bir pointer'dır. T -> *T
                         Ok(Type::Ptr(Box::new(right_type)))
@@ -1483,7 +1803,7 @@ impl<'a> TypeChecker<'a> {
                             // Sonuç, pointer'ın işaret ettiği tiptir. *T -> T
                             Ok(*inner_type)
                         } else {
-                            Err(format!("Hata: Dereferans operatörü '*' sadece pointer tiplerine uygulanabilir, bulundu: {:?}.", right_type))
+                            Err(format!("Hata: Dereferans operatörü '*' sadece pointer tiplerine uygulanabilir, bulundu: {}.", right_type))
                         }
                     }
                     _ => {
@@ -1493,26 +1813,26 @@ impl<'a> TypeChecker<'a> {
                             Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128 |
                             Type::F32 | Type::F64 | Type::F128 |
                             Type::D32 | Type::D64 | Type::D128 => {
-                                if let Expr::Variable(name) = right.as_ref() {
+                                if let ExprKind::Variable(name) = &right.kind {
                                     let var_info = self.get_variable_info(name)?;
                                     if !var_info._is_mutable {
                                         return Err(format!("Hata: Artırma/azaltma operatörü değiştirilemeyen (immutable) değişkene uygulanamaz: '{}'", name));
                                     }
                                 } else {
-                                    return Err(format!("Hata: Artırma/azaltma operatörü sadece değişkenlere uygulanabilir."));
+                                    return Err("Hata: Artırma/azaltma operatörü sadece değişkenlere uygulanabilir.".to_string());
                                 }
                                 Ok(right_type)
                             },
-                            _ => Err(format!("Hata: Artırma/azaltma operatörü sayısal olmayan tipe ({:?}) uygulanamaz.", right_type)),
+                            _ => Err(format!("Hata: Artırma/azaltma operatörü sayısal olmayan tipe ({}) uygulanamaz.", right_type)),
                         }
                     }
                 }
             }
-            Expr::Call { callee, args } => {
+            ExprKind::Call { callee, args } => {
                 // echo ve print gibi özel, esnek (variadic) fonksiyonlar için öncelikli kontrol.
 
                 //  clone() fonksiyonu için özel kontrol
-                if let Expr::Variable(callee_name) = &**callee {
+                if let ExprKind::Variable(callee_name) = &callee.kind {
                     if callee_name == "clone" {
                         if args.len() != 1 {
                             return Err("Hata: 'clone' fonksiyonu tam olarak bir argüman bekler.".to_string());
@@ -1528,11 +1848,57 @@ impl<'a> TypeChecker<'a> {
                             Type::Custom(_) | Type::Enum(_, _) | Type::Array(_, _) | Type::Tuple(_) => {
                                 return Ok(arg_type); // Klonlanmış ifadenin tipi, orijinaliyle aynıdır.
                             },
-                            _ => return Err(format!("Hata: Sadece struct, enum, dizi ve tuple tipleri klonlanabilir, bulundu: {:?}.", arg_type)),
+                            _ => return Err(format!("Hata: Sadece struct, enum, dizi ve tuple tipleri klonlanabilir, bulundu: {}.", arg_type)),
+                        }
+                    }
+                }
+
+                //  asmcall(TAG): kapsamdaki etiketli asm bloğunu yeniden çalıştırır. Argüman bir değişken
+                //  değil, asm bloğunun etiketidir.
+                if let ExprKind::Variable(callee_name) = &callee.kind {
+                    if callee_name == "asmcall" && self.get_variable_info(callee_name).is_err() {
+                        if !self.in_fastexec_block {
+                            return Err("Hata: 'asmcall' yalnızca bir 'fastexec' bloğu içinde kullanılabilir.".to_string());
+                        }
+                        let tag = match args.as_slice() {
+                            [(None, Expr { kind: ExprKind::Variable(tag), .. })] => tag,
+                            _ => return Err("Hata: 'asmcall' tam olarak bir asm etiketi bekler: asmcall(TAG).".to_string()),
+                        };
+                        if !self.get_label(tag) {
+                            return Err(format!("Hata: '{}' etiketli bir asm bloğu bu kapsamda tanımlı değil.", tag));
+                        }
+                        return Ok(Type::Void);
+                    }
+                }
+
+                //  Result ve Option kurucuları: Ok(x), Err(e), Some(x)
+                if let ExprKind::Variable(callee_name) = &callee.kind {
+                    if matches!(callee_name.as_str(), "Ok" | "Err" | "Some") && self.get_variable_info(callee_name).is_err() {
+                        if args.len() != 1 || args[0].0.is_some() {
+                            return Err(format!("Hata: '{}' tam olarak bir pozisyonel argüman bekler.", callee_name));
                         }
+                        let value_type = self.type_of_expr(&args[0].1)?;
+                        return Ok(match callee_name.as_str() {
+                            "Ok" => Type::Result(Box::new(value_type), Box::new(Type::Any)),
+                            "Err" => Type::Result(Box::new(Type::Any), Box::new(value_type)),
+                            _ => Type::Option(Box::new(value_type)),
+                        });
                     }
                 }
 
+                // YENİ: Jenerik fonksiyon ve jenerik grup üyesi çağrıları; tip argümanları çıkarılır.
+                let generic_key = match &callee.kind {
+                    ExprKind::Variable(callee_name) => Some(callee_name.clone()),
+                    ExprKind::MemberAccess { object, member } => match &object.kind {
+                        ExprKind::Variable(group) => Some(format!("{}.{}", group, member)),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                if let Some(key) = generic_key.filter(|key| self.generic_functions.contains_key(key)) {
+                    return self.check_generic_call(&key, args, expr.span);
+                }
+
                 let callee_type = self.type_of_expr(callee)?; // `callee` bir `&Expr`
 
                 let (params_def, return_type) = match callee_type {
@@ -1540,7 +1906,7 @@ impl<'a> TypeChecker<'a> {
                         let params: Vec<(String, Type, bool)> = param_types.iter().map(|t| ("".to_string(), t.clone(), false)).collect();
                         (params, *ret_type.clone())
                     }
-                    _ => return Err(format!("Hata: Çağrılabilir olmayan bir ifade çağrılamaz: {:?}", callee)),
+                    _ => return Err(format!("Hata: Çağrılabilir olmayan bir ifade çağrılamaz: {}", callee.kind.name())),
                 };
 
                 // Argüman kontrolü için hazırlık
@@ -1573,22 +1939,22 @@ impl<'a> TypeChecker<'a> {
 
                         
                         // arrlen için özel kontrol: Herhangi bir dizi tipini kabul et
-                        if let Expr::Variable(callee_name) = &**callee {
+                        if let ExprKind::Variable(callee_name) = &callee.kind {
                             if callee_name == "arrlen" {
                                 if arg_type.is_array() || arg_type == Type::Arr {
                                     // Tip doğru, devam et
                                 } else {
-                                    return Err(format!("Hata: 'arrlen' fonksiyonu bir dizi bekler, bulundu: {:?}.", arg_type));
+                                    return Err(format!("Hata: 'arrlen' fonksiyonu bir dizi bekler, bulundu: {}.", arg_type));
                                 }
                             } else if arg_type != resolved_expected_type && resolved_expected_type != Type::Any && arg_type != Type::Any && arg_type != Type::Null {
-                                return Err(format!("Hata: '{}' parametresi için tip uyuşmazlığı: beklenen {:?}, bulunan {:?}.", name, &resolved_expected_type, arg_type));
+                                return Err(format!("Hata: '{}' parametresi için tip uyuşmazlığı: beklenen {}, bulunan {}.", name, &resolved_expected_type, arg_type));
                             }
                         } else if resolved_expected_type.is_array() && arg_type == Type::Arr {
                             // Genel durum: Eğer bir fonksiyon Array bekliyorsa ve Arr gönderildiyse, kabul et.
                             // Bu, arrlen dışındaki fonksiyonlar için de çalışır.
                             // Tip doğru, devam et.
                         } else if arg_type != resolved_expected_type && resolved_expected_type != Type::Any && arg_type != Type::Any && arg_type != Type::Null {
-                             return Err(format!("Hata: '{}' parametresi için tip uyuşmazlığı: beklenen {:?}, bulunan {:?}.", name, &resolved_expected_type, arg_type));
+                             return Err(format!("Hata: '{}' parametresi için tip uyuşmazlığı: beklenen {}, bulunan {}.", name, &resolved_expected_type, arg_type));
                         }
                     } else {
                         // Pozisyonel Argüman (positional argument)
@@ -1602,28 +1968,25 @@ impl<'a> TypeChecker<'a> {
 
                         // Eğer parametre adı boşsa (bu bir fonksiyon pointer'ı çağrısıdır),
                         // bu kontrolü atla çünkü tüm parametreler aynı boş isme sahip olacaktır.
-                        if !param_name.is_empty() {
-                            if provided_args.contains(param_name) {
+                        if !param_name.is_empty()
+                            && provided_args.contains(param_name) {
                                 return Err(format!("Hata: '{}' parametresi hem pozisyonel hem de isimlendirilmiş olarak sağlandı.", param_name.clone()));
                             }
-                        }
                         
                         // arrlen için özel kontrol: Herhangi bir dizi tipini kabul et
-                        if let Expr::Variable(callee_name) = &**callee {
+                        if let ExprKind::Variable(callee_name) = &callee.kind {
                             if callee_name == "arrlen" {
                                 if arg_type.is_array() || arg_type == Type::Arr {
                                     // Tip doğru, devam et
                                 } else {
-                                    return Err(format!("Hata: 'arrlen' fonksiyonu bir dizi bekler, bulundu: {:?}.", arg_type));
+                                    return Err(format!("Hata: 'arrlen' fonksiyonu bir dizi bekler, bulundu: {}.", arg_type));
                                 }
                             } else {
-                                //  Pozitif i32 literallerinin işaretsiz tamsayı parametrelerine atanmasına izin ver.
-                                let allow_i32_to_unsigned_literal = resolved_expected_type.is_unsigned_integer() &&
-                                    arg_type == Type::I32 &&
-                                    matches!(arg_expr, Expr::Literal(LiteralValue::Int(val)) if *val >= 0);
+                                //  Soneksiz tamsayı sabitleri, aralığındaysa her tamsayı parametresine verilebilir.
+                                let literal_fits = int_literal_fits(arg_expr, &resolved_expected_type)?;
 
-                                if arg_type != resolved_expected_type && resolved_expected_type != Type::Any && arg_type != Type::Any && arg_type != Type::Null && !allow_i32_to_unsigned_literal {
-                                    return Err(format!("Hata: {}. parametre tipi uyumsuz: beklenen {:?}, bulundu {:?}.", positional_arg_index + 1, &resolved_expected_type, arg_type));
+                                if arg_type != resolved_expected_type && resolved_expected_type != Type::Any && arg_type != Type::Any && arg_type != Type::Null && !literal_fits {
+                                    return Err(format!("Hata: {}. parametre tipi uyumsuz: beklenen {}, bulundu {}.", positional_arg_index + 1, &resolved_expected_type, arg_type));
                                 }
                             }
                         } else if resolved_expected_type.is_array() && arg_type == Type::Arr {
@@ -1631,12 +1994,10 @@ impl<'a> TypeChecker<'a> {
                             // Bu, arrlen dışındaki fonksiyonlar için de çalışır.
                             // Tip doğru, devam et.
                         } else {
-                            let allow_i32_to_unsigned_literal = resolved_expected_type.is_unsigned_integer() &&
-                                arg_type == Type::I32 &&
-                                matches!(arg_expr, Expr::Literal(LiteralValue::Int(val)) if *val >= 0);
+                            let literal_fits = int_literal_fits(arg_expr, &resolved_expected_type)?;
 
-                            if arg_type != resolved_expected_type && resolved_expected_type != Type::Any && arg_type != Type::Any && arg_type != Type::Null && !allow_i32_to_unsigned_literal {
-                                return Err(format!("Hata: {}. parametre tipi uyumsuz: beklenen {:?}, bulundu {:?}.", positional_arg_index + 1, &resolved_expected_type, arg_type));
+                            if arg_type != resolved_expected_type && resolved_expected_type != Type::Any && arg_type != Type::Any && arg_type != Type::Null && !literal_fits {
+                                return Err(format!("Hata: {}. parametre tipi uyumsuz: beklenen {}, bulundu {}.", positional_arg_index + 1, &resolved_expected_type, arg_type));
                             }
                         }
 
@@ -1655,14 +2016,14 @@ impl<'a> TypeChecker<'a> {
 
                 Ok(return_type)
             }
-            Expr::Match { discriminant, cases } => {
+            ExprKind::Match { discriminant, cases } => {
                 let discriminant_type = self.type_of_expr(discriminant)?;
                 let mut case_types = Vec::new();
 
                 for (pattern, result) in cases {
                     let pattern_type = self.type_of_expr(pattern)?; // `pattern` bir `&Expr`
                     if pattern_type != discriminant_type && pattern_type != Type::Any {
-                        return Err(format!("Hata: Match ifadesindeki desen tipi ({:?}), kontrol edilen ifadenin tipiyle ({:?}) uyuşmuyor.", pattern_type, discriminant_type));
+                        return Err(format!("Hata: Match ifadesindeki desen tipi ({}), kontrol edilen ifadenin tipiyle ({}) uyuşmuyor.", pattern_type, discriminant_type));
                     }
                     case_types.push(self.type_of_expr(result)?);
                 }
@@ -1674,32 +2035,42 @@ impl<'a> TypeChecker<'a> {
                 let first_case_type = case_types[0].clone();
                 for case_type in case_types.iter().skip(1) {
                     if *case_type != first_case_type {
-                        return Err(format!("Hata: Match ifadesindeki tüm kollar aynı tipi döndürmelidir. Bulunan tipler: {:?}.", case_types));
+                        return Err(format!("Hata: Match ifadesindeki tüm kollar aynı tipi döndürmelidir. Bulunan tipler: {}.", type_list(&case_types)));
                     }
                 }
 
                 Ok(first_case_type)
             }
-            Expr::DefaultCase => {
+            ExprKind::DefaultCase => {
                 Ok(Type::Any) // 'def' durumu her tiple eşleşebilir.
             }
-            Expr::InterpolatedString(parts) => {
+            ExprKind::InterpolatedString(parts) => {
                 // İnterpolasyonlu string içindeki her bir ifadenin tipini kontrol et.
                 for part in parts {
+                    // YENİ: `{x.2}`: float değer iki ondalık basamakla yazılır.
+                    if let Some((object, digits)) = part.numeric_member() {
+                        let object_type = self.type_of_expr(object)?;
+                        if object_type.is_float() {
+                            if digits > 9 {
+                                return Err(format!("Hata: Yer tutucudaki ondalık hassasiyet en fazla 9 basamak olabilir, bulundu: {}.", digits));
+                            }
+                            continue;
+                        }
+                    }
                     self.type_of_expr(part)?; // `part` bir `&Expr`
                 }
                 Ok(Type::Str(None))
             },
-            Expr::Lambda { params, return_type, body } => {
+            ExprKind::Lambda { params, return_type, body } => {
                 self.push_scope();
                 for (param_name, param_type, default_value) in params {
                     if let Some(val) = default_value {
                         let val_type = self.type_of_expr(val)?; // `val` bir `&Expr`
                         if val_type != *param_type {
-                            return Err(format!("Hata: Lambda parametresi '{}' için varsayılan değer tipi ({:?}) uyumsuz, beklenen {:?}.", param_name, val_type, param_type));
+                            return Err(format!("Hata: Lambda parametresi '{}' için varsayılan değer tipi ({}) uyumsuz, beklenen {}.", param_name, val_type, param_type));
                         }
                     }
-                    let info = VarInfo { ty: param_type.clone(), is_const: false, _is_mutable: false };
+                    let info = VarInfo { ty: param_type.clone(), is_const: false, _is_mutable: false, span: expr.span };
                     self.define_variable(param_name.clone(), info)?;
                 }
                 
@@ -1713,18 +2084,18 @@ impl<'a> TypeChecker<'a> {
                 self.expected_return_type = old_expected_return_type;
 
                 if body_type != *return_type && *return_type != Type::Any && body_type != Type::Any {
-                    return Err(format!("Hata: Lambda gövdesinin tipi ({:?}), beklenen dönüş tipiyle ({:?}) uyuşmuyor.", body_type, return_type));
+                    return Err(format!("Hata: Lambda gövdesinin tipi ({}), beklenen dönüş tipiyle ({}) uyuşmuyor.", body_type, return_type));
                 }
 
                 self.pop_scope()?;
                 let param_types = params.iter().map(|(_, ty, _)| ty.clone()).collect();
                 Ok(Type::Fn(param_types, Box::new(return_type.clone())))
             },
-            Expr::SizeOf(_) => {
+            ExprKind::SizeOf(_) => {
                 // sizeof her zaman bir tamsayı boyutu döndürür.
                 Ok(Type::U64)
             },
-            Expr::EnumAccess { enum_name, variant_name } => {
+            ExprKind::EnumAccess { enum_name, variant_name } => {
                 //  `::` operatörü bir modül takma adına mı erişiyor?
                 if let Some(real_module_name) = self.module_aliases.get(enum_name).cloned() {
                     // Evet, bu bir modül erişimi. Modülü yükle ve öğeyi bul.
@@ -1758,13 +2129,24 @@ impl<'a> TypeChecker<'a> {
                     Err(format!("Hata: Tanımlanmamış enum tipi: '{}'.", enum_name))
                 }
             },
-            Expr::StructLiteral { name, fields } => {
+            ExprKind::StructLiteral { name, type_args: explicit_args, fields } => {
                 // 1. Struct'ın tanımlı olup olmadığını kontrol et.
                 let struct_def = self.struct_definitions.get(name)
                     .ok_or_else(|| format!("Hata: Tanımlanmamış struct tipi: '{}'.", name))?
                     .clone(); //  Borrow checker hatasını çözmek için struct tanımını klonla.
 
                 let mut provided_fields = HashSet::new();
+                // YENİ: Jenerik struct'larda tip argümanları alan değerlerinden çıkarılır.
+                let type_params = self.generic_structs.get(name).cloned().unwrap_or_default();
+                let mut bindings = HashMap::new();
+                // Açıkça verilen tip argümanları (Kutu<i32> { ... }) alanlardan önce bağlanır.
+                if !explicit_args.is_empty() {
+                    let explicit_args = explicit_args.iter().map(|t| self.resolve_type(t)).collect::<Result<Vec<_>, _>>()?;
+                    self.check_type_args(&Type::Generic(name.clone(), explicit_args.clone()))?;
+                    for (param, arg) in type_params.iter().zip(explicit_args) {
+                        bindings.insert(param.name.clone(), arg);
+                    }
+                }
 
                 // 2. Sağlanan her alan için tip kontrolü yap.
                 for (field_name, field_expr) in fields {
@@ -1774,7 +2156,9 @@ impl<'a> TypeChecker<'a> {
                     let provided_type = self.type_of_expr(field_expr)?; // `field_expr` bir `&Expr`
 
                     //  Enum tipi karşılaştırması için özel mantık.
-                    let types_match = if let (Type::Custom(expected_name), Type::Enum(provided_name, _)) = (expected_field_type, &provided_type) {
+                    let types_match = if !type_params.is_empty() {
+                        unify(&self.resolve_type(expected_field_type)?, &provided_type, &type_params, &mut bindings)
+                    } else if let (Type::Custom(expected_name), Type::Enum(provided_name, _)) = (expected_field_type, &provided_type) {
                         // Eğer beklenen tip bir Custom("Status") ise ve sağlanan tip bir Enum("Status", ...) ise,
                         // isimleri eşleşiyorsa bunu geçerli kabul et.
                         expected_name == provided_name
@@ -1784,7 +2168,8 @@ impl<'a> TypeChecker<'a> {
                     };
 
                     if !types_match {
-                        return Err(format!("Hata: '{}' struct'ının '{}' alanı için tip uyuşmazlığı. Beklenen: {:?}, bulunan: {:?}.", name, field_name, expected_field_type, provided_type));
+                        let expected_field_type = expected_field_type.substitute(&bindings);
+                        return Err(format!("Hata: '{}' struct'ının '{}' alanı için tip uyuşmazlığı. Beklenen: {}, bulunan: {}.", name, field_name, expected_field_type, provided_type));
                     }
                     provided_fields.insert(field_name.clone());
                 }
@@ -1796,30 +2181,44 @@ impl<'a> TypeChecker<'a> {
                     }
                 }
 
+                if !type_params.is_empty() {
+                    let mut type_args = Vec::new();
+                    for param in &type_params {
+                        let ty = bindings
+                            .get(&param.name)
+                            .cloned()
+                            .ok_or_else(|| format!("Hata: '{}' struct'ının '{}' tip parametresi alanlardan çıkarılamadı.", name, param.name))?;
+                        self.check_bounds(param, &ty)?;
+                        type_args.push(ty);
+                    }
+                    self.instances.insert(expr.span, (name.clone(), type_args.clone()));
+                    return Ok(Type::Generic(name.clone(), type_args));
+                }
+
                 // Her şey yolundaysa, ifadenin tipi bu struct'tır.
                 Ok(Type::Custom(name.clone()))
             },
             //  Kanal gönderme ve alma işlemlerinin tip kontrolü
-            Expr::Send { channel, value } => {
+            ExprKind::Send { channel, value } => {
                 let channel_type = self.type_of_expr(channel)?; // `channel` bir `&Expr`
                 let value_type = self.type_of_expr(value)?; // `value` bir `&Expr`
                 if let Type::Channel(inner_type) = channel_type {
                     if *inner_type != value_type && *inner_type != Type::Any {
-                        return Err(format!("Hata: Kanala gönderilen değerin tipi ({:?}), kanalın beklediği tiple ({:?}) uyuşmuyor.", value_type, inner_type));
+                        return Err(format!("Hata: Kanala gönderilen değerin tipi ({}), kanalın beklediği tiple ({}) uyuşmuyor.", value_type, inner_type));
                     }
                     // Gönderme işlemi bir değer döndürmez.
                     Ok(Type::Void)
                 } else {
-                    Err(format!("Hata: Gönderme işlemi (<-) sadece kanal tiplerine uygulanabilir, bulundu: {:?}.", channel_type))
+                    Err(format!("Hata: Gönderme işlemi (<-) sadece kanal tiplerine uygulanabilir, bulundu: {}.", channel_type))
                 }
             },
-            Expr::Recv(channel) => {
+            ExprKind::Recv(channel) => {
                 let channel_type = self.type_of_expr(channel)?; // `channel` bir `&Expr`
                 if let Type::Channel(inner_type) = channel_type {
                     // Alma işlemi, kanalın içindeki değeri döndürür.
                     Ok(*inner_type)
                 } else {
-                    Err(format!("Hata: Alma işlemi (<-) sadece kanal tiplerine uygulanabilir, bulundu: {:?}.", channel_type))
+                    Err(format!("Hata: Alma işlemi (<-) sadece kanal tiplerine uygulanabilir, bulundu: {}.", channel_type))
                 }
             },
         }
@@ -1832,9 +2231,179 @@ impl<'a> TypeChecker<'a> {
                 return self.resolve_type(resolved_type); // Zincirleme takma adları için özyinelemeli çözümle
             }
         }
+        if ty.has_generics() {
+            let expanded = self.expand_generic_aliases(ty);
+            if expanded != *ty {
+                return self.resolve_type(&expanded);
+            }
+        }
         Ok(ty.clone())
     }
 
+    // YENİ: Tipin içindeki jenerik typedef örneklerini (Cift<i32>) hedef tiplerine açar.
+    fn expand_generic_aliases(&self, ty: &Type) -> Type {
+        match ty {
+            Type::Generic(name, args) => {
+                let args: Vec<Type> = args.iter().map(|arg| self.expand_generic_aliases(arg)).collect();
+                match self.generic_aliases.get(name) {
+                    Some((params, target)) if params.len() == args.len() => {
+                        let bindings = params.iter().map(|p| p.name.clone()).zip(args).collect();
+                        self.expand_generic_aliases(&target.substitute(&bindings))
+                    }
+                    _ => Type::Generic(name.clone(), args),
+                }
+            }
+            Type::Tuple(types) => Type::Tuple(types.iter().map(|t| self.expand_generic_aliases(t)).collect()),
+            Type::Array(inner, size) => Type::Array(Box::new(self.expand_generic_aliases(inner)), *size),
+            Type::Ptr(inner) => Type::Ptr(Box::new(self.expand_generic_aliases(inner))),
+            Type::Ref(inner) => Type::Ref(Box::new(self.expand_generic_aliases(inner))),
+            Type::Option(inner) => Type::Option(Box::new(self.expand_generic_aliases(inner))),
+            Type::Result(ok, err) => Type::Result(Box::new(self.expand_generic_aliases(ok)), Box::new(self.expand_generic_aliases(err))),
+            _ => ty.clone(),
+        }
+    }
+
+    // YENİ: Tipteki jenerik örneklerin (Kutu<i32>) tanımlı olduğunu, doğru sayıda tip argümanı aldığını ve
+    // argümanların sınırları karşıladığını denetler. Tip argümanı verilmeden kullanılan jenerik tipler hatadır.
+    fn check_type_args(&self, ty: &Type) -> Result<(), String> {
+        match ty {
+            Type::Generic(name, args) => {
+                let params = self
+                    .generic_structs
+                    .get(name)
+                    .or_else(|| self.generic_aliases.get(name).map(|(params, _)| params))
+                    .ok_or_else(|| format!("Hata: '{}' jenerik bir struct veya typedef değil.", name))?;
+                if params.len() != args.len() {
+                    return Err(format!("Hata: '{}' tipi {} tip argümanı bekliyor, {} verildi.", name, params.len(), args.len()));
+                }
+                for (param, arg) in params.iter().zip(args) {
+                    self.check_type_args(arg)?;
+                    self.check_bounds(param, arg)?;
+                }
+                Ok(())
+            }
+            Type::Custom(name) if self.generic_structs.contains_key(name) || self.generic_aliases.contains_key(name) => {
+                Err(format!("Hata: '{}' jenerik bir tiptir; tip argümanları verilmelidir (örn: {}<i32>).", name, name))
+            }
+            Type::Param(name) if !self.type_params.iter().any(|p| p.name == *name) => {
+                Err(format!("Hata: '{}' tip parametresi bu kapsamda tanımlı değil.", name))
+            }
+            Type::Tuple(types) => types.iter().try_for_each(|t| self.check_type_args(t)),
+            Type::Array(inner, _) | Type::Ptr(inner) | Type::Ref(inner) | Type::Option(inner) | Type::Future(inner) | Type::Channel(inner) => self.check_type_args(inner),
+            Type::Result(ok, err) => self.check_type_args(ok).and_then(|_| self.check_type_args(err)),
+            Type::Fn(params, ret) => params.iter().chain(std::iter::once(&**ret)).try_for_each(|t| self.check_type_args(t)),
+            _ => Ok(()),
+        }
+    }
+
+    // YENİ: `ty` tipi, `param` tip parametresinin tüm sınırlarını karşılıyor mu?
+    fn check_bounds(&self, param: &TypeParam, ty: &Type) -> Result<(), String> {
+        for bound in &param.bounds {
+            if !self.satisfies_bound(ty, bound) {
+                return Err(format!("Hata: '{}' tipi, '{}' tip parametresinin '{}' sınırını karşılamıyor.", ty, param.name, bound));
+            }
+        }
+        Ok(())
+    }
+
+    // Somut tipler desteklediği işlemlere göre, tip parametreleri ise bildirilen sınırlarına göre değerlendirilir.
+    fn satisfies_bound(&self, ty: &Type, bound: &str) -> bool {
+        if let Type::Param(name) = ty {
+            return self
+                .type_params
+                .iter()
+                .rev()
+                .find(|p| p.name == *name)
+                .is_some_and(|p| p.bounds.iter().any(|have| bound_implies(have, bound)));
+        }
+        let numeric = ty.is_integer() || ty.is_float() || matches!(ty, Type::D32 | Type::D64 | Type::D128);
+        match bound {
+            "Num" => numeric,
+            "Int" => ty.is_integer(),
+            "Float" => ty.is_float(),
+            "Eq" => numeric || matches!(ty, Type::Bool | Type::Char | Type::Str(_) | Type::Enum(..) | Type::Byte | Type::Hex | Type::Bit),
+            "Ord" => numeric || matches!(ty, Type::Char | Type::Byte | Type::Hex),
+            _ => false,
+        }
+    }
+
+    // Tip parametresi üzerinde bir işlemin gerektirdiği sınırı denetler.
+    fn require_bound(&self, ty: &Type, bound: &str, operation: &str) -> Result<(), String> {
+        match ty {
+            Type::Param(name) if !self.satisfies_bound(ty, bound) => Err(format!(
+                "Hata: {} '{}' tip parametresine uygulanamaz; '{}: {}' sınırı gerekli.",
+                operation, name, name, bound
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    // YENİ: Jenerik fonksiyon çağrısı. Tip argümanları argüman tiplerinden çıkarılır, sınırlar denetlenir ve
+    // çağrı yeri monomorphization için `instances`'a kaydedilir. Dönen tip, çıkarılan argümanlarla örneklenir.
+    fn check_generic_call(&mut self, key: &str, args: &[(Option<String>, Expr)], span: Span) -> Result<Type, String> {
+        let type_params = self.generic_functions.get(key).cloned().unwrap_or_default();
+        let signature = match key.split_once('.') {
+            Some((group, member)) => self.group_definitions.get(group).and_then(|g| g.functions.get(member)).cloned(),
+            None => self.function_signatures.get(key).cloned(),
+        };
+        let Some((params, return_type, _, _)) = signature else {
+            return Err(format!("Hata: Tanımlanmamış fonksiyon: '{}'.", key));
+        };
+
+        let mut bindings = HashMap::new();
+        let mut provided = vec![false; params.len()];
+        let mut positional = 0;
+        for (arg_name, arg_expr) in args {
+            let index = match arg_name {
+                Some(name) => params
+                    .iter()
+                    .position(|(p, _, _)| p == name)
+                    .ok_or_else(|| format!("Hata: Fonksiyonun '{}' isminde bir parametresi yok.", name))?,
+                None => {
+                    positional += 1;
+                    positional - 1
+                }
+            };
+            if index >= params.len() {
+                return Err(format!("Hata: Fonksiyona çok fazla argüman verildi. Beklenen: {}, Sağlanan: {}", params.len(), args.len()));
+            }
+            if std::mem::replace(&mut provided[index], true) {
+                return Err(format!("Hata: '{}' parametresi birden fazla kez sağlandı.", params[index].0));
+            }
+            let arg_type = self.type_of_expr(arg_expr)?;
+            if arg_type == Type::Unknown {
+                return Ok(Type::Unknown);
+            }
+            let expected = self.resolve_type(&params[index].1)?;
+            if !unify(&expected, &arg_type, &type_params, &mut bindings) {
+                return Err(format!("Hata: {}. parametre tipi uyumsuz: beklenen {}, bulundu {}.", index + 1, expected.substitute(&bindings), arg_type));
+            }
+        }
+        if let Some((name, _, _)) = params.iter().zip(&provided).find(|((_, _, has_default), given)| !**given && !*has_default).map(|(p, _)| p) {
+            return Err(format!("Hata: '{}' fonksiyonunun '{}' parametresi için argüman verilmedi.", key, name));
+        }
+
+        let mut type_args = Vec::new();
+        for param in &type_params {
+            let ty = bindings
+                .get(&param.name)
+                .cloned()
+                .ok_or_else(|| format!("Hata: '{}' fonksiyonunun '{}' tip parametresi argümanlardan çıkarılamadı.", key, param.name))?;
+            self.check_bounds(param, &ty)?;
+            type_args.push(ty);
+        }
+        self.instances.insert(span, (key.to_string(), type_args));
+        self.resolve_type(&return_type.substitute(&bindings))
+    }
+
+    // Jenerik struct örneğinin (Kutu<i32>) tip parametresi bağları.
+    fn struct_bindings(&self, name: &str, args: &[Type]) -> HashMap<String, Type> {
+        self.generic_structs
+            .get(name)
+            .map(|params| params.iter().map(|p| p.name.clone()).zip(args.iter().cloned()).collect())
+            .unwrap_or_default()
+    }
+
     fn check_block_stmt(&mut self, block: &Stmt) -> Result<(), String> {
         self.push_scope();
         let check_result = self.check_stmt(block);
@@ -1848,11 +2417,122 @@ impl<'a> TypeChecker<'a> {
     //  Kapsam açmadan bir blok deyimini kontrol eden yardımcı fonksiyon.
     // 'for' döngüsü gibi zaten kendi kapsamını yöneten yapılar için kullanılır.
     fn check_block_stmt_no_scope(&mut self, block: &Stmt) -> Result<(), String> {
-        if let Stmt::Block(stmts) = block {
+        if let StmtKind::Block(stmts) = &block.kind {
             for stmt in stmts {
                 self.check_stmt(stmt)?;
             }
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+// YENİ: Tip parametre listesini denetler: adlar tekrarlanmamalı ve sınırlar yerleşik sınırlardan olmalı.
+// Hata mesajları için tip listesi: "i32, str".
+fn type_list(types: &[Type]) -> String {
+    types.iter().map(Type::to_string).collect::<Vec<_>>().join(", ")
+}
+
+// Yeniden tanımlanabilen üst düzey bildirimlerin adı.
+fn definition_name(decl: &Decl) -> Option<&str> {
+    match decl {
+        Decl::Function { name, .. } | Decl::Struct { name, .. } | Decl::Enum { name, .. } | Decl::Typedef { name, .. } | Decl::Group { name, .. } => Some(name),
+        _ => None,
+    }
+}
+
+fn check_type_params(params: &[TypeParam]) -> Result<(), String> {
+    for (i, param) in params.iter().enumerate() {
+        if params[..i].iter().any(|p| p.name == param.name) {
+            return Err(format!("Hata: '{}' tip parametresi birden fazla kez tanımlanmış.", param.name));
+        }
+        if let Some(bound) = param.bounds.iter().find(|b| !TYPE_BOUNDS.contains(&b.as_str())) {
+            return Err(format!("Hata: Bilinmeyen tip sınırı '{}'. Geçerli sınırlar: {}.", bound, TYPE_BOUNDS.join(", ")));
+        }
+    }
+    Ok(())
+}
+
+// `have` sınırına sahip bir tip, `want` sınırının işlemlerini de destekler mi? (Int ve Float, Num'ın;
+// Num, Ord'un; Ord da Eq'nun alt kümesidir.)
+fn bound_implies(have: &str, want: &str) -> bool {
+    have == want
+        || matches!(
+            (have, want),
+            ("Int" | "Float", "Num" | "Ord" | "Eq") | ("Num", "Ord" | "Eq") | ("Ord", "Eq")
+        )
+}
+
+// YENİ: Jenerik parametre tipini (`expected`) argüman tipiyle eşleştirir; `params` içindeki tip parametreleri
+// ilk eşleştikleri tipe bağlanır ve sonraki kullanımlar aynı tipi vermelidir.
+fn unify(expected: &Type, actual: &Type, params: &[TypeParam], bindings: &mut HashMap<String, Type>) -> bool {
+    match (expected, actual) {
+        (Type::Param(name), _) if params.iter().any(|p| p.name == *name) => match bindings.get(name) {
+            Some(bound) => bound == actual,
+            None => {
+                bindings.insert(name.clone(), actual.clone());
+                true
+            }
+        },
+        (Type::Array(e, _), Type::Array(a, _)) => unify(e, a, params, bindings),
+        (Type::Array(e, _), Type::ArrayLiteral(items)) => items.iter().all(|a| unify(e, a, params, bindings)),
+        (Type::Ptr(e), Type::Ptr(a))
+        | (Type::Ref(e), Type::Ref(a))
+        | (Type::Future(e), Type::Future(a))
+        | (Type::Channel(e), Type::Channel(a)) => unify(e, a, params, bindings),
+        (Type::Option(e), Type::Option(a)) => **a == Type::Any || unify(e, a, params, bindings),
+        (Type::Result(eo, ee), Type::Result(ao, ae)) => {
+            (**ao == Type::Any || unify(eo, ao, params, bindings)) && (**ae == Type::Any || unify(ee, ae, params, bindings))
+        }
+        (Type::Tuple(es), Type::Tuple(actuals)) => es.len() == actuals.len() && es.iter().zip(actuals).all(|(e, a)| unify(e, a, params, bindings)),
+        (Type::Generic(en, es), Type::Generic(an, actuals)) => {
+            en == an && es.len() == actuals.len() && es.iter().zip(actuals).all(|(e, a)| unify(e, a, params, bindings))
+        }
+        (Type::Fn(eps, er), Type::Fn(aps, ar)) => {
+            eps.len() == aps.len() && eps.iter().zip(aps).all(|(e, a)| unify(e, a, params, bindings)) && unify(er, ar, params, bindings)
+        }
+        _ => {
+            expected == actual
+                || matches!(actual, Type::Any | Type::Null)
+                || (*expected == Type::Any && !expected.has_params())
+                || matches!((expected, actual), (Type::Custom(e), Type::Enum(a, _)) if e == a)
+        }
+    }
+}
+
+// YENİ: Soneksiz tamsayı sabitinin tipi: i32'ye sığıyorsa i32, değilse i64 veya u64. Önündeki eksi işareti
+// (`negative`) değere katılır; i64'ün altına inen değerler hatadır.
+fn int_literal_type(value: u64, negative: bool) -> Result<Type, String> {
+    let value = if negative { -(value as i128) } else { value as i128 };
+    [Type::I32, Type::I64, Type::U64]
+        .into_iter()
+        .find(|ty| ty.int_range().is_some_and(|(min, max)| (min..=max).contains(&value)))
+        .ok_or_else(|| format!("Hata: '{}' sabiti i64 aralığının altında (en küçük değer: {}).", value, i64::MIN))
+}
+
+// YENİ: Soneksiz tamsayı sabitinin (başındaki eksi işaretiyle birlikte) değeri.
+fn unsuffixed_int(expr: &Expr) -> Option<i128> {
+    match &expr.kind {
+        ExprKind::Literal(LiteralValue::Int(value)) => Some(*value as i128),
+        ExprKind::Unary { op: UnOp::Neg, right } => match &right.kind {
+            ExprKind::Literal(LiteralValue::Int(value)) => Some(-(*value as i128)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn literal_range_error(value: &str, ty: &Type, (min, max): (i128, i128)) -> String {
+    format!("Hata: '{}' sabiti '{}' tipinin aralığı dışında ({}..={}).", value, ty, min, max)
+}
+
+// YENİ: Soneksiz tamsayı sabiti, bildirilen tamsayı tipinin aralığındaysa o tipe atanabilir (`true`);
+// aralık dışındaysa hata verilir. İfade soneksiz bir sabit değilse veya hedef tamsayı tipi değilse
+// `false` döner ve normal tip uyumu kuralları uygulanır.
+fn int_literal_fits(expr: &Expr, target: &Type) -> Result<bool, String> {
+    let (Some(value), Some(range)) = (unsuffixed_int(expr), target.int_range()) else {
+        return Ok(false);
+    };
+    if !(range.0..=range.1).contains(&value) {
+        return Err(literal_range_error(&value.to_string(), target, range));
+    }
+    Ok(true)
+}
@@ -0,0 +1,39 @@
+// 60_c_abi.oc'nin çağırdığı C fonksiyonları; gcc ile derlenip programla birlikte bağlanır.
+#include <stdint.h>
+
+typedef struct { int64_t x; int64_t y; } Nokta;
+typedef struct { double a; int64_t b; } Karisik;
+typedef struct { int64_t a; int64_t b; int64_t c; } Kutu;
+
+int64_t c_sekiz(int64_t a, int64_t b, int64_t c, int64_t d, int64_t e, int64_t f, int64_t g, int64_t h) {
+    return a + 2 * b + 3 * c + 4 * d + 5 * e + 6 * f + 7 * g + 8 * h;
+}
+
+int32_t c_sekiz_i32(int32_t a, int32_t b, int32_t c, int32_t d, int32_t e, int32_t f, int32_t g, int32_t h) {
+    return a - b + c - d + e - f + g - h;
+}
+
+double c_karisik(int64_t a, double x, int64_t b, double y, int64_t c, double z) {
+    return (double)a * x + (double)b * y + (double)c * z;
+}
+
+double c_dokuz_float(double a, double b, double c, double d, double e, double f, double g, double h, double i, int64_t n) {
+    return (a + b + c + d + e + f + g + h + i) * (double)n;
+}
+
+int64_t c_nokta(int64_t carpan, Nokta p) {
+    return carpan * (p.x * 100 + p.y);
+}
+
+double c_karisik_struct(Karisik k, double olcek) {
+    return (k.a + (double)k.b) * olcek;
+}
+
+int64_t c_kutu(Kutu k, int64_t n) {
+    return (k.a * 100 + k.b * 10 + k.c) * n;
+}
+
+// İlk beş tamsayı register'ı dolu olduğundan Nokta'nın iki parçası register'a sığmaz ve stack'ten geçer.
+int64_t c_tasan_nokta(int64_t a, int64_t b, int64_t c, int64_t d, int64_t e, Nokta p, int64_t f) {
+    return a + b + c + d + e + f + p.x * 1000 + p.y;
+}
@@ -0,0 +1,211 @@
+// tests/session.rs
+
+// YENİ: Programatik API'nin (`Session::compile`) testleri. Golden testler (`nim test`) programın
+// çıktısını karşılaştırır; buradakiler ise gömülen derleyicinin çağırana ne döndürdüğünü denetler:
+// tanılama kodları ve konumları, `take_warnings` ile alınan uyarılar ve `OutputSink`'e yazılan çıktılar.
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+
+use nim::diagnostic::{Severity, Stage};
+use nim::{Artifact, Backend, Diagnostic, EmitKind, Options, OutputSink, Session};
+
+// Yazılan çıktıları sırasıyla kaydeden sink.
+#[derive(Default)]
+struct RecordingSink {
+    artifacts: Vec<(Artifact, String)>,
+}
+
+impl OutputSink for RecordingSink {
+    fn write_artifact(&mut self, artifact: Artifact, contents: &str) -> io::Result<()> {
+        self.artifacts.push((artifact, contents.to_string()));
+        Ok(())
+    }
+}
+
+// Her yazma isteğini reddeden sink (disk dolu, salt okunur dizin vb. durumların yerine).
+struct FailingSink;
+
+impl OutputSink for FailingSink {
+    fn write_artifact(&mut self, _artifact: Artifact, _contents: &str) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::PermissionDenied, "yazma reddedildi"))
+    }
+}
+
+const HELLO: &str = "fn main(): i32 {\n    println(\"merhaba\");\n    return 0;\n}\n";
+
+fn compile(source: &str, emit: EmitKind, sink: &mut dyn OutputSink) -> Result<(), Vec<Diagnostic>> {
+    Session::from_source("test.oc", source, Options::default()).compile(emit, sink).map(|_| ())
+}
+
+// Derlemenin başarısız olmasını bekler ve hataları döndürür; hatalı derleme çıktı yazmamalıdır.
+fn errors(source: &str) -> Vec<Diagnostic> {
+    let mut sink = RecordingSink::default();
+    let errors = compile(source, EmitKind::Asm, &mut sink).expect_err("derleme başarısız olmalıydı");
+    assert!(sink.artifacts.is_empty(), "hatalı derleme çıktı yazmamalı");
+    errors
+}
+
+fn single_error(source: &str) -> Diagnostic {
+    let mut errors = errors(source);
+    assert_eq!(errors.len(), 1, "tek hata bekleniyordu: {:?}", errors);
+    errors.remove(0)
+}
+
+// `use` ile yüklenecek modül için geçici dizin (her test kendi dizinini kullanır).
+fn module_dir(test: &str, module: &str, source: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("nim-session-{}-{}", test, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join(format!("{}.nim", module)), source).unwrap();
+    dir
+}
+
+// Modülden içe aktarılan struct, programdaki aynı adlı struct'la çakışır ve uyarı üretir.
+const SHADOWED_IMPORT: &str = "struct Nokta { x: i64; y: i64; }\nuse modul;\nfn main(): i32 {\n    return 0;\n}\n";
+const MODULE: &str = "pub struct Nokta { x: i64; }\n";
+
+#[test]
+fn syntax_error_has_code_and_span() {
+    // Parser hatadan sonra toparlanıp sonraki deyimlerde de hata bulabilir; ilk hata asıl olandır.
+    let error = errors("fn main(): i32 {\n    var x: i32 = ;\n    return 0;\n}\n").remove(0);
+    assert_eq!(error.severity, Severity::Error);
+    assert_eq!(error.stage, Some(Stage::Parser));
+    assert_eq!(error.code.as_deref(), Some("E0001"));
+    let span = error.primary_span().expect("sözdizimi hatasının konumu olmalı");
+    assert_eq!((span.line, span.column), (2, 18));
+}
+
+#[test]
+fn type_mismatch_has_code_and_span() {
+    let error = single_error("fn main(): i32 {\n    var x: i32 = \"metin\";\n    return 0;\n}\n");
+    assert_eq!(error.stage, Some(Stage::Type));
+    assert_eq!(error.code.as_deref(), Some("E0102"));
+    let span = error.primary_span().expect("tip hatasının konumu olmalı");
+    assert_eq!(span.line, 2);
+}
+
+#[test]
+fn undefined_name_has_code_and_span() {
+    let error = single_error("fn main(): i32 {\n    return yok;\n}\n");
+    assert_eq!(error.code.as_deref(), Some("E0101"));
+    let span = error.primary_span().expect("tanımsız ismin konumu olmalı");
+    assert_eq!((span.line, span.column), (2, 12));
+}
+
+#[test]
+fn warnings_are_returned_not_printed() {
+    let dir = module_dir("warnings", "modul", MODULE);
+    let options = Options { include_paths: vec![dir.display().to_string()], ..Options::default() };
+    let mut session = Session::from_source("test.oc", SHADOWED_IMPORT, options);
+    let mut sink = RecordingSink::default();
+    session.compile(EmitKind::TypedAst, &mut sink).expect("uyarı derlemeyi durdurmamalı");
+
+    let warnings = session.take_warnings();
+    assert_eq!(warnings.len(), 1, "tek uyarı bekleniyordu: {:?}", warnings);
+    let warning = &warnings[0];
+    assert_eq!(warning.severity, Severity::Warning);
+    assert!(warning.message.contains("'Nokta'"), "{}", warning.message);
+    // Birincil konum `use` bildirimi, ikincil konum modüldeki tanımdır.
+    let span = warning.primary_span().expect("uyarının konumu olmalı");
+    assert_eq!(span.line, 2);
+    let imported = warning.labels.iter().find(|label| !label.primary).expect("ikincil etiket olmalı");
+    let module_file = session.sources().get(imported.span.file).expect("modül kaynak haritasında olmalı");
+    assert!(module_file.name.ends_with("modul.nim"), "{}", module_file.name);
+    assert!(session.take_warnings().is_empty(), "take_warnings listeyi boşaltmalı");
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn cli_reports_warnings_on_stderr() {
+    let dir = module_dir("cli", "modul", MODULE);
+    fs::write(dir.join("ana.oc"), SHADOWED_IMPORT).unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_nim"))
+        .args(["ana.oc", "-I.", "--emit", "typed-ast"])
+        .current_dir(&dir)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{}", stderr);
+    assert!(stderr.contains("uyarı:"), "uyarı stderr'e yazılmalı: {}", stderr);
+    assert!(!stdout.contains("uyarı"), "uyarı stdout'a yazılmamalı: {}", stdout);
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn sink_receives_only_the_requested_stage() {
+    let cases = [
+        (EmitKind::Tokens, Artifact::Tokens),
+        (EmitKind::Ast, Artifact::Ast),
+        (EmitKind::TypedAst, Artifact::TypedAst),
+        (EmitKind::Ir, Artifact::Ir),
+        (EmitKind::Asm, Artifact::Asm),
+    ];
+    for (emit, expected) in cases {
+        let mut sink = RecordingSink::default();
+        compile(HELLO, emit, &mut sink).unwrap_or_else(|errors| panic!("{:?}: {:?}", emit, errors));
+        let written: Vec<Artifact> = sink.artifacts.iter().map(|(artifact, _)| *artifact).collect();
+        assert_eq!(written, vec![expected], "{:?}", emit);
+        assert!(!sink.artifacts[0].1.is_empty(), "{:?} çıktısı boş", emit);
+    }
+}
+
+#[test]
+fn sink_receives_program_contents() {
+    let mut sink = RecordingSink::default();
+    compile(HELLO, EmitKind::Asm, &mut sink).unwrap();
+    let (_, asm) = &sink.artifacts[0];
+    assert!(asm.contains("main"), "{}", asm);
+    assert!(asm.contains("merhaba"), "{}", asm);
+
+    let options = Options { backend: Backend::C, ..Options::default() };
+    let mut sink = RecordingSink::default();
+    Session::from_source("test.oc", HELLO, options).compile(EmitKind::Exe, &mut sink).unwrap();
+    assert_eq!(sink.artifacts.len(), 1);
+    let (artifact, c_code) = &sink.artifacts[0];
+    assert_eq!(*artifact, Artifact::C);
+    assert!(c_code.contains("merhaba"), "{}", c_code);
+}
+
+#[test]
+fn sink_failure_is_reported_as_diagnostic() {
+    let errors = compile(HELLO, EmitKind::Ir, &mut FailingSink).expect_err("yazma hatası raporlanmalı");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].severity, Severity::Error);
+    assert!(errors[0].message.contains("'ir'"), "{}", errors[0].message);
+    assert!(errors[0].message.contains("yazma reddedildi"), "{}", errors[0].message);
+}
+
+// `use modul::{...}` yalnızca `pub` öğeleri içe aktarır; `pub` olmayan aynı adlı tanım atlanır.
+const EXPORTS: &str = "pub fn iki(): i64 { return 2i64; }\nfn gizli(): i64 { return 3i64; }\npub struct Nokta { x: i64; }\nstruct Gizli { x: i64; }\n";
+
+fn import(test: &str, source: &str) -> Result<(), Vec<Diagnostic>> {
+    let dir = module_dir(test, "modul", EXPORTS);
+    let options = Options { include_paths: vec![dir.display().to_string()], ..Options::default() };
+    let mut sink = RecordingSink::default();
+    let result = Session::from_source("test.oc", source, options).compile(EmitKind::TypedAst, &mut sink);
+    let _ = fs::remove_dir_all(dir);
+    result.map(|_| ())
+}
+
+#[test]
+fn named_imports_accept_public_items() {
+    import("named-pub", "use modul::{iki, Nokta};\nfn main(): i32 {\n    var n: Nokta = Nokta { x: iki() };\n    return 0;\n}\n")
+        .unwrap_or_else(|errors| panic!("{:?}", errors));
+    import("named-renamed", "use modul::{iki as two};\nfn main(): i32 {\n    var n: i64 = two();\n    return 0;\n}\n")
+        .unwrap_or_else(|errors| panic!("{:?}", errors));
+}
+
+#[test]
+fn named_imports_reject_private_items() {
+    for (test, item) in [("named-private-fn", "gizli"), ("named-private-struct", "Gizli")] {
+        let source = format!("use modul::{{{}}};\nfn main(): i32 {{\n    return 0;\n}}\n", item);
+        let errors = import(test, &source).expect_err("pub olmayan öğe içe aktarılmamalı");
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert_eq!(errors[0].code.as_deref(), Some("E0109"), "{:?}", errors[0]);
+        assert!(errors[0].message.contains(&format!("'{}'", item)), "{}", errors[0].message);
+        assert_eq!(errors[0].primary_span().map(|span| span.line), Some(1));
+    }
+}